@@ -0,0 +1,235 @@
+//! Benchmarks for the hot paths of the toolkit: tree building, log filtering,
+//! JSON pretty-printing and Postman import.
+//!
+//! Run with `cargo bench`.
+
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use serde_json::json;
+use std::collections::HashMap;
+use tui_one::models::{AppConfig, LogEntry, LogLevel, TreeState};
+use tui_one::services::HttpCollectionStorage;
+use tui_one::ui::components::http_response_viewer::format_response_body;
+
+/// Build folder/template listings shaped like a real templates directory
+fn make_storage_listing(file_count: usize) -> (Vec<String>, HashMap<String, Vec<String>>) {
+    let mut folders = Vec::new();
+    let mut templates_by_folder: HashMap<String, Vec<String>> = HashMap::new();
+
+    for i in 0..file_count {
+        let top = format!("Folder {}", i % 20);
+        let folder = format!("{}/Sub {}", top, i % 7);
+        if !folders.contains(&top) {
+            folders.push(top);
+        }
+        if !folders.contains(&folder) {
+            folders.push(folder.clone());
+        }
+        templates_by_folder
+            .entry(folder)
+            .or_default()
+            .push(format!("Template {}", i));
+    }
+
+    (folders, templates_by_folder)
+}
+
+/// Create a collections directory on disk holding `file_count` request files
+fn make_collections_on_disk(file_count: usize) -> (tempfile::TempDir, HttpCollectionStorage) {
+    let temp = tempfile::tempdir().expect("create temp dir");
+    let config = AppConfig {
+        templates_directory: temp.path().join("templates"),
+        ..AppConfig::default()
+    };
+    let storage = HttpCollectionStorage::new(config);
+
+    let (_, templates_by_folder) = make_storage_listing(file_count);
+    for (folder, names) in &templates_by_folder {
+        for name in names {
+            let request = tui_one::models::http_client::HttpRequest::new(name)
+                .with_url("https://example.com/api");
+            storage
+                .save_request(folder, name, request)
+                .expect("save request");
+        }
+    }
+
+    (temp, storage)
+}
+
+fn bench_tree_build(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tree_build");
+
+    for file_count in [100, 1_000, 5_000] {
+        let (folders, templates_by_folder) = make_storage_listing(file_count);
+        group.bench_with_input(
+            BenchmarkId::new("in_memory", file_count),
+            &file_count,
+            |b, _| {
+                b.iter(|| {
+                    TreeState::build_from_storage(
+                        black_box(folders.clone()),
+                        black_box(templates_by_folder.clone()),
+                    )
+                })
+            },
+        );
+    }
+
+    for file_count in [100, 1_000] {
+        let (_temp, storage) = make_collections_on_disk(file_count);
+        group.bench_with_input(
+            BenchmarkId::new("from_disk", file_count),
+            &file_count,
+            |b, _| {
+                b.iter(|| {
+                    let folders = storage.list_all_folders().unwrap();
+                    let mut requests_by_folder = HashMap::new();
+                    for folder in &folders {
+                        let requests = storage.list_requests_in_folder(folder).unwrap();
+                        if !requests.is_empty() {
+                            requests_by_folder.insert(folder.clone(), requests);
+                        }
+                    }
+                    TreeState::build_from_storage(folders, requests_by_folder)
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_log_filtering(c: &mut Criterion) {
+    let levels = [
+        LogLevel::Debug,
+        LogLevel::Info,
+        LogLevel::Warn,
+        LogLevel::Error,
+        LogLevel::Success,
+    ];
+    let entries: Vec<LogEntry> = (0..10_000)
+        .map(|i| {
+            LogEntry::new(
+                levels[i % levels.len()].clone(),
+                format!(
+                    "Request {} to https://example.com/items/{} finished",
+                    i,
+                    i % 97
+                ),
+            )
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("log_filtering_10k");
+    for (label, query) in [
+        ("empty", ""),
+        ("level", "error"),
+        ("message", "items/42"),
+        ("no_match", "no-match-at-all"),
+    ] {
+        group.bench_with_input(BenchmarkId::from_parameter(label), &query, |b, query| {
+            b.iter(|| {
+                entries
+                    .iter()
+                    .filter(|entry| entry.matches_search(black_box(query)))
+                    .count()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_json_pretty_print(c: &mut Criterion) {
+    let mut group = c.benchmark_group("json_pretty_print");
+
+    for item_count in [100, 1_000, 10_000] {
+        let items: Vec<_> = (0..item_count)
+            .map(|i| {
+                json!({
+                    "id": i,
+                    "name": format!("Item {}", i),
+                    "tags": ["alpha", "beta", "gamma"],
+                    "nested": { "active": i % 2 == 0, "score": i as f64 * 1.5 },
+                })
+            })
+            .collect();
+        let body = serde_json::to_string(&items).unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{}_bytes", body.len())),
+            &body,
+            |b, body| b.iter(|| format_response_body(black_box(body), "application/json")),
+        );
+    }
+
+    group.finish();
+}
+
+/// Build a Postman v2.1 collection with nested folders
+fn make_postman_collection(request_count: usize) -> String {
+    let folders: Vec<_> = (0..10)
+        .map(|folder| {
+            let requests: Vec<_> = (0..request_count / 10)
+                .map(|i| {
+                    json!({
+                        "name": format!("Request {}-{}", folder, i),
+                        "request": {
+                            "method": "POST",
+                            "header": [{ "key": "Content-Type", "value": "application/json" }],
+                            "body": { "mode": "raw", "raw": "{\"hello\":\"world\"}" },
+                            "url": {
+                                "raw": format!("https://example.com/{}/{}?page=1", folder, i),
+                                "query": [{ "key": "page", "value": "1" }]
+                            }
+                        }
+                    })
+                })
+                .collect();
+            json!({ "name": format!("Folder {}", folder), "item": requests })
+        })
+        .collect();
+
+    json!({
+        "info": {
+            "_postman_id": "00000000-0000-0000-0000-000000000000",
+            "name": "Benchmark Collection",
+            "schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"
+        },
+        "item": folders
+    })
+    .to_string()
+}
+
+fn bench_postman_import(c: &mut Criterion) {
+    let mut group = c.benchmark_group("postman_import");
+    group.sample_size(10);
+
+    for request_count in [100, 1_000] {
+        let temp = tempfile::tempdir().expect("create temp dir");
+        let collection_path = temp.path().join("collection.json");
+        std::fs::write(&collection_path, make_postman_collection(request_count)).unwrap();
+
+        let config = AppConfig {
+            templates_directory: temp.path().join("templates"),
+            ..AppConfig::default()
+        };
+        let storage = HttpCollectionStorage::new(config);
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(request_count),
+            &collection_path,
+            |b, path| b.iter(|| storage.import_postman_collection(black_box(path)).unwrap()),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_tree_build,
+    bench_log_filtering,
+    bench_json_pretty_print,
+    bench_postman_import
+);
+criterion_main!(benches);
@@ -1,17 +1,89 @@
-use crate::models::http::HttpState;
+use crate::models::clipboard::duplicate_name;
+use crate::models::confirmation::{ConfirmAction, Confirmation, DangerLevel};
+use crate::models::cookie_jar::cookie_jar_path;
+use crate::models::hex_dump::{hex_row_count, is_binary_body};
+use crate::models::http::{BodyContentType, HttpRequestTab, HttpState};
 use crate::models::http_client::{
-    HttpAuth, HttpHeader, HttpMethod, HttpRequest, HttpRequestBody, HttpResponse,
+    AssertionResult, BodyTruncation, ConnectionInfo, DIRECT_PROXY, HttpAuth, HttpHeader,
+    HttpMethod, HttpRequest, HttpRequestBody, HttpResponse, PaginationRule, ProxySettings,
+    RetryPolicy, TimingBreakdown, TlsSettings, merge_default_headers,
 };
+use crate::models::image_info::image_info;
+use crate::models::navigation::NavigationHistory;
+use crate::models::search::{SendCommand, rank_search_results, rank_send_targets};
+use crate::models::secrets::{is_secret_name, mask_secrets};
+use crate::models::storage_format::{
+    find_item_file, is_item_file, item_file_path, read_item_file, write_item_file,
+};
+use crate::models::text_search::find_matches;
+use crate::models::transfer::{SessionTransferStats, TransferStats, header_bytes};
+use crate::models::tutorial::{Tutorial, TutorialEvent};
 use crate::models::{
-    AppConfig, ClipboardItem, ClipboardOperation, LogEntry, LogLevel, NodeType, TreeState,
+    AppConfig, AutoLock, AutomationTemplate, BackupSchedule, BufferUsage, ClipboardItem,
+    ClipboardOperation, CookieJar, DEFAULT_ENVIRONMENT_NAME, Environment, FieldDoc, FieldType,
+    FolderColor, FolderInheritance, FolderSettings, FormField, HistoryRetention, InputPreset,
+    ItemNote, KeyValueEditor, KeyValuePair, LogEntry, LogLevel, LogTimeFilter, NodeType, NoteTree,
+    PerfStats, RecentItem, RecentItemKind, ResponseDisplayLimit, SearchCategory, SearchEntry,
+    SearchTarget, SharedCookieJar, StartupAction, StartupMode, StartupPane, StorageFormat,
+    TimestampFormat, TreeNode, TreeState, WebsiteConfig,
 };
 use crate::modes::BrowserEngine;
 use crate::modes::automation::AutomationState;
+use crate::services::assertions::evaluate_assertions;
+use crate::services::audit_log::{AuditAction, AuditEntry, AuditLog, is_production_environment};
+use crate::services::backup::{BackupInfo, BackupStore};
+use crate::services::bruno_import::{is_bruno_collection, parse_bruno_collection};
+use crate::services::bug_report::{BugReport, BugReportFormat};
+use crate::services::changelog::ChangelogRelease;
+use crate::services::collection_runner::{CollectionRun, RunResult, run_requests};
+use crate::services::drafts::{AUTOSAVE_INTERVAL, Draft, DraftContent, DraftStore};
+use crate::services::environment_rewrite::rewrite_for_environment;
+use crate::services::extraction::{apply_variables, extract_variables};
+use crate::services::health_check::{HealthReport, run_health_check};
+use crate::services::history_storage::{
+    HistoryEntry, HistoryStorage, MAX_HISTORY_BODY_BYTES, prune_history, search_recent_sends,
+};
+use crate::services::image_preview::{GraphicsProtocol, ImagePreview};
+use crate::services::insomnia_import::{looks_like_insomnia_export, parse_insomnia_export};
+use crate::services::item_diff::{FieldChange, diff_fields, request_fields, template_fields};
+use crate::services::json_tree::json_tree_value;
+use crate::services::load_test::{
+    LoadSample, LoadTest, LoadTestSettings, MAX_CONCURRENCY, MAX_ITERATIONS, run_load_test,
+};
+use crate::services::oauth2::{
+    OAuth2Config, OAuth2Token, OAuth2TokenCache, access_token as oauth2_access_token,
+};
+use crate::services::pagination::walk_pages;
+use crate::services::postman_import::{
+    LARGE_IMPORT_BYTES, POSTMAN_IMPORT_TIMEOUT, PostmanImportProgress, import_postman_streaming,
+    read_postman_info,
+};
+use crate::services::pre_request_script::prepare_request;
+use crate::services::process_registry::ProcessRegistry;
+use crate::services::proxy::{apply_proxy, effective_proxy};
+use crate::services::raw_headers::{format_raw_headers, parse_raw_headers};
+use crate::services::request_log::RequestLog;
+use crate::services::response_cache::ResponseCache;
+use crate::services::response_diff::{DiffKind, ResponseDiff};
+use crate::services::session::{RequestSession, SessionStore};
+use crate::services::snippet_generator::{SnippetFormat, generate_snippet};
+use crate::services::system_clipboard::copy_to_clipboard;
+use crate::services::template_bundle::{BUNDLE_EXTENSION, export_bundle, import_bundle};
+use crate::services::timing::{TimedConnector, TimedResolver};
+use crate::services::tls::{apply_tls, certificate_fingerprint};
+use crate::services::update_checker::UpdateInfo;
 use crate::services::{AuthService, HttpCollectionStorage, TemplateStorage};
-use anyhow::Result;
-use std::collections::HashMap;
-use std::path::Path;
+use crate::ui::components::http_response_viewer::{
+    ResponseBodyView, format_bytes, format_response_body, shows_hex_dump,
+};
+use anyhow::{Context, Result};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
+use tokio::io::AsyncWriteExt;
 use tokio::sync::{Mutex, mpsc};
 
 #[derive(Debug, Clone)]
@@ -20,10 +92,55 @@ pub struct CollectionPreview {
     pub request_count: usize,
     pub folder_count: usize,
     pub description: Option<String>,
+    /// False for huge files, whose items are only counted while importing
+    pub counts_known: bool,
+}
+
+/// Collection formats the import dialog understands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImportFormat {
+    #[default]
+    Postman,
+    OpenApi,
+    /// Insomnia v4 export (JSON or YAML)
+    Insomnia,
+    /// Bruno collection directory of `.bru` files
+    Bruno,
+    /// Checksummed automation template bundle (Automation mode)
+    TemplateBundle,
 }
 
+impl ImportFormat {
+    pub fn title(&self) -> &'static str {
+        match self {
+            ImportFormat::Postman => "Postman collection",
+            ImportFormat::OpenApi => "OpenAPI / Swagger spec",
+            ImportFormat::Insomnia => "Insomnia export",
+            ImportFormat::Bruno => "Bruno collection",
+            ImportFormat::TemplateBundle => "template bundle",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            ImportFormat::Postman => ImportFormat::OpenApi,
+            ImportFormat::OpenApi => ImportFormat::Insomnia,
+            ImportFormat::Insomnia => ImportFormat::Bruno,
+            ImportFormat::Bruno => ImportFormat::Postman,
+            // Bundles are the only format Automation mode imports
+            ImportFormat::TemplateBundle => ImportFormat::TemplateBundle,
+        }
+    }
+}
+
+/// Number of focusable fields in the settings dialog
+pub const SETTINGS_DIALOG_FIELDS: usize = 15;
+
+/// How many of the latest audit log entries the dialog loads
+const AUDIT_LOG_DIALOG_ENTRIES: usize = 500;
+
 /// Different modes the app can be in
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AppMode {
     Automation,
     Http, // Placeholder for future implementation
@@ -37,6 +154,127 @@ pub enum FocusedPane {
     Logs,
 }
 
+/// Where the user is: mode, focused pane, tree cursor and the item open in the editor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NavLocation {
+    pub mode: AppMode,
+    pub pane: FocusedPane,
+    pub tree_path: Option<String>,
+    pub item_path: Option<String>,
+}
+
+impl NavLocation {
+    /// Moving the tree cursor alone isn't a jump worth recording
+    pub fn is_same_place(&self, other: &NavLocation) -> bool {
+        self.mode == other.mode && self.pane == other.pane && self.item_path == other.item_path
+    }
+}
+
+/// Pane, tree and log position of a mode, kept while the other mode is in use
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModeView {
+    pub pane: FocusedPane,
+    /// Node under the tree cursor
+    pub tree_path: Option<String>,
+    /// Node highlighted as the open item
+    pub selected_path: Option<String>,
+    pub expanded_folders: Vec<String>,
+    pub log_scroll_position: usize,
+}
+
+/// A folder's environment binding, recorded when one of its requests is loaded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FolderEnvironment {
+    /// Request the binding was picked up for
+    pub request_key: String,
+    /// Folder declaring the environment (the request's own or one above it)
+    pub folder: String,
+    pub environment: String,
+}
+
+/// "Clone to folder…" state: the request being cloned and where (and for which environment)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CloneRequestDialog {
+    /// Tree path of the request being cloned
+    pub source_path: String,
+    /// Environment the source request's literal values belong to
+    pub source_environment: String,
+    /// Candidate folders, the collections root ("") first
+    pub folders: Vec<String>,
+    pub selected_folder: usize,
+    /// Environment to rewrite values for; `None` copies the request unchanged
+    pub target_environment: Option<String>,
+}
+
+/// Note popup for a tree item: freeform text and an optional "review by" date
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteDialog {
+    pub tree: NoteTree,
+    /// Tree path of the annotated item
+    pub path: String,
+    pub text: String,
+    /// Date as typed: empty, `YYYY-MM-DD`, `+3d` or `+2w`
+    pub review_by: String,
+    /// Typing goes to the date instead of the text
+    pub editing_date: bool,
+    pub error: Option<String>,
+}
+
+/// Variables a collection folder passes to the requests inside it, being edited
+#[derive(Debug, Clone)]
+pub struct FolderSettingsDialog {
+    /// Tree path of the folder
+    pub folder: String,
+    pub variables: Vec<KeyValuePair>,
+    pub editor: KeyValueEditor,
+    /// The folder's auth, shown for reference; edited in $EDITOR
+    pub auth: HttpAuth,
+}
+
+/// Restore picker listing the automatic and manual backups, newest first
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackupDialog {
+    pub backups: Vec<BackupInfo>,
+    pub selected: usize,
+    /// Enter was pressed once; the next Enter overwrites templates and collections
+    pub confirming: bool,
+    pub error: Option<String>,
+}
+
+/// Text handed to `$EDITOR`, picked when the key is pressed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExternalEditTarget {
+    /// JSON, text or raw request body
+    RequestBody,
+    /// Script, Tests or Extract tab of the request
+    RequestScript,
+    /// Automation form field, by index
+    AutomationField(usize),
+    /// Auth of a collection folder, as JSON
+    FolderAuth(String),
+}
+
+/// Everything the response body view depends on; it is rebuilt when any of it changes
+#[derive(Debug, Clone, PartialEq)]
+struct ResponseViewKey {
+    response_generation: u64,
+    raw: bool,
+    tree: bool,
+    filter: String,
+    collapsed: std::collections::HashSet<String>,
+    /// Values masked in the body; `None` while secrets are revealed
+    secrets: Option<Vec<String>>,
+}
+
+/// README.md of the folder focused in the tree, read once per folder
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FolderReadme {
+    pub mode: AppMode,
+    pub folder: String,
+    /// `None` when the folder has no README
+    pub text: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InputMode {
     Normal,
@@ -56,10 +294,73 @@ pub enum AppMessage {
     AutomationProgress(String),
     /// Request to quit the application
     Quit,
-    /// Response from API call
-    HttpResponseReceived(HttpResponse),
-    /// HTTP request failed - clear sending state
-    HttpRequestFailed(String),
+    /// Response from API call, keyed by the request that sent it. `sent` is the request
+    /// as it went out, when a single send produced the response.
+    HttpResponseReceived {
+        request_key: String,
+        generation: u64,
+        sent: Option<Box<HttpRequest>>,
+        response: Box<HttpResponse>,
+    },
+    /// A page walk fetched another page
+    PaginationProgress {
+        request_key: String,
+        generation: u64,
+        pages: usize,
+        items: usize,
+    },
+    /// HTTP request failed - clear sending state for that request
+    HttpRequestFailed {
+        request_key: String,
+        generation: u64,
+        error: String,
+    },
+    /// A response's extraction rules captured variables for the environment
+    VariablesExtracted(Vec<KeyValuePair>),
+    /// A background tree scan discovered a folder and its items
+    TreeFolderLoaded {
+        generation: u64,
+        folder: String,
+        items: Vec<String>,
+        color: Option<FolderColor>,
+        sort_weight: i32,
+    },
+    /// A background tree scan has finished
+    TreeLoadComplete { generation: u64 },
+    /// The update checker found a newer release
+    UpdateAvailable(UpdateInfo),
+    /// One request of a collection run finished
+    CollectionRunResult { generation: u64, result: RunResult },
+    /// A collection run has sent every request
+    CollectionRunComplete { generation: u64 },
+    /// One send of a load test finished
+    LoadTestSample { generation: u64, sample: LoadSample },
+    /// A load test has sent every iteration
+    LoadTestComplete { generation: u64 },
+    /// Progress of a background Postman import
+    PostmanImportProgress {
+        generation: u64,
+        progress: PostmanImportProgress,
+    },
+    /// A background Postman import finished, failed or was cancelled
+    PostmanImportFinished {
+        generation: u64,
+        outcome: Result<PostmanImportProgress, String>,
+    },
+    /// The startup health check finished
+    HealthChecked(HealthReport),
+}
+
+/// Tab keys and labels plus the active index, compared by `persist_request_tabs`
+pub type RequestTabsSignature = (Vec<(String, Option<String>)>, usize);
+
+fn request_tabs_signature(http_state: &HttpState) -> RequestTabsSignature {
+    let tabs = http_state
+        .open_tabs
+        .iter()
+        .map(|tab| (tab.key.clone(), tab.title.clone()))
+        .collect();
+    (tabs, http_state.active_tab)
 }
 
 /// Global app state that coordinates everything
@@ -76,6 +377,9 @@ pub struct App {
     // NEWCollections tree state
     pub tree_state: TreeState,
 
+    /// Generation of the current background tree scan (stale scans are ignored)
+    pub tree_load_generation: u64,
+
     /// Current mode (Automation or HTTP)
     pub current_mode: AppMode,
 
@@ -106,6 +410,13 @@ pub struct App {
     /// Search query for filtering logs
     pub log_search_query: String,
 
+    /// Time window for the log panel (last N minutes, since marker)
+    pub log_time_filter: LogTimeFilter,
+
+    /// Number of markers inserted and when the latest one was added
+    pub log_marker_count: usize,
+    pub last_log_marker_at: Option<chrono::DateTime<chrono::Local>>,
+
     /// Whether the login popup is visible
     pub show_login_popup: bool,
 
@@ -115,6 +426,11 @@ pub struct App {
     pub login_error: Option<String>,
     pub login_focused_field: usize, // 0 - username, 1 - password
 
+    /// Last key press or paste, for the idle auto-lock
+    pub last_activity_at: Instant,
+    /// Credentials were cleared for inactivity and the user hasn't logged in since
+    pub session_locked: bool,
+
     /// Whether the app should quit
     pub should_quit: bool,
 
@@ -147,12 +463,8 @@ pub struct App {
     /// Clipboard for cut/copy/paste operations
     pub clipboard: Option<ClipboardItem>,
 
-    /// Folder deletion confirmation dialog
-    pub show_delete_confirmation_dialog: bool,
-    pub delete_confirmation_item_name: String,
-    pub delete_confirmation_item_path: String,
-    pub delete_confirmation_is_folder: bool,
-    pub delete_confirmation_contents: Vec<String>, // List of what will be deleted
+    /// Pending question for the shared confirmation dialog (deletes and the like)
+    pub confirmation: Option<Confirmation>,
 
     /// Current input mode for form fields
     pub input_mode: InputMode,
@@ -170,54 +482,359 @@ pub struct App {
     pub import_dialog_file_path: String,
     pub import_dialog_error: Option<String>,
     pub import_dialog_preview: Option<CollectionPreview>,
+    pub import_dialog_format: ImportFormat,
+
+    /// Progress of the running Postman import (None when no import is running)
+    pub import_progress: Option<PostmanImportProgress>,
+
+    /// Set to stop the running Postman import
+    pub import_cancel: Arc<AtomicBool>,
+
+    /// Generation of the current Postman import (stale messages are ignored)
+    pub import_generation: u64,
+
+    /// Settings dialog state
+    pub show_settings_dialog: bool,
+    pub settings_dialog_templates_dir: String,
+    pub settings_dialog_collections_dir: String,
+    pub settings_dialog_move_data: bool,
+    pub settings_dialog_focused_field: usize, // 0=templates, 1=collections, 2=move data, 3=updates, 4=format JSON, 5=block invalid JSON, 6=timestamps, 7=history, 8=storage format, 9=cookies, 10=request log, 11=auto-lock
+    pub settings_dialog_check_updates: bool,
+    pub settings_dialog_auto_format_json: bool,
+    pub settings_dialog_block_invalid_json: bool,
+    pub settings_dialog_timestamp_format: TimestampFormat,
+    pub settings_dialog_history_retention: HistoryRetention,
+    pub settings_dialog_storage_format: StorageFormat,
+    pub settings_dialog_persist_cookies: bool,
+    pub settings_dialog_request_log_secrets: bool,
+    pub settings_dialog_auto_lock: AutoLock,
+    pub settings_dialog_response_limit: ResponseDisplayLimit,
+    pub settings_dialog_retry: RetryPolicy,
+    pub settings_dialog_backup_schedule: BackupSchedule,
+    pub settings_dialog_error: Option<String>,
+
+    /// Newer release found by the update checker (shown until dismissed)
+    pub update_notice: Option<UpdateInfo>,
+
+    /// Why the config file couldn't be loaded, if it couldn't (defaults are used then)
+    pub config_load_error: Option<String>,
+    /// Started with `--safe-mode`: default config, no scripts, read-only storage
+    pub safe_mode: bool,
+    /// Latest health check: item counts, python, config warnings and broken files
+    pub health_report: Option<HealthReport>,
+    /// Run the health check once the first tree scan has initialized storage
+    pub health_check_pending: bool,
+    /// Health panel overlay; opens by itself on launch only when there are problems
+    pub show_health_panel: bool,
+    pub health_panel_scroll: u16,
+
+    /// What's New overlay state
+    pub show_whats_new: bool,
+    pub whats_new_releases: Vec<ChangelogRelease>,
+    pub whats_new_scroll: u16,
+
+    /// Background tasks that finished while their mode was not active
+    pub background_notices: Vec<(AppMode, String)>,
+
+    /// Show secret values instead of masking them (Alt+s); logs stay scrubbed either way
+    pub reveal_secrets: bool,
+
+    /// Environment switcher popup (Alt+e) and its highlighted environment
+    pub show_environment_switcher: bool,
+    pub environment_switcher_selected: usize,
+
+    /// Environment variables dialog state
+    pub show_environment_dialog: bool,
+    pub environment_editor: KeyValueEditor,
+    /// Name being typed for the active environment (`n` new / `r` rename)
+    pub environment_name_input: Option<String>,
+    /// Proxy URL being typed for the active environment (`p`)
+    pub environment_proxy_input: Option<String>,
+    /// Environment the loaded request's folder asks for
+    pub folder_environment: Option<FolderEnvironment>,
+    /// Auth and variables the request in the editor picks up from its folders
+    pub folder_inheritance: FolderInheritance,
+
+    /// Open request tabs, kept for the next launch
+    pub session_store: SessionStore,
+    /// Tab keys, labels and active index as last saved, to save again only on change
+    pub saved_tabs_signature: RequestTabsSignature,
+    /// New label being typed for the active request tab (Alt+r)
+    pub tab_rename_input: Option<String>,
+
+    /// Autosaved drafts of the current request and form
+    pub draft_store: DraftStore,
+    /// Draft left behind by the previous session, offered for restore
+    pub pending_draft: Option<Draft>,
+    /// State last loaded or explicitly saved (not worth autosaving)
+    pub draft_baseline: DraftContent,
+    pub last_autosaved_draft: Option<DraftContent>,
+    pub last_autosave_at: Instant,
+
+    /// Quick switcher over recently opened templates and requests
+    pub show_quick_switcher: bool,
+    pub quick_switcher_selected: usize,
+
+    /// Pane focused before the run layout took over (restored when the run ends)
+    pub run_layout_previous_pane: Option<FocusedPane>,
+
+    /// Paste-a-cURL-command import dialog state
+    pub show_curl_import_dialog: bool,
+    pub curl_import_text: String,
+    pub curl_import_error: Option<String>,
+
+    /// Headers being edited as `Name: Value` text (dialog open while `Some`)
+    pub raw_headers_text: Option<String>,
+    pub raw_headers_error: Option<String>,
+    /// The raw headers dialog edits the default headers sent with every request
+    pub raw_headers_defaults: bool,
+
+    /// Expanded URL editor state (the URL as it was when opened, restored on cancel)
+    pub show_url_editor: bool,
+    pub url_editor_original: String,
+
+    /// Copy-as-snippet format picker state
+    pub show_snippet_dialog: bool,
+    pub snippet_dialog_selected: usize,
+
+    /// Global search overlay: index built on open, ranked results as indices into it
+    pub show_global_search: bool,
+    pub global_search_query: String,
+    pub global_search_entries: Vec<SearchEntry>,
+    pub global_search_results: Vec<usize>,
+    pub global_search_selected: usize,
+    /// Tree path to focus once the (re)loading tree contains it
+    pub pending_tree_reveal: Option<String>,
+    /// Where each mode was when it was last left, restored by `switch_mode`
+    pub mode_views: HashMap<AppMode, ModeView>,
+    /// Tree expansion and selection to restore once the reloading tree is complete
+    pub pending_tree_view: Option<ModeView>,
+
+    /// Cookies set by responses, sent with later requests to the same site
+    pub cookie_jar: SharedCookieJar,
+    /// OAuth 2.0 access tokens fetched this session (never written to disk)
+    pub oauth_tokens: OAuth2TokenCache,
+
+    /// Persistent history of sent requests, oldest first
+    pub history_storage: HistoryStorage,
+    pub history_entries: Vec<HistoryEntry>,
+    /// Snapshots of in-flight requests, keyed by request key, recorded when they finish
+    pub pending_history: HashMap<String, (HttpRequest, chrono::DateTime<chrono::Local>)>,
+    /// History browser state (selection counts from the newest entry)
+    pub show_history_dialog: bool,
+    pub history_selected: usize,
+    /// Recent-sends palette: fuzzy query and the matching history indices, best first
+    pub show_history_palette: bool,
+    pub history_palette_query: String,
+    pub history_palette_results: Vec<usize>,
+    pub history_palette_selected: usize,
+
+    /// Back/forward history of focused panes and opened items
+    pub navigation: NavigationHistory<NavLocation>,
+    /// Template last loaded into the automation form
+    pub loaded_template_path: Option<String>,
+    /// That template as loaded, for its description and field docs
+    pub loaded_template: Option<AutomationTemplate>,
+    /// Whether the template info popup (description and field docs) is open
+    pub show_template_info: bool,
+
+    /// Append-only record of deletes, renames, credential changes and prod sends
+    pub audit_log: AuditLog,
+    /// Entries shown by the audit log dialog; `Some` while it is open
+    pub audit_log_entries: Option<Vec<AuditEntry>>,
+    pub audit_log_scroll: usize,
+
+    /// Render/event-loop timings and the debug overlay showing them
+    pub perf_stats: PerfStats,
+    pub show_perf_overlay: bool,
+
+    /// Guided tutorial launched from help; `None` when not running
+    pub tutorial: Option<Tutorial>,
+
+    /// Image responses drawn inline with the terminal's graphics protocol
+    pub image_preview: ImagePreview,
+
+    /// The shown response with its secrets masked, when it has any; masked once per
+    /// response or change of secrets, not on every frame
+    pub masked_last_response: Option<HttpResponse>,
+
+    /// The shown response body laid out for the viewer, and what it was built from
+    pub response_view: Option<ResponseBodyView>,
+    response_view_key: Option<ResponseViewKey>,
+
+    /// Whether the terminal is too small for three panes and shows only the focused one
+    pub tiny_screen: bool,
+
+    /// Clone-to-folder dialog for HTTP requests
+    pub clone_request_dialog: Option<CloneRequestDialog>,
+
+    /// Notes popup for the focused tree item
+    pub note_dialog: Option<NoteDialog>,
+
+    /// Variables editor of the focused collection folder
+    pub folder_settings_dialog: Option<FolderSettingsDialog>,
+
+    /// Text waiting to be opened in `$EDITOR` once the main loop hands over the terminal
+    pub pending_external_edit: Option<ExternalEditTarget>,
+
+    /// Restore-from-backup picker
+    pub backup_dialog: Option<BackupDialog>,
+    /// When templates and collections were last snapshotted (or a scheduled attempt failed)
+    pub last_backup_at: Option<chrono::DateTime<chrono::Local>>,
+
+    /// Running sends and page fetches by request key, with the generation of the send,
+    /// so they can be cancelled
+    pub http_send_tasks: HashMap<String, (u64, tokio::task::AbortHandle)>,
+    /// Bumped for every send; results of an older send of the same request are dropped
+    http_send_generation: u64,
+
+    /// README of the folder focused in the tree, shown in place of the form
+    pub folder_readme: Option<FolderReadme>,
+    pub folder_readme_scroll: u16,
+
+    /// Input presets picker (automation mode) and the name being typed for a new preset
+    pub show_input_presets_dialog: bool,
+    pub input_presets_selected: usize,
+    pub input_preset_name_input: Option<String>,
+
+    /// Response diff overlay (current response against a previous one)
+    pub response_diff: Option<ResponseDiff>,
+    pub response_diff_scroll: usize,
+
+    /// Latest collection run (every request in a folder) and its results view
+    pub collection_run: Option<CollectionRun>,
+    /// Generation of the current run (results of abandoned runs are ignored)
+    pub collection_run_generation: u64,
+    pub show_collection_run: bool,
+    pub collection_run_selected: usize,
+
+    /// Latest load test of the current request and the popup that sets it up
+    pub load_test: Option<LoadTest>,
+    /// Generation of the current load test (samples of cancelled ones are ignored)
+    pub load_test_generation: u64,
+    pub load_test_task: Option<tokio::task::AbortHandle>,
+    pub load_test_settings: LoadTestSettings,
+    pub show_load_test: bool,
+    /// Setting being edited: 0 iterations, 1 concurrency
+    pub load_test_field: usize,
+
+    /// Bytes sent and received since startup, and whether their popup is open
+    pub session_transfer: SessionTransferStats,
+    pub show_transfer_stats: bool,
+
+    /// Whether the automation run queue popup is open, and its selected run
+    pub show_run_queue: bool,
+    pub run_queue_selected: usize,
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl App {
     /// Create a new app instance
     pub fn new() -> Self {
+        Self::with_safe_mode(false)
+    }
+
+    /// Start normally, or in safe mode to recover from a config or state file that
+    /// breaks startup: the config is ignored, scripts don't run and nothing is written
+    pub fn with_safe_mode(safe_mode: bool) -> Self {
         let (message_sender, message_receiver) = mpsc::unbounded_channel();
+        crate::models::config::set_read_only_storage(safe_mode);
 
         // Load or create configuration
-        let config = AppConfig::load().unwrap_or_else(|e| {
-            eprintln!("Failed to load config: {}, using defaults", e);
+        let mut config_load_error = None;
+        let mut config = if safe_mode {
             AppConfig::default()
-        });
+        } else {
+            AppConfig::load().unwrap_or_else(|e| {
+                eprintln!("Failed to load config: {}, using defaults", e);
+                config_load_error = Some(format!("{:#}", e));
+                AppConfig::default()
+            })
+        };
+
+        // Move data out of the config directory if this config predates the split
+        let layout_migration = if safe_mode {
+            Ok(None)
+        } else {
+            crate::services::storage_migration::migrate_legacy_layout(&mut config)
+        };
 
-        // Initialize template storage
+        // Storage is initialized and scanned in the background (see start_tree_load)
         let template_storage = TemplateStorage::new(config.clone());
-        if let Err(e) = template_storage.initialize() {
-            eprintln!("Failed to initialize template storage: {}", e);
+        let http_collection_storage = HttpCollectionStorage::new(config.clone()); // NEW
+
+        // Initialize HTTP collection storage off the startup path; the template
+        // storage is initialized by the first tree scan
+        let http_config = config.clone();
+        let http_init_sender = message_sender.clone();
+        tokio::task::spawn_blocking(move || {
+            if let Err(e) = HttpCollectionStorage::new(http_config).initialize() {
+                let _ = http_init_sender.send(AppMessage::Log(
+                    LogLevel::Error,
+                    format!("Failed to initialize HTTP collection storage: {}", e),
+                ));
+            }
+        });
+
+        let mut http_state = HttpState::new();
+        http_state.environment_vars = config
+            .environment_variables_of(config.active_environment_name())
+            .unwrap_or(&config.environment_variables)
+            .clone();
+
+        let automation_state = AutomationState::new();
+
+        let cookie_jar = if config.persist_cookies {
+            CookieJar::load(&cookie_jar_path())
+        } else {
+            CookieJar::default()
+        };
+
+        // Full bodies of truncated responses only matter to the session that received them
+        if !safe_mode {
+            let _ = std::fs::remove_dir_all(response_spool_directory());
         }
 
-        // Initialize HTTP collection storage
-        let http_collection_storage = HttpCollectionStorage::new(config.clone()); // NEW
-        if let Err(e) = http_collection_storage.initialize() {
-            // NEW
-            eprintln!("Failed to initialize HTTP collection storage: {}", e); // NEW
-        } // NEW
+        let history_storage = HistoryStorage::default();
+        let history_entries = history_storage.load();
 
-        // Build the initial tree state
-        let tree_state = Self::build_initial_tree_state_for_mode(
-            &template_storage,
-            &http_collection_storage,
-            &AppMode::Automation,
+        // Reopen the request tabs of the previous session
+        let session_store = SessionStore::default();
+        if !safe_mode && let Some(session) = session_store.load() {
+            http_state.restore_tabs(session.tabs, session.active);
+        }
+        let saved_tabs_signature = request_tabs_signature(&http_state);
+
+        let draft_store = DraftStore::default();
+        let pending_draft = draft_store.load();
+        let draft_baseline = DraftContent::capture(
+            &http_state.current_request_key,
+            &http_state.current_request,
+            &automation_state.fields,
         );
 
-        let show_logs = config.show_logs_on_startup;
-
         let mut app = Self {
             config,
             template_storage,
             http_collection_storage,
-            tree_state,
+            tree_state: TreeState::loading(),
+            tree_load_generation: 0,
             current_mode: AppMode::Automation,
             focused_pane: FocusedPane::Form, // Start with form focused
-            automation_state: AutomationState::new(),
-            http_state: HttpState::new(),
+            automation_state,
+            http_state,
             auth_service: AuthService::new(),
             log_entries: Vec::new(),
             // show_logs,
             log_search_query: String::new(),
+            log_time_filter: LogTimeFilter::All,
+            log_marker_count: 0,
+            last_log_marker_at: None,
             log_scroll_position: 0,
             log_search_mode: false,
             show_login_popup: false,
@@ -225,6 +842,8 @@ impl App {
             login_password: String::new(),
             login_error: None,
             login_focused_field: 0,
+            last_activity_at: Instant::now(),
+            session_locked: false,
             should_quit: false,
             message_receiver: Arc::new(Mutex::new(message_receiver)),
             message_sender,
@@ -244,11 +863,7 @@ impl App {
             rename_dialog_is_folder: false,
             rename_dialog_error: None,
             clipboard: None,
-            show_delete_confirmation_dialog: false,
-            delete_confirmation_item_name: String::new(),
-            delete_confirmation_item_path: String::new(),
-            delete_confirmation_is_folder: false,
-            delete_confirmation_contents: Vec::new(),
+            confirmation: None,
             input_mode: InputMode::Normal, // Prevent editing texting until explicitly in InputMode
             show_help_dialog: false,
             help_search_query: String::new(),
@@ -258,9 +873,184 @@ impl App {
             import_dialog_file_path: String::new(),
             import_dialog_error: None,
             import_dialog_preview: None,
+            import_dialog_format: ImportFormat::default(),
+            import_progress: None,
+            import_cancel: Arc::new(AtomicBool::new(false)),
+            import_generation: 0,
+            show_settings_dialog: false,
+            settings_dialog_templates_dir: String::new(),
+            settings_dialog_collections_dir: String::new(),
+            settings_dialog_move_data: true,
+            settings_dialog_focused_field: 0,
+            settings_dialog_check_updates: false,
+            settings_dialog_auto_format_json: false,
+            settings_dialog_block_invalid_json: false,
+            settings_dialog_timestamp_format: TimestampFormat::default(),
+            settings_dialog_history_retention: HistoryRetention::default(),
+            settings_dialog_storage_format: StorageFormat::default(),
+            settings_dialog_persist_cookies: false,
+            settings_dialog_request_log_secrets: false,
+            settings_dialog_auto_lock: AutoLock::default(),
+            settings_dialog_response_limit: ResponseDisplayLimit::default(),
+            settings_dialog_retry: RetryPolicy::default(),
+            settings_dialog_backup_schedule: BackupSchedule::default(),
+            settings_dialog_error: None,
+            update_notice: None,
+            show_whats_new: false,
+            whats_new_releases: Vec::new(),
+            whats_new_scroll: 0,
+            background_notices: Vec::new(),
+            reveal_secrets: false,
+            show_environment_switcher: false,
+            environment_switcher_selected: 0,
+            show_environment_dialog: false,
+            environment_editor: KeyValueEditor::new(),
+            environment_name_input: None,
+            environment_proxy_input: None,
+            folder_environment: None,
+            folder_inheritance: FolderInheritance::default(),
+            session_store,
+            saved_tabs_signature,
+            tab_rename_input: None,
+            draft_store,
+            pending_draft,
+            draft_baseline,
+            last_autosaved_draft: None,
+            last_autosave_at: Instant::now(),
+            show_quick_switcher: false,
+            quick_switcher_selected: 0,
+            run_layout_previous_pane: None,
+            show_curl_import_dialog: false,
+            curl_import_text: String::new(),
+            curl_import_error: None,
+            raw_headers_text: None,
+            raw_headers_error: None,
+            raw_headers_defaults: false,
+            show_url_editor: false,
+            url_editor_original: String::new(),
+            show_snippet_dialog: false,
+            snippet_dialog_selected: 0,
+            show_global_search: false,
+            global_search_query: String::new(),
+            global_search_entries: Vec::new(),
+            global_search_results: Vec::new(),
+            global_search_selected: 0,
+            pending_tree_reveal: None,
+            mode_views: HashMap::new(),
+            pending_tree_view: None,
+            cookie_jar: Arc::new(std::sync::Mutex::new(cookie_jar)),
+            oauth_tokens: OAuth2TokenCache::default(),
+            history_storage,
+            history_entries,
+            pending_history: HashMap::new(),
+            show_history_dialog: false,
+            show_history_palette: false,
+            history_palette_query: String::new(),
+            history_palette_results: Vec::new(),
+            history_palette_selected: 0,
+            history_selected: 0,
+            navigation: NavigationHistory::new(),
+            loaded_template_path: None,
+            loaded_template: None,
+            show_template_info: false,
+            audit_log: AuditLog::new(AuditLog::default_path()),
+            audit_log_entries: None,
+            audit_log_scroll: 0,
+            perf_stats: PerfStats::default(),
+            show_perf_overlay: false,
+            tutorial: None,
+            image_preview: ImagePreview::new(GraphicsProtocol::detect()),
+            masked_last_response: None,
+            response_view: None,
+            response_view_key: None,
+            tiny_screen: false,
+            http_send_tasks: HashMap::new(),
+            http_send_generation: 0,
+            folder_readme: None,
+            folder_readme_scroll: 0,
+            clone_request_dialog: None,
+            note_dialog: None,
+            folder_settings_dialog: None,
+            pending_external_edit: None,
+            backup_dialog: None,
+            last_backup_at: BackupStore::new(BackupStore::default_path()).latest(),
+            show_input_presets_dialog: false,
+            input_presets_selected: 0,
+            input_preset_name_input: None,
+            response_diff: None,
+            response_diff_scroll: 0,
+            collection_run: None,
+            collection_run_generation: 0,
+            show_collection_run: false,
+            collection_run_selected: 0,
+            load_test: None,
+            load_test_generation: 0,
+            load_test_task: None,
+            load_test_settings: LoadTestSettings::default(),
+            show_load_test: false,
+            load_test_field: 0,
+            session_transfer: SessionTransferStats::default(),
+            show_transfer_stats: false,
+            show_run_queue: false,
+            run_queue_selected: 0,
+            config_load_error,
+            safe_mode,
+            health_report: None,
+            health_check_pending: true,
+            show_health_panel: false,
+            health_panel_scroll: 0,
         };
 
         app.log(LogLevel::Info, "Application started");
+        if !safe_mode {
+            let cleanup = ProcessRegistry::default().reap_orphans();
+            if !cleanup.is_empty() {
+                app.log(
+                    LogLevel::Warn,
+                    format!(
+                        "Cleaned up after a previous session: stopped {} orphaned automation process(es), removed {} temp project dir(s)",
+                        cleanup.killed.len(),
+                        cleanup.removed_dirs
+                    ),
+                );
+            }
+        }
+        if safe_mode {
+            app.log(
+                LogLevel::Warn,
+                "Safe mode: default config, scripts disabled, storage is read-only",
+            );
+        }
+        match layout_migration {
+            Ok(Some(summary)) => app.log(LogLevel::Success, summary),
+            Ok(None) => {}
+            Err(e) => app.log(
+                LogLevel::Error,
+                format!("Failed to migrate data to the new directory layout: {}", e),
+            ),
+        }
+        app.prune_history_entries();
+        app.start_tree_load(true);
+
+        // Show what changed since the last version the user has seen
+        let current_version = env!("CARGO_PKG_VERSION");
+        if app.config.last_seen_version.as_deref() != Some(current_version) {
+            let releases =
+                crate::services::changelog::releases_since(app.config.last_seen_version.as_deref());
+            if releases.is_empty() {
+                app.mark_whats_new_seen();
+            } else {
+                app.whats_new_releases = releases;
+                app.show_whats_new = true;
+            }
+        }
+
+        if app.config.check_for_updates {
+            crate::services::update_checker::spawn_update_check(
+                env!("CARGO_PKG_VERSION"),
+                app.message_sender.clone(),
+            );
+        }
         app.log(
             LogLevel::Info,
             format!(
@@ -293,17 +1083,17 @@ impl App {
                 let mut templates_by_folder = HashMap::new();
 
                 for folder in &folders {
-                    if let Ok(templates) = template_storage.list_templates_in_folder(folder) {
-                        if !templates.is_empty() {
-                            templates_by_folder.insert(folder.clone(), templates);
-                        }
+                    if let Ok(templates) = template_storage.list_templates_in_folder(folder)
+                        && !templates.is_empty()
+                    {
+                        templates_by_folder.insert(folder.clone(), templates);
                     }
                 }
 
-                if let Ok(root_templates) = template_storage.list_templates_in_folder("") {
-                    if !root_templates.is_empty() {
-                        templates_by_folder.insert("".to_string(), root_templates);
-                    }
+                if let Ok(root_templates) = template_storage.list_templates_in_folder("")
+                    && !root_templates.is_empty()
+                {
+                    templates_by_folder.insert("".to_string(), root_templates);
                 }
 
                 TreeState::build_from_storage(folders, templates_by_folder)
@@ -316,47 +1106,43 @@ impl App {
                 let mut requests_by_folder = HashMap::new();
 
                 for folder in &folders {
-                    if let Ok(requests) = http_collection_storage.list_requests_in_folder(folder) {
-                        if !requests.is_empty() {
-                            requests_by_folder.insert(folder.clone(), requests);
-                        }
+                    if let Ok(requests) = http_collection_storage.list_requests_in_folder(folder)
+                        && !requests.is_empty()
+                    {
+                        requests_by_folder.insert(folder.clone(), requests);
                     }
                 }
 
-                if let Ok(root_requests) = http_collection_storage.list_requests_in_folder("") {
-                    if !root_requests.is_empty() {
-                        requests_by_folder.insert("".to_string(), root_requests);
-                    }
+                if let Ok(root_requests) = http_collection_storage.list_requests_in_folder("")
+                    && !root_requests.is_empty()
+                {
+                    requests_by_folder.insert("".to_string(), root_requests);
                 }
 
-                TreeState::build_from_storage(folders, requests_by_folder)
-            }
-        }
-    }
-
-    /// Build the initial tree state from template storage
-    fn build_initial_tree_state(template_storage: &TemplateStorage) -> TreeState {
-        // Get all folders and templates
-        let folders = template_storage.list_all_folders().unwrap_or_default();
-        let mut templates_by_folder = HashMap::new();
-
-        // Get templates for each folder
-        for folder in &folders {
-            if let Ok(templates) = template_storage.list_templates_in_folder(folder) {
-                if !templates.is_empty() {
-                    templates_by_folder.insert(folder.clone(), templates);
+                let mut tree = TreeState::build_from_storage(folders.clone(), requests_by_folder);
+                for folder in &folders {
+                    let settings = http_collection_storage.read_folder_settings(folder);
+                    tree.set_folder_style(folder, settings.color, settings.sort_weight);
                 }
+                tree
             }
         }
+    }
 
-        // Also check for templates in the root directory
-        if let Ok(root_templates) = template_storage.list_templates_in_folder("") {
-            if !root_templates.is_empty() {
-                templates_by_folder.insert("".to_string(), root_templates);
-            }
-        }
-
-        TreeState::build_from_storage(folders, templates_by_folder)
+    /// Replace the tree with a loading placeholder and scan storage in the background
+    pub fn start_tree_load(&mut self, initialize: bool) {
+        self.tree_load_generation += 1;
+        self.tree_state = TreeState::loading();
+        // READMEs may have changed on disk too
+        self.folder_readme = None;
+
+        crate::services::spawn_tree_scan(
+            self.config.clone(),
+            self.current_mode.clone(),
+            self.tree_load_generation,
+            initialize,
+            self.message_sender.clone(),
+        );
     }
 
     /// Process any pending messages from background tasks
@@ -374,6 +1160,7 @@ impl App {
             collected_messages
             // receiver lock is dropped here
         };
+        self.perf_stats.record_queue_depth(messages.len());
 
         // Now process the collected messages without holding the lock
         for message in messages {
@@ -388,34 +1175,181 @@ impl App {
                 }
                 AppMessage::AutomationComplete => {
                     self.automation_state.set_running(false);
+                    self.automation_state.record_run_step("✅ Completed");
+                    self.end_run_layout();
+                    self.note_task_finished(AppMode::Automation, "automation completed");
                     self.log(LogLevel::Success, "Automation completed successfully");
+                    self.start_next_queued_run();
                 }
                 AppMessage::AutomationFailed(error) => {
                     self.automation_state.set_running(false);
+                    self.automation_state
+                        .record_run_step(format!("❌ Failed: {}", error));
+                    self.end_run_layout();
+                    self.note_task_finished(AppMode::Automation, "automation failed");
                     self.log(LogLevel::Error, format!("Automation failed: {}", error));
+                    self.start_next_queued_run();
                 }
                 AppMessage::AutomationProgress(progress) => {
+                    self.automation_state.record_run_step(progress.clone());
                     self.log(LogLevel::Info, progress);
                 }
                 AppMessage::Quit => {
                     self.should_quit = true;
                 }
-                AppMessage::HttpResponseReceived(response) => {
-                    self.http_state.last_response = Some(response);
-                    self.http_state.is_sending = false;
+                AppMessage::PaginationProgress {
+                    request_key,
+                    generation,
+                    pages,
+                    items,
+                } => {
+                    if self.is_current_send(&request_key, generation) {
+                        self.http_state
+                            .page_progress
+                            .insert(request_key, (pages, items));
+                    }
+                }
+                AppMessage::HttpResponseReceived {
+                    request_key,
+                    generation,
+                    sent,
+                    response,
+                } => {
+                    if !self.is_current_send(&request_key, generation) {
+                        // Cancelled after the response was already on its way
+                        continue;
+                    }
+                    self.http_send_tasks.remove(&request_key);
+                    let response = *response;
+                    self.note_task_finished(
+                        AppMode::Http,
+                        format!("{} {}", response.status_code, response.status_text),
+                    );
+                    self.record_history(&request_key, Ok(&response));
+                    self.session_transfer.record(
+                        &response.transfer,
+                        response.body.len(),
+                        response.duration_ms,
+                    );
+                    match sent {
+                        Some(sent) => {
+                            self.http_state
+                                .prepared_requests
+                                .insert(request_key.clone(), *sent);
+                        }
+                        None => {
+                            self.http_state.prepared_requests.remove(&request_key);
+                        }
+                    }
+                    self.http_state.finish_send(&request_key, Some(response));
+                    self.tutorial_event(TutorialEvent::ResponseReceived);
                 }
-                AppMessage::HttpRequestFailed(error) => {
-                    // NEW: Add this case
-                    self.http_state.is_sending = false;
+                AppMessage::HttpRequestFailed {
+                    request_key,
+                    generation,
+                    error,
+                } => {
+                    if !self.is_current_send(&request_key, generation) {
+                        continue;
+                    }
+                    self.http_send_tasks.remove(&request_key);
+                    self.note_task_finished(AppMode::Http, "request failed");
+                    self.record_history(&request_key, Err(&error));
+                    self.session_transfer.record_failure();
+                    self.http_state.finish_send(&request_key, None);
                     self.log(LogLevel::Error, error);
                 }
+                AppMessage::VariablesExtracted(variables) => {
+                    apply_variables(&mut self.http_state.environment_vars, &variables);
+                    self.save_environment_variables();
+                }
+                AppMessage::TreeFolderLoaded {
+                    generation,
+                    folder,
+                    items,
+                    color,
+                    sort_weight,
+                } => {
+                    if generation == self.tree_load_generation {
+                        self.tree_state.add_discovered_folder(&folder, &items);
+                        self.tree_state
+                            .set_folder_style(&folder, color, sort_weight);
+                    }
+                }
+                AppMessage::TreeLoadComplete { generation } => {
+                    if generation == self.tree_load_generation {
+                        self.tree_state.is_loading = false;
+                        self.apply_pending_tree_reveal();
+                    }
+                    if self.health_check_pending {
+                        self.health_check_pending = false;
+                        self.start_health_check();
+                    }
+                }
+                AppMessage::HealthChecked(report) => self.finish_health_check(report),
+                AppMessage::UpdateAvailable(info) => {
+                    self.log(
+                        LogLevel::Info,
+                        format!("Version {} is available: {}", info.version, info.url),
+                    );
+                    self.update_notice = Some(info);
+                }
+                AppMessage::CollectionRunResult { generation, result } => {
+                    if result.error.is_some() {
+                        self.session_transfer.record_failure();
+                    } else {
+                        self.session_transfer.record(
+                            &result.transfer,
+                            result.size_bytes,
+                            result.duration_ms,
+                        );
+                    }
+                    if generation == self.collection_run_generation
+                        && let Some(run) = &mut self.collection_run
+                    {
+                        run.results.push(result);
+                    }
+                }
+                AppMessage::LoadTestSample { generation, sample } => {
+                    if generation == self.load_test_generation
+                        && let Some(test) = &mut self.load_test
+                    {
+                        test.samples.push(sample);
+                    }
+                }
+                AppMessage::LoadTestComplete { generation } => {
+                    if generation == self.load_test_generation {
+                        self.finish_load_test(false);
+                    }
+                }
+                AppMessage::CollectionRunComplete { generation } => {
+                    if generation == self.collection_run_generation {
+                        self.finish_collection_run();
+                    }
+                }
+                AppMessage::PostmanImportProgress {
+                    generation,
+                    progress,
+                } => {
+                    if generation == self.import_generation && self.import_progress.is_some() {
+                        self.import_progress = Some(progress);
+                    }
+                }
+                AppMessage::PostmanImportFinished {
+                    generation,
+                    outcome,
+                } => {
+                    if generation == self.import_generation {
+                        self.finish_postman_import(outcome).await?;
+                    }
+                }
             }
         }
 
         Ok(())
     }
 
-    /// Add a log entry
+    // Add a log entry
     // pub fn log(&mut self, level: LogLevel, message: impl Into<String>) {
     //     self.log_entries.push(LogEntry::new(level, message));
     //
@@ -427,12 +1361,50 @@ impl App {
 
     /// Get filtered log entries based on search query
     pub fn get_filtered_logs(&self) -> Vec<&LogEntry> {
+        let cutoff = self
+            .log_time_filter
+            .cutoff(chrono::Local::now(), self.last_log_marker_at);
+
         self.log_entries
             .iter()
-            .filter(|entry| entry.matches_search(&self.log_search_query))
+            .filter(|entry| cutoff.is_none_or(|cutoff| entry.timestamp >= cutoff))
+            .filter(|entry| entry.is_marker || entry.matches_search(&self.log_search_query))
             .collect()
     }
 
+    /// Format a timestamp using the configured local/UTC style
+    pub fn format_timestamp(&self, timestamp: &chrono::DateTime<chrono::Local>) -> String {
+        self.config.timestamp_format.format(timestamp)
+    }
+
+    /// Format a timestamp using the configured style, always including the date
+    pub fn format_timestamp_with_date(
+        &self,
+        timestamp: &chrono::DateTime<chrono::Local>,
+    ) -> String {
+        self.config.timestamp_format.with_date().format(timestamp)
+    }
+
+    /// Whether any search or time filter is narrowing the log panel
+    pub fn is_log_filter_active(&self) -> bool {
+        !self.log_search_query.is_empty() || self.log_time_filter != LogTimeFilter::All
+    }
+
+    /// Insert a marker line so later entries can be filtered from here
+    pub fn insert_log_marker(&mut self) {
+        self.log_marker_count += 1;
+        let marker = LogEntry::marker(self.log_marker_count);
+        self.last_log_marker_at = Some(marker.timestamp);
+        self.log_entries.push(marker);
+        self.log_scroll_position = 0;
+    }
+
+    /// Cycle the log time filter: all → 5m → 15m → 1h → since marker
+    pub fn cycle_log_time_filter(&mut self) {
+        self.log_time_filter = self.log_time_filter.next(self.last_log_marker_at.is_some());
+        self.log_scroll_position = 0;
+    }
+
     /// Scroll up in logs (towards older entries)
     pub fn scroll_logs_up(&mut self) {
         let filtered_logs = self.get_filtered_logs();
@@ -498,6 +1470,11 @@ impl App {
 
     // Update the log method to auto-scroll to bottom when new logs arrive
     pub fn log(&mut self, level: LogLevel, message: impl Into<String>) {
+        let message = message.into();
+        let message = match mask_secrets(&message, &self.secret_values()) {
+            Cow::Borrowed(_) => message,
+            Cow::Owned(masked) => masked,
+        };
         self.log_entries.push(LogEntry::new(level, message));
 
         // Keep log entries under a reasonable limit
@@ -516,81 +1493,472 @@ impl App {
     /// Switch to a different mode (refresh tree when mode changes)
     pub fn switch_mode(&mut self, mode: AppMode) {
         if mode != self.current_mode {
-            self.current_mode = mode.clone();
+            // Tasks keep running in the background; their results stay in the mode state
+            let running = self.running_task_count(&self.current_mode);
+            if running > 0 {
+                self.log(
+                    LogLevel::Info,
+                    format!(
+                        "{} {:?} task(s) still running in the background",
+                        running, self.current_mode
+                    ),
+                );
+            }
 
-            // Refresh tree state for the new mode
-            self.tree_state = Self::build_initial_tree_state_for_mode(
-                &self.template_storage,
-                &self.http_collection_storage,
-                &mode,
-            );
+            let left = self.current_mode_view();
+            self.mode_views.insert(self.current_mode.clone(), left);
+
+            self.current_mode = mode.clone();
+            self.background_notices
+                .retain(|(notice_mode, _)| *notice_mode != mode);
+
+            // Reload the tree for the new mode in the background
+            self.start_tree_load(false);
+
+            // Pick up where this mode was left; the tree part waits for the reload
+            self.pending_tree_reveal = None;
+            self.pending_tree_view = None;
+            if let Some(view) = self.mode_views.get(&mode).cloned() {
+                self.focused_pane = view.pane.clone();
+                self.log_scroll_position = view.log_scroll_position;
+                self.pending_tree_reveal = view.tree_path.clone();
+                self.pending_tree_view = Some(view);
+            }
 
             self.log(LogLevel::Info, format!("Switched to {:?} mode", mode));
         }
     }
 
-    /// Switch focus to a different pane
-    pub fn focus_pane(&mut self, pane: FocusedPane) {
-        self.focused_pane = pane.clone();
-        self.log(LogLevel::Debug, format!("Focused {:?} pane", pane));
+    /// Snapshot of the pane, tree and log position of the current mode
+    fn current_mode_view(&self) -> ModeView {
+        // A tree still loading hasn't had the previous view applied yet
+        if self.tree_state.is_loading
+            && let Some(pending) = &self.pending_tree_view
+        {
+            return ModeView {
+                pane: self.focused_pane.clone(),
+                tree_path: self.pending_tree_reveal.clone(),
+                log_scroll_position: self.log_scroll_position,
+                ..pending.clone()
+            };
+        }
+        ModeView {
+            pane: self.focused_pane.clone(),
+            tree_path: self
+                .tree_state
+                .get_focused_node()
+                .map(|node| node.path.clone()),
+            selected_path: self.tree_state.selected_path.clone(),
+            expanded_folders: self.tree_state.expanded_folders(),
+            log_scroll_position: self.log_scroll_position,
+        }
     }
 
-    /// Show the login popup
-    pub fn show_login(&mut self) {
-        self.show_login_popup = true;
-        self.login_username.clear();
-        self.login_password.clear();
-        self.login_error = None;
-        self.login_focused_field = 0; // Start with username focused
-        self.log(LogLevel::Debug, "Login popup opened");
+    /// Run the config's `startup_actions` in order; a step that fails is logged and skipped
+    pub async fn run_startup_actions(&mut self) {
+        if self.safe_mode {
+            return;
+        }
+        for action in self.config.startup_actions.clone() {
+            match action {
+                StartupAction::SwitchMode(mode) => self.switch_mode(match mode {
+                    StartupMode::Automation => AppMode::Automation,
+                    StartupMode::Http => AppMode::Http,
+                }),
+                StartupAction::ActivateEnvironment(name) => {
+                    if !self.switch_environment(&name) {
+                        self.log(
+                            LogLevel::Warn,
+                            format!("Startup: no environment named '{}'", name),
+                        );
+                    }
+                }
+                StartupAction::Open(path) => {
+                    // Failures are logged by the loader; the reveal waits for the tree load
+                    if self.load_template_into_form(&path).await.is_ok() {
+                        self.pending_tree_reveal = Some(path);
+                        self.apply_pending_tree_reveal();
+                    }
+                }
+                StartupAction::Focus(pane) => self.focus_pane(match pane {
+                    StartupPane::Collections => FocusedPane::Collections,
+                    StartupPane::Form => FocusedPane::Form,
+                    StartupPane::Logs => FocusedPane::Logs,
+                }),
+            }
+        }
     }
 
-    /// Hide the login popup
-    pub fn hide_login(&mut self) {
-        self.show_login_popup = false;
-        self.login_username.clear();
-        self.login_password.clear();
-        self.login_error = None;
-        self.login_focused_field = 0;
-        self.log(LogLevel::Debug, "Login popup closed");
+    /// Read the README of the folder focused in the tree when the focus moves to another folder
+    pub fn refresh_folder_readme(&mut self) {
+        let folder = self
+            .tree_state
+            .get_focused_node()
+            .filter(|node| node.node_type == NodeType::Folder)
+            .map(|node| node.path.clone());
+        let Some(folder) = folder else {
+            self.folder_readme = None;
+            return;
+        };
+        if self
+            .folder_readme
+            .as_ref()
+            .is_some_and(|readme| readme.folder == folder && readme.mode == self.current_mode)
+        {
+            return;
+        }
+
+        let text = match self.current_mode {
+            AppMode::Automation => self.template_storage.read_folder_readme(&folder),
+            AppMode::Http => self.http_collection_storage.read_folder_readme(&folder),
+        };
+        self.folder_readme = Some(FolderReadme {
+            mode: self.current_mode.clone(),
+            folder,
+            text,
+        });
+        self.folder_readme_scroll = 0;
     }
 
-    /// Attempt to login with current form data
-    pub fn attempt_login(&mut self) -> bool {
-        // Clear previous error
-        self.login_error = None;
+    /// Whether the focused folder's README is on screen in place of the form
+    pub fn is_folder_readme_shown(&self) -> bool {
+        self.focused_pane == FocusedPane::Collections
+            && !self.tiny_screen
+            && self
+                .folder_readme
+                .as_ref()
+                .is_some_and(|readme| readme.text.is_some())
+    }
 
-        // Validate credentials format
-        if self.login_username.trim().is_empty() {
-            self.login_error = Some("Username cannot be empty".to_string());
-            return false;
-        }
+    pub fn scroll_folder_readme(&mut self, lines: i32) {
+        self.folder_readme_scroll = self
+            .folder_readme_scroll
+            .saturating_add_signed(lines as i16);
+    }
 
-        if self.login_password.is_empty() {
-            self.login_error = Some("Password cannot be empty".to_string());
-            return false;
+    /// Show or hide the performance overlay
+    pub fn toggle_perf_overlay(&mut self) {
+        self.show_perf_overlay = !self.show_perf_overlay;
+    }
+
+    /// Draw the inline image preview after a frame. Returns true when the screen
+    /// needs a full redraw to wipe the previous image.
+    pub fn sync_image_preview(&mut self, out: &mut impl std::io::Write) -> std::io::Result<bool> {
+        let image = self.http_state.last_response.as_ref().and_then(|response| {
+            let info = image_info(&response.body_bytes)?;
+            Some((info, response.body_bytes.as_slice()))
+        });
+        self.image_preview
+            .sync(out, image.as_ref().map(|(info, bytes)| (info, *bytes)))
+    }
+
+    /// Start the guided tutorial, or stop it when it's already running
+    pub fn toggle_tutorial(&mut self) {
+        if self.tutorial.take().is_some() {
+            self.log(LogLevel::Info, "Tutorial stopped");
+            return;
         }
+        self.tutorial = Some(Tutorial::new());
+        self.hide_help_dialog();
+        self.log(
+            LogLevel::Info,
+            "Tutorial started - follow the steps in the panel (? then Ctrl+T to stop)",
+        );
+    }
 
-        if self.login_username.len() < 3 {
-            self.login_error = Some("Username must be at least 3 characters".to_string());
-            return false;
+    /// Let the running tutorial check whether `event` completes its current step
+    fn tutorial_event(&mut self, event: TutorialEvent) {
+        let Some(tutorial) = self.tutorial.as_mut() else {
+            return;
+        };
+        let Some(step) = tutorial.record(event) else {
+            return;
+        };
+        if tutorial.finished() {
+            self.tutorial = None;
+            self.log(
+                LogLevel::Success,
+                "Tutorial complete - press ? any time to look up more keys",
+            );
+        } else {
+            self.log(
+                LogLevel::Success,
+                format!("Tutorial: '{}' done", step.title()),
+            );
         }
+    }
 
-        if self.login_password.len() < 3 {
-            self.login_error = Some("Password must be at least 3 characters".to_string());
-            return false;
+    /// Approximate size of the buffers that grow with use, largest contributors first
+    pub fn buffer_usage(&self) -> Vec<BufferUsage> {
+        fn count_nodes(nodes: &[TreeNode]) -> usize {
+            nodes
+                .iter()
+                .map(|node| 1 + count_nodes(&node.children))
+                .sum()
         }
 
-        // Store credentials in auth service
-        match self
-            .auth_service
+        let responses = &self.http_state.responses;
+        let tree_nodes = count_nodes(&self.tree_state.roots);
+        vec![
+            BufferUsage {
+                label: "Logs",
+                items: self.log_entries.len(),
+                bytes: self
+                    .log_entries
+                    .iter()
+                    .map(|entry| std::mem::size_of::<LogEntry>() + entry.message.len())
+                    .sum(),
+            },
+            BufferUsage {
+                label: "Responses",
+                items: responses.len(),
+                bytes: responses
+                    .values()
+                    .map(|response| {
+                        response.body.len()
+                            + response
+                                .headers
+                                .iter()
+                                .map(|header| header.name.len() + header.value.len())
+                                .sum::<usize>()
+                    })
+                    .sum(),
+            },
+            BufferUsage {
+                label: "History",
+                items: self.history_entries.len(),
+                bytes: self
+                    .history_entries
+                    .iter()
+                    .map(|entry| {
+                        std::mem::size_of_val(entry)
+                            + entry.response_body.as_ref().map_or(0, String::len)
+                    })
+                    .sum(),
+            },
+            BufferUsage {
+                label: "Tree",
+                items: tree_nodes,
+                bytes: tree_nodes * std::mem::size_of::<TreeNode>(),
+            },
+        ]
+    }
+
+    /// Note switching into or out of the single-pane layout after a resize
+    pub fn set_tiny_screen(&mut self, tiny: bool, width: u16, height: u16) {
+        if tiny == self.tiny_screen {
+            return;
+        }
+        self.tiny_screen = tiny;
+        if tiny {
+            self.log(
+                LogLevel::Info,
+                format!(
+                    "Terminal is {}x{}: showing one pane at a time (F5/F6/F7 or J/K to switch)",
+                    width, height
+                ),
+            );
+        } else {
+            self.log(
+                LogLevel::Info,
+                "Terminal is large enough for all panes again",
+            );
+        }
+    }
+
+    /// Switch focus to a different pane
+    pub fn focus_pane(&mut self, pane: FocusedPane) {
+        self.focused_pane = pane.clone();
+        self.log(LogLevel::Debug, format!("Focused {:?} pane", pane));
+    }
+
+    /// Snapshot of the current location for back/forward navigation
+    pub fn current_location(&self) -> NavLocation {
+        let item_path = match self.current_mode {
+            AppMode::Automation => self.loaded_template_path.clone(),
+            // Unsaved requests have no path to re-open
+            AppMode::Http => Some(self.http_state.current_request_key.clone())
+                .filter(|key| !key.starts_with("draft-")),
+        };
+        NavLocation {
+            mode: self.current_mode.clone(),
+            pane: self.focused_pane.clone(),
+            tree_path: self
+                .tree_state
+                .get_focused_node()
+                .map(|node| node.path.clone()),
+            item_path,
+        }
+    }
+
+    /// Record `before` if handling a key moved us somewhere else
+    pub fn record_navigation(&mut self, before: NavLocation) {
+        if !before.is_same_place(&self.current_location()) {
+            self.navigation.record(before);
+        }
+    }
+
+    /// Jump back to the previous location (like a browser's back button)
+    pub async fn navigate_back(&mut self) {
+        match self.navigation.back(self.current_location()) {
+            Some(target) => self.restore_location(target).await,
+            None => self.log(LogLevel::Info, "Nothing to go back to"),
+        }
+    }
+
+    /// Undo a `navigate_back`
+    pub async fn navigate_forward(&mut self) {
+        match self.navigation.forward(self.current_location()) {
+            Some(target) => self.restore_location(target).await,
+            None => self.log(LogLevel::Info, "Nothing to go forward to"),
+        }
+    }
+
+    async fn restore_location(&mut self, target: NavLocation) {
+        self.switch_mode(target.mode.clone());
+
+        let current_item = self.current_location().item_path;
+        if let Some(path) = &target.item_path
+            && current_item.as_ref() != Some(path)
+        {
+            // Failures are logged by the loader
+            let _ = self.load_template_into_form(path).await;
+        }
+
+        if let Some(path) = target.tree_path {
+            self.pending_tree_reveal = Some(path);
+            self.apply_pending_tree_reveal();
+        }
+        self.focus_pane(target.pane);
+    }
+
+    /// Switch HTTP mode between stacked and side-by-side editor/response layouts
+    pub fn toggle_http_split_layout(&mut self) {
+        self.config.http_split_layout = !self.config.http_split_layout;
+        if let Err(e) = self.config.save() {
+            self.log(LogLevel::Error, format!("Failed to save layout: {}", e));
+        }
+        self.log(
+            LogLevel::Info,
+            if self.config.http_split_layout {
+                "Split-screen layout: request and response side by side"
+            } else {
+                "Stacked layout: response below the request"
+            },
+        );
+    }
+
+    /// Toggle whether automation runs switch to the timeline/output layout
+    pub fn toggle_automation_run_layout(&mut self) {
+        self.config.automation_run_layout = !self.config.automation_run_layout;
+        if let Err(e) = self.config.save() {
+            self.log(LogLevel::Error, format!("Failed to save layout: {}", e));
+        }
+        self.log(
+            LogLevel::Info,
+            if self.config.automation_run_layout {
+                "Runs will show the step timeline beside live output"
+            } else {
+                "Runs will keep the form and logs layout"
+            },
+        );
+    }
+
+    /// Whether the run layout (timeline | live output) is currently shown
+    pub fn is_run_layout_active(&self) -> bool {
+        self.run_layout_previous_pane.is_some()
+    }
+
+    /// Switch to the run layout, remembering the focus to restore afterwards
+    fn begin_run_layout(&mut self) {
+        if !self.config.automation_run_layout || self.is_run_layout_active() {
+            return;
+        }
+        self.run_layout_previous_pane = Some(self.focused_pane.clone());
+        self.focused_pane = FocusedPane::Logs;
+        self.log(LogLevel::Debug, "Switched to run layout");
+    }
+
+    /// Restore the layout that was active before the run started
+    fn end_run_layout(&mut self) {
+        if let Some(pane) = self.run_layout_previous_pane.take() {
+            self.focused_pane = pane;
+            self.log(LogLevel::Debug, "Restored layout after run");
+        }
+    }
+
+    /// Jump between the request editor and the response viewer
+    pub fn toggle_request_response_focus(&mut self) {
+        let pane = if self.focused_pane == FocusedPane::Form {
+            FocusedPane::Logs
+        } else {
+            FocusedPane::Form
+        };
+        self.focus_pane(pane);
+    }
+
+    /// Show the login popup
+    pub fn show_login(&mut self) {
+        self.show_login_popup = true;
+        self.login_username.clear();
+        self.login_password.clear();
+        self.login_error = None;
+        self.login_focused_field = 0; // Start with username focused
+        self.log(LogLevel::Debug, "Login popup opened");
+    }
+
+    /// Hide the login popup
+    pub fn hide_login(&mut self) {
+        self.show_login_popup = false;
+        self.login_username.clear();
+        self.login_password.clear();
+        self.login_error = None;
+        self.login_focused_field = 0;
+        self.log(LogLevel::Debug, "Login popup closed");
+    }
+
+    /// Attempt to login with current form data
+    pub fn attempt_login(&mut self) -> bool {
+        // Clear previous error
+        self.login_error = None;
+
+        // Validate credentials format
+        if self.login_username.trim().is_empty() {
+            self.login_error = Some("Username cannot be empty".to_string());
+            return false;
+        }
+
+        if self.login_password.is_empty() {
+            self.login_error = Some("Password cannot be empty".to_string());
+            return false;
+        }
+
+        if self.login_username.len() < 3 {
+            self.login_error = Some("Username must be at least 3 characters".to_string());
+            return false;
+        }
+
+        if self.login_password.len() < 3 {
+            self.login_error = Some("Password must be at least 3 characters".to_string());
+            return false;
+        }
+
+        // Store credentials in auth service
+        match self
+            .auth_service
             .store_credentials(self.login_username.clone(), self.login_password.clone())
         {
             Ok(()) => {
+                self.audit(
+                    AuditAction::CredentialsStored,
+                    Vec::new(),
+                    format!("logged in as {}", self.login_username),
+                );
                 self.log(
                     LogLevel::Success,
                     format!("Logged in as: {}", self.login_username),
                 );
+                self.session_locked = false;
                 self.hide_login();
                 true
             }
@@ -601,7 +1969,7 @@ impl App {
         }
     }
 
-    /// Attempt to login with current form data
+    // Attempt to login with current form data
     // pub fn attempt_login(&mut self) -> bool {
     //     // Validate credentials format
     //     match crate::services::AuthService::validate_credentials(
@@ -639,11 +2007,6 @@ impl App {
     pub async fn start_automation(&mut self) -> Result<()> {
         self.log(LogLevel::Debug, "start_automation() called");
 
-        if self.automation_state.is_running {
-            self.log(LogLevel::Warn, "Automation is already running");
-            return Ok(());
-        }
-
         // Check if fields are valid
         self.log(LogLevel::Debug, "Checking if fields are valid...");
         if !self.automation_state.is_valid() {
@@ -655,27 +2018,51 @@ impl App {
         }
         self.log(LogLevel::Debug, "Fields validation passed");
 
+        // Another run is in progress: offer to run this one after it
+        if self.automation_state.is_running {
+            let label = self.automation_run_label();
+            let waiting = self.automation_state.run_queue.len();
+            self.confirmation = Some(
+                Confirmation::new("Automation Running", label, ConfirmAction::QueueAutomation)
+                    .with_labels("Queue", "Cancel")
+                    .with_details(vec![
+                        "Queued runs start one after another as each run finishes.".to_string(),
+                        format!(
+                            "{} run(s) already waiting - Ctrl+R shows the queue.",
+                            waiting
+                        ),
+                    ]),
+            );
+            return Ok(());
+        }
+
         // Check if we have credentials
         self.log(LogLevel::Debug, "Checking credentials...");
-        if !self.auth_service.has_credentials() {
+        let fields = self.automation_state.fields.clone();
+        let website_config = self.automation_state.website_config.clone();
+        if !self.launch_automation(fields, website_config) {
             self.log(
                 LogLevel::Error,
                 "Cannot start automation: no credentials provided",
             );
             self.show_login();
-            return Ok(());
         }
-        self.log(LogLevel::Debug, "Credentials check passed");
+        Ok(())
+    }
 
-        // Get credentials from auth service
-        let credentials = self.auth_service.get_credentials().unwrap();
+    /// Spawn a browser run of `fields`; returns false when there are no credentials
+    fn launch_automation(&mut self, fields: Vec<FormField>, website_config: WebsiteConfig) -> bool {
+        let Some(credentials) = self.auth_service.get_credentials() else {
+            return false;
+        };
+        self.log(LogLevel::Debug, "Credentials check passed");
 
         self.automation_state.set_running(true);
+        self.automation_state.start_run_timeline();
+        self.begin_run_layout();
         self.log(LogLevel::Info, "🚀 Starting browser automation...");
 
         // Clone the data we need for the background task
-        let fields = self.automation_state.fields.clone();
-        let website_config = self.automation_state.website_config.clone();
         let sender = self.message_sender.clone();
 
         self.log(LogLevel::Debug, "Spawning browser automation task...");
@@ -708,105 +2095,546 @@ impl App {
         });
 
         self.log(LogLevel::Debug, "Browser automation task spawned");
-        Ok(())
+        true
     }
 
-    /// Request app shutdown
-    pub fn quit(&mut self) {
-        self.should_quit = true;
-        self.log(LogLevel::Info, "Application shutting down");
+    /// Name a queued run after its template, or the first value typed into the form
+    fn automation_run_label(&self) -> String {
+        if let Some(path) = &self.loaded_template_path {
+            return path.rsplit('/').next().unwrap_or(path).to_string();
+        }
+        self.automation_state
+            .fields
+            .iter()
+            .find(|field| !field.value.is_empty())
+            .map(|field| field.value.clone())
+            .unwrap_or_else(|| "Unsaved form".to_string())
     }
 
-    /// Get a clone of the message sender for background tasks
-    pub fn get_message_sender(&self) -> mpsc::UnboundedSender<AppMessage> {
-        self.message_sender.clone()
+    /// Put the current form at the back of the run queue, starting it right away if the
+    /// run it was waiting for finished while the confirmation was open
+    fn queue_automation(&mut self) {
+        let label = self.automation_run_label();
+        let position = self.automation_state.queue_run(&label);
+        self.log(
+            LogLevel::Info,
+            format!("⏸ Queued run '{}' (#{} in line)", label, position),
+        );
+        self.start_next_queued_run();
     }
 
-    // TODO: remove this later - old method before implementation of collections tree
-    /// Refresh tree state from template storage
-    // pub async fn refresh_tree_from_storage(&mut self) -> Result<()> {
-    //     use std::collections::HashMap;
-    //
-    //     // Get all folders and templates from storage
-    //     let folders = self.template_storage.list_all_folders().unwrap_or_default();
-    //     let mut templates_by_folder = HashMap::new();
-    //
-    //     // Get templates for each folder
-    //     for folder in &folders {
-    //         if let Ok(templates) = self.template_storage.list_templates_in_folder(folder) {
-    //             if !templates.is_empty() {
-    //                 templates_by_folder.insert(folder.clone(), templates);
-    //             }
-    //         }
-    //     }
-    //
-    //     // Also check for templates in the root directory
-    //     if let Ok(root_templates) = self.template_storage.list_templates_in_folder("") {
-    //         if !root_templates.is_empty() {
-    //             templates_by_folder.insert("".to_string(), root_templates);
-    //         }
-    //     }
-    //
-    //     // Rebuild tree state
-    //     self.tree_state =
-    //         crate::models::TreeState::build_from_storage(folders, templates_by_folder);
-    //
-    //     self.log(LogLevel::Debug, "Tree state refreshed from storage");
-    //     Ok(())
-    // }
-    //
-    // UPDATE refresh_tree_from_storage to be mode-aware
-    /// Refresh tree state from storage based on current mode
-    pub async fn refresh_tree_from_storage(&mut self) -> Result<()> {
-        self.tree_state = Self::build_initial_tree_state_for_mode(
-            &self.template_storage,
-            &self.http_collection_storage,
-            &self.current_mode,
+    /// Start the next queued run, if nothing is running
+    pub fn start_next_queued_run(&mut self) {
+        if self.automation_state.is_running || self.automation_state.run_queue.is_empty() {
+            return;
+        }
+        if !self.auth_service.has_credentials() {
+            self.log(
+                LogLevel::Warn,
+                "Queued runs are waiting for credentials (log in, then Enter in the run queue)",
+            );
+            return;
+        }
+        let Some(run) = self.automation_state.take_next_queued_run() else {
+            return;
+        };
+        self.log(
+            LogLevel::Info,
+            format!(
+                "▶ Starting queued run '{}' ({} still waiting)",
+                run.label,
+                self.automation_state.run_queue.len()
+            ),
         );
+        self.launch_automation(run.fields, run.website_config);
+    }
 
-        let mode_name = match self.current_mode {
-            AppMode::Automation => "automation templates",
-            AppMode::Http => "HTTP collections",
+    pub fn show_run_queue(&mut self) {
+        self.show_run_queue = true;
+        self.run_queue_selected = 0;
+        self.log(LogLevel::Debug, "Run queue opened");
+    }
+
+    pub fn hide_run_queue(&mut self) {
+        self.show_run_queue = false;
+        self.log(LogLevel::Debug, "Run queue closed");
+    }
+
+    /// Move the selection in the run queue
+    pub fn run_queue_move_selection(&mut self, down: bool) {
+        let count = self.automation_state.run_queue.len();
+        if count == 0 {
+            return;
+        }
+        self.run_queue_selected = if down {
+            (self.run_queue_selected + 1).min(count - 1)
+        } else {
+            self.run_queue_selected.saturating_sub(1)
         };
+    }
+
+    /// Move the selected queued run one place earlier or later
+    pub fn reorder_queued_run(&mut self, later: bool) {
+        self.run_queue_selected = self
+            .automation_state
+            .move_queued_run(self.run_queue_selected, later);
+    }
 
+    /// Drop the selected run from the queue
+    pub fn cancel_queued_run(&mut self) {
+        let Some(run) = self
+            .automation_state
+            .cancel_queued_run(self.run_queue_selected)
+        else {
+            return;
+        };
+        self.run_queue_selected = self
+            .run_queue_selected
+            .min(self.automation_state.run_queue.len().saturating_sub(1));
         self.log(
-            LogLevel::Debug,
-            format!("Tree state refreshed from {} storage", mode_name),
+            LogLevel::Info,
+            format!("Cancelled queued run '{}'", run.label),
         );
-        Ok(())
     }
 
-    /// Load an HTTP request from storage into the HTTP form
-    pub async fn load_http_request_into_form(&mut self, request_path: &str) -> Result<()> {
-        let (folder_path, request_name) = if let Some(pos) = request_path.rfind('/') {
-            (&request_path[..pos], &request_path[pos + 1..])
-        } else {
-            ("", request_path)
-        };
+    /// Request app shutdown
+    pub fn quit(&mut self) {
+        self.should_quit = true;
+        self.log(LogLevel::Info, "Application shutting down");
 
-        match self
-            .http_collection_storage
-            .load_request(folder_path, request_name)
-        {
-            Ok(stored_request) => {
-                self.http_state.load_request(stored_request.request);
-                self.log(
-                    LogLevel::Success,
-                    format!("Loaded HTTP request: {}", request_name),
-                );
-                Ok(())
+        self.save_request_tabs();
+
+        // Keep unsaved work around so an accidental quit can be undone
+        if self.pending_draft.is_none() {
+            let content = self.current_draft_content();
+            let result = if content == self.draft_baseline {
+                self.draft_store.discard()
+            } else {
+                self.draft_store.save(&content)
+            };
+            if let Err(e) = result {
+                self.log(LogLevel::Error, format!("Failed to update draft: {}", e));
             }
-            Err(e) => {
-                self.log(
-                    LogLevel::Error,
-                    format!("Failed to load HTTP request: {}", e),
-                );
-                Err(e)
+        }
+    }
+
+    /// Write the cookie jar to disk when persisting is on and it changed
+    pub fn save_cookie_jar(&mut self) {
+        if !self.config.persist_cookies {
+            return;
+        }
+        let result = {
+            let mut jar = self.cookie_jar.lock().unwrap();
+            if !jar.changed {
+                return;
             }
+            jar.save(&cookie_jar_path())
+        };
+        if let Err(e) = result {
+            self.log(LogLevel::Error, format!("Failed to save cookies: {:#}", e));
         }
     }
 
-    /// Save current HTTP request as collection item
+    fn http_session(&self) -> HttpSession {
+        HttpSession {
+            scripts_disabled: self.safe_mode,
+            cookie_jar: self.cookie_jar.clone(),
+            oauth_tokens: self.oauth_tokens.clone(),
+            request_log: RequestLog::new(
+                RequestLog::default_path(),
+                self.config.request_log_secrets,
+            ),
+            tls: self.config.tls.clone(),
+            proxy: self.config.active_proxy(),
+            bypass_proxy: false,
+            response_display_limit: self.config.response_display_limit.max_bytes(),
+            retry: self.config.retry,
+            default_headers: self.config.default_headers.clone(),
+        }
+    }
+
+    /// OAuth 2.0 settings of the current request, with `{{variables}}` filled in
+    fn current_oauth2_config(&mut self) -> Option<OAuth2Config> {
+        let request = &self.http_state.current_request;
+        OAuth2Config::from_auth(&request.auth)?;
+        match prepare_request(request, &self.http_state.environment_vars) {
+            Ok(prepared) => OAuth2Config::from_auth(&prepared.request.auth).map(|mut config| {
+                config.proxy = request.settings.proxy.or(&self.config.active_proxy());
+                config
+            }),
+            Err(e) => {
+                self.log(LogLevel::Error, format!("{:#}", e));
+                None
+            }
+        }
+    }
+
+    /// Drop the cached token and fetch a new one in the background
+    pub fn fetch_oauth2_token(&mut self) {
+        let Some(config) = self.current_oauth2_config() else {
+            return;
+        };
+        self.oauth_tokens
+            .lock()
+            .unwrap()
+            .remove(&config.cache_key());
+        self.log(LogLevel::Info, "🔑 Fetching OAuth 2.0 access token...");
+
+        let cache = self.oauth_tokens.clone();
+        let sender = self.message_sender.clone();
+        tokio::spawn(async move {
+            let notify_sender = sender.clone();
+            let result = oauth2_access_token(&config, &cache, |message| {
+                let _ =
+                    notify_sender.send(AppMessage::Log(LogLevel::Info, format!("🔑 {}", message)));
+            })
+            .await;
+            if let Err(e) = result {
+                let _ = sender.send(AppMessage::Log(
+                    LogLevel::Error,
+                    format!("OAuth 2.0 authorization failed: {:#}", e),
+                ));
+            }
+        });
+    }
+
+    /// Forget the current request's cached token so the next send fetches a new one
+    pub fn forget_oauth2_token(&mut self) {
+        let Some(config) = self.current_oauth2_config() else {
+            return;
+        };
+        if self
+            .oauth_tokens
+            .lock()
+            .unwrap()
+            .remove(&config.cache_key())
+            .is_some()
+        {
+            self.log(LogLevel::Info, "🔑 Forgot the cached OAuth 2.0 token");
+        }
+    }
+
+    /// Cached token of the current request, for the Auth tab
+    pub fn current_oauth2_token(&self) -> Option<OAuth2Token> {
+        let request = &self.http_state.current_request;
+        OAuth2Config::from_auth(&request.auth)?;
+        let prepared = prepare_request(request, &self.http_state.environment_vars).ok()?;
+        let config = OAuth2Config::from_auth(&prepared.request.auth)?;
+        self.oauth_tokens
+            .lock()
+            .unwrap()
+            .get(&config.cache_key())
+            .cloned()
+    }
+
+    /// Snapshot of the in-progress HTTP request and automation form
+    fn current_draft_content(&self) -> DraftContent {
+        DraftContent::capture(
+            &self.http_state.current_request_key,
+            &self.http_state.current_request,
+            &self.automation_state.fields,
+        )
+    }
+
+    /// Persist unsaved work to the drafts area if it changed since the last autosave
+    pub fn autosave_draft(&mut self) {
+        if self.pending_draft.is_some() || self.last_autosave_at.elapsed() < AUTOSAVE_INTERVAL {
+            return;
+        }
+        self.last_autosave_at = Instant::now();
+
+        let content = self.current_draft_content();
+        if self.last_autosaved_draft.as_ref() == Some(&content) {
+            return;
+        }
+
+        let result = if content == self.draft_baseline {
+            self.draft_store.discard()
+        } else {
+            self.draft_store.save(&content)
+        };
+        match result {
+            Ok(()) => self.log(LogLevel::Debug, "Draft autosaved"),
+            Err(e) => self.log(LogLevel::Error, format!("Failed to autosave draft: {}", e)),
+        }
+        self.last_autosaved_draft = Some(content);
+    }
+
+    /// Save the open request tabs if their set, order, labels or the active one changed
+    pub fn persist_request_tabs(&mut self) {
+        let signature = request_tabs_signature(&self.http_state);
+        if signature == self.saved_tabs_signature {
+            return;
+        }
+        self.saved_tabs_signature = signature;
+        self.save_request_tabs();
+    }
+
+    fn save_request_tabs(&mut self) {
+        let session = RequestSession {
+            tabs: self.http_state.session_tabs(),
+            active: self.http_state.active_tab,
+        };
+        if let Err(e) = self.session_store.save(&session) {
+            self.log(
+                LogLevel::Error,
+                format!("Failed to save open tabs: {:#}", e),
+            );
+        }
+    }
+
+    /// Show the next or previous request tab
+    pub fn cycle_request_tab(&mut self, forward: bool) {
+        if self.http_state.open_tabs.len() < 2 {
+            return;
+        }
+        self.http_state.cycle_tab(forward);
+        self.after_request_tab_change();
+    }
+
+    /// Move the active request tab one place left or right
+    pub fn move_request_tab(&mut self, right: bool) {
+        self.http_state.move_active_tab(right);
+    }
+
+    pub fn start_tab_rename(&mut self) {
+        let index = self.http_state.active_tab;
+        self.tab_rename_input = Some(self.http_state.tab_label(index).to_string());
+    }
+
+    /// Apply (or drop) the label typed for the active tab
+    pub fn finish_tab_rename(&mut self, commit: bool) {
+        let Some(title) = self.tab_rename_input.take() else {
+            return;
+        };
+        if !commit {
+            return;
+        }
+        // A label equal to the request name isn't a label of its own
+        let title = if title.trim() == self.http_state.current_request.name {
+            ""
+        } else {
+            title.as_str()
+        };
+        self.http_state.rename_active_tab(title);
+    }
+
+    /// Close the active request tab; its edits go with it
+    pub fn close_request_tab(&mut self) {
+        let closed = self.http_state.close_active_tab();
+        self.after_request_tab_change();
+        self.log(
+            LogLevel::Info,
+            format!(
+                "Closed tab '{}'",
+                closed.title.as_deref().unwrap_or(&closed.request.name)
+            ),
+        );
+    }
+
+    /// A different request is in the editor: it starts clean and brings its folder's environment
+    fn after_request_tab_change(&mut self) {
+        let key = self.http_state.current_request_key.clone();
+        if key.starts_with("draft-") {
+            self.folder_environment = None;
+            self.folder_inheritance = FolderInheritance::default();
+        } else {
+            let folder = key.rsplit_once('/').map_or("", |(folder, _)| folder);
+            self.apply_folder_environment(&key, folder);
+        }
+        self.mark_draft_clean();
+    }
+
+    /// Note user input, pushing back the idle auto-lock
+    pub fn record_activity(&mut self) {
+        self.last_activity_at = Instant::now();
+    }
+
+    /// Lock the session once it has been idle longer than the configured limit;
+    /// a running automation counts as activity
+    pub fn check_idle_lock(&mut self) {
+        let Some(timeout) = self.config.auto_lock.timeout() else {
+            return;
+        };
+        if self.session_locked
+            || self.automation_state.is_running
+            || self.last_activity_at.elapsed() < timeout
+        {
+            return;
+        }
+        let has_tokens = !self.oauth_tokens.lock().unwrap().is_empty();
+        if !self.auth_service.has_credentials() && !has_tokens && !self.show_login_popup {
+            return;
+        }
+        self.lock_session();
+    }
+
+    /// Forget login credentials and cached OAuth 2.0 tokens; automations need a
+    /// fresh login afterwards
+    pub fn lock_session(&mut self) {
+        if let Err(e) = self.auth_service.clear_credentials() {
+            self.log(
+                LogLevel::Error,
+                format!("Failed to clear credentials: {}", e),
+            );
+        }
+        self.oauth_tokens.lock().unwrap().clear();
+        if self.show_login_popup {
+            self.hide_login();
+        }
+        self.session_locked = true;
+        self.audit(
+            AuditAction::CredentialsCleared,
+            Vec::new(),
+            "session locked after idle",
+        );
+        self.log(
+            LogLevel::Warn,
+            format!(
+                "🔒 Session locked after {} minute(s) idle; credentials cleared, log in again to run automations",
+                self.config.auto_lock.idle_minutes
+            ),
+        );
+    }
+
+    /// Treat the current state as saved: nothing to recover until it changes again
+    fn mark_draft_clean(&mut self) {
+        self.draft_baseline = self.current_draft_content();
+        self.last_autosaved_draft = Some(self.draft_baseline.clone());
+        if let Err(e) = self.draft_store.discard() {
+            self.log(LogLevel::Error, format!("Failed to discard draft: {}", e));
+        }
+    }
+
+    /// Bring back the draft left by the previous session
+    pub fn restore_pending_draft(&mut self) {
+        let Some(draft) = self.pending_draft.take() else {
+            return;
+        };
+
+        self.http_state.load_request(
+            &draft.content.http_request_key,
+            draft.content.http_request.clone(),
+        );
+        draft
+            .content
+            .apply_to_fields(&mut self.automation_state.fields);
+        self.log(
+            LogLevel::Success,
+            format!(
+                "Restored draft from {}",
+                self.format_timestamp_with_date(&draft.saved_at)
+            ),
+        );
+    }
+
+    /// Throw away the draft left by the previous session
+    pub fn discard_pending_draft(&mut self) {
+        if self.pending_draft.take().is_none() {
+            return;
+        }
+        match self.draft_store.discard() {
+            Ok(()) => self.log(LogLevel::Info, "Discarded draft from previous session"),
+            Err(e) => self.log(LogLevel::Error, format!("Failed to discard draft: {}", e)),
+        }
+    }
+
+    /// Get a clone of the message sender for background tasks
+    pub fn get_message_sender(&self) -> mpsc::UnboundedSender<AppMessage> {
+        self.message_sender.clone()
+    }
+
+    // TODO: remove this later - old method before implementation of collections tree
+    /// Refresh tree state from template storage
+    // pub async fn refresh_tree_from_storage(&mut self) -> Result<()> {
+    //     use std::collections::HashMap;
+    //
+    //     // Get all folders and templates from storage
+    //     let folders = self.template_storage.list_all_folders().unwrap_or_default();
+    //     let mut templates_by_folder = HashMap::new();
+    //
+    //     // Get templates for each folder
+    //     for folder in &folders {
+    //         if let Ok(templates) = self.template_storage.list_templates_in_folder(folder) {
+    //             if !templates.is_empty() {
+    //                 templates_by_folder.insert(folder.clone(), templates);
+    //             }
+    //         }
+    //     }
+    //
+    //     // Also check for templates in the root directory
+    //     if let Ok(root_templates) = self.template_storage.list_templates_in_folder("") {
+    //         if !root_templates.is_empty() {
+    //             templates_by_folder.insert("".to_string(), root_templates);
+    //         }
+    //     }
+    //
+    //     // Rebuild tree state
+    //     self.tree_state =
+    //         crate::models::TreeState::build_from_storage(folders, templates_by_folder);
+    //
+    //     self.log(LogLevel::Debug, "Tree state refreshed from storage");
+    //     Ok(())
+    // }
+    //
+    // UPDATE refresh_tree_from_storage to be mode-aware
+    /// Refresh tree state from storage based on current mode
+    pub async fn refresh_tree_from_storage(&mut self) -> Result<()> {
+        // Any scan still streaming in would be stale now
+        self.tree_load_generation += 1;
+        self.tree_state = Self::build_initial_tree_state_for_mode(
+            &self.template_storage,
+            &self.http_collection_storage,
+            &self.current_mode,
+        );
+
+        let mode_name = match self.current_mode {
+            AppMode::Automation => "automation templates",
+            AppMode::Http => "HTTP collections",
+        };
+
+        self.log(
+            LogLevel::Debug,
+            format!("Tree state refreshed from {} storage", mode_name),
+        );
+        Ok(())
+    }
+
+    /// Load an HTTP request from storage into the HTTP form
+    pub async fn load_http_request_into_form(&mut self, request_path: &str) -> Result<()> {
+        let (folder_path, request_name) = if let Some(pos) = request_path.rfind('/') {
+            (&request_path[..pos], &request_path[pos + 1..])
+        } else {
+            ("", request_path)
+        };
+
+        match self
+            .http_collection_storage
+            .load_request(folder_path, request_name)
+        {
+            Ok(stored_request) => {
+                self.http_state
+                    .load_request(request_path, stored_request.request);
+                self.apply_folder_environment(request_path, folder_path);
+                self.mark_draft_clean();
+                self.remember_recent_item(RecentItemKind::Request, request_path);
+                self.log(
+                    LogLevel::Success,
+                    format!("Loaded HTTP request: {}", request_name),
+                );
+                Ok(())
+            }
+            Err(e) => {
+                self.log(
+                    LogLevel::Error,
+                    format!("Failed to load HTTP request: {}", e),
+                );
+                Err(e)
+            }
+        }
+    }
+
+    /// Save current HTTP request as collection item
     pub async fn save_http_request_to_collection(
         &mut self,
         folder_path: &str,
@@ -819,6 +2647,7 @@ impl App {
             .save_request(folder_path, request_name, request)
         {
             Ok(_) => {
+                self.mark_draft_clean();
                 self.log(
                     LogLevel::Success,
                     format!("Saved HTTP request: {}", request_name),
@@ -846,6 +2675,11 @@ impl App {
 
         self.http_collection_storage
             .delete_request(folder_path, request_name)?;
+        self.audit(
+            AuditAction::Delete,
+            vec![request_path.to_string()],
+            "HTTP request",
+        );
         self.log(
             LogLevel::Success,
             format!("Deleted HTTP request: {}", request_name),
@@ -854,24 +2688,48 @@ impl App {
         Ok(())
     }
 
-    // 10. ADD NEW METHOD - Import Postman collection
-    pub async fn import_postman_collection(&mut self, file_path: &std::path::Path) -> Result<()> {
-        match self
-            .http_collection_storage
-            .import_postman_collection(file_path)
-        {
+    pub async fn import_openapi_spec(&mut self, file_path: &std::path::Path) -> Result<()> {
+        match self.http_collection_storage.import_openapi(file_path) {
             Ok(()) => {
                 self.log(
                     LogLevel::Success,
-                    format!("Imported Postman collection: {}", file_path.display()),
+                    format!("Imported OpenAPI spec: {}", file_path.display()),
+                );
+                self.refresh_tree_from_storage().await?;
+                self.tutorial_event(TutorialEvent::CollectionImported);
+                Ok(())
+            }
+            Err(e) => {
+                self.log(
+                    LogLevel::Error,
+                    format!("Failed to import OpenAPI spec: {:#}", e),
+                );
+                Err(e)
+            }
+        }
+    }
+
+    /// Import an Insomnia export or a Bruno collection folder
+    pub async fn import_converted_collection(&mut self, path: &std::path::Path) -> Result<()> {
+        let format = self.import_dialog_format;
+        let imported = match format {
+            ImportFormat::Bruno => self.http_collection_storage.import_bruno(path),
+            _ => self.http_collection_storage.import_insomnia(path),
+        };
+        match imported {
+            Ok(_) => {
+                self.log(
+                    LogLevel::Success,
+                    format!("Imported {}: {}", format.title(), path.display()),
                 );
                 self.refresh_tree_from_storage().await?;
+                self.tutorial_event(TutorialEvent::CollectionImported);
                 Ok(())
             }
             Err(e) => {
                 self.log(
                     LogLevel::Error,
-                    format!("Failed to import Postman collection: {}", e),
+                    format!("Failed to import {}: {:#}", format.title(), e),
                 );
                 Err(e)
             }
@@ -898,10 +2756,16 @@ impl App {
                         stored_template
                             .template
                             .apply_to_fields(&mut self.automation_state.fields);
+                        self.loaded_template_path = Some(template_path.to_string());
+                        self.mark_draft_clean();
+                        self.remember_recent_item(RecentItemKind::Template, template_path);
                         self.log(
                             LogLevel::Success,
                             format!("Loaded template: {}", template_name),
                         );
+                        // Documented templates explain their fields up front
+                        self.show_template_info = !stored_template.template.field_docs.is_empty();
+                        self.loaded_template = Some(stored_template.template);
                         Ok(())
                     }
                     Err(e) => {
@@ -916,48 +2780,223 @@ impl App {
             }
         }
     }
-    /// Create a new template from current form state
-    pub async fn create_template_from_form(
-        &mut self,
-        folder_path: &str,
-        template_name: &str,
-    ) -> Result<()> {
-        use crate::models::AutomationTemplate;
+    pub fn show_input_presets_dialog(&mut self) {
+        self.show_input_presets_dialog = true;
+        self.input_presets_selected = self
+            .input_presets_selected
+            .min(self.config.input_presets.len().saturating_sub(1));
+        self.log(LogLevel::Debug, "Input presets opened");
+    }
 
-        // Create template from current form values
-        let mut template = AutomationTemplate::new(template_name, "Template created from form");
+    pub fn hide_input_presets_dialog(&mut self) {
+        self.show_input_presets_dialog = false;
+        self.input_preset_name_input = None;
+        self.log(LogLevel::Debug, "Input presets closed");
+    }
 
-        // Add current field values to template
-        for field in &self.automation_state.fields {
-            if !field.value.is_empty() {
-                template = template.with_field(&field.name, &field.value);
-            }
+    pub fn input_presets_next(&mut self) {
+        let count = self.config.input_presets.len();
+        if count > 0 {
+            self.input_presets_selected = (self.input_presets_selected + 1) % count;
         }
+    }
 
-        // Save to storage
-        match self
-            .template_storage
-            .save_template(folder_path, template_name, template)
-        {
-            Ok(_) => {
-                self.log(
-                    LogLevel::Success,
-                    format!("Created template: {}", template_name),
-                );
-                // Refresh tree to show new template
-                self.refresh_tree_from_storage().await?;
-                Ok(())
-            }
-            Err(e) => {
-                self.log(LogLevel::Error, format!("Failed to save template: {}", e));
-                Err(e)
-            }
+    pub fn input_presets_previous(&mut self) {
+        let count = self.config.input_presets.len();
+        if count > 0 {
+            self.input_presets_selected = (self.input_presets_selected + count - 1) % count;
         }
     }
 
-    /// Delete a template from storage
-    pub async fn delete_template(&mut self, template_path: &str) -> Result<()> {
-        let (folder_path, template_name) = if let Some(pos) = template_path.rfind('/') {
+    /// Fill the form with the selected preset's values, leaving other fields as they are
+    pub fn apply_selected_input_preset(&mut self) {
+        let Some(preset) = self
+            .config
+            .input_presets
+            .get(self.input_presets_selected)
+            .cloned()
+        else {
+            return;
+        };
+        let applied = preset.apply_to_fields(&mut self.automation_state.fields);
+        self.hide_input_presets_dialog();
+        self.log(
+            LogLevel::Success,
+            format!(
+                "Applied preset '{}' ({} of {} values matched this form)",
+                preset.name,
+                applied,
+                preset.field_values.len()
+            ),
+        );
+    }
+
+    /// Start typing a name for a preset captured from the current form
+    pub fn start_input_preset_save(&mut self) {
+        self.input_preset_name_input = Some(String::new());
+    }
+
+    /// Save the current values as a preset: only what differs from the loaded template
+    pub fn finish_input_preset_save(&mut self, save: bool) {
+        let Some(name) = self.input_preset_name_input.take() else {
+            return;
+        };
+        let name = name.trim().to_string();
+        if !save || name.is_empty() {
+            return;
+        }
+
+        let base = self.loaded_template_path.as_deref().and_then(|path| {
+            let (folder, template_name) = path.rsplit_once('/').unwrap_or(("", path));
+            self.template_storage
+                .load_template(folder, template_name)
+                .ok()
+                .map(|stored| stored.template)
+        });
+        let preset = InputPreset::from_fields(&name, &self.automation_state.fields, base.as_ref());
+        if preset.field_values.is_empty() {
+            self.log(
+                LogLevel::Warn,
+                "Nothing to save: the form matches the loaded template",
+            );
+            return;
+        }
+
+        let count = preset.field_values.len();
+        let presets = &mut self.config.input_presets;
+        match presets.iter().position(|existing| existing.name == name) {
+            Some(index) => {
+                presets[index] = preset;
+                self.input_presets_selected = index;
+            }
+            None => {
+                presets.push(preset);
+                self.input_presets_selected = presets.len() - 1;
+            }
+        }
+        if let Err(e) = self.config.save() {
+            self.log(LogLevel::Error, format!("Failed to save presets: {}", e));
+            return;
+        }
+        self.log(
+            LogLevel::Success,
+            format!("Saved preset '{}' with {} value(s)", name, count),
+        );
+    }
+
+    /// Ask before deleting the selected preset
+    pub fn request_delete_input_preset(&mut self) {
+        let Some(preset) = self.config.input_presets.get(self.input_presets_selected) else {
+            return;
+        };
+        let name = preset.name.clone();
+        self.confirmation = Some(
+            Confirmation::new(
+                "Delete Input Preset",
+                &name,
+                ConfirmAction::DeleteInputPreset { name: name.clone() },
+            )
+            .with_danger(DangerLevel::Warn)
+            .with_labels("Delete", "Keep")
+            .with_details(vec![format!(
+                "Removes the {} saved value(s); templates are not affected.",
+                preset.field_values.len()
+            )]),
+        );
+    }
+
+    fn delete_input_preset(&mut self, name: &str) {
+        self.config
+            .input_presets
+            .retain(|preset| preset.name != name);
+        self.input_presets_selected = self
+            .input_presets_selected
+            .min(self.config.input_presets.len().saturating_sub(1));
+        if let Err(e) = self.config.save() {
+            self.log(LogLevel::Error, format!("Failed to save presets: {}", e));
+            return;
+        }
+        self.audit(AuditAction::Delete, vec![name.to_string()], "input preset");
+        self.log(LogLevel::Info, format!("Deleted preset '{}'", name));
+    }
+
+    /// Create a new template from current form state
+    pub async fn create_template_from_form(
+        &mut self,
+        folder_path: &str,
+        template_name: &str,
+    ) -> Result<()> {
+        use crate::models::AutomationTemplate;
+
+        // Create template from current form values, keeping the loaded template's field docs
+        let mut template = AutomationTemplate::new(template_name, "Template created from form");
+        template.field_docs = self.loaded_field_docs();
+
+        // Add current field values to template
+        for field in &self.automation_state.fields {
+            if !field.value.is_empty() {
+                template = template.with_field(&field.name, &field.value);
+            }
+        }
+
+        // Save to storage
+        match self
+            .template_storage
+            .save_template(folder_path, template_name, template)
+        {
+            Ok(_) => {
+                self.mark_draft_clean();
+                self.log(
+                    LogLevel::Success,
+                    format!("Created template: {}", template_name),
+                );
+                // Refresh tree to show new template
+                self.refresh_tree_from_storage().await?;
+                Ok(())
+            }
+            Err(e) => {
+                self.log(LogLevel::Error, format!("Failed to save template: {}", e));
+                Err(e)
+            }
+        }
+    }
+
+    /// Field docs of the template loaded into the form, if any
+    fn loaded_field_docs(&self) -> BTreeMap<String, FieldDoc> {
+        self.loaded_template
+            .as_ref()
+            .map(|template| template.field_docs.clone())
+            .unwrap_or_default()
+    }
+
+    /// Help line for an automation field, from the loaded template's docs
+    pub fn field_hint(&self, field_name: &str) -> Option<String> {
+        self.loaded_template
+            .as_ref()?
+            .field_docs
+            .get(field_name)
+            .map(FieldDoc::hint)
+            .filter(|hint| !hint.is_empty())
+    }
+
+    /// Show the loaded template's description and field docs
+    pub fn show_template_info(&mut self) {
+        if self.loaded_template.is_none() {
+            self.log(LogLevel::Warn, "Load a template to see its field docs");
+            return;
+        }
+        self.show_template_info = true;
+        self.log(LogLevel::Debug, "Template info opened");
+    }
+
+    pub fn hide_template_info(&mut self) {
+        self.show_template_info = false;
+        self.log(LogLevel::Debug, "Template info closed");
+    }
+
+    /// Delete a template from storage
+    pub async fn delete_template(&mut self, template_path: &str) -> Result<()> {
+        let (folder_path, template_name) = if let Some(pos) = template_path.rfind('/') {
             (&template_path[..pos], &template_path[pos + 1..])
         } else {
             ("", template_path)
@@ -965,6 +3004,11 @@ impl App {
 
         self.template_storage
             .delete_template(folder_path, template_name)?;
+        self.audit(
+            AuditAction::Delete,
+            vec![template_path.to_string()],
+            "template",
+        );
         self.log(
             LogLevel::Success,
             format!("Deleted template: {}", template_name),
@@ -973,8 +3017,128 @@ impl App {
         Ok(())
     }
 
+    /// Export the focused template, the website config and the script it runs
+    /// with as a checksummed bundle in the exports directory
+    pub fn export_template_bundle(&mut self) {
+        if self.refuse_in_safe_mode("Exporting") {
+            return;
+        }
+        let Some(template_path) = self
+            .tree_state
+            .get_focused_node()
+            .filter(|node| node.node_type == NodeType::Template)
+            .map(|node| node.path.clone())
+        else {
+            self.log(LogLevel::Warn, "Focus a template to export it as a bundle");
+            return;
+        };
+        let (folder_path, template_name) = match template_path.rfind('/') {
+            Some(pos) => (&template_path[..pos], &template_path[pos + 1..]),
+            None => ("", template_path.as_str()),
+        };
+
+        let script = BrowserEngine::main_script_name().unwrap_or_default();
+        let export_dir = crate::models::config::data_directory().join("exports");
+        let path = export_dir.join(format!(
+            "{}.{}",
+            sanitize_filename(template_name),
+            BUNDLE_EXTENSION
+        ));
+        let result = self
+            .template_storage
+            .read_template(folder_path, template_name)
+            .and_then(|stored| {
+                export_bundle(
+                    &stored.template,
+                    &self.automation_state.website_config,
+                    script,
+                )
+            })
+            .and_then(|content| {
+                std::fs::create_dir_all(&export_dir)?;
+                std::fs::write(&path, content)?;
+                Ok(())
+            });
+
+        match result {
+            Ok(()) => self.log(
+                LogLevel::Success,
+                format!("Exported template bundle to {}", path.display()),
+            ),
+            Err(e) => self.log(
+                LogLevel::Error,
+                format!("Failed to export template bundle: {:#}", e),
+            ),
+        }
+    }
+
+    /// Verify a template bundle and add its template next to the focused item;
+    /// the bundled website config replaces the current one
+    pub async fn import_template_bundle(&mut self, file_path: &Path) -> Result<()> {
+        let content = std::fs::read_to_string(file_path)
+            .with_context(|| format!("Failed to read {}", file_path.display()))?;
+        let bundle = import_bundle(&content)?;
+
+        let folder = match self.tree_state.get_focused_node() {
+            Some(node) if node.node_type == NodeType::Folder => node.path.clone(),
+            Some(node) => node
+                .path
+                .rfind('/')
+                .map(|pos| node.path[..pos].to_string())
+                .unwrap_or_default(),
+            None => String::new(),
+        };
+
+        // Never overwrite a template the team already has
+        let mut template = bundle.template;
+        let base_name = template.name.clone();
+        let mut counter = 1;
+        while self
+            .template_storage
+            .read_template(&folder, &template.name)
+            .is_ok()
+        {
+            template.name = format!("{}_imported_{}", base_name, counter);
+            counter += 1;
+        }
+        let name = template.name.clone();
+        self.template_storage
+            .save_template(&folder, &name, template)?;
+
+        if bundle.website != self.automation_state.website_config {
+            self.log(
+                LogLevel::Info,
+                format!(
+                    "Using website config '{}' from the bundle",
+                    bundle.website.name
+                ),
+            );
+            self.automation_state.website_config = bundle.website;
+        }
+        let script = BrowserEngine::main_script_name().unwrap_or_default();
+        if bundle.script != script {
+            self.log(
+                LogLevel::Warn,
+                format!(
+                    "Bundle was made for script '{}', this build runs '{}'",
+                    bundle.script, script
+                ),
+            );
+        }
+
+        self.log(
+            LogLevel::Success,
+            format!("Imported template '{}' (checksum verified)", name),
+        );
+        self.refresh_tree_from_storage().await?;
+        Ok(())
+    }
+
     /// Show the template creation dialog
     pub fn show_template_creation_dialog(&mut self) {
+        if self.refuse_in_safe_mode("Saving templates") {
+            return;
+        }
         let focused_folder = if let Some(focused_node) = self.tree_state.get_focused_node() {
             match focused_node.node_type {
                 NodeType::Folder => focused_node.path.clone(),
@@ -1001,7 +3165,13 @@ impl App {
             AppMode::Http => {
                 let method = self.http_state.current_request.method.as_str();
                 self.template_dialog_name = format!("New {} Request", method);
-                self.template_dialog_description = "HTTP request saved from editor".to_string();
+                // The description doubles as the Docs tab: keep notes already written
+                let description = &self.http_state.current_request.description;
+                self.template_dialog_description = if description.trim().is_empty() {
+                    "HTTP request saved from editor".to_string()
+                } else {
+                    description.clone()
+                };
             }
         }
 
@@ -1031,6 +3201,57 @@ impl App {
 
     /// Create_template_from_dialog to be mode-aware
     pub async fn create_template_from_dialog(&mut self) -> Result<()> {
+        self.save_from_template_dialog(false).await
+    }
+
+    /// Ask before replacing a saved item whose fields differ from what's about to be saved
+    fn confirm_overwrite(&mut self, folder: &str, name: &str, changes: Vec<FieldChange>) {
+        let item_type = match self.current_mode {
+            AppMode::Automation => "Template",
+            AppMode::Http => "Request",
+        };
+        let path = if folder.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}/{}", folder, name)
+        };
+        let count = |kind: fn(&FieldChange) -> bool| changes.iter().filter(|c| kind(c)).count();
+        let added = count(|change| matches!(change, FieldChange::Added { .. }));
+        let removed = count(|change| matches!(change, FieldChange::Removed { .. }));
+        let modified = count(|change| matches!(change, FieldChange::Modified { .. }));
+
+        let mut details = vec![
+            format!(
+                "A saved {} with this name already exists.",
+                item_type.to_lowercase()
+            ),
+            format!(
+                "Saving replaces it: {} added, {} removed, {} modified",
+                added, removed, modified
+            ),
+        ];
+        if removed > 0 {
+            details.push("Removed values are lost - cancel to merge them first.".to_string());
+        }
+        let confirmation = Confirmation::new(
+            format!("Overwrite {}", item_type),
+            path,
+            ConfirmAction::OverwriteFromDialog,
+        )
+        .with_danger(if removed > 0 {
+            DangerLevel::Warn
+        } else {
+            DangerLevel::Info
+        })
+        .with_details(details)
+        .with_items(changes.iter().map(FieldChange::describe).collect())
+        .with_items_title("Changes")
+        .with_labels("Overwrite", "Cancel");
+        self.confirmation = Some(confirmation);
+    }
+
+    /// Save the dialog's item; an existing one is only replaced once its changes are confirmed
+    async fn save_from_template_dialog(&mut self, overwrite_confirmed: bool) -> Result<()> {
         if self.template_dialog_name.trim().is_empty() {
             self.log(LogLevel::Error, "Name cannot be empty");
             return Ok(());
@@ -1052,12 +3273,42 @@ impl App {
                     }
                 }
 
+                // The form has no say over field docs: keep the ones already written
+                let existing = self
+                    .template_storage
+                    .read_template(&self.template_dialog_folder, &self.template_dialog_name)
+                    .ok();
+                template.field_docs = match &existing {
+                    Some(existing) => existing.template.field_docs.clone(),
+                    None => self.loaded_field_docs(),
+                };
+
+                if !overwrite_confirmed && let Some(existing) = existing {
+                    let changes = diff_fields(
+                        &template_fields(&existing.template),
+                        &template_fields(&template),
+                    );
+                    if !changes.is_empty() {
+                        let (folder, name) = (
+                            self.template_dialog_folder.clone(),
+                            self.template_dialog_name.clone(),
+                        );
+                        self.confirm_overwrite(&folder, &name, changes);
+                        return Ok(());
+                    }
+                }
+
                 match self.template_storage.save_template(
                     &self.template_dialog_folder,
                     &self.template_dialog_name,
                     template,
                 ) {
                     Ok(_) => {
+                        if overwrite_confirmed {
+                            let path = self.template_dialog_item_path();
+                            self.audit(AuditAction::Overwrite, vec![path], "template");
+                        }
+                        self.mark_draft_clean();
                         self.log(
                             LogLevel::Success,
                             format!(
@@ -1073,6 +3324,7 @@ impl App {
 
                         self.refresh_tree_from_storage().await?;
                         self.hide_template_creation_dialog();
+                        self.tutorial_event(TutorialEvent::TemplateSaved);
                         Ok(())
                     }
                     Err(e) => {
@@ -1088,12 +3340,36 @@ impl App {
                 updated_request.name = self.template_dialog_name.clone();
                 updated_request.description = self.template_dialog_description.clone();
 
+                if !overwrite_confirmed
+                    && let Ok(existing) = self
+                        .http_collection_storage
+                        .read_request(&self.template_dialog_folder, &self.template_dialog_name)
+                {
+                    let changes = diff_fields(
+                        &request_fields(&existing.request),
+                        &request_fields(&updated_request),
+                    );
+                    if !changes.is_empty() {
+                        let (folder, name) = (
+                            self.template_dialog_folder.clone(),
+                            self.template_dialog_name.clone(),
+                        );
+                        self.confirm_overwrite(&folder, &name, changes);
+                        return Ok(());
+                    }
+                }
+
                 match self.http_collection_storage.save_request(
                     &self.template_dialog_folder,
                     &self.template_dialog_name,
                     updated_request,
                 ) {
                     Ok(_) => {
+                        if overwrite_confirmed {
+                            let path = self.template_dialog_item_path();
+                            self.audit(AuditAction::Overwrite, vec![path], "HTTP request");
+                        }
+                        self.mark_draft_clean();
                         self.log(
                             LogLevel::Success,
                             format!(
@@ -1109,6 +3385,7 @@ impl App {
 
                         self.refresh_tree_from_storage().await?;
                         self.hide_template_creation_dialog();
+                        self.tutorial_event(TutorialEvent::TemplateSaved);
                         Ok(())
                     }
                     Err(e) => {
@@ -1123,7 +3400,22 @@ impl App {
         }
     }
 
+    /// `folder/name` of the item the template/request dialog saves
+    fn template_dialog_item_path(&self) -> String {
+        if self.template_dialog_folder.is_empty() {
+            self.template_dialog_name.clone()
+        } else {
+            format!(
+                "{}/{}",
+                self.template_dialog_folder, self.template_dialog_name
+            )
+        }
+    }
+
     pub fn show_folder_creation_dialog(&mut self) {
+        if self.refuse_in_safe_mode("Creating folders") {
+            return;
+        }
         // Pre-populate with smart defaults based on focused node
         let parent_folder = if let Some(focused_node) = self.tree_state.get_focused_node() {
             match focused_node.node_type {
@@ -1207,6 +3499,7 @@ impl App {
                 // Refresh tree and hide dialog
                 self.refresh_tree_from_storage().await?;
                 self.hide_folder_creation_dialog();
+                self.tutorial_event(TutorialEvent::FolderCreated);
                 Ok(())
             }
             Err(e) => {
@@ -1255,6 +3548,9 @@ impl App {
 
     /// Show the rename dialog for the currently focused node
     pub fn show_rename_dialog(&mut self) {
+        if self.refuse_in_safe_mode("Renaming") {
+            return;
+        }
         if let Some(focused_node) = self.tree_state.get_focused_node() {
             self.show_rename_dialog = true;
             self.rename_dialog_original_name = focused_node.name.clone();
@@ -1278,6 +3574,77 @@ impl App {
         }
     }
 
+    /// Save a copy of the focused request or template next to it as "<name> copy"
+    /// and open the rename dialog on the copy
+    pub async fn duplicate_focused_item(&mut self) -> Result<()> {
+        if self.refuse_in_safe_mode("Duplicating") {
+            return Ok(());
+        }
+        let Some(node) = self.tree_state.get_focused_node() else {
+            return Ok(());
+        };
+        if node.node_type == NodeType::Folder {
+            self.log(
+                LogLevel::Warn,
+                "Folders can't be duplicated; copy and paste them with Ctrl+C / Ctrl+V",
+            );
+            return Ok(());
+        }
+        let (folder, file_name) = match node.path.rfind('/') {
+            Some(pos) => (
+                node.path[..pos].to_string(),
+                node.path[pos + 1..].to_string(),
+            ),
+            None => (String::new(), node.path.clone()),
+        };
+        let name = node.name.clone();
+
+        let copy_name = match self.current_mode {
+            AppMode::Automation => {
+                let storage = &self.template_storage;
+                let mut template = storage.read_template(&folder, &file_name)?.template;
+                let copy_name = duplicate_name(&name, |candidate| {
+                    storage.read_template(&folder, candidate).is_ok()
+                });
+                template.name = copy_name.clone();
+                storage.save_template(&folder, &copy_name, template)?;
+                copy_name
+            }
+            AppMode::Http => {
+                let storage = &self.http_collection_storage;
+                let mut request = storage.read_request(&folder, &file_name)?.request;
+                let copy_name = duplicate_name(&name, |candidate| {
+                    storage.read_request(&folder, candidate).is_ok()
+                });
+                request.name = copy_name.clone();
+                storage.save_request(&folder, &copy_name, request)?;
+                copy_name
+            }
+        };
+
+        let path = if folder.is_empty() {
+            sanitize_filename(&copy_name)
+        } else {
+            format!("{}/{}", folder, sanitize_filename(&copy_name))
+        };
+        self.log(
+            LogLevel::Success,
+            format!("Duplicated '{}' as '{}'", name, copy_name),
+        );
+        self.pending_tree_reveal = Some(path.clone());
+        self.refresh_tree_from_storage().await?;
+        self.apply_pending_tree_reveal();
+
+        // Straight to naming the copy, whether or not the tree could reveal it yet
+        self.show_rename_dialog = true;
+        self.rename_dialog_original_name = copy_name.clone();
+        self.rename_dialog_new_name = copy_name;
+        self.rename_dialog_path = path;
+        self.rename_dialog_is_folder = false;
+        self.rename_dialog_error = None;
+        Ok(())
+    }
+
     /// Hide the rename dialog
     pub fn hide_rename_dialog(&mut self) {
         self.show_rename_dialog = false;
@@ -1311,7 +3678,7 @@ impl App {
 
     /// Rename a folder
     async fn rename_folder(&mut self) -> Result<()> {
-        let templates_dir = self.config.get_templates_directory();
+        let templates_dir = self.tree_storage_directory();
         let old_path = templates_dir.join(&self.rename_dialog_path);
 
         // Calculate new path
@@ -1325,6 +3692,11 @@ impl App {
 
         match std::fs::rename(&old_path, &new_full_path) {
             Ok(()) => {
+                self.audit(
+                    AuditAction::Rename,
+                    vec![self.rename_dialog_path.clone(), new_path.clone()],
+                    "folder",
+                );
                 self.log(
                     LogLevel::Success,
                     format!(
@@ -1332,6 +3704,7 @@ impl App {
                         self.rename_dialog_original_name, self.rename_dialog_new_name
                     ),
                 );
+                self.carry_notes(&self.rename_dialog_path.clone(), &new_path);
 
                 // Refresh tree and hide dialog
                 self.refresh_tree_from_storage().await?;
@@ -1349,6 +3722,9 @@ impl App {
 
     /// Rename a template
     async fn rename_template(&mut self) -> Result<()> {
+        if self.current_mode == AppMode::Http {
+            return self.rename_request().await;
+        }
         // Parse the template path
         let (folder_path, _old_name) = if let Some(pos) = self.rename_dialog_path.rfind('/') {
             (
@@ -1361,36 +3737,50 @@ impl App {
 
         let templates_dir = self.config.get_templates_directory();
         let folder_dir = templates_dir.join(folder_path);
+        let new_path = match folder_path {
+            "" => sanitize_filename(&self.rename_dialog_new_name),
+            folder => format!(
+                "{}/{}",
+                folder,
+                sanitize_filename(&self.rename_dialog_new_name)
+            ),
+        };
 
-        // Build old and new file paths
-        let old_filename = format!(
-            "{}.json",
-            sanitize_filename(&self.rename_dialog_original_name)
+        // Build old and new file paths (the renamed file keeps its format)
+        let old_file_path = item_file_path(
+            &folder_dir,
+            &sanitize_filename(&self.rename_dialog_original_name),
+            self.config.storage_format,
+        );
+        let extension = old_file_path
+            .extension()
+            .and_then(|s| s.to_str())
+            .unwrap_or("json");
+        let new_filename = format!(
+            "{}.{}",
+            sanitize_filename(&self.rename_dialog_new_name),
+            extension
         );
-        let new_filename = format!("{}.json", sanitize_filename(&self.rename_dialog_new_name));
-        let old_file_path = folder_dir.join(old_filename);
         let new_file_path = folder_dir.join(new_filename);
 
         // Load the template to update its internal name
-        match std::fs::read_to_string(&old_file_path) {
-            Ok(json_content) => {
-                let mut stored_template: crate::services::StoredTemplate =
-                    serde_json::from_str(&json_content)
-                        .map_err(|e| anyhow::anyhow!("Failed to parse template: {}", e))?;
-
+        match read_item_file::<crate::services::StoredTemplate>(&old_file_path) {
+            Ok(mut stored_template) => {
                 // Update the template name
                 stored_template.template.name = self.rename_dialog_new_name.clone();
                 stored_template.modified_at = chrono::Utc::now();
 
                 // Save with new name and delete old file
-                let updated_json = serde_json::to_string_pretty(&stored_template)
-                    .map_err(|e| anyhow::anyhow!("Failed to serialize template: {}", e))?;
-
-                std::fs::write(&new_file_path, updated_json)
-                    .map_err(|e| anyhow::anyhow!("Failed to write renamed template: {}", e))?;
+                write_item_file(&new_file_path, &stored_template)
+                    .map_err(|e| anyhow::anyhow!("Failed to write renamed template: {:#}", e))?;
 
                 std::fs::remove_file(&old_file_path)
                     .map_err(|e| anyhow::anyhow!("Failed to remove old template file: {}", e))?;
+                self.audit(
+                    AuditAction::Rename,
+                    vec![self.rename_dialog_path.clone(), new_path.clone()],
+                    "item",
+                );
 
                 self.log(
                     LogLevel::Success,
@@ -1399,6 +3789,7 @@ impl App {
                         self.rename_dialog_original_name, self.rename_dialog_new_name
                     ),
                 );
+                self.carry_notes(&self.rename_dialog_path.clone(), &new_path);
 
                 // Refresh tree and hide dialog
                 self.refresh_tree_from_storage().await?;
@@ -1406,7 +3797,7 @@ impl App {
                 Ok(())
             }
             Err(e) => {
-                let error_msg = format!("Failed to read template file: {}", e);
+                let error_msg = format!("Failed to read template file: {:#}", e);
                 self.rename_dialog_error = Some(error_msg.clone());
                 self.log(LogLevel::Error, error_msg);
                 Ok(())
@@ -1414,6 +3805,60 @@ impl App {
         }
     }
 
+    /// Rename a saved HTTP request, keeping its file format and metadata
+    async fn rename_request(&mut self) -> Result<()> {
+        let (folder, _) = self
+            .rename_dialog_path
+            .rsplit_once('/')
+            .unwrap_or(("", self.rename_dialog_path.as_str()));
+        let folder = folder.to_string();
+        let (old_name, new_name) = (
+            self.rename_dialog_original_name.clone(),
+            self.rename_dialog_new_name.clone(),
+        );
+        let storage = &self.http_collection_storage;
+        let renamed = storage.read_request(&folder, &old_name).and_then(|stored| {
+            let mut request = stored.request;
+            request.name = new_name.clone();
+            storage.save_request(&folder, &new_name, request)?;
+            storage.delete_request(&folder, &old_name)
+        });
+        if let Err(e) = renamed {
+            let error_msg = format!("Failed to rename request: {:#}", e);
+            self.rename_dialog_error = Some(error_msg.clone());
+            self.log(LogLevel::Error, error_msg);
+            return Ok(());
+        }
+
+        let new_path = match folder.as_str() {
+            "" => sanitize_filename(&new_name),
+            folder => format!("{}/{}", folder, sanitize_filename(&new_name)),
+        };
+        self.audit(
+            AuditAction::Rename,
+            vec![self.rename_dialog_path.clone(), new_path.clone()],
+            "HTTP request",
+        );
+        self.log(
+            LogLevel::Success,
+            format!("Renamed request '{}' to '{}'", old_name, new_name),
+        );
+        self.carry_notes(&self.rename_dialog_path.clone(), &new_path);
+        self.pending_tree_reveal = Some(new_path);
+        self.refresh_tree_from_storage().await?;
+        self.apply_pending_tree_reveal();
+        self.hide_rename_dialog();
+        Ok(())
+    }
+
+    /// Directory the tree of the current mode is stored in
+    fn tree_storage_directory(&self) -> PathBuf {
+        match self.current_mode {
+            AppMode::Automation => self.config.get_templates_directory().clone(),
+            AppMode::Http => self.http_collection_storage.get_collections_directory(),
+        }
+    }
+
     /// Validate rename operation
     fn validate_rename(
         &self,
@@ -1438,7 +3883,7 @@ impl App {
         }
 
         // Check if item with new name already exists in the same location
-        let templates_dir = self.config.get_templates_directory();
+        let templates_dir = self.tree_storage_directory();
 
         if is_folder {
             // For folders, check if folder with new name exists
@@ -1467,10 +3912,7 @@ impl App {
             };
 
             let folder_dir = templates_dir.join(folder_path);
-            let new_filename = format!("{}.json", sanitize_filename(new_name));
-            let check_path = folder_dir.join(new_filename);
-
-            if check_path.exists() {
+            if find_item_file(&folder_dir, &sanitize_filename(new_name)).is_some() {
                 return Err("Template with this name already exists".to_string());
             }
         }
@@ -1485,14 +3927,17 @@ impl App {
             let full_path = if focused_node.node_type == NodeType::Folder {
                 templates_dir.join(&focused_node.path)
             } else {
-                // For templates, we need the .json file path
+                // For templates, we need the path of the stored file
                 let (folder_path, template_name) = if let Some(pos) = focused_node.path.rfind('/') {
                     (&focused_node.path[..pos], &focused_node.path[pos + 1..])
                 } else {
                     ("", focused_node.path.as_str())
                 };
-                let filename = format!("{}.json", sanitize_filename(template_name));
-                templates_dir.join(folder_path).join(filename)
+                item_file_path(
+                    &templates_dir.join(folder_path),
+                    &sanitize_filename(template_name),
+                    self.config.storage_format,
+                )
             };
 
             self.clipboard = Some(ClipboardItem {
@@ -1530,8 +3975,11 @@ impl App {
                 } else {
                     ("", focused_node.path.as_str())
                 };
-                let filename = format!("{}.json", sanitize_filename(template_name));
-                templates_dir.join(folder_path).join(filename)
+                item_file_path(
+                    &templates_dir.join(folder_path),
+                    &sanitize_filename(template_name),
+                    self.config.storage_format,
+                )
             };
 
             self.clipboard = Some(ClipboardItem {
@@ -1559,6 +4007,9 @@ impl App {
 
     /// Paste clipboard item to currently focused folder
     pub async fn paste_clipboard_item(&mut self) -> Result<()> {
+        if self.refuse_in_safe_mode("Pasting") {
+            return Ok(());
+        }
         if let Some(clipboard_item) = &self.clipboard.clone() {
             // Determine target folder
             let target_folder = if let Some(focused_node) = self.tree_state.get_focused_node() {
@@ -1626,6 +4077,7 @@ impl App {
                 // Move the folder
                 std::fs::rename(&item.full_file_path, &new_full_path)
                     .map_err(|e| anyhow::anyhow!("Failed to move folder: {}", e))?;
+                self.carry_notes(&item.path, &new_path);
 
                 self.log(
                     LogLevel::Success,
@@ -1641,8 +4093,12 @@ impl App {
                 );
             }
             NodeType::Template => {
-                // Calculate new template path
-                let filename = format!("{}.json", sanitize_filename(&item.name));
+                // Calculate new template path (the file keeps its format)
+                let filename = item
+                    .full_file_path
+                    .file_name()
+                    .map(|name| name.to_os_string())
+                    .unwrap_or_else(|| format!("{}.json", sanitize_filename(&item.name)).into());
                 let target_dir = templates_dir.join(target_folder);
                 let new_file_path = target_dir.join(&filename);
 
@@ -1650,8 +4106,8 @@ impl App {
                 std::fs::create_dir_all(&target_dir)
                     .map_err(|e| anyhow::anyhow!("Failed to create target directory: {}", e))?;
 
-                // Check if target file already exists
-                if new_file_path.exists() {
+                // Check if target file already exists (in any format)
+                if find_item_file(&target_dir, &sanitize_filename(&item.name)).is_some() {
                     self.log(
                         LogLevel::Error,
                         format!("Template '{}' already exists in target location", item.name),
@@ -1662,6 +4118,12 @@ impl App {
                 // Move the template file
                 std::fs::rename(&item.full_file_path, &new_file_path)
                     .map_err(|e| anyhow::anyhow!("Failed to move template: {}", e))?;
+                let name = item.path.rsplit('/').next().unwrap_or(&item.path);
+                let new_path = match target_folder {
+                    "" => name.to_string(),
+                    folder => format!("{}/{}", folder, name),
+                };
+                self.carry_notes(&item.path, &new_path);
 
                 self.log(
                     LogLevel::Success,
@@ -1727,24 +4189,24 @@ impl App {
                     .map_err(|e| anyhow::anyhow!("Failed to create target directory: {}", e))?;
 
                 // Find unique filename
-                while target_dir
-                    .join(format!("{}.json", sanitize_filename(&copy_name)))
-                    .exists()
-                {
+                while find_item_file(&target_dir, &sanitize_filename(&copy_name)).is_some() {
                     copy_name = format!("{}_{}", base_name, counter);
                     counter += 1;
                 }
 
-                let filename = format!("{}.json", sanitize_filename(&copy_name));
+                // The copy keeps the format of the original
+                let extension = item
+                    .full_file_path
+                    .extension()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("json");
+                let filename = format!("{}.{}", sanitize_filename(&copy_name), extension);
                 let new_file_path = target_dir.join(&filename);
 
                 // Load and modify the template
-                let json_content = std::fs::read_to_string(&item.full_file_path)
-                    .map_err(|e| anyhow::anyhow!("Failed to read template: {}", e))?;
-
                 let mut stored_template: crate::services::StoredTemplate =
-                    serde_json::from_str(&json_content)
-                        .map_err(|e| anyhow::anyhow!("Failed to parse template: {}", e))?;
+                    read_item_file(&item.full_file_path)
+                        .map_err(|e| anyhow::anyhow!("Failed to read template: {:#}", e))?;
 
                 // Update name and timestamps
                 stored_template.template.name = copy_name.clone();
@@ -1753,11 +4215,8 @@ impl App {
                 stored_template.last_used_at = None;
 
                 // Save the copy
-                let updated_json = serde_json::to_string_pretty(&stored_template)
-                    .map_err(|e| anyhow::anyhow!("Failed to serialize template: {}", e))?;
-
-                std::fs::write(&new_file_path, updated_json)
-                    .map_err(|e| anyhow::anyhow!("Failed to write template copy: {}", e))?;
+                write_item_file(&new_file_path, &stored_template)
+                    .map_err(|e| anyhow::anyhow!("Failed to write template copy: {:#}", e))?;
 
                 self.log(
                     LogLevel::Success,
@@ -1828,26 +4287,66 @@ impl App {
             format!("{} {}: {}", operation, item_type, item.name)
         })
     }
-    /// Show deletion confirmation dialog
+    /// Ask before deleting a tree item; non-empty folders require typing their name
     pub fn show_delete_confirmation_dialog(
         &mut self,
         item_path: &str,
         item_name: &str,
         is_folder: bool,
     ) {
-        self.show_delete_confirmation_dialog = true;
-        self.delete_confirmation_item_name = item_name.to_string();
-        self.delete_confirmation_item_path = item_path.to_string();
-        self.delete_confirmation_is_folder = is_folder;
-
-        // If it's a folder, scan its contents
-        if is_folder {
-            self.delete_confirmation_contents = self.scan_folder_contents(item_path);
-        } else {
-            self.delete_confirmation_contents = vec![];
+        if self.refuse_in_safe_mode("Deleting") {
+            return;
         }
+        let item_type = match (is_folder, &self.current_mode) {
+            (true, _) => "folder",
+            (false, AppMode::Automation) => "template",
+            (false, AppMode::Http) => "request",
+        };
+        let action = ConfirmAction::DeleteItem {
+            path: item_path.to_string(),
+            is_folder,
+        };
+        let title = format!(
+            "Delete {}{}",
+            item_type[..1].to_uppercase(),
+            &item_type[1..]
+        );
+        let confirmation = Confirmation::new(title, item_name, action)
+            .with_danger(DangerLevel::Destructive)
+            .with_labels("DELETE", "Cancel");
+
+        let confirmation = if is_folder {
+            let contents = self.scan_folder_contents(item_path);
+            let folders = contents
+                .iter()
+                .filter(|item| item.starts_with('📁'))
+                .count();
+            let items = contents.len() - folders;
+            let item_noun = match self.current_mode {
+                AppMode::Automation => "template",
+                AppMode::Http => "request",
+            };
+            let confirmation = confirmation.with_details(vec![
+                "This will permanently delete:".to_string(),
+                format!("   • {} folder(s)", folders + 1), // +1 for the folder itself
+                format!("   • {} {}(s)", items, item_noun),
+            ]);
+            if contents.is_empty() {
+                confirmation.with_danger(DangerLevel::Warn)
+            } else {
+                confirmation
+                    .with_items(contents)
+                    .with_typed_confirmation(item_name)
+            }
+        } else {
+            confirmation.with_details(vec![
+                format!("This will permanently delete this {}.", item_type),
+                String::new(),
+                "This action cannot be undone.".to_string(),
+            ])
+        };
+        self.confirmation = Some(confirmation);
 
-        let item_type = if is_folder { "folder" } else { "template" };
         self.log(
             LogLevel::Debug,
             format!(
@@ -1857,39 +4356,55 @@ impl App {
         );
     }
 
-    /// Hide deletion confirmation dialog
-    pub fn hide_delete_confirmation_dialog(&mut self) {
-        self.show_delete_confirmation_dialog = false;
-        self.delete_confirmation_item_name.clear();
-        self.delete_confirmation_item_path.clear();
-        self.delete_confirmation_is_folder = false;
-        self.delete_confirmation_contents.clear();
-
-        self.log(LogLevel::Debug, "Delete confirmation dialog closed");
+    /// Close the confirmation dialog without running its action
+    pub fn dismiss_confirmation(&mut self) {
+        self.confirmation = None;
+        self.log(LogLevel::Debug, "Confirmation dialog closed");
     }
 
-    /// Perform the confirmed deletion (mode-aware)
-    pub async fn confirm_deletion(&mut self) -> Result<()> {
-        if self.delete_confirmation_is_folder {
-            self.delete_folder_confirmed(&self.delete_confirmation_item_path.clone())
-                .await
-        } else {
-            // Mode-aware template/request deletion
-            match self.current_mode {
-                AppMode::Automation => {
-                    self.delete_template(&self.delete_confirmation_item_path.clone())
-                        .await
-                }
-                AppMode::Http => {
-                    self.delete_http_request(&self.delete_confirmation_item_path.clone())
-                        .await
-                }
+    /// Run the pending confirmation's action, unless it still needs typed input
+    pub async fn confirm_pending(&mut self) -> Result<()> {
+        let Some(confirmation) = self.confirmation.take() else {
+            return Ok(());
+        };
+        if !confirmation.can_confirm() {
+            let expected = confirmation.typed_confirmation.clone().unwrap_or_default();
+            self.confirmation = Some(confirmation);
+            self.log(LogLevel::Warn, format!("Type '{}' to confirm", expected));
+            return Ok(());
+        }
+
+        match confirmation.action {
+            ConfirmAction::DeleteItem { path, is_folder } => {
+                if is_folder {
+                    self.delete_folder_confirmed(&path, &confirmation.subject)
+                        .await
+                } else {
+                    // Mode-aware template/request deletion
+                    match self.current_mode {
+                        AppMode::Automation => self.delete_template(&path).await,
+                        AppMode::Http => self.delete_http_request(&path).await,
+                    }
+                }
+            }
+            ConfirmAction::DeleteInputPreset { name } => {
+                self.delete_input_preset(&name);
+                Ok(())
+            }
+            ConfirmAction::OverwriteFromDialog => self.save_from_template_dialog(true).await,
+            ConfirmAction::QueueAutomation => {
+                self.queue_automation();
+                Ok(())
             }
         }
     }
 
     /// Delete folder after confirmation (works for both modes)
-    async fn delete_folder_confirmed(&mut self, folder_path: &str) -> Result<()> {
+    async fn delete_folder_confirmed(
+        &mut self,
+        folder_path: &str,
+        folder_name: &str,
+    ) -> Result<()> {
         let base_dir = match self.current_mode {
             AppMode::Automation => self.config.get_templates_directory().clone(),
             AppMode::Http => self.http_collection_storage.get_collections_directory(),
@@ -1899,7 +4414,6 @@ impl App {
 
         if !full_folder_path.exists() {
             self.log(LogLevel::Error, "Folder no longer exists");
-            self.hide_delete_confirmation_dialog();
             return Ok(());
         }
 
@@ -1909,22 +4423,25 @@ impl App {
                     AppMode::Automation => "templates",
                     AppMode::Http => "HTTP requests",
                 };
+                self.audit(
+                    AuditAction::Delete,
+                    vec![folder_path.to_string()],
+                    format!("{} folder and all its contents", mode_name),
+                );
                 self.log(
                     LogLevel::Success,
                     format!(
                         "Deleted {} folder '{}' and all its contents",
-                        mode_name, self.delete_confirmation_item_name
+                        mode_name, folder_name
                     ),
                 );
 
                 self.refresh_tree_from_storage().await?;
-                self.hide_delete_confirmation_dialog();
                 Ok(())
             }
             Err(e) => {
                 let error_msg = format!("Failed to delete folder: {}", e);
                 self.log(LogLevel::Error, error_msg);
-                self.hide_delete_confirmation_dialog();
                 Ok(())
             }
         }
@@ -1974,8 +4491,8 @@ impl App {
                 if let Ok(sub_items) = self.scan_directory_recursive(&path, &folder_path) {
                     items.extend(sub_items);
                 }
-            } else if path.extension().and_then(|s| s.to_str()) == Some("json") {
-                // Add template (remove .json extension for display)
+            } else if is_item_file(&path) {
+                // Add template (remove the .json/.yaml extension for display)
                 let template_name = path.file_stem().and_then(|s| s.to_str()).unwrap_or(&name);
                 let template_path = if relative_path.is_empty() {
                     template_name.to_string()
@@ -1989,22 +4506,6 @@ impl App {
         Ok(items)
     }
 
-    /// Get count of items that will be deleted
-    pub fn get_deletion_count(&self) -> (usize, usize) {
-        let folders = self
-            .delete_confirmation_contents
-            .iter()
-            .filter(|item| item.starts_with("📁"))
-            .count();
-        let templates = self
-            .delete_confirmation_contents
-            .iter()
-            .filter(|item| item.starts_with("📄"))
-            .count();
-
-        (folders, templates)
-    }
-
     // Add cursor movement methods
     pub fn move_field_cursor_left(&mut self) {
         if let Some(field) = self.automation_state.get_focused_field() {
@@ -2034,18 +4535,18 @@ impl App {
     }
 
     pub fn delete_char_at_cursor(&mut self) {
-        if self.form_field_cursor_index > 0 {
-            if let Some(field) = self.automation_state.get_focused_field_mut() {
-                let current_index = self.form_field_cursor_index;
-                let from_left_to_current_index = current_index - 1;
+        if self.form_field_cursor_index > 0
+            && let Some(field) = self.automation_state.get_focused_field_mut()
+        {
+            let current_index = self.form_field_cursor_index;
+            let from_left_to_current_index = current_index - 1;
 
-                // Split string and rebuild without the character at cursor-1
-                let before_char_to_delete = field.value.chars().take(from_left_to_current_index);
-                let after_char_to_delete = field.value.chars().skip(current_index);
+            // Split string and rebuild without the character at cursor-1
+            let before_char_to_delete = field.value.chars().take(from_left_to_current_index);
+            let after_char_to_delete = field.value.chars().skip(current_index);
 
-                field.value = before_char_to_delete.chain(after_char_to_delete).collect();
-                self.move_field_cursor_left();
-            }
+            field.value = before_char_to_delete.chain(after_char_to_delete).collect();
+            self.move_field_cursor_left();
         }
     }
 
@@ -2069,6 +4570,15 @@ impl App {
     pub fn enter_edit_mode(&mut self) {
         self.input_mode = InputMode::Edit;
         self.set_cursor_to_end_of_field(); // Start at end of existing text
+
+        // Keep the body cursor on a reported JSON error, otherwise start at the end
+        if self.current_mode == AppMode::Http && self.http_state.body_error.is_none() {
+            self.http_state.move_body_cursor_to_end();
+        }
+        if self.current_mode == AppMode::Http {
+            self.http_state.move_script_cursor_to_end();
+            self.http_state.move_url_cursor_to_end();
+        }
         self.log(LogLevel::Debug, "Entered edit mode");
     }
 
@@ -2076,6 +4586,173 @@ impl App {
         self.input_mode = InputMode::Normal;
         self.reset_field_cursor();
         self.log(LogLevel::Debug, "Exited edit mode");
+
+        if self.current_mode == AppMode::Http && self.http_state.is_body_text_editable() {
+            self.check_json_body();
+        }
+    }
+
+    /// Ask the main loop to open the body, script or form field in focus in `$EDITOR`
+    pub fn request_external_edit(&mut self) {
+        let target = match self.current_mode {
+            AppMode::Http => match self.http_state.current_request_tab {
+                HttpRequestTab::Body
+                    if matches!(
+                        self.http_state.current_body_type,
+                        BodyContentType::Json | BodyContentType::Text | BodyContentType::Raw
+                    ) =>
+                {
+                    Some(ExternalEditTarget::RequestBody)
+                }
+                _ if self.http_state.is_script_editable() => {
+                    Some(ExternalEditTarget::RequestScript)
+                }
+                _ => None,
+            },
+            AppMode::Automation => match self.automation_state.get_focused_field() {
+                Some(field)
+                    if matches!(
+                        field.field_type,
+                        FieldType::Text | FieldType::Email | FieldType::Textarea
+                    ) =>
+                {
+                    Some(ExternalEditTarget::AutomationField(
+                        self.automation_state.focused_field,
+                    ))
+                }
+                _ => None,
+            },
+        };
+        match target {
+            Some(target) => self.pending_external_edit = Some(target),
+            None => self.log(
+                LogLevel::Warn,
+                "Nothing to open in $EDITOR here (text bodies, scripts and text fields only)",
+            ),
+        }
+    }
+
+    /// The text to edit and the file extension the editor should see
+    pub fn external_edit_content(&self, target: &ExternalEditTarget) -> (String, &'static str) {
+        match target {
+            ExternalEditTarget::RequestBody => {
+                let extension = match self.http_state.current_body_type {
+                    BodyContentType::Json => "json",
+                    _ => "txt",
+                };
+                (self.http_state.get_body_content(), extension)
+            }
+            ExternalEditTarget::RequestScript => (self.http_state.script_text().to_string(), "txt"),
+            ExternalEditTarget::AutomationField(index) => (
+                self.automation_state
+                    .fields
+                    .get(*index)
+                    .map(|field| field.value.clone())
+                    .unwrap_or_default(),
+                "txt",
+            ),
+            ExternalEditTarget::FolderAuth(folder) => {
+                // Without auth an empty Basic one is offered, so there is something to fill in
+                let auth = self
+                    .http_collection_storage
+                    .read_folder_settings(folder)
+                    .auth
+                    .unwrap_or_else(|| HttpAuth::None.next_kind());
+                (
+                    serde_json::to_string_pretty(&auth).unwrap_or_default(),
+                    "json",
+                )
+            }
+        }
+    }
+
+    /// Put the text saved in `$EDITOR` back where it came from
+    pub fn finish_external_edit(&mut self, target: ExternalEditTarget, result: Result<String>) {
+        let content = match result {
+            Ok(content) => content,
+            Err(e) => {
+                self.log(LogLevel::Error, format!("External editor: {:#}", e));
+                return;
+            }
+        };
+        if content == self.external_edit_content(&target).0 {
+            self.log(LogLevel::Debug, "External editor closed without changes");
+            return;
+        }
+
+        match target {
+            ExternalEditTarget::RequestBody => {
+                self.http_state.update_body_content(content);
+                self.http_state.move_body_cursor_to_end();
+                // Builder rows follow the new body; rows can't show a broken one
+                if self.http_state.json_builder.is_some() {
+                    self.http_state.json_builder = None;
+                    self.http_state.json_builder_error = None;
+                    if let Err(e) = self.http_state.toggle_json_builder() {
+                        self.log(
+                            LogLevel::Warn,
+                            format!("Back to the raw JSON editor: {}", e),
+                        );
+                    }
+                }
+                self.check_json_body();
+            }
+            ExternalEditTarget::RequestScript => self.http_state.set_script_text(content),
+            ExternalEditTarget::AutomationField(index) => {
+                if let Some(field) = self.automation_state.fields.get_mut(index) {
+                    field.value = content;
+                }
+            }
+            ExternalEditTarget::FolderAuth(folder) => {
+                let saved = serde_json::from_str::<HttpAuth>(&content)
+                    .context("Folder auth is not valid")
+                    .and_then(|auth| {
+                        let mut settings =
+                            self.http_collection_storage.read_folder_settings(&folder);
+                        settings.auth = (auth != HttpAuth::None).then_some(auth.clone());
+                        self.http_collection_storage
+                            .save_folder_settings(&folder, &settings)?;
+                        Ok(auth)
+                    });
+                let auth = match saved {
+                    Ok(auth) => auth,
+                    Err(e) => {
+                        self.log(LogLevel::Error, format!("{:#}; nothing was changed", e));
+                        return;
+                    }
+                };
+                if let Some(dialog) = self
+                    .folder_settings_dialog
+                    .as_mut()
+                    .filter(|dialog| dialog.folder == folder)
+                {
+                    dialog.auth = auth;
+                }
+                self.refresh_folder_inheritance();
+                self.log(
+                    LogLevel::Success,
+                    format!("Saved the auth of folder '{}'", folder),
+                );
+                return;
+            }
+        }
+        self.log(LogLevel::Info, "Updated from the external editor");
+    }
+
+    /// Validate (and optionally format) a JSON request body, logging where it fails to parse
+    pub fn check_json_body(&mut self) -> bool {
+        if self
+            .http_state
+            .check_json_body(self.config.auto_format_json)
+        {
+            return true;
+        }
+
+        if let Some(error) = &self.http_state.body_error {
+            let message = format!("Invalid JSON body at {}", error);
+            self.log(LogLevel::Error, message);
+        }
+        false
     }
 
     /// Show the help dialog
@@ -2096,38 +4773,102 @@ impl App {
 
     /// Send HTTP request (for HTTP mode)
     pub async fn send_http_request(&mut self) -> Result<()> {
+        self.send_current_request(false).await
+    }
+
+    /// Send the current request once without any proxy, whatever the settings say
+    pub async fn send_http_request_direct(&mut self) -> Result<()> {
+        self.send_current_request(true).await
+    }
+
+    async fn send_current_request(&mut self, bypass_proxy: bool) -> Result<()> {
+        let pagination = &self.http_state.current_request.pagination;
+        if pagination.auto_follow && pagination.rule != PaginationRule::None && !bypass_proxy {
+            self.fetch_all_pages();
+            return Ok(());
+        }
+        let environment = self.http_state.environment_vars.clone();
+        let proxy = self.config.active_proxy();
+        let environment_name = self.config.active_environment_name().to_string();
+        self.send_current_request_with(&environment_name, environment, proxy, bypass_proxy)
+            .await
+    }
+
+    /// Send the current request with the given variables and proxy; `environment_name`
+    /// only decides whether the send goes on the audit record
+    async fn send_current_request_with(
+        &mut self,
+        environment_name: &str,
+        environment: Vec<KeyValuePair>,
+        proxy: ProxySettings,
+        bypass_proxy: bool,
+    ) -> Result<()> {
         self.log(LogLevel::Debug, "send_http_request() called");
 
-        if self.http_state.is_sending {
-            self.log(LogLevel::Warn, "HTTP request is already being sent");
+        // Other requests may still be in flight; only block re-sending the same one
+        if self.http_state.is_sending() {
+            self.log(LogLevel::Warn, "This request is already being sent");
             return Ok(());
         }
 
-        // Check if request is valid
-        if !self.http_state.is_valid() {
-            let errors = self.http_state.get_validation_errors();
-            for error in &errors {
-                self.log(LogLevel::Error, error.clone());
-            }
+        if !self.check_before_send(environment_name) {
             return Ok(());
         }
 
-        self.http_state.is_sending = true;
-        self.log(LogLevel::Info, "🌐 Sending HTTP request...");
+        // Folder auth and variables fill in what the request doesn't set itself
+        let mut request = self.http_state.current_request.clone();
+        let mut environment = environment;
+        let inheritance = self.current_folder_inheritance();
+        inheritance.apply_auth(&mut request);
+        inheritance.apply_variables(&mut environment);
+
+        let request_key = self.http_state.begin_send();
+        self.pending_history
+            .insert(request_key.clone(), (request.clone(), chrono::Local::now()));
+        self.log(
+            LogLevel::Info,
+            format!(
+                "🌐 Sending HTTP request '{}'{}...",
+                self.http_state.current_request.name,
+                if bypass_proxy {
+                    " (bypassing proxy)"
+                } else {
+                    ""
+                }
+            ),
+        );
 
         // Clone the data we need for the background task
-        let request = self.http_state.current_request.clone();
+        let request_name = request.name.clone();
+        let session = HttpSession {
+            proxy,
+            bypass_proxy,
+            ..self.http_session()
+        };
         let sender = self.message_sender.clone();
 
         self.log(LogLevel::Debug, "Spawning HTTP request task...");
 
-        // Spawn the HTTP request task
-        tokio::spawn(async move {
-            match send_http_request_impl(request).await {
-                Ok(response) => {
+        // Spawn the HTTP request task; its abort handle lets the send be cancelled
+        self.http_send_generation += 1;
+        let generation = self.http_send_generation;
+        let task_key = request_key.clone();
+        let task = tokio::spawn(async move {
+            match send_prepared_request(request, &environment, &session, &sender).await {
+                Ok((sent, response)) => {
+                    let note = if response.served_from_cache {
+                        " (served from cache)".to_string()
+                    } else if let Some(truncation) = &response.truncation {
+                        format!(" (body {})", truncation.label())
+                    } else {
+                        String::new()
+                    };
                     let _ = sender.send(AppMessage::Log(
                         LogLevel::Success,
-                        format!("✅ HTTP {} {}", response.status_code, response.status_text),
+                        format!(
+                            "✅ {}: HTTP {} {}{}",
+                            request_name, response.status_code, response.status_text, note
+                        ),
                     ));
 
                     let _ = sender.send(AppMessage::Log(
@@ -2135,246 +4876,3683 @@ impl App {
                         format!("Response received in {} ms", response.duration_ms),
                     ));
 
-                    // FIXED: Send the actual response to update the UI
-                    let _ = sender.send(AppMessage::HttpResponseReceived(response));
+                    // Route the response back to the request that sent it
+                    let _ = sender.send(AppMessage::HttpResponseReceived {
+                        request_key,
+                        generation,
+                        sent: Some(Box::new(sent)),
+                        response: Box::new(response),
+                    });
                 }
                 Err(error) => {
-                    let error_msg = format!("HTTP request failed: {}", error);
-
-                    // FIXED: Use new message type to clear sending state
-                    let _ = sender.send(AppMessage::HttpRequestFailed(error_msg));
+                    let error = format!("HTTP request '{}' failed: {}", request_name, error);
+                    let _ = sender.send(AppMessage::HttpRequestFailed {
+                        request_key,
+                        generation,
+                        error,
+                    });
                 }
             }
         });
+        self.http_send_tasks
+            .insert(task_key, (generation, task.abort_handle()));
 
         self.log(LogLevel::Debug, "HTTP request task spawned");
         Ok(())
     }
-    /// Show HTTP request creation dialog (placeholder for now)
-    pub fn show_http_request_creation_dialog(&mut self) {
-        // For now, let's create a quick save with a default name
-        // Later we can implement a proper dialog similar to template_creation_dialog
 
-        let focused_folder = if let Some(focused_node) = self.tree_state.get_focused_node() {
-            match focused_node.node_type {
-                NodeType::Folder => focused_node.path.clone(),
-                NodeType::Template => {
-                    if let Some(parent_pos) = focused_node.path.rfind('/') {
-                        focused_node.path[..parent_pos].to_string()
-                    } else {
-                        "".to_string()
-                    }
-                }
+    /// Checks every send of the current request must pass, logging why one fails.
+    /// Sends to a production environment go on the audit record once they pass.
+    fn check_before_send(&mut self, environment_name: &str) -> bool {
+        if !self.http_state.is_valid() {
+            for error in self.http_state.get_validation_errors() {
+                self.log(LogLevel::Error, error);
             }
-        } else {
-            "".to_string()
-        };
+            return false;
+        }
 
-        // Quick save with auto-generated name for now
-        let request_name = format!(
-            "{} Request",
-            self.http_state.current_request.method.as_str()
-        );
+        if let Some(error) = &self.http_state.json_builder_error {
+            let message = format!("Request not sent: fix the JSON builder rows ({})", error);
+            self.log(LogLevel::Warn, message);
+            return false;
+        }
 
-        tokio::spawn(async move {
-            // We'll implement proper async save later
-        });
+        if !self.check_json_body() && self.config.block_invalid_json {
+            self.log(
+                LogLevel::Warn,
+                "Request not sent: fix the JSON body or disable blocking in settings (F9)",
+            );
+            return false;
+        }
+
+        let request = &self.http_state.current_request;
+        let paths = vec![self.http_state.current_request_key.clone()];
+        let detail = format!("{} {}", request.method.as_str(), request.url);
+        self.audit_production_send(environment_name, paths, detail);
+        true
+    }
+
+    /// Fetch every page of the current request (per its pagination rule) into one document
+    pub fn fetch_all_pages(&mut self) {
+        if self.http_state.is_sending() {
+            self.log(LogLevel::Warn, "This request is already being sent");
+            return;
+        }
+
+        if self.http_state.current_request.pagination.rule == PaginationRule::None {
+            self.log(
+                LogLevel::Warn,
+                "No pagination rule set - choose one on the Settings tab (p)",
+            );
+            return;
+        }
+
+        let environment_name = self.config.active_environment_name().to_string();
+        if !self.check_before_send(&environment_name) {
+            return;
+        }
+
+        let mut request = self.http_state.current_request.clone();
+        let mut environment = self.http_state.environment_vars.clone();
+        let inheritance = self.current_folder_inheritance();
+        inheritance.apply_auth(&mut request);
+        inheritance.apply_variables(&mut environment);
+
+        let request_key = self.http_state.begin_send();
+        self.pending_history
+            .insert(request_key.clone(), (request.clone(), chrono::Local::now()));
+        let request_name = request.name.clone();
+        let sender = self.message_sender.clone();
 
         self.log(
             LogLevel::Info,
             format!(
-                "TODO: Implement HTTP request save dialog. Would save to: {}",
-                focused_folder
+                "📚 Fetching up to {} page(s) of '{}'...",
+                request.pagination.max_pages, request_name
             ),
         );
+
+        let session = self.http_session();
+        let tests = request.tests.clone();
+        let extract = request.extract.clone();
+
+        self.http_send_generation += 1;
+        let generation = self.http_send_generation;
+        let task_key = request_key.clone();
+        let task = tokio::spawn(async move {
+            // For the assertion and extraction lines of the combined result
+            let logger = RequestLogger::new(&sender, &request, &environment);
+            let progress_sender = sender.clone();
+            let fetch = move |mut page_request: HttpRequest| {
+                let environment = environment.clone();
+                let session = session.clone();
+                let progress_sender = progress_sender.clone();
+                // Assertions and extraction run once, against the aggregated result
+                page_request.tests.clear();
+                page_request.extract.clear();
+                async move {
+                    let _ = progress_sender.send(AppMessage::Log(
+                        LogLevel::Debug,
+                        format!("Fetching page: {}", page_request.url),
+                    ));
+                    send_prepared_request(page_request, &environment, &session, &progress_sender)
+                        .await
+                        .map(|(_, response)| response)
+                }
+            };
+
+            let page_sender = sender.clone();
+            let page_key = request_key.clone();
+            let progress = move |pages, items| {
+                let _ = page_sender.send(AppMessage::PaginationProgress {
+                    request_key: page_key.clone(),
+                    generation,
+                    pages,
+                    items,
+                });
+            };
+            match walk_pages(request, fetch, progress).await {
+                Ok(result) => {
+                    let note = if result.truncated {
+                        " (stopped at page limit)"
+                    } else {
+                        ""
+                    };
+                    let _ = sender.send(AppMessage::Log(
+                        LogLevel::Success,
+                        format!(
+                            "✅ {}: {} item(s) from {} page(s){}",
+                            request_name,
+                            result.items.len(),
+                            result.pages,
+                            note
+                        ),
+                    ));
+                    let mut response = result.into_response();
+                    check_assertions(&request_name, &tests, &mut response, &logger);
+                    capture_variables(&request_name, &extract, &mut response, &logger);
+                    let _ = sender.send(AppMessage::HttpResponseReceived {
+                        request_key,
+                        generation,
+                        sent: None,
+                        response: Box::new(response),
+                    });
+                }
+                Err(error) => {
+                    let error = format!("Pagination of '{}' failed: {:#}", request_name, error);
+                    let _ = sender.send(AppMessage::HttpRequestFailed {
+                        request_key,
+                        generation,
+                        error,
+                    });
+                }
+            }
+        });
+        self.http_send_tasks
+            .insert(task_key, (generation, task.abort_handle()));
+    }
+
+    /// Whether a message comes from the send of `request_key` still running, rather than
+    /// from one cancelled (or replaced) before its result arrived
+    fn is_current_send(&self, request_key: &str, generation: u64) -> bool {
+        self.http_send_tasks
+            .get(request_key)
+            .is_some_and(|(running, _)| *running == generation)
+    }
+
+    /// Abort the current request's send (or page fetch), dropping whatever it would return
+    pub fn cancel_http_request(&mut self) {
+        let key = self.http_state.current_request_key.clone();
+        let Some((_, task)) = self.http_send_tasks.remove(&key) else {
+            self.log(LogLevel::Info, "This request isn't being sent");
+            return;
+        };
+        task.abort();
+        self.http_state.finish_send(&key, None);
+        self.record_history(&key, Err(&"Cancelled".to_string()));
+        self.note_task_finished(AppMode::Http, "request cancelled");
         self.log(
-            LogLevel::Info,
-            "For now, use a proper dialog - will implement next",
+            LogLevel::Warn,
+            format!("⏹ Cancelled '{}'", self.http_state.current_request.name),
         );
     }
 
-    /// Show the import dialog
-    pub fn show_import_dialog(&mut self) {
-        self.show_import_dialog = true;
-        self.import_dialog_file_path.clear();
-        self.import_dialog_error = None;
-        self.import_dialog_preview = None;
-        self.log(LogLevel::Debug, "Import dialog opened");
+    /// Mask and rebuild the response body view when the response, its view settings or
+    /// the secrets to mask changed since it was built
+    pub fn refresh_response_view(&mut self) {
+        let state = &self.http_state;
+        let Some(response) = &state.last_response else {
+            self.masked_last_response = None;
+            self.response_view = None;
+            self.response_view_key = None;
+            self.http_state.response_search_matches.clear();
+            return;
+        };
+        let key = ResponseViewKey {
+            response_generation: state.response_generation(),
+            raw: state.response_raw_view,
+            tree: state.response_tree_view,
+            filter: state.response_filter.trim().to_string(),
+            collapsed: state.response_collapsed.clone(),
+            secrets: (!self.reveal_secrets).then(|| self.secret_values()),
+        };
+        if self.response_view_key.as_ref() == Some(&key) {
+            return;
+        }
+        // Filters and view toggles don't change what needs masking
+        let remask = self.response_view_key.as_ref().is_none_or(|old| {
+            old.response_generation != key.response_generation || old.secrets != key.secrets
+        });
+        if remask {
+            self.masked_last_response = self.masked_response(response);
+        }
+        let masked = self.masked_last_response.as_ref();
+        let view = ResponseBodyView::new(masked.unwrap_or(response), state);
+        self.response_view = Some(view);
+        self.response_view_key = Some(key);
+        self.update_response_search_matches();
     }
 
-    /// Hide the import dialog
-    pub fn hide_import_dialog(&mut self) {
-        self.show_import_dialog = false;
-        self.import_dialog_file_path.clear();
-        self.import_dialog_error = None;
-        self.import_dialog_preview = None;
-        self.log(LogLevel::Debug, "Import dialog closed");
+    /// Find the search text in the body text as the viewer shows it
+    fn update_response_search_matches(&mut self) {
+        let matches = match self.response_view.as_ref().map(|view| &view.text) {
+            Some(Ok(text)) => find_matches(text, &self.http_state.response_search),
+            _ => Vec::new(),
+        };
+        self.http_state.response_search_matches = matches;
     }
 
-    /// Update file path and validate/preview
-    pub fn update_import_file_path(&mut self, file_path: String) {
-        self.import_dialog_file_path = file_path;
-        self.import_dialog_error = None;
-        self.import_dialog_preview = None;
+    /// The response body view, brought up to date first
+    fn current_response_view(&mut self) -> Option<&ResponseBodyView> {
+        self.refresh_response_view();
+        self.response_view.as_ref()
+    }
 
-        if !self.import_dialog_file_path.trim().is_empty() {
-            // Validate and preview the file
-            match self.validate_and_preview_collection(&self.import_dialog_file_path) {
-                Ok(preview) => {
-                    self.import_dialog_preview = Some(preview);
-                }
-                Err(error) => {
-                    self.import_dialog_error = Some(error);
+    /// Number of lines with a JSONPath in the shown body (tree rows or formatted JSON lines)
+    fn response_json_path_count(&mut self) -> Option<usize> {
+        self.current_response_view()?
+            .json_paths
+            .as_ref()
+            .map(Vec::len)
+    }
+
+    /// Move the response body cursor by `delta` lines
+    pub fn move_response_cursor(&mut self, delta: isize) {
+        let rows = match self.response_json_path_count() {
+            Some(rows) => rows,
+            None => match self.response_hex_rows() {
+                Some(rows) => rows,
+                None => return,
+            },
+        };
+        let last = rows.saturating_sub(1);
+        self.http_state.response_cursor = self
+            .http_state
+            .response_cursor
+            .saturating_add_signed(delta)
+            .min(last);
+    }
+
+    /// Rows of the hex dump of the shown response, when it's binary
+    fn response_hex_rows(&self) -> Option<usize> {
+        let response = self.http_state.last_response.as_ref()?;
+        shows_hex_dump(response, &self.http_state).then(|| hex_row_count(response.body_bytes.len()))
+    }
+
+    /// Switch the response body between the JSON tree and the text views
+    pub fn toggle_response_tree_view(&mut self) {
+        self.http_state.response_tree_view = !self.http_state.response_tree_view;
+        // Tree rows and text lines don't line up, so start again from the top
+        self.http_state.response_cursor = 0;
+        self.http_state.response_search_current = 0;
+        self.log(
+            LogLevel::Debug,
+            if self.http_state.response_tree_view {
+                "Showing response body as a JSON tree"
+            } else {
+                "Showing response body as text"
+            },
+        );
+    }
+
+    /// Expand or collapse the tree node under the cursor; `None` toggles it.
+    /// Collapsing a leaf or an already collapsed node moves up to its parent.
+    pub fn fold_response_node(&mut self, expand: Option<bool>) {
+        let cursor = self.http_state.response_cursor;
+        let Some(rows) = self
+            .current_response_view()
+            .and_then(|view| view.tree.as_ref())
+        else {
+            return;
+        };
+        let cursor = cursor.min(rows.len().saturating_sub(1));
+        let Some(row) = rows.get(cursor) else {
+            return;
+        };
+        let parent = rows[..cursor]
+            .iter()
+            .rposition(|parent| parent.depth + 1 == row.depth);
+        let (path, expandable, expanded) = (row.path.clone(), row.expandable, row.expanded);
+        match expand.unwrap_or(!expanded) {
+            true if expandable => {
+                self.http_state.response_collapsed.remove(&path);
+            }
+            false if expanded => {
+                self.http_state.response_collapsed.insert(path);
+            }
+            false if expand.is_some() => {
+                if let Some(parent) = parent {
+                    self.http_state.response_cursor = parent;
                 }
             }
+            _ => {}
         }
     }
 
-    /// Validate file and create preview
-    fn validate_and_preview_collection(
-        &self,
-        file_path: &str,
-    ) -> Result<CollectionPreview, String> {
-        use std::path::Path;
+    /// Copy the value of the tree node under the cursor - strings unquoted,
+    /// objects and arrays as pretty JSON
+    pub fn copy_response_json_value(&mut self) {
+        let cursor = self.http_state.response_cursor;
+        let copied = self.current_response_view().and_then(|view| {
+            let row = view.tree.as_ref()?.get(cursor)?;
+            let value = json_tree_value(view.document.as_ref()?, &row.pointer)?;
+            Some((row.path.clone(), value))
+        });
+        let Some((path, value)) = copied else {
+            self.log(
+                LogLevel::Warn,
+                "Values can be copied from the JSON tree view (t) without a filter",
+            );
+            return;
+        };
 
-        let path = Path::new(file_path);
+        match copy_to_clipboard(&value) {
+            Ok(mechanism) => self.log(
+                LogLevel::Success,
+                format!("Copied the value of {} (via {})", path, mechanism),
+            ),
+            Err(e) => self.log(LogLevel::Error, format!("Failed to copy value: {}", e)),
+        }
+    }
 
-        // Check if file exists
-        if !path.exists() {
-            return Err("File does not exist".to_string());
+    /// Find the new search text and jump to its first match
+    pub fn response_search_changed(&mut self) {
+        self.refresh_response_view();
+        self.update_response_search_matches();
+        self.http_state.response_search_current = 0;
+        self.follow_response_search_match();
+    }
+
+    /// Move to the next or previous response search match, wrapping around
+    pub fn step_response_search(&mut self, forward: bool) {
+        self.refresh_response_view();
+        let count = self.http_state.response_search_matches.len();
+        if count == 0 {
+            self.log(
+                LogLevel::Warn,
+                format!("No matches for '{}'", self.http_state.response_search),
+            );
+            return;
         }
+        let current = self.http_state.response_search_current.min(count - 1);
+        self.http_state.response_search_current = if forward {
+            (current + 1) % count
+        } else {
+            (current + count - 1) % count
+        };
+        self.follow_response_search_match();
+    }
 
-        // Check if it's a file
-        if !path.is_file() {
-            return Err("Path is not a file".to_string());
+    /// Put the JSON cursor on the current match so j/k carry on from there
+    fn follow_response_search_match(&mut self) {
+        if self.response_json_path_count().is_none() {
+            return;
+        }
+        let state = &mut self.http_state;
+        if let Some(found) = state
+            .response_search_matches
+            .get(state.response_search_current)
+        {
+            state.response_cursor = found.line;
         }
+    }
 
-        // Try to read and parse the file
-        let json_content =
-            std::fs::read_to_string(path).map_err(|e| format!("Failed to read file: {}", e))?;
+    /// Copy the JSONPath under the response body cursor, ready for extraction rules and assertions
+    pub fn copy_response_json_path(&mut self) {
+        let cursor = self.http_state.response_cursor;
+        let path = self
+            .current_response_view()
+            .and_then(|view| view.json_paths.as_ref()?.get(cursor).cloned());
+        let Some(path) = path else {
+            self.log(
+                LogLevel::Warn,
+                "JSONPaths are available on formatted JSON bodies without a filter",
+            );
+            return;
+        };
+
+        match copy_to_clipboard(&path) {
+            Ok(mechanism) => self.log(
+                LogLevel::Success,
+                format!("Copied {} (via {})", path, mechanism),
+            ),
+            Err(e) => self.log(LogLevel::Error, format!("Failed to copy JSONPath: {}", e)),
+        }
+    }
+
+    /// Save the body of the shown response to the exports directory
+    pub fn export_response_body(&mut self) {
+        if self.refuse_in_safe_mode("Exporting") {
+            return;
+        }
+        let Some(response) = &self.http_state.last_response else {
+            self.log(LogLevel::Warn, "No response to export");
+            return;
+        };
+
+        let extension = if !response.body_bytes.is_empty() {
+            "bin"
+        } else if response.content_type.contains("json") {
+            "json"
+        } else {
+            "txt"
+        };
+        let file_name = format!(
+            "{}-{}.{}",
+            sanitize_file_name(&self.http_state.current_request.name),
+            chrono::Local::now().format("%Y%m%d-%H%M%S"),
+            extension
+        );
+        let export_dir = crate::models::config::data_directory().join("exports");
+        let path = export_dir.join(file_name);
+
+        // A truncated body is exported in full from its spool file, and only a complete
+        // copy ever appears under the final name
+        let partial_path = path.with_extension("part");
+        let result = std::fs::create_dir_all(&export_dir)
+            .and_then(|_| match &response.truncation {
+                Some(truncation) => {
+                    std::fs::copy(truncation.spool_path()?, &partial_path).map(|_| ())
+                }
+                None if !response.body_bytes.is_empty() => {
+                    std::fs::write(&partial_path, &response.body_bytes)
+                }
+                None => std::fs::write(&partial_path, &response.body),
+            })
+            .and_then(|_| std::fs::rename(&partial_path, &path));
+        if result.is_err() {
+            let _ = std::fs::remove_file(&partial_path);
+        }
+        let full_size = format_bytes(response.full_body_len());
+        match result {
+            Ok(()) => self.log(
+                LogLevel::Success,
+                format!(
+                    "Exported the full response ({}) to {}",
+                    full_size,
+                    path.display()
+                ),
+            ),
+            Err(e) => self.log(
+                LogLevel::Error,
+                format!(
+                    "Failed to export response (nothing partial was written): {}",
+                    e
+                ),
+            ),
+        }
+    }
+
+    /// Save the shown request/response pair, with secrets redacted, as a bug report
+    pub fn export_bug_report(&mut self, format: BugReportFormat) {
+        if self.refuse_in_safe_mode("Exporting") {
+            return;
+        }
+        let Some(response) = &self.http_state.last_response else {
+            self.log(LogLevel::Warn, "No response to include in a bug report");
+            return;
+        };
+
+        // Reports get shared, so they carry the whole body or aren't written at all
+        let full_body = match response.full_body() {
+            Ok(body) => body,
+            Err(e) => {
+                self.log(
+                    LogLevel::Error,
+                    format!(
+                        "Not exporting a bug report with a partial body: full body unavailable ({})",
+                        e
+                    ),
+                );
+                return;
+            }
+        };
+        let sent = self.sent_request_for_report();
+        let mut report = BugReport::new(
+            &sent,
+            response,
+            self.config.active_environment_name(),
+            &self.http_state.environment_vars,
+        );
+        report.response_body = full_body;
+        let file_name = format!(
+            "{}-{}-report.{}",
+            sanitize_file_name(&self.http_state.current_request.name),
+            chrono::Local::now().format("%Y%m%d-%H%M%S"),
+            format.extension()
+        );
+        let export_dir = crate::models::config::data_directory().join("exports");
+        let path = export_dir.join(file_name);
+
+        let result = std::fs::create_dir_all(&export_dir)
+            .and_then(|_| std::fs::write(&path, report.render(format)));
+        match result {
+            Ok(()) => self.log(
+                LogLevel::Success,
+                format!("Exported bug report to {}", path.display()),
+            ),
+            Err(e) => self.log(
+                LogLevel::Error,
+                format!("Failed to export bug report: {}", e),
+            ),
+        }
+    }
+
+    /// The request behind the shown response as it went out. Responses that several
+    /// sends were aggregated from only have the request as sent, so its variables are
+    /// filled in from the environment here.
+    fn sent_request_for_report(&self) -> HttpRequest {
+        let key = &self.http_state.current_request_key;
+        if let Some(prepared) = self.http_state.prepared_requests.get(key) {
+            return prepared.clone();
+        }
+        let mut request = self
+            .http_state
+            .sent_requests
+            .get(key)
+            .unwrap_or(&self.http_state.current_request)
+            .clone();
+        let mut environment = self.http_state.environment_vars.clone();
+        let inheritance = self.current_folder_inheritance();
+        inheritance.apply_auth(&mut request);
+        inheritance.apply_variables(&mut environment);
+        let values = environment
+            .iter()
+            .filter(|var| var.enabled && !var.name.is_empty())
+            .map(|var| (var.name.clone(), var.value.clone()))
+            .collect();
+        crate::services::pre_request_script::substitute_variables(&mut request, &values);
+        request
+    }
+
+    /// Show HTTP request creation dialog (placeholder for now)
+    pub fn show_http_request_creation_dialog(&mut self) {
+        if self.refuse_in_safe_mode("Saving requests") {
+            return;
+        }
+        // For now, let's create a quick save with a default name
+        // Later we can implement a proper dialog similar to template_creation_dialog
+
+        let focused_folder = if let Some(focused_node) = self.tree_state.get_focused_node() {
+            match focused_node.node_type {
+                NodeType::Folder => focused_node.path.clone(),
+                NodeType::Template => {
+                    if let Some(parent_pos) = focused_node.path.rfind('/') {
+                        focused_node.path[..parent_pos].to_string()
+                    } else {
+                        "".to_string()
+                    }
+                }
+            }
+        } else {
+            "".to_string()
+        };
+
+        tokio::spawn(async move {
+            // We'll implement proper async save later
+        });
+
+        self.log(
+            LogLevel::Info,
+            format!(
+                "TODO: Implement HTTP request save dialog. Would save to: {}",
+                focused_folder
+            ),
+        );
+        self.log(
+            LogLevel::Info,
+            "For now, use a proper dialog - will implement next",
+        );
+    }
+
+    /// Show the import dialog
+    /// Open the expanded editor for long URLs
+    pub fn show_url_editor(&mut self) {
+        self.show_url_editor = true;
+        self.url_editor_original = self.http_state.current_request.url.clone();
+        self.http_state.move_url_cursor_to_end();
+        self.log(LogLevel::Debug, "URL editor opened");
+    }
+
+    /// Close the expanded URL editor, keeping the edits or putting the old URL back
+    pub fn hide_url_editor(&mut self, apply: bool) {
+        self.show_url_editor = false;
+        if !apply {
+            self.http_state.current_request.url = std::mem::take(&mut self.url_editor_original);
+            self.http_state.move_url_cursor_to_end();
+        }
+        self.url_editor_original.clear();
+        self.log(LogLevel::Debug, "URL editor closed");
+    }
+
+    pub fn show_curl_import_dialog(&mut self) {
+        self.show_curl_import_dialog = true;
+        self.curl_import_text.clear();
+        self.curl_import_error = None;
+        self.log(LogLevel::Debug, "cURL import dialog opened");
+    }
+
+    pub fn hide_curl_import_dialog(&mut self) {
+        self.show_curl_import_dialog = false;
+        self.curl_import_text.clear();
+        self.curl_import_error = None;
+        self.log(LogLevel::Debug, "cURL import dialog closed");
+    }
+
+    /// Append typed or pasted text to the cURL command
+    pub fn curl_import_add_text(&mut self, text: &str) {
+        self.curl_import_text.push_str(text);
+        self.curl_import_error = None;
+    }
+
+    pub fn curl_import_backspace(&mut self) {
+        self.curl_import_text.pop();
+        self.curl_import_error = None;
+    }
+
+    /// Parse the pasted cURL command and load it into the request editor
+    pub fn import_curl_command(&mut self) {
+        match crate::services::curl_import::parse_curl_command(&self.curl_import_text) {
+            Ok(request) => {
+                let summary = format!("{} {}", request.method.as_str(), request.url);
+                self.http_state.new_request();
+                let key = self.http_state.current_request_key.clone();
+                self.http_state.load_request(&key, request);
+                self.hide_curl_import_dialog();
+                self.focus_pane(FocusedPane::Form);
+                self.log(
+                    LogLevel::Success,
+                    format!("Imported cURL command: {}", summary),
+                );
+            }
+            Err(e) => {
+                self.curl_import_error = Some(e.to_string());
+            }
+        }
+    }
+
+    /// Edit the request headers as raw `Name: Value` lines
+    pub fn show_raw_headers_dialog(&mut self) {
+        self.raw_headers_text = Some(format_raw_headers(&self.http_state.current_request.headers));
+        self.raw_headers_error = None;
+        self.raw_headers_defaults = false;
+        self.log(LogLevel::Debug, "Raw headers editor opened");
+    }
+
+    /// Edit the default headers sent with every request, in the raw headers editor
+    pub fn show_default_headers_dialog(&mut self) {
+        self.raw_headers_text = Some(format_raw_headers(&self.config.default_headers));
+        self.raw_headers_error = None;
+        self.raw_headers_defaults = true;
+        self.log(LogLevel::Debug, "Default headers editor opened");
+    }
+
+    pub fn hide_raw_headers_dialog(&mut self) {
+        self.raw_headers_text = None;
+        self.raw_headers_error = None;
+        self.raw_headers_defaults = false;
+        self.log(LogLevel::Debug, "Raw headers editor closed");
+    }
+
+    /// Append typed or pasted text to the raw headers
+    pub fn raw_headers_add_text(&mut self, text: &str) {
+        if let Some(raw) = self.raw_headers_text.as_mut() {
+            // Pastes from Windows tools come with \r\n line ends
+            raw.push_str(&text.replace("\r\n", "\n"));
+            self.raw_headers_error = None;
+        }
+    }
+
+    pub fn raw_headers_backspace(&mut self) {
+        if let Some(raw) = self.raw_headers_text.as_mut() {
+            raw.pop();
+            self.raw_headers_error = None;
+        }
+    }
+
+    /// Replace the request headers (or the default headers) with the parsed raw text
+    pub fn apply_raw_headers(&mut self) {
+        let Some(raw) = &self.raw_headers_text else {
+            return;
+        };
+        match parse_raw_headers(raw) {
+            Ok(parsed) if self.raw_headers_defaults => {
+                let count = parsed.headers.len();
+                self.config.default_headers = parsed.headers;
+                self.hide_raw_headers_dialog();
+                match self.config.save() {
+                    Ok(()) => self.log(
+                        LogLevel::Success,
+                        format!("Saved {} default header(s) sent with every request", count),
+                    ),
+                    Err(e) => self.log(
+                        LogLevel::Error,
+                        format!("Default headers apply this session only: {:#}", e),
+                    ),
+                }
+            }
+            Ok(parsed) => {
+                let count = parsed.headers.len();
+                self.http_state.current_request.headers = parsed.headers;
+                self.http_state.key_value_editor.reset();
+                self.hide_raw_headers_dialog();
+                let skipped = if parsed.skipped.is_empty() {
+                    String::new()
+                } else {
+                    format!(
+                        " (left out {}; set from the body when sent)",
+                        parsed.skipped.join(", ")
+                    )
+                };
+                self.log(
+                    LogLevel::Success,
+                    format!("Set {} header(s) from raw text{}", count, skipped),
+                );
+            }
+            Err(e) => self.raw_headers_error = Some(e.to_string()),
+        }
+    }
+
+    pub fn show_snippet_dialog(&mut self) {
+        self.show_snippet_dialog = true;
+        self.log(LogLevel::Debug, "Snippet format picker opened");
+    }
+
+    pub fn hide_snippet_dialog(&mut self) {
+        self.show_snippet_dialog = false;
+        self.log(LogLevel::Debug, "Snippet format picker closed");
+    }
+
+    pub fn snippet_dialog_next(&mut self) {
+        let count = SnippetFormat::all().len();
+        self.snippet_dialog_selected = (self.snippet_dialog_selected + 1) % count;
+    }
+
+    pub fn snippet_dialog_previous(&mut self) {
+        let count = SnippetFormat::all().len();
+        self.snippet_dialog_selected = (self.snippet_dialog_selected + count - 1) % count;
+    }
+
+    pub fn selected_snippet_format(&self) -> SnippetFormat {
+        SnippetFormat::all()
+            .get(self.snippet_dialog_selected)
+            .copied()
+            .unwrap_or(SnippetFormat::Curl)
+    }
+
+    /// Generate the current request in the selected format and copy it to the clipboard
+    pub fn copy_selected_snippet(&mut self) {
+        let format = self.selected_snippet_format();
+        let snippet = generate_snippet(&self.http_state.current_request, format);
+        self.hide_snippet_dialog();
+
+        match copy_to_clipboard(&snippet) {
+            Ok(mechanism) => self.log(
+                LogLevel::Success,
+                format!("Copied request as {} (via {})", format.title(), mechanism),
+            ),
+            Err(e) => self.log(
+                LogLevel::Error,
+                format!("Failed to copy {} snippet: {}", format.title(), e),
+            ),
+        }
+    }
+
+    /// Record the outcome of a finished send in the persistent history
+    fn record_history(&mut self, request_key: &str, outcome: Result<&HttpResponse, &String>) {
+        let Some((request, sent_at)) = self.pending_history.remove(request_key) else {
+            return;
+        };
+
+        let entry = match outcome {
+            Ok(response) => HistoryEntry {
+                sent_at,
+                request,
+                status_code: Some(response.status_code),
+                status_text: response.status_text.clone(),
+                duration_ms: response.duration_ms,
+                size_bytes: response.full_body_len(),
+                error: None,
+                // Only whole bodies are kept; re-opening a partial one would pass it off as complete
+                response_body: (response.truncation.is_none()
+                    && response.body.len() <= MAX_HISTORY_BODY_BYTES)
+                    .then(|| response.body.clone()),
+                content_type: response.content_type.clone(),
+            },
+            Err(error) => HistoryEntry {
+                sent_at,
+                request,
+                status_code: None,
+                status_text: "Failed".to_string(),
+                duration_ms: (chrono::Local::now() - sent_at).num_milliseconds().max(0) as u64,
+                size_bytes: 0,
+                error: Some(error.clone()),
+                response_body: None,
+                content_type: String::new(),
+            },
+        };
+
+        if let Err(e) = self.history_storage.append(&entry) {
+            self.log(LogLevel::Error, format!("Failed to record history: {}", e));
+        }
+        self.history_entries.push(entry);
+
+        self.prune_history_entries();
+    }
+
+    /// Apply the retention limits, rewriting the history file if anything was dropped
+    fn prune_history_entries(&mut self) {
+        if !prune_history(&mut self.history_entries, self.config.history_retention) {
+            return;
+        }
+        if let Err(e) = self.history_storage.rewrite(&self.history_entries) {
+            self.log(
+                LogLevel::Error,
+                format!("Failed to prune request history: {}", e),
+            );
+        }
+        self.history_selected = self
+            .history_selected
+            .min(self.history_entries.len().saturating_sub(1));
+    }
+
+    pub fn show_history_dialog(&mut self) {
+        self.show_history_dialog = true;
+        self.history_selected = 0;
+        self.log(LogLevel::Debug, "History opened");
+    }
+
+    pub fn hide_history_dialog(&mut self) {
+        self.show_history_dialog = false;
+        self.log(LogLevel::Debug, "History closed");
+    }
+
+    pub fn history_next(&mut self) {
+        if self.history_selected + 1 < self.history_entries.len() {
+            self.history_selected += 1;
+        }
+    }
+
+    pub fn history_previous(&mut self) {
+        self.history_selected = self.history_selected.saturating_sub(1);
+    }
+
+    /// The highlighted history entry (the list is shown newest first)
+    pub fn selected_history_entry(&self) -> Option<&HistoryEntry> {
+        let len = self.history_entries.len();
+        if self.history_selected >= len {
+            return None;
+        }
+        self.history_entries.get(len - 1 - self.history_selected)
+    }
+
+    /// Open the highlighted entry's request snapshot as a new unsaved request
+    pub fn reopen_history_entry(&mut self) -> bool {
+        let Some(entry) = self.selected_history_entry() else {
+            return false;
+        };
+        let request = entry.request.clone();
+        self.hide_history_dialog();
+        self.load_history_request(request);
+        true
+    }
+
+    /// Load a request snapshot from the history as a new unsaved request
+    fn load_history_request(&mut self, request: HttpRequest) {
+        self.http_state.new_request();
+        let key = self.http_state.current_request_key.clone();
+        self.http_state.load_request(&key, request);
+        self.log(
+            LogLevel::Info,
+            format!(
+                "Re-opened '{}' from history",
+                self.http_state.current_request.name
+            ),
+        );
+    }
+
+    /// Re-open the highlighted entry and send it again
+    pub async fn resend_history_entry(&mut self) -> Result<()> {
+        if self.reopen_history_entry() {
+            self.send_http_request().await?;
+        }
+        Ok(())
+    }
+
+    /// Remove the highlighted entry from the history
+    pub fn delete_history_entry(&mut self) {
+        let len = self.history_entries.len();
+        if self.history_selected >= len {
+            return;
+        }
+        let removed = self.history_entries.remove(len - 1 - self.history_selected);
+        self.history_selected = self
+            .history_selected
+            .min(self.history_entries.len().saturating_sub(1));
+
+        match self.history_storage.rewrite(&self.history_entries) {
+            Ok(()) => {
+                self.audit(
+                    AuditAction::Delete,
+                    vec![removed.request.name.clone()],
+                    format!(
+                        "history entry sent {}",
+                        self.format_timestamp(&removed.sent_at)
+                    ),
+                );
+                self.log(
+                    LogLevel::Info,
+                    format!("Removed '{}' from history", removed.request.name),
+                );
+            }
+            Err(e) => self.log(LogLevel::Error, format!("Failed to update history: {}", e)),
+        }
+    }
+
+    /// Send the most recently sent request again, as it was sent: from its tab while that
+    /// is still open, otherwise from the newest history entry. A tab edited since the
+    /// send keeps its edits; the sent copy goes out from a new tab instead.
+    pub async fn resend_last_request(&mut self) -> Result<()> {
+        let last_sent_key = self.http_state.last_sent_key.clone();
+        let open_tab = last_sent_key
+            .as_deref()
+            .and_then(|key| self.http_state.tab_index(key));
+        if let Some(index) = open_tab {
+            if index != self.http_state.active_tab {
+                self.http_state.switch_to_tab(index);
+                self.after_request_tab_change();
+            }
+            let sent = last_sent_key
+                .and_then(|key| self.http_state.sent_requests.get(&key))
+                .filter(|sent| !sent.sends_same_as(&self.http_state.current_request))
+                .cloned();
+            if let Some(sent) = sent {
+                self.http_state.new_request();
+                let key = self.http_state.current_request_key.clone();
+                self.http_state.load_request(&key, sent);
+                self.log(
+                    LogLevel::Info,
+                    "Re-sending the request as it was sent, from a new tab (its tab was edited since)",
+                );
+            }
+        } else if let Some(entry) = self.history_entries.last() {
+            let request = entry.request.clone();
+            self.load_history_request(request);
+        } else {
+            self.log(LogLevel::Warn, "Nothing has been sent yet");
+            return Ok(());
+        }
+        self.send_http_request().await
+    }
+
+    /// Re-send the `position`-th (0-based) send listed in the palette
+    pub async fn resend_history_palette_entry(&mut self, position: usize) -> Result<()> {
+        if position >= self.history_palette_results.len() {
+            return Ok(());
+        }
+        self.history_palette_selected = position;
+        self.open_history_palette_selection(true).await
+    }
+
+    pub fn show_history_palette(&mut self) {
+        self.history_palette_query.clear();
+        self.update_history_palette_results();
+        self.show_history_palette = true;
+    }
+
+    pub fn hide_history_palette(&mut self) {
+        self.show_history_palette = false;
+        self.history_palette_results.clear();
+    }
+
+    pub fn history_palette_add_char(&mut self, c: char) {
+        self.history_palette_query.push(c);
+        self.update_history_palette_results();
+    }
+
+    pub fn history_palette_backspace(&mut self) {
+        self.history_palette_query.pop();
+        self.update_history_palette_results();
+    }
+
+    fn update_history_palette_results(&mut self) {
+        self.history_palette_results =
+            search_recent_sends(&self.history_entries, &self.history_palette_query);
+        self.history_palette_selected = 0;
+    }
+
+    pub fn history_palette_next(&mut self) {
+        let count = self.history_palette_results.len();
+        if count > 0 {
+            self.history_palette_selected = (self.history_palette_selected + 1) % count;
+        }
+    }
+
+    pub fn history_palette_previous(&mut self) {
+        let count = self.history_palette_results.len();
+        if count > 0 {
+            self.history_palette_selected = (self.history_palette_selected + count - 1) % count;
+        }
+    }
+
+    /// Load the highlighted send into the editor, sending it again when `send` is set
+    pub async fn open_history_palette_selection(&mut self, send: bool) -> Result<()> {
+        let request = self
+            .history_palette_results
+            .get(self.history_palette_selected)
+            .and_then(|&index| self.history_entries.get(index))
+            .map(|entry| entry.request.clone());
+        let Some(request) = request else {
+            return Ok(());
+        };
+        self.hide_history_palette();
+        self.load_history_request(request);
+        if send {
+            self.send_http_request().await?;
+        }
+        Ok(())
+    }
+
+    /// Diff the shown response against the previous response of the same request
+    pub fn diff_with_previous_response(&mut self) {
+        let Some(current) = &self.http_state.last_response else {
+            self.log(LogLevel::Warn, "No response to compare");
+            return;
+        };
+        let Some(previous) = self
+            .http_state
+            .previous_responses
+            .get(&self.http_state.current_request_key)
+        else {
+            self.log(
+                LogLevel::Warn,
+                "No earlier response for this request - send it again, or compare with history (Ctrl+R, c)",
+            );
+            return;
+        };
+
+        let diff = ResponseDiff::new(
+            format!(
+                "Previous: {} {} ({} ms)",
+                previous.status_code, previous.status_text, previous.duration_ms
+            ),
+            &format_response_body(&previous.body, &previous.content_type),
+            format!(
+                "Current: {} {} ({} ms)",
+                current.status_code, current.status_text, current.duration_ms
+            ),
+            &format_response_body(&current.body, &current.content_type),
+        );
+        self.show_response_diff(diff);
+    }
+
+    /// Diff the shown response against the highlighted history entry
+    pub fn diff_with_history_entry(&mut self) {
+        let Some(current) = &self.http_state.last_response else {
+            self.log(LogLevel::Warn, "No current response to compare with");
+            return;
+        };
+        let Some(entry) = self.selected_history_entry() else {
+            return;
+        };
+        let Some(body) = &entry.response_body else {
+            self.log(
+                LogLevel::Warn,
+                "That history entry has no stored body (failed or too large)",
+            );
+            return;
+        };
+
+        let diff = ResponseDiff::new(
+            format!(
+                "History {}: {} {} ({} ms)",
+                self.format_timestamp_with_date(&entry.sent_at),
+                entry.status_code.unwrap_or_default(),
+                entry.status_text,
+                entry.duration_ms
+            ),
+            &format_response_body(body, &entry.content_type),
+            format!(
+                "Current: {} {} ({} ms)",
+                current.status_code, current.status_text, current.duration_ms
+            ),
+            &format_response_body(&current.body, &current.content_type),
+        );
+        self.hide_history_dialog();
+        self.show_response_diff(diff);
+    }
+
+    fn show_response_diff(&mut self, diff: ResponseDiff) {
+        let (added, removed, changed) = diff.summary();
+        self.log(
+            LogLevel::Info,
+            if diff.is_identical() {
+                "Responses are identical".to_string()
+            } else {
+                format!(
+                    "Response diff: {} added, {} removed, {} changed",
+                    added, removed, changed
+                )
+            },
+        );
+        // Start at the first difference
+        let starts_changed = diff
+            .lines
+            .first()
+            .is_some_and(|line| line.kind != DiffKind::Unchanged);
+        self.response_diff_scroll = if starts_changed {
+            0
+        } else {
+            diff.next_change(0, true).unwrap_or(0)
+        };
+        self.response_diff = Some(diff);
+    }
+
+    pub fn hide_response_diff(&mut self) {
+        self.response_diff = None;
+        self.response_diff_scroll = 0;
+        self.log(LogLevel::Debug, "Response diff closed");
+    }
+
+    pub fn scroll_response_diff(&mut self, lines: isize) {
+        let Some(diff) = &self.response_diff else {
+            return;
+        };
+        let max = diff.lines.len().saturating_sub(1);
+        self.response_diff_scroll = self
+            .response_diff_scroll
+            .saturating_add_signed(lines)
+            .min(max);
+    }
+
+    /// Jump to the next (or previous) block of differences
+    pub fn jump_response_diff_change(&mut self, forward: bool) {
+        let Some(diff) = &self.response_diff else {
+            return;
+        };
+        if let Some(index) = diff.next_change(self.response_diff_scroll, forward) {
+            self.response_diff_scroll = index;
+        }
+    }
+
+    /// Send every request in the focused folder (or the focused request's folder) in order
+    pub fn run_collection_folder(&mut self) {
+        if self
+            .collection_run
+            .as_ref()
+            .is_some_and(|run| !run.finished)
+        {
+            self.log(LogLevel::Warn, "A collection run is already in progress");
+            self.show_collection_run_results();
+            return;
+        }
+
+        let Some(node) = self.tree_state.get_focused_node() else {
+            self.log(
+                LogLevel::Warn,
+                "Focus a folder in the collections tree to run it",
+            );
+            return;
+        };
+        let folder = match node.node_type {
+            NodeType::Folder => node.path.clone(),
+            NodeType::Template => node
+                .path
+                .rfind('/')
+                .map(|pos| node.path[..pos].to_string())
+                .unwrap_or_default(),
+        };
+
+        let names = match self
+            .http_collection_storage
+            .list_requests_in_folder(&folder)
+        {
+            Ok(names) => names,
+            Err(e) => {
+                self.log(LogLevel::Error, format!("Failed to list requests: {}", e));
+                return;
+            }
+        };
+
+        // Every request of the run sits in the same folder, so inherits the same
+        let inheritance = self.http_collection_storage.folder_inheritance(&folder);
+        let mut requests = Vec::new();
+        for name in names {
+            let path = if folder.is_empty() {
+                name.clone()
+            } else {
+                format!("{}/{}", folder, name)
+            };
+            match self.http_collection_storage.read_request(&folder, &name) {
+                Ok(mut stored) => {
+                    inheritance.apply_auth(&mut stored.request);
+                    requests.push((path, stored.request));
+                }
+                Err(e) => self.log(
+                    LogLevel::Warn,
+                    format!("Skipping '{}' in the run: {}", path, e),
+                ),
+            }
+        }
+
+        let folder_label = if folder.is_empty() {
+            "collections root".to_string()
+        } else {
+            folder.clone()
+        };
+        if requests.is_empty() {
+            self.log(
+                LogLevel::Warn,
+                format!("No requests to run in {}", folder_label),
+            );
+            return;
+        }
+
+        let environment_name = self.config.active_environment_name().to_string();
+        let paths = requests.iter().map(|(path, _)| path.clone()).collect();
+        let detail = format!(
+            "collection run of {} ({} request(s))",
+            folder_label,
+            requests.len()
+        );
+        self.audit_production_send(&environment_name, paths, detail);
+
+        self.collection_run_generation += 1;
+        self.collection_run = Some(CollectionRun::new(folder_label.clone(), requests.len()));
+        self.show_collection_run_results();
+        self.log(
+            LogLevel::Info,
+            format!(
+                "▶ Running {} request(s) in {}...",
+                requests.len(),
+                folder_label
+            ),
+        );
+
+        let generation = self.collection_run_generation;
+        // Variables extracted by one request are available to the ones after it
+        let mut environment = self.http_state.environment_vars.clone();
+        inheritance.apply_variables(&mut environment);
+        let environment = Arc::new(std::sync::Mutex::new(environment));
+        let session = self.http_session();
+        let sender = self.message_sender.clone();
+        tokio::spawn(async move {
+            let result_sender = sender.clone();
+            let script_sender = sender.clone();
+            let send = move |request: HttpRequest| {
+                let environment = environment.clone();
+                let session = session.clone();
+                let script_sender = script_sender.clone();
+                async move {
+                    let snapshot = environment.lock().unwrap().clone();
+                    let (_, response) =
+                        send_prepared_request(request, &snapshot, &session, &script_sender).await?;
+                    apply_variables(&mut environment.lock().unwrap(), &response.extracted);
+                    Ok(response)
+                }
+            };
+            run_requests(requests, send, move |result| {
+                let _ = result_sender.send(AppMessage::CollectionRunResult { generation, result });
+            })
+            .await;
+            let _ = sender.send(AppMessage::CollectionRunComplete { generation });
+        });
+    }
+
+    /// Mark the current run finished and log its pass/fail summary
+    fn finish_collection_run(&mut self) {
+        let Some(run) = &mut self.collection_run else {
+            return;
+        };
+        run.finished = true;
+
+        let summary = format!(
+            "{}: {} passed, {} failed in {} ms",
+            run.folder,
+            run.passed_count(),
+            run.failed_count(),
+            run.total_duration_ms()
+        );
+        let level = if run.failed_count() == 0 {
+            LogLevel::Success
+        } else {
+            LogLevel::Warn
+        };
+        self.note_task_finished(AppMode::Http, format!("collection run {}", summary));
+        self.log(level, format!("Collection run finished - {}", summary));
+    }
+
+    /// Show the results of the latest collection run
+    pub fn show_collection_run_results(&mut self) {
+        if self.collection_run.is_none() {
+            self.log(
+                LogLevel::Info,
+                "No collection run yet - press R on a folder",
+            );
+            return;
+        }
+        self.show_collection_run = true;
+        self.collection_run_selected = 0;
+        self.log(LogLevel::Debug, "Collection run results opened");
+    }
+
+    pub fn hide_collection_run_results(&mut self) {
+        self.show_collection_run = false;
+        self.log(LogLevel::Debug, "Collection run results closed");
+    }
+
+    pub fn collection_run_next(&mut self) {
+        let count = self
+            .collection_run
+            .as_ref()
+            .map_or(0, |run| run.results.len());
+        if self.collection_run_selected + 1 < count {
+            self.collection_run_selected += 1;
+        }
+    }
+
+    pub fn collection_run_previous(&mut self) {
+        self.collection_run_selected = self.collection_run_selected.saturating_sub(1);
+    }
+
+    /// Open the highlighted result's request in the form
+    pub async fn open_collection_run_result(&mut self) -> Result<()> {
+        let Some(path) = self
+            .collection_run
+            .as_ref()
+            .and_then(|run| run.results.get(self.collection_run_selected))
+            .map(|result| result.path.clone())
+        else {
+            return Ok(());
+        };
+
+        self.hide_collection_run_results();
+        self.load_http_request_into_form(&path).await?;
+        self.focus_pane(FocusedPane::Form);
+        Ok(())
+    }
+
+    pub fn show_load_test_dialog(&mut self) {
+        self.show_load_test = true;
+        self.log(LogLevel::Debug, "Load test opened");
+    }
+
+    /// Close the popup; a running test carries on in the background
+    pub fn hide_load_test_dialog(&mut self) {
+        self.show_load_test = false;
+        self.log(LogLevel::Debug, "Load test closed");
+    }
+
+    pub fn load_test_running(&self) -> bool {
+        self.load_test.as_ref().is_some_and(|test| !test.finished())
+    }
+
+    pub fn load_test_next_field(&mut self) {
+        self.load_test_field = (self.load_test_field + 1) % 2;
+    }
+
+    fn load_test_field_mut(&mut self) -> (&mut usize, usize) {
+        let settings = &mut self.load_test_settings;
+        if self.load_test_field == 0 {
+            (&mut settings.iterations, MAX_ITERATIONS)
+        } else {
+            (&mut settings.concurrency, MAX_CONCURRENCY)
+        }
+    }
+
+    /// Type a digit into the setting being edited
+    pub fn load_test_add_digit(&mut self, digit: u32) {
+        let (value, max) = self.load_test_field_mut();
+        *value = (*value * 10 + digit as usize).min(max);
+    }
+
+    pub fn load_test_backspace(&mut self) {
+        let (value, _) = self.load_test_field_mut();
+        *value /= 10;
+    }
+
+    /// Step the setting being edited up or down, staying between 1 and its maximum
+    pub fn load_test_adjust(&mut self, delta: isize) {
+        let (value, max) = self.load_test_field_mut();
+        *value = value.saturating_add_signed(delta).clamp(1, max);
+    }
+
+    /// Send the current request `iterations` times, `concurrency` at a time
+    pub fn start_load_test(&mut self) {
+        if self.load_test_running() {
+            self.log(LogLevel::Warn, "A load test is already running");
+            return;
+        }
+        if !self.http_state.is_valid() {
+            for error in self.http_state.get_validation_errors() {
+                self.log(LogLevel::Error, error);
+            }
+            return;
+        }
+        let settings = LoadTestSettings {
+            iterations: self.load_test_settings.iterations.clamp(1, MAX_ITERATIONS),
+            concurrency: self
+                .load_test_settings
+                .concurrency
+                .clamp(1, MAX_CONCURRENCY),
+        };
+        self.load_test_settings = settings;
+
+        let mut request = self.http_state.current_request.clone();
+        let mut environment = self.http_state.environment_vars.clone();
+        let inheritance = self.current_folder_inheritance();
+        inheritance.apply_auth(&mut request);
+        inheritance.apply_variables(&mut environment);
+        // Measure the endpoint itself: no assertions, extraction or retries per send
+        request.tests.clear();
+        request.extract.clear();
+        request.settings.retry.max_attempts = 1;
+
+        let environment_name = self.config.active_environment_name().to_string();
+        let paths = vec![self.http_state.current_request_key.clone()];
+        let detail = format!(
+            "load test {}x{} {} {}",
+            settings.iterations,
+            settings.concurrency,
+            request.method.as_str(),
+            request.url
+        );
+        self.audit_production_send(&environment_name, paths, detail);
+
+        self.load_test_generation += 1;
+        self.load_test = Some(LoadTest::new(&request, settings));
+        self.log(
+            LogLevel::Info,
+            format!(
+                "🔥 Load testing '{}': {} request(s), {} at a time...",
+                request.name, settings.iterations, settings.concurrency
+            ),
+        );
+
+        let generation = self.load_test_generation;
+        let session = self.http_session();
+        let sender = self.message_sender.clone();
+        let task = tokio::spawn(async move {
+            let script_sender = sender.clone();
+            let sample_sender = sender.clone();
+            let environment = Arc::new(environment);
+            let send = move |request: HttpRequest| {
+                let environment = environment.clone();
+                let session = session.clone();
+                let script_sender = script_sender.clone();
+                async move {
+                    send_prepared_request(request, &environment, &session, &script_sender)
+                        .await
+                        .map(|(_, response)| response)
+                }
+            };
+            run_load_test(request, settings, send, move |sample| {
+                let _ = sample_sender.send(AppMessage::LoadTestSample { generation, sample });
+            })
+            .await;
+            let _ = sender.send(AppMessage::LoadTestComplete { generation });
+        });
+        self.load_test_task = Some(task.abort_handle());
+    }
+
+    /// Stop a running load test, keeping the samples collected so far
+    pub fn cancel_load_test(&mut self) {
+        if !self.load_test_running() {
+            return;
+        }
+        if let Some(task) = self.load_test_task.take() {
+            task.abort();
+        }
+        // Samples still queued from the aborted test are dropped
+        self.load_test_generation += 1;
+        self.finish_load_test(true);
+    }
+
+    /// Mark the load test finished and log its summary
+    fn finish_load_test(&mut self, cancelled: bool) {
+        self.load_test_task = None;
+        let Some(test) = &mut self.load_test else {
+            return;
+        };
+        test.finish(cancelled);
+
+        let summary = format!(
+            "'{}': {} sent, p50 {} ms, p95 {} ms, p99 {} ms, {:.1}% errors, {:.1} req/s",
+            test.request_name,
+            test.samples.len(),
+            test.percentile(50.0).unwrap_or_default(),
+            test.percentile(95.0).unwrap_or_default(),
+            test.percentile(99.0).unwrap_or_default(),
+            test.error_rate() * 100.0,
+            test.throughput()
+        );
+        let level = if cancelled || test.error_count() > 0 {
+            LogLevel::Warn
+        } else {
+            LogLevel::Success
+        };
+        let verb = if cancelled { "cancelled" } else { "finished" };
+        self.note_task_finished(AppMode::Http, format!("load test {}", summary));
+        self.log(level, format!("Load test {} - {}", verb, summary));
+    }
+
+    /// Put a destructive or sensitive action on the audit record
+    fn audit(&mut self, action: AuditAction, paths: Vec<String>, detail: impl Into<String>) {
+        if let Err(e) = self
+            .audit_log
+            .append(&AuditEntry::new(action, paths, detail))
+        {
+            self.log(
+                LogLevel::Warn,
+                format!("Failed to write the audit log: {:#}", e),
+            );
+        }
+    }
+
+    /// Put a send to a production environment on the audit record, whichever way it went
+    /// out: a single send, all pages, a collection run or a load test
+    fn audit_production_send(&mut self, environment_name: &str, paths: Vec<String>, what: String) {
+        if is_production_environment(environment_name) {
+            let detail = format!("{} ({})", what, environment_name);
+            self.audit(AuditAction::ProductionSend, paths, detail);
+        }
+    }
+
+    /// Open the audit log dialog on its most recent entries
+    pub fn show_audit_log(&mut self) {
+        match self.audit_log.read_recent(AUDIT_LOG_DIALOG_ENTRIES) {
+            Ok(entries) => {
+                self.audit_log_entries = Some(entries);
+                self.audit_log_scroll = 0;
+                self.log(LogLevel::Debug, "Audit log opened");
+            }
+            Err(e) => self.log(
+                LogLevel::Error,
+                format!("Failed to read the audit log: {:#}", e),
+            ),
+        }
+    }
+
+    pub fn hide_audit_log(&mut self) {
+        self.audit_log_entries = None;
+        self.log(LogLevel::Debug, "Audit log closed");
+    }
+
+    /// Scroll the audit log dialog; it lists the newest entry first
+    pub fn scroll_audit_log(&mut self, down: bool) {
+        let len = self.audit_log_entries.as_ref().map_or(0, Vec::len);
+        self.audit_log_scroll = if down {
+            (self.audit_log_scroll + 1).min(len.saturating_sub(1))
+        } else {
+            self.audit_log_scroll.saturating_sub(1)
+        };
+    }
+
+    pub fn show_transfer_stats(&mut self) {
+        self.show_transfer_stats = true;
+        self.log(LogLevel::Debug, "Transfer stats opened");
+    }
+
+    pub fn hide_transfer_stats(&mut self) {
+        self.show_transfer_stats = false;
+        self.log(LogLevel::Debug, "Transfer stats closed");
+    }
+
+    pub fn show_import_dialog(&mut self) {
+        if self.refuse_in_safe_mode("Importing") {
+            return;
+        }
+        self.show_import_dialog = true;
+        self.import_dialog_format = match (&self.current_mode, self.import_dialog_format) {
+            (AppMode::Automation, _) => ImportFormat::TemplateBundle,
+            (AppMode::Http, ImportFormat::TemplateBundle) => ImportFormat::Postman,
+            (AppMode::Http, format) => format,
+        };
+        self.import_dialog_file_path.clear();
+        self.import_dialog_error = None;
+        self.import_dialog_preview = None;
+        self.log(LogLevel::Debug, "Import dialog opened");
+    }
+
+    /// Hide the import dialog
+    pub fn hide_import_dialog(&mut self) {
+        self.show_import_dialog = false;
+        self.import_dialog_file_path.clear();
+        self.import_dialog_error = None;
+        self.import_dialog_preview = None;
+        self.log(LogLevel::Debug, "Import dialog closed");
+    }
+
+    /// Update file path and validate/preview
+    pub fn update_import_file_path(&mut self, file_path: String) {
+        self.import_dialog_file_path = file_path;
+        self.import_dialog_error = None;
+        self.import_dialog_preview = None;
+
+        if let Some(format) = self.detect_import_format()
+            && format != self.import_dialog_format
+        {
+            self.import_dialog_format = format;
+            self.log(
+                LogLevel::Debug,
+                format!("Detected import format: {}", format.title()),
+            );
+        }
+
+        if !self.import_dialog_file_path.trim().is_empty() {
+            // Validate and preview the file
+            match self.validate_and_preview_collection(&self.import_dialog_file_path) {
+                Ok(preview) => {
+                    self.import_dialog_preview = Some(preview);
+                }
+                Err(error) => {
+                    self.import_dialog_error = Some(error);
+                }
+            }
+        }
+    }
+
+    /// Recognise an Insomnia export or a Bruno collection folder at the typed path
+    fn detect_import_format(&self) -> Option<ImportFormat> {
+        if self.import_dialog_format == ImportFormat::TemplateBundle {
+            return None;
+        }
+        let path = std::path::Path::new(&self.import_dialog_file_path);
+        if is_bruno_collection(path) {
+            return Some(ImportFormat::Bruno);
+        }
+
+        // Large files are Postman dumps; don't read them on every keystroke
+        let size = path.metadata().ok().filter(|m| m.is_file())?.len();
+        if size >= LARGE_IMPORT_BYTES {
+            return None;
+        }
+        let content = std::fs::read_to_string(path).ok()?;
+        looks_like_insomnia_export(&content).then_some(ImportFormat::Insomnia)
+    }
+
+    /// Validate file and create preview
+    fn validate_and_preview_collection(
+        &self,
+        file_path: &str,
+    ) -> Result<CollectionPreview, String> {
+        use std::path::Path;
+
+        let path = Path::new(file_path);
+
+        // Check if file exists
+        if !path.exists() {
+            return Err("File does not exist".to_string());
+        }
+
+        if self.import_dialog_format == ImportFormat::Bruno {
+            let collection = parse_bruno_collection(path)
+                .map_err(|e| format!("Invalid Bruno collection: {:#}", e))?;
+            return Ok(self.preview_parsed_collection(collection));
+        }
+
+        // Check if it's a file
+        if !path.is_file() {
+            return Err("Path is not a file".to_string());
+        }
+
+        // Parsing a huge collection on every keystroke would freeze the dialog
+        let size = path.metadata().map(|m| m.len()).unwrap_or(0);
+        if self.import_dialog_format == ImportFormat::Postman && size >= LARGE_IMPORT_BYTES {
+            let info = read_postman_info(path)
+                .map_err(|e| format!("Invalid Postman collection format: {:#}", e))?;
+            return Ok(CollectionPreview {
+                name: info.name,
+                request_count: 0,
+                folder_count: 0,
+                description: info.description,
+                counts_known: false,
+            });
+        }
+
+        // Try to read and parse the file
+        let json_content =
+            std::fs::read_to_string(path).map_err(|e| format!("Failed to read file: {}", e))?;
+
+        if self.import_dialog_format == ImportFormat::TemplateBundle {
+            let bundle = import_bundle(&json_content).map_err(|e| format!("{:#}", e))?;
+            return Ok(CollectionPreview {
+                name: bundle.template.name,
+                request_count: bundle.template.field_values.len(),
+                folder_count: 0,
+                description: Some(format!(
+                    "{} ({}) • script {}",
+                    bundle.website.name, bundle.website.url, bundle.script
+                )),
+                counts_known: true,
+            });
+        }
+
+        if self.import_dialog_format == ImportFormat::OpenApi {
+            let spec = crate::services::openapi_import::parse_openapi_spec(&json_content)
+                .map_err(|e| format!("Invalid OpenAPI spec: {:#}", e))?;
+            return Ok(CollectionPreview {
+                name: spec.title.clone(),
+                request_count: spec.request_count(),
+                folder_count: spec.folder_count(),
+                description: spec.description,
+                counts_known: true,
+            });
+        }
+
+        if self.import_dialog_format == ImportFormat::Insomnia {
+            let collection = parse_insomnia_export(&json_content)
+                .map_err(|e| format!("Invalid Insomnia export: {:#}", e))?;
+            return Ok(self.preview_parsed_collection(collection));
+        }
+
+        // Parse as Postman collection
+        let collection: crate::services::http_collection_storage::PostmanCollection =
+            serde_json::from_str(&json_content)
+                .map_err(|e| format!("Invalid Postman collection format: {}", e))?;
+
+        // Count requests and folders
+        let (request_count, folder_count) = self.count_collection_items(&collection.item);
+
+        Ok(CollectionPreview {
+            name: collection.info.name,
+            request_count,
+            folder_count,
+            description: collection.info.description,
+            counts_known: true,
+        })
+    }
+
+    /// Preview of a collection converted from another tool
+    fn preview_parsed_collection(
+        &self,
+        collection: crate::services::http_collection_storage::PostmanCollection,
+    ) -> CollectionPreview {
+        let (request_count, folder_count) = self.count_collection_items(&collection.item);
+        CollectionPreview {
+            name: collection.info.name,
+            request_count,
+            folder_count,
+            description: collection.info.description,
+            counts_known: true,
+        }
+    }
+
+    /// Recursively count requests and folders in collection items
+    fn count_collection_items(
+        &self,
+        items: &[crate::services::http_collection_storage::PostmanItem],
+    ) -> (usize, usize) {
+        let mut request_count = 0;
+        let mut folder_count = 0;
+
+        for item in items {
+            match item {
+                crate::services::http_collection_storage::PostmanItem::Request(_) => {
+                    request_count += 1;
+                }
+                crate::services::http_collection_storage::PostmanItem::Folder(folder) => {
+                    folder_count += 1;
+                    let (sub_requests, sub_folders) = self.count_collection_items(&folder.item);
+                    request_count += sub_requests;
+                    folder_count += sub_folders;
+                }
+            }
+        }
+
+        (request_count, folder_count)
+    }
+
+    /// Execute the import
+    pub async fn execute_import(&mut self) -> Result<()> {
+        if self.import_progress.is_some() {
+            return Ok(());
+        }
+        if self.import_dialog_preview.is_none() {
+            self.import_dialog_error = Some("No valid collection to import".to_string());
+            return Ok(());
+        }
+
+        let file_path = std::path::PathBuf::from(&self.import_dialog_file_path);
+        if self.import_dialog_format == ImportFormat::Postman {
+            self.start_postman_import(file_path);
+            return Ok(());
+        }
+        if self.import_dialog_format == ImportFormat::TemplateBundle {
+            match self.import_template_bundle(&file_path).await {
+                Ok(()) => self.hide_import_dialog(),
+                Err(e) => {
+                    self.import_dialog_error = Some(format!("Import failed: {:#}", e));
+                    self.log(LogLevel::Error, format!("Import failed: {:#}", e));
+                }
+            }
+            return Ok(());
+        }
+
+        let imported = match self.import_dialog_format {
+            ImportFormat::Insomnia | ImportFormat::Bruno => {
+                self.import_converted_collection(&file_path).await
+            }
+            _ => self.import_openapi_spec(&file_path).await,
+        };
+        match imported {
+            Ok(()) => {
+                let preview = self.import_dialog_preview.as_ref().unwrap();
+                self.log(
+                    LogLevel::Success,
+                    format!(
+                        "Successfully imported '{}' ({} requests, {} folders)",
+                        preview.name, preview.request_count, preview.folder_count
+                    ),
+                );
+                self.hide_import_dialog();
+                self.tutorial_event(TutorialEvent::CollectionImported);
+            }
+            Err(e) => {
+                self.import_dialog_error = Some(format!("Import failed: {}", e));
+                self.log(LogLevel::Error, format!("Import failed: {}", e));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Stream a Postman collection to disk on a background thread
+    fn start_postman_import(&mut self, file_path: std::path::PathBuf) {
+        self.import_generation += 1;
+        let generation = self.import_generation;
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.import_cancel = Arc::clone(&cancel);
+        self.import_dialog_error = None;
+        self.import_progress = Some(PostmanImportProgress {
+            total_bytes: file_path.metadata().map(|m| m.len()).unwrap_or(0),
+            ..Default::default()
+        });
+        self.log(
+            LogLevel::Info,
+            format!("Importing Postman collection: {}", file_path.display()),
+        );
+
+        let config = self.config.clone();
+        let sender = self.message_sender.clone();
+        tokio::task::spawn_blocking(move || {
+            let storage = HttpCollectionStorage::new(config);
+            let outcome = import_postman_streaming(
+                &storage,
+                &file_path,
+                cancel,
+                Some(POSTMAN_IMPORT_TIMEOUT),
+                |progress| {
+                    let _ = sender.send(AppMessage::PostmanImportProgress {
+                        generation,
+                        progress,
+                    });
+                },
+            )
+            .map_err(|e| format!("{:#}", e));
+            let _ = sender.send(AppMessage::PostmanImportFinished {
+                generation,
+                outcome,
+            });
+        });
+    }
+
+    /// Ask the running Postman import to stop
+    pub fn cancel_postman_import(&mut self) {
+        if self.import_progress.is_none() {
+            return;
+        }
+        self.import_cancel.store(true, Ordering::Relaxed);
+        self.log(LogLevel::Warn, "Cancelling import...");
+    }
+
+    async fn finish_postman_import(
+        &mut self,
+        outcome: Result<PostmanImportProgress, String>,
+    ) -> Result<()> {
+        self.import_progress = None;
+        let name = self
+            .import_dialog_preview
+            .as_ref()
+            .map(|preview| preview.name.clone())
+            .unwrap_or_default();
+
+        match outcome {
+            Ok(progress) => {
+                self.log(
+                    LogLevel::Success,
+                    format!(
+                        "Successfully imported '{}' ({} requests, {} folders)",
+                        name, progress.requests, progress.folders
+                    ),
+                );
+                self.note_task_finished(AppMode::Http, "import finished");
+                self.hide_import_dialog();
+                self.tutorial_event(TutorialEvent::CollectionImported);
+            }
+            Err(error) if self.import_cancel.load(Ordering::Relaxed) => {
+                self.log(LogLevel::Warn, error.clone());
+                self.import_dialog_error = Some(error);
+            }
+            Err(error) => {
+                self.log(LogLevel::Error, format!("Import failed: {}", error));
+                self.import_dialog_error = Some(format!("Import failed: {}", error));
+            }
+        }
+
+        // A cancelled or failed import may still have written some requests
+        self.refresh_tree_from_storage().await
+    }
+
+    /// Add character to file path
+    pub fn import_dialog_add_char(&mut self, c: char) {
+        self.import_dialog_file_path.push(c);
+        self.update_import_file_path(self.import_dialog_file_path.clone());
+    }
+
+    /// Remove character from file path
+    pub fn import_dialog_backspace(&mut self) {
+        self.import_dialog_file_path.pop();
+        self.update_import_file_path(self.import_dialog_file_path.clone());
+    }
+
+    /// Clear file path
+    pub fn import_dialog_clear(&mut self) {
+        self.import_dialog_file_path.clear();
+        self.update_import_file_path(self.import_dialog_file_path.clone());
+    }
+
+    /// Switch between the collection formats and re-validate the current path
+    pub fn cycle_import_format(&mut self) {
+        self.import_dialog_format = self.import_dialog_format.next();
+        self.update_import_file_path(self.import_dialog_file_path.clone());
+        self.log(
+            LogLevel::Debug,
+            format!("Import format: {}", self.import_dialog_format.title()),
+        );
+    }
+
+    /// Suggest common file paths
+    pub fn import_dialog_suggest_path(&mut self) {
+        // Try to suggest common locations
+        if let Some(home_dir) = dirs::home_dir() {
+            let suggestions = [
+                home_dir.join("Downloads").join("collection.json"),
+                home_dir.join("Desktop").join("collection.json"),
+                std::path::PathBuf::from("./collection.json"),
+            ];
+
+            for suggestion in &suggestions {
+                if suggestion.exists() {
+                    self.import_dialog_file_path = suggestion.to_string_lossy().to_string();
+                    self.update_import_file_path(self.import_dialog_file_path.clone());
+                    self.log(
+                        LogLevel::Info,
+                        format!("Found collection at: {}", suggestion.display()),
+                    );
+                    return;
+                }
+            }
+        }
+
+        // If no suggestions found, provide a template
+        if let Some(home_dir) = dirs::home_dir() {
+            self.import_dialog_file_path = home_dir
+                .join("Downloads")
+                .join("my-collection.json")
+                .to_string_lossy()
+                .to_string();
+            self.update_import_file_path(self.import_dialog_file_path.clone());
+        }
+    }
+
+    /// Show the settings dialog, pre-filled with the current directories
+    pub fn show_settings_dialog(&mut self) {
+        self.show_settings_dialog = true;
+        self.settings_dialog_templates_dir =
+            self.config.get_templates_directory().display().to_string();
+        self.settings_dialog_collections_dir = self
+            .config
+            .get_collections_directory()
+            .display()
+            .to_string();
+        self.settings_dialog_move_data = true;
+        self.settings_dialog_check_updates = self.config.check_for_updates;
+        self.settings_dialog_auto_format_json = self.config.auto_format_json;
+        self.settings_dialog_block_invalid_json = self.config.block_invalid_json;
+        self.settings_dialog_timestamp_format = self.config.timestamp_format;
+        self.settings_dialog_history_retention = self.config.history_retention;
+        self.settings_dialog_storage_format = self.config.storage_format;
+        self.settings_dialog_persist_cookies = self.config.persist_cookies;
+        self.settings_dialog_request_log_secrets = self.config.request_log_secrets;
+        self.settings_dialog_auto_lock = self.config.auto_lock;
+        self.settings_dialog_response_limit = self.config.response_display_limit;
+        self.settings_dialog_retry = self.config.retry;
+        self.settings_dialog_backup_schedule = self.config.backup_schedule;
+        self.settings_dialog_focused_field = 0;
+        self.settings_dialog_error = None;
+        self.log(LogLevel::Debug, "Settings dialog opened");
+    }
+
+    /// Hide the settings dialog
+    pub fn hide_settings_dialog(&mut self) {
+        self.show_settings_dialog = false;
+        self.settings_dialog_templates_dir.clear();
+        self.settings_dialog_collections_dir.clear();
+        self.settings_dialog_error = None;
+        self.log(LogLevel::Debug, "Settings dialog closed");
+    }
+
+    /// Show the environment variables dialog
+    pub fn show_environment_dialog(&mut self) {
+        self.show_environment_dialog = true;
+        self.environment_editor.reset();
+        self.log(LogLevel::Debug, "Environment dialog opened");
+    }
+
+    /// Hide the environment variables dialog and persist the variables
+    pub fn hide_environment_dialog(&mut self) {
+        self.show_environment_dialog = false;
+        self.environment_editor.reset();
+        self.save_environment_variables();
+        self.log(LogLevel::Debug, "Environment dialog closed");
+    }
+
+    /// Flag or unflag the selected environment variable as secret
+    pub fn toggle_environment_variable_secret(&mut self) {
+        let selected = self.environment_editor.selected;
+        let Some(variable) = self.http_state.environment_vars.get_mut(selected) else {
+            return;
+        };
+        variable.secret = !variable.secret;
+        let (name, secret) = (variable.name.clone(), variable.secret);
+        self.save_environment_variables();
+        self.log(
+            LogLevel::Info,
+            format!(
+                "🔒 '{}' is {}",
+                name,
+                if secret {
+                    "now secret"
+                } else {
+                    "no longer secret"
+                }
+            ),
+        );
+    }
+
+    /// Show or mask secret values in the request editor and response viewer
+    pub fn toggle_reveal_secrets(&mut self) {
+        self.reveal_secrets = !self.reveal_secrets;
+        self.log(
+            LogLevel::Info,
+            if self.reveal_secrets {
+                "🔓 Secrets revealed (Alt+s masks them again)"
+            } else {
+                "🔒 Secrets masked"
+            },
+        );
+    }
+
+    /// Values masked in the UI and scrubbed from logs: variables flagged secret in any
+    /// environment and the credentials of the request in the editor, with the values of
+    /// the variables they reference
+    pub fn secret_values(&self) -> Vec<String> {
+        let variables = || {
+            self.http_state
+                .environment_vars
+                .iter()
+                .chain(self.config.environments.iter().flat_map(|e| &e.variables))
+        };
+        let mut secrets: Vec<String> = variables()
+            .filter(|variable| variable.secret)
+            .map(|variable| variable.value.clone())
+            .collect();
+
+        for credential in request_credentials(&self.http_state.current_request) {
+            if !credential.contains("{{") {
+                secrets.push(credential.to_string());
+                continue;
+            }
+            secrets.extend(
+                variables()
+                    .filter(|variable| credential.contains(&format!("{{{{{}}}}}", variable.name)))
+                    .map(|variable| variable.value.clone()),
+            );
+        }
+
+        secrets.retain(|secret| !secret.is_empty());
+        secrets.sort();
+        secrets.dedup();
+        secrets
+    }
+
+    /// The response with secret values masked, unless nothing needs masking or secrets are shown
+    fn masked_response(&self, response: &HttpResponse) -> Option<HttpResponse> {
+        if self.reveal_secrets {
+            return None;
+        }
+        let secrets = self.secret_values();
+        let body = mask_secrets(&response.body, &secrets);
+        let masked_header = response
+            .headers
+            .iter()
+            .any(|header| matches!(mask_secrets(&header.value, &secrets), Cow::Owned(_)));
+        if matches!(body, Cow::Borrowed(_)) && !masked_header {
+            return None;
+        }
+        let mut masked = response.clone();
+        masked.body = body.into_owned();
+        for header in &mut masked.headers {
+            header.value = mask_secrets(&header.value, &secrets).into_owned();
+        }
+        Some(masked)
+    }
+
+    /// Persist the environment variables if they changed
+    fn save_environment_variables(&mut self) {
+        let stored = self.config.active_environment_variables_mut();
+        if *stored != self.http_state.environment_vars {
+            *stored = self.http_state.environment_vars.clone();
+            if let Err(e) = self.config.save() {
+                self.log(
+                    LogLevel::Error,
+                    format!("Failed to save environment variables: {}", e),
+                );
+            }
+        }
+    }
+
+    /// Make `name` the active environment, keeping the edits made to the current one
+    pub fn switch_environment(&mut self, name: &str) -> bool {
+        if name == self.config.active_environment_name() {
+            return true;
+        }
+        let Some(variables) = self.config.environment_variables_of(name).cloned() else {
+            return false;
+        };
+
+        *self.config.active_environment_variables_mut() = self.http_state.environment_vars.clone();
+        self.config.active_environment =
+            (name != DEFAULT_ENVIRONMENT_NAME).then(|| name.to_string());
+        self.http_state.environment_vars = variables;
+        self.environment_editor.reset();
+        // Safe mode switches for this session only; the choice isn't worth an error
+        if !crate::models::config::is_read_only_storage()
+            && let Err(e) = self.config.save()
+        {
+            self.log(
+                LogLevel::Error,
+                format!("Failed to save active environment: {}", e),
+            );
+        }
+        self.log(LogLevel::Info, format!("🌍 Environment: {}", name));
+        true
+    }
+
+    /// Open the environment switcher with the active environment highlighted
+    pub fn show_environment_switcher(&mut self) {
+        let active = self.config.active_environment_name();
+        self.environment_switcher_selected = self
+            .config
+            .environment_names()
+            .iter()
+            .position(|name| name == active)
+            .unwrap_or(0);
+        self.show_environment_switcher = true;
+    }
+
+    pub fn hide_environment_switcher(&mut self) {
+        self.show_environment_switcher = false;
+    }
+
+    pub fn environment_switcher_next(&mut self) {
+        let count = self.config.environment_names().len();
+        self.environment_switcher_selected = (self.environment_switcher_selected + 1) % count;
+    }
+
+    pub fn environment_switcher_previous(&mut self) {
+        let count = self.config.environment_names().len();
+        self.environment_switcher_selected =
+            (self.environment_switcher_selected + count - 1) % count;
+    }
+
+    /// Activate the environment at `index` in the switcher and close it
+    pub fn select_switcher_environment(&mut self, index: usize) {
+        let Some(name) = self.config.environment_names().get(index).cloned() else {
+            return;
+        };
+        self.hide_environment_switcher();
+        self.switch_environment(&name);
+    }
+
+    /// Variables of an environment as they are now; the active one may have unsaved edits
+    pub fn environment_variables_for(&self, name: &str) -> &[KeyValuePair] {
+        if name == self.config.active_environment_name() {
+            &self.http_state.environment_vars
+        } else {
+            self.config
+                .environment_variables_of(name)
+                .map_or(&[], Vec::as_slice)
+        }
+    }
+
+    /// Step through the environments in the environment dialog
+    pub fn cycle_environment(&mut self, forward: bool) {
+        let names = self.config.environment_names();
+        let current = names
+            .iter()
+            .position(|name| name == self.config.active_environment_name())
+            .unwrap_or(0);
+        let next = if forward {
+            (current + 1) % names.len()
+        } else {
+            (current + names.len() - 1) % names.len()
+        };
+        self.switch_environment(&names[next]);
+    }
+
+    /// Add an empty environment, switch to it and start naming it
+    pub fn create_environment(&mut self) {
+        let names = self.config.environment_names();
+        let name = (1..)
+            .map(|n| format!("env-{}", n))
+            .find(|name| !names.contains(name))
+            .unwrap_or_default();
+        self.config.environments.push(Environment {
+            name: name.clone(),
+            variables: Vec::new(),
+            proxy: ProxySettings::default(),
+        });
+        self.switch_environment(&name);
+        self.environment_name_input = Some(name);
+    }
+
+    /// Start renaming the active environment (the default one keeps its name)
+    pub fn start_environment_rename(&mut self) {
+        if self.config.active_environment.is_none() {
+            self.log(
+                LogLevel::Warn,
+                "The default environment can't be renamed; press n to add a named one",
+            );
+            return;
+        }
+        self.environment_name_input = Some(self.config.active_environment_name().to_string());
+    }
+
+    /// Finish typing an environment name; Esc (`apply` false) keeps the old one
+    pub fn finish_environment_rename(&mut self, apply: bool) {
+        let Some(input) = self.environment_name_input.take() else {
+            return;
+        };
+        let name = input.trim().to_string();
+        let current = self.config.active_environment_name().to_string();
+        if !apply || name == current {
+            return;
+        }
+        if name.is_empty() || self.config.environment_names().contains(&name) {
+            self.log(
+                LogLevel::Warn,
+                format!("'{}' is empty or already taken; name unchanged", name),
+            );
+            return;
+        }
+
+        if let Some(env) = self
+            .config
+            .environments
+            .iter_mut()
+            .find(|env| env.name == current)
+        {
+            env.name = name.clone();
+        }
+        self.config.active_environment = Some(name.clone());
+        if let Err(e) = self.config.save() {
+            self.log(
+                LogLevel::Error,
+                format!("Failed to save environments: {}", e),
+            );
+        }
+        self.log(
+            LogLevel::Info,
+            format!("Renamed environment '{}' to '{}'", current, name),
+        );
+    }
+
+    /// Start editing the active environment's proxy URL (the global proxy for the
+    /// default environment)
+    pub fn start_environment_proxy_edit(&mut self) {
+        self.environment_proxy_input = Some(self.config.active_proxy_mut().url.clone());
+    }
+
+    /// Finish typing a proxy URL; Esc (`apply` false) keeps the old one
+    pub fn finish_environment_proxy_edit(&mut self, apply: bool) {
+        let Some(input) = self.environment_proxy_input.take() else {
+            return;
+        };
+        let url = input.trim().to_string();
+        if !apply || url == self.config.active_proxy_mut().url {
+            return;
+        }
+        if !url.is_empty()
+            && !url.eq_ignore_ascii_case(DIRECT_PROXY)
+            && let Err(e) = crate::services::proxy::proxy_url(&url)
+        {
+            self.log(LogLevel::Warn, format!("{}; proxy unchanged", e));
+            return;
+        }
+
+        self.config.active_proxy_mut().url = url;
+        if let Err(e) = self.config.save() {
+            self.log(
+                LogLevel::Error,
+                format!("Failed to save environments: {}", e),
+            );
+        }
+        let environment = self.config.active_environment_name().to_string();
+        let proxy = self.config.active_proxy();
+        let shown = if proxy.is_empty() {
+            "none".to_string()
+        } else {
+            proxy.display_url()
+        };
+        self.log(
+            LogLevel::Info,
+            format!("Proxy for '{}' is now {}", environment, shown),
+        );
+    }
+
+    /// Delete the active named environment and fall back to the default one
+    pub fn delete_active_environment(&mut self) {
+        let Some(name) = self.config.active_environment.clone() else {
+            self.log(LogLevel::Warn, "The default environment can't be deleted");
+            return;
+        };
+        self.switch_environment(DEFAULT_ENVIRONMENT_NAME);
+        self.config.environments.retain(|env| env.name != name);
+        if let Err(e) = self.config.save() {
+            self.log(
+                LogLevel::Error,
+                format!("Failed to save environments: {}", e),
+            );
+        }
+        self.log(LogLevel::Info, format!("Deleted environment '{}'", name));
+    }
+
+    /// Activate the environment the folder of a just-loaded request is bound to
+    fn apply_folder_environment(&mut self, request_key: &str, folder_path: &str) {
+        self.folder_inheritance = self.http_collection_storage.folder_inheritance(folder_path);
+        self.folder_environment = self
+            .http_collection_storage
+            .folder_environment(folder_path)
+            .map(|(folder, environment)| FolderEnvironment {
+                request_key: request_key.to_string(),
+                folder,
+                environment,
+            });
+        let Some(binding) = self.folder_environment.clone() else {
+            return;
+        };
+        if binding.environment == self.config.active_environment_name() {
+            return;
+        }
+
+        if self.switch_environment(&binding.environment) {
+            self.log(
+                LogLevel::Info,
+                format!(
+                    "🌍 Switched to '{}', the environment of folder '{}'",
+                    binding.environment, binding.folder
+                ),
+            );
+        } else {
+            self.log(
+                LogLevel::Warn,
+                format!(
+                    "Folder '{}' wants environment '{}', which doesn't exist",
+                    binding.folder, binding.environment
+                ),
+            );
+        }
+    }
+
+    /// Folder auth and variables for the request in the editor, read fresh for sending
+    fn current_folder_inheritance(&self) -> FolderInheritance {
+        let key = &self.http_state.current_request_key;
+        if key.starts_with("draft-") {
+            return FolderInheritance::default();
+        }
+        let folder = key.rsplit_once('/').map_or("", |(folder, _)| folder);
+        self.http_collection_storage.folder_inheritance(folder)
+    }
+
+    /// Open the variables editor of the focused folder
+    pub fn show_folder_settings_dialog(&mut self) {
+        if self.refuse_in_safe_mode("Editing folder settings") {
+            return;
+        }
+        let Some(folder) = self
+            .tree_state
+            .get_focused_node()
+            .filter(|node| node.node_type == NodeType::Folder)
+            .map(|node| node.path.clone())
+        else {
+            self.log(
+                LogLevel::Info,
+                "Focus a folder to set the auth and variables its requests inherit",
+            );
+            return;
+        };
+
+        let settings = self.http_collection_storage.read_folder_settings(&folder);
+        self.folder_settings_dialog = Some(FolderSettingsDialog {
+            folder,
+            variables: settings.variables,
+            editor: KeyValueEditor::new(),
+            auth: settings.auth.unwrap_or(HttpAuth::None),
+        });
+    }
+
+    /// Close the folder variables editor and save the variables
+    pub fn hide_folder_settings_dialog(&mut self) {
+        let Some(dialog) = self.folder_settings_dialog.take() else {
+            return;
+        };
+        // Read fresh so the other settings (auth edited meanwhile, color) are kept
+        let mut settings = self
+            .http_collection_storage
+            .read_folder_settings(&dialog.folder);
+        if settings.variables == dialog.variables {
+            return;
+        }
+        settings.variables = dialog.variables;
+        if let Err(e) = self
+            .http_collection_storage
+            .save_folder_settings(&dialog.folder, &settings)
+        {
+            self.log(
+                LogLevel::Error,
+                format!("Failed to save folder settings: {}", e),
+            );
+            return;
+        }
+        self.refresh_folder_inheritance();
+        self.log(
+            LogLevel::Success,
+            format!("Saved the variables of folder '{}'", dialog.folder),
+        );
+    }
+
+    /// Flag or unflag the selected folder variable as secret
+    pub fn toggle_folder_variable_secret(&mut self) {
+        let Some(dialog) = self.folder_settings_dialog.as_mut() else {
+            return;
+        };
+        if let Some(variable) = dialog.variables.get_mut(dialog.editor.selected) {
+            variable.secret = !variable.secret;
+        }
+    }
+
+    /// Edit the auth of the folder whose variables are open in $EDITOR
+    pub fn edit_folder_auth(&mut self) {
+        if let Some(dialog) = &self.folder_settings_dialog {
+            self.pending_external_edit =
+                Some(ExternalEditTarget::FolderAuth(dialog.folder.clone()));
+        }
+    }
+
+    /// Re-read what the request in the editor inherits after a folder's settings changed
+    fn refresh_folder_inheritance(&mut self) {
+        let key = self.http_state.current_request_key.clone();
+        if let Some((request_folder, _)) = key.rsplit_once('/') {
+            self.folder_inheritance = self
+                .http_collection_storage
+                .folder_inheritance(request_folder);
+        }
+    }
+
+    /// Bind the focused folder to the next environment (none → each environment → none)
+    pub fn cycle_folder_environment(&mut self) {
+        if self.refuse_in_safe_mode("Binding folders to environments") {
+            return;
+        }
+        let Some(folder) = self
+            .tree_state
+            .get_focused_node()
+            .filter(|node| node.node_type == NodeType::Folder)
+            .map(|node| node.path.clone())
+        else {
+            self.log(
+                LogLevel::Info,
+                "Focus a folder to bind it to an environment",
+            );
+            return;
+        };
+
+        let mut settings = self.http_collection_storage.read_folder_settings(&folder);
+        let names = self.config.environment_names();
+        let next = match &settings.environment {
+            None => names.first().cloned(),
+            Some(current) => names
+                .iter()
+                .position(|name| name == current)
+                .and_then(|index| names.get(index + 1).cloned()),
+        };
+        settings.environment = next;
+
+        if let Err(e) = self
+            .http_collection_storage
+            .save_folder_settings(&folder, &settings)
+        {
+            self.log(
+                LogLevel::Error,
+                format!("Failed to save folder settings: {}", e),
+            );
+            return;
+        }
+        match &settings.environment {
+            Some(environment) => self.log(
+                LogLevel::Success,
+                format!(
+                    "🌍 Requests in '{}' now use environment '{}'",
+                    folder, environment
+                ),
+            ),
+            None => self.log(
+                LogLevel::Info,
+                format!("🌍 Folder '{}' no longer picks an environment", folder),
+            ),
+        }
+    }
+
+    /// Give the focused folder the next color label (none → each color → none)
+    pub fn cycle_folder_color(&mut self) {
+        self.update_focused_folder_style("Labelling folders", |settings| {
+            settings.color = FolderColor::cycle(settings.color);
+        });
+    }
+
+    /// Move the focused folder up (positive) or down among its siblings by changing its weight
+    pub fn adjust_folder_weight(&mut self, delta: i32) {
+        self.update_focused_folder_style("Reordering folders", |settings| {
+            settings.sort_weight = settings.sort_weight.saturating_add(delta);
+        });
+    }
+
+    /// Change the focused folder's label or weight, save it and redraw the folder
+    fn update_focused_folder_style(
+        &mut self,
+        action: &str,
+        update: impl FnOnce(&mut FolderSettings),
+    ) {
+        if self.refuse_in_safe_mode(action) {
+            return;
+        }
+        let Some(folder) = self
+            .tree_state
+            .get_focused_node()
+            .filter(|node| node.node_type == NodeType::Folder)
+            .map(|node| node.path.clone())
+        else {
+            self.log(
+                LogLevel::Info,
+                "Focus a folder to change its color or position",
+            );
+            return;
+        };
+
+        let mut settings = self.http_collection_storage.read_folder_settings(&folder);
+        update(&mut settings);
+        if let Err(e) = self
+            .http_collection_storage
+            .save_folder_settings(&folder, &settings)
+        {
+            self.log(
+                LogLevel::Error,
+                format!("Failed to save folder settings: {}", e),
+            );
+            return;
+        }
+        self.tree_state
+            .set_folder_style(&folder, settings.color, settings.sort_weight);
+        self.log(
+            LogLevel::Info,
+            format!(
+                "Folder '{}': color {}, weight {}",
+                folder,
+                settings.color.map_or("none", FolderColor::name),
+                settings.sort_weight
+            ),
+        );
+    }
+
+    /// The folder binding of the request in the editor, if its folder has one
+    pub fn current_folder_environment(&self) -> Option<&FolderEnvironment> {
+        self.folder_environment
+            .as_ref()
+            .filter(|binding| binding.request_key == self.http_state.current_request_key)
+    }
+
+    /// Log why an action that would write to storage is unavailable in safe mode
+    fn refuse_in_safe_mode(&mut self, action: &str) -> bool {
+        if self.safe_mode {
+            self.log(
+                LogLevel::Warn,
+                format!("{} is disabled in safe mode (storage is read-only)", action),
+            );
+        }
+        self.safe_mode
+    }
+
+    /// Tree the notes of the current mode belong to
+    pub fn note_tree(&self) -> NoteTree {
+        match self.current_mode {
+            AppMode::Automation => NoteTree::Templates,
+            AppMode::Http => NoteTree::Collections,
+        }
+    }
+
+    /// Note attached to an item of the current tree
+    pub fn note_for(&self, path: &str) -> Option<&ItemNote> {
+        let tree = self.note_tree();
+        self.config
+            .notes
+            .iter()
+            .find(|note| note.tree == tree && note.path == path)
+    }
+
+    /// Notes of either tree whose review date has arrived
+    pub fn due_notes(&self) -> Vec<&ItemNote> {
+        crate::models::notes::due_notes(&self.config.notes, chrono::Local::now().date_naive())
+    }
+
+    /// Open the notes popup for the focused tree item
+    pub fn show_note_dialog(&mut self) {
+        if self.refuse_in_safe_mode("Editing notes") {
+            return;
+        }
+        let Some(path) = self
+            .tree_state
+            .get_focused_node()
+            .map(|node| node.path.clone())
+        else {
+            self.log(LogLevel::Info, "Focus an item to add a note");
+            return;
+        };
+        let note = self.note_for(&path);
+        self.note_dialog = Some(NoteDialog {
+            tree: self.note_tree(),
+            text: note.map(|note| note.text.clone()).unwrap_or_default(),
+            review_by: note
+                .and_then(|note| note.review_by)
+                .map(|date| date.format("%Y-%m-%d").to_string())
+                .unwrap_or_default(),
+            path,
+            editing_date: false,
+            error: None,
+        });
+    }
+
+    pub fn hide_note_dialog(&mut self) {
+        self.note_dialog = None;
+    }
+
+    pub fn note_dialog_add_char(&mut self, c: char) {
+        if let Some(dialog) = self.note_dialog.as_mut() {
+            if dialog.editing_date {
+                dialog.review_by.push(c);
+            } else {
+                dialog.text.push(c);
+            }
+            dialog.error = None;
+        }
+    }
+
+    pub fn note_dialog_backspace(&mut self) {
+        if let Some(dialog) = self.note_dialog.as_mut() {
+            if dialog.editing_date {
+                dialog.review_by.pop();
+            } else {
+                dialog.text.pop();
+            }
+            dialog.error = None;
+        }
+    }
+
+    /// Switch typing between the note text and its "review by" date
+    pub fn note_dialog_toggle_field(&mut self) {
+        if let Some(dialog) = self.note_dialog.as_mut() {
+            dialog.editing_date = !dialog.editing_date;
+        }
+    }
+
+    /// Store the note (an empty one without a date removes it) and persist it
+    pub fn save_note_from_dialog(&mut self) {
+        let Some(dialog) = self.note_dialog.as_mut() else {
+            return;
+        };
+        let review_by = match crate::models::notes::parse_review_date(
+            &dialog.review_by,
+            chrono::Local::now().date_naive(),
+        ) {
+            Ok(date) => date,
+            Err(error) => {
+                dialog.error = Some(error);
+                dialog.editing_date = true;
+                return;
+            }
+        };
+        let Some(dialog) = self.note_dialog.take() else {
+            return;
+        };
+
+        let text = dialog.text.trim_end().to_string();
+        self.config
+            .notes
+            .retain(|note| !(note.tree == dialog.tree && note.path == dialog.path));
+        let message = if text.is_empty() && review_by.is_none() {
+            format!("Removed note from '{}'", dialog.path)
+        } else {
+            self.config.notes.push(ItemNote {
+                tree: dialog.tree,
+                path: dialog.path.clone(),
+                text,
+                review_by,
+            });
+            match review_by {
+                Some(date) => format!("Saved note on '{}' (review by {})", dialog.path, date),
+                None => format!("Saved note on '{}'", dialog.path),
+            }
+        };
+
+        match self.config.save() {
+            Ok(()) => self.log(LogLevel::Success, message),
+            Err(e) => self.log(LogLevel::Error, format!("Failed to save notes: {}", e)),
+        }
+    }
 
-        // Parse as Postman collection
-        let collection: crate::services::http_collection_storage::PostmanCollection =
-            serde_json::from_str(&json_content)
-                .map_err(|e| format!("Invalid Postman collection format: {}", e))?;
+    /// Keep notes attached to an item (and everything below it) that was renamed or moved
+    fn carry_notes(&mut self, old_path: &str, new_path: &str) {
+        if old_path == new_path {
+            return;
+        }
+        let tree = self.note_tree();
+        crate::models::notes::move_notes(&mut self.config.notes, tree, old_path, new_path);
+        if let Err(e) = self.config.save() {
+            self.log(LogLevel::Error, format!("Failed to save notes: {}", e));
+        }
+    }
 
-        // Count requests and folders
-        let (request_count, folder_count) = self.count_collection_items(&collection.item);
+    /// Snapshot templates and collections when the backup schedule says one is due
+    pub fn run_scheduled_backup(&mut self) {
+        let Some(interval) = self.config.backup_schedule.interval() else {
+            return;
+        };
+        if self.safe_mode
+            || self
+                .last_backup_at
+                .is_some_and(|last| chrono::Local::now() - last < interval)
+        {
+            return;
+        }
+        // A failed attempt waits for the next interval too, instead of retrying every tick
+        self.last_backup_at = Some(chrono::Local::now());
+        if let Err(e) = self.back_up_now() {
+            self.log(LogLevel::Error, format!("Scheduled backup failed: {:#}", e));
+        }
+    }
+
+    /// Snapshot templates and collections now, pruning old backups per the schedule
+    pub fn back_up_now(&mut self) -> Result<()> {
+        let store = BackupStore::new(BackupStore::default_path());
+        let (backup, summary) = store.create(
+            self.config.get_templates_directory(),
+            &self.config.get_collections_directory(),
+            self.config.backup_schedule.keep,
+        )?;
+        self.last_backup_at = Some(backup.created_at);
+        let skipped = match summary.skipped {
+            0 => String::new(),
+            n => format!(", {} non-text file(s) skipped", n),
+        };
+        self.log(
+            LogLevel::Info,
+            format!(
+                "💾 Backed up {} file(s) to {}{}",
+                summary.files,
+                backup.path.display(),
+                skipped
+            ),
+        );
+        if let Some(dialog) = self.backup_dialog.as_mut() {
+            dialog.backups = store.list();
+            dialog.selected = 0;
+            dialog.confirming = false;
+        }
+        Ok(())
+    }
+
+    pub fn show_backup_dialog(&mut self) {
+        if self.refuse_in_safe_mode("Restoring backups") {
+            return;
+        }
+        self.backup_dialog = Some(BackupDialog {
+            backups: BackupStore::new(BackupStore::default_path()).list(),
+            selected: 0,
+            confirming: false,
+            error: None,
+        });
+    }
+
+    pub fn hide_backup_dialog(&mut self) {
+        self.backup_dialog = None;
+    }
+
+    pub fn backup_dialog_move(&mut self, down: bool) {
+        if let Some(dialog) = self.backup_dialog.as_mut() {
+            let last = dialog.backups.len().saturating_sub(1);
+            dialog.selected = if down {
+                (dialog.selected + 1).min(last)
+            } else {
+                dialog.selected.saturating_sub(1)
+            };
+            dialog.confirming = false;
+        }
+    }
+
+    /// Replace templates and collections with the selected backup; asks for a
+    /// second Enter first
+    pub async fn restore_selected_backup(&mut self) {
+        let Some(dialog) = self.backup_dialog.as_mut() else {
+            return;
+        };
+        let Some(backup) = dialog.backups.get(dialog.selected).cloned() else {
+            return;
+        };
+        if !dialog.confirming {
+            dialog.confirming = true;
+            return;
+        }
+
+        let store = BackupStore::new(BackupStore::default_path());
+        let result = store.restore(
+            &backup.path,
+            self.config.get_templates_directory(),
+            &self.config.get_collections_directory(),
+        );
+        match result {
+            Ok(summary) => {
+                self.backup_dialog = None;
+                self.audit(
+                    AuditAction::Restore,
+                    vec![backup.path.display().to_string()],
+                    format!("{} file(s) over templates and collections", summary.files),
+                );
+                self.log(
+                    LogLevel::Success,
+                    format!(
+                        "Restored {} file(s) from the backup of {} (the previous state was backed up first)",
+                        summary.files,
+                        self.format_timestamp(&backup.created_at)
+                    ),
+                );
+                if let Err(e) = self.refresh_tree_from_storage().await {
+                    self.log(LogLevel::Error, format!("Failed to refresh tree: {}", e));
+                }
+            }
+            Err(e) => {
+                if let Some(dialog) = self.backup_dialog.as_mut() {
+                    dialog.confirming = false;
+                    dialog.backups = store.list();
+                    dialog.error = Some(format!("{:#}", e));
+                }
+            }
+        }
+    }
+
+    /// Open "Clone to folder…" for the focused request
+    pub fn show_clone_request_dialog(&mut self) {
+        if self.refuse_in_safe_mode("Cloning requests") {
+            return;
+        }
+        let Some(source_path) = self
+            .tree_state
+            .get_focused_node()
+            .filter(|node| node.node_type == NodeType::Template)
+            .map(|node| node.path.clone())
+        else {
+            self.log(LogLevel::Info, "Focus a request to clone it");
+            return;
+        };
+        let source_folder = source_path
+            .rsplit_once('/')
+            .map_or("", |(folder, _)| folder)
+            .to_string();
+        let source_environment = self
+            .http_collection_storage
+            .folder_environment(&source_folder)
+            .map(|(_, environment)| environment)
+            .unwrap_or_else(|| self.config.active_environment_name().to_string());
+
+        let mut folders = vec![String::new()];
+        folders.extend(
+            self.http_collection_storage
+                .list_all_folders()
+                .unwrap_or_default(),
+        );
+        let selected_folder = folders
+            .iter()
+            .position(|folder| *folder == source_folder)
+            .unwrap_or(0);
+
+        self.clone_request_dialog = Some(CloneRequestDialog {
+            source_path,
+            source_environment,
+            folders,
+            selected_folder,
+            target_environment: None,
+        });
+    }
+
+    pub fn hide_clone_request_dialog(&mut self) {
+        self.clone_request_dialog = None;
+    }
+
+    /// Move the folder selection; a folder bound to another environment preselects it
+    pub fn clone_dialog_move(&mut self, forward: bool) {
+        let Some(dialog) = self.clone_request_dialog.as_mut() else {
+            return;
+        };
+        let count = dialog.folders.len();
+        dialog.selected_folder = if forward {
+            (dialog.selected_folder + 1) % count
+        } else {
+            (dialog.selected_folder + count - 1) % count
+        };
+
+        let folder = dialog.folders[dialog.selected_folder].clone();
+        let bound = self
+            .http_collection_storage
+            .folder_environment(&folder)
+            .map(|(_, environment)| environment);
+        if let Some(dialog) = self.clone_request_dialog.as_mut()
+            && let Some(environment) = bound
+        {
+            dialog.target_environment =
+                (environment != dialog.source_environment).then_some(environment);
+        }
+    }
+
+    /// Step the target environment: unchanged, then every environment but the source one
+    pub fn cycle_clone_environment(&mut self) {
+        let names = self.config.environment_names();
+        let Some(dialog) = self.clone_request_dialog.as_mut() else {
+            return;
+        };
+        let options: Vec<&String> = names
+            .iter()
+            .filter(|name| **name != dialog.source_environment)
+            .collect();
+        dialog.target_environment = match &dialog.target_environment {
+            None => options.first().map(|name| name.to_string()),
+            Some(current) => options
+                .iter()
+                .position(|name| *name == current)
+                .and_then(|index| options.get(index + 1))
+                .map(|name| name.to_string()),
+        };
+    }
+
+    /// Variables of an environment, using the live copy for the active one
+    fn environment_values(&self, name: &str) -> Vec<KeyValuePair> {
+        if name == self.config.active_environment_name() {
+            return self.http_state.environment_vars.clone();
+        }
+        self.config
+            .environment_variables_of(name)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// The clone as it would be saved, with the number of values rewritten
+    pub fn clone_request_preview(&self) -> Option<(HttpRequest, usize)> {
+        let dialog = self.clone_request_dialog.as_ref()?;
+        let (folder, name) = dialog
+            .source_path
+            .rsplit_once('/')
+            .unwrap_or(("", dialog.source_path.as_str()));
+        let mut request = self
+            .http_collection_storage
+            .read_request(folder, name)
+            .ok()?
+            .request;
+
+        let rewritten = match &dialog.target_environment {
+            Some(target) => rewrite_for_environment(
+                &mut request,
+                &self.environment_values(&dialog.source_environment),
+                &self.environment_values(target),
+            ),
+            None => 0,
+        };
+        Some((request, rewritten))
+    }
+
+    /// Save the clone into the selected folder under a name that is free there
+    pub async fn confirm_clone_request(&mut self) -> Result<()> {
+        let Some((mut request, rewritten)) = self.clone_request_preview() else {
+            self.log(LogLevel::Error, "The request to clone could not be read");
+            self.hide_clone_request_dialog();
+            return Ok(());
+        };
+        let Some(dialog) = self.clone_request_dialog.take() else {
+            return Ok(());
+        };
+        let folder = dialog.folders[dialog.selected_folder].clone();
+
+        let base_name = match &dialog.target_environment {
+            Some(environment) => format!("{} ({})", request.name, environment),
+            None => request.name.clone(),
+        };
+        let mut name = base_name.clone();
+        let mut counter = 2;
+        while self
+            .http_collection_storage
+            .read_request(&folder, &name)
+            .is_ok()
+        {
+            name = format!("{} {}", base_name, counter);
+            counter += 1;
+        }
+        request.name = name.clone();
+
+        self.http_collection_storage
+            .save_request(&folder, &name, request)?;
+        let path = if folder.is_empty() {
+            sanitize_filename(&name)
+        } else {
+            format!("{}/{}", folder, sanitize_filename(&name))
+        };
+        self.log(
+            LogLevel::Success,
+            match &dialog.target_environment {
+                Some(environment) => format!(
+                    "Cloned to '{}' for {} ({} value(s) rewritten from {})",
+                    path, environment, rewritten, dialog.source_environment
+                ),
+                None => format!("Cloned to '{}'", path),
+            },
+        );
+        self.pending_tree_reveal = Some(path);
+        self.refresh_tree_from_storage().await?;
+        self.apply_pending_tree_reveal();
+        Ok(())
+    }
+
+    /// Get the text field currently focused in the settings dialog
+    pub fn settings_dialog_focused_text(&mut self) -> Option<&mut String> {
+        match self.settings_dialog_focused_field {
+            0 => Some(&mut self.settings_dialog_templates_dir),
+            1 => Some(&mut self.settings_dialog_collections_dir),
+            _ => None,
+        }
+    }
+
+    /// Apply directory changes from the settings dialog, migrating data if requested
+    pub async fn apply_settings_from_dialog(&mut self) -> Result<()> {
+        use crate::services::storage_migration::{
+            expand_user_path, migrate_directory, validate_writable_directory,
+        };
+
+        let old_templates_dir = self.config.get_templates_directory().clone();
+        let old_collections_dir = self.config.get_collections_directory();
+
+        let new_templates_dir = expand_user_path(&self.settings_dialog_templates_dir);
+        let new_collections_dir = expand_user_path(&self.settings_dialog_collections_dir);
+
+        if new_templates_dir == new_collections_dir {
+            self.settings_dialog_error =
+                Some("Templates and collections must use different directories".to_string());
+            return Ok(());
+        }
+
+        // Validate write access before touching anything
+        for dir in [&new_templates_dir, &new_collections_dir] {
+            if let Err(e) = validate_writable_directory(dir) {
+                self.settings_dialog_error = Some(format!("{:#}", e));
+                return Ok(());
+            }
+        }
+
+        if self.settings_dialog_move_data {
+            for (from, to, label) in [
+                (&old_templates_dir, &new_templates_dir, "templates"),
+                (
+                    &old_collections_dir,
+                    &new_collections_dir,
+                    "HTTP collections",
+                ),
+            ] {
+                if from == to {
+                    continue;
+                }
+                match migrate_directory(from, to) {
+                    Ok(moved) => self.log(
+                        LogLevel::Info,
+                        format!("Moved {} {} item(s) to {}", moved, label, to.display()),
+                    ),
+                    Err(e) => {
+                        self.settings_dialog_error =
+                            Some(format!("Failed to move {}: {:#}", label, e));
+                        return Ok(());
+                    }
+                }
+            }
+        }
+
+        let mut new_config = self.config.clone();
+        new_config.check_for_updates = self.settings_dialog_check_updates;
+        new_config.auto_format_json = self.settings_dialog_auto_format_json;
+        new_config.block_invalid_json = self.settings_dialog_block_invalid_json;
+        new_config.timestamp_format = self.settings_dialog_timestamp_format;
+        new_config.history_retention = self.settings_dialog_history_retention;
+        new_config.storage_format = self.settings_dialog_storage_format;
+        new_config.persist_cookies = self.settings_dialog_persist_cookies;
+        new_config.request_log_secrets = self.settings_dialog_request_log_secrets;
+        new_config.auto_lock = self.settings_dialog_auto_lock;
+        new_config.response_display_limit = self.settings_dialog_response_limit;
+        new_config.retry = self.settings_dialog_retry;
+        new_config.backup_schedule = self.settings_dialog_backup_schedule;
+        new_config.custom_templates_dir = if new_templates_dir == new_config.templates_directory {
+            None
+        } else {
+            Some(new_templates_dir)
+        };
+        new_config.custom_collections_dir = None;
+        if new_config.get_collections_directory() != new_collections_dir {
+            new_config.custom_collections_dir = Some(new_collections_dir);
+        }
+
+        if let Err(e) = new_config.save() {
+            self.settings_dialog_error = Some(format!("Failed to save config: {}", e));
+            return Ok(());
+        }
+
+        if self.config.persist_cookies != new_config.persist_cookies {
+            if new_config.persist_cookies {
+                // Save what this session collected so far on the next tick
+                self.cookie_jar.lock().unwrap().changed = true;
+            } else if let Err(e) = std::fs::remove_file(cookie_jar_path())
+                && e.kind() != std::io::ErrorKind::NotFound
+            {
+                self.log(
+                    LogLevel::Warn,
+                    format!("Failed to remove saved cookies: {}", e),
+                );
+            }
+        }
+
+        self.config = new_config;
+        self.template_storage = TemplateStorage::new(self.config.clone());
+        self.http_collection_storage = HttpCollectionStorage::new(self.config.clone());
+
+        self.prune_history_entries();
+
+        self.log(
+            LogLevel::Success,
+            format!(
+                "Templates directory: {} | HTTP collections directory: {}",
+                self.template_storage.get_templates_directory_display(),
+                self.http_collection_storage
+                    .get_collections_directory_display()
+            ),
+        );
+
+        self.hide_settings_dialog();
+        self.start_tree_load(true);
+        Ok(())
+    }
+
+    /// Show the What's New overlay with the full embedded changelog
+    /// Move an opened item to the front of the recent list and persist it
+    fn remember_recent_item(&mut self, kind: RecentItemKind, path: &str) {
+        crate::models::recent::record_recent_item(
+            &mut self.config.recent_items,
+            RecentItem::new(kind, path),
+        );
+        if let Err(e) = self.config.save() {
+            self.log(
+                LogLevel::Error,
+                format!("Failed to save recent items: {}", e),
+            );
+        }
+    }
+
+    pub fn show_quick_switcher(&mut self) {
+        if self.config.recent_items.is_empty() {
+            self.log(LogLevel::Info, "No recently opened templates or requests");
+            return;
+        }
+        // Like editor buffer switching, start on the previously opened item
+        self.quick_switcher_selected = usize::from(self.config.recent_items.len() > 1);
+        self.show_quick_switcher = true;
+        self.log(LogLevel::Debug, "Quick switcher opened");
+    }
+
+    pub fn hide_quick_switcher(&mut self) {
+        self.show_quick_switcher = false;
+        self.log(LogLevel::Debug, "Quick switcher closed");
+    }
+
+    pub fn quick_switcher_next(&mut self) {
+        let count = self.config.recent_items.len();
+        if count > 0 {
+            self.quick_switcher_selected = (self.quick_switcher_selected + 1) % count;
+        }
+    }
+
+    pub fn quick_switcher_previous(&mut self) {
+        let count = self.config.recent_items.len();
+        if count > 0 {
+            self.quick_switcher_selected = (self.quick_switcher_selected + count - 1) % count;
+        }
+    }
+
+    /// Open the selected recent item, switching mode if needed
+    pub async fn open_quick_switcher_selection(&mut self) -> Result<()> {
+        self.hide_quick_switcher();
+        let Some(item) = self
+            .config
+            .recent_items
+            .get(self.quick_switcher_selected)
+            .cloned()
+        else {
+            return Ok(());
+        };
+
+        let mode = match item.kind {
+            RecentItemKind::Template => AppMode::Automation,
+            RecentItemKind::Request => AppMode::Http,
+        };
+        self.switch_mode(mode);
+        self.focus_pane(FocusedPane::Form);
+
+        if self.load_template_into_form(&item.path).await.is_err() {
+            // The item was renamed or deleted since it was opened
+            self.config.recent_items.retain(|recent| recent != &item);
+            self.config.save()?;
+            self.log(
+                LogLevel::Warn,
+                format!("Removed missing item from recent list: {}", item.path),
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn show_global_search(&mut self) {
+        self.global_search_entries = self.build_global_search_index();
+        self.global_search_query.clear();
+        self.global_search_results.clear();
+        self.global_search_selected = 0;
+        self.show_global_search = true;
+        self.log(LogLevel::Debug, "Global search opened");
+    }
+
+    pub fn hide_global_search(&mut self) {
+        self.show_global_search = false;
+        self.global_search_entries.clear();
+        self.global_search_results.clear();
+        self.log(LogLevel::Debug, "Global search closed");
+    }
+
+    pub fn global_search_add_char(&mut self, c: char) {
+        self.global_search_query.push(c);
+        self.update_global_search_results();
+    }
+
+    pub fn global_search_backspace(&mut self) {
+        self.global_search_query.pop();
+        self.update_global_search_results();
+    }
+
+    fn update_global_search_results(&mut self) {
+        self.global_search_results = match SendCommand::parse(&self.global_search_query) {
+            Some(command) => rank_send_targets(&self.global_search_entries, &command.query),
+            None => rank_search_results(&self.global_search_entries, &self.global_search_query),
+        };
+        self.global_search_selected = 0;
+    }
+
+    pub fn global_search_next(&mut self) {
+        let count = self.global_search_results.len();
+        if count > 0 {
+            self.global_search_selected = (self.global_search_selected + 1) % count;
+        }
+    }
+
+    pub fn global_search_previous(&mut self) {
+        let count = self.global_search_results.len();
+        if count > 0 {
+            self.global_search_selected = (self.global_search_selected + count - 1) % count;
+        }
+    }
+
+    /// Collect everything global search can find: saved items in both modes, logs and help
+    fn build_global_search_index(&self) -> Vec<SearchEntry> {
+        let mut entries = Vec::new();
+
+        let join = |folder: &str, name: &str| {
+            if folder.is_empty() {
+                name.to_string()
+            } else {
+                format!("{}/{}", folder, name)
+            }
+        };
+
+        let mut template_folders = self.template_storage.list_all_folders().unwrap_or_default();
+        template_folders.insert(0, String::new());
+        for folder in &template_folders {
+            if !folder.is_empty() {
+                entries.push(SearchEntry::new(
+                    SearchCategory::Folder,
+                    folder.clone(),
+                    "Automation templates",
+                    SearchTarget::Folder {
+                        http: false,
+                        path: folder.clone(),
+                    },
+                ));
+            }
+            let names = self
+                .template_storage
+                .list_templates_in_folder(folder)
+                .unwrap_or_default();
+            for name in names {
+                let detail = match self.template_storage.read_template(folder, &name) {
+                    Ok(stored) => {
+                        let mut values: Vec<String> = stored
+                            .template
+                            .field_values
+                            .iter()
+                            .map(|(field, value)| format!("{}={}", field, value))
+                            .collect();
+                        values.sort();
+                        format!("{} {}", stored.template.description, values.join(" "))
+                    }
+                    Err(_) => String::new(),
+                };
+                entries.push(SearchEntry::new(
+                    SearchCategory::Template,
+                    name.clone(),
+                    detail,
+                    SearchTarget::Item {
+                        http: false,
+                        path: join(folder, &name),
+                    },
+                ));
+            }
+        }
+
+        let mut request_folders = self
+            .http_collection_storage
+            .list_all_folders()
+            .unwrap_or_default();
+        request_folders.insert(0, String::new());
+        for folder in &request_folders {
+            if !folder.is_empty() {
+                entries.push(SearchEntry::new(
+                    SearchCategory::Folder,
+                    folder.clone(),
+                    "HTTP collections",
+                    SearchTarget::Folder {
+                        http: true,
+                        path: folder.clone(),
+                    },
+                ));
+            }
+            let names = self
+                .http_collection_storage
+                .list_requests_in_folder(folder)
+                .unwrap_or_default();
+            for name in names {
+                let detail = match self.http_collection_storage.read_request(folder, &name) {
+                    Ok(stored) => format!(
+                        "{} {} {} {}",
+                        stored.request.method.as_str(),
+                        stored.request.url,
+                        stored.request.description,
+                        join(folder, &name)
+                    ),
+                    Err(_) => String::new(),
+                };
+                entries.push(SearchEntry::new(
+                    SearchCategory::Request,
+                    name.clone(),
+                    detail,
+                    SearchTarget::Item {
+                        http: true,
+                        path: join(folder, &name),
+                    },
+                ));
+            }
+        }
+
+        for (index, entry) in self.log_entries.iter().enumerate() {
+            if entry.is_marker {
+                continue;
+            }
+            entries.push(SearchEntry::new(
+                SearchCategory::Log,
+                entry.message.clone(),
+                format!(
+                    "{} {}",
+                    entry.level.as_str(),
+                    self.format_timestamp(&entry.timestamp)
+                ),
+                SearchTarget::Log { index },
+            ));
+        }
+
+        for section in crate::ui::components::help_dialog::get_help_sections() {
+            for item in section.items {
+                entries.push(SearchEntry::new(
+                    SearchCategory::Help,
+                    item.description.clone(),
+                    format!(
+                        "{} {} {}",
+                        item.keys,
+                        section.title,
+                        item.example.unwrap_or_default()
+                    ),
+                    SearchTarget::Help {
+                        query: item.description,
+                    },
+                ));
+            }
+        }
+
+        entries
+    }
+
+    /// Jump to the selected global search result
+    pub async fn open_global_search_selection(&mut self) -> Result<()> {
+        let target = self
+            .global_search_results
+            .get(self.global_search_selected)
+            .and_then(|&index| self.global_search_entries.get(index))
+            .map(|entry| entry.target.clone());
+        self.hide_global_search();
+        let Some(target) = target else {
+            return Ok(());
+        };
+
+        if let Some(command) = SendCommand::parse(&self.global_search_query)
+            && let SearchTarget::Item { path, .. } = &target
+        {
+            return self.send_from_palette(path, command).await;
+        }
+
+        match target {
+            SearchTarget::Item { http, path } => {
+                self.switch_mode(if http {
+                    AppMode::Http
+                } else {
+                    AppMode::Automation
+                });
+                self.focus_pane(FocusedPane::Form);
+                self.pending_tree_reveal = Some(path.clone());
+                self.apply_pending_tree_reveal();
+                // Failures are logged by the loader
+                let _ = self.load_template_into_form(&path).await;
+            }
+            SearchTarget::Folder { http, path } => {
+                self.switch_mode(if http {
+                    AppMode::Http
+                } else {
+                    AppMode::Automation
+                });
+                self.focus_pane(FocusedPane::Collections);
+                self.pending_tree_reveal = Some(path);
+                self.apply_pending_tree_reveal();
+            }
+            SearchTarget::Log { index } => {
+                self.log_search_query.clear();
+                self.log_search_mode = false;
+                self.log_time_filter = LogTimeFilter::All;
+                // Scroll position counts back from the newest entry
+                self.log_scroll_position = self.log_entries.len().saturating_sub(index + 1);
+                self.focus_pane(FocusedPane::Logs);
+            }
+            SearchTarget::Help { query } => {
+                self.show_help_dialog();
+                self.help_search_query = query;
+            }
+        }
 
-        Ok(CollectionPreview {
-            name: collection.info.name,
-            request_count,
-            folder_count,
-            description: collection.info.description,
-        })
+        Ok(())
     }
 
-    /// Recursively count requests and folders in collection items
-    fn count_collection_items(
-        &self,
-        items: &[crate::services::http_collection_storage::PostmanItem],
-    ) -> (usize, usize) {
-        let mut request_count = 0;
-        let mut folder_count = 0;
-
-        for item in items {
-            match item {
-                crate::services::http_collection_storage::PostmanItem::Request(_) => {
-                    request_count += 1;
-                }
-                crate::services::http_collection_storage::PostmanItem::Folder(folder) => {
-                    folder_count += 1;
-                    let (sub_requests, sub_folders) = self.count_collection_items(&folder.item);
-                    request_count += sub_requests;
-                    folder_count += sub_folders;
+    /// Open a saved request and send it once with the command's environment and overrides;
+    /// the active environment and the saved request are left as they are
+    async fn send_from_palette(&mut self, path: &str, command: SendCommand) -> Result<()> {
+        let environment_name = command
+            .environment
+            .clone()
+            .unwrap_or_else(|| self.config.active_environment_name().to_string());
+        let mut environment = if environment_name == self.config.active_environment_name() {
+            // Unsaved edits to the active environment live in the HTTP state
+            self.http_state.environment_vars.clone()
+        } else {
+            match self.config.environment_variables_of(&environment_name) {
+                Some(variables) => variables.clone(),
+                None => {
+                    self.log(
+                        LogLevel::Warn,
+                        format!("No environment named '{}'", environment_name),
+                    );
+                    return Ok(());
                 }
             }
+        };
+        apply_variables(&mut environment, &command.overrides);
+        let proxy = self.config.proxy_of(&environment_name);
+
+        self.switch_mode(AppMode::Http);
+        self.focus_pane(FocusedPane::Form);
+        self.pending_tree_reveal = Some(path.to_string());
+        self.apply_pending_tree_reveal();
+        // Failures are logged by the loader
+        if self.load_template_into_form(path).await.is_err() {
+            return Ok(());
         }
 
-        (request_count, folder_count)
+        let summary = command.summary();
+        if !summary.is_empty() {
+            self.log(LogLevel::Info, format!("⚡ Sending with {}", summary));
+        }
+        self.send_current_request_with(&environment_name, environment, proxy, false)
+            .await
     }
 
-    /// Execute the import
-    pub async fn execute_import(&mut self) -> Result<()> {
-        if self.import_dialog_preview.is_none() {
-            self.import_dialog_error = Some("No valid collection to import".to_string());
-            return Ok(());
+    /// Focus the pending tree path once the tree has finished loading
+    fn apply_pending_tree_reveal(&mut self) {
+        if self.tree_state.is_loading {
+            return;
+        }
+        if let Some(view) = self.pending_tree_view.take() {
+            self.tree_state.set_expanded_folders(&view.expanded_folders);
+            if let Some(path) = &view.selected_path {
+                self.tree_state.select_node(path);
+            }
+        }
+        if let Some(path) = self.pending_tree_reveal.take() {
+            self.tree_state.reveal(&path);
         }
+    }
 
-        let import_dialog_path = &self.import_dialog_file_path.clone();
+    pub fn show_whats_new(&mut self) {
+        self.whats_new_releases = crate::services::changelog::embedded_changelog();
+        self.whats_new_scroll = 0;
+        self.show_whats_new = true;
+        self.log(LogLevel::Debug, "What's New opened");
+    }
 
-        let file_path = std::path::Path::new(import_dialog_path);
+    /// Close the What's New overlay and remember that this version was seen
+    pub fn hide_whats_new(&mut self) {
+        self.show_whats_new = false;
+        self.whats_new_scroll = 0;
+        self.mark_whats_new_seen();
+        self.log(LogLevel::Debug, "What's New closed");
+    }
 
-        match self.import_postman_collection(file_path).await {
-            Ok(()) => {
-                let preview = self.import_dialog_preview.as_ref().unwrap();
-                self.log(
-                    LogLevel::Success,
-                    format!(
-                        "Successfully imported '{}' ({} requests, {} folders)",
-                        preview.name, preview.request_count, preview.folder_count
-                    ),
-                );
-                self.hide_import_dialog();
-            }
-            Err(e) => {
-                self.import_dialog_error = Some(format!("Import failed: {}", e));
-                self.log(LogLevel::Error, format!("Import failed: {}", e));
-            }
+    /// Record the running version as seen so the overlay doesn't reappear
+    fn mark_whats_new_seen(&mut self) {
+        let current_version = env!("CARGO_PKG_VERSION").to_string();
+        if self.config.last_seen_version.as_ref() == Some(&current_version) {
+            return;
         }
 
-        Ok(())
+        self.config.last_seen_version = Some(current_version);
+        if let Err(e) = self.config.save() {
+            self.log(LogLevel::Warn, format!("Failed to save config: {}", e));
+        }
     }
 
-    /// Add character to file path
-    pub fn import_dialog_add_char(&mut self, c: char) {
-        self.import_dialog_file_path.push(c);
-        self.update_import_file_path(self.import_dialog_file_path.clone());
+    /// Count items, look for broken files and check python and the config in the background
+    pub fn start_health_check(&mut self) {
+        let config = self.config.clone();
+        let config_error = self.config_load_error.clone();
+        let sender = self.message_sender.clone();
+        tokio::task::spawn_blocking(move || {
+            let report = run_health_check(&config, config_error);
+            let _ = sender.send(AppMessage::HealthChecked(report));
+        });
     }
 
-    /// Remove character from file path
-    pub fn import_dialog_backspace(&mut self) {
-        self.import_dialog_file_path.pop();
-        self.update_import_file_path(self.import_dialog_file_path.clone());
+    /// Log the health summary; the panel opens by itself only when something is wrong
+    fn finish_health_check(&mut self, report: HealthReport) {
+        for (level, line) in report.summary_lines() {
+            self.log(level, line);
+        }
+        if report.problem_count() > 0 {
+            self.show_health_panel = true;
+        }
+        self.health_report = Some(report);
     }
 
-    /// Clear file path
-    pub fn import_dialog_clear(&mut self) {
-        self.import_dialog_file_path.clear();
-        self.update_import_file_path(self.import_dialog_file_path.clone());
+    /// Re-run the health check and show its results
+    pub fn show_health_panel(&mut self) {
+        self.show_health_panel = true;
+        self.health_panel_scroll = 0;
+        self.start_health_check();
+        self.log(LogLevel::Debug, "Health panel opened");
     }
 
-    /// Suggest common file paths
-    pub fn import_dialog_suggest_path(&mut self) {
-        // Try to suggest common locations
-        if let Some(home_dir) = dirs::home_dir() {
-            let suggestions = [
-                home_dir.join("Downloads").join("collection.json"),
-                home_dir.join("Desktop").join("collection.json"),
-                std::path::PathBuf::from("./collection.json"),
-            ];
+    pub fn hide_health_panel(&mut self) {
+        self.show_health_panel = false;
+        self.health_panel_scroll = 0;
+        self.log(LogLevel::Debug, "Health panel closed");
+    }
 
-            for suggestion in &suggestions {
-                if suggestion.exists() {
-                    self.import_dialog_file_path = suggestion.to_string_lossy().to_string();
-                    self.update_import_file_path(self.import_dialog_file_path.clone());
-                    self.log(
-                        LogLevel::Info,
-                        format!("Found collection at: {}", suggestion.display()),
-                    );
-                    return;
-                }
-            }
+    /// Dismiss the update notice
+    pub fn dismiss_update_notice(&mut self) {
+        self.update_notice = None;
+        self.log(LogLevel::Debug, "Update notice dismissed");
+    }
+
+    /// Number of background tasks currently running for a mode
+    pub fn running_task_count(&self, mode: &AppMode) -> usize {
+        match mode {
+            AppMode::Automation => usize::from(self.automation_state.is_running),
+            AppMode::Http => self.http_state.in_flight_count(),
         }
+    }
 
-        // If no suggestions found, provide a template
-        if let Some(home_dir) = dirs::home_dir() {
-            self.import_dialog_file_path = home_dir
-                .join("Downloads")
-                .join("my-collection.json")
-                .to_string_lossy()
-                .to_string();
-            self.update_import_file_path(self.import_dialog_file_path.clone());
+    /// Remember a finished task so the status line can point at it from the other mode
+    fn note_task_finished(&mut self, mode: AppMode, summary: impl Into<String>) {
+        if mode != self.current_mode {
+            self.background_notices.push((mode, summary.into()));
         }
     }
 
@@ -2414,13 +8592,402 @@ fn sanitize_filename(name: &str) -> String {
 }
 
 // HTTP request implementation using reqwest
-async fn send_http_request_impl(request: HttpRequest) -> Result<HttpResponse> {
+/// Replace characters that are not safe in file names
+fn sanitize_file_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if cleaned.is_empty() {
+        "response".to_string()
+    } else {
+        cleaned
+    }
+}
+
+/// State shared by every request sent this session
+#[derive(Clone)]
+struct HttpSession {
+    cookie_jar: SharedCookieJar,
+    oauth_tokens: OAuth2TokenCache,
+    request_log: RequestLog,
+    /// Global certificates, for requests that don't set their own
+    tls: TlsSettings,
+    /// Proxy of the active environment (or the global one), for requests without their own
+    proxy: ProxySettings,
+    /// Skip every proxy, configured or from the environment, for this send
+    bypass_proxy: bool,
+    /// Bytes of each response body kept in memory
+    response_display_limit: usize,
+    /// Global retry policy, for requests without their own
+    retry: RetryPolicy,
+    /// Safe mode: skip pre-request scripts and refuse custom auth scripts
+    scripts_disabled: bool,
+    /// Sent with every request that doesn't set the same header itself
+    default_headers: Vec<HttpHeader>,
+}
+
+/// Run the request's pre-request script and fill in `{{variables}}`, then send it
+async fn send_prepared_request(
+    request: HttpRequest,
+    environment: &[KeyValuePair],
+    session: &HttpSession,
+    sender: &mpsc::UnboundedSender<AppMessage>,
+) -> Result<(HttpRequest, HttpResponse)> {
+    let mut request = request;
+    if session.scripts_disabled {
+        if matches!(request.auth, HttpAuth::Script { .. }) {
+            anyhow::bail!("Custom auth scripts don't run in safe mode");
+        }
+        if !request.pre_request_script.trim().is_empty() {
+            request.pre_request_script.clear();
+            let _ = sender.send(AppMessage::Log(
+                LogLevel::Warn,
+                format!(
+                    "Safe mode: skipped the pre-request script of '{}'",
+                    request.name
+                ),
+            ));
+        }
+    }
+    request.headers = merge_default_headers(&session.default_headers, &request.headers);
+    let mut prepared = prepare_request(&request, environment)?;
+    // Variables are filled in by now, so the credentials are the values that go out
+    let mut logger = RequestLogger::new(sender, &prepared.request, environment);
+    if !prepared.variables.is_empty() {
+        let assigned: Vec<String> = prepared
+            .variables
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+        logger.log(
+            LogLevel::Debug,
+            format!(
+                "Pre-request script of '{}' set {}",
+                request.name,
+                assigned.join(", ")
+            ),
+        );
+    }
+    let tests = prepared.request.tests.clone();
+    let extract = prepared.request.extract.clone();
+    prepared.request.settings.tls = prepared.request.settings.tls.or(&session.tls);
+    prepared.request.settings.proxy = if session.bypass_proxy {
+        ProxySettings {
+            url: DIRECT_PROXY.to_string(),
+            ..ProxySettings::default()
+        }
+    } else {
+        prepared.request.settings.proxy.or(&session.proxy)
+    };
+    // OAuth 2.0 settings become a bearer token, fetched or refreshed as needed
+    if let Some(mut config) = OAuth2Config::from_auth(&prepared.request.auth) {
+        config.proxy = prepared.request.settings.proxy.clone();
+        let token = oauth2_access_token(&config, &session.oauth_tokens, |message| {
+            logger.log(LogLevel::Info, format!("🔑 {}", message));
+        })
+        .await
+        .context("OAuth 2.0 authorization failed")?;
+        logger.add_secret(&token);
+        prepared.request.auth = HttpAuth::Bearer { token };
+    }
+    let logged_request = with_jar_cookies(&prepared.request, &session.cookie_jar);
+    let retry = prepared.request.settings.retry.or(&session.retry);
+    let max_attempts = retry.max_attempts.max(1);
+    let mut attempt = 1;
+    let result = loop {
+        let sent_at = chrono::Local::now();
+        let result = send_http_request_impl(
+            prepared.request.clone(),
+            &session.cookie_jar,
+            session.response_display_limit,
+        )
+        .await;
+
+        let outcome = match &result {
+            Ok(response) => format!(
+                "{} {} ({} ms)",
+                response.status_code, response.status_text, response.duration_ms
+            ),
+            Err(e) => format!("failed: {:#}", e),
+        };
+        if let Err(e) = session
+            .request_log
+            .append(sent_at, &logged_request, &outcome)
+        {
+            logger.log(
+                LogLevel::Warn,
+                format!("Failed to write the request log: {:#}", e),
+            );
+        }
+
+        let retryable = match &result {
+            Ok(response) => retry.retries_status(response.status_code),
+            Err(e) => retry.retries_connection_errors() && is_connection_error(e),
+        };
+        if !retryable || attempt >= max_attempts {
+            break result;
+        }
+        let delay = retry.delay_after(attempt);
+        logger.log(
+            LogLevel::Warn,
+            format!(
+                "↻ '{}' attempt {}/{} {}; retrying in {} ms",
+                request.name,
+                attempt,
+                max_attempts,
+                outcome,
+                delay.as_millis()
+            ),
+        );
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    };
+    if attempt > 1 {
+        logger.log(
+            LogLevel::Info,
+            format!("'{}' finished after {} attempts", request.name, attempt),
+        );
+    }
+
+    let mut response = result.map_err(|e| logger.scrub_error(e))?;
+    response.connection.proxy_bypassed = session.bypass_proxy;
+    check_assertions(&request.name, &tests, &mut response, &logger);
+    capture_variables(&request.name, &extract, &mut response, &logger);
+    Ok((prepared.request, response))
+}
+
+/// Sends the log lines of one send to the app with that request's credentials masked.
+///
+/// The app scrubs its log with the credentials of the request in the editor, which
+/// aren't those of the other requests of a collection run.
+struct RequestLogger<'a> {
+    sender: &'a mpsc::UnboundedSender<AppMessage>,
+    secrets: Vec<String>,
+}
+
+impl<'a> RequestLogger<'a> {
+    fn new(
+        sender: &'a mpsc::UnboundedSender<AppMessage>,
+        request: &HttpRequest,
+        environment: &[KeyValuePair],
+    ) -> Self {
+        let mut logger = Self {
+            sender,
+            secrets: Vec::new(),
+        };
+        let flagged = environment
+            .iter()
+            .filter(|variable| variable.secret)
+            .map(|variable| variable.value.as_str());
+        for secret in request_credentials(request).chain(flagged) {
+            logger.add_secret(secret);
+        }
+        logger
+    }
+
+    fn add_secret(&mut self, secret: &str) {
+        if !secret.is_empty() {
+            self.secrets.push(secret.to_string());
+        }
+    }
+
+    fn log(&self, level: LogLevel, message: String) {
+        let message = mask_secrets(&message, &self.secrets).into_owned();
+        let _ = self.sender.send(AppMessage::Log(level, message));
+    }
+
+    fn send(&self, message: AppMessage) {
+        let _ = self.sender.send(message);
+    }
+
+    /// The error as text with the credentials masked, when it mentions any
+    fn scrub_error(&self, error: anyhow::Error) -> anyhow::Error {
+        match mask_secrets(&format!("{:#}", error), &self.secrets) {
+            Cow::Borrowed(_) => error,
+            Cow::Owned(masked) => anyhow::anyhow!(masked),
+        }
+    }
+}
+
+/// Auth fields and headers of a request that hold credentials
+fn request_credentials(request: &HttpRequest) -> impl Iterator<Item = &str> {
+    request
+        .auth
+        .fields()
+        .into_iter()
+        .filter(|(label, _)| HttpAuth::is_secret_field(label))
+        .map(|(_, value)| value)
+        .chain(
+            request
+                .headers
+                .iter()
+                .filter(|header| is_secret_name(&header.name))
+                .map(|header| header.value.as_str()),
+        )
+}
+
+/// Whether a send failed before any response arrived: refused, reset, unresolvable or
+/// timed out
+fn is_connection_error(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        cause
+            .downcast_ref::<reqwest::Error>()
+            .is_some_and(|e| e.is_connect() || e.is_timeout() || e.is_request())
+    })
+}
+
+/// The request with the Cookie header the jar will add, so logged commands replay exactly
+fn with_jar_cookies(request: &HttpRequest, cookie_jar: &SharedCookieJar) -> HttpRequest {
+    let mut request = request.clone();
+    let user_cookies = request
+        .headers
+        .iter()
+        .any(|h| h.enabled && h.name.eq_ignore_ascii_case("cookie"));
+    if !user_cookies
+        && let Ok(url) = reqwest::Url::parse(&request.url)
+        && let Some(cookies) = cookie_jar.lock().unwrap().cookie_header(&url)
+    {
+        request.headers.push(HttpHeader::new("Cookie", cookies));
+    }
+    request
+}
+
+/// Run a request's extraction rules and hand the captured variables to the environment
+fn capture_variables(
+    request_name: &str,
+    rules: &str,
+    response: &mut HttpResponse,
+    logger: &RequestLogger,
+) {
+    if rules.trim().is_empty() {
+        return;
+    }
+    let extraction = extract_variables(rules, response);
+
+    for error in &extraction.errors {
+        logger.log(LogLevel::Warn, format!("'{}': {}", request_name, error));
+    }
+    if extraction.variables.is_empty() {
+        return;
+    }
+
+    let names: Vec<&str> = extraction
+        .variables
+        .iter()
+        .map(|var| var.name.as_str())
+        .collect();
+    logger.log(
+        LogLevel::Info,
+        format!("🔗 '{}' set {}", request_name, names.join(", ")),
+    );
+    response.extracted = extraction.variables.clone();
+    logger.send(AppMessage::VariablesExtracted(extraction.variables));
+}
+
+/// Evaluate a request's assertions against its response and log the outcome
+fn check_assertions(
+    request_name: &str,
+    tests: &str,
+    response: &mut HttpResponse,
+    logger: &RequestLogger,
+) {
+    if tests.trim().is_empty() {
+        return;
+    }
+    response.assertions = evaluate_assertions(tests, response);
+
+    let total = response.assertions.len();
+    let failed: Vec<&AssertionResult> = response
+        .assertions
+        .iter()
+        .filter(|result| !result.passed)
+        .collect();
+    let (level, icon) = if failed.is_empty() {
+        (LogLevel::Success, "✅")
+    } else {
+        (LogLevel::Error, "❌")
+    };
+    logger.log(
+        level,
+        format!(
+            "{} Tests for '{}': {}/{} passed",
+            icon,
+            request_name,
+            total - failed.len(),
+            total
+        ),
+    );
+    for result in failed {
+        logger.log(
+            LogLevel::Error,
+            format!("   ✗ {} ({})", result.assertion, result.message),
+        );
+    }
+}
+
+/// Wire size of a reqwest header map
+fn transfer_header_bytes(headers: &reqwest::header::HeaderMap) -> usize {
+    header_bytes(
+        headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.to_str().unwrap_or_default())),
+    )
+}
+
+/// Spool files holding the full bodies of truncated responses (cleared at startup)
+fn response_spool_directory() -> PathBuf {
+    crate::models::config::cache_directory().join("responses")
+}
+
+/// Decode a body as UTF-8; a truncated one may end mid-character, which is dropped
+fn decode_body(bytes: &[u8], truncated: bool) -> String {
+    let end = match std::str::from_utf8(bytes) {
+        Err(e) if truncated && e.error_len().is_none() => e.valid_up_to(),
+        _ => bytes.len(),
+    };
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+async fn send_http_request_impl(
+    request: HttpRequest,
+    cookie_jar: &SharedCookieJar,
+    display_limit: usize,
+) -> Result<HttpResponse> {
     use std::time::Instant;
 
     let start_time = Instant::now();
 
-    // Create reqwest client
-    let client = reqwest::Client::new();
+    // Create a client honoring the request's own timeout, redirect and TLS settings
+    let settings = &request.settings;
+    let redirect_policy = if settings.follow_redirects {
+        reqwest::redirect::Policy::limited(settings.max_redirects)
+    } else {
+        reqwest::redirect::Policy::none()
+    };
+    let resolver = TimedResolver::default();
+    let connector = TimedConnector::default();
+    let mut client_builder = reqwest::Client::builder()
+        .dns_resolver(Arc::new(resolver.clone()))
+        .connector_layer(connector.clone())
+        .redirect(redirect_policy)
+        .danger_accept_invalid_certs(!settings.verify_ssl)
+        .user_agent(settings.effective_user_agent())
+        .tls_info(true);
+    if settings.timeout_secs > 0 {
+        client_builder =
+            client_builder.timeout(std::time::Duration::from_secs(settings.timeout_secs));
+    }
+    client_builder = apply_proxy(client_builder, &settings.proxy)?;
+    let client = apply_tls(client_builder, &settings.tls)?
+        .build()
+        .context("Failed to build HTTP client")?;
 
     // Build the request
     let mut req_builder = match request.method {
@@ -2473,6 +9040,33 @@ async fn send_http_request_impl(request: HttpRequest) -> Result<HttpResponse> {
             }
             req_builder.form(&form_data)
         }
+        HttpRequestBody::Multipart { parts } => {
+            let body = crate::services::multipart::encode_multipart(parts)?;
+            req_builder
+                .header("Content-Type", body.content_type)
+                .body(body.bytes)
+        }
+        HttpRequestBody::File { path, content_type } => {
+            // Streamed rather than read into memory; an explicit Content-Type header wins
+            let file_path = crate::services::storage_migration::expand_user_path(path);
+            let file = tokio::fs::File::open(&file_path)
+                .await
+                .with_context(|| format!("Failed to open body file: {}", file_path.display()))?;
+            let length = file.metadata().await?.len();
+            let has_content_type = request
+                .headers
+                .iter()
+                .any(|h| h.enabled && h.name.eq_ignore_ascii_case("content-type"));
+            if !has_content_type {
+                req_builder = req_builder.header(
+                    "Content-Type",
+                    crate::services::multipart::file_content_type(path, content_type),
+                );
+            }
+            req_builder
+                .header(reqwest::header::CONTENT_LENGTH, length)
+                .body(file)
+        }
     };
 
     // Add authentication
@@ -2488,11 +9082,145 @@ async fn send_http_request_impl(request: HttpRequest) -> Result<HttpResponse> {
             crate::models::ApiKeyLocation::Header => req_builder.header(key, value),
             crate::models::ApiKeyLocation::QueryParam => req_builder.query(&[(key, value)]),
         },
+        HttpAuth::OAuth2ClientCredentials { .. } | HttpAuth::OAuth2AuthorizationCode { .. } => {
+            anyhow::bail!("OAuth 2.0 token must be fetched before sending")
+        }
+        HttpAuth::Script { script_path } => {
+            let headers = crate::services::auth_script::compute_auth_headers(script_path, &request)
+                .await
+                .context("Custom auth script failed")?;
+            for header in headers {
+                req_builder = req_builder.header(&header.name, &header.value);
+            }
+            req_builder
+        }
+    };
+
+    // Revalidate a cached copy instead of downloading it again
+    let cache = ResponseCache::default();
+    let cache_key = ResponseCache::cache_key(&request).filter(|_| request.settings.use_cache);
+    let cached = cache_key.as_deref().and_then(|key| cache.lookup(key));
+    if let Some(entry) = &cached {
+        for (name, value) in entry.conditional_headers() {
+            // Headers the user set explicitly win
+            let user_set = request
+                .headers
+                .iter()
+                .any(|h| h.enabled && h.name.eq_ignore_ascii_case(name));
+            if !user_set {
+                req_builder = req_builder.header(name, value);
+            }
+        }
+    }
+
+    // Build first so the sizes are those of the request actually sent
+    let mut built_request = req_builder.build()?;
+
+    // Send the jar's cookies for this URL, unless the request sets its own Cookie header
+    let user_cookies = request
+        .headers
+        .iter()
+        .any(|h| h.enabled && h.name.eq_ignore_ascii_case("cookie"));
+    if !user_cookies
+        && let Some(cookies) = cookie_jar
+            .lock()
+            .unwrap()
+            .cookie_header(built_request.url())
+        && let Ok(value) = reqwest::header::HeaderValue::from_str(&cookies)
+    {
+        built_request
+            .headers_mut()
+            .insert(reqwest::header::COOKIE, value);
+    }
+
+    let mut transfer = TransferStats {
+        request_header_count: built_request.headers().len(),
+        request_header_bytes: transfer_header_bytes(built_request.headers()),
+        request_body_bytes: built_request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .map_or(0, <[u8]>::len),
+        ..TransferStats::default()
     };
 
     // Send the request
-    let response = req_builder.send().await?;
-    let duration_ms = start_time.elapsed().as_millis() as u64;
+    let prepare_ms = start_time.elapsed().as_millis() as u64;
+    let sent_at = Instant::now();
+    let mut response = client.execute(built_request).await.map_err(|e| {
+        if e.is_timeout() {
+            anyhow::anyhow!(
+                "Timed out after {} s (Settings tab: timeout)",
+                request.settings.timeout_secs
+            )
+        } else if e.is_redirect() {
+            anyhow::anyhow!(
+                "Stopped after {} redirects (Settings tab: max redirects)",
+                request.settings.max_redirects
+            )
+        } else {
+            e.into()
+        }
+    })?;
+    // The connector's time includes the lookups it made; each is shown as its own phase
+    let dns = resolver.elapsed();
+    let connecting = connector.elapsed();
+    let mut timing = TimingBreakdown {
+        prepare_ms,
+        dns_ms: dns.map(|dns| dns.as_millis() as u64),
+        connect_ms: connecting.map(|connecting| {
+            connecting
+                .saturating_sub(dns.unwrap_or_default())
+                .as_millis() as u64
+        }),
+        tls_handshake: connecting.is_some() && response.url().scheme() == "https",
+        waiting_ms: sent_at
+            .elapsed()
+            .saturating_sub(connecting.or(dns).unwrap_or_default())
+            .as_millis() as u64,
+        download_ms: 0,
+    };
+    cookie_jar.lock().unwrap().store_response_cookies(
+        response.url(),
+        response
+            .headers()
+            .get_all(reqwest::header::SET_COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok()),
+    );
+    // Cipher suites aren't exposed by the TLS backend; the protocol and certificate are
+    let connection = ConnectionInfo {
+        proxy: effective_proxy(&request.settings.proxy, &request.url),
+        proxy_bypassed: false,
+        http_version: format!("{:?}", response.version()),
+        certificate_sha256: response
+            .extensions()
+            .get::<reqwest::tls::TlsInfo>()
+            .and_then(|info| info.peer_certificate())
+            .map(certificate_fingerprint),
+    };
+    transfer.response_header_bytes = transfer_header_bytes(response.headers());
+    transfer.response_wire_bytes = response
+        .headers()
+        .get(reqwest::header::CONTENT_LENGTH)
+        .and_then(|length| length.to_str().ok()?.parse().ok());
+
+    if response.status() == reqwest::StatusCode::NOT_MODIFIED
+        && let Some(entry) = cached
+    {
+        return Ok(HttpResponse {
+            status_code: 304,
+            status_text: "Not Modified".to_string(),
+            duration_ms: start_time.elapsed().as_millis() as u64,
+            served_from_cache: true,
+            transfer: TransferStats {
+                response_wire_bytes: Some(0),
+                ..transfer
+            },
+            connection,
+            timing,
+            ..entry.response
+        });
+    }
 
     // Extract response data
     let status_code = response.status().as_u16();
@@ -2519,15 +9247,85 @@ async fn send_http_request_impl(request: HttpRequest) -> Result<HttpResponse> {
         .unwrap_or("text/plain")
         .to_string();
 
-    // Extract body
-    let body = response.text().await?;
+    // Stream the body so huge responses neither stall the send nor fill memory: the start is
+    // kept for display and, past the limit, the whole body is spooled to disk instead
+    let download_start = Instant::now();
+    let mut kept: Vec<u8> = Vec::new();
+    let mut total_bytes: u64 = 0;
+    let mut spool: Option<(PathBuf, tokio::fs::File)> = None;
+    let mut truncated = false;
+    while let Some(chunk) = response.chunk().await? {
+        total_bytes += chunk.len() as u64;
+        if let Some((_, file)) = spool.as_mut() {
+            file.write_all(&chunk).await?;
+        } else if truncated {
+            // Safe mode writes nothing, so the rest is only counted
+        } else if kept.len() + chunk.len() <= display_limit {
+            kept.extend_from_slice(&chunk);
+        } else {
+            truncated = true;
+            if !crate::models::config::is_read_only_storage() {
+                let directory = response_spool_directory();
+                tokio::fs::create_dir_all(&directory).await?;
+                let path = directory.join(format!("{}.body", uuid::Uuid::new_v4()));
+                let mut file = tokio::fs::File::create(&path)
+                    .await
+                    .with_context(|| format!("Failed to create {}", path.display()))?;
+                file.write_all(&kept).await?;
+                file.write_all(&chunk).await?;
+                spool = Some((path, file));
+            }
+            let room = display_limit - kept.len();
+            kept.extend_from_slice(&chunk[..room]);
+        }
+    }
+    if let Some((_, file)) = spool.as_mut() {
+        file.flush().await?;
+    }
+    let truncation = truncated.then(|| BodyTruncation {
+        total_bytes,
+        kept_bytes: kept.len() as u64,
+        full_body_path: spool.map(|(path, _)| path),
+    });
+    if truncation.is_some() && transfer.response_wire_bytes.is_none() {
+        transfer.response_wire_bytes = Some(total_bytes as usize);
+    }
+    let body = decode_body(&kept, truncation.is_some());
+    // Binary bodies keep their bytes for the hex view
+    let body_bytes = if is_binary_body(&content_type, &kept) {
+        kept
+    } else {
+        Vec::new()
+    };
+    timing.download_ms = download_start.elapsed().as_millis() as u64;
+    // The whole exchange, body included
+    let duration_ms = start_time.elapsed().as_millis() as u64;
 
-    Ok(HttpResponse {
+    let response = HttpResponse {
         status_code,
         status_text,
         headers,
         body,
+        body_bytes,
         content_type,
         duration_ms,
-    })
+        served_from_cache: false,
+        transfer,
+        connection,
+        assertions: Vec::new(),
+        extracted: Vec::new(),
+        truncation,
+        timing,
+    };
+
+    // A truncated body can't be replayed from the cache
+    if response.is_success()
+        && response.truncation.is_none()
+        && let Some(key) = &cache_key
+    {
+        // A cache write failure should never fail the request itself
+        let _ = cache.store(key, &response);
+    }
+
+    Ok(response)
 }
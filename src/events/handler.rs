@@ -1,12 +1,44 @@
-use crate::app::{App, AppMode, FocusedPane, InputMode};
-use crate::models::http::HttpRequestTab;
-use crate::models::http_client::{HttpMethod, HttpRequestBody};
-use crate::models::{FocusDirection, LogLevel, NodeType};
+use crate::app::{App, AppMode, FocusedPane, InputMode, SETTINGS_DIALOG_FIELDS};
+use crate::models::header_names::complete_header_name;
+use crate::models::http::BodyContentType;
+use crate::models::http::{HttpRequestTab, HttpResponseTab};
+use crate::models::http_client::{ApiKeyLocation, HttpAuth, HttpMethod, HttpRequestBody};
+use crate::models::{
+    FieldType, FocusDirection, KeyValueColumn, KeyValueEditor, KeyValueEntry, LogLevel, NodeType,
+};
+use crate::services::bug_report::BugReportFormat;
+use crate::services::snippet_generator::SnippetFormat;
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 /// Handle keyboard events and update app state accordingly
 pub async fn handle_key_event(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    // Jumps (pane, mode or opened item changes) are recorded for back/forward
+    let before = app.current_location();
+    let is_history_key = is_navigation_key(&key_event);
+    let result = dispatch_key_event(app, key_event).await;
+    if !is_history_key {
+        app.record_navigation(before);
+    }
+    result
+}
+
+/// Alt+←/→ (Alt+b/f where the terminal sends those instead) step back/forward
+fn is_navigation_key(key_event: &KeyEvent) -> bool {
+    key_event.modifiers.contains(KeyModifiers::ALT)
+        && matches!(
+            key_event.code,
+            KeyCode::Left | KeyCode::Right | KeyCode::Char('b') | KeyCode::Char('f')
+        )
+}
+
+async fn dispatch_key_event(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    // The performance overlay toggles from anywhere, so a slow dialog can be diagnosed too
+    if key_event.code == KeyCode::Char('g') && key_event.modifiers.contains(KeyModifiers::CONTROL) {
+        app.toggle_perf_overlay();
+        return Ok(());
+    }
+
     // Handle dialogs first (in priority order)
     if app.show_help_dialog {
         return handle_help_dialog_keys(app, key_event).await;
@@ -14,8 +46,8 @@ pub async fn handle_key_event(app: &mut App, key_event: KeyEvent) -> Result<()>
     if app.show_import_dialog {
         return handle_import_dialog_keys(app, key_event).await;
     }
-    if app.show_delete_confirmation_dialog {
-        return handle_delete_confirmation_keys(app, key_event).await;
+    if app.confirmation.is_some() {
+        return handle_confirmation_keys(app, key_event).await;
     }
     if app.show_template_dialog {
         return handle_template_dialog_keys(app, key_event).await;
@@ -29,6 +61,152 @@ pub async fn handle_key_event(app: &mut App, key_event: KeyEvent) -> Result<()>
     if app.show_login_popup {
         return handle_login_dialog_keys(app, key_event).await;
     }
+    if app.backup_dialog.is_some() {
+        return handle_backup_dialog_keys(app, key_event).await;
+    }
+    if app.show_settings_dialog {
+        return handle_settings_dialog_keys(app, key_event).await;
+    }
+    if app.show_whats_new {
+        return handle_whats_new_keys(app, key_event).await;
+    }
+    if app.show_health_panel {
+        return handle_health_panel_keys(app, key_event).await;
+    }
+    if app.update_notice.is_some() {
+        if matches!(key_event.code, KeyCode::Esc | KeyCode::Enter) {
+            app.dismiss_update_notice();
+        }
+        return Ok(());
+    }
+    if app.pending_draft.is_some() {
+        match key_event.code {
+            KeyCode::Enter | KeyCode::Char('y') => app.restore_pending_draft(),
+            KeyCode::Esc | KeyCode::Char('n') => app.discard_pending_draft(),
+            _ => {}
+        }
+        return Ok(());
+    }
+    if app.show_environment_dialog {
+        return handle_environment_dialog_keys(app, key_event).await;
+    }
+    if app.show_environment_switcher {
+        handle_environment_switcher_keys(app, key_event);
+        return Ok(());
+    }
+    if app.show_quick_switcher {
+        return handle_quick_switcher_keys(app, key_event).await;
+    }
+    if app.show_curl_import_dialog {
+        return handle_curl_import_dialog_keys(app, key_event).await;
+    }
+    if app.raw_headers_text.is_some() {
+        handle_raw_headers_dialog_keys(app, key_event);
+        return Ok(());
+    }
+    if app.show_url_editor {
+        handle_url_editor_keys(app, key_event);
+        return Ok(());
+    }
+    if app.show_snippet_dialog {
+        handle_snippet_dialog_keys(app, key_event);
+        return Ok(());
+    }
+    if app.show_input_presets_dialog {
+        handle_input_presets_dialog_keys(app, key_event);
+        return Ok(());
+    }
+    if app.clone_request_dialog.is_some() {
+        return handle_clone_request_dialog_keys(app, key_event).await;
+    }
+    if app.note_dialog.is_some() {
+        handle_note_dialog_keys(app, key_event);
+        return Ok(());
+    }
+    if app.folder_settings_dialog.is_some() {
+        handle_folder_settings_dialog_keys(app, key_event);
+        return Ok(());
+    }
+    if app.show_global_search {
+        return handle_global_search_keys(app, key_event).await;
+    }
+    if app.show_history_dialog {
+        return handle_history_dialog_keys(app, key_event).await;
+    }
+    if app.show_history_palette {
+        return handle_history_palette_keys(app, key_event).await;
+    }
+    if app.response_diff.is_some() {
+        handle_response_diff_keys(app, key_event);
+        return Ok(());
+    }
+    if app.show_collection_run {
+        return handle_collection_run_keys(app, key_event).await;
+    }
+    if app.show_load_test {
+        handle_load_test_keys(app, key_event);
+        return Ok(());
+    }
+    if app.audit_log_entries.is_some() {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => app.hide_audit_log(),
+            KeyCode::Up | KeyCode::Char('k') => app.scroll_audit_log(false),
+            KeyCode::Down | KeyCode::Char('j') => app.scroll_audit_log(true),
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    if app.show_template_info {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => app.hide_template_info(),
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    if app.show_run_queue {
+        handle_run_queue_keys(app, key_event);
+        return Ok(());
+    }
+    if app.show_transfer_stats {
+        if matches!(
+            key_event.code,
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q')
+        ) {
+            app.hide_transfer_stats();
+        }
+        return Ok(());
+    }
+
+    // A new request tab label being typed gets every key
+    if app.tab_rename_input.is_some() {
+        handle_tab_rename_keys(app, key_event);
+        return Ok(());
+    }
+    // A key/value row being typed into gets every key (so 'H', '?' etc. are just text)
+    if app.current_mode == AppMode::Http
+        && app.focused_pane == FocusedPane::Form
+        && app.http_state.key_value_editor.is_editing()
+    {
+        handle_http_key_value_keys(app, key_event);
+        return Ok(());
+    }
+    // Likewise for the response filter input
+    if app.current_mode == AppMode::Http
+        && app.focused_pane == FocusedPane::Logs
+        && app.http_state.response_filter_mode
+    {
+        handle_response_filter_keys(app, key_event);
+        return Ok(());
+    }
+    if app.current_mode == AppMode::Http
+        && app.focused_pane == FocusedPane::Logs
+        && app.http_state.response_search_mode
+    {
+        handle_response_search_keys(app, key_event);
+        return Ok(());
+    }
     // Global keybindings that work in all modes
     match key_event.code {
         // Quit application
@@ -51,6 +229,58 @@ pub async fn handle_key_event(app: &mut App, key_event: KeyEvent) -> Result<()>
             app.switch_mode(AppMode::Http);
             return Ok(());
         }
+        // Stop waiting on a hung server
+        KeyCode::Char('b')
+            if key_event.modifiers.contains(KeyModifiers::CONTROL)
+                && app.current_mode == AppMode::Http =>
+        {
+            app.cancel_http_request();
+            return Ok(());
+        }
+        // Load test the current request
+        KeyCode::Char('t')
+            if key_event.modifiers.contains(KeyModifiers::ALT)
+                && app.current_mode == AppMode::Http =>
+        {
+            app.show_load_test_dialog();
+            return Ok(());
+        }
+        // Environment switcher
+        KeyCode::Char('e')
+            if key_event.modifiers.contains(KeyModifiers::ALT)
+                && app.current_mode == AppMode::Http =>
+        {
+            app.show_environment_switcher();
+            return Ok(());
+        }
+        // Re-send the last sent request, whatever pane or tab has focus
+        KeyCode::Char('.')
+            if key_event.modifiers.contains(KeyModifiers::ALT)
+                && app.current_mode == AppMode::Http =>
+        {
+            if let Err(e) = app.resend_last_request().await {
+                app.log(LogLevel::Error, format!("Failed to re-send: {}", e));
+            }
+            return Ok(());
+        }
+        // Request tabs: Alt+h/l switch, Alt+Shift+H/L reorder, Alt+r rename, Alt+w close;
+        // Alt+s reveals or masks secrets
+        KeyCode::Char(c)
+            if key_event.modifiers.contains(KeyModifiers::ALT)
+                && app.current_mode == AppMode::Http
+                && matches!(c, 'h' | 'l' | 'H' | 'L' | 'r' | 'w' | 's') =>
+        {
+            match c {
+                'h' => app.cycle_request_tab(false),
+                'l' => app.cycle_request_tab(true),
+                'H' => app.move_request_tab(false),
+                'L' => app.move_request_tab(true),
+                'r' => app.start_tab_rename(),
+                's' => app.toggle_reveal_secrets(),
+                _ => app.close_request_tab(),
+            }
+            return Ok(());
+        }
         // Focus switching
         KeyCode::F(5) => {
             app.focus_pane(FocusedPane::Collections);
@@ -140,6 +370,98 @@ pub async fn handle_key_event(app: &mut App, key_event: KeyEvent) -> Result<()>
             app.show_help_dialog();
             return Ok(());
         }
+        KeyCode::F(9) => {
+            app.show_settings_dialog();
+            return Ok(());
+        }
+        KeyCode::F(10) => {
+            app.show_whats_new();
+            return Ok(());
+        }
+        KeyCode::F(11) => {
+            app.show_health_panel();
+            return Ok(());
+        }
+        KeyCode::Char('e')
+            if key_event.modifiers.contains(KeyModifiers::CONTROL)
+                && app.current_mode == AppMode::Http =>
+        {
+            app.show_environment_dialog();
+            return Ok(());
+        }
+        KeyCode::Char('l') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            match app.current_mode {
+                AppMode::Http => app.toggle_http_split_layout(),
+                AppMode::Automation => app.toggle_automation_run_layout(),
+            }
+            return Ok(());
+        }
+        KeyCode::Char('w')
+            if key_event.modifiers.contains(KeyModifiers::CONTROL)
+                && app.current_mode == AppMode::Http =>
+        {
+            app.toggle_request_response_focus();
+            return Ok(());
+        }
+        KeyCode::Char('u')
+            if key_event.modifiers.contains(KeyModifiers::CONTROL)
+                && app.current_mode == AppMode::Http =>
+        {
+            app.show_curl_import_dialog();
+            return Ok(());
+        }
+        KeyCode::Char('k') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.show_global_search();
+            return Ok(());
+        }
+        KeyCode::Char('a') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.show_audit_log();
+            return Ok(());
+        }
+        // Back/forward through visited panes and items
+        KeyCode::Left | KeyCode::Char('b') if is_navigation_key(&key_event) => {
+            app.navigate_back().await;
+            return Ok(());
+        }
+        KeyCode::Right | KeyCode::Char('f') if is_navigation_key(&key_event) => {
+            app.navigate_forward().await;
+            return Ok(());
+        }
+        KeyCode::Char('r')
+            if key_event.modifiers.contains(KeyModifiers::CONTROL)
+                && app.current_mode == AppMode::Http =>
+        {
+            app.show_history_dialog();
+            return Ok(());
+        }
+        KeyCode::Char('y')
+            if key_event.modifiers.contains(KeyModifiers::CONTROL)
+                && app.current_mode == AppMode::Http =>
+        {
+            app.show_history_palette();
+            return Ok(());
+        }
+        KeyCode::Char('t')
+            if key_event.modifiers.contains(KeyModifiers::CONTROL)
+                && app.current_mode == AppMode::Http =>
+        {
+            app.show_transfer_stats();
+            return Ok(());
+        }
+        KeyCode::Char('r')
+            if key_event.modifiers.contains(KeyModifiers::CONTROL)
+                && app.current_mode == AppMode::Automation =>
+        {
+            app.show_run_queue();
+            return Ok(());
+        }
+        // Quick switcher (Ctrl+Tab where the terminal reports it, Ctrl+O everywhere)
+        KeyCode::Tab | KeyCode::Char('o')
+            if key_event.modifiers.contains(KeyModifiers::CONTROL) =>
+        {
+            app.show_quick_switcher();
+            return Ok(());
+        }
         // TODO: remove later only used for testing
         // ADD THIS: Test Python integration
         // KeyCode::F(9) => {
@@ -161,8 +483,105 @@ pub async fn handle_key_event(app: &mut App, key_event: KeyEvent) -> Result<()>
     Ok(())
 }
 
+/// Handle pasted text (bracketed paste) by routing it to the open text input
+pub fn handle_paste_event(app: &mut App, text: &str) {
+    if app.show_curl_import_dialog {
+        app.curl_import_add_text(text);
+    } else if app.raw_headers_text.is_some() {
+        app.raw_headers_add_text(text);
+    } else if app.show_url_editor
+        || (app.current_mode == AppMode::Http
+            && app.input_mode == InputMode::Edit
+            && app.focused_pane == FocusedPane::Form
+            && app.http_state.is_url_editable())
+    {
+        app.http_state.insert_url_text(text);
+    }
+}
+
+/// Handle keyboard events for the expanded URL editor
+fn handle_url_editor_keys(app: &mut App, key_event: KeyEvent) {
+    match key_event.code {
+        KeyCode::Esc => app.hide_url_editor(false),
+        KeyCode::Enter => app.hide_url_editor(true),
+        KeyCode::Char(c)
+            if key_event.modifiers.is_empty() || key_event.modifiers == KeyModifiers::SHIFT =>
+        {
+            app.http_state.insert_url_char(c);
+        }
+        KeyCode::Backspace => app.http_state.delete_url_char(),
+        KeyCode::Delete => app.http_state.delete_url_char_forward(),
+        KeyCode::Left => app.http_state.move_url_cursor_left(),
+        KeyCode::Right => app.http_state.move_url_cursor_right(),
+        KeyCode::Home => app.http_state.move_url_cursor_home(),
+        KeyCode::End => app.http_state.move_url_cursor_to_end(),
+        _ => {}
+    }
+}
+
+/// Handle keyboard events for the raw headers editor
+fn handle_raw_headers_dialog_keys(app: &mut App, key_event: KeyEvent) {
+    match key_event.code {
+        KeyCode::Esc => app.hide_raw_headers_dialog(),
+        KeyCode::Char('s') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.apply_raw_headers();
+        }
+        KeyCode::Enter => app.raw_headers_add_text("\n"),
+        KeyCode::Char(c)
+            if key_event.modifiers.is_empty() || key_event.modifiers == KeyModifiers::SHIFT =>
+        {
+            app.raw_headers_add_text(c.encode_utf8(&mut [0; 4]));
+        }
+        KeyCode::Backspace => app.raw_headers_backspace(),
+        KeyCode::Delete => {
+            app.raw_headers_text = Some(String::new());
+            app.raw_headers_error = None;
+        }
+        _ => {}
+    }
+}
+
+/// Handle keyboard events for the cURL import dialog
+async fn handle_curl_import_dialog_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    match key_event.code {
+        KeyCode::Esc => {
+            app.hide_curl_import_dialog();
+        }
+        // A trailing backslash is a line continuation, not the end of the command
+        KeyCode::Enter if app.curl_import_text.trim_end().ends_with('\\') => {
+            app.curl_import_add_text("\n");
+        }
+        KeyCode::Enter => {
+            app.import_curl_command();
+        }
+        KeyCode::Char(c)
+            if key_event.modifiers.is_empty() || key_event.modifiers == KeyModifiers::SHIFT =>
+        {
+            app.curl_import_add_text(c.encode_utf8(&mut [0; 4]));
+        }
+        KeyCode::Backspace => {
+            app.curl_import_backspace();
+        }
+        KeyCode::Delete => {
+            app.curl_import_text.clear();
+            app.curl_import_error = None;
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
 /// Handle keyboard events for the import dialog
 async fn handle_import_dialog_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    // While a Postman import is streaming, the only thing to do is cancel it
+    if app.import_progress.is_some() {
+        if key_event.code == KeyCode::Esc {
+            app.cancel_postman_import();
+        }
+        return Ok(());
+    }
+
     match key_event.code {
         // Cancel import
         KeyCode::Esc => {
@@ -177,7 +596,7 @@ async fn handle_import_dialog_keys(app: &mut App, key_event: KeyEvent) -> Result
         }
 
         // Text input for file path
-        KeyCode::Char(c) 
+        KeyCode::Char(c)
             if key_event.modifiers.is_empty() || key_event.modifiers == KeyModifiers::SHIFT =>
         {
             app.import_dialog_add_char(c);
@@ -198,6 +617,11 @@ async fn handle_import_dialog_keys(app: &mut App, key_event: KeyEvent) -> Result
             app.import_dialog_suggest_path();
         }
 
+        // Switch between Postman and OpenAPI
+        KeyCode::Char('f') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.cycle_import_format();
+        }
+
         // Quick paths (for common locations)
         KeyCode::Char('d') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
             // Suggest Downloads folder
@@ -299,6 +723,9 @@ async fn handle_template_dialog_keys(app: &mut App, key_event: KeyEvent) -> Resu
 /// Handle keyboard events for the collections tree
 async fn handle_tree_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
     match key_event.code {
+        KeyCode::PageDown if app.is_folder_readme_shown() => app.scroll_folder_readme(10),
+        KeyCode::PageUp if app.is_folder_readme_shown() => app.scroll_folder_readme(-10),
+
         // Navigate tree
         KeyCode::Up => {
             app.tree_state.move_focus(FocusDirection::Up);
@@ -411,6 +838,13 @@ async fn handle_tree_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
             app.show_rename_dialog();
         }
 
+        // Duplicate the focused request/template as "<name> copy" and rename it
+        KeyCode::Char('d') => {
+            if let Err(e) = app.duplicate_focused_item().await {
+                app.log(LogLevel::Error, format!("Failed to duplicate: {:#}", e));
+            }
+        }
+
         // === CLIPBOARD OPERATIONS ===
 
         // Cut (Ctrl+X)
@@ -449,23 +883,65 @@ async fn handle_tree_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
             }
         }
 
-        // === IMPORT OPERATIONS (HTTP mode only) ===
+        // === IMPORT OPERATIONS ===
+        // Collections in HTTP mode, template bundles in Automation mode
         KeyCode::Char('i') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-            if app.current_mode == AppMode::Http {
-                app.show_import_dialog();
-            } else {
-                app.log(LogLevel::Info, "Import is only available in HTTP Client mode (F4)");
-            }
+            app.show_import_dialog();
         }
 
-
         // Alternative import shortcut (F8 key)
         KeyCode::F(8) => {
-            if app.current_mode == AppMode::Http {
-                app.show_import_dialog();
-            } else {
-                app.log(LogLevel::Info, "Import is only available in HTTP Client mode (F4)");
-            }
+            app.show_import_dialog();
+        }
+
+        // Share the focused template as a checksummed bundle
+        KeyCode::Char('x') if app.current_mode == AppMode::Automation => {
+            app.export_template_bundle();
+        }
+
+        // === COLLECTION RUNNER (HTTP mode only) ===
+
+        // Run every request in the focused folder
+        KeyCode::Char('x') if app.current_mode == AppMode::Http => {
+            app.run_collection_folder();
+        }
+
+        // Re-open the results of the latest run
+        KeyCode::Char('X') if app.current_mode == AppMode::Http => {
+            app.show_collection_run_results();
+        }
+
+        // Bind the focused folder to an environment
+        KeyCode::Char('E') if app.current_mode == AppMode::Http => {
+            app.cycle_folder_environment();
+        }
+
+        // Edit the auth and variables the focused folder's requests inherit
+        KeyCode::Char('A') if app.current_mode == AppMode::Http => {
+            app.show_folder_settings_dialog();
+        }
+
+        // Color-label the focused folder
+        KeyCode::Char('l') if app.current_mode == AppMode::Http => {
+            app.cycle_folder_color();
+        }
+
+        // Pin the focused folder above (+) or below (-) its alphabetical place
+        KeyCode::Char('+') if app.current_mode == AppMode::Http => {
+            app.adjust_folder_weight(1);
+        }
+        KeyCode::Char('-') if app.current_mode == AppMode::Http => {
+            app.adjust_folder_weight(-1);
+        }
+
+        // Annotate the focused item, optionally with a "review by" reminder
+        KeyCode::Char('n') => {
+            app.show_note_dialog();
+        }
+
+        // Clone the focused request into a folder, optionally for another environment
+        KeyCode::Char('c') if app.current_mode == AppMode::Http => {
+            app.show_clone_request_dialog();
         }
 
         // === UTILITY OPERATIONS ===
@@ -489,7 +965,7 @@ async fn handle_tree_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
                 AppMode::Automation => "Automation",
                 AppMode::Http => "HTTP Client",
             };
-            app.log(LogLevel::Info, 
+            app.log(LogLevel::Info,
                 format!("{} Tree Help: ↑/↓=Navigate, Enter=Load/Expand, Space=Toggle, Ctrl+N=New, Ctrl+F=Folder, F2/R=Rename, Del=Delete, F12=Refresh", mode_name)
             );
         }
@@ -509,7 +985,7 @@ async fn handle_form_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
     Ok(())
 }
 
-/// Handle keyboard events for the form pane (both automation and HTTP modes)
+// Handle keyboard events for the form pane (both automation and HTTP modes)
 // async fn handle_form_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
 //     // // Handle log search when logs pane is focused
 //     // if app.focused_pane == FocusedPane::Logs && app.show_logs && key_event.code != KeyCode::Esc {
@@ -532,6 +1008,11 @@ async fn handle_form_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
 
 /// Handle HTTP form keys in normal mode
 async fn handle_http_normal_mode_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    // Row editing on the headers, params and form body tabs
+    if handle_http_key_value_keys(app, key_event) {
+        return Ok(());
+    }
+
     match key_event.code {
         // Enter edit mode
         KeyCode::Enter => {
@@ -543,6 +1024,11 @@ async fn handle_http_normal_mode_keys(app: &mut App, key_event: KeyEvent) -> Res
             app.enter_edit_mode();
         }
 
+        // Edit a long URL in the expanded popup
+        KeyCode::Char('U') => {
+            app.show_url_editor();
+        }
+
         // Tab navigation for request editor
         KeyCode::Tab => {
             app.http_state.next_request_tab();
@@ -565,6 +1051,137 @@ async fn handle_http_normal_mode_keys(app: &mut App, key_event: KeyEvent) -> Res
             );
         }
 
+        // Copy the request as cURL or a code snippet
+        KeyCode::Char('y') => {
+            app.show_snippet_dialog();
+        }
+
+        // Auth tab: change type, select field, toggle API key location
+        KeyCode::Char('c') if app.http_state.current_request_tab == HttpRequestTab::Auth => {
+            app.http_state.cycle_auth_type();
+            app.log(
+                LogLevel::Debug,
+                format!(
+                    "Auth type set to {}",
+                    app.http_state.current_request.auth.title()
+                ),
+            );
+        }
+        KeyCode::Char('j') | KeyCode::Down
+            if app.http_state.current_request_tab == HttpRequestTab::Auth =>
+        {
+            app.http_state.move_auth_focus(true);
+        }
+        KeyCode::Char('k') | KeyCode::Up
+            if app.http_state.current_request_tab == HttpRequestTab::Auth =>
+        {
+            app.http_state.move_auth_focus(false);
+        }
+        KeyCode::Char('l') if app.http_state.current_request_tab == HttpRequestTab::Auth => {
+            if let HttpAuth::ApiKey { location, .. } = &mut app.http_state.current_request.auth {
+                *location = match location {
+                    ApiKeyLocation::Header => ApiKeyLocation::QueryParam,
+                    ApiKeyLocation::QueryParam => ApiKeyLocation::Header,
+                };
+            }
+        }
+        // Auth tab (OAuth 2.0): fetch a fresh token now, or drop the cached one
+        KeyCode::Char('g') if app.http_state.current_request_tab == HttpRequestTab::Auth => {
+            app.fetch_oauth2_token();
+        }
+        KeyCode::Char('f') if app.http_state.current_request_tab == HttpRequestTab::Auth => {
+            app.forget_oauth2_token();
+        }
+
+        // Fetch all pages into one document
+        KeyCode::Char('p') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.fetch_all_pages();
+        }
+
+        // Settings tab: pagination rule, row selection, toggles and numbers
+        KeyCode::Char('p') if app.http_state.current_request_tab == HttpRequestTab::Settings => {
+            app.http_state.cycle_pagination_rule();
+            app.log(
+                LogLevel::Debug,
+                format!(
+                    "Pagination rule set to {}",
+                    app.http_state.current_request.pagination.rule.title()
+                ),
+            );
+        }
+        KeyCode::Char('a') if app.http_state.current_request_tab == HttpRequestTab::Settings => {
+            app.http_state.toggle_pagination_auto_follow();
+            let state = if app.http_state.current_request.pagination.auto_follow {
+                "on"
+            } else {
+                "off"
+            };
+            app.log(
+                LogLevel::Debug,
+                format!("Auto-follow pagination on send {}", state),
+            );
+        }
+        KeyCode::Char('j') | KeyCode::Down
+            if app.http_state.current_request_tab == HttpRequestTab::Settings =>
+        {
+            app.http_state.move_settings_focus(true);
+        }
+        KeyCode::Char('k') | KeyCode::Up
+            if app.http_state.current_request_tab == HttpRequestTab::Settings =>
+        {
+            app.http_state.move_settings_focus(false);
+        }
+        KeyCode::Char('+') | KeyCode::Char('-')
+            if app.http_state.current_request_tab == HttpRequestTab::Settings =>
+        {
+            let steps = if key_event.code == KeyCode::Char('+') {
+                1
+            } else {
+                -1
+            };
+            app.http_state.step_focused_setting(steps);
+        }
+        KeyCode::Char('t') if app.http_state.current_request_tab == HttpRequestTab::Settings => {
+            app.http_state.toggle_focused_setting();
+        }
+
+        // Edit all headers as raw `Name: Value` text
+        KeyCode::Char('R') if app.http_state.current_request_tab == HttpRequestTab::Headers => {
+            app.show_raw_headers_dialog();
+        }
+        // Edit the default headers every request gets
+        KeyCode::Char('G') if app.http_state.current_request_tab == HttpRequestTab::Headers => {
+            app.show_default_headers_dialog();
+        }
+
+        // Open the body or script in $EDITOR
+        KeyCode::Char('E') => {
+            app.request_external_edit();
+        }
+
+        // Edit a JSON body as path = value rows, or go back to the raw text
+        KeyCode::Char('B') if app.http_state.current_request_tab == HttpRequestTab::Body => {
+            match app.http_state.toggle_json_builder() {
+                Ok(()) if app.http_state.json_builder.is_some() => {
+                    app.log(LogLevel::Debug, "Editing the JSON body as builder rows");
+                }
+                Ok(()) => app.log(LogLevel::Debug, "Editing the raw JSON body"),
+                Err(e) => app.log(LogLevel::Warn, format!("JSON builder: {}", e)),
+            }
+        }
+
+        // Body type selection on the Body tab
+        KeyCode::Char('b') if app.http_state.current_request_tab == HttpRequestTab::Body => {
+            app.http_state.cycle_body_type();
+            app.log(
+                LogLevel::Debug,
+                format!(
+                    "Body type set to {}",
+                    app.http_state.current_body_type.title()
+                ),
+            );
+        }
+
         // Method selection (M key)
         KeyCode::Char('m') => {
             cycle_http_method(app);
@@ -599,6 +1216,8 @@ async fn handle_http_normal_mode_keys(app: &mut App, key_event: KeyEvent) -> Res
                 HttpRequestTab::Body => {
                     // Clear body content
                     app.http_state.set_body(HttpRequestBody::None);
+                    app.http_state.json_builder = None;
+                    app.http_state.json_builder_error = None;
                     app.log(LogLevel::Debug, "Cleared request body");
                 }
                 HttpRequestTab::QueryParams => {
@@ -606,6 +1225,11 @@ async fn handle_http_normal_mode_keys(app: &mut App, key_event: KeyEvent) -> Res
                     app.http_state.current_request.query_params.clear();
                     app.log(LogLevel::Debug, "Cleared all query parameters");
                 }
+                HttpRequestTab::Cookies => {
+                    app.cookie_jar.lock().unwrap().clear();
+                    app.http_state.key_value_editor.reset();
+                    app.log(LogLevel::Info, "Cleared the cookie jar");
+                }
                 _ => {}
             }
         }
@@ -619,9 +1243,18 @@ async fn handle_http_normal_mode_keys(app: &mut App, key_event: KeyEvent) -> Res
                 );
             }
         }
-
-        // New request
-        KeyCode::Char('n') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+        // Send once straight to the server, skipping any proxy
+        KeyCode::Char('P') => {
+            if let Err(e) = app.send_http_request_direct().await {
+                app.log(
+                    LogLevel::Error,
+                    format!("Failed to send HTTP request: {}", e),
+                );
+            }
+        }
+
+        // New request
+        KeyCode::Char('n') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
             app.http_state.new_request();
             app.log(LogLevel::Info, "Created new HTTP request");
         }
@@ -640,21 +1273,103 @@ async fn handle_http_edit_mode_keys(app: &mut App, key_event: KeyEvent) -> Resul
             app.exit_edit_mode();
         }
 
-        // Text input (URL editing for now - we'll expand this)
+        // Body text editing (JSON, text and raw bodies)
         KeyCode::Char(c)
-            if key_event.modifiers.is_empty() || key_event.modifiers == KeyModifiers::SHIFT =>
+            if app.http_state.is_body_text_editable()
+                && (key_event.modifiers.is_empty()
+                    || key_event.modifiers == KeyModifiers::SHIFT) =>
+        {
+            app.http_state.insert_body_char(c);
+        }
+        KeyCode::Enter if app.http_state.is_body_text_editable() => {
+            app.http_state.insert_body_char('\n');
+        }
+        KeyCode::Backspace if app.http_state.is_body_text_editable() => {
+            app.http_state.delete_body_char();
+        }
+        KeyCode::Left if app.http_state.is_body_text_editable() => {
+            app.http_state.move_body_cursor_left();
+        }
+        KeyCode::Right if app.http_state.is_body_text_editable() => {
+            app.http_state.move_body_cursor_right();
+        }
+
+        // Pre-request script editing
+        KeyCode::Char(c)
+            if app.http_state.is_script_editable()
+                && (key_event.modifiers.is_empty()
+                    || key_event.modifiers == KeyModifiers::SHIFT) =>
         {
-            // For now, just handle URL editing
-            let mut url = app.http_state.current_request.url.clone();
-            url.push(c);
-            app.http_state.set_url(url);
+            app.http_state.insert_script_char(c);
+        }
+        KeyCode::Enter if app.http_state.is_script_editable() => {
+            app.http_state.insert_script_char('\n');
+        }
+        KeyCode::Backspace if app.http_state.is_script_editable() => {
+            app.http_state.delete_script_char();
+        }
+        KeyCode::Left if app.http_state.is_script_editable() => {
+            app.http_state.move_script_cursor_left();
+        }
+        KeyCode::Right if app.http_state.is_script_editable() => {
+            app.http_state.move_script_cursor_right();
         }
 
-        // Backspace
+        // Pagination field editing
+        KeyCode::Char(c)
+            if app.http_state.focused_settings_field_mut().is_some()
+                && (key_event.modifiers.is_empty()
+                    || key_event.modifiers == KeyModifiers::SHIFT) =>
+        {
+            if let Some(field) = app.http_state.focused_settings_field_mut() {
+                field.push(c);
+            }
+        }
+        KeyCode::Backspace if app.http_state.focused_settings_field_mut().is_some() => {
+            if let Some(field) = app.http_state.focused_settings_field_mut() {
+                field.pop();
+            }
+        }
+
+        // Auth field editing
+        KeyCode::Char(c)
+            if app.http_state.focused_auth_field_mut().is_some()
+                && (key_event.modifiers.is_empty()
+                    || key_event.modifiers == KeyModifiers::SHIFT) =>
+        {
+            if let Some(field) = app.http_state.focused_auth_field_mut() {
+                field.push(c);
+            }
+        }
+        KeyCode::Backspace if app.http_state.focused_auth_field_mut().is_some() => {
+            if let Some(field) = app.http_state.focused_auth_field_mut() {
+                field.pop();
+            }
+        }
+
+        // Anything else typed goes to the URL, at its cursor
+        KeyCode::Char(c)
+            if key_event.modifiers.is_empty() || key_event.modifiers == KeyModifiers::SHIFT =>
+        {
+            app.http_state.insert_url_char(c);
+        }
         KeyCode::Backspace if key_event.modifiers.is_empty() => {
-            let mut url = app.http_state.current_request.url.clone();
-            url.pop();
-            app.http_state.set_url(url);
+            app.http_state.delete_url_char();
+        }
+        KeyCode::Delete if app.http_state.is_url_editable() => {
+            app.http_state.delete_url_char_forward();
+        }
+        KeyCode::Left if app.http_state.is_url_editable() => {
+            app.http_state.move_url_cursor_left();
+        }
+        KeyCode::Right if app.http_state.is_url_editable() => {
+            app.http_state.move_url_cursor_right();
+        }
+        KeyCode::Home if app.http_state.is_url_editable() => {
+            app.http_state.move_url_cursor_home();
+        }
+        KeyCode::End if app.http_state.is_url_editable() => {
+            app.http_state.move_url_cursor_to_end();
         }
 
         // Navigate to next tab (but stay in edit mode)
@@ -695,6 +1410,189 @@ async fn handle_http_edit_mode_keys(app: &mut App, key_event: KeyEvent) -> Resul
     Ok(())
 }
 
+/// Route a key to the row editor of the current request tab, if it has one
+fn handle_http_key_value_keys(app: &mut App, key_event: KeyEvent) -> bool {
+    let state = &mut app.http_state;
+    let editor = &mut state.key_value_editor;
+
+    match state.current_request_tab {
+        HttpRequestTab::Headers => {
+            // Tab completes a known header name before moving on to the value
+            if key_event.code == KeyCode::Tab
+                && editor.editing == Some(KeyValueColumn::Name)
+                && let Some(completion) = complete_header_name(&editor.buffer)
+            {
+                editor.buffer = completion;
+                return true;
+            }
+            handle_key_value_keys(editor, &mut state.current_request.headers, key_event)
+        }
+        HttpRequestTab::QueryParams => {
+            handle_key_value_keys(editor, &mut state.current_request.query_params, key_event)
+        }
+        HttpRequestTab::Cookies => {
+            let mut jar = app.cookie_jar.lock().unwrap();
+            let before = jar.cookies.clone();
+            let consumed = handle_key_value_keys(editor, &mut jar.cookies, key_event);
+            if jar.cookies != before {
+                jar.changed = true;
+            }
+            consumed
+        }
+        HttpRequestTab::Body if state.current_body_type == BodyContentType::Form => {
+            if let HttpRequestBody::Form { fields } = &mut state.current_request.body {
+                handle_key_value_keys(editor, fields, key_event)
+            } else {
+                false
+            }
+        }
+        HttpRequestTab::Body
+            if state.current_body_type == BodyContentType::Json && state.json_builder.is_some() =>
+        {
+            let Some(rows) = &mut state.json_builder else {
+                return false;
+            };
+            let consumed = handle_key_value_keys(editor, rows, key_event);
+            if consumed {
+                state.sync_json_builder();
+            }
+            consumed
+        }
+        HttpRequestTab::Body if state.current_body_type == BodyContentType::Multipart => {
+            let HttpRequestBody::Multipart { parts } = &mut state.current_request.body else {
+                return false;
+            };
+            // f switches the selected part between text and file upload
+            if key_event.code == KeyCode::Char('f')
+                && key_event.modifiers.is_empty()
+                && !editor.is_editing()
+            {
+                if let Some(part) = parts.get_mut(editor.selected) {
+                    part.is_file = !part.is_file;
+                }
+                return true;
+            }
+            handle_key_value_keys(editor, parts, key_event)
+        }
+        _ => false,
+    }
+}
+
+/// Shared keybindings for every name/value list (headers, params, form fields, variables).
+///
+/// Returns whether the key was consumed.
+fn handle_key_value_keys<T: KeyValueEntry>(
+    editor: &mut KeyValueEditor,
+    rows: &mut Vec<T>,
+    key_event: KeyEvent,
+) -> bool {
+    if editor.is_editing() {
+        match key_event.code {
+            KeyCode::Esc => editor.cancel_edit(rows),
+            KeyCode::Enter => editor.commit_edit(rows),
+            KeyCode::Tab | KeyCode::BackTab => editor.switch_column(rows),
+            KeyCode::Backspace => {
+                editor.buffer.pop();
+            }
+            KeyCode::Char(c)
+                if key_event.modifiers.is_empty() || key_event.modifiers == KeyModifiers::SHIFT =>
+            {
+                editor.buffer.push(c);
+            }
+            _ => {}
+        }
+        return true;
+    }
+
+    match key_event.code {
+        KeyCode::Char('j') | KeyCode::Down => editor.select_next(rows),
+        KeyCode::Char('k') | KeyCode::Up => editor.select_prev(),
+        KeyCode::Char('a') => editor.add_row(rows),
+        KeyCode::Char('e') => editor.begin_edit(rows, KeyValueColumn::Name),
+        KeyCode::Char('v') => editor.begin_edit(rows, KeyValueColumn::Value),
+        KeyCode::Char('t') => editor.toggle_selected(rows),
+        KeyCode::Char('d') => {
+            editor.remove_selected(rows);
+        }
+        _ => return false,
+    }
+
+    true
+}
+
+/// Handle keyboard events for the environment variables dialog
+async fn handle_environment_dialog_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    // Typing an environment name
+    if let Some(input) = &mut app.environment_name_input {
+        match key_event.code {
+            KeyCode::Enter => app.finish_environment_rename(true),
+            KeyCode::Esc => app.finish_environment_rename(false),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) => input.push(c),
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    // Typing a proxy URL
+    if let Some(input) = &mut app.environment_proxy_input {
+        match key_event.code {
+            KeyCode::Enter => app.finish_environment_proxy_edit(true),
+            KeyCode::Esc => app.finish_environment_proxy_edit(false),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) => input.push(c),
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    if !app.environment_editor.is_editing() {
+        match key_event.code {
+            KeyCode::Esc => {
+                app.hide_environment_dialog();
+                return Ok(());
+            }
+            KeyCode::Char('[') | KeyCode::Char(']') => {
+                app.cycle_environment(key_event.code == KeyCode::Char(']'));
+                return Ok(());
+            }
+            KeyCode::Char('n') => {
+                app.create_environment();
+                return Ok(());
+            }
+            KeyCode::Char('r') => {
+                app.start_environment_rename();
+                return Ok(());
+            }
+            KeyCode::Char('p') => {
+                app.start_environment_proxy_edit();
+                return Ok(());
+            }
+            KeyCode::Char('D') => {
+                app.delete_active_environment();
+                return Ok(());
+            }
+            KeyCode::Char('s') => {
+                app.toggle_environment_variable_secret();
+                return Ok(());
+            }
+            _ => {}
+        }
+    }
+
+    handle_key_value_keys(
+        &mut app.environment_editor,
+        &mut app.http_state.environment_vars,
+        key_event,
+    );
+
+    Ok(())
+}
+
 /// Handle keyboard events for HTTP request editor
 async fn handle_http_form_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
     // Handle keys based on input mode
@@ -706,8 +1604,8 @@ async fn handle_http_form_keys(app: &mut App, key_event: KeyEvent) -> Result<()>
     Ok(())
 }
 
-/// TODO: rRemove the template selection
-/// Handle keyboard events for the form
+// TODO: rRemove the template selection
+// Handle keyboard events for the form
 // async fn handle_form_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
 //     // If logging panel is open and we're searching, handle search input
 //     // TODO: might not need this anymore if logs are on by default
@@ -897,12 +1795,48 @@ async fn handle_http_form_keys(app: &mut App, key_event: KeyEvent) -> Result<()>
 // }
 
 async fn handle_normal_mode_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    let focused_type = app
+        .automation_state
+        .get_focused_field()
+        .map(|field| field.field_type.clone());
+
     match key_event.code {
+        // Checkboxes toggle instead of being typed into
+        KeyCode::Enter | KeyCode::Char(' ')
+            if matches!(focused_type, Some(FieldType::Checkbox)) =>
+        {
+            if let Some(field) = app.automation_state.get_focused_field_mut() {
+                field.toggle_checked();
+            }
+        }
+
+        // Number fields step within their range
+        KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Right
+            if matches!(focused_type, Some(FieldType::Number)) =>
+        {
+            if let Some(field) = app.automation_state.get_focused_field_mut() {
+                field.step_number(1);
+            }
+        }
+        KeyCode::Char('-') | KeyCode::Left if matches!(focused_type, Some(FieldType::Number)) => {
+            if let Some(field) = app.automation_state.get_focused_field_mut() {
+                field.step_number(-1);
+            }
+        }
+
         // Enter edit mode
         KeyCode::Enter => {
             app.enter_edit_mode();
         }
 
+        // Open the focused field in $EDITOR
+        KeyCode::Char('E') => {
+            app.request_external_edit();
+        }
+        // Show the loaded template's description and field docs
+        KeyCode::Char('I') => {
+            app.show_template_info();
+        }
         // Alternative: 'i' for insert mode (like Vim)
         KeyCode::Char('i') => {
             app.enter_edit_mode();
@@ -941,6 +1875,11 @@ async fn handle_normal_mode_keys(app: &mut App, key_event: KeyEvent) -> Result<(
             app.show_template_creation_dialog();
         }
 
+        // Field value presets layered on top of the loaded template
+        KeyCode::Char('p') => {
+            app.show_input_presets_dialog();
+        }
+
         KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
             // Demo credentials
             if let Err(e) = app
@@ -952,6 +1891,7 @@ async fn handle_normal_mode_keys(app: &mut App, key_event: KeyEvent) -> Result<(
                     format!("Failed to store credentials: {}", e),
                 );
             } else {
+                app.session_locked = false;
                 app.log(LogLevel::Info, "Demo credentials set");
             }
         }
@@ -988,7 +1928,22 @@ async fn handle_normal_mode_keys(app: &mut App, key_event: KeyEvent) -> Result<(
 }
 
 async fn handle_edit_mode_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    let editing_number = matches!(
+        app.automation_state
+            .get_focused_field()
+            .map(|field| &field.field_type),
+        Some(FieldType::Number)
+    );
+
     match key_event.code {
+        // Up/Down step a number while typing it
+        KeyCode::Up | KeyCode::Down if editing_number => {
+            let steps = if key_event.code == KeyCode::Up { 1 } else { -1 };
+            if let Some(field) = app.automation_state.get_focused_field_mut() {
+                field.step_number(steps);
+            }
+            app.set_cursor_to_end_of_field();
+        }
         // Exit edit mode
         KeyCode::Esc => {
             app.exit_edit_mode();
@@ -1189,11 +2144,20 @@ async fn handle_automation_log_keys(app: &mut App, key_event: KeyEvent) -> Resul
             }
 
             // Clear search (if any)
-            KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-                if !app.log_search_query.is_empty() {
-                    app.log_search_query.clear();
-                    app.log(LogLevel::Debug, "Cleared log search");
-                }
+            KeyCode::Char('c')
+                if key_event.modifiers.contains(KeyModifiers::CONTROL)
+                    && !app.log_search_query.is_empty() =>
+            {
+                app.log_search_query.clear();
+                app.log(LogLevel::Debug, "Cleared log search");
+            }
+
+            // Markers and time-range filters
+            KeyCode::Char('m') => {
+                app.insert_log_marker();
+            }
+            KeyCode::Char('t') => {
+                app.cycle_log_time_filter();
             }
 
             _ => {}
@@ -1203,6 +2167,62 @@ async fn handle_automation_log_keys(app: &mut App, key_event: KeyEvent) -> Resul
     Ok(())
 }
 
+/// Handle typing into the response filter (Enter keeps the filter, Esc clears it)
+fn handle_response_filter_keys(app: &mut App, key_event: KeyEvent) {
+    match key_event.code {
+        KeyCode::Esc => {
+            app.http_state.response_filter.clear();
+            app.http_state.response_filter_mode = false;
+            app.log(LogLevel::Debug, "Cleared response filter");
+        }
+        KeyCode::Enter => {
+            app.http_state.response_filter_mode = false;
+        }
+        KeyCode::Char(c)
+            if key_event.modifiers.is_empty() || key_event.modifiers == KeyModifiers::SHIFT =>
+        {
+            app.http_state.response_filter.push(c);
+        }
+        KeyCode::Backspace => {
+            app.http_state.response_filter.pop();
+        }
+        KeyCode::Delete => {
+            app.http_state.response_filter.clear();
+        }
+        _ => {}
+    }
+}
+
+/// Handle typing in the response body find bar; matches update as you type
+fn handle_response_search_keys(app: &mut App, key_event: KeyEvent) {
+    match key_event.code {
+        KeyCode::Esc => {
+            app.http_state.response_search.clear();
+            app.http_state.response_search_mode = false;
+            app.response_search_changed();
+            app.log(LogLevel::Debug, "Cleared response search");
+        }
+        KeyCode::Enter => {
+            app.http_state.response_search_mode = false;
+        }
+        KeyCode::Char(c)
+            if key_event.modifiers.is_empty() || key_event.modifiers == KeyModifiers::SHIFT =>
+        {
+            app.http_state.response_search.push(c);
+            app.response_search_changed();
+        }
+        KeyCode::Backspace => {
+            app.http_state.response_search.pop();
+            app.response_search_changed();
+        }
+        KeyCode::Delete => {
+            app.http_state.response_search.clear();
+            app.response_search_changed();
+        }
+        _ => {}
+    }
+}
+
 /// Handle keyboard events for HTTP response viewer
 async fn handle_http_response_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
     match key_event.code {
@@ -1229,16 +2249,113 @@ async fn handle_http_response_keys(app: &mut App, key_event: KeyEvent) -> Result
         }
 
         // Copy response (TODO: implement clipboard)
-        KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-            if let Some(response) = &app.http_state.last_response {
-                // TODO: Copy response body to clipboard
-                app.log(LogLevel::Info, "Response copied to clipboard");
-            }
+        KeyCode::Char('c')
+            if key_event.modifiers.contains(KeyModifiers::CONTROL)
+                && app.http_state.last_response.is_some() =>
+        {
+            // TODO: Copy response body to clipboard
+            app.log(LogLevel::Info, "Response copied to clipboard");
+        }
+
+        // Move over formatted JSON and copy the JSONPath under the cursor
+        KeyCode::Char('j') | KeyCode::Down
+            if app.http_state.current_response_tab == HttpResponseTab::Body =>
+        {
+            app.move_response_cursor(1);
+        }
+        KeyCode::Char('k') | KeyCode::Up
+            if app.http_state.current_response_tab == HttpResponseTab::Body =>
+        {
+            app.move_response_cursor(-1);
+        }
+        KeyCode::Char('g') if app.http_state.current_response_tab == HttpResponseTab::Body => {
+            app.http_state.response_cursor = 0;
+        }
+        KeyCode::Char('G') if app.http_state.current_response_tab == HttpResponseTab::Body => {
+            app.move_response_cursor(isize::MAX);
+        }
+        KeyCode::Char('y') if app.http_state.current_response_tab == HttpResponseTab::Body => {
+            app.copy_response_json_path();
+        }
+
+        // Fold nodes of the JSON tree view and copy the value under the cursor
+        KeyCode::Char('t') => {
+            app.http_state.current_response_tab = HttpResponseTab::Body;
+            app.toggle_response_tree_view();
+        }
+        KeyCode::Enter | KeyCode::Char(' ')
+            if app.http_state.current_response_tab == HttpResponseTab::Body =>
+        {
+            app.fold_response_node(None);
+        }
+        KeyCode::Char('h') | KeyCode::Left
+            if app.http_state.current_response_tab == HttpResponseTab::Body =>
+        {
+            app.fold_response_node(Some(false));
+        }
+        KeyCode::Char('l') | KeyCode::Right
+            if app.http_state.current_response_tab == HttpResponseTab::Body =>
+        {
+            app.fold_response_node(Some(true));
+        }
+        KeyCode::Char('Y') if app.http_state.current_response_tab == HttpResponseTab::Body => {
+            app.copy_response_json_value();
+        }
+
+        // Filter the body with a JSONPath / jq-style expression
+        KeyCode::Char('/') => {
+            app.http_state.current_response_tab = HttpResponseTab::Body;
+            app.http_state.response_filter_mode = true;
+            app.log(LogLevel::Debug, "Entered response filter mode");
+        }
+
+        // Find text in the body, then step through the matches
+        KeyCode::Char('f') => {
+            app.http_state.current_response_tab = HttpResponseTab::Body;
+            app.http_state.response_search_mode = true;
+            app.log(LogLevel::Debug, "Entered response search mode");
+        }
+        KeyCode::Char('n') if !app.http_state.response_search.is_empty() => {
+            app.step_response_search(true);
+        }
+        KeyCode::Char('N') if !app.http_state.response_search.is_empty() => {
+            app.step_response_search(false);
+        }
+
+        // Compare with the previous response of this request
+        KeyCode::Char('d') => {
+            app.diff_with_previous_response();
+        }
+
+        // Switch the body between formatted (highlighted JSON) and raw text
+        KeyCode::Char('r') => {
+            app.http_state.response_raw_view = !app.http_state.response_raw_view;
+            app.log(
+                LogLevel::Debug,
+                if app.http_state.response_raw_view {
+                    "Showing raw response body"
+                } else {
+                    "Showing formatted response body"
+                },
+            );
+        }
+
+        // Save response body to the exports directory
+        KeyCode::Char('s') => {
+            app.export_response_body();
+        }
+
+        // Export the request/response pair as a bug report (Markdown or JSON)
+        KeyCode::Char('b') => {
+            app.export_bug_report(BugReportFormat::Markdown);
+        }
+        KeyCode::Char('B') => {
+            app.export_bug_report(BugReportFormat::Json);
         }
 
         // Clear response
         KeyCode::Delete => {
-            app.http_state.last_response = None;
+            app.http_state.clear_response();
             app.log(LogLevel::Debug, "Cleared response");
         }
 
@@ -1397,22 +2514,31 @@ pub fn get_help_text(app: &App) -> Vec<String> {
     help
 }
 
-/// Handle keyboard events for the delete confirmation dialog
-async fn handle_delete_confirmation_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
+/// Handle keyboard events for the shared confirmation dialog
+async fn handle_confirmation_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
     match key_event.code {
-        // Cancel deletion
         KeyCode::Esc => {
-            app.hide_delete_confirmation_dialog();
+            app.dismiss_confirmation();
         }
-        // Confirm deletion
         KeyCode::Enter => {
-            if let Err(e) = app.confirm_deletion().await {
-                app.log(LogLevel::Error, format!("Failed to delete: {}", e));
+            if let Err(e) = app.confirm_pending().await {
+                app.log(LogLevel::Error, format!("Failed: {}", e));
             }
         }
-        _ => {
-            // Ignore other keys in confirmation dialog
+        // Typed confirmation input; other keys are ignored
+        KeyCode::Backspace => {
+            if let Some(confirmation) = app.confirmation.as_mut() {
+                confirmation.typed.pop();
+            }
         }
+        KeyCode::Char(c) => {
+            if let Some(confirmation) = app.confirmation.as_mut()
+                && confirmation.typed_confirmation.is_some()
+            {
+                confirmation.typed.push(c);
+            }
+        }
+        _ => {}
     }
 
     Ok(())
@@ -1451,6 +2577,469 @@ async fn handle_folder_dialog_keys(app: &mut App, key_event: KeyEvent) -> Result
     Ok(())
 }
 
+/// Handle keyboard events for the What's New overlay
+async fn handle_whats_new_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    match key_event.code {
+        KeyCode::Esc | KeyCode::Enter | KeyCode::F(10) => {
+            app.hide_whats_new();
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.whats_new_scroll = app.whats_new_scroll.saturating_add(1);
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.whats_new_scroll = app.whats_new_scroll.saturating_sub(1);
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+async fn handle_health_panel_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    match key_event.code {
+        KeyCode::Esc | KeyCode::Enter | KeyCode::F(11) => {
+            app.hide_health_panel();
+        }
+        KeyCode::Char('r') => app.start_health_check(),
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.health_panel_scroll = app.health_panel_scroll.saturating_add(1);
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.health_panel_scroll = app.health_panel_scroll.saturating_sub(1);
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Handle keyboard events for the recent items quick switcher
+/// Handle keyboard events for the environment switcher
+fn handle_environment_switcher_keys(app: &mut App, key_event: KeyEvent) {
+    match key_event.code {
+        KeyCode::Esc => app.hide_environment_switcher(),
+        KeyCode::Enter => app.select_switcher_environment(app.environment_switcher_selected),
+        KeyCode::Tab | KeyCode::Char('j') | KeyCode::Down => app.environment_switcher_next(),
+        KeyCode::BackTab | KeyCode::Char('k') | KeyCode::Up => app.environment_switcher_previous(),
+        // Switch to the highlighted environment and edit its variables
+        KeyCode::Char('e') => {
+            app.select_switcher_environment(app.environment_switcher_selected);
+            app.show_environment_dialog();
+        }
+        KeyCode::Char(c) if c.is_ascii_digit() && c != '0' => {
+            let index = c.to_digit(10).unwrap_or(1) as usize - 1;
+            app.select_switcher_environment(index);
+        }
+        _ => {}
+    }
+}
+
+async fn handle_quick_switcher_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    match key_event.code {
+        KeyCode::Esc => {
+            app.hide_quick_switcher();
+        }
+        KeyCode::Enter => {
+            app.open_quick_switcher_selection().await?;
+        }
+        KeyCode::Char('o') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.quick_switcher_next();
+        }
+        KeyCode::Tab | KeyCode::Char('j') | KeyCode::Down => {
+            app.quick_switcher_next();
+        }
+        KeyCode::BackTab | KeyCode::Char('k') | KeyCode::Up => {
+            app.quick_switcher_previous();
+        }
+        KeyCode::Char(c) if c.is_ascii_digit() && c != '0' => {
+            let index = c.to_digit(10).unwrap_or(1) as usize - 1;
+            if index < app.config.recent_items.len() {
+                app.quick_switcher_selected = index;
+                app.open_quick_switcher_selection().await?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Handle keyboard events for the global search overlay
+async fn handle_global_search_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    match key_event.code {
+        KeyCode::Esc => app.hide_global_search(),
+        KeyCode::Enter => app.open_global_search_selection().await?,
+        KeyCode::Down | KeyCode::Tab => app.global_search_next(),
+        KeyCode::Up | KeyCode::BackTab => app.global_search_previous(),
+        KeyCode::Char('j') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.global_search_next()
+        }
+        KeyCode::Char('k') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.global_search_previous()
+        }
+        KeyCode::Backspace => app.global_search_backspace(),
+        KeyCode::Char(c)
+            if key_event.modifiers.is_empty() || key_event.modifiers == KeyModifiers::SHIFT =>
+        {
+            app.global_search_add_char(c)
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Handle keyboard events for the recent-sends palette
+async fn handle_history_palette_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    match key_event.code {
+        KeyCode::Esc => app.hide_history_palette(),
+        KeyCode::Enter => app.open_history_palette_selection(true).await?,
+        KeyCode::Tab => app.open_history_palette_selection(false).await?,
+        // One-key recall of the first nine sends
+        KeyCode::Char(c @ '1'..='9') if key_event.modifiers.contains(KeyModifiers::ALT) => {
+            app.resend_history_palette_entry(c as usize - '1' as usize)
+                .await?
+        }
+        KeyCode::Down => app.history_palette_next(),
+        KeyCode::Up => app.history_palette_previous(),
+        KeyCode::Char('j') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.history_palette_next()
+        }
+        KeyCode::Char('k') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.history_palette_previous()
+        }
+        KeyCode::Backspace => app.history_palette_backspace(),
+        KeyCode::Char(c)
+            if key_event.modifiers.is_empty() || key_event.modifiers == KeyModifiers::SHIFT =>
+        {
+            app.history_palette_add_char(c)
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Handle keyboard events for the request history browser
+async fn handle_history_dialog_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    match key_event.code {
+        KeyCode::Esc => app.hide_history_dialog(),
+        KeyCode::Enter => {
+            app.reopen_history_entry();
+        }
+        KeyCode::Char('s') | KeyCode::Char(' ') => app.resend_history_entry().await?,
+        KeyCode::Char('d') | KeyCode::Delete => app.delete_history_entry(),
+        KeyCode::Char('c') => app.diff_with_history_entry(),
+        KeyCode::Char('j') | KeyCode::Down => app.history_next(),
+        KeyCode::Char('k') | KeyCode::Up => app.history_previous(),
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Handle keyboard events for the collection run results
+async fn handle_collection_run_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    match key_event.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.hide_collection_run_results(),
+        KeyCode::Down | KeyCode::Char('j') => app.collection_run_next(),
+        KeyCode::Up | KeyCode::Char('k') => app.collection_run_previous(),
+        KeyCode::Enter => app.open_collection_run_result().await?,
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle keyboard events for the load test popup
+fn handle_load_test_keys(app: &mut App, key_event: KeyEvent) {
+    if app.load_test_running() {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => app.hide_load_test_dialog(),
+            KeyCode::Char('c') => app.cancel_load_test(),
+            _ => {}
+        }
+        return;
+    }
+    match key_event.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.hide_load_test_dialog(),
+        KeyCode::Enter => app.start_load_test(),
+        KeyCode::Tab | KeyCode::BackTab | KeyCode::Char('j') | KeyCode::Char('k') => {
+            app.load_test_next_field()
+        }
+        KeyCode::Char('+') | KeyCode::Char('=') => app.load_test_adjust(1),
+        KeyCode::Char('-') => app.load_test_adjust(-1),
+        KeyCode::Char(c) if c.is_ascii_digit() => {
+            app.load_test_add_digit(c.to_digit(10).unwrap_or_default())
+        }
+        KeyCode::Backspace => app.load_test_backspace(),
+        _ => {}
+    }
+}
+
+/// Handle keyboard events for the response diff overlay
+fn handle_response_diff_keys(app: &mut App, key_event: KeyEvent) {
+    match key_event.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.hide_response_diff(),
+        KeyCode::Char('j') | KeyCode::Down => app.scroll_response_diff(1),
+        KeyCode::Char('k') | KeyCode::Up => app.scroll_response_diff(-1),
+        KeyCode::PageDown => app.scroll_response_diff(20),
+        KeyCode::PageUp => app.scroll_response_diff(-20),
+        KeyCode::Char('g') | KeyCode::Home => app.response_diff_scroll = 0,
+        KeyCode::Char('G') | KeyCode::End => app.scroll_response_diff(isize::MAX),
+        KeyCode::Char('n') => app.jump_response_diff_change(true),
+        KeyCode::Char('N') => app.jump_response_diff_change(false),
+        _ => {}
+    }
+}
+
+/// Handle keyboard events for the copy-as-snippet format picker
+fn handle_snippet_dialog_keys(app: &mut App, key_event: KeyEvent) {
+    match key_event.code {
+        KeyCode::Esc => app.hide_snippet_dialog(),
+        KeyCode::Enter => app.copy_selected_snippet(),
+        KeyCode::Tab | KeyCode::Char('j') | KeyCode::Down => app.snippet_dialog_next(),
+        KeyCode::BackTab | KeyCode::Char('k') | KeyCode::Up => app.snippet_dialog_previous(),
+        KeyCode::Char(c) if c.is_ascii_digit() && c != '0' => {
+            let index = c.to_digit(10).unwrap_or(1) as usize - 1;
+            if index < SnippetFormat::all().len() {
+                app.snippet_dialog_selected = index;
+                app.copy_selected_snippet();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Handle keyboard events for the input presets picker
+fn handle_input_presets_dialog_keys(app: &mut App, key_event: KeyEvent) {
+    // Typing a name for a new preset
+    if let Some(input) = &mut app.input_preset_name_input {
+        match key_event.code {
+            KeyCode::Enter => app.finish_input_preset_save(true),
+            KeyCode::Esc => app.finish_input_preset_save(false),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) => input.push(c),
+            _ => {}
+        }
+        return;
+    }
+
+    match key_event.code {
+        KeyCode::Esc => app.hide_input_presets_dialog(),
+        KeyCode::Enter => app.apply_selected_input_preset(),
+        KeyCode::Char('j') | KeyCode::Down => app.input_presets_next(),
+        KeyCode::Char('k') | KeyCode::Up => app.input_presets_previous(),
+        KeyCode::Char('s') => app.start_input_preset_save(),
+        KeyCode::Char('D') | KeyCode::Delete => app.request_delete_input_preset(),
+        _ => {}
+    }
+}
+
+/// Handle keyboard events for the clone-to-folder dialog
+async fn handle_clone_request_dialog_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    match key_event.code {
+        KeyCode::Esc => app.hide_clone_request_dialog(),
+        KeyCode::Enter => {
+            if let Err(e) = app.confirm_clone_request().await {
+                app.log(LogLevel::Error, format!("Failed to clone request: {}", e));
+            }
+        }
+        KeyCode::Char('j') | KeyCode::Down => app.clone_dialog_move(true),
+        KeyCode::Char('k') | KeyCode::Up => app.clone_dialog_move(false),
+        KeyCode::Char('e') => app.cycle_clone_environment(),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle keyboard events while a request tab label is typed
+fn handle_tab_rename_keys(app: &mut App, key_event: KeyEvent) {
+    let Some(input) = app.tab_rename_input.as_mut() else {
+        return;
+    };
+    match key_event.code {
+        KeyCode::Enter => app.finish_tab_rename(true),
+        KeyCode::Esc => app.finish_tab_rename(false),
+        KeyCode::Backspace => {
+            input.pop();
+        }
+        KeyCode::Char(c) if !key_event.modifiers.contains(KeyModifiers::CONTROL) => input.push(c),
+        _ => {}
+    }
+}
+
+/// Handle keyboard events for the notes popup
+fn handle_note_dialog_keys(app: &mut App, key_event: KeyEvent) {
+    let editing_date = app
+        .note_dialog
+        .as_ref()
+        .is_some_and(|dialog| dialog.editing_date);
+    match key_event.code {
+        KeyCode::Esc => app.hide_note_dialog(),
+        KeyCode::Char('s') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.save_note_from_dialog();
+        }
+        KeyCode::Enter if editing_date => app.save_note_from_dialog(),
+        KeyCode::Enter => app.note_dialog_add_char('\n'),
+        KeyCode::Tab | KeyCode::BackTab => app.note_dialog_toggle_field(),
+        KeyCode::Backspace => app.note_dialog_backspace(),
+        KeyCode::Char(c) if !key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.note_dialog_add_char(c);
+        }
+        _ => {}
+    }
+}
+
+/// Handle keyboard events for the folder variables editor
+fn handle_folder_settings_dialog_keys(app: &mut App, key_event: KeyEvent) {
+    let Some(dialog) = app.folder_settings_dialog.as_mut() else {
+        return;
+    };
+    if !dialog.editor.is_editing() {
+        match key_event.code {
+            KeyCode::Esc => {
+                app.hide_folder_settings_dialog();
+                return;
+            }
+            KeyCode::Char('s') => {
+                app.toggle_folder_variable_secret();
+                return;
+            }
+            KeyCode::Char('u') => {
+                app.edit_folder_auth();
+                return;
+            }
+            _ => {}
+        }
+    }
+
+    handle_key_value_keys(&mut dialog.editor, &mut dialog.variables, key_event);
+}
+
+/// Handle keyboard events for the restore-from-backup picker
+async fn handle_backup_dialog_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    match key_event.code {
+        KeyCode::Esc => app.hide_backup_dialog(),
+        KeyCode::Up | KeyCode::Char('k') => app.backup_dialog_move(false),
+        KeyCode::Down | KeyCode::Char('j') => app.backup_dialog_move(true),
+        KeyCode::Enter => app.restore_selected_backup().await,
+        KeyCode::Char('b') => {
+            if let Err(e) = app.back_up_now() {
+                let error = format!("Backup failed: {:#}", e);
+                if let Some(dialog) = app.backup_dialog.as_mut() {
+                    dialog.error = Some(error);
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle keyboard events for the automation run queue
+fn handle_run_queue_keys(app: &mut App, key_event: KeyEvent) {
+    match key_event.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.hide_run_queue(),
+        KeyCode::Up | KeyCode::Char('k') => app.run_queue_move_selection(false),
+        KeyCode::Down | KeyCode::Char('j') => app.run_queue_move_selection(true),
+        KeyCode::Char('K') => app.reorder_queued_run(false),
+        KeyCode::Char('J') => app.reorder_queued_run(true),
+        KeyCode::Char('d') | KeyCode::Delete => app.cancel_queued_run(),
+        KeyCode::Enter => app.start_next_queued_run(),
+        _ => {}
+    }
+}
+
+/// Handle keyboard events for the settings dialog
+async fn handle_settings_dialog_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
+    match key_event.code {
+        KeyCode::Esc => {
+            app.hide_settings_dialog();
+        }
+        KeyCode::Enter => {
+            if let Err(e) = app.apply_settings_from_dialog().await {
+                app.log(LogLevel::Error, format!("Failed to apply settings: {}", e));
+            }
+        }
+        KeyCode::Tab => {
+            app.settings_dialog_focused_field =
+                (app.settings_dialog_focused_field + 1) % SETTINGS_DIALOG_FIELDS;
+        }
+        KeyCode::BackTab => {
+            app.settings_dialog_focused_field =
+                (app.settings_dialog_focused_field + SETTINGS_DIALOG_FIELDS - 1)
+                    % SETTINGS_DIALOG_FIELDS;
+        }
+        KeyCode::Char(' ') if app.settings_dialog_focused_field == 2 => {
+            app.settings_dialog_move_data = !app.settings_dialog_move_data;
+        }
+        KeyCode::Char(' ') if app.settings_dialog_focused_field == 3 => {
+            app.settings_dialog_check_updates = !app.settings_dialog_check_updates;
+        }
+        KeyCode::Char(' ') if app.settings_dialog_focused_field == 4 => {
+            app.settings_dialog_auto_format_json = !app.settings_dialog_auto_format_json;
+        }
+        KeyCode::Char(' ') if app.settings_dialog_focused_field == 5 => {
+            app.settings_dialog_block_invalid_json = !app.settings_dialog_block_invalid_json;
+        }
+        KeyCode::Char(' ') if app.settings_dialog_focused_field == 6 => {
+            app.settings_dialog_timestamp_format = app.settings_dialog_timestamp_format.next();
+        }
+        KeyCode::Char(' ') if app.settings_dialog_focused_field == 7 => {
+            app.settings_dialog_history_retention = app.settings_dialog_history_retention.next();
+        }
+        KeyCode::Char(' ') if app.settings_dialog_focused_field == 8 => {
+            app.settings_dialog_storage_format = app.settings_dialog_storage_format.next();
+        }
+        KeyCode::Char(' ') if app.settings_dialog_focused_field == 9 => {
+            app.settings_dialog_persist_cookies = !app.settings_dialog_persist_cookies;
+        }
+        KeyCode::Char(' ') if app.settings_dialog_focused_field == 10 => {
+            app.settings_dialog_request_log_secrets = !app.settings_dialog_request_log_secrets;
+        }
+        KeyCode::Char(' ') if app.settings_dialog_focused_field == 11 => {
+            app.settings_dialog_auto_lock = app.settings_dialog_auto_lock.next();
+        }
+        KeyCode::Char(' ') if app.settings_dialog_focused_field == 12 => {
+            app.settings_dialog_response_limit = app.settings_dialog_response_limit.next();
+        }
+        KeyCode::Char(' ') if app.settings_dialog_focused_field == 13 => {
+            app.settings_dialog_retry = app.settings_dialog_retry.next();
+        }
+        KeyCode::Char(' ') if app.settings_dialog_focused_field == 14 => {
+            app.settings_dialog_backup_schedule = app.settings_dialog_backup_schedule.next();
+        }
+        KeyCode::Char('r') if app.settings_dialog_focused_field == 14 => {
+            app.show_backup_dialog();
+        }
+        KeyCode::Char(c)
+            if key_event.modifiers.is_empty() || key_event.modifiers == KeyModifiers::SHIFT =>
+        {
+            if let Some(text) = app.settings_dialog_focused_text() {
+                text.push(c);
+                app.settings_dialog_error = None;
+            }
+        }
+        KeyCode::Backspace => {
+            if let Some(text) = app.settings_dialog_focused_text() {
+                text.pop();
+                app.settings_dialog_error = None;
+            }
+        }
+        KeyCode::Delete => {
+            if let Some(text) = app.settings_dialog_focused_text() {
+                text.clear();
+                app.settings_dialog_error = None;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
 /// Handle keyboard events for the rename dialog
 async fn handle_rename_dialog_keys(app: &mut App, key_event: KeyEvent) -> Result<()> {
     match key_event.code {
@@ -1543,6 +3132,11 @@ async fn handle_help_dialog_keys(app: &mut App, key_event: KeyEvent) -> Result<(
         KeyCode::Char('5') => app.help_selected_section = 5,
         KeyCode::Char('0') => app.help_selected_section = 0, // All sections
 
+        // Guided tutorial
+        KeyCode::Char('t') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.toggle_tutorial();
+        }
+
         // Search functionality
         KeyCode::Char(c)
             if key_event.modifiers.is_empty() || key_event.modifiers == KeyModifiers::SHIFT =>
@@ -1571,19 +3165,17 @@ async fn handle_login_dialog_keys(app: &mut App, key_event: KeyEvent) -> Result<
         }
 
         // Attempt login
-        KeyCode::Enter => {
-            if app.attempt_login() {
-                // Login successful, dialog closed automatically
-                // Now actually start the automation
-                if let Err(e) = app.start_automation().await {
-                    app.log(
-                        LogLevel::Error,
-                        format!("Failed to start automation: {}", e),
-                    );
-                }
+        KeyCode::Enter if app.attempt_login() => {
+            // Login successful, dialog closed automatically
+            // Now actually start the automation
+            if let Err(e) = app.start_automation().await {
+                app.log(
+                    LogLevel::Error,
+                    format!("Failed to start automation: {}", e),
+                );
             }
-            // If login failed, stay in dialog with error shown
         }
+        // If login failed, stay in dialog with error shown
 
         // Navigate between username and password fields
         KeyCode::Tab => {
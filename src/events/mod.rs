@@ -1,4 +1,4 @@
 pub mod handler;
 
 // Re-export for convenience
-pub use handler::{get_help_text, handle_key_event};
+pub use handler::{get_help_text, handle_key_event, handle_paste_event};
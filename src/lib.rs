@@ -0,0 +1,9 @@
+//! Automation toolkit library: models, services and UI used by the TUI binary
+//! (and by the benchmark suite under `benches/`).
+
+pub mod app;
+pub mod events;
+pub mod models;
+pub mod modes;
+pub mod services;
+pub mod ui;
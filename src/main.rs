@@ -1,14 +1,9 @@
-mod app;
-mod events;
-mod models;
-mod modes;
-mod services;
-mod ui;
-
 use anyhow::Result;
-use app::App;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event},
+    event::{
+        self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        Event,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
@@ -18,12 +13,39 @@ use std::{
     time::{Duration, Instant},
 };
 use tokio::time::sleep;
+use tui_one::app::App;
+use tui_one::services::external_editor;
+use tui_one::services::process_registry::ProcessRegistry;
+use tui_one::{events, ui};
+
+const USAGE: &str = "Usage: tui_one [--safe-mode]
+
+Options:
+  --safe-mode  Start with the default config, without running scripts and
+               without writing anything to disk; use it to recover when a bad
+               config or state file prevents normal startup
+  -h, --help   Show this help";
 
 /// Main entry point for the automation toolkit
 #[tokio::main]
 async fn main() -> Result<()> {
+    let mut safe_mode = false;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--safe-mode" => safe_mode = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            other => {
+                eprintln!("Unknown argument: {}\n\n{}", other, USAGE);
+                std::process::exit(2);
+            }
+        }
+    }
+
     // Initialize the app
-    let mut app = App::new();
+    let mut app = App::with_safe_mode(safe_mode);
 
     // Set up the terminal
     let mut terminal = setup_terminal()?;
@@ -34,6 +56,12 @@ async fn main() -> Result<()> {
     // Restore the terminal
     restore_terminal(&mut terminal)?;
 
+    // Stop automation still running so its Python and driver processes don't outlive us.
+    // Safe mode keeps its hands off: it tracks nothing and deletes nothing.
+    if !safe_mode {
+        ProcessRegistry::default().release_own();
+    }
+
     result
 }
 
@@ -41,7 +69,12 @@ async fn main() -> Result<()> {
 fn setup_terminal() -> Result<Terminal<CrosstermBackend<io::Stdout>>> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
 
     let backend = CrosstermBackend::new(stdout);
     let terminal = Terminal::new(backend)?;
@@ -55,13 +88,28 @@ fn restore_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Re
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )?;
     terminal.show_cursor()?;
 
     Ok(())
 }
 
+/// Take the terminal back after an external program used it
+fn resume_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
+    enable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
+    terminal.clear()?;
+
+    Ok(())
+}
+
 /// Main application loop with async event handling
 async fn run_app(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
@@ -70,15 +118,35 @@ async fn run_app(
     let mut last_tick = Instant::now();
     let tick_rate = Duration::from_millis(50); // 20 FPS for smooth UI updates
 
+    // Start in the single-pane layout if the terminal is already small
+    let size = terminal.size()?;
+    app.set_tiny_screen(
+        ui::layout::is_tiny_screen(ratatui::layout::Rect::new(0, 0, size.width, size.height)),
+        size.width,
+        size.height,
+    );
+
+    app.run_startup_actions().await;
+
     loop {
+        let iteration_start = Instant::now();
+
         // Handle terminal events (keyboard input, etc.)
         if event::poll(Duration::from_millis(0))? {
             match event::read()? {
                 Event::Key(key_event) => {
+                    app.record_activity();
                     events::handle_key_event(app, key_event).await?;
                 }
-                Event::Resize(_, _) => {
-                    // Terminal was resized, redraw will happen automatically
+                Event::Paste(text) => {
+                    app.record_activity();
+                    events::handle_paste_event(app, &text);
+                }
+                Event::Resize(width, height) => {
+                    // Redraw happens automatically; only the layout switch is worth a note
+                    let tiny =
+                        ui::layout::is_tiny_screen(ratatui::layout::Rect::new(0, 0, width, height));
+                    app.set_tiny_screen(tiny, width, height);
                 }
                 _ => {
                     // Ignore other events (mouse, focus, etc.)
@@ -86,16 +154,55 @@ async fn run_app(
             }
         }
 
+        // Hand the terminal to $EDITOR, then take it back with the edited text
+        if let Some(target) = app.pending_external_edit.take() {
+            let (content, extension) = app.external_edit_content(&target);
+            restore_terminal(terminal)?;
+            let result =
+                external_editor::edit_text(&external_editor::editor_command(), &content, extension);
+            resume_terminal(terminal)?;
+            app.image_preview.forget();
+            app.finish_external_edit(target, result);
+        }
+
         // Process any pending messages from background tasks
         app.process_messages().await?;
 
+        // Keep a recoverable copy of unsaved work
+        app.autosave_draft();
+
+        // Remember the open request tabs for the next launch
+        app.persist_request_tabs();
+
+        // Show the README of the folder highlighted in the tree
+        app.refresh_folder_readme();
+
+        // Parse and lay out the shown response only when it or its view settings change
+        app.refresh_response_view();
+
+        // Keep persisted cookies in step with the jar
+        app.save_cookie_jar();
+
+        // Forget credentials on shared workstations left unattended
+        app.check_idle_lock();
+
+        // Snapshot templates and collections on the configured schedule
+        app.run_scheduled_backup();
+
         // Check if we should quit
         if app.should_quit {
             break;
         }
 
         // Render the UI
+        let render_start = Instant::now();
         terminal.draw(|f| ui::render_app(f, app))?;
+        // Inline images go straight to the terminal, over the frame just drawn
+        if app.sync_image_preview(terminal.backend_mut())? {
+            terminal.clear()?;
+        }
+        app.perf_stats.record_frame(render_start.elapsed());
+        app.perf_stats.record_loop(iteration_start.elapsed());
 
         // Handle timing for smooth updates
         let now = Instant::now();
@@ -117,7 +224,12 @@ fn setup_panic_hook() {
     std::panic::set_hook(Box::new(move |panic_info| {
         // Attempt to restore the terminal
         let _ = disable_raw_mode();
-        let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture);
+        let _ = execute!(
+            io::stdout(),
+            LeaveAlternateScreen,
+            DisableMouseCapture,
+            DisableBracketedPaste
+        );
 
         // Call the original panic hook
         original_hook(panic_info);
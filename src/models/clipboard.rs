@@ -18,3 +18,31 @@ pub struct ClipboardItem {
     pub path: String,
     pub full_file_path: PathBuf, // For filesystem operations
 }
+
+/// Name for a duplicate of `name`: "<name> copy", then "<name> copy 2", ...
+/// skipping names `exists` reports as taken
+pub fn duplicate_name(name: &str, exists: impl Fn(&str) -> bool) -> String {
+    let base = format!("{} copy", name);
+    let mut candidate = base.clone();
+    let mut counter = 2;
+    while exists(&candidate) {
+        candidate = format!("{} {}", base, counter);
+        counter += 1;
+    }
+    candidate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duplicate_name_skips_taken_names() {
+        assert_eq!(duplicate_name("List users", |_| false), "List users copy");
+        let taken = ["List users copy", "List users copy 2"];
+        assert_eq!(
+            duplicate_name("List users", |name| taken.contains(&name)),
+            "List users copy 3"
+        );
+    }
+}
@@ -1,6 +1,18 @@
+use crate::models::http_client::{HttpHeader, ProxySettings, RetryPolicy, TlsSettings};
+use crate::models::{
+    InputPreset, ItemNote, KeyValuePair, RecentItem, StorageFormat, TimestampFormat,
+};
 use anyhow::{Context, Result};
+use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Application name used for platform directories
+const APP_NAME: &str = "automation-toolkit";
+
+/// Current on-disk layout version (1 = config/data/cache split)
+pub const CURRENT_LAYOUT_VERSION: u32 = 1;
 
 /// Application configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,18 +25,399 @@ pub struct AppConfig {
 
     /// Last used template directory (for user override)
     pub custom_templates_dir: Option<PathBuf>,
+
+    /// HTTP collections directory override (defaults to a sibling of the templates directory)
+    #[serde(default)]
+    pub custom_collections_dir: Option<PathBuf>,
+
+    /// On-disk layout version, used for one-time migrations (missing = legacy layout)
+    #[serde(default)]
+    pub layout_version: u32,
+
+    /// Opt-in: check the release feed for a newer version at startup
+    #[serde(default)]
+    pub check_for_updates: bool,
+
+    /// Last version whose What's New overlay was dismissed (missing = upgraded from an older build)
+    #[serde(default)]
+    pub last_seen_version: Option<String>,
+
+    /// Pretty-print JSON request bodies when leaving edit mode
+    #[serde(default)]
+    pub auto_format_json: bool,
+
+    /// Refuse to send requests whose JSON body does not parse
+    #[serde(default)]
+    pub block_invalid_json: bool,
+
+    /// Environment variables available to HTTP requests (the default environment)
+    #[serde(default)]
+    pub environment_variables: Vec<KeyValuePair>,
+
+    /// Named environments (staging, prod, ...) that can replace the default one
+    #[serde(default)]
+    pub environments: Vec<Environment>,
+
+    /// Environment requests are sent with; `None` is the default environment
+    #[serde(default)]
+    pub active_environment: Option<String>,
+
+    /// Recently opened templates and requests, newest first
+    #[serde(default)]
+    pub recent_items: Vec<RecentItem>,
+
+    /// Notes attached to tree items, some with a "review by" reminder
+    #[serde(default)]
+    pub notes: Vec<ItemNote>,
+
+    /// Show the HTTP request editor and response viewer side by side
+    #[serde(default)]
+    pub http_split_layout: bool,
+
+    /// Switch to the step timeline / live output layout while automation runs
+    #[serde(default)]
+    pub automation_run_layout: bool,
+
+    /// Local vs UTC, with or without date, for logs and run records
+    #[serde(default)]
+    pub timestamp_format: TimestampFormat,
+
+    /// How much request history is kept
+    #[serde(default)]
+    pub history_retention: HistoryRetention,
+
+    /// File format for newly saved templates and requests (existing files keep theirs)
+    #[serde(default)]
+    pub storage_format: StorageFormat,
+
+    /// Keep the cookie jar on disk between sessions
+    #[serde(default)]
+    pub persist_cookies: bool,
+
+    /// Write credentials into the request log's curl commands instead of redacting them
+    #[serde(default)]
+    pub request_log_secrets: bool,
+
+    /// CA bundle and client certificate used by requests that don't set their own
+    #[serde(default)]
+    pub tls: TlsSettings,
+
+    /// Proxy of the default environment, and of named ones that don't set their own
+    #[serde(default)]
+    pub proxy: ProxySettings,
+
+    /// Forget credentials after this long without a key press
+    #[serde(default)]
+    pub auto_lock: AutoLock,
+
+    /// Automation field value sets applied on top of any template
+    #[serde(default)]
+    pub input_presets: Vec<InputPreset>,
+
+    /// How much of a response body is kept in memory for display
+    #[serde(default)]
+    pub response_display_limit: ResponseDisplayLimit,
+
+    /// Retries for requests that don't set their own
+    #[serde(default)]
+    pub retry: RetryPolicy,
+
+    /// Headers sent with every request that doesn't set the same header itself
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub default_headers: Vec<HttpHeader>,
+
+    /// Steps run in order at launch so the app opens straight into a daily workflow
+    #[serde(default)]
+    pub startup_actions: Vec<StartupAction>,
+
+    /// How often templates and collections are snapshotted, and how many snapshots are kept
+    #[serde(default)]
+    pub backup_schedule: BackupSchedule,
 }
 
-impl AppConfig {
+/// Label of the unnamed environment kept in `environment_variables`
+pub const DEFAULT_ENVIRONMENT_NAME: &str = "Default";
+
+/// A named set of variables, such as the base URL and token of one deployment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Environment {
+    pub name: String,
+    #[serde(default)]
+    pub variables: Vec<KeyValuePair>,
+    /// Overrides the global proxy while this environment is active
+    #[serde(default, skip_serializing_if = "ProxySettings::is_empty")]
+    pub proxy: ProxySettings,
+}
+
+/// Pruning limits for the request history
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryRetention {
+    pub max_entries: usize,
+    /// Entries older than this are dropped; 0 keeps them regardless of age
+    pub max_age_days: u32,
+}
+
+impl Default for HistoryRetention {
+    fn default() -> Self {
+        Self {
+            max_entries: 500,
+            max_age_days: 30,
+        }
+    }
+}
+
+impl HistoryRetention {
+    const PRESETS: [HistoryRetention; 4] = [
+        HistoryRetention {
+            max_entries: 100,
+            max_age_days: 7,
+        },
+        HistoryRetention {
+            max_entries: 500,
+            max_age_days: 30,
+        },
+        HistoryRetention {
+            max_entries: 2000,
+            max_age_days: 90,
+        },
+        HistoryRetention {
+            max_entries: 10000,
+            max_age_days: 0,
+        },
+    ];
+
+    pub fn label(&self) -> String {
+        if self.max_age_days == 0 {
+            format!("Last {} requests, any age", self.max_entries)
+        } else {
+            format!(
+                "Last {} requests, up to {} days",
+                self.max_entries, self.max_age_days
+            )
+        }
+    }
+
+    /// The next preset in the cycle (custom values from the config file restart it)
+    pub fn next(&self) -> Self {
+        let position = Self::PRESETS.iter().position(|preset| preset == self);
+        match position {
+            Some(index) => Self::PRESETS[(index + 1) % Self::PRESETS.len()],
+            None => Self::PRESETS[0],
+        }
+    }
+}
+
+/// Idle time after which login credentials and cached tokens are cleared
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoLock {
+    /// 0 never locks
+    pub idle_minutes: u32,
+}
+
+impl AutoLock {
+    const PRESETS: [u32; 6] = [0, 5, 10, 15, 30, 60];
+
+    /// How long the session may sit idle, if locking is on
+    pub fn timeout(&self) -> Option<std::time::Duration> {
+        (self.idle_minutes > 0)
+            .then(|| std::time::Duration::from_secs(60 * self.idle_minutes as u64))
+    }
+
+    pub fn label(&self) -> String {
+        match self.idle_minutes {
+            0 => "Never".to_string(),
+            1 => "After 1 minute idle".to_string(),
+            minutes => format!("After {} minutes idle", minutes),
+        }
+    }
+
+    /// The next preset in the cycle (custom values from the config file restart it)
+    pub fn next(&self) -> Self {
+        let position = Self::PRESETS
+            .iter()
+            .position(|minutes| *minutes == self.idle_minutes);
+        let idle_minutes = match position {
+            Some(index) => Self::PRESETS[(index + 1) % Self::PRESETS.len()],
+            None => Self::PRESETS[0],
+        };
+        Self { idle_minutes }
+    }
+}
+
+/// Response bodies beyond this size are truncated in memory and spooled to disk in full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponseDisplayLimit {
+    pub max_megabytes: u32,
+}
+
+impl Default for ResponseDisplayLimit {
+    fn default() -> Self {
+        Self { max_megabytes: 10 }
+    }
+}
+
+impl ResponseDisplayLimit {
+    const PRESETS: [u32; 5] = [1, 5, 10, 50, 100];
+
+    pub fn max_bytes(&self) -> usize {
+        self.max_megabytes.max(1) as usize * 1024 * 1024
+    }
+
+    pub fn label(&self) -> String {
+        format!("{} MB", self.max_megabytes.max(1))
+    }
+
+    /// The next preset in the cycle (custom values from the config file restart it)
+    pub fn next(&self) -> Self {
+        let position = Self::PRESETS
+            .iter()
+            .position(|megabytes| *megabytes == self.max_megabytes);
+        let max_megabytes = match position {
+            Some(index) => Self::PRESETS[(index + 1) % Self::PRESETS.len()],
+            None => Self::PRESETS[0],
+        };
+        Self { max_megabytes }
+    }
+}
+
+/// Automatic snapshots of the templates and http-collections directories
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupSchedule {
+    /// 0 turns automatic backups off
+    pub interval_hours: u32,
+    /// Older backups are deleted; 0 keeps them all
+    pub keep: usize,
+}
+
+impl Default for BackupSchedule {
+    fn default() -> Self {
+        Self {
+            interval_hours: 24,
+            keep: 7,
+        }
+    }
+}
+
+impl BackupSchedule {
+    const PRESETS: [BackupSchedule; 5] = [
+        BackupSchedule {
+            interval_hours: 0,
+            keep: 7,
+        },
+        BackupSchedule {
+            interval_hours: 1,
+            keep: 24,
+        },
+        BackupSchedule {
+            interval_hours: 24,
+            keep: 7,
+        },
+        BackupSchedule {
+            interval_hours: 24,
+            keep: 30,
+        },
+        BackupSchedule {
+            interval_hours: 168,
+            keep: 8,
+        },
+    ];
+
+    /// Time between backups, if they are on
+    pub fn interval(&self) -> Option<chrono::TimeDelta> {
+        (self.interval_hours > 0).then(|| chrono::TimeDelta::hours(self.interval_hours as i64))
+    }
+
+    pub fn label(&self) -> String {
+        let every = match self.interval_hours {
+            0 => return "Off".to_string(),
+            1 => "Hourly".to_string(),
+            24 => "Daily".to_string(),
+            168 => "Weekly".to_string(),
+            hours => format!("Every {} hours", hours),
+        };
+        match self.keep {
+            0 => format!("{}, keep all", every),
+            keep => format!("{}, keep last {}", every, keep),
+        }
+    }
+
+    /// The next preset in the cycle (custom values from the config file restart it)
+    pub fn next(&self) -> Self {
+        let position = Self::PRESETS.iter().position(|preset| preset == self);
+        match position {
+            Some(index) => Self::PRESETS[(index + 1) % Self::PRESETS.len()],
+            None => Self::PRESETS[0],
+        }
+    }
+}
+
+/// One step of `startup_actions`, e.g. `{ "switch_mode": "http" }`,
+/// `{ "activate_environment": "staging" }`, `{ "open": "users/List users" }` or
+/// `{ "focus": "form" }`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StartupAction {
+    SwitchMode(StartupMode),
+    ActivateEnvironment(String),
+    /// Template or request path, opened in whichever mode is current at that step
+    Open(String),
+    Focus(StartupPane),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StartupMode {
+    Automation,
+    Http,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StartupPane {
+    Collections,
+    Form,
+    Logs,
+}
+
+impl Default for AppConfig {
     /// Create default configuration with cross-platform paths
-    pub fn default() -> Self {
+    fn default() -> Self {
         Self {
             templates_directory: get_default_templates_dir(),
             show_logs_on_startup: true,
             custom_templates_dir: None,
+            custom_collections_dir: None,
+            layout_version: CURRENT_LAYOUT_VERSION,
+            check_for_updates: false,
+            last_seen_version: Some(env!("CARGO_PKG_VERSION").to_string()),
+            auto_format_json: false,
+            block_invalid_json: false,
+            environment_variables: Vec::new(),
+            environments: Vec::new(),
+            active_environment: None,
+            recent_items: Vec::new(),
+            notes: Vec::new(),
+            http_split_layout: false,
+            automation_run_layout: false,
+            timestamp_format: TimestampFormat::default(),
+            history_retention: HistoryRetention::default(),
+            storage_format: StorageFormat::default(),
+            persist_cookies: false,
+            request_log_secrets: false,
+            tls: TlsSettings::default(),
+            proxy: ProxySettings::default(),
+            auto_lock: AutoLock::default(),
+            input_presets: Vec::new(),
+            response_display_limit: ResponseDisplayLimit::default(),
+            retry: RetryPolicy::default(),
+            default_headers: Vec::new(),
+            startup_actions: Vec::new(),
+            backup_schedule: BackupSchedule::default(),
         }
     }
+}
 
+impl AppConfig {
     /// Load configuration from file, or create default if it doesn't exist
     pub fn load() -> Result<Self> {
         let config_path = get_config_file_path()?;
@@ -47,6 +440,7 @@ impl AppConfig {
 
     /// Save configuration to file
     pub fn save(&self) -> Result<()> {
+        ensure_writable_storage()?;
         let config_path = get_config_file_path()?;
 
         // Create parent directory if it doesn't exist
@@ -69,6 +463,18 @@ impl AppConfig {
             .unwrap_or(&self.templates_directory)
     }
 
+    /// Get the HTTP collections directory, preferring custom over default
+    pub fn get_collections_directory(&self) -> PathBuf {
+        match &self.custom_collections_dir {
+            Some(dir) => dir.clone(),
+            None => self
+                .get_templates_directory()
+                .parent()
+                .unwrap_or_else(|| Path::new("."))
+                .join("http-collections"),
+        }
+    }
+
     /// Set a custom templates directory
     pub fn set_custom_templates_directory(&mut self, path: PathBuf) -> Result<()> {
         // Validate that the directory exists or can be created
@@ -89,37 +495,145 @@ impl AppConfig {
         Ok(())
     }
 
+    /// Name of the environment requests are sent with
+    pub fn active_environment_name(&self) -> &str {
+        self.active_environment
+            .as_deref()
+            .unwrap_or(DEFAULT_ENVIRONMENT_NAME)
+    }
+
+    /// Every environment name, the default one first
+    pub fn environment_names(&self) -> Vec<String> {
+        std::iter::once(DEFAULT_ENVIRONMENT_NAME.to_string())
+            .chain(self.environments.iter().map(|env| env.name.clone()))
+            .collect()
+    }
+
+    /// Variables of the environment called `name`, if there is one
+    pub fn environment_variables_of(&self, name: &str) -> Option<&Vec<KeyValuePair>> {
+        if name == DEFAULT_ENVIRONMENT_NAME {
+            return Some(&self.environment_variables);
+        }
+        self.environments
+            .iter()
+            .find(|env| env.name == name)
+            .map(|env| &env.variables)
+    }
+
+    /// Proxy of the active environment, falling back to the global one
+    pub fn active_proxy(&self) -> ProxySettings {
+        self.proxy_of(self.active_environment_name())
+    }
+
+    /// Proxy of the environment called `name`, falling back to the global one
+    pub fn proxy_of(&self, name: &str) -> ProxySettings {
+        self.environments
+            .iter()
+            .find(|env| env.name == name)
+            .map_or_else(|| self.proxy.clone(), |env| env.proxy.or(&self.proxy))
+    }
+
+    /// Proxy edited for the active environment: the global one for the default environment
+    pub fn active_proxy_mut(&mut self) -> &mut ProxySettings {
+        let name = self.active_environment.clone();
+        match self
+            .environments
+            .iter_mut()
+            .find(|env| Some(&env.name) == name.as_ref())
+        {
+            Some(env) => &mut env.proxy,
+            None => &mut self.proxy,
+        }
+    }
+
+    /// Variables of the active environment (the default one if it no longer exists)
+    pub fn active_environment_variables_mut(&mut self) -> &mut Vec<KeyValuePair> {
+        let name = self.active_environment.clone();
+        match self
+            .environments
+            .iter_mut()
+            .find(|env| Some(&env.name) == name.as_ref())
+        {
+            Some(env) => &mut env.variables,
+            None => &mut self.environment_variables,
+        }
+    }
+
     /// Get a display-friendly path for the UI
     pub fn get_templates_directory_display(&self) -> String {
         let path = self.get_templates_directory();
 
         // Try to show a shortened version for common directories
-        if let Some(home_dir) = dirs::home_dir() {
-            if let Ok(relative) = path.strip_prefix(&home_dir) {
-                return format!("~/{}", relative.display());
-            }
+        if let Some(home_dir) = dirs::home_dir()
+            && let Ok(relative) = path.strip_prefix(&home_dir)
+        {
+            return format!("~/{}", relative.display());
         }
 
         path.display().to_string()
     }
 }
 
+/// Platform directories for the app (XDG on Linux, Library on macOS, AppData on Windows)
+fn project_dirs() -> Option<ProjectDirs> {
+    ProjectDirs::from("", "", APP_NAME)
+}
+
+/// Directory for configuration files (XDG_CONFIG_HOME)
+pub fn config_directory() -> PathBuf {
+    project_dirs()
+        .map(|dirs| dirs.config_dir().to_path_buf())
+        .unwrap_or_else(|| PathBuf::from(".").join(APP_NAME))
+}
+
+/// Set by `--safe-mode`: nothing under the config, data or cache directories is written
+static READ_ONLY_STORAGE: AtomicBool = AtomicBool::new(false);
+
+pub fn set_read_only_storage(read_only: bool) {
+    READ_ONLY_STORAGE.store(read_only, Ordering::Relaxed);
+}
+
+pub fn is_read_only_storage() -> bool {
+    READ_ONLY_STORAGE.load(Ordering::Relaxed)
+}
+
+/// Fail a write the user asked for while storage is read-only
+pub fn ensure_writable_storage() -> Result<()> {
+    if is_read_only_storage() {
+        anyhow::bail!("Storage is read-only in safe mode (restart without --safe-mode to save)");
+    }
+    Ok(())
+}
+
+/// Directory for user data such as templates and collections (XDG_DATA_HOME)
+pub fn data_directory() -> PathBuf {
+    project_dirs()
+        .map(|dirs| dirs.data_dir().to_path_buf())
+        .unwrap_or_else(|| PathBuf::from(".").join(APP_NAME))
+}
+
+/// Directory for disposable cached data (XDG_CACHE_HOME)
+pub fn cache_directory() -> PathBuf {
+    project_dirs()
+        .map(|dirs| dirs.cache_dir().to_path_buf())
+        .unwrap_or_else(|| PathBuf::from(".").join(APP_NAME).join("cache"))
+}
+
+/// Where templates and collections lived before the config/data split
+pub fn legacy_data_directory() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(APP_NAME)
+}
+
 /// Get the default templates directory for the current platform
 fn get_default_templates_dir() -> PathBuf {
-    // Try platform-specific config directory first
-    if let Some(config_dir) = dirs::config_dir() {
-        config_dir.join("automation-toolkit").join("templates")
-    } else {
-        // Fallback to current directory
-        PathBuf::from(".").join("automation-templates")
-    }
+    data_directory().join("templates")
 }
 
 /// Get the path for the config file
 fn get_config_file_path() -> Result<PathBuf> {
-    let config_dir = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
-
-    Ok(config_dir.join("automation-toolkit").join("config.json"))
+    Ok(config_directory().join("config.json"))
 }
 
 /// Initialize the templates directory and create demo structure if needed
@@ -136,7 +650,7 @@ pub fn ensure_templates_directory(config: &AppConfig) -> Result<()> {
 }
 
 /// Create the demo folder structure: Customer > Add > Email, etc.
-fn create_demo_structure(templates_dir: &PathBuf) -> Result<()> {
+fn create_demo_structure(templates_dir: &Path) -> Result<()> {
     // Create Customer folder
     let customer_dir = templates_dir.join("Customer");
     std::fs::create_dir_all(&customer_dir)?;
@@ -164,8 +678,107 @@ mod tests {
     fn test_default_config() {
         let config = AppConfig::default();
         assert!(!config.templates_directory.as_os_str().is_empty());
-        assert_eq!(config.show_logs_on_startup, true);
+        assert!(config.show_logs_on_startup);
         assert_eq!(config.custom_templates_dir, None);
+        assert_eq!(config.auto_lock.timeout(), None);
+    }
+
+    #[test]
+    fn test_auto_lock_presets_cycle() {
+        let mut auto_lock = AutoLock::default();
+        let mut seen = Vec::new();
+        for _ in 0..AutoLock::PRESETS.len() {
+            auto_lock = auto_lock.next();
+            seen.push(auto_lock.idle_minutes);
+        }
+        assert_eq!(seen, vec![5, 10, 15, 30, 60, 0]);
+
+        let custom = AutoLock { idle_minutes: 7 };
+        assert_eq!(
+            custom.timeout(),
+            Some(std::time::Duration::from_secs(7 * 60))
+        );
+        assert_eq!(custom.label(), "After 7 minutes idle");
+        assert_eq!(custom.next(), AutoLock::default());
+    }
+
+    #[test]
+    fn test_response_display_limit_presets_cycle() {
+        let limit = ResponseDisplayLimit::default();
+        assert_eq!(limit.max_bytes(), 10 * 1024 * 1024);
+        assert_eq!(limit.next().max_megabytes, 50);
+        assert_eq!(
+            ResponseDisplayLimit { max_megabytes: 100 }
+                .next()
+                .max_megabytes,
+            1
+        );
+
+        // A zero from a hand-edited config still keeps something to show
+        let zero = ResponseDisplayLimit { max_megabytes: 0 };
+        assert_eq!(zero.max_bytes(), 1024 * 1024);
+        assert_eq!(zero.label(), "1 MB");
+    }
+
+    #[test]
+    fn test_retry_policy_presets_and_backoff() {
+        let global = AppConfig::default().retry;
+        assert_eq!(global.label(), "Off");
+
+        let retry = global.next();
+        assert_eq!((retry.max_attempts, retry.backoff_ms), (3, 500));
+        assert_eq!(retry.delay_after(1), std::time::Duration::from_millis(500));
+        assert_eq!(retry.delay_after(3), std::time::Duration::from_millis(2000));
+        // Doubling stops at the cap
+        assert_eq!(retry.delay_after(40), std::time::Duration::from_secs(30));
+        assert!(retry.retries_status(503));
+        assert!(!retry.retries_status(404));
+
+        // A request without its own attempt count uses the global policy
+        let request = RetryPolicy {
+            backoff_ms: 100,
+            ..RetryPolicy::default()
+        };
+        assert_eq!(request.or(&retry), retry);
+        let request = RetryPolicy {
+            max_attempts: 2,
+            ..request
+        };
+        assert_eq!(request.or(&retry).backoff_ms, 100);
+    }
+
+    #[test]
+    fn test_startup_actions_from_config_file() {
+        let json = r#"{
+            "templates_directory": "/tmp/templates",
+            "show_logs_on_startup": true,
+            "custom_templates_dir": null,
+            "startup_actions": [
+                { "switch_mode": "http" },
+                { "activate_environment": "staging" },
+                { "open": "users/List users" },
+                { "focus": "form" }
+            ]
+        }"#;
+        let config: AppConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            config.startup_actions,
+            vec![
+                StartupAction::SwitchMode(StartupMode::Http),
+                StartupAction::ActivateEnvironment("staging".to_string()),
+                StartupAction::Open("users/List users".to_string()),
+                StartupAction::Focus(StartupPane::Form),
+            ]
+        );
+
+        // Older config files have no startup actions
+        let json = r#"{
+            "templates_directory": "/tmp/templates",
+            "show_logs_on_startup": true,
+            "custom_templates_dir": null
+        }"#;
+        let config: AppConfig = serde_json::from_str(json).unwrap();
+        assert!(config.startup_actions.is_empty());
     }
 
     #[test]
@@ -174,4 +787,57 @@ mod tests {
         let display = config.get_templates_directory_display();
         assert!(!display.is_empty());
     }
+
+    #[test]
+    fn test_collections_directory_override() {
+        let mut config = AppConfig {
+            templates_directory: PathBuf::from("/data/toolkit/templates"),
+            ..AppConfig::default()
+        };
+        assert_eq!(
+            config.get_collections_directory(),
+            PathBuf::from("/data/toolkit/http-collections")
+        );
+
+        config.custom_collections_dir = Some(PathBuf::from("/mnt/shared/collections"));
+        assert_eq!(
+            config.get_collections_directory(),
+            PathBuf::from("/mnt/shared/collections")
+        );
+    }
+
+    #[test]
+    fn test_active_environment_falls_back_to_default() {
+        let mut config = AppConfig {
+            environment_variables: vec![KeyValuePair::new("base", "http://localhost")],
+            environments: vec![Environment {
+                name: "prod".to_string(),
+                variables: vec![KeyValuePair::new("base", "https://api.example.com")],
+                proxy: ProxySettings::default(),
+            }],
+            ..AppConfig::default()
+        };
+        assert_eq!(config.environment_names(), vec!["Default", "prod"]);
+        assert_eq!(config.active_environment_name(), "Default");
+
+        config.active_environment = Some("prod".to_string());
+        config.active_environment_variables_mut()[0].value = "https://prod".to_string();
+        assert_eq!(
+            config.environment_variables_of("prod").unwrap()[0].value,
+            "https://prod"
+        );
+
+        // Named environments use the global proxy unless they set their own
+        config.proxy.url = "http://proxy.corp:3128".to_string();
+        assert_eq!(config.active_proxy().url, "http://proxy.corp:3128");
+        config.active_proxy_mut().url = "socks5://jump:1080".to_string();
+        assert_eq!(config.active_proxy().url, "socks5://jump:1080");
+        assert_eq!(config.proxy.url, "http://proxy.corp:3128");
+
+        // A deleted environment edits the default one instead
+        config.active_environment = Some("gone".to_string());
+        config.active_environment_variables_mut().clear();
+        assert!(config.environment_variables.is_empty());
+        assert!(config.environment_variables_of("gone").is_none());
+    }
 }
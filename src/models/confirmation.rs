@@ -0,0 +1,149 @@
+/// How much is at stake when the user confirms
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DangerLevel {
+    Info,
+    Warn,
+    Destructive,
+}
+
+impl DangerLevel {
+    /// Border and confirm button color
+    pub fn color(&self) -> ratatui::style::Color {
+        use ratatui::style::Color;
+
+        match self {
+            DangerLevel::Info => Color::Cyan,
+            DangerLevel::Warn => Color::Yellow,
+            DangerLevel::Destructive => Color::Red,
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            DangerLevel::Info => "ℹ️",
+            DangerLevel::Warn => "⚠️",
+            DangerLevel::Destructive => "🗑️",
+        }
+    }
+}
+
+/// What happens once the user confirms
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfirmAction {
+    /// Delete a tree item (folder, template or request) by its relative path
+    DeleteItem { path: String, is_folder: bool },
+    /// Remove a saved automation input preset
+    DeleteInputPreset { name: String },
+    /// Save the template/request dialog over the existing item it names
+    OverwriteFromDialog,
+    /// Queue a run of the automation form behind the one in progress
+    QueueAutomation,
+}
+
+/// A pending yes/no question shown by the shared confirmation dialog
+#[derive(Debug, Clone, PartialEq)]
+pub struct Confirmation {
+    pub title: String,
+    /// The subject of the question, e.g. the item being deleted
+    pub subject: String,
+    pub details: Vec<String>,
+    /// Optional list of affected items, e.g. a folder's contents; a leading `+`, `-`
+    /// or `~` colors the item as added, removed or changed
+    pub items: Vec<String>,
+    pub items_title: String,
+    pub danger: DangerLevel,
+    pub confirm_label: String,
+    pub cancel_label: String,
+    /// Text the user must type before Enter is accepted
+    pub typed_confirmation: Option<String>,
+    pub typed: String,
+    pub action: ConfirmAction,
+}
+
+impl Confirmation {
+    pub fn new(
+        title: impl Into<String>,
+        subject: impl Into<String>,
+        action: ConfirmAction,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            subject: subject.into(),
+            details: Vec::new(),
+            items: Vec::new(),
+            items_title: "Affected items".to_string(),
+            danger: DangerLevel::Info,
+            confirm_label: "Confirm".to_string(),
+            cancel_label: "Cancel".to_string(),
+            typed_confirmation: None,
+            typed: String::new(),
+            action,
+        }
+    }
+
+    pub fn with_danger(mut self, danger: DangerLevel) -> Self {
+        self.danger = danger;
+        self
+    }
+
+    pub fn with_details(mut self, details: Vec<String>) -> Self {
+        self.details = details;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<String>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_items_title(mut self, title: impl Into<String>) -> Self {
+        self.items_title = title.into();
+        self
+    }
+
+    pub fn with_labels(mut self, confirm: impl Into<String>, cancel: impl Into<String>) -> Self {
+        self.confirm_label = confirm.into();
+        self.cancel_label = cancel.into();
+        self
+    }
+
+    /// Require typing `text` (e.g. the folder name) before confirming
+    pub fn with_typed_confirmation(mut self, text: impl Into<String>) -> Self {
+        self.typed_confirmation = Some(text.into());
+        self
+    }
+
+    /// Whether Enter should run the action
+    pub fn can_confirm(&self) -> bool {
+        self.typed_confirmation
+            .as_ref()
+            .is_none_or(|expected| self.typed == *expected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typed_confirmation_gates_confirm() {
+        let action = ConfirmAction::DeleteItem {
+            path: "api/users".to_string(),
+            is_folder: true,
+        };
+        let plain = Confirmation::new("Delete", "users", action.clone());
+        assert!(plain.can_confirm());
+        assert_eq!(plain.danger, DangerLevel::Info);
+
+        let mut guarded = Confirmation::new("Delete", "users", action)
+            .with_danger(DangerLevel::Destructive)
+            .with_labels("Delete", "Keep")
+            .with_typed_confirmation("users");
+        assert!(!guarded.can_confirm());
+        guarded.typed = "user".to_string();
+        assert!(!guarded.can_confirm());
+        guarded.typed.push('s');
+        assert!(guarded.can_confirm());
+        assert_eq!(guarded.confirm_label, "Delete");
+    }
+}
@@ -0,0 +1,323 @@
+use crate::models::config::{data_directory, is_read_only_storage};
+use crate::models::key_value::KeyValueEntry;
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Cookie jar shared between the UI and the tasks sending requests
+pub type SharedCookieJar = Arc<Mutex<CookieJar>>;
+
+/// Where the cookie jar is kept when persisting cookies is switched on
+pub fn cookie_jar_path() -> PathBuf {
+    data_directory().join("cookies.json")
+}
+
+/// A cookie set by a server (or added by hand on the Cookies tab)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cookie {
+    pub name: String,
+    pub value: String,
+    /// Host the cookie belongs to; empty sends it to every host
+    #[serde(default)]
+    pub domain: String,
+    #[serde(default = "default_path")]
+    pub path: String,
+    /// `None` for session cookies
+    #[serde(default)]
+    pub expires: Option<DateTime<Utc>>,
+    /// Only sent over https
+    #[serde(default)]
+    pub secure: bool,
+    /// Sent to `domain` only, not its subdomains (no Domain attribute was given)
+    #[serde(default)]
+    pub host_only: bool,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_path() -> String {
+    "/".to_string()
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl Cookie {
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            domain: String::new(),
+            path: default_path(),
+            expires: None,
+            secure: false,
+            host_only: false,
+            enabled: true,
+        }
+    }
+
+    /// Parse a `Set-Cookie` header received from `url`; `None` if it is malformed
+    /// or names a domain the server doesn't belong to
+    pub fn parse_set_cookie(header: &str, url: &Url) -> Option<Self> {
+        let host = url.host_str()?.to_ascii_lowercase();
+        let mut parts = header.split(';');
+        let (name, value) = parts.next()?.split_once('=')?;
+        let name = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+
+        let mut cookie = Cookie::new(name, value.trim().trim_matches('"'));
+        cookie.domain = host.clone();
+        cookie.host_only = true;
+        cookie.path = default_cookie_path(url.path());
+
+        let mut max_age = None;
+        for attribute in parts {
+            let (key, value) = match attribute.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => (attribute.trim(), ""),
+            };
+            match key.to_ascii_lowercase().as_str() {
+                "domain" if !value.is_empty() => {
+                    let domain = value.trim_start_matches('.').to_ascii_lowercase();
+                    if !domain_matches(&host, &domain) {
+                        return None;
+                    }
+                    cookie.domain = domain;
+                    cookie.host_only = false;
+                }
+                "path" if value.starts_with('/') => cookie.path = value.to_string(),
+                "expires" => cookie.expires = parse_cookie_date(value),
+                "max-age" => max_age = value.parse::<i64>().ok(),
+                "secure" => cookie.secure = true,
+                _ => {}
+            }
+        }
+
+        // Max-Age wins over Expires
+        if let Some(seconds) = max_age {
+            cookie.expires = Some(Utc::now() + chrono::Duration::seconds(seconds));
+        }
+        Some(cookie)
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires.is_some_and(|expires| expires <= Utc::now())
+    }
+
+    /// Whether this cookie goes with a request to `url`
+    pub fn matches(&self, url: &Url) -> bool {
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+        let host = host.to_ascii_lowercase();
+
+        let domain_ok = if self.domain.is_empty() {
+            true
+        } else if self.host_only {
+            host == self.domain
+        } else {
+            domain_matches(&host, &self.domain)
+        };
+
+        self.enabled
+            && domain_ok
+            && path_matches(url.path(), &self.path)
+            && (!self.secure || url.scheme() == "https")
+            && !self.is_expired()
+    }
+
+    /// Same cookie in the jar's eyes (a new value replaces the old one)
+    fn same_slot(&self, other: &Cookie) -> bool {
+        self.name == other.name && self.domain == other.domain && self.path == other.path
+    }
+}
+
+impl KeyValueEntry for Cookie {
+    fn blank() -> Self {
+        Self::new("", "")
+    }
+
+    fn parts(&self) -> (&str, &str, bool) {
+        (&self.name, &self.value, self.enabled)
+    }
+
+    fn parts_mut(&mut self) -> (&mut String, &mut String, &mut bool) {
+        (&mut self.name, &mut self.value, &mut self.enabled)
+    }
+}
+
+/// Cookies remembered across requests, like a browser would
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CookieJar {
+    pub cookies: Vec<Cookie>,
+    /// Changed since it was last saved
+    #[serde(skip)]
+    pub changed: bool,
+}
+
+impl CookieJar {
+    /// Read a saved jar; a missing or unreadable file gives an empty jar
+    pub fn load(path: &Path) -> Self {
+        let mut jar: CookieJar = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        jar.remove_expired();
+        jar.changed = false;
+        jar
+    }
+
+    pub fn save(&mut self, path: &Path) -> Result<()> {
+        if is_read_only_storage() {
+            self.changed = false;
+            return Ok(());
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create data directory")?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .context("Failed to write cookie jar")?;
+        self.changed = false;
+        Ok(())
+    }
+
+    /// Remember the cookies a response set; expired ones delete their earlier value
+    pub fn store_response_cookies<'a>(
+        &mut self,
+        url: &Url,
+        set_cookie_headers: impl IntoIterator<Item = &'a str>,
+    ) {
+        for header in set_cookie_headers {
+            let Some(cookie) = Cookie::parse_set_cookie(header, url) else {
+                continue;
+            };
+            self.cookies.retain(|existing| !existing.same_slot(&cookie));
+            if !cookie.is_expired() {
+                self.cookies.push(cookie);
+            }
+            self.changed = true;
+        }
+    }
+
+    /// `Cookie` header value for a request to `url`, longest paths first
+    pub fn cookie_header(&self, url: &Url) -> Option<String> {
+        let mut matching: Vec<&Cookie> = self
+            .cookies
+            .iter()
+            .filter(|cookie| !cookie.name.is_empty() && cookie.matches(url))
+            .collect();
+        if matching.is_empty() {
+            return None;
+        }
+        matching.sort_by_key(|cookie| std::cmp::Reverse(cookie.path.len()));
+        let pairs: Vec<String> = matching
+            .iter()
+            .map(|cookie| format!("{}={}", cookie.name, cookie.value))
+            .collect();
+        Some(pairs.join("; "))
+    }
+
+    pub fn remove_expired(&mut self) {
+        let before = self.cookies.len();
+        self.cookies.retain(|cookie| !cookie.is_expired());
+        if self.cookies.len() != before {
+            self.changed = true;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        if !self.cookies.is_empty() {
+            self.cookies.clear();
+            self.changed = true;
+        }
+    }
+}
+
+/// `host` is `domain` or one of its subdomains
+fn domain_matches(host: &str, domain: &str) -> bool {
+    host == domain
+        || host
+            .strip_suffix(domain)
+            .is_some_and(|prefix| prefix.ends_with('.'))
+}
+
+/// RFC 6265 path matching: `/api` covers `/api` and `/api/users`, not `/apiary`
+fn path_matches(request_path: &str, cookie_path: &str) -> bool {
+    request_path == cookie_path
+        || (request_path.starts_with(cookie_path)
+            && (cookie_path.ends_with('/') || request_path[cookie_path.len()..].starts_with('/')))
+}
+
+/// Directory of the request path, used when a cookie has no Path attribute
+fn default_cookie_path(request_path: &str) -> String {
+    match request_path.rfind('/') {
+        Some(0) | None => "/".to_string(),
+        Some(index) => request_path[..index].to_string(),
+    }
+}
+
+/// `Wed, 21 Oct 2015 07:28:00 GMT`, or the older dashed form servers still send
+fn parse_cookie_date(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc2822(value)
+        .map(|date| date.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(value, "%a, %d-%b-%Y %H:%M:%S GMT")
+                .ok()
+                .map(|date| date.and_utc())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cookie_jar_round_trip() {
+        let login = Url::parse("https://api.example.com/auth/login").unwrap();
+        let mut jar = CookieJar::default();
+        jar.store_response_cookies(
+            &login,
+            [
+                "session=abc123; Path=/; HttpOnly; Secure",
+                "theme=dark; Domain=.example.com; Path=/",
+                "scoped=1",
+                "evil=1; Domain=attacker.com",
+                "old=1; Expires=Wed, 21 Oct 2015 07:28:00 GMT",
+            ],
+        );
+        assert!(jar.changed);
+        assert_eq!(jar.cookies.len(), 3);
+
+        let users = Url::parse("https://api.example.com/users").unwrap();
+        assert_eq!(
+            jar.cookie_header(&users).as_deref(),
+            Some("session=abc123; theme=dark")
+        );
+        // Default path is the login URL's directory, so only /auth/... gets `scoped`
+        let refresh = Url::parse("https://api.example.com/auth/refresh").unwrap();
+        assert_eq!(
+            jar.cookie_header(&refresh).as_deref(),
+            Some("scoped=1; session=abc123; theme=dark")
+        );
+        // Secure and host-only cookies stay with their origin
+        let other = Url::parse("http://www.example.com/").unwrap();
+        assert_eq!(jar.cookie_header(&other).as_deref(), Some("theme=dark"));
+
+        // Logging out with Max-Age=0 removes the session
+        jar.store_response_cookies(&login, ["session=; Max-Age=0; Path=/"]);
+        assert_eq!(jar.cookie_header(&users).as_deref(), Some("theme=dark"));
+
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("cookies.json");
+        jar.save(&path).unwrap();
+        assert!(!jar.changed);
+        assert_eq!(CookieJar::load(&path), jar);
+    }
+}
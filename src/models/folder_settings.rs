@@ -0,0 +1,144 @@
+use crate::models::KeyValuePair;
+use crate::models::http_client::{HttpAuth, HttpRequest};
+use crate::models::tree::FolderColor;
+use serde::{Deserialize, Serialize};
+
+/// File stem of a folder's settings file; hidden, so it never lists as a request
+pub const FOLDER_SETTINGS_STEM: &str = ".folder";
+
+/// Settings a collection folder applies to the requests inside it
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FolderSettings {
+    /// Environment activated when a request in this folder (or below it) is loaded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
+    /// Auth for requests inside that have none of their own
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<HttpAuth>,
+    /// Variables for requests inside; the active environment's values win over them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub variables: Vec<KeyValuePair>,
+    /// Color label the folder is drawn with in the tree
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<FolderColor>,
+    /// Folders with a higher weight sort above their siblings; 0 keeps alphabetical order
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub sort_weight: i32,
+}
+
+fn is_zero(weight: &i32) -> bool {
+    *weight == 0
+}
+
+impl FolderSettings {
+    /// Nothing set, so no settings file is needed
+    pub fn is_empty(&self) -> bool {
+        self == &FolderSettings::default()
+    }
+}
+
+/// Auth and variables a request picks up from the folders above it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FolderInheritance {
+    /// Auth of the nearest folder that sets one, with that folder
+    pub auth: Option<(String, HttpAuth)>,
+    /// Variables of every folder from the root down; deeper folders come later and win
+    pub variables: Vec<KeyValuePair>,
+}
+
+impl FolderInheritance {
+    /// Take one more folder's settings, going from the root down
+    pub fn add_folder(&mut self, folder_path: &str, settings: FolderSettings) {
+        if let Some(auth) = settings.auth {
+            self.auth = Some((folder_path.to_string(), auth));
+        }
+        self.variables.extend(settings.variables);
+    }
+
+    /// Folder auth for a request that has none of its own
+    pub fn apply_auth(&self, request: &mut HttpRequest) {
+        if request.auth == HttpAuth::None
+            && let Some((_, auth)) = &self.auth
+        {
+            request.auth = auth.clone();
+        }
+    }
+
+    /// Put the folder variables under `environment`, whose own values still win
+    pub fn apply_variables(&self, environment: &mut Vec<KeyValuePair>) {
+        if self.variables.is_empty() {
+            return;
+        }
+        let mut merged = self.variables.clone();
+        merged.append(environment);
+        *environment = merged;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearest_folder_auth_and_variables_are_inherited() {
+        let mut inheritance = FolderInheritance::default();
+        inheritance.add_folder(
+            "api",
+            FolderSettings {
+                auth: Some(HttpAuth::Bearer {
+                    token: "{{token}}".to_string(),
+                }),
+                variables: vec![
+                    KeyValuePair::new("version", "v1"),
+                    KeyValuePair::new("host", "api.local"),
+                ],
+                ..FolderSettings::default()
+            },
+        );
+        inheritance.add_folder(
+            "api/admin",
+            FolderSettings {
+                auth: Some(HttpAuth::Basic {
+                    username: "admin".to_string(),
+                    password: "secret".to_string(),
+                }),
+                variables: vec![KeyValuePair::new("version", "v2")],
+                ..FolderSettings::default()
+            },
+        );
+        assert_eq!(inheritance.auth.as_ref().unwrap().0, "api/admin");
+
+        // Requests with their own auth keep it
+        let mut own = HttpRequest::new("Own");
+        own.auth = HttpAuth::Bearer {
+            token: "mine".to_string(),
+        };
+        inheritance.apply_auth(&mut own);
+        assert_eq!(
+            own.auth,
+            HttpAuth::Bearer {
+                token: "mine".to_string()
+            }
+        );
+        let mut bare = HttpRequest::new("Bare");
+        inheritance.apply_auth(&mut bare);
+        assert_eq!(bare.auth.title(), "Basic Auth");
+
+        // Later entries win when variables are resolved: environment, then deeper folders
+        let mut environment = vec![KeyValuePair::new("host", "api.example.com")];
+        inheritance.apply_variables(&mut environment);
+        let names: Vec<(&str, &str)> = environment
+            .iter()
+            .map(|var| (var.name.as_str(), var.value.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("version", "v1"),
+                ("host", "api.local"),
+                ("version", "v2"),
+                ("host", "api.example.com"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,99 @@
+/// Request header names offered while typing a header name in the Headers tab
+pub const COMMON_HEADER_NAMES: &[&str] = &[
+    "Accept",
+    "Accept-Charset",
+    "Accept-Encoding",
+    "Accept-Language",
+    "Authorization",
+    "Cache-Control",
+    "Connection",
+    "Content-Disposition",
+    "Content-Encoding",
+    "Content-Language",
+    "Content-Length",
+    "Content-Type",
+    "Cookie",
+    "Date",
+    "Expect",
+    "Forwarded",
+    "From",
+    "Host",
+    "If-Match",
+    "If-Modified-Since",
+    "If-None-Match",
+    "If-Range",
+    "If-Unmodified-Since",
+    "Idempotency-Key",
+    "Origin",
+    "Pragma",
+    "Prefer",
+    "Proxy-Authorization",
+    "Range",
+    "Referer",
+    "TE",
+    "Upgrade",
+    "User-Agent",
+    "Via",
+    "X-API-Key",
+    "X-Correlation-ID",
+    "X-Forwarded-For",
+    "X-Forwarded-Host",
+    "X-Forwarded-Proto",
+    "X-Request-ID",
+    "X-Requested-With",
+];
+
+/// Known header names starting with `prefix` (case-insensitive), leaving out
+/// one that was typed in full
+pub fn header_name_suggestions(prefix: &str) -> Vec<&'static str> {
+    if prefix.is_empty() {
+        return Vec::new();
+    }
+    let prefix = prefix.to_ascii_lowercase();
+    COMMON_HEADER_NAMES
+        .iter()
+        .copied()
+        .filter(|name| {
+            let name = name.to_ascii_lowercase();
+            name.starts_with(&prefix) && name != prefix
+        })
+        .collect()
+}
+
+/// What Tab completes `prefix` to: the longest start shared by every
+/// suggestion, in its usual capitalization, if that changes anything
+pub fn complete_header_name(prefix: &str) -> Option<String> {
+    let suggestions = header_name_suggestions(prefix);
+    let (first, rest) = suggestions.split_first()?;
+    let shared = rest.iter().fold(first.len(), |shared, name| {
+        first
+            .bytes()
+            .zip(name.bytes())
+            .take(shared)
+            .take_while(|(a, b)| a.eq_ignore_ascii_case(b))
+            .count()
+    });
+    (first[..shared] != *prefix).then(|| first[..shared].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_name_completion() {
+        assert_eq!(
+            header_name_suggestions("content-l"),
+            ["Content-Language", "Content-Length"]
+        );
+        assert!(header_name_suggestions("").is_empty());
+        // A name typed in full isn't suggested again
+        assert!(!header_name_suggestions("accept").contains(&"Accept"));
+
+        assert_eq!(complete_header_name("auth"), Some("Authorization".into()));
+        assert_eq!(complete_header_name("cont"), Some("Content-".into()));
+        assert_eq!(complete_header_name("content-l"), Some("Content-L".into()));
+        assert_eq!(complete_header_name("Content-L"), None);
+        assert_eq!(complete_header_name("X-Custom"), None);
+    }
+}
@@ -0,0 +1,120 @@
+/// Bytes shown on each row of a hex dump
+pub const HEX_ROW_BYTES: usize = 16;
+
+/// Content types whose bodies aren't text, whatever bytes they happen to start with
+pub fn is_binary_content_type(content_type: &str) -> bool {
+    let essence = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    if essence.ends_with("+xml") || essence.ends_with("+json") {
+        return false;
+    }
+    ["image/", "audio/", "video/", "font/"]
+        .iter()
+        .any(|prefix| essence.starts_with(prefix))
+        || matches!(
+            essence.as_str(),
+            "application/octet-stream"
+                | "application/protobuf"
+                | "application/x-protobuf"
+                | "application/vnd.google.protobuf"
+                | "application/grpc"
+                | "application/pdf"
+                | "application/zip"
+                | "application/gzip"
+                | "application/wasm"
+                | "application/msgpack"
+                | "application/x-msgpack"
+                | "application/cbor"
+        )
+}
+
+/// Whether a body should be shown as a hex dump: a binary content type, or bytes that
+/// aren't UTF-8. A body cut off mid-character by the display limit still counts as text.
+pub fn is_binary_body(content_type: &str, bytes: &[u8]) -> bool {
+    is_binary_content_type(content_type)
+        || std::str::from_utf8(bytes).is_err_and(|e| e.error_len().is_some())
+}
+
+/// Number of rows the dump of `len` bytes takes
+pub fn hex_row_count(len: usize) -> usize {
+    len.div_ceil(HEX_ROW_BYTES)
+}
+
+/// One row of a `hexdump -C` style dump
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HexRow {
+    pub offset: usize,
+    /// Byte values, with an extra gap after the first eight
+    pub hex: String,
+    /// Printable ASCII, with `.` for everything else
+    pub ascii: String,
+}
+
+impl HexRow {
+    pub fn text(&self) -> String {
+        format!("{:08x}  {}  |{}|", self.offset, self.hex, self.ascii)
+    }
+}
+
+/// Row `row` of the dump of `bytes`, padded so the ASCII column lines up
+pub fn hex_row(bytes: &[u8], row: usize) -> Option<HexRow> {
+    let offset = row.checked_mul(HEX_ROW_BYTES)?;
+    if offset >= bytes.len() {
+        return None;
+    }
+    let chunk = &bytes[offset..(offset + HEX_ROW_BYTES).min(bytes.len())];
+
+    let mut hex = String::with_capacity(HEX_ROW_BYTES * 3 + 1);
+    for index in 0..HEX_ROW_BYTES {
+        if index == HEX_ROW_BYTES / 2 {
+            hex.push(' ');
+        }
+        match chunk.get(index) {
+            Some(byte) => hex.push_str(&format!("{:02x} ", byte)),
+            None => hex.push_str("   "),
+        }
+    }
+    hex.pop();
+
+    let ascii = chunk
+        .iter()
+        .map(|&byte| {
+            if byte.is_ascii_graphic() || byte == b' ' {
+                byte as char
+            } else {
+                '.'
+            }
+        })
+        .collect();
+    Some(HexRow { offset, hex, ascii })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_rows_match_hexdump_layout() {
+        let bytes: Vec<u8> = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR-tail".to_vec();
+        assert!(is_binary_body("image/png", &bytes));
+        assert!(is_binary_body("text/plain", &[0xff, 0xfe, 0x00]));
+        assert!(!is_binary_body("image/svg+xml", b"<svg/>"));
+        // Cut off in the middle of "é" by the display limit
+        assert!(!is_binary_body("text/plain", &"café".as_bytes()[..4]));
+
+        assert_eq!(hex_row_count(bytes.len()), 2);
+        assert_eq!(
+            hex_row(&bytes, 0).unwrap().text(),
+            "00000000  89 50 4e 47 0d 0a 1a 0a  00 00 00 0d 49 48 44 52  |.PNG........IHDR|"
+        );
+        assert_eq!(
+            hex_row(&bytes, 1).unwrap().text(),
+            "00000010  2d 74 61 69 6c                                    |-tail|"
+        );
+        assert_eq!(hex_row(&bytes, 2), None);
+    }
+}
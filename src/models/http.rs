@@ -1,6 +1,35 @@
 use crate::models::http_client::{
-    HttpHeader, HttpMethod, HttpQueryParam, HttpRequest, HttpRequestBody, HttpResponse,
+    HttpHeader, HttpMethod, HttpMultipartPart, HttpQueryParam, HttpRequest, HttpRequestBody,
+    HttpResponse,
 };
+use crate::models::json_builder::{json_to_rows, rows_to_json};
+use crate::models::key_value::{KeyValueEditor, KeyValuePair};
+use crate::models::text_search::TextMatch;
+use serde::{Deserialize, Serialize};
+
+use crate::models::stepper::{
+    MAX_PAGES_RANGE, MAX_REDIRECTS_RANGE, RETRY_ATTEMPTS_RANGE, RETRY_BACKOFF_MS_RANGE,
+    TIMEOUT_SECS_RANGE,
+};
+
+/// Settings tab rows, in display order; the pagination fields follow them
+pub const SETTINGS_ROW_TIMEOUT: usize = 0;
+pub const SETTINGS_ROW_FOLLOW_REDIRECTS: usize = 1;
+pub const SETTINGS_ROW_MAX_REDIRECTS: usize = 2;
+pub const SETTINGS_ROW_VERIFY_SSL: usize = 3;
+pub const SETTINGS_ROW_RETRY_ATTEMPTS: usize = 4;
+pub const SETTINGS_ROW_RETRY_BACKOFF: usize = 5;
+pub const SETTINGS_ROW_RETRY_ON: usize = 6;
+pub const SETTINGS_ROW_USE_CACHE: usize = 7;
+pub const SETTINGS_ROW_USER_AGENT: usize = 8;
+pub const SETTINGS_ROW_CA_BUNDLE: usize = 9;
+pub const SETTINGS_ROW_CLIENT_CERT: usize = 10;
+pub const SETTINGS_ROW_CLIENT_KEY: usize = 11;
+pub const SETTINGS_ROW_CLIENT_CERT_PASSWORD: usize = 12;
+pub const SETTINGS_ROW_PROXY_URL: usize = 13;
+pub const SETTINGS_ROW_PROXY_USERNAME: usize = 14;
+pub const SETTINGS_ROW_PROXY_PASSWORD: usize = 15;
+pub const REQUEST_SETTINGS_ROWS: usize = 16;
 
 /// Current tab in the request editor
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -9,6 +38,11 @@ pub enum HttpRequestTab {
     Body,
     QueryParams,
     Auth,
+    Cookies,
+    Script,
+    Tests,
+    Extract,
+    Docs,
     Settings,
 }
 
@@ -19,6 +53,11 @@ impl HttpRequestTab {
             HttpRequestTab::Body,
             HttpRequestTab::QueryParams,
             HttpRequestTab::Auth,
+            HttpRequestTab::Cookies,
+            HttpRequestTab::Script,
+            HttpRequestTab::Tests,
+            HttpRequestTab::Extract,
+            HttpRequestTab::Docs,
             HttpRequestTab::Settings,
         ]
     }
@@ -29,6 +68,11 @@ impl HttpRequestTab {
             HttpRequestTab::Body => "Body",
             HttpRequestTab::QueryParams => "Query",
             HttpRequestTab::Auth => "Auth",
+            HttpRequestTab::Cookies => "Cookies",
+            HttpRequestTab::Script => "Script",
+            HttpRequestTab::Tests => "Tests",
+            HttpRequestTab::Extract => "Extract",
+            HttpRequestTab::Docs => "Docs",
             HttpRequestTab::Settings => "Settings",
         }
     }
@@ -39,6 +83,7 @@ impl HttpRequestTab {
 pub enum HttpResponseTab {
     Body,
     Headers,
+    Tests,
     Info,
 }
 
@@ -47,6 +92,7 @@ impl HttpResponseTab {
         vec![
             HttpResponseTab::Body,
             HttpResponseTab::Headers,
+            HttpResponseTab::Tests,
             HttpResponseTab::Info,
         ]
     }
@@ -55,6 +101,7 @@ impl HttpResponseTab {
         match self {
             HttpResponseTab::Body => "Body",
             HttpResponseTab::Headers => "Headers",
+            HttpResponseTab::Tests => "Tests",
             HttpResponseTab::Info => "Info",
         }
     }
@@ -67,7 +114,9 @@ pub enum BodyContentType {
     Json,
     Text,
     Form,
+    Multipart,
     Raw,
+    File,
 }
 
 impl BodyContentType {
@@ -77,7 +126,9 @@ impl BodyContentType {
             BodyContentType::Json,
             BodyContentType::Text,
             BodyContentType::Form,
+            BodyContentType::Multipart,
             BodyContentType::Raw,
+            BodyContentType::File,
         ]
     }
 
@@ -87,7 +138,49 @@ impl BodyContentType {
             BodyContentType::Json => "JSON",
             BodyContentType::Text => "Text",
             BodyContentType::Form => "Form",
+            BodyContentType::Multipart => "Multipart",
             BodyContentType::Raw => "Raw",
+            BodyContentType::File => "File",
+        }
+    }
+}
+
+/// Where a JSON body failed to parse (1-based line and column, as reported by serde_json)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonBodyError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for JsonBodyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+/// A request open in the editor's tab strip
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OpenRequestTab {
+    /// Request key (collection path or draft id)
+    pub key: String,
+    /// The request as last left in the editor; the active tab's live copy is `current_request`
+    pub request: HttpRequest,
+    /// Label given to the tab, independent of the stored request name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+impl OpenRequestTab {
+    fn new(key: &str, request: &HttpRequest) -> Self {
+        Self {
+            key: key.to_string(),
+            request: request.clone(),
+            title: None,
         }
     }
 }
@@ -104,6 +197,31 @@ pub struct HttpState {
     /// Current tab in response viewer
     pub current_response_tab: HttpResponseTab,
 
+    /// Show response bodies exactly as received instead of pretty-printed and highlighted
+    pub response_raw_view: bool,
+
+    /// Show JSON response bodies as a collapsible tree
+    pub response_tree_view: bool,
+
+    /// JSONPath / jq-style filter applied to the response body, and whether it's being typed
+    pub response_filter: String,
+    pub response_filter_mode: bool,
+
+    /// Line under the cursor in the formatted JSON response body (row in the tree view)
+    pub response_cursor: usize,
+
+    /// JSONPaths of the nodes collapsed in the tree view
+    pub response_collapsed: std::collections::HashSet<String>,
+
+    /// Text searched for in the shown response body, whether it's being typed, and
+    /// which match n/N moved to
+    pub response_search: String,
+    pub response_search_mode: bool,
+    pub response_search_current: usize,
+    /// Where `response_search` occurs in the shown body text; found again only when the
+    /// search text or the shown text changes
+    pub response_search_matches: Vec<TextMatch>,
+
     /// Current body content type being edited
     pub current_body_type: BodyContentType,
 
@@ -113,14 +231,78 @@ pub struct HttpState {
     /// Most recent response (if any)
     pub last_response: Option<HttpResponse>,
 
-    /// Whether a request is currently being sent
-    pub is_sending: bool,
+    /// Bumped whenever `last_response` changes, so views built from it know to rebuild
+    response_generation: u64,
+
+    /// Key identifying the request in the editor (collection path or draft id)
+    pub current_request_key: String,
+
+    /// Requests open side by side, in strip order; never empty
+    pub open_tabs: Vec<OpenRequestTab>,
+
+    /// Index into `open_tabs` of the request in the editor
+    pub active_tab: usize,
+
+    /// Requests currently being sent, as they were when the send started
+    pub in_flight: std::collections::HashMap<String, HttpRequest>,
+
+    /// Pages and items fetched so far by each in-flight page walk
+    pub page_progress: std::collections::HashMap<String, (usize, usize)>,
+
+    /// The request behind each key's latest response, to tell when edits made it stale
+    pub sent_requests: std::collections::HashMap<String, HttpRequest>,
+    /// The same requests as they went out: scripts run, variables filled in
+    pub prepared_requests: std::collections::HashMap<String, HttpRequest>,
+    /// Key of the request sent most recently, for re-sending it from anywhere
+    pub last_sent_key: Option<String>,
+
+    /// Latest response per request key, so responses land on the request that sent them
+    pub responses: std::collections::HashMap<String, HttpResponse>,
+
+    /// The response before the latest one, per request key (for diffing)
+    pub previous_responses: std::collections::HashMap<String, HttpResponse>,
+
+    /// Counter used to give unsaved requests a unique key
+    next_draft_id: u64,
 
     /// List of recent URLs for autocomplete
     pub recent_urls: Vec<String>,
 
     /// Environment variables for template substitution
-    pub environment_vars: std::collections::HashMap<String, String>,
+    pub environment_vars: Vec<KeyValuePair>,
+
+    /// Row editor for the headers, query params and form body lists
+    pub key_value_editor: KeyValueEditor,
+
+    /// Cursor position (in characters) inside the body text editor
+    pub body_cursor: usize,
+
+    /// Last JSON parse error for the body (cleared when the body parses again)
+    pub body_error: Option<JsonBodyError>,
+
+    /// Rows of the JSON body builder (`path = value`), when it replaces the raw editor
+    pub json_builder: Option<Vec<KeyValuePair>>,
+
+    /// Why the builder rows don't make a JSON document; the body keeps the last valid one
+    pub json_builder_error: Option<String>,
+
+    /// Cursor position (in characters) inside the pre-request script editor
+    pub script_cursor: usize,
+
+    /// Cursor position (in characters) inside the URL
+    pub url_cursor: usize,
+
+    /// Selected field on the Auth tab
+    pub auth_focused_field: usize,
+
+    /// Selected pagination field on the Settings tab
+    pub settings_focused_field: usize,
+}
+
+impl Default for HttpState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl HttpState {
@@ -129,12 +311,45 @@ impl HttpState {
             current_request: HttpRequest::new("New Request"),
             current_request_tab: HttpRequestTab::Headers,
             current_response_tab: HttpResponseTab::Body,
+            response_raw_view: false,
+            response_tree_view: false,
+            response_filter: String::new(),
+            response_filter_mode: false,
+            response_cursor: 0,
+            response_collapsed: std::collections::HashSet::new(),
+            response_search: String::new(),
+            response_search_mode: false,
+            response_search_current: 0,
+            response_search_matches: Vec::new(),
             current_body_type: BodyContentType::None,
             focused_field: 0,
             last_response: None,
-            is_sending: false,
+            response_generation: 0,
+            current_request_key: "draft-0".to_string(),
+            open_tabs: vec![OpenRequestTab::new(
+                "draft-0",
+                &HttpRequest::new("New Request"),
+            )],
+            active_tab: 0,
+            in_flight: std::collections::HashMap::new(),
+            page_progress: std::collections::HashMap::new(),
+            sent_requests: std::collections::HashMap::new(),
+            prepared_requests: std::collections::HashMap::new(),
+            last_sent_key: None,
+            responses: std::collections::HashMap::new(),
+            previous_responses: std::collections::HashMap::new(),
+            next_draft_id: 1,
             recent_urls: Vec::new(),
-            environment_vars: std::collections::HashMap::new(),
+            environment_vars: Vec::new(),
+            key_value_editor: KeyValueEditor::new(),
+            body_cursor: 0,
+            body_error: None,
+            json_builder: None,
+            json_builder_error: None,
+            script_cursor: 0,
+            url_cursor: 0,
+            auth_focused_field: 0,
+            settings_focused_field: 0,
         }
     }
 
@@ -146,6 +361,8 @@ impl HttpState {
             .position(|t| t == &self.current_request_tab)
             .unwrap_or(0);
         self.current_request_tab = tabs[(current_index + 1) % tabs.len()].clone();
+        self.key_value_editor.reset();
+        self.move_script_cursor_to_end();
     }
 
     /// Switch to previous request tab
@@ -161,6 +378,8 @@ impl HttpState {
             current_index - 1
         };
         self.current_request_tab = tabs[prev_index].clone();
+        self.key_value_editor.reset();
+        self.move_script_cursor_to_end();
     }
 
     /// Switch to next response tab
@@ -232,6 +451,73 @@ impl HttpState {
         self.current_request.url = url;
     }
 
+    /// Whether typing in edit mode goes to the URL (the current tab has no text of its own)
+    pub fn is_url_editable(&self) -> bool {
+        let tab_has_text = match self.current_request_tab {
+            HttpRequestTab::Auth => !self.current_request.auth.fields().is_empty(),
+            HttpRequestTab::Settings => self.is_settings_text_row(),
+            _ => self.is_body_text_editable() || self.is_script_editable(),
+        };
+        !tab_has_text
+    }
+
+    /// Insert a character at the URL cursor
+    pub fn insert_url_char(&mut self, c: char) {
+        let byte_index = byte_index_for_char(&self.current_request.url, self.url_cursor);
+        self.current_request.url.insert(byte_index, c);
+        self.url_cursor += 1;
+    }
+
+    /// Insert pasted text at the URL cursor (line breaks are dropped)
+    pub fn insert_url_text(&mut self, text: &str) {
+        for c in text.chars().filter(|c| !c.is_control()) {
+            self.insert_url_char(c);
+        }
+    }
+
+    /// Delete the character before the URL cursor
+    pub fn delete_url_char(&mut self) {
+        if self.url_cursor == 0 {
+            return;
+        }
+        let byte_index = byte_index_for_char(&self.current_request.url, self.url_cursor - 1);
+        self.current_request.url.remove(byte_index);
+        self.url_cursor -= 1;
+    }
+
+    /// Delete the character under the URL cursor
+    pub fn delete_url_char_forward(&mut self) {
+        if self.url_cursor < self.current_request.url.chars().count() {
+            let byte_index = byte_index_for_char(&self.current_request.url, self.url_cursor);
+            self.current_request.url.remove(byte_index);
+        }
+    }
+
+    pub fn move_url_cursor_left(&mut self) {
+        self.url_cursor = self.url_cursor.saturating_sub(1);
+    }
+
+    pub fn move_url_cursor_right(&mut self) {
+        let len = self.current_request.url.chars().count();
+        self.url_cursor = (self.url_cursor + 1).min(len);
+    }
+
+    pub fn move_url_cursor_home(&mut self) {
+        self.url_cursor = 0;
+    }
+
+    pub fn move_url_cursor_to_end(&mut self) {
+        self.url_cursor = self.current_request.url.chars().count();
+    }
+
+    /// First URL character to show in a field `width` characters wide, keeping the cursor in view
+    pub fn url_scroll_offset(&self, width: usize) -> usize {
+        let len = self.current_request.url.chars().count();
+        let cursor = self.url_cursor.min(len);
+        // One column is kept for the cursor past the last character
+        (cursor + 1).saturating_sub(width.max(1))
+    }
+
     /// Set request body
     pub fn set_body(&mut self, body: HttpRequestBody) {
         self.current_request.body = body;
@@ -244,7 +530,9 @@ impl HttpState {
             HttpRequestBody::Text { content, .. } => content.clone(),
             HttpRequestBody::Json { content } => content.clone(),
             HttpRequestBody::Raw { content } => content.clone(),
+            HttpRequestBody::File { path, .. } => path.clone(),
             HttpRequestBody::Form { .. } => String::new(), // Form handled separately
+            HttpRequestBody::Multipart { .. } => String::new(),
         }
     }
 
@@ -266,9 +554,411 @@ impl HttpState {
             BodyContentType::Raw => {
                 self.current_request.body = HttpRequestBody::Raw { content };
             }
+            BodyContentType::File => {
+                // The text being edited is the path; keep a content type set by an import
+                let content_type = match &self.current_request.body {
+                    HttpRequestBody::File { content_type, .. } => content_type.clone(),
+                    _ => String::new(),
+                };
+                self.current_request.body = HttpRequestBody::File {
+                    path: content,
+                    content_type,
+                };
+            }
+            BodyContentType::Form | BodyContentType::Multipart => {
+                // Fields and parts are edited as rows, not as text
+            }
+        }
+    }
+
+    /// Whether the body tab is showing a free-text body (JSON, text, raw or a file path)
+    pub fn is_body_text_editable(&self) -> bool {
+        self.current_request_tab == HttpRequestTab::Body
+            && !self.is_json_builder_active()
+            && matches!(
+                self.current_body_type,
+                BodyContentType::Json
+                    | BodyContentType::Text
+                    | BodyContentType::Raw
+                    | BodyContentType::File
+            )
+    }
+
+    /// Switch to the next body type, carrying text content over where possible
+    pub fn cycle_body_type(&mut self) {
+        let types = BodyContentType::all();
+        let current_index = types
+            .iter()
+            .position(|t| t == &self.current_body_type)
+            .unwrap_or(0);
+        let content = self.get_body_content();
+
+        self.current_body_type = types[(current_index + 1) % types.len()].clone();
+        match self.current_body_type {
             BodyContentType::Form => {
-                // Form data handled separately through add_form_field
+                self.current_request.body = HttpRequestBody::Form { fields: Vec::new() };
             }
+            BodyContentType::Multipart => {
+                // Form fields carry over as text parts
+                let parts = match &self.current_request.body {
+                    HttpRequestBody::Form { fields } => fields
+                        .iter()
+                        .map(|field| HttpMultipartPart {
+                            enabled: field.enabled,
+                            ..HttpMultipartPart::new(&field.name, &field.value)
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                self.current_request.body = HttpRequestBody::Multipart { parts };
+            }
+            _ => self.update_body_content(content),
+        }
+
+        self.body_cursor = self.get_body_content().chars().count();
+        self.body_error = None;
+        self.json_builder = None;
+        self.json_builder_error = None;
+        self.key_value_editor.reset();
+    }
+
+    /// Insert a character at the body cursor
+    pub fn insert_body_char(&mut self, c: char) {
+        let mut content = self.get_body_content();
+        let byte_index = byte_index_for_char(&content, self.body_cursor);
+        content.insert(byte_index, c);
+        self.update_body_content(content);
+        self.body_cursor += 1;
+    }
+
+    /// Delete the character before the body cursor
+    pub fn delete_body_char(&mut self) {
+        if self.body_cursor == 0 {
+            return;
+        }
+
+        let mut content = self.get_body_content();
+        let byte_index = byte_index_for_char(&content, self.body_cursor - 1);
+        content.remove(byte_index);
+        self.update_body_content(content);
+        self.body_cursor -= 1;
+    }
+
+    pub fn move_body_cursor_left(&mut self) {
+        self.body_cursor = self.body_cursor.saturating_sub(1);
+    }
+
+    pub fn move_body_cursor_right(&mut self) {
+        let len = self.get_body_content().chars().count();
+        self.body_cursor = (self.body_cursor + 1).min(len);
+    }
+
+    pub fn move_body_cursor_to_end(&mut self) {
+        self.body_cursor = self.get_body_content().chars().count();
+    }
+
+    /// Zero-based (line, column) of the body cursor
+    pub fn body_cursor_line_col(&self) -> (usize, usize) {
+        text_line_col(&self.get_body_content(), self.body_cursor)
+    }
+
+    /// Whether a script-style text tab (pre-request script, tests, extraction rules or
+    /// docs) is showing
+    pub fn is_script_editable(&self) -> bool {
+        matches!(
+            self.current_request_tab,
+            HttpRequestTab::Script
+                | HttpRequestTab::Tests
+                | HttpRequestTab::Extract
+                | HttpRequestTab::Docs
+        )
+    }
+
+    /// Text edited by the script cursor: the text of the Tests, Extract or Docs tab,
+    /// the script otherwise
+    pub fn script_text(&self) -> &str {
+        match self.current_request_tab {
+            HttpRequestTab::Tests => &self.current_request.tests,
+            HttpRequestTab::Extract => &self.current_request.extract,
+            HttpRequestTab::Docs => &self.current_request.description,
+            _ => &self.current_request.pre_request_script,
+        }
+    }
+
+    fn script_text_mut(&mut self) -> &mut String {
+        match self.current_request_tab {
+            HttpRequestTab::Tests => &mut self.current_request.tests,
+            HttpRequestTab::Extract => &mut self.current_request.extract,
+            HttpRequestTab::Docs => &mut self.current_request.description,
+            _ => &mut self.current_request.pre_request_script,
+        }
+    }
+
+    /// Insert a character at the script cursor
+    pub fn insert_script_char(&mut self, c: char) {
+        let cursor = self.script_cursor;
+        let script = self.script_text_mut();
+        let byte_index = byte_index_for_char(script, cursor);
+        script.insert(byte_index, c);
+        self.script_cursor += 1;
+    }
+
+    /// Delete the character before the script cursor
+    pub fn delete_script_char(&mut self) {
+        if self.script_cursor == 0 {
+            return;
+        }
+        let cursor = self.script_cursor;
+        let script = self.script_text_mut();
+        let byte_index = byte_index_for_char(script, cursor - 1);
+        script.remove(byte_index);
+        self.script_cursor -= 1;
+    }
+
+    pub fn move_script_cursor_left(&mut self) {
+        self.script_cursor = self.script_cursor.saturating_sub(1);
+    }
+
+    pub fn move_script_cursor_right(&mut self) {
+        let len = self.script_text().chars().count();
+        self.script_cursor = (self.script_cursor + 1).min(len);
+    }
+
+    /// Replace the text of the Script, Tests, Extract or Docs tab
+    pub fn set_script_text(&mut self, text: String) {
+        *self.script_text_mut() = text;
+        self.move_script_cursor_to_end();
+    }
+
+    pub fn move_script_cursor_to_end(&mut self) {
+        self.script_cursor = self.script_text().chars().count();
+    }
+
+    /// Zero-based (line, column) of the script cursor
+    pub fn script_cursor_line_col(&self) -> (usize, usize) {
+        text_line_col(self.script_text(), self.script_cursor)
+    }
+
+    /// Parse a JSON body, returning the error location if it is invalid.
+    ///
+    /// Non-JSON and empty bodies are always considered valid.
+    pub fn json_body_error(&self) -> Option<JsonBodyError> {
+        if self.current_body_type != BodyContentType::Json {
+            return None;
+        }
+
+        let content = self.get_body_content();
+        if content.trim().is_empty() {
+            return None;
+        }
+
+        serde_json::from_str::<serde_json::Value>(&content)
+            .err()
+            .map(|e| JsonBodyError {
+                line: e.line(),
+                column: e.column(),
+                message: strip_position(&e.to_string()),
+            })
+    }
+
+    /// Validate the JSON body, moving the cursor to the error or pretty-printing it when valid.
+    ///
+    /// Returns whether the body is valid.
+    pub fn check_json_body(&mut self, auto_format: bool) -> bool {
+        self.body_error = self.json_body_error();
+
+        if let Some(error) = &self.body_error {
+            self.body_cursor =
+                char_index_for_line_col(&self.get_body_content(), error.line, error.column);
+            return false;
+        }
+
+        // Builder output is already formatted, in row order
+        if auto_format
+            && self.current_body_type == BodyContentType::Json
+            && self.json_builder.is_none()
+        {
+            let content = self.get_body_content();
+            if let Ok(value) = serde_json::from_str::<serde_json::Value>(&content)
+                && let Ok(pretty) = serde_json::to_string_pretty(&value)
+                && pretty != content
+            {
+                self.update_body_content(pretty);
+                self.move_body_cursor_to_end();
+            }
+        }
+
+        true
+    }
+
+    /// Whether the JSON body is being edited as builder rows
+    pub fn is_json_builder_active(&self) -> bool {
+        self.current_body_type == BodyContentType::Json && self.json_builder.is_some()
+    }
+
+    /// Switch a JSON body between the raw editor and builder rows
+    ///
+    /// Entering the builder flattens the current body, so it has to be valid JSON
+    /// with an object or array at the top.
+    pub fn toggle_json_builder(&mut self) -> anyhow::Result<()> {
+        if self.json_builder.take().is_some() {
+            self.json_builder_error = None;
+        } else {
+            if self.current_body_type != BodyContentType::Json {
+                anyhow::bail!("The builder is only for JSON bodies");
+            }
+            self.json_builder = Some(json_to_rows(&self.get_body_content())?);
+            self.body_error = None;
+        }
+        self.key_value_editor.reset();
+        self.move_body_cursor_to_end();
+        Ok(())
+    }
+
+    /// Rebuild the JSON body from the builder rows after they change
+    pub fn sync_json_builder(&mut self) {
+        let Some(rows) = &self.json_builder else {
+            return;
+        };
+        match rows_to_json(rows) {
+            Ok(content) => {
+                self.json_builder_error = None;
+                self.update_body_content(content);
+            }
+            Err(e) => self.json_builder_error = Some(e.to_string()),
+        }
+    }
+
+    /// Switch the request to the next auth type
+    pub fn cycle_auth_type(&mut self) {
+        self.current_request.auth = self.current_request.auth.next_kind();
+        self.auth_focused_field = 0;
+    }
+
+    /// Select the next/previous field on the Auth tab
+    pub fn move_auth_focus(&mut self, forward: bool) {
+        let count = self.current_request.auth.fields().len();
+        if count == 0 {
+            return;
+        }
+        self.auth_focused_field = if forward {
+            (self.auth_focused_field + 1) % count
+        } else {
+            (self.auth_focused_field + count - 1) % count
+        };
+    }
+
+    /// The auth field being edited, if the Auth tab is showing one
+    pub fn focused_auth_field_mut(&mut self) -> Option<&mut String> {
+        if self.current_request_tab != HttpRequestTab::Auth {
+            return None;
+        }
+        self.current_request.auth.field_mut(self.auth_focused_field)
+    }
+
+    /// Switch the request to the next pagination rule
+    pub fn cycle_pagination_rule(&mut self) {
+        let pagination = &mut self.current_request.pagination;
+        pagination.rule = pagination.rule.next_kind();
+        self.settings_focused_field = self
+            .settings_focused_field
+            .min(self.settings_row_count() - 1);
+    }
+
+    /// Turn fetching every page on each send on or off
+    pub fn toggle_pagination_auto_follow(&mut self) {
+        let pagination = &mut self.current_request.pagination;
+        pagination.auto_follow = !pagination.auto_follow;
+    }
+
+    /// Rows on the Settings tab: the request settings, then the pagination fields
+    pub fn settings_row_count(&self) -> usize {
+        REQUEST_SETTINGS_ROWS + self.current_request.pagination.fields().len()
+    }
+
+    /// Select the next/previous row on the Settings tab
+    pub fn move_settings_focus(&mut self, forward: bool) {
+        let count = self.settings_row_count();
+        self.settings_focused_field = if forward {
+            (self.settings_focused_field + 1) % count
+        } else {
+            (self.settings_focused_field + count - 1) % count
+        };
+    }
+
+    /// Whether the focused Settings row is typed into: the user agent, certificate
+    /// paths, proxy and pagination fields all follow each other
+    fn is_settings_text_row(&self) -> bool {
+        (SETTINGS_ROW_USER_AGENT..self.settings_row_count()).contains(&self.settings_focused_field)
+    }
+
+    /// Flip the focused on/off setting (or cycle what is retried); false if the focused
+    /// row isn't one
+    pub fn toggle_focused_setting(&mut self) -> bool {
+        let settings = &mut self.current_request.settings;
+        match self.settings_focused_field {
+            SETTINGS_ROW_FOLLOW_REDIRECTS => settings.follow_redirects = !settings.follow_redirects,
+            SETTINGS_ROW_VERIFY_SSL => settings.verify_ssl = !settings.verify_ssl,
+            SETTINGS_ROW_RETRY_ON => settings.retry.retry_on = settings.retry.retry_on.next(),
+            SETTINGS_ROW_USE_CACHE => settings.use_cache = !settings.use_cache,
+            _ => return false,
+        }
+        true
+    }
+
+    /// Step the focused number: timeout, max redirects, retries, or the page limit on
+    /// pagination rows
+    pub fn step_focused_setting(&mut self, steps: i64) {
+        let request = &mut self.current_request;
+        match self.settings_focused_field {
+            SETTINGS_ROW_TIMEOUT => {
+                request.settings.timeout_secs =
+                    TIMEOUT_SECS_RANGE.step_by(request.settings.timeout_secs as i64, steps) as u64;
+            }
+            SETTINGS_ROW_MAX_REDIRECTS => {
+                request.settings.max_redirects = MAX_REDIRECTS_RANGE
+                    .step_by(request.settings.max_redirects as i64, steps)
+                    as usize;
+            }
+            SETTINGS_ROW_RETRY_ATTEMPTS => {
+                let retry = &mut request.settings.retry;
+                retry.max_attempts =
+                    RETRY_ATTEMPTS_RANGE.step_by(retry.max_attempts as i64, steps) as u32;
+            }
+            SETTINGS_ROW_RETRY_BACKOFF => {
+                let retry = &mut request.settings.retry;
+                retry.backoff_ms =
+                    RETRY_BACKOFF_MS_RANGE.step_by(retry.backoff_ms as i64, steps) as u64;
+            }
+            _ => {
+                let pagination = &mut request.pagination;
+                pagination.max_pages =
+                    MAX_PAGES_RANGE.step_by(pagination.max_pages as i64, steps) as usize;
+            }
+        }
+    }
+
+    /// The Settings tab text being edited: the user agent, a certificate path, the
+    /// proxy or a pagination field
+    pub fn focused_settings_field_mut(&mut self) -> Option<&mut String> {
+        if self.current_request_tab != HttpRequestTab::Settings {
+            return None;
+        }
+        let settings = &mut self.current_request.settings;
+        match self.settings_focused_field {
+            SETTINGS_ROW_USER_AGENT => Some(&mut settings.user_agent),
+            SETTINGS_ROW_CA_BUNDLE => Some(&mut settings.tls.ca_bundle),
+            SETTINGS_ROW_CLIENT_CERT => Some(&mut settings.tls.client_cert),
+            SETTINGS_ROW_CLIENT_KEY => Some(&mut settings.tls.client_key),
+            SETTINGS_ROW_CLIENT_CERT_PASSWORD => Some(&mut settings.tls.client_cert_password),
+            SETTINGS_ROW_PROXY_URL => Some(&mut settings.proxy.url),
+            SETTINGS_ROW_PROXY_USERNAME => Some(&mut settings.proxy.username),
+            SETTINGS_ROW_PROXY_PASSWORD => Some(&mut settings.proxy.password),
+            index if index >= REQUEST_SETTINGS_ROWS => self
+                .current_request
+                .pagination
+                .field_mut(index - REQUEST_SETTINGS_ROWS),
+            _ => None,
         }
     }
 
@@ -296,11 +986,233 @@ impl HttpState {
         errors
     }
 
-    /// Load a request into the editor
-    pub fn load_request(&mut self, request: HttpRequest) {
+    /// Whether the request in the editor is currently being sent
+    pub fn is_sending(&self) -> bool {
+        self.in_flight.contains_key(&self.current_request_key)
+    }
+
+    /// Pages and items fetched so far while the current request walks its pages
+    pub fn current_page_progress(&self) -> Option<(usize, usize)> {
+        self.page_progress.get(&self.current_request_key).copied()
+    }
+
+    /// Number of requests currently being sent
+    pub fn in_flight_count(&self) -> usize {
+        self.in_flight.len()
+    }
+
+    /// Mark the current request as in flight, returning its key
+    pub fn begin_send(&mut self) -> String {
+        let key = self.current_request_key.clone();
+        self.in_flight
+            .insert(key.clone(), self.current_request.clone());
+        self.last_sent_key = Some(key.clone());
+        key
+    }
+
+    /// Record the outcome of a send, showing the response if its request is still open
+    pub fn finish_send(&mut self, key: &str, response: Option<HttpResponse>) {
+        let sent = self.in_flight.remove(key);
+        self.page_progress.remove(key);
+
+        if let Some(response) = response {
+            if let Some(sent) = sent {
+                self.sent_requests.insert(key.to_string(), sent);
+            }
+            if key == self.current_request_key {
+                self.last_response = Some(response.clone());
+                self.response_generation += 1;
+                self.response_cursor = 0;
+                self.response_search_current = 0;
+                self.response_collapsed.clear();
+            }
+            if let Some(previous) = self.responses.insert(key.to_string(), response) {
+                self.previous_responses.insert(key.to_string(), previous);
+            }
+        }
+    }
+
+    pub fn response_generation(&self) -> u64 {
+        self.response_generation
+    }
+
+    /// Whether the request was edited since the shown response was sent
+    pub fn is_response_stale(&self) -> bool {
+        self.last_response.is_some()
+            && self
+                .sent_requests
+                .get(&self.current_request_key)
+                .is_some_and(|sent| !sent.sends_same_as(&self.current_request))
+    }
+
+    /// Clear the response shown for the current request
+    pub fn clear_response(&mut self) {
+        self.last_response = None;
+        self.response_generation += 1;
+        self.response_cursor = 0;
+        self.response_search_current = 0;
+        self.response_collapsed.clear();
+        self.responses.remove(&self.current_request_key);
+    }
+
+    /// Load a request into the editor, switching to its tab or opening one
+    pub fn load_request(&mut self, key: &str, request: HttpRequest) {
+        self.sync_active_tab();
+        match self.open_tabs.iter().position(|tab| tab.key == key) {
+            Some(index) => {
+                self.open_tabs[index].request = request.clone();
+                self.active_tab = index;
+            }
+            None => {
+                self.open_tabs.push(OpenRequestTab::new(key, &request));
+                self.active_tab = self.open_tabs.len() - 1;
+            }
+        }
+        self.show_request(key, request);
+    }
+
+    /// Label of a tab: its own title, else the name of the request in it
+    pub fn tab_label(&self, index: usize) -> &str {
+        let Some(tab) = self.open_tabs.get(index) else {
+            return "";
+        };
+        match &tab.title {
+            Some(title) => title,
+            None if index == self.active_tab => &self.current_request.name,
+            None => &tab.request.name,
+        }
+    }
+
+    /// Show the next (or previous) tab, wrapping around
+    pub fn cycle_tab(&mut self, forward: bool) {
+        let count = self.open_tabs.len();
+        let index = if forward {
+            (self.active_tab + 1) % count
+        } else {
+            (self.active_tab + count - 1) % count
+        };
+        self.switch_to_tab(index);
+    }
+
+    /// Index of the open tab showing the request with this key
+    pub fn tab_index(&self, key: &str) -> Option<usize> {
+        self.open_tabs.iter().position(|tab| tab.key == key)
+    }
+
+    pub fn switch_to_tab(&mut self, index: usize) {
+        if index == self.active_tab || index >= self.open_tabs.len() {
+            return;
+        }
+        self.sync_active_tab();
+        self.active_tab = index;
+        let tab = self.open_tabs[index].clone();
+        self.show_request(&tab.key, tab.request);
+    }
+
+    /// Swap the active tab with its left or right neighbour; false at either end
+    pub fn move_active_tab(&mut self, right: bool) -> bool {
+        let target = if right {
+            self.active_tab + 1
+        } else {
+            match self.active_tab.checked_sub(1) {
+                Some(target) => target,
+                None => return false,
+            }
+        };
+        if target >= self.open_tabs.len() {
+            return false;
+        }
+        self.open_tabs.swap(self.active_tab, target);
+        self.active_tab = target;
+        true
+    }
+
+    /// Give the active tab its own label; an empty one goes back to the request name
+    pub fn rename_active_tab(&mut self, title: &str) {
+        let title = title.trim();
+        if let Some(tab) = self.open_tabs.get_mut(self.active_tab) {
+            tab.title = (!title.is_empty()).then(|| title.to_string());
+        }
+    }
+
+    /// Close the active tab and show its neighbour (a fresh draft if it was the last)
+    pub fn close_active_tab(&mut self) -> OpenRequestTab {
+        let closed = self.open_tabs.remove(self.active_tab);
+        let closed_request = self.current_request.clone();
+        if !self.in_flight.contains_key(&closed.key) {
+            self.responses.remove(&closed.key);
+            self.previous_responses.remove(&closed.key);
+            self.sent_requests.remove(&closed.key);
+            self.prepared_requests.remove(&closed.key);
+        }
+
+        if self.open_tabs.is_empty() {
+            self.new_request();
+        } else {
+            self.active_tab = self.active_tab.min(self.open_tabs.len() - 1);
+            let tab = self.open_tabs[self.active_tab].clone();
+            self.show_request(&tab.key, tab.request);
+        }
+        OpenRequestTab {
+            request: closed_request,
+            ..closed
+        }
+    }
+
+    /// Tabs as they stand, the active one with its latest edits
+    pub fn session_tabs(&self) -> Vec<OpenRequestTab> {
+        let mut tabs = self.open_tabs.clone();
+        if let Some(tab) = tabs.get_mut(self.active_tab) {
+            tab.request = self.current_request.clone();
+        }
+        tabs
+    }
+
+    /// Reopen the tabs of an earlier session
+    pub fn restore_tabs(&mut self, tabs: Vec<OpenRequestTab>, active: usize) {
+        if tabs.is_empty() {
+            return;
+        }
+        // New drafts must not reuse a restored draft's key
+        let highest_draft = tabs
+            .iter()
+            .filter_map(|tab| tab.key.strip_prefix("draft-")?.parse::<u64>().ok())
+            .max();
+        if let Some(highest) = highest_draft {
+            self.next_draft_id = self.next_draft_id.max(highest + 1);
+        }
+        self.open_tabs = tabs;
+        self.active_tab = active.min(self.open_tabs.len() - 1);
+        let tab = self.open_tabs[self.active_tab].clone();
+        self.show_request(&tab.key, tab.request);
+    }
+
+    /// Write the editor's request back into its tab before another one is shown
+    fn sync_active_tab(&mut self) {
+        if let Some(tab) = self.open_tabs.get_mut(self.active_tab) {
+            tab.request = self.current_request.clone();
+        }
+    }
+
+    /// Put a request in the editor and reset the per-request UI state
+    fn show_request(&mut self, key: &str, request: HttpRequest) {
         self.current_request = request;
+        self.current_request_key = key.to_string();
+        self.last_response = self.responses.get(key).cloned();
+        self.response_generation += 1;
+        self.response_cursor = 0;
+        self.response_search_current = 0;
+        self.response_collapsed.clear();
 
         // Reset UI state
+        self.key_value_editor.reset();
+        self.body_error = None;
+        self.json_builder = None;
+        self.json_builder_error = None;
+        self.body_cursor = 0;
+        self.script_cursor = 0;
+        self.auth_focused_field = 0;
+        self.settings_focused_field = 0;
         self.current_request_tab = HttpRequestTab::Headers;
         self.current_response_tab = HttpResponseTab::Body;
         self.focused_field = 0;
@@ -311,17 +1223,330 @@ impl HttpState {
             HttpRequestBody::Json { .. } => BodyContentType::Json,
             HttpRequestBody::Text { .. } => BodyContentType::Text,
             HttpRequestBody::Form { .. } => BodyContentType::Form,
+            HttpRequestBody::Multipart { .. } => BodyContentType::Multipart,
             HttpRequestBody::Raw { .. } => BodyContentType::Raw,
+            HttpRequestBody::File { .. } => BodyContentType::File,
         };
     }
 
-    /// Create a new empty request
+    /// Create a new empty request in a tab of its own
     pub fn new_request(&mut self) {
+        self.sync_active_tab();
         self.current_request = HttpRequest::new("New Request");
+        self.current_request_key = format!("draft-{}", self.next_draft_id);
+        self.next_draft_id += 1;
+        self.open_tabs.push(OpenRequestTab::new(
+            &self.current_request_key,
+            &self.current_request,
+        ));
+        self.active_tab = self.open_tabs.len() - 1;
+        self.key_value_editor.reset();
+        self.body_error = None;
+        self.json_builder = None;
+        self.json_builder_error = None;
+        self.body_cursor = 0;
+        self.script_cursor = 0;
+        self.auth_focused_field = 0;
+        self.settings_focused_field = 0;
         self.current_request_tab = HttpRequestTab::Headers;
         self.current_response_tab = HttpResponseTab::Body;
         self.current_body_type = BodyContentType::None;
         self.focused_field = 0;
         self.last_response = None;
+        self.response_generation += 1;
+        self.response_cursor = 0;
+        self.response_search_current = 0;
+        self.response_collapsed.clear();
+    }
+}
+
+/// Zero-based (line, column) of the `char_index`-th character
+fn text_line_col(text: &str, char_index: usize) -> (usize, usize) {
+    let mut line = 0;
+    let mut column = 0;
+    for c in text.chars().take(char_index) {
+        if c == '\n' {
+            line += 1;
+            column = 0;
+        } else {
+            column += 1;
+        }
+    }
+    (line, column)
+}
+
+/// Byte offset of the `char_index`-th character (or the end of the string)
+fn byte_index_for_char(text: &str, char_index: usize) -> usize {
+    text.char_indices()
+        .nth(char_index)
+        .map(|(index, _)| index)
+        .unwrap_or(text.len())
+}
+
+/// Character index for a 1-based line/column pair, clamped to the text
+fn char_index_for_line_col(text: &str, line: usize, column: usize) -> usize {
+    let mut index = 0;
+    for (line_index, line_text) in text.split('\n').enumerate() {
+        let line_len = line_text.chars().count();
+        if line_index + 1 == line {
+            return index + column.saturating_sub(1).min(line_len);
+        }
+        index += line_len + 1;
+    }
+    text.chars().count()
+}
+
+/// serde_json appends " at line X column Y" to its messages; we show that separately
+fn strip_position(message: &str) -> String {
+    match message.rfind(" at line ") {
+        Some(pos) => message[..pos].to_string(),
+        None => message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::http_client::RetryOn;
+    use crate::models::transfer::TransferStats;
+
+    fn response(status_code: u16) -> HttpResponse {
+        HttpResponse {
+            status_code,
+            status_text: String::new(),
+            headers: Vec::new(),
+            body: String::new(),
+            body_bytes: Vec::new(),
+            content_type: String::new(),
+            duration_ms: 0,
+            served_from_cache: false,
+            connection: Default::default(),
+            transfer: TransferStats::default(),
+            assertions: Vec::new(),
+            extracted: Vec::new(),
+            truncation: None,
+            timing: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_response_routed_to_sending_request() {
+        let mut state = HttpState::new();
+        state.load_request("Users/List", HttpRequest::new("List"));
+        let first = state.begin_send();
+
+        // Switch to another request and send it while the first is still in flight
+        state.load_request("Users/Create", HttpRequest::new("Create"));
+        assert!(!state.is_sending());
+        let second = state.begin_send();
+        assert_eq!(state.in_flight_count(), 2);
+
+        state.finish_send(&first, Some(response(200)));
+        assert!(state.last_response.is_none());
+        assert!(state.is_sending());
+
+        state.finish_send(&second, Some(response(201)));
+        assert_eq!(state.last_response.as_ref().unwrap().status_code, 201);
+
+        state.load_request("Users/List", HttpRequest::new("List"));
+        assert_eq!(state.last_response.as_ref().unwrap().status_code, 200);
+        assert_eq!(state.in_flight_count(), 0);
+    }
+
+    #[test]
+    fn test_docs_tab_edits_the_description() {
+        let mut state = HttpState::new();
+        state.current_request_tab = HttpRequestTab::Docs;
+        assert!(state.is_script_editable());
+        assert!(!state.is_url_editable());
+
+        state.set_script_text("## Users".to_string());
+        state.insert_script_char('!');
+        assert_eq!(state.current_request.description, "## Users!");
+        assert!(state.current_request.pre_request_script.is_empty());
+    }
+
+    #[test]
+    fn test_response_goes_stale_after_edits() {
+        let mut state = HttpState::new();
+        state.load_request("Users/List", HttpRequest::new("List").with_url("/users"));
+        let key = state.begin_send();
+        assert_eq!(state.last_sent_key.as_deref(), Some("Users/List"));
+        assert!(state.tab_index("Users/List").is_some());
+        // Edited while the request was on its way
+        state.current_request.url = "/users?page=2".to_string();
+        state.finish_send(&key, Some(response(200)));
+        assert!(state.is_response_stale());
+
+        state.current_request.url = "/users".to_string();
+        assert!(!state.is_response_stale());
+        // Renaming or documenting the request doesn't change what was sent
+        state.current_request.name = "All users".to_string();
+        state.current_request.description = "Lists users".to_string();
+        assert!(!state.is_response_stale());
+
+        state.current_request.method = HttpMethod::POST;
+        assert!(state.is_response_stale());
+        state.clear_response();
+        assert!(!state.is_response_stale());
+    }
+
+    #[test]
+    fn test_json_body_error_moves_cursor() {
+        let mut state = HttpState::new();
+        state.current_request_tab = HttpRequestTab::Body;
+        state.current_body_type = BodyContentType::Json;
+        state.update_body_content("{\n  \"a\": 1,\n  \"b\" 2\n}".to_string());
+
+        assert!(!state.check_json_body(true));
+        let error = state.body_error.clone().unwrap();
+        assert_eq!(error.line, 3);
+        assert_eq!(state.body_cursor_line_col(), (2, error.column - 1));
+
+        state.update_body_content("{\"a\":1}".to_string());
+        assert!(state.check_json_body(true));
+        assert_eq!(state.get_body_content(), "{\n  \"a\": 1\n}");
+        assert!(state.body_error.is_none());
+    }
+
+    #[test]
+    fn test_settings_tab_rows() {
+        let mut state = HttpState::new();
+        state.current_request_tab = HttpRequestTab::Settings;
+        assert_eq!(state.settings_row_count(), REQUEST_SETTINGS_ROWS);
+
+        state.step_focused_setting(2);
+        assert_eq!(state.current_request.settings.timeout_secs, 40);
+        assert!(!state.toggle_focused_setting());
+        assert!(state.is_url_editable());
+
+        state.move_settings_focus(false);
+        assert_eq!(state.settings_focused_field, SETTINGS_ROW_PROXY_PASSWORD);
+        assert!(!state.is_url_editable());
+        state.settings_focused_field = SETTINGS_ROW_USER_AGENT;
+        state
+            .focused_settings_field_mut()
+            .unwrap()
+            .push_str("probe/1");
+        assert_eq!(state.current_request.settings.user_agent, "probe/1");
+
+        state.settings_focused_field = SETTINGS_ROW_CLIENT_CERT;
+        assert!(!state.is_url_editable());
+        state
+            .focused_settings_field_mut()
+            .unwrap()
+            .push_str("client.p12");
+        assert_eq!(state.current_request.settings.tls.client_cert, "client.p12");
+
+        state.settings_focused_field = SETTINGS_ROW_VERIFY_SSL;
+        assert!(state.toggle_focused_setting());
+        assert!(!state.current_request.settings.verify_ssl);
+        state.settings_focused_field = SETTINGS_ROW_USE_CACHE;
+        assert!(state.toggle_focused_setting());
+        assert!(!state.current_request.settings.use_cache);
+
+        state.settings_focused_field = SETTINGS_ROW_RETRY_ATTEMPTS;
+        state.step_focused_setting(3);
+        state.settings_focused_field = SETTINGS_ROW_RETRY_BACKOFF;
+        state.step_focused_setting(-10);
+        state.settings_focused_field = SETTINGS_ROW_RETRY_ON;
+        assert!(state.toggle_focused_setting());
+        let retry = state.current_request.settings.retry;
+        assert_eq!(retry.max_attempts, 3);
+        assert_eq!(retry.backoff_ms, 100);
+        assert_eq!(retry.retry_on, RetryOn::ServerErrors);
+
+        // Pagination fields come after the request settings
+        state.cycle_pagination_rule();
+        state.cycle_pagination_rule();
+        state.settings_focused_field = REQUEST_SETTINGS_ROWS;
+        *state.focused_settings_field_mut().unwrap() = "meta.next".to_string();
+        assert_eq!(state.current_request.pagination.fields()[0].1, "meta.next");
+    }
+
+    #[test]
+    fn test_url_cursor_editing_and_scroll() {
+        let mut state = HttpState::new();
+        state.insert_url_text("https://api.example.com/users?page=2");
+        assert_eq!(state.url_cursor, 36);
+        // A 20-column field scrolls so the cursor sits in its last column
+        assert_eq!(state.url_scroll_offset(20), 17);
+
+        state.move_url_cursor_home();
+        assert_eq!(state.url_scroll_offset(20), 0);
+        for _ in 0..8 {
+            state.move_url_cursor_right();
+        }
+        state.insert_url_char('v');
+        state.insert_url_char('2');
+        state.insert_url_char('.');
+        assert_eq!(
+            state.current_request.url,
+            "https://v2.api.example.com/users?page=2"
+        );
+
+        state.delete_url_char();
+        state.delete_url_char_forward();
+        assert_eq!(
+            state.current_request.url,
+            "https://v2pi.example.com/users?page=2"
+        );
+        state.move_url_cursor_to_end();
+        state.move_url_cursor_right();
+        assert_eq!(state.url_cursor, 37);
+
+        // Typing goes to the URL unless the tab has text of its own
+        assert!(state.is_url_editable());
+        state.current_request_tab = HttpRequestTab::Script;
+        assert!(!state.is_url_editable());
+    }
+
+    #[test]
+    fn test_request_tabs_reorder_rename_and_close() {
+        let mut state = HttpState::new();
+        state.load_request("api/users", HttpRequest::new("Users"));
+        state.load_request("api/orders", HttpRequest::new("Orders"));
+        assert_eq!(state.open_tabs.len(), 3);
+        assert_eq!(state.active_tab, 2);
+
+        // Edits survive switching away and back
+        state.current_request.url = "https://api.example.com/orders".to_string();
+        state.cycle_tab(false);
+        assert_eq!(state.current_request_key, "api/users");
+        state.cycle_tab(true);
+        assert_eq!(state.current_request.url, "https://api.example.com/orders");
+
+        // Loading an open request switches to its tab instead of opening another
+        state.load_request("api/users", HttpRequest::new("Users"));
+        assert_eq!((state.open_tabs.len(), state.active_tab), (3, 1));
+
+        assert!(state.move_active_tab(false));
+        assert!(!state.move_active_tab(false));
+        assert_eq!(state.open_tabs[0].key, "api/users");
+        assert_eq!(state.current_request_key, "api/users");
+
+        state.rename_active_tab("  Users (staging) ");
+        assert_eq!(state.tab_label(0), "Users (staging)");
+        assert_eq!(state.open_tabs[0].request.name, "Users");
+        state.rename_active_tab("");
+        assert_eq!(state.tab_label(0), "Users");
+
+        let closed = state.close_active_tab();
+        assert_eq!(closed.key, "api/users");
+        assert_eq!(state.current_request_key, "draft-0");
+        state.close_active_tab();
+        state.close_active_tab();
+        // Closing the last tab leaves a fresh draft
+        assert_eq!(state.open_tabs.len(), 1);
+        assert!(state.current_request_key.starts_with("draft-"));
+
+        let tabs = vec![
+            OpenRequestTab::new("draft-7", &HttpRequest::new("Scratch")),
+            OpenRequestTab::new("api/users", &HttpRequest::new("Users")),
+        ];
+        state.restore_tabs(tabs, 1);
+        assert_eq!(state.current_request_key, "api/users");
+        state.new_request();
+        assert_eq!(state.current_request_key, "draft-8");
     }
 }
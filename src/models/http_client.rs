@@ -1,5 +1,6 @@
+use crate::models::key_value::KeyValuePair;
+use crate::models::transfer::TransferStats;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 /// HTTP request methods
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -40,7 +41,7 @@ impl HttpMethod {
 }
 
 /// HTTP header key-value pair
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct HttpHeader {
     pub name: String,
     pub value: String,
@@ -62,8 +63,28 @@ impl HttpHeader {
     }
 }
 
+/// Whether `headers` has its own `name` header, enabled or not, so a default is left out
+pub fn overrides_header(headers: &[HttpHeader], name: &str) -> bool {
+    headers
+        .iter()
+        .any(|header| header.name.eq_ignore_ascii_case(name))
+}
+
+/// The request's headers with the enabled defaults it doesn't set itself put first.
+///
+/// A disabled request header of the same name still counts as setting it, which
+/// is how one request opts out of a default.
+pub fn merge_default_headers(defaults: &[HttpHeader], headers: &[HttpHeader]) -> Vec<HttpHeader> {
+    defaults
+        .iter()
+        .filter(|default| default.enabled && !overrides_header(headers, &default.name))
+        .chain(headers)
+        .cloned()
+        .collect()
+}
+
 /// Query parameter key-value pair
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct HttpQueryParam {
     pub name: String,
     pub value: String,
@@ -97,6 +118,15 @@ pub enum HttpRequestBody {
     Raw {
         content: String,
     },
+    /// multipart/form-data with text and file parts
+    Multipart {
+        parts: Vec<HttpMultipartPart>,
+    },
+    /// Raw bytes streamed from a file at send time; an empty content type is guessed from the extension
+    File {
+        path: String,
+        content_type: String,
+    },
 }
 
 /// Form field for form-data requests
@@ -117,6 +147,36 @@ impl HttpFormField {
     }
 }
 
+/// Part of a multipart/form-data body
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HttpMultipartPart {
+    pub name: String,
+    /// Text value, or the path of the file to upload
+    pub value: String,
+    pub enabled: bool,
+    #[serde(default)]
+    pub is_file: bool,
+}
+
+impl HttpMultipartPart {
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            enabled: true,
+            is_file: false,
+        }
+    }
+
+    /// A part uploading the file at `path`
+    pub fn file(name: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            is_file: true,
+            ..Self::new(name, path)
+        }
+    }
+}
+
 /// Authentication types for HTTP requests
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum HttpAuth {
@@ -133,6 +193,158 @@ pub enum HttpAuth {
         value: String,
         location: ApiKeyLocation,
     },
+    /// Headers computed by an external script (for bespoke signing schemes)
+    Script {
+        script_path: String,
+    },
+    /// OAuth 2.0 client credentials grant, for machine-to-machine APIs
+    OAuth2ClientCredentials {
+        token_url: String,
+        client_id: String,
+        client_secret: String,
+        scope: String,
+    },
+    /// OAuth 2.0 authorization code grant (with PKCE); the browser redirects
+    /// back to a listener on `redirect_port`
+    OAuth2AuthorizationCode {
+        auth_url: String,
+        token_url: String,
+        client_id: String,
+        client_secret: String,
+        scope: String,
+        redirect_port: String,
+    },
+}
+
+impl HttpAuth {
+    pub fn title(&self) -> &'static str {
+        match self {
+            HttpAuth::None => "None",
+            HttpAuth::Basic { .. } => "Basic Auth",
+            HttpAuth::Bearer { .. } => "Bearer Token",
+            HttpAuth::ApiKey { .. } => "API Key",
+            HttpAuth::Script { .. } => "Custom (script)",
+            HttpAuth::OAuth2ClientCredentials { .. } => "OAuth 2.0 (client credentials)",
+            HttpAuth::OAuth2AuthorizationCode { .. } => "OAuth 2.0 (authorization code)",
+        }
+    }
+
+    /// The next auth type in the cycle, with empty fields
+    pub fn next_kind(&self) -> HttpAuth {
+        match self {
+            HttpAuth::None => HttpAuth::Bearer {
+                token: String::new(),
+            },
+            HttpAuth::Bearer { .. } => HttpAuth::Basic {
+                username: String::new(),
+                password: String::new(),
+            },
+            HttpAuth::Basic { .. } => HttpAuth::ApiKey {
+                key: String::new(),
+                value: String::new(),
+                location: ApiKeyLocation::Header,
+            },
+            HttpAuth::ApiKey { .. } => HttpAuth::Script {
+                script_path: String::new(),
+            },
+            HttpAuth::Script { .. } => HttpAuth::OAuth2ClientCredentials {
+                token_url: String::new(),
+                client_id: String::new(),
+                client_secret: String::new(),
+                scope: String::new(),
+            },
+            HttpAuth::OAuth2ClientCredentials { .. } => HttpAuth::OAuth2AuthorizationCode {
+                auth_url: String::new(),
+                token_url: String::new(),
+                client_id: String::new(),
+                client_secret: String::new(),
+                scope: String::new(),
+                redirect_port: "8765".to_string(),
+            },
+            HttpAuth::OAuth2AuthorizationCode { .. } => HttpAuth::None,
+        }
+    }
+
+    /// Whether the field labelled `label` in `fields` holds a credential
+    pub fn is_secret_field(label: &str) -> bool {
+        matches!(label, "Password" | "Token" | "Value" | "Client Secret")
+    }
+
+    /// Editable text fields as (label, value), in display order
+    pub fn fields(&self) -> Vec<(&'static str, &str)> {
+        match self {
+            HttpAuth::None => Vec::new(),
+            HttpAuth::Basic { username, password } => {
+                vec![("Username", username), ("Password", password)]
+            }
+            HttpAuth::Bearer { token } => vec![("Token", token)],
+            HttpAuth::ApiKey { key, value, .. } => vec![("Key", key), ("Value", value)],
+            HttpAuth::Script { script_path } => vec![("Script", script_path)],
+            HttpAuth::OAuth2ClientCredentials {
+                token_url,
+                client_id,
+                client_secret,
+                scope,
+            } => vec![
+                ("Token URL", token_url),
+                ("Client ID", client_id),
+                ("Client Secret", client_secret),
+                ("Scope", scope),
+            ],
+            HttpAuth::OAuth2AuthorizationCode {
+                auth_url,
+                token_url,
+                client_id,
+                client_secret,
+                scope,
+                redirect_port,
+            } => vec![
+                ("Auth URL", auth_url),
+                ("Token URL", token_url),
+                ("Client ID", client_id),
+                ("Client Secret", client_secret),
+                ("Scope", scope),
+                ("Redirect Port", redirect_port),
+            ],
+        }
+    }
+
+    /// Mutable access to the `index`-th editable field
+    pub fn field_mut(&mut self, index: usize) -> Option<&mut String> {
+        let mut fields: Vec<&mut String> = match self {
+            HttpAuth::None => Vec::new(),
+            HttpAuth::Basic { username, password } => vec![username, password],
+            HttpAuth::Bearer { token } => vec![token],
+            HttpAuth::ApiKey { key, value, .. } => vec![key, value],
+            HttpAuth::Script { script_path } => vec![script_path],
+            HttpAuth::OAuth2ClientCredentials {
+                token_url,
+                client_id,
+                client_secret,
+                scope,
+            } => vec![token_url, client_id, client_secret, scope],
+            HttpAuth::OAuth2AuthorizationCode {
+                auth_url,
+                token_url,
+                client_id,
+                client_secret,
+                scope,
+                redirect_port,
+            } => vec![
+                auth_url,
+                token_url,
+                client_id,
+                client_secret,
+                scope,
+                redirect_port,
+            ],
+        };
+        if index < fields.len() {
+            Some(fields.swap_remove(index))
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -142,7 +354,7 @@ pub enum ApiKeyLocation {
 }
 
 /// HTTP request model
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct HttpRequest {
     pub name: String,
     pub method: HttpMethod,
@@ -152,9 +364,433 @@ pub struct HttpRequest {
     pub body: HttpRequestBody,
     pub auth: HttpAuth,
     pub description: String,
+    /// How to follow pages when fetching all results of a list endpoint
+    #[serde(default)]
+    pub pagination: PaginationSettings,
+    /// Script run before sending (see `services::pre_request_script`)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub pre_request_script: String,
+    /// Assertions checked once the response arrives (see `services::assertions`)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub tests: String,
+    /// Rules copying response values into variables (see `services::extraction`)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub extract: String,
+    /// Timeout, redirect, TLS and user agent options used when sending
+    #[serde(default, skip_serializing_if = "RequestSettings::is_default")]
+    pub settings: RequestSettings,
+}
+
+/// User-Agent sent when a request doesn't set its own
+pub const DEFAULT_USER_AGENT: &str = concat!("tui_one/", env!("CARGO_PKG_VERSION"));
+
+/// Transport options of one request, honored when building its HTTP client
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RequestSettings {
+    /// Give up after this many seconds; 0 waits forever
+    pub timeout_secs: u64,
+    pub follow_redirects: bool,
+    /// Redirects followed before giving up
+    pub max_redirects: usize,
+    /// Reject invalid or self-signed certificates
+    pub verify_ssl: bool,
+    /// Empty sends `DEFAULT_USER_AGENT`
+    #[serde(default)]
+    pub user_agent: String,
+    /// Certificates for this request; empty fields fall back to the global ones
+    #[serde(default, skip_serializing_if = "TlsSettings::is_empty")]
+    pub tls: TlsSettings,
+    /// Proxy for this request; empty uses the environment's or the global one
+    #[serde(default, skip_serializing_if = "ProxySettings::is_empty")]
+    pub proxy: ProxySettings,
+    /// Retries for this request; zero attempts uses the global policy
+    #[serde(default, skip_serializing_if = "RetryPolicy::is_default")]
+    pub retry: RetryPolicy,
+    /// Keep GET/HEAD responses with an ETag or Last-Modified and revalidate them
+    /// with `If-None-Match`/`If-Modified-Since` on the next send
+    #[serde(default = "default_use_cache")]
+    pub use_cache: bool,
+}
+
+fn default_use_cache() -> bool {
+    true
+}
+
+impl Default for RequestSettings {
+    fn default() -> Self {
+        Self {
+            timeout_secs: 30,
+            follow_redirects: true,
+            max_redirects: 10,
+            verify_ssl: true,
+            user_agent: String::new(),
+            tls: TlsSettings::default(),
+            proxy: ProxySettings::default(),
+            retry: RetryPolicy::default(),
+            use_cache: true,
+        }
+    }
+}
+
+impl RequestSettings {
+    pub fn is_default(&self) -> bool {
+        self == &RequestSettings::default()
+    }
+
+    pub fn effective_user_agent(&self) -> &str {
+        if self.user_agent.trim().is_empty() {
+            DEFAULT_USER_AGENT
+        } else {
+            &self.user_agent
+        }
+    }
+}
+
+/// Custom CA and client certificate (mTLS) files, for services behind a private PKI
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct TlsSettings {
+    /// PEM file of extra CA certificates trusted alongside the system roots
+    pub ca_bundle: String,
+    /// Client certificate: a PEM file, or a PKCS#12 bundle (.p12 / .pfx)
+    pub client_cert: String,
+    /// PEM private key; empty when `client_cert` holds the key as well
+    pub client_key: String,
+    /// Password of a PKCS#12 bundle
+    pub client_cert_password: String,
+}
+
+impl TlsSettings {
+    pub fn is_empty(&self) -> bool {
+        self == &TlsSettings::default()
+    }
+
+    /// These settings with blanks filled from `global`; the client certificate,
+    /// key and password are taken together so they never come from two sources
+    pub fn or(&self, global: &TlsSettings) -> TlsSettings {
+        let ca_bundle = if self.ca_bundle.trim().is_empty() {
+            global.ca_bundle.clone()
+        } else {
+            self.ca_bundle.clone()
+        };
+        let identity = if self.client_cert.trim().is_empty() {
+            global
+        } else {
+            self
+        };
+        TlsSettings {
+            ca_bundle,
+            client_cert: identity.client_cert.clone(),
+            client_key: identity.client_key.clone(),
+            client_cert_password: identity.client_cert_password.clone(),
+        }
+    }
+}
+
+/// Proxy URL that sends a request directly, ignoring any environment or global proxy
+pub const DIRECT_PROXY: &str = "direct";
+
+/// HTTP(S) or SOCKS proxy requests are sent through
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ProxySettings {
+    /// `http://`, `https://`, `socks5://` or `socks5h://` URL; `direct` for no proxy
+    pub url: String,
+    pub username: String,
+    pub password: String,
+    /// Comma-separated hosts/domains reached without the proxy
+    pub no_proxy: String,
+}
+
+impl ProxySettings {
+    pub fn is_empty(&self) -> bool {
+        self.url.trim().is_empty()
+    }
+
+    pub fn is_direct(&self) -> bool {
+        self.url.trim().eq_ignore_ascii_case(DIRECT_PROXY)
+    }
+
+    /// These settings, or `fallback` when no proxy URL is set here
+    pub fn or(&self, fallback: &ProxySettings) -> ProxySettings {
+        if self.is_empty() {
+            fallback.clone()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// The URL with any password it carries masked, for display
+    pub fn display_url(&self) -> String {
+        let url = self.url.trim();
+        let (scheme, rest) = match url.split_once("://") {
+            Some((scheme, rest)) => (format!("{}://", scheme), rest),
+            None => (String::new(), url),
+        };
+        match rest.split_once('@') {
+            Some((userinfo, host)) if userinfo.contains(':') => {
+                let user = userinfo.split(':').next().unwrap_or_default();
+                format!("{}{}:••••@{}", scheme, user, host)
+            }
+            _ => url.to_string(),
+        }
+    }
+}
+
+/// Failures worth another attempt
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum RetryOn {
+    #[default]
+    ServerAndConnectionErrors,
+    ServerErrors,
+    ConnectionErrors,
+}
+
+impl RetryOn {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RetryOn::ServerAndConnectionErrors => "5xx and connection errors",
+            RetryOn::ServerErrors => "5xx only",
+            RetryOn::ConnectionErrors => "Connection errors only",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            RetryOn::ServerAndConnectionErrors => RetryOn::ServerErrors,
+            RetryOn::ServerErrors => RetryOn::ConnectionErrors,
+            RetryOn::ConnectionErrors => RetryOn::ServerAndConnectionErrors,
+        }
+    }
+}
+
+/// Resend a failed request, waiting twice as long before each new attempt
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct RetryPolicy {
+    /// Attempts in total, so 1 never retries; 0 on a request defers to the global policy
+    pub max_attempts: u32,
+    /// Wait before the first retry
+    pub backoff_ms: u64,
+    pub retry_on: RetryOn,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 0,
+            backoff_ms: 500,
+            retry_on: RetryOn::default(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Longest wait between two attempts, however many retries came before
+    const MAX_BACKOFF_MS: u64 = 30_000;
+
+    /// Attempt counts and first waits offered in the settings dialog
+    const PRESETS: [(u32, u64); 4] = [(0, 500), (3, 500), (5, 1000), (10, 2000)];
+
+    pub fn is_default(&self) -> bool {
+        self == &RetryPolicy::default()
+    }
+
+    /// This policy, or `fallback` when it doesn't set a number of attempts
+    pub fn or(&self, fallback: &RetryPolicy) -> RetryPolicy {
+        if self.max_attempts == 0 {
+            *fallback
+        } else {
+            *self
+        }
+    }
+
+    /// Wait after `attempt` (1-based) failed: the backoff, doubled for each earlier retry
+    pub fn delay_after(&self, attempt: u32) -> std::time::Duration {
+        let factor = 1u64 << attempt.saturating_sub(1).min(16);
+        std::time::Duration::from_millis(
+            self.backoff_ms
+                .saturating_mul(factor)
+                .min(Self::MAX_BACKOFF_MS),
+        )
+    }
+
+    pub fn retries_status(&self, status_code: u16) -> bool {
+        self.retry_on != RetryOn::ConnectionErrors && (500..600).contains(&status_code)
+    }
+
+    pub fn retries_connection_errors(&self) -> bool {
+        self.retry_on != RetryOn::ServerErrors
+    }
+
+    pub fn label(&self) -> String {
+        if self.max_attempts <= 1 {
+            "Off".to_string()
+        } else {
+            format!(
+                "{} attempts, {} ms backoff ({})",
+                self.max_attempts,
+                self.backoff_ms,
+                self.retry_on.label()
+            )
+        }
+    }
+
+    /// The next global preset (custom values from the config file restart the cycle)
+    pub fn next(&self) -> Self {
+        let position = Self::PRESETS.iter().position(|(attempts, backoff)| {
+            *attempts == self.max_attempts && *backoff == self.backoff_ms
+        });
+        let (max_attempts, backoff_ms) = match position {
+            Some(index) => Self::PRESETS[(index + 1) % Self::PRESETS.len()],
+            None => Self::PRESETS[0],
+        };
+        Self {
+            max_attempts,
+            backoff_ms,
+            retry_on: self.retry_on,
+        }
+    }
+}
+
+/// How to find the next page of a list endpoint
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub enum PaginationRule {
+    #[default]
+    None,
+    /// Follow the `rel="next"` URL of the `Link` response header
+    LinkHeader,
+    /// Follow a next-page URL read from the response body (`next_page_url`)
+    NextUrlField { next_path: String },
+    /// Read a cursor from the response body and send it back as a query parameter
+    CursorField {
+        cursor_path: String,
+        cursor_param: String,
+    },
+    /// Increment a page-number query parameter until a page comes back empty
+    PageParam { page_param: String, start_page: u32 },
+}
+
+impl PaginationRule {
+    pub fn title(&self) -> &'static str {
+        match self {
+            PaginationRule::None => "None",
+            PaginationRule::LinkHeader => "Link header",
+            PaginationRule::NextUrlField { .. } => "JSON next URL",
+            PaginationRule::CursorField { .. } => "JSON cursor field",
+            PaginationRule::PageParam { .. } => "Page parameter",
+        }
+    }
+
+    /// The next rule in the cycle, with sensible default names
+    pub fn next_kind(&self) -> PaginationRule {
+        match self {
+            PaginationRule::None => PaginationRule::LinkHeader,
+            PaginationRule::LinkHeader => PaginationRule::NextUrlField {
+                next_path: "next_page_url".to_string(),
+            },
+            PaginationRule::NextUrlField { .. } => PaginationRule::CursorField {
+                cursor_path: "next_cursor".to_string(),
+                cursor_param: "cursor".to_string(),
+            },
+            PaginationRule::CursorField { .. } => PaginationRule::PageParam {
+                page_param: "page".to_string(),
+                start_page: 1,
+            },
+            PaginationRule::PageParam { .. } => PaginationRule::None,
+        }
+    }
+}
+
+/// Pagination settings stored with a request
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PaginationSettings {
+    pub rule: PaginationRule,
+    /// Dotted path to the list inside each page (empty = the whole body)
+    #[serde(default)]
+    pub items_path: String,
+    /// Stop after this many pages
+    pub max_pages: usize,
+    /// Fetch every page whenever the request is sent, not only with Ctrl+P
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub auto_follow: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+impl Default for PaginationSettings {
+    fn default() -> Self {
+        Self {
+            rule: PaginationRule::None,
+            items_path: String::new(),
+            max_pages: 10,
+            auto_follow: false,
+        }
+    }
+}
+
+impl PaginationSettings {
+    /// Editable text fields as (label, value), in display order
+    pub fn fields(&self) -> Vec<(&'static str, &str)> {
+        let mut fields = Vec::new();
+        match &self.rule {
+            PaginationRule::None => return fields,
+            PaginationRule::LinkHeader => {}
+            PaginationRule::NextUrlField { next_path } => {
+                fields.push(("Next URL field", next_path.as_str()));
+            }
+            PaginationRule::CursorField {
+                cursor_path,
+                cursor_param,
+            } => {
+                fields.push(("Cursor field", cursor_path.as_str()));
+                fields.push(("Cursor param", cursor_param.as_str()));
+            }
+            PaginationRule::PageParam { page_param, .. } => {
+                fields.push(("Page param", page_param.as_str()));
+            }
+        }
+        fields.push(("Items path", self.items_path.as_str()));
+        fields
+    }
+
+    /// Mutable access to the `index`-th editable field
+    pub fn field_mut(&mut self, index: usize) -> Option<&mut String> {
+        let mut fields: Vec<&mut String> = match &mut self.rule {
+            PaginationRule::None => return None,
+            PaginationRule::LinkHeader => Vec::new(),
+            PaginationRule::NextUrlField { next_path } => vec![next_path],
+            PaginationRule::CursorField {
+                cursor_path,
+                cursor_param,
+            } => vec![cursor_path, cursor_param],
+            PaginationRule::PageParam { page_param, .. } => vec![page_param],
+        };
+        fields.push(&mut self.items_path);
+        if index < fields.len() {
+            Some(fields.swap_remove(index))
+        } else {
+            None
+        }
+    }
 }
 
 impl HttpRequest {
+    /// Whether sending `other` sends the same thing; the name, notes, tests, extraction
+    /// and pagination rules don't change what goes over the wire
+    pub fn sends_same_as(&self, other: &HttpRequest) -> bool {
+        self.method == other.method
+            && self.url == other.url
+            && self.headers == other.headers
+            && self.query_params == other.query_params
+            && self.body == other.body
+            && self.auth == other.auth
+            && self.pre_request_script == other.pre_request_script
+            && self.settings == other.settings
+    }
+
     pub fn new(name: impl Into<String>) -> Self {
         Self {
             name: name.into(),
@@ -165,6 +801,11 @@ impl HttpRequest {
             body: HttpRequestBody::None,
             auth: HttpAuth::None,
             description: String::new(),
+            pagination: PaginationSettings::default(),
+            pre_request_script: String::new(),
+            tests: String::new(),
+            extract: String::new(),
+            settings: RequestSettings::default(),
         }
     }
 
@@ -182,20 +823,210 @@ impl HttpRequest {
         self.headers.push(HttpHeader::new(name, value));
         self
     }
+
+    /// Set a query parameter, replacing (and re-enabling) an existing one with the same name
+    pub fn set_query_param(&mut self, name: &str, value: impl Into<String>) {
+        let value = value.into();
+        match self.query_params.iter_mut().find(|p| p.name == name) {
+            Some(param) => {
+                param.value = value;
+                param.enabled = true;
+            }
+            None => self.query_params.push(HttpQueryParam::new(name, value)),
+        }
+    }
 }
 
 /// HTTP response model
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HttpResponse {
     pub status_code: u16,
     pub status_text: String,
     pub headers: Vec<HttpHeader>,
     pub body: String,
+    /// Raw bytes of a binary body (shown as a hex dump); `body` only holds them
+    /// lossily decoded. Empty for text bodies.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub body_bytes: Vec<u8>,
     pub content_type: String,
     pub duration_ms: u64,
+    /// Body and headers come from the local cache after a 304 Not Modified
+    #[serde(default)]
+    pub served_from_cache: bool,
+    /// Bytes sent and received for this exchange
+    #[serde(default)]
+    pub transfer: TransferStats,
+    /// Proxy, protocol and certificate of the connection
+    #[serde(default)]
+    pub connection: ConnectionInfo,
+    /// Results of the request's assertions, in the order they were written
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assertions: Vec<AssertionResult>,
+    /// Variables captured by the request's extraction rules
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extracted: Vec<KeyValuePair>,
+    /// Set when `body` holds only the start of a body too large to keep in memory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub truncation: Option<BodyTruncation>,
+    /// Where the time went, phase by phase
+    #[serde(default)]
+    pub timing: TimingBreakdown,
+}
+
+/// Phases of one exchange, in the order they happen
+///
+/// reqwest opens the TCP connection and runs the TLS handshake in one connector call,
+/// so for HTTPS the two are timed together as `connect_ms`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimingBreakdown {
+    /// Building the client: certificates, proxy and the request body
+    pub prepare_ms: u64,
+    /// Host name lookups; `None` when nothing was resolved (IP literal, SOCKS proxy resolving)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dns_ms: Option<u64>,
+    /// TCP connect, plus the TLS handshake when `tls_handshake` is set; `None` when no
+    /// connection was opened
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connect_ms: Option<u64>,
+    /// Set for HTTPS, whose handshake is part of `connect_ms`
+    #[serde(default)]
+    pub tls_handshake: bool,
+    /// Sending the request and the server's time until the response headers arrived
+    pub waiting_ms: u64,
+    /// Reading the body
+    pub download_ms: u64,
+}
+
+impl TimingBreakdown {
+    /// Labeled phases for display; DNS and connect only when they happened
+    pub fn phases(&self) -> Vec<(&'static str, u64)> {
+        let mut phases = vec![("Prepare", self.prepare_ms)];
+        if let Some(dns_ms) = self.dns_ms {
+            phases.push(("DNS", dns_ms));
+        }
+        if let Some(connect_ms) = self.connect_ms {
+            let label = if self.tls_handshake {
+                "Connect + TLS"
+            } else {
+                "Connect"
+            };
+            phases.push((label, connect_ms));
+        }
+        phases.push(("Waiting", self.waiting_ms));
+        phases.push(("Download", self.download_ms));
+        phases
+    }
+
+    pub fn total_ms(&self) -> u64 {
+        self.phases().iter().map(|(_, ms)| ms).sum()
+    }
+
+    /// Whether anything was measured (cached and older history entries have no breakdown)
+    pub fn is_empty(&self) -> bool {
+        self.total_ms() == 0
+    }
+}
+
+/// Where the full body of a truncated response went
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BodyTruncation {
+    /// Size of the whole decoded body
+    pub total_bytes: u64,
+    /// How much of it `body` holds (the display limit at the time)
+    #[serde(default)]
+    pub kept_bytes: u64,
+    /// Spool file holding the whole body; `None` in safe mode, where nothing is spooled
+    #[serde(default)]
+    pub full_body_path: Option<std::path::PathBuf>,
+}
+
+impl BodyTruncation {
+    /// "truncated at 10240 KB (123.4 MB total)"
+    pub fn label(&self) -> String {
+        format!(
+            "truncated at {} KB ({:.1} MB total)",
+            self.kept_bytes / 1024,
+            self.total_bytes as f64 / (1024.0 * 1024.0)
+        )
+    }
+
+    /// The spool file, or an error saying why the whole body wasn't kept
+    pub fn spool_path(&self) -> std::io::Result<&std::path::Path> {
+        self.full_body_path.as_deref().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "the full body isn't kept in safe mode",
+            )
+        })
+    }
+}
+
+/// How a response reached us, for debugging gateways and proxies
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConnectionInfo {
+    /// Proxy the request went through (password masked); `None` when it went direct
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    /// The configured proxy was skipped for this one send
+    #[serde(default)]
+    pub proxy_bypassed: bool,
+    /// Negotiated HTTP version ("HTTP/1.1", "HTTP/2.0"); over TLS, ALPN picks it
+    #[serde(default)]
+    pub http_version: String,
+    /// SHA-256 fingerprint of the server's certificate, for https
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificate_sha256: Option<String>,
+}
+
+impl ConnectionInfo {
+    /// "direct", "direct (proxy bypassed)" or the proxy URL
+    pub fn proxy_label(&self) -> String {
+        match (&self.proxy, self.proxy_bypassed) {
+            (Some(proxy), _) => proxy.clone(),
+            (None, true) => "direct (proxy bypassed for this send)".to_string(),
+            (None, false) => "direct".to_string(),
+        }
+    }
+}
+
+/// Outcome of one post-response assertion (see `services::assertions`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AssertionResult {
+    /// The assertion as written
+    pub assertion: String,
+    pub passed: bool,
+    /// What was found, or why the assertion could not be checked
+    pub message: String,
 }
 
 impl HttpResponse {
+    /// The whole body, read back from the spool file when only its start is in memory.
+    /// Anything that leaves the app (exports, reports) goes through this, never `body`.
+    pub fn full_body(&self) -> std::io::Result<String> {
+        match &self.truncation {
+            Some(truncation) => std::fs::read(truncation.spool_path()?)
+                .map(|bytes| String::from_utf8_lossy(&bytes).into_owned()),
+            None => Ok(self.body.clone()),
+        }
+    }
+
+    /// Size of the whole body, not just the part kept for display
+    pub fn full_body_len(&self) -> usize {
+        self.truncation
+            .as_ref()
+            .map_or(self.body.len(), |truncation| {
+                truncation.total_bytes as usize
+            })
+    }
+
+    /// Look up a response header by name (case-insensitive)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|h| h.name.eq_ignore_ascii_case(name))
+            .map(|h| h.value.as_str())
+    }
+
     pub fn is_success(&self) -> bool {
         self.status_code >= 200 && self.status_code < 300
     }
@@ -243,3 +1074,77 @@ impl HttpCollection {
         self.folders.push(folder);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_headers_yield_to_request_headers() {
+        let defaults = [
+            HttpHeader::new("User-Agent", "tui_one"),
+            HttpHeader::new("Accept", "application/json"),
+            HttpHeader::new("X-Trace", "on"),
+            HttpHeader::new("X-Off", "1").with_enabled(false),
+        ];
+        let headers = [
+            HttpHeader::new("accept", "text/csv"),
+            HttpHeader::new("X-Trace", "on").with_enabled(false),
+        ];
+        assert_eq!(
+            merge_default_headers(&defaults, &headers),
+            [
+                HttpHeader::new("User-Agent", "tui_one"),
+                HttpHeader::new("accept", "text/csv"),
+                HttpHeader::new("X-Trace", "on").with_enabled(false),
+            ]
+        );
+        assert_eq!(merge_default_headers(&[], &headers), headers);
+    }
+
+    #[test]
+    fn test_truncated_response_exposes_full_body() {
+        let temp = tempfile::tempdir().unwrap();
+        let full_body_path = temp.path().join("body.spool");
+        let full = format!("[{}]", "1,".repeat(3000) + "1");
+        std::fs::write(&full_body_path, &full).unwrap();
+
+        let mut response = HttpResponse {
+            status_code: 200,
+            status_text: "OK".to_string(),
+            headers: Vec::new(),
+            body: full[..2048].to_string(),
+            body_bytes: Vec::new(),
+            content_type: "application/json".to_string(),
+            duration_ms: 5,
+            served_from_cache: false,
+            connection: Default::default(),
+            transfer: Default::default(),
+            assertions: Vec::new(),
+            extracted: Vec::new(),
+            truncation: Some(BodyTruncation {
+                total_bytes: full.len() as u64,
+                kept_bytes: 2048,
+                full_body_path: Some(full_body_path.clone()),
+            }),
+            timing: Default::default(),
+        };
+        assert_eq!(response.full_body().unwrap(), full);
+        assert_eq!(response.full_body_len(), full.len());
+        assert!(
+            response
+                .truncation
+                .as_ref()
+                .unwrap()
+                .label()
+                .starts_with("truncated at 2 KB")
+        );
+
+        // Once the spool file is gone the partial body is never offered in its place
+        std::fs::remove_file(&full_body_path).unwrap();
+        assert!(response.full_body().is_err());
+
+        response.truncation = None;
+        assert_eq!(response.full_body().unwrap(), response.body);
+    }
+}
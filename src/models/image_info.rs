@@ -0,0 +1,148 @@
+/// Image formats whose dimensions can be read from their headers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    Gif,
+    WebP,
+}
+
+impl ImageFormat {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ImageFormat::Png => "PNG",
+            ImageFormat::Jpeg => "JPEG",
+            ImageFormat::Gif => "GIF",
+            ImageFormat::WebP => "WebP",
+        }
+    }
+}
+
+/// Format and pixel size of an image body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageInfo {
+    pub format: ImageFormat,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Read the format and dimensions from the start of an image; `None` if the bytes
+/// aren't a PNG, JPEG, GIF or WebP image (or are cut off before the size)
+pub fn image_info(bytes: &[u8]) -> Option<ImageInfo> {
+    let (format, (width, height)) = if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        (ImageFormat::Png, (be32(bytes, 16)?, be32(bytes, 20)?))
+    } else if bytes.starts_with(&[0xff, 0xd8]) {
+        (ImageFormat::Jpeg, jpeg_size(bytes)?)
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        (
+            ImageFormat::Gif,
+            (le16(bytes, 6)? as u32, le16(bytes, 8)? as u32),
+        )
+    } else if bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP") {
+        (ImageFormat::WebP, webp_size(bytes)?)
+    } else {
+        return None;
+    };
+    Some(ImageInfo {
+        format,
+        width,
+        height,
+    })
+}
+
+/// Walk the JPEG segments to the first start-of-frame marker, which holds the size
+fn jpeg_size(bytes: &[u8]) -> Option<(u32, u32)> {
+    let mut index = 2;
+    loop {
+        if *bytes.get(index)? != 0xff {
+            return None;
+        }
+        let marker = *bytes.get(index + 1)?;
+        match marker {
+            // Fill bytes before a marker
+            0xff => index += 1,
+            // Markers without a length
+            0x01 | 0xd0..=0xd9 => index += 2,
+            // SOF0-SOF15, except DHT, JPG and DAC which share the range
+            0xc0..=0xcf if !matches!(marker, 0xc4 | 0xc8 | 0xcc) => {
+                let height = be16(bytes, index + 5)? as u32;
+                let width = be16(bytes, index + 7)? as u32;
+                return Some((width, height));
+            }
+            _ => index += 2 + be16(bytes, index + 2)? as usize,
+        }
+    }
+}
+
+/// Lossy (`VP8 `), lossless (`VP8L`) and extended (`VP8X`) WebP headers
+fn webp_size(bytes: &[u8]) -> Option<(u32, u32)> {
+    match bytes.get(12..16)? {
+        b"VP8X" => Some((le24(bytes, 24)? + 1, le24(bytes, 27)? + 1)),
+        b"VP8 " => Some((
+            (le16(bytes, 26)? & 0x3fff) as u32,
+            (le16(bytes, 28)? & 0x3fff) as u32,
+        )),
+        b"VP8L" => {
+            let bits = u32::from_le_bytes(bytes.get(21..25)?.try_into().ok()?);
+            Some(((bits & 0x3fff) + 1, ((bits >> 14) & 0x3fff) + 1))
+        }
+        _ => None,
+    }
+}
+
+fn be16(bytes: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(bytes.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn be32(bytes: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(bytes.get(at..at + 4)?.try_into().ok()?))
+}
+
+fn le16(bytes: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(bytes.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn le24(bytes: &[u8], at: usize) -> Option<u32> {
+    let b = bytes.get(at..at + 3)?;
+    Some(u32::from(b[0]) | u32::from(b[1]) << 8 | u32::from(b[2]) << 16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_info_reads_dimensions_from_headers() {
+        let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec();
+        png.extend_from_slice(&640u32.to_be_bytes());
+        png.extend_from_slice(&480u32.to_be_bytes());
+        assert_eq!(
+            image_info(&png),
+            Some(ImageInfo {
+                format: ImageFormat::Png,
+                width: 640,
+                height: 480
+            })
+        );
+
+        // SOI, an APP0 segment to skip, then SOF0 with height 200 and width 300
+        let jpeg = [
+            0xff, 0xd8, 0xff, 0xe0, 0x00, 0x04, 0x4a, 0x46, 0xff, 0xc0, 0x00, 0x11, 0x08, 0x00,
+            0xc8, 0x01, 0x2c,
+        ];
+        let info = image_info(&jpeg).unwrap();
+        assert_eq!(
+            (info.format, info.width, info.height),
+            (ImageFormat::Jpeg, 300, 200)
+        );
+
+        let gif = b"GIF89a\x10\x00\x20\x00";
+        assert_eq!(
+            image_info(gif).map(|info| (info.width, info.height)),
+            Some((16, 32))
+        );
+
+        assert_eq!(image_info(b"\x89PNG\r\n\x1a\n"), None);
+        assert_eq!(image_info(b"{\"not\": \"an image\"}"), None);
+    }
+}
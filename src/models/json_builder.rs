@@ -0,0 +1,388 @@
+use anyhow::{Result, anyhow, bail};
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_json::Value;
+use std::fmt;
+
+use crate::models::key_value::KeyValuePair;
+
+/// A JSON document that keeps object keys in the order they were written
+/// (`serde_json::Value` sorts them)
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Leaf(Value),
+    Object(Vec<(String, Node)>),
+    Array(Vec<Node>),
+}
+
+impl<'de> Deserialize<'de> for Node {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(NodeVisitor)
+    }
+}
+
+struct NodeVisitor;
+
+impl<'de> Visitor<'de> for NodeVisitor {
+    type Value = Node;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any JSON value")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Node, E> {
+        Ok(Node::Leaf(Value::Bool(value)))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Node, E> {
+        Ok(Node::Leaf(Value::from(value)))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Node, E> {
+        Ok(Node::Leaf(Value::from(value)))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Node, E> {
+        Ok(Node::Leaf(Value::from(value)))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Node, E> {
+        Ok(Node::Leaf(Value::String(value.to_string())))
+    }
+
+    fn visit_unit<E>(self) -> Result<Node, E> {
+        Ok(Node::Leaf(Value::Null))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Node, A::Error> {
+        let mut items = Vec::new();
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Node::Array(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Node, A::Error> {
+        let mut members: Vec<(String, Node)> = Vec::new();
+        while let Some((key, value)) = map.next_entry::<String, Node>()? {
+            // A repeated key keeps its first position and its last value, like JSON.parse
+            match members.iter_mut().find(|(existing, _)| *existing == key) {
+                Some(member) => member.1 = value,
+                None => members.push((key, value)),
+            }
+        }
+        Ok(Node::Object(members))
+    }
+}
+
+/// One step of a row path
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// Flatten a JSON body into builder rows: one `path = value` row per leaf
+///
+/// Empty bodies give no rows. The root has to be an object or an array.
+pub fn json_to_rows(content: &str) -> Result<Vec<KeyValuePair>> {
+    if content.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let node: Node =
+        serde_json::from_str(content).map_err(|e| anyhow!("Body is not valid JSON: {}", e))?;
+    if let Node::Leaf(_) = node {
+        bail!("The builder needs an object or array at the top level");
+    }
+
+    let mut rows = Vec::new();
+    flatten(&node, String::new(), &mut rows);
+    Ok(rows)
+}
+
+fn flatten(node: &Node, path: String, rows: &mut Vec<KeyValuePair>) {
+    match node {
+        Node::Object(members) if !members.is_empty() => {
+            for (key, child) in members {
+                flatten(child, key_path(&path, key), rows);
+            }
+        }
+        Node::Array(items) if !items.is_empty() => {
+            for (index, child) in items.iter().enumerate() {
+                flatten(child, format!("{}[{}]", path, index), rows);
+            }
+        }
+        // The empty top-level container is implied by having no rows
+        _ if path.is_empty() => {}
+        Node::Object(_) => rows.push(KeyValuePair::new(path, "{}")),
+        Node::Array(_) => rows.push(KeyValuePair::new(path, "[]")),
+        Node::Leaf(value) => rows.push(KeyValuePair::new(path, display_value(value))),
+    }
+}
+
+/// `path.key`, or `path["key"]` when the key isn't a plain name
+fn key_path(path: &str, key: &str) -> String {
+    let plain = key.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && key
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-');
+    match (plain, path.is_empty()) {
+        (true, true) => key.to_string(),
+        (true, false) => format!("{}.{}", path, key),
+        (false, _) => format!("{}[{}]", path, Value::String(key.to_string())),
+    }
+}
+
+/// How a leaf is shown in the value column: strings bare, unless they would
+/// read back as another JSON value
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(text) if serde_json::from_str::<Value>(text).is_err() => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Build the JSON body from the enabled builder rows
+///
+/// Values are JSON literals (`42`, `true`, `null`, `"42"`, `[]`, `{"a": 1}`);
+/// anything else is taken as a string. No rows gives `{}`.
+pub fn rows_to_json(rows: &[KeyValuePair]) -> Result<String> {
+    let mut root: Option<Node> = None;
+    for (index, row) in rows.iter().enumerate().filter(|(_, row)| row.enabled) {
+        let segments = parse_path(&row.name)
+            .map_err(|e| anyhow!("Row {} ({}): {}", index + 1, row.name, e))?;
+        let value = serde_json::from_str::<Node>(&row.value)
+            .unwrap_or_else(|_| Node::Leaf(Value::String(row.value.clone())));
+
+        let root = root.get_or_insert_with(|| container_for(&segments[0]));
+        insert(root, &segments, value)
+            .map_err(|e| anyhow!("Row {} ({}): {}", index + 1, row.name, e))?;
+    }
+
+    let mut output = String::new();
+    write_node(&root.unwrap_or(Node::Object(Vec::new())), 0, &mut output);
+    Ok(output)
+}
+
+/// Parse `user.name`, `tags[0]`, `["content-type"]` or `[0].id` into segments
+fn parse_path(path: &str) -> Result<Vec<Segment>> {
+    let mut segments = Vec::new();
+    let mut rest = path.trim();
+    if rest.is_empty() {
+        bail!("no key");
+    }
+
+    while !rest.is_empty() {
+        if let Some(inner) = rest.strip_prefix('[') {
+            if inner.starts_with('"') {
+                let end = closing_quote(inner).ok_or_else(|| anyhow!("unclosed quoted key"))?;
+                let key: String = serde_json::from_str(&inner[..=end])
+                    .map_err(|_| anyhow!("invalid quoted key"))?;
+                rest = inner[end + 1..]
+                    .strip_prefix(']')
+                    .ok_or_else(|| anyhow!("expected ] after quoted key"))?;
+                segments.push(Segment::Key(key));
+            } else {
+                let end = inner.find(']').ok_or_else(|| anyhow!("unclosed ["))?;
+                let index = inner[..end]
+                    .trim()
+                    .parse()
+                    .map_err(|_| anyhow!("'{}' is not an array index", &inner[..end]))?;
+                segments.push(Segment::Index(index));
+                rest = &inner[end + 1..];
+            }
+        } else {
+            // A dot separates keys; the very first key has none
+            let key_start = match rest.strip_prefix('.') {
+                Some(after) => after,
+                None if segments.is_empty() => rest,
+                None => bail!("expected . or [ before '{}'", rest),
+            };
+            let end = key_start.find(['.', '[']).unwrap_or(key_start.len());
+            if end == 0 {
+                bail!("empty key");
+            }
+            segments.push(Segment::Key(key_start[..end].to_string()));
+            rest = &key_start[end..];
+        }
+    }
+
+    Ok(segments)
+}
+
+/// Byte index of the quote closing the JSON string starting at `text[0]`
+fn closing_quote(text: &str) -> Option<usize> {
+    let mut escaped = false;
+    for (index, c) in text.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn container_for(segment: &Segment) -> Node {
+    match segment {
+        Segment::Key(_) => Node::Object(Vec::new()),
+        Segment::Index(_) => Node::Array(Vec::new()),
+    }
+}
+
+fn insert(node: &mut Node, segments: &[Segment], value: Node) -> Result<()> {
+    let (segment, rest) = segments.split_first().expect("paths are never empty");
+    let slot = match (node, segment) {
+        (Node::Object(members), Segment::Key(key)) => {
+            let position = match members.iter().position(|(existing, _)| existing == key) {
+                Some(_) if rest.is_empty() => bail!("'{}' is set twice", key),
+                Some(position) => position,
+                None => {
+                    members.push((key.clone(), container_or(rest, &value)));
+                    members.len() - 1
+                }
+            };
+            &mut members[position].1
+        }
+        (Node::Array(items), Segment::Index(index)) => {
+            if *index > items.len() {
+                bail!("[{}] skips past [{}]", index, items.len());
+            }
+            if *index == items.len() {
+                items.push(container_or(rest, &value));
+            } else if rest.is_empty() {
+                bail!("[{}] is set twice", index);
+            }
+            &mut items[*index]
+        }
+        (Node::Object(_), Segment::Index(index)) => {
+            bail!("[{}] indexes an object", index)
+        }
+        (Node::Array(_), Segment::Key(key)) => bail!("'{}' is a key inside an array", key),
+        (Node::Leaf(_), _) => bail!("a parent is already set to a plain value"),
+    };
+
+    if rest.is_empty() {
+        *slot = value;
+        Ok(())
+    } else {
+        insert(slot, rest, value)
+    }
+}
+
+/// The container the next segment needs, or the value itself at the end of the path
+fn container_or(rest: &[Segment], value: &Node) -> Node {
+    match rest.first() {
+        Some(segment) => container_for(segment),
+        None => value.clone(),
+    }
+}
+
+/// Pretty-print with two-space indentation, matching `serde_json::to_string_pretty`
+fn write_node(node: &Node, depth: usize, output: &mut String) {
+    let indent = "  ".repeat(depth + 1);
+    match node {
+        Node::Leaf(value) => output.push_str(&value.to_string()),
+        Node::Object(members) if members.is_empty() => output.push_str("{}"),
+        Node::Array(items) if items.is_empty() => output.push_str("[]"),
+        Node::Object(members) => {
+            output.push_str("{\n");
+            for (index, (key, child)) in members.iter().enumerate() {
+                if index > 0 {
+                    output.push_str(",\n");
+                }
+                output.push_str(&indent);
+                output.push_str(&Value::String(key.clone()).to_string());
+                output.push_str(": ");
+                write_node(child, depth + 1, output);
+            }
+            output.push('\n');
+            output.push_str(&"  ".repeat(depth));
+            output.push('}');
+        }
+        Node::Array(items) => {
+            output.push_str("[\n");
+            for (index, child) in items.iter().enumerate() {
+                if index > 0 {
+                    output.push_str(",\n");
+                }
+                output.push_str(&indent);
+                write_node(child, depth + 1, output);
+            }
+            output.push('\n');
+            output.push_str(&"  ".repeat(depth));
+            output.push(']');
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_builder_roundtrip() {
+        let body = r#"{
+  "name": "Ada",
+  "age": 36,
+  "id": "42",
+  "tags": [
+    "admin",
+    {
+      "level": 2
+    }
+  ],
+  "v1.2": null,
+  "meta": {}
+}"#;
+        let rows = json_to_rows(body).unwrap();
+        let pairs: Vec<(&str, &str)> = rows
+            .iter()
+            .map(|row| (row.name.as_str(), row.value.as_str()))
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                ("name", "Ada"),
+                ("age", "36"),
+                ("id", "\"42\""),
+                ("tags[0]", "admin"),
+                ("tags[1].level", "2"),
+                ("[\"v1.2\"]", "null"),
+                ("meta", "{}"),
+            ]
+        );
+        // Key order survives the trip back
+        assert_eq!(rows_to_json(&rows).unwrap(), body);
+
+        assert_eq!(rows_to_json(&[]).unwrap(), "{}");
+        assert!(json_to_rows("42").is_err());
+
+        let mut disabled = KeyValuePair::new("skip", "1");
+        disabled.enabled = false;
+        let rows = [
+            KeyValuePair::new("[0].id", "1"),
+            disabled,
+            KeyValuePair::new("[1]", "x"),
+        ];
+        assert_eq!(
+            rows_to_json(&rows).unwrap(),
+            "[\n  {\n    \"id\": 1\n  },\n  \"x\"\n]"
+        );
+    }
+
+    #[test]
+    fn test_json_builder_rejects_conflicts() {
+        let conflicts = [
+            vec![KeyValuePair::new("a", "1"), KeyValuePair::new("a", "2")],
+            vec![KeyValuePair::new("a", "1"), KeyValuePair::new("a.b", "2")],
+            vec![KeyValuePair::new("a[1]", "1")],
+            vec![KeyValuePair::new("a", "1"), KeyValuePair::new("[0]", "2")],
+            vec![KeyValuePair::new("a..b", "1")],
+        ];
+        for rows in conflicts {
+            let error = rows_to_json(&rows).unwrap_err().to_string();
+            assert!(error.starts_with("Row "), "{}", error);
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use crate::models::http_client::{HttpFormField, HttpHeader, HttpMultipartPart, HttpQueryParam};
+use crate::models::secrets::is_secret_name;
+use serde::{Deserialize, Serialize};
+
+/// A name/value row that can be switched on and off (headers, params, form fields, variables)
+pub trait KeyValueEntry {
+    /// Create an empty, enabled row
+    fn blank() -> Self;
+
+    /// Name, value and enabled flag
+    fn parts(&self) -> (&str, &str, bool);
+
+    /// Mutable access to name, value and enabled flag
+    fn parts_mut(&mut self) -> (&mut String, &mut String, &mut bool);
+
+    /// Shown before the value to say what kind it is (a file part's path)
+    fn value_marker(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Whether the value is masked until secrets are revealed: by default when the
+    /// name looks like a credential and the value is more than a `{{variable}}` reference
+    fn is_secret(&self) -> bool {
+        let (name, value, _) = self.parts();
+        is_secret_name(name) && !value.contains("{{")
+    }
+}
+
+macro_rules! impl_key_value_entry {
+    ($($ty:ty),*) => {
+        $(
+            impl KeyValueEntry for $ty {
+                fn blank() -> Self {
+                    Self::new("", "")
+                }
+
+                fn parts(&self) -> (&str, &str, bool) {
+                    (&self.name, &self.value, self.enabled)
+                }
+
+                fn parts_mut(&mut self) -> (&mut String, &mut String, &mut bool) {
+                    (&mut self.name, &mut self.value, &mut self.enabled)
+                }
+            }
+        )*
+    };
+}
+
+impl_key_value_entry!(HttpHeader, HttpQueryParam, HttpFormField);
+
+impl KeyValueEntry for KeyValuePair {
+    fn blank() -> Self {
+        Self::new("", "")
+    }
+
+    fn parts(&self) -> (&str, &str, bool) {
+        (&self.name, &self.value, self.enabled)
+    }
+
+    fn parts_mut(&mut self) -> (&mut String, &mut String, &mut bool) {
+        (&mut self.name, &mut self.value, &mut self.enabled)
+    }
+
+    /// Variables are only secret when flagged, whatever their name
+    fn is_secret(&self) -> bool {
+        self.secret
+    }
+}
+
+impl KeyValueEntry for HttpMultipartPart {
+    fn blank() -> Self {
+        Self::new("", "")
+    }
+
+    fn parts(&self) -> (&str, &str, bool) {
+        (&self.name, &self.value, self.enabled)
+    }
+
+    fn parts_mut(&mut self) -> (&mut String, &mut String, &mut bool) {
+        (&mut self.name, &mut self.value, &mut self.enabled)
+    }
+
+    fn value_marker(&self) -> Option<&'static str> {
+        self.is_file.then_some("📎 ")
+    }
+}
+
+/// Generic name/value pair (environment and collection variables)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyValuePair {
+    pub name: String,
+    pub value: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Masked in the UI and scrubbed from logs and response echoes
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub secret: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+impl KeyValuePair {
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            enabled: true,
+            secret: false,
+        }
+    }
+}
+
+/// Column being edited in a key/value row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyValueColumn {
+    Name,
+    Value,
+}
+
+/// Selection and inline-edit state shared by every key/value list editor
+#[derive(Debug, Clone, Default)]
+pub struct KeyValueEditor {
+    /// Selected row index
+    pub selected: usize,
+
+    /// Column currently being edited (None = navigating)
+    pub editing: Option<KeyValueColumn>,
+
+    /// Text being typed into the edited cell
+    pub buffer: String,
+
+    /// Whether the edited row was just added (cancelling removes it again)
+    is_new_row: bool,
+}
+
+impl KeyValueEditor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether a cell is being edited
+    pub fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+
+    /// Reset selection and editing (e.g. when the underlying list changes)
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn select_next<T: KeyValueEntry>(&mut self, rows: &[T]) {
+        if self.selected + 1 < rows.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Append a blank row and start editing its name
+    pub fn add_row<T: KeyValueEntry>(&mut self, rows: &mut Vec<T>) {
+        rows.push(T::blank());
+        self.selected = rows.len() - 1;
+        self.is_new_row = true;
+        self.begin_edit(rows, KeyValueColumn::Name);
+    }
+
+    /// Remove the selected row
+    pub fn remove_selected<T: KeyValueEntry>(&mut self, rows: &mut Vec<T>) -> Option<T> {
+        if self.selected >= rows.len() {
+            return None;
+        }
+
+        let removed = rows.remove(self.selected);
+        if self.selected >= rows.len() {
+            self.selected = rows.len().saturating_sub(1);
+        }
+        Some(removed)
+    }
+
+    /// Flip the enabled flag of the selected row
+    pub fn toggle_selected<T: KeyValueEntry>(&mut self, rows: &mut [T]) {
+        if let Some(row) = rows.get_mut(self.selected) {
+            let (_, _, enabled) = row.parts_mut();
+            *enabled = !*enabled;
+        }
+    }
+
+    /// Start editing a cell of the selected row
+    pub fn begin_edit<T: KeyValueEntry>(&mut self, rows: &[T], column: KeyValueColumn) {
+        let Some(row) = rows.get(self.selected) else {
+            return;
+        };
+
+        let (name, value, _) = row.parts();
+        self.buffer = match column {
+            KeyValueColumn::Name => name.to_string(),
+            KeyValueColumn::Value => value.to_string(),
+        };
+        self.editing = Some(column);
+    }
+
+    /// Write the buffer back and move to the other column of the same row
+    pub fn switch_column<T: KeyValueEntry>(&mut self, rows: &mut [T]) {
+        let next = match self.editing {
+            Some(KeyValueColumn::Name) => KeyValueColumn::Value,
+            Some(KeyValueColumn::Value) => KeyValueColumn::Name,
+            None => return,
+        };
+        self.write_buffer(rows);
+        self.begin_edit(rows, next);
+    }
+
+    /// Write the buffer back and stop editing
+    pub fn commit_edit<T: KeyValueEntry>(&mut self, rows: &mut Vec<T>) {
+        self.write_buffer(rows);
+        self.finish_edit(rows);
+    }
+
+    /// Discard the buffer; a freshly added row that was never named is removed
+    pub fn cancel_edit<T: KeyValueEntry>(&mut self, rows: &mut Vec<T>) {
+        self.finish_edit(rows);
+    }
+
+    fn write_buffer<T: KeyValueEntry>(&mut self, rows: &mut [T]) {
+        let Some(row) = rows.get_mut(self.selected) else {
+            return;
+        };
+
+        let (name, value, _) = row.parts_mut();
+        match self.editing {
+            Some(KeyValueColumn::Name) => *name = self.buffer.trim().to_string(),
+            Some(KeyValueColumn::Value) => *value = self.buffer.clone(),
+            None => {}
+        }
+    }
+
+    fn finish_edit<T: KeyValueEntry>(&mut self, rows: &mut Vec<T>) {
+        if self.is_new_row
+            && rows
+                .get(self.selected)
+                .is_some_and(|row| row.parts().0.is_empty())
+        {
+            self.remove_selected(rows);
+        }
+
+        self.editing = None;
+        self.buffer.clear();
+        self.is_new_row = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_edit_and_cancel_rows() {
+        let mut rows: Vec<HttpHeader> = Vec::new();
+        let mut editor = KeyValueEditor::new();
+
+        editor.add_row(&mut rows);
+        editor.buffer.push_str("Accept");
+        editor.switch_column(&mut rows);
+        assert_eq!(editor.editing, Some(KeyValueColumn::Value));
+        editor.buffer.push_str("application/json");
+        editor.commit_edit(&mut rows);
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].parts(), ("Accept", "application/json", true));
+
+        // A new row that never got a name disappears on cancel
+        editor.add_row(&mut rows);
+        editor.cancel_edit(&mut rows);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(editor.selected, 0);
+
+        editor.toggle_selected(&mut rows);
+        assert!(!rows[0].enabled);
+    }
+}
@@ -1,4 +1,5 @@
-use chrono::{DateTime, Local};
+use crate::models::TimestampFormat;
+use chrono::{DateTime, Duration, Local};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -50,6 +51,9 @@ pub struct LogEntry {
     pub timestamp: DateTime<Local>,
     pub level: LogLevel,
     pub message: String,
+    /// Marker lines inserted by the user to separate run attempts
+    #[serde(default)]
+    pub is_marker: bool,
 }
 
 impl LogEntry {
@@ -59,14 +63,26 @@ impl LogEntry {
             timestamp: Local::now(),
             level,
             message: message.into(),
+            is_marker: false,
+        }
+    }
+
+    /// Create a marker line that time filters can start from
+    pub fn marker(number: usize) -> Self {
+        Self {
+            is_marker: true,
+            ..Self::new(
+                LogLevel::Info,
+                format!("──────── Marker #{} ────────", number),
+            )
         }
     }
 
     /// Format the log entry for display in the UI
-    pub fn formatted(&self) -> String {
+    pub fn formatted(&self, timestamp_format: TimestampFormat) -> String {
         format!(
             "[{}] {:>7} {}",
-            self.timestamp.format("%H:%M:%S"),
+            timestamp_format.format(&self.timestamp),
             self.level.as_str(),
             self.message
         )
@@ -83,3 +99,83 @@ impl LogEntry {
             || self.level.as_str().to_lowercase().contains(&query_lower)
     }
 }
+
+/// Time window applied to the log panel on top of the text search
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogTimeFilter {
+    #[default]
+    All,
+    /// Entries from the last N minutes
+    LastMinutes(i64),
+    /// Entries from the most recent marker onwards
+    SinceMarker,
+}
+
+impl LogTimeFilter {
+    /// Short label for the log panel title
+    pub fn label(&self) -> String {
+        match self {
+            LogTimeFilter::All => "all time".to_string(),
+            LogTimeFilter::LastMinutes(minutes) if minutes % 60 == 0 => {
+                format!("last {}h", minutes / 60)
+            }
+            LogTimeFilter::LastMinutes(minutes) => format!("last {}m", minutes),
+            LogTimeFilter::SinceMarker => "since marker".to_string(),
+        }
+    }
+
+    /// The next filter in the cycle (the marker filter only once a marker exists)
+    pub fn next(&self, has_marker: bool) -> LogTimeFilter {
+        match self {
+            LogTimeFilter::All => LogTimeFilter::LastMinutes(5),
+            LogTimeFilter::LastMinutes(5) => LogTimeFilter::LastMinutes(15),
+            LogTimeFilter::LastMinutes(15) => LogTimeFilter::LastMinutes(60),
+            LogTimeFilter::LastMinutes(_) if has_marker => LogTimeFilter::SinceMarker,
+            LogTimeFilter::LastMinutes(_) | LogTimeFilter::SinceMarker => LogTimeFilter::All,
+        }
+    }
+
+    /// Oldest timestamp that passes the filter (None = no limit)
+    pub fn cutoff(
+        &self,
+        now: DateTime<Local>,
+        last_marker: Option<DateTime<Local>>,
+    ) -> Option<DateTime<Local>> {
+        match self {
+            LogTimeFilter::All => None,
+            LogTimeFilter::LastMinutes(minutes) => Some(now - Duration::minutes(*minutes)),
+            LogTimeFilter::SinceMarker => last_marker,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_time_filter() {
+        let now = Local::now();
+        let marker = now - Duration::minutes(30);
+
+        assert_eq!(LogTimeFilter::All.cutoff(now, Some(marker)), None);
+        assert_eq!(
+            LogTimeFilter::LastMinutes(5).cutoff(now, None),
+            Some(now - Duration::minutes(5))
+        );
+        assert_eq!(
+            LogTimeFilter::SinceMarker.cutoff(now, Some(marker)),
+            Some(marker)
+        );
+
+        assert_eq!(
+            LogTimeFilter::LastMinutes(60).next(false),
+            LogTimeFilter::All
+        );
+        assert_eq!(
+            LogTimeFilter::LastMinutes(60).next(true),
+            LogTimeFilter::SinceMarker
+        );
+        assert_eq!(LogTimeFilter::LastMinutes(60).label(), "last 1h");
+    }
+}
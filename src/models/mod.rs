@@ -1,15 +1,50 @@
 pub mod clipboard;
 pub mod config;
+pub mod confirmation;
+pub mod cookie_jar;
+pub mod folder_settings;
+pub mod header_names;
+pub mod hex_dump;
 pub mod http;
 pub mod http_client;
+pub mod image_info;
+pub mod json_builder;
+pub mod key_value;
 pub mod log_entry;
+pub mod navigation;
+pub mod notes;
+pub mod perf_stats;
+pub mod recent;
+pub mod search;
+pub mod secrets;
+pub mod stepper;
+pub mod storage_format;
 pub mod template;
+pub mod text_search;
+pub mod timestamp;
+pub mod transfer;
 pub mod tree;
+pub mod tutorial;
 
 // Re-export commonly used types for convenience
 pub use clipboard::{ClipboardItem, ClipboardOperation};
-pub use config::AppConfig;
+pub use config::{
+    AppConfig, AutoLock, BackupSchedule, DEFAULT_ENVIRONMENT_NAME, Environment, HistoryRetention,
+    ResponseDisplayLimit, StartupAction, StartupMode, StartupPane,
+};
+pub use cookie_jar::{Cookie, CookieJar, SharedCookieJar};
+pub use folder_settings::{FolderInheritance, FolderSettings};
 pub use http_client::ApiKeyLocation;
-pub use log_entry::{LogEntry, LogLevel};
-pub use template::{AutomationTemplate, FieldType, FormField, WebsiteConfig};
-pub use tree::{FocusDirection, NodeType, TreeNode, TreeState};
+pub use key_value::{KeyValueColumn, KeyValueEditor, KeyValueEntry, KeyValuePair};
+pub use log_entry::{LogEntry, LogLevel, LogTimeFilter};
+pub use notes::{ItemNote, NoteTree};
+pub use perf_stats::{BufferUsage, PerfStats};
+pub use recent::{RecentItem, RecentItemKind};
+pub use search::{SearchCategory, SearchEntry, SearchTarget};
+pub use storage_format::StorageFormat;
+pub use template::{
+    AutomationTemplate, FieldDoc, FieldType, FormField, InputPreset, WebsiteConfig,
+};
+pub use timestamp::TimestampFormat;
+pub use transfer::{SessionTransferStats, TransferStats};
+pub use tree::{FocusDirection, FolderColor, NodeType, TreeNode, TreeState};
@@ -0,0 +1,81 @@
+/// Maximum number of locations kept in each direction
+pub const MAX_NAVIGATION_HISTORY: usize = 50;
+
+/// Browser-style back/forward stacks of visited locations
+#[derive(Debug, Clone)]
+pub struct NavigationHistory<T> {
+    back: Vec<T>,
+    forward: Vec<T>,
+}
+
+impl<T> Default for NavigationHistory<T> {
+    fn default() -> Self {
+        Self {
+            back: Vec::new(),
+            forward: Vec::new(),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> NavigationHistory<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember `from` as the place just left; a new jump discards the forward stack
+    pub fn record(&mut self, from: T) {
+        if self.back.last() != Some(&from) {
+            self.back.push(from);
+            if self.back.len() > MAX_NAVIGATION_HISTORY {
+                self.back.remove(0);
+            }
+        }
+        self.forward.clear();
+    }
+
+    /// Step back from `current`, returning the location to restore
+    pub fn back(&mut self, current: T) -> Option<T> {
+        let target = self.back.pop()?;
+        self.forward.push(current);
+        Some(target)
+    }
+
+    /// Step forward from `current`, returning the location to restore
+    pub fn forward(&mut self, current: T) -> Option<T> {
+        let target = self.forward.pop()?;
+        self.back.push(current);
+        Some(target)
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        !self.back.is_empty()
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        !self.forward.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_navigation_history_back_and_forward() {
+        let mut history = NavigationHistory::new();
+        history.record("tree");
+        history.record("tree");
+        history.record("form");
+
+        assert_eq!(history.back("response"), Some("form"));
+        assert_eq!(history.back("form"), Some("tree"));
+        assert_eq!(history.back("tree"), None);
+        assert!(history.can_go_forward());
+
+        assert_eq!(history.forward("tree"), Some("form"));
+        // A new jump drops the forward entries
+        history.record("form");
+        assert!(!history.can_go_forward());
+        assert_eq!(history.back("logs"), Some("form"));
+    }
+}
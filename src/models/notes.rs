@@ -0,0 +1,130 @@
+use chrono::{Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// Which tree a note belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NoteTree {
+    /// Automation templates
+    Templates,
+    /// HTTP collections
+    Collections,
+}
+
+/// Freeform note attached to a template, request or folder
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemNote {
+    pub tree: NoteTree,
+    /// Tree path ("folder/name")
+    pub path: String,
+    #[serde(default)]
+    pub text: String,
+    /// Date from which the note shows up as a reminder
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_by: Option<NaiveDate>,
+}
+
+impl ItemNote {
+    pub fn is_due(&self, today: NaiveDate) -> bool {
+        self.review_by.is_some_and(|date| date <= today)
+    }
+
+    /// First line of the note, for one-line summaries
+    pub fn headline(&self) -> &str {
+        self.text.lines().next().unwrap_or_default().trim()
+    }
+}
+
+/// Parse a "review by" date: empty for none, `YYYY-MM-DD`, or `+N` / `+Nw` days or weeks from today
+pub fn parse_review_date(input: &str, today: NaiveDate) -> Result<Option<NaiveDate>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+
+    if let Some(offset) = input.strip_prefix('+') {
+        let (count, per_unit) = match offset.strip_suffix(['w', 'W']) {
+            Some(weeks) => (weeks, 7),
+            None => (offset.trim_end_matches(['d', 'D']), 1),
+        };
+        let count: u64 = count
+            .trim()
+            .parse()
+            .map_err(|_| format!("'{}' is not a number of days or weeks", offset))?;
+        return today
+            .checked_add_days(Days::new(count * per_unit))
+            .map(Some)
+            .ok_or_else(|| "Date is out of range".to_string());
+    }
+
+    NaiveDate::parse_from_str(input, "%Y-%m-%d")
+        .map(Some)
+        .map_err(|_| format!("'{}' is not a date (YYYY-MM-DD, +3d or +2w)", input))
+}
+
+/// Notes whose review date has arrived, oldest first
+pub fn due_notes(notes: &[ItemNote], today: NaiveDate) -> Vec<&ItemNote> {
+    let mut due: Vec<&ItemNote> = notes.iter().filter(|note| note.is_due(today)).collect();
+    due.sort_by_key(|note| note.review_by);
+    due
+}
+
+/// Keep notes attached after a rename or move: `old` and everything below it now live under `new`
+pub fn move_notes(notes: &mut [ItemNote], tree: NoteTree, old: &str, new: &str) {
+    for note in notes.iter_mut().filter(|note| note.tree == tree) {
+        if note.path == old {
+            note.path = new.to_string();
+        } else if let Some(rest) = note.path.strip_prefix(&format!("{}/", old)) {
+            note.path = format!("{}/{}", new, rest);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_review_dates_and_due_notes() {
+        let today = NaiveDate::from_ymd_opt(2024, 5, 10).unwrap();
+        assert_eq!(parse_review_date("", today), Ok(None));
+        assert_eq!(
+            parse_review_date("2024-06-01", today),
+            Ok(NaiveDate::from_ymd_opt(2024, 6, 1))
+        );
+        assert_eq!(
+            parse_review_date("+3", today),
+            Ok(NaiveDate::from_ymd_opt(2024, 5, 13))
+        );
+        assert_eq!(
+            parse_review_date("+2w", today),
+            Ok(NaiveDate::from_ymd_opt(2024, 5, 24))
+        );
+        assert!(parse_review_date("next week", today).is_err());
+
+        let note = |path: &str, review_by| ItemNote {
+            tree: NoteTree::Collections,
+            path: path.to_string(),
+            text: "Portal down Sundays\nuse the mirror".to_string(),
+            review_by,
+        };
+        let mut notes = vec![
+            note("api/users", NaiveDate::from_ymd_opt(2024, 5, 10)),
+            note("api/orders", NaiveDate::from_ymd_opt(2024, 5, 11)),
+            note("legacy/login", NaiveDate::from_ymd_opt(2024, 5, 1)),
+            note("api/health", None),
+        ];
+        let due = due_notes(&notes, today);
+        assert_eq!(
+            due.iter().map(|n| n.path.as_str()).collect::<Vec<_>>(),
+            ["legacy/login", "api/users"]
+        );
+        assert_eq!(due[0].headline(), "Portal down Sundays");
+
+        move_notes(&mut notes, NoteTree::Collections, "api", "v2");
+        assert_eq!(notes[0].path, "v2/users");
+        assert_eq!(notes[2].path, "legacy/login");
+        move_notes(&mut notes, NoteTree::Templates, "legacy", "old");
+        assert_eq!(notes[2].path, "legacy/login");
+    }
+}
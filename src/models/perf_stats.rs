@@ -0,0 +1,127 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// How many recent frames the averages and peaks cover (~6s at 20 FPS)
+const SAMPLE_WINDOW: usize = 120;
+
+/// Rolling window of samples with average and peak
+#[derive(Debug, Clone, Default)]
+pub struct SampleWindow<T> {
+    samples: VecDeque<T>,
+}
+
+impl<T: Copy + Ord + Default> SampleWindow<T> {
+    pub fn push(&mut self, sample: T) {
+        if self.samples.len() == SAMPLE_WINDOW {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    pub fn last(&self) -> T {
+        self.samples.back().copied().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> T {
+        self.samples.iter().copied().max().unwrap_or_default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+}
+
+impl SampleWindow<Duration> {
+    pub fn average(&self) -> Duration {
+        if self.samples.is_empty() {
+            return Duration::ZERO;
+        }
+        self.samples.iter().sum::<Duration>() / self.samples.len() as u32
+    }
+}
+
+/// Event loop timings behind the performance overlay
+#[derive(Debug, Clone, Default)]
+pub struct PerfStats {
+    /// Time spent drawing each frame
+    pub render_times: SampleWindow<Duration>,
+    /// Time each loop iteration spent before sleeping (events, messages, housekeeping, draw)
+    pub loop_latencies: SampleWindow<Duration>,
+    /// Background messages waiting when the loop drained the queue
+    pub queue_depths: SampleWindow<usize>,
+    /// Time between consecutive frames
+    frame_intervals: SampleWindow<Duration>,
+    last_frame_at: Option<Instant>,
+}
+
+impl PerfStats {
+    pub fn record_frame(&mut self, render_time: Duration) {
+        let now = Instant::now();
+        if let Some(previous) = self.last_frame_at {
+            self.frame_intervals.push(now - previous);
+        }
+        self.last_frame_at = Some(now);
+        self.render_times.push(render_time);
+    }
+
+    pub fn record_loop(&mut self, latency: Duration) {
+        self.loop_latencies.push(latency);
+    }
+
+    pub fn record_queue_depth(&mut self, depth: usize) {
+        self.queue_depths.push(depth);
+    }
+
+    /// Frames per second over the sample window
+    pub fn fps(&self) -> f64 {
+        let interval = self.frame_intervals.average();
+        if interval.is_zero() {
+            0.0
+        } else {
+            1.0 / interval.as_secs_f64()
+        }
+    }
+}
+
+/// Approximate heap usage of one of the app's growable buffers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BufferUsage {
+    pub label: &'static str,
+    pub items: usize,
+    pub bytes: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_window_keeps_recent_samples() {
+        let mut stats = PerfStats::default();
+        assert_eq!(stats.render_times.average(), Duration::ZERO);
+        assert_eq!(stats.fps(), 0.0);
+
+        for millis in 1..=SAMPLE_WINDOW as u64 + 10 {
+            stats.record_frame(Duration::from_millis(millis));
+        }
+        // The oldest ten fell out of the window
+        assert_eq!(stats.render_times.len(), SAMPLE_WINDOW);
+        assert_eq!(
+            stats.render_times.last(),
+            Duration::from_millis(SAMPLE_WINDOW as u64 + 10)
+        );
+        assert_eq!(
+            stats.render_times.average(),
+            Duration::from_micros(((11 + SAMPLE_WINDOW as u64 + 10) * 1000) / 2)
+        );
+
+        stats.record_queue_depth(3);
+        stats.record_queue_depth(0);
+        assert_eq!(stats.queue_depths.max(), 3);
+        assert_eq!(stats.queue_depths.last(), 0);
+    }
+}
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+/// How many recently opened items are remembered
+pub const MAX_RECENT_ITEMS: usize = 10;
+
+/// What kind of item was opened
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RecentItemKind {
+    Template,
+    Request,
+}
+
+/// A template or request that was recently opened
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentItem {
+    pub kind: RecentItemKind,
+    /// Tree path ("folder/name")
+    pub path: String,
+}
+
+impl RecentItem {
+    pub fn new(kind: RecentItemKind, path: impl Into<String>) -> Self {
+        Self {
+            kind,
+            path: path.into(),
+        }
+    }
+
+    /// Item name without its folder
+    pub fn name(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or(&self.path)
+    }
+
+    /// Folder part of the path ("" for root)
+    pub fn folder(&self) -> &str {
+        self.path
+            .rfind('/')
+            .map(|pos| &self.path[..pos])
+            .unwrap_or("")
+    }
+}
+
+/// Move `item` to the front of the list, dropping duplicates and the oldest entries
+pub fn record_recent_item(items: &mut Vec<RecentItem>, item: RecentItem) {
+    items.retain(|existing| existing != &item);
+    items.insert(0, item);
+    items.truncate(MAX_RECENT_ITEMS);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_recent_item() {
+        let mut items = Vec::new();
+        for i in 0..MAX_RECENT_ITEMS + 2 {
+            record_recent_item(
+                &mut items,
+                RecentItem::new(RecentItemKind::Request, format!("api/req{}", i)),
+            );
+        }
+        assert_eq!(items.len(), MAX_RECENT_ITEMS);
+        assert_eq!(items[0].name(), "req11");
+
+        record_recent_item(
+            &mut items,
+            RecentItem::new(RecentItemKind::Request, "api/req5"),
+        );
+        assert_eq!(items.len(), MAX_RECENT_ITEMS);
+        assert_eq!(items[0].path, "api/req5");
+        assert_eq!(items[0].folder(), "api");
+        assert_eq!(items.iter().filter(|i| i.path == "api/req5").count(), 1);
+    }
+}
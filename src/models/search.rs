@@ -0,0 +1,322 @@
+use crate::models::KeyValuePair;
+
+/// Maximum number of results shown by the global search overlay
+pub const MAX_SEARCH_RESULTS: usize = 50;
+
+/// Where a global search result came from (shown as a badge)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchCategory {
+    Template,
+    Request,
+    Folder,
+    Log,
+    Help,
+}
+
+impl SearchCategory {
+    pub fn badge(&self) -> &'static str {
+        match self {
+            SearchCategory::Template => "TEMPLATE",
+            SearchCategory::Request => "REQUEST",
+            SearchCategory::Folder => "FOLDER",
+            SearchCategory::Log => "LOG",
+            SearchCategory::Help => "HELP",
+        }
+    }
+
+    /// Small boost so saved items outrank log lines with the same match quality
+    fn weight(&self) -> u32 {
+        match self {
+            SearchCategory::Template | SearchCategory::Request => 3,
+            SearchCategory::Folder => 2,
+            SearchCategory::Help => 1,
+            SearchCategory::Log => 0,
+        }
+    }
+}
+
+/// What Enter does with a result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchTarget {
+    /// Open a template (Automation mode) or request (HTTP mode) by its tree path
+    Item { http: bool, path: String },
+    /// Reveal a folder in the collections tree
+    Folder { http: bool, path: String },
+    /// Scroll the log panel to this entry
+    Log { index: usize },
+    /// Open help filtered to this entry
+    Help { query: String },
+}
+
+/// One searchable thing: a title (matched first) plus secondary text
+#[derive(Debug, Clone)]
+pub struct SearchEntry {
+    pub category: SearchCategory,
+    pub title: String,
+    pub detail: String,
+    pub target: SearchTarget,
+}
+
+impl SearchEntry {
+    pub fn new(
+        category: SearchCategory,
+        title: impl Into<String>,
+        detail: impl Into<String>,
+        target: SearchTarget,
+    ) -> Self {
+        Self {
+            category,
+            title: title.into(),
+            detail: detail.into(),
+            target,
+        }
+    }
+
+    /// Score this entry against the query; `None` if any word doesn't match
+    pub fn score(&self, query: &str) -> Option<u32> {
+        let title = self.title.to_lowercase();
+        let detail = self.detail.to_lowercase();
+
+        let mut total = 0;
+        for word in query.split_whitespace() {
+            let word = word.to_lowercase();
+            let word_score = if title == word {
+                100
+            } else if title.starts_with(&word) {
+                80
+            } else if title
+                .split(|c: char| !c.is_alphanumeric())
+                .any(|part| part.starts_with(&word))
+            {
+                60
+            } else if title.contains(&word) {
+                40
+            } else if detail.contains(&word) {
+                20
+            } else {
+                return None;
+            };
+            total += word_score;
+        }
+        Some(total + self.category.weight())
+    }
+}
+
+/// `send <request> name=value… env=<environment>` typed into global search
+#[derive(Debug, Clone, PartialEq)]
+pub struct SendCommand {
+    /// Path or words identifying the saved request
+    pub query: String,
+    /// Variables set on top of the environment for this send only
+    pub overrides: Vec<KeyValuePair>,
+    /// Environment to send with instead of the active one
+    pub environment: Option<String>,
+}
+
+impl SendCommand {
+    /// Parse a query starting with `send `; trailing `name=value` words are overrides
+    pub fn parse(query: &str) -> Option<Self> {
+        let rest = query.trim_start().strip_prefix("send ")?;
+        let mut words = Vec::new();
+        let mut overrides = Vec::new();
+        let mut environment = None;
+        for word in rest.split_whitespace() {
+            match word.split_once('=') {
+                Some(("env", name)) if !name.is_empty() => environment = Some(name.to_string()),
+                Some((name, value)) if !name.is_empty() => {
+                    overrides.push(KeyValuePair::new(name, value))
+                }
+                _ => words.push(word),
+            }
+        }
+        Some(Self {
+            query: words.join(" "),
+            overrides,
+            environment,
+        })
+    }
+
+    /// `id=42, env prod` for the search overlay and the send log
+    pub fn summary(&self) -> String {
+        let mut parts: Vec<String> = self
+            .overrides
+            .iter()
+            .map(|pair| format!("{}={}", pair.name, pair.value))
+            .collect();
+        if let Some(environment) = &self.environment {
+            parts.push(format!("env {}", environment));
+        }
+        parts.join(", ")
+    }
+}
+
+/// Indices of the entries matching `query`, best first
+pub fn rank_search_results(entries: &[SearchEntry], query: &str) -> Vec<usize> {
+    rank_entries(entries, query, |_| true)
+}
+
+/// Saved requests matching a `send` query, an exact path match first
+pub fn rank_send_targets(entries: &[SearchEntry], query: &str) -> Vec<usize> {
+    let mut ranked = rank_entries(entries, query, |entry| {
+        entry.category == SearchCategory::Request
+    });
+    let exact = ranked.iter().position(|&index| {
+        matches!(&entries[index].target, SearchTarget::Item { path, .. }
+            if path.eq_ignore_ascii_case(query.trim()))
+    });
+    if let Some(position) = exact {
+        let index = ranked.remove(position);
+        ranked.insert(0, index);
+    }
+    ranked
+}
+
+/// Score `text` against a fuzzy `query`: every query character must appear in order.
+///
+/// Runs of consecutive characters and characters starting a word score higher.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<u32> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous: Option<usize> = None;
+    for wanted in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let index = position + text[position..].iter().position(|&c| c == wanted)?;
+        score += 1;
+        if index > 0 && previous == Some(index - 1) {
+            score += 5;
+        }
+        if index == 0 || !text[index - 1].is_alphanumeric() {
+            score += 3;
+        }
+        previous = Some(index);
+        position = index + 1;
+    }
+    Some(score)
+}
+
+fn rank_entries(
+    entries: &[SearchEntry],
+    query: &str,
+    include: impl Fn(&SearchEntry) -> bool,
+) -> Vec<usize> {
+    if query.trim().is_empty() {
+        return Vec::new();
+    }
+
+    let mut scored: Vec<(u32, usize)> = entries
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| include(entry))
+        .filter_map(|(index, entry)| entry.score(query).map(|score| (score, index)))
+        .collect();
+    // Higher score first, then shorter titles, then newer entries (logs are appended)
+    scored.sort_by(|a, b| {
+        b.0.cmp(&a.0)
+            .then_with(|| entries[a.1].title.len().cmp(&entries[b.1].title.len()))
+            .then_with(|| b.1.cmp(&a.1))
+    });
+    scored
+        .into_iter()
+        .take(MAX_SEARCH_RESULTS)
+        .map(|(_, index)| index)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_search_results() {
+        let entries = vec![
+            SearchEntry::new(
+                SearchCategory::Log,
+                "Imported users collection",
+                "",
+                SearchTarget::Log { index: 0 },
+            ),
+            SearchEntry::new(
+                SearchCategory::Request,
+                "List users",
+                "GET https://api.example.com/users",
+                SearchTarget::Item {
+                    http: true,
+                    path: "API/List users".to_string(),
+                },
+            ),
+            SearchEntry::new(
+                SearchCategory::Template,
+                "Signup",
+                "email=user@example.com",
+                SearchTarget::Item {
+                    http: false,
+                    path: "Signup".to_string(),
+                },
+            ),
+        ];
+
+        assert_eq!(rank_search_results(&entries, "users"), vec![1, 0]);
+        assert_eq!(rank_search_results(&entries, "example"), vec![2, 1]);
+        assert_eq!(rank_search_results(&entries, "list example"), vec![1]);
+        assert!(rank_search_results(&entries, "  ").is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert!(fuzzy_score("gusr", "GET https://api.example.com/users").is_some());
+        assert!(fuzzy_score("usrg", "GET https://api.example.com/users").is_none());
+        // A contiguous word outranks the same letters scattered around
+        assert!(
+            fuzzy_score("users", "POST /users").unwrap()
+                > fuzzy_score("users", "GET /u/s/e/r/s").unwrap()
+        );
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn test_send_command() {
+        let command = SendCommand::parse("send users/get-user id=42 env=prod").unwrap();
+        assert_eq!(command.query, "users/get-user");
+        assert_eq!(command.overrides, vec![KeyValuePair::new("id", "42")]);
+        assert_eq!(command.environment.as_deref(), Some("prod"));
+        assert_eq!(command.summary(), "id=42, env prod");
+
+        // Request names may have spaces; values may contain '='
+        let command = SendCommand::parse("send API/List users filter=a=b").unwrap();
+        assert_eq!(command.query, "API/List users");
+        assert_eq!(command.overrides, vec![KeyValuePair::new("filter", "a=b")]);
+        assert_eq!(command.environment, None);
+
+        assert_eq!(SendCommand::parse("sender"), None);
+
+        let entries = vec![
+            SearchEntry::new(
+                SearchCategory::Request,
+                "get-user",
+                "GET {{base}}/users/{{id}} admin/get-user",
+                SearchTarget::Item {
+                    http: true,
+                    path: "admin/get-user".to_string(),
+                },
+            ),
+            SearchEntry::new(
+                SearchCategory::Request,
+                "get-user",
+                "GET {{base}}/users/{{id}} users/get-user",
+                SearchTarget::Item {
+                    http: true,
+                    path: "users/get-user".to_string(),
+                },
+            ),
+            SearchEntry::new(
+                SearchCategory::Log,
+                "get-user failed",
+                "",
+                SearchTarget::Log { index: 0 },
+            ),
+        ];
+        assert_eq!(rank_send_targets(&entries, "get-user"), vec![1, 0]);
+        assert_eq!(rank_send_targets(&entries, "users/get-user"), vec![1]);
+        assert_eq!(rank_send_targets(&entries, "admin/get-user"), vec![0]);
+    }
+}
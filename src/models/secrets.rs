@@ -0,0 +1,69 @@
+use std::borrow::Cow;
+
+/// Shown in place of a secret value until secrets are revealed
+pub const MASK: &str = "•••";
+
+/// Secret values shorter than this are not scrubbed from free text; they match too much
+const MIN_MASKED_LEN: usize = 4;
+
+/// Name fragments that mark a header, parameter or variable as secret
+const SECRET_NAME_HINTS: &[&str] = &[
+    "auth",
+    "token",
+    "secret",
+    "password",
+    "passwd",
+    "key",
+    "cookie",
+    "session",
+    "credential",
+    "signature",
+];
+
+/// Whether a header/parameter/variable name suggests its value is a secret
+pub fn is_secret_name(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    SECRET_NAME_HINTS.iter().any(|hint| name.contains(hint))
+}
+
+/// Replace every occurrence of the secret values in `text` with the mask
+pub fn mask_secrets<'a>(text: &'a str, secrets: &[String]) -> Cow<'a, str> {
+    let mut secrets: Vec<&String> = secrets
+        .iter()
+        .filter(|secret| secret.chars().count() >= MIN_MASKED_LEN && text.contains(secret.as_str()))
+        .collect();
+    if secrets.is_empty() {
+        return Cow::Borrowed(text);
+    }
+    // Longest first, so a secret containing another one is masked whole
+    secrets.sort_by_key(|secret| std::cmp::Reverse(secret.len()));
+    let mut masked = text.to_string();
+    for secret in secrets {
+        masked = masked.replace(secret.as_str(), MASK);
+    }
+    Cow::Owned(masked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask_secrets() {
+        let secrets = vec![
+            "s3cr3t-token".to_string(),
+            "s3cr3t-token-admin".to_string(),
+            "abc".to_string(),
+        ];
+        assert_eq!(
+            mask_secrets("Bearer s3cr3t-token-admin sent, abc", &secrets),
+            "Bearer ••• sent, abc"
+        );
+        assert!(matches!(
+            mask_secrets("nothing here", &secrets),
+            Cow::Borrowed(_)
+        ));
+        assert!(is_secret_name("X-Api-Key"));
+        assert!(!is_secret_name("Accept"));
+    }
+}
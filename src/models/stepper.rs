@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+/// Bounds and step size for a numeric field edited with increment/decrement keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NumberRange {
+    pub min: i64,
+    pub max: i64,
+    #[serde(default = "default_step")]
+    pub step: i64,
+}
+
+fn default_step() -> i64 {
+    1
+}
+
+impl NumberRange {
+    pub const fn new(min: i64, max: i64) -> Self {
+        Self { min, max, step: 1 }
+    }
+
+    pub const fn with_step(mut self, step: i64) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn clamp(&self, value: i64) -> i64 {
+        value.clamp(self.min, self.max)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+
+    /// Step `value` up (`steps > 0`) or down, staying within the bounds
+    pub fn step_by(&self, value: i64, steps: i64) -> i64 {
+        self.clamp(value.saturating_add(self.step.saturating_mul(steps)))
+    }
+
+    /// Step a numeric text value; text that doesn't parse starts from the minimum
+    pub fn step_text(&self, text: &str, steps: i64) -> String {
+        match text.trim().parse::<i64>() {
+            Ok(value) => self.step_by(value, steps).to_string(),
+            Err(_) => self.min.to_string(),
+        }
+    }
+}
+
+/// Page limit for "fetch all pages"
+pub const MAX_PAGES_RANGE: NumberRange = NumberRange::new(1, 1000);
+
+/// Per-request timeout in seconds (0 = no timeout)
+pub const TIMEOUT_SECS_RANGE: NumberRange = NumberRange::new(0, 600).with_step(5);
+
+/// Redirects a request may follow
+pub const MAX_REDIRECTS_RANGE: NumberRange = NumberRange::new(0, 50);
+
+/// Attempts per send (0 = the global retry policy, 1 = no retries)
+pub const RETRY_ATTEMPTS_RANGE: NumberRange = NumberRange::new(0, 10);
+
+/// Wait before the first retry, in milliseconds
+pub const RETRY_BACKOFF_MS_RANGE: NumberRange = NumberRange::new(100, 10_000).with_step(100);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_range_steps_and_clamps() {
+        let range = NumberRange::new(1, 20).with_step(5);
+        assert_eq!(range.step_by(1, 1), 6);
+        assert_eq!(range.step_by(18, 1), 20);
+        assert_eq!(range.step_by(3, -1), 1);
+        assert_eq!(range.step_text(" 10 ", -1), "5");
+        assert_eq!(range.step_text("abc", 1), "1");
+        assert!(range.contains(20));
+        assert!(!range.contains(0));
+    }
+}
@@ -0,0 +1,159 @@
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Extensions a stored template or request may have, in lookup order
+const ITEM_EXTENSIONS: [&str; 3] = ["json", "yaml", "yml"];
+
+/// File format for stored templates and requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StorageFormat {
+    #[default]
+    Json,
+    /// Diffs much better in code review when collections live in git
+    Yaml,
+}
+
+impl StorageFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            StorageFormat::Json => "json",
+            StorageFormat::Yaml => "yaml",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StorageFormat::Json => "JSON (.json)",
+            StorageFormat::Yaml => "YAML (.yaml)",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            StorageFormat::Json => StorageFormat::Yaml,
+            StorageFormat::Yaml => StorageFormat::Json,
+        }
+    }
+
+    /// Format of a stored file, from its extension (`.yml` counts as YAML)
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension().and_then(|s| s.to_str())? {
+            "json" => Some(StorageFormat::Json),
+            "yaml" | "yml" => Some(StorageFormat::Yaml),
+            _ => None,
+        }
+    }
+
+    /// Keys come out in declaration order and files end with a newline, so
+    /// saving the same value twice gives byte-identical output
+    pub fn serialize<T: Serialize>(&self, value: &T) -> Result<String> {
+        Ok(match self {
+            StorageFormat::Json => serde_json::to_string_pretty(value)? + "\n",
+            StorageFormat::Yaml => serde_yaml::to_string(value)?,
+        })
+    }
+
+    pub fn parse<T: DeserializeOwned>(&self, content: &str) -> Result<T> {
+        Ok(match self {
+            StorageFormat::Json => serde_json::from_str(content)?,
+            StorageFormat::Yaml => serde_yaml::from_str(content)?,
+        })
+    }
+}
+
+/// Whether `path` is a stored template or request in any supported format.
+///
+/// Hidden files (such as folder settings) are not items.
+pub fn is_item_file(path: &Path) -> bool {
+    let hidden = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with('.'));
+    !hidden && path.is_file() && StorageFormat::from_path(path).is_some()
+}
+
+/// The existing file for `file_stem` in `dir`, whichever format it was saved in
+pub fn find_item_file(dir: &Path, file_stem: &str) -> Option<PathBuf> {
+    ITEM_EXTENSIONS
+        .iter()
+        .map(|extension| dir.join(format!("{}.{}", file_stem, extension)))
+        .find(|path| path.is_file())
+}
+
+/// Contents of the `README.md` (any case) in `dir`, if it has one
+pub fn read_folder_readme(dir: &Path) -> Option<String> {
+    std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .find(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.eq_ignore_ascii_case("README.md"))
+        })
+        .and_then(|path| std::fs::read_to_string(path).ok())
+}
+
+/// Where to save `file_stem`: its existing file (keeping that format), or a new file in `format`
+pub fn item_file_path(dir: &Path, file_stem: &str, format: StorageFormat) -> PathBuf {
+    find_item_file(dir, file_stem)
+        .unwrap_or_else(|| dir.join(format!("{}.{}", file_stem, format.extension())))
+}
+
+/// Read a stored item in the format given by its extension
+pub fn read_item_file<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    StorageFormat::from_path(path)
+        .unwrap_or_default()
+        .parse(&content)
+}
+
+/// Write a stored item in the format given by the extension of `path`.
+///
+/// A file that already holds exactly this content is left alone.
+pub fn write_item_file<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    crate::models::config::ensure_writable_storage()?;
+    let content = StorageFormat::from_path(path)
+        .unwrap_or_default()
+        .serialize(value)?;
+    if std::fs::read_to_string(path).is_ok_and(|existing| existing == content) {
+        return Ok(());
+    }
+    std::fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::http_client::HttpRequest;
+
+    #[test]
+    fn test_item_files_keep_their_format() {
+        let temp = tempfile::tempdir().unwrap();
+        let request = HttpRequest::new("Users").with_url("https://api.example.com/users");
+
+        let yaml_path = item_file_path(temp.path(), "Users", StorageFormat::Yaml);
+        assert!(yaml_path.ends_with("Users.yaml"));
+        write_item_file(&yaml_path, &request).unwrap();
+        let content = std::fs::read_to_string(&yaml_path).unwrap();
+        assert!(content.contains("url: https://api.example.com/users"));
+        assert_eq!(read_item_file::<HttpRequest>(&yaml_path).unwrap(), request);
+
+        // An existing file is saved over in place, whatever the default format
+        assert_eq!(
+            item_file_path(temp.path(), "Users", StorageFormat::Json),
+            yaml_path
+        );
+
+        std::fs::rename(&yaml_path, temp.path().join("Users.yml")).unwrap();
+        let found = find_item_file(temp.path(), "Users").unwrap();
+        assert!(is_item_file(&found));
+        assert_eq!(read_item_file::<HttpRequest>(&found).unwrap(), request);
+        assert!(find_item_file(temp.path(), "Missing").is_none());
+    }
+}
@@ -1,5 +1,6 @@
+use crate::models::stepper::NumberRange;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 /// Represents a form field with its selector and current value
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -10,6 +11,9 @@ pub struct FormField {
     pub field_type: FieldType,
     pub is_required: bool,
     pub dropdown_options: Option<Vec<String>>, // For dropdown fields
+    /// Bounds for number fields (stepped with +/-)
+    #[serde(default)]
+    pub number_range: Option<NumberRange>,
 }
 
 /// Different types of form fields we can handle
@@ -20,6 +24,8 @@ pub enum FieldType {
     Number,
     Select,
     Textarea,
+    /// Boolean stored as "true"/"false", toggled with Space
+    Checkbox,
 }
 
 impl FormField {
@@ -35,6 +41,7 @@ impl FormField {
             field_type,
             is_required: true,
             dropdown_options: None,
+            number_range: None,
         }
     }
 
@@ -53,6 +60,28 @@ impl FormField {
         self
     }
 
+    pub fn with_number_range(mut self, range: NumberRange) -> Self {
+        self.number_range = Some(range);
+        self
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.value.trim().eq_ignore_ascii_case("true")
+    }
+
+    /// Flip a checkbox field
+    pub fn toggle_checked(&mut self) {
+        self.value = (!self.is_checked()).to_string();
+    }
+
+    /// Step a number field up or down within its range
+    pub fn step_number(&mut self, steps: i64) {
+        let range = self
+            .number_range
+            .unwrap_or(NumberRange::new(i64::MIN, i64::MAX));
+        self.value = range.step_text(&self.value, steps);
+    }
+
     /// Get the display label for the field (includes optional indicator)
     pub fn get_display_label(&self) -> String {
         if self.is_required {
@@ -64,11 +93,23 @@ impl FormField {
 
     /// Check if the field is valid (required fields must have values)
     pub fn is_valid(&self) -> bool {
-        if !self.is_required {
-            return true; // Optional fields are always valid
+        let value = self.value.trim();
+
+        // Numbers must parse (and fit the range) whenever they are filled in
+        if matches!(self.field_type, FieldType::Number) && !value.is_empty() {
+            let Ok(number) = value.parse::<i64>() else {
+                return false;
+            };
+            if let Some(range) = self.number_range {
+                return range.contains(number);
+            }
+        }
+
+        if !self.is_required || matches!(self.field_type, FieldType::Checkbox) {
+            return true; // Optional fields and checkboxes are always valid
         }
 
-        !self.value.trim().is_empty()
+        !value.is_empty()
     }
 
     /// Get dropdown options or empty vec if not a dropdown
@@ -78,11 +119,35 @@ impl FormField {
 }
 
 /// A template contains predefined values for the form fields
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AutomationTemplate {
     pub name: String,
     pub description: String,
-    pub field_values: HashMap<String, String>, // field_name -> value
+    /// Sorted by field name so saved templates diff cleanly
+    pub field_values: BTreeMap<String, String>, // field_name -> value
+    /// What each field expects, shown under the focused field and when the template loads
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub field_docs: BTreeMap<String, FieldDoc>,
+}
+
+/// Help for one template field, written alongside its value in the template file
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct FieldDoc {
+    #[serde(default)]
+    pub help: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub example: String,
+}
+
+impl FieldDoc {
+    /// One-line hint: the help text, then the example
+    pub fn hint(&self) -> String {
+        match (self.help.is_empty(), self.example.is_empty()) {
+            (_, true) => self.help.clone(),
+            (true, false) => format!("e.g. {}", self.example),
+            (false, false) => format!("{} (e.g. {})", self.help, self.example),
+        }
+    }
 }
 
 impl AutomationTemplate {
@@ -90,7 +155,8 @@ impl AutomationTemplate {
         Self {
             name: name.into(),
             description: description.into(),
-            field_values: HashMap::new(),
+            field_values: BTreeMap::new(),
+            field_docs: BTreeMap::new(),
         }
     }
 
@@ -99,6 +165,22 @@ impl AutomationTemplate {
         self
     }
 
+    pub fn with_field_doc(
+        mut self,
+        field_name: impl Into<String>,
+        help: impl Into<String>,
+        example: impl Into<String>,
+    ) -> Self {
+        self.field_docs.insert(
+            field_name.into(),
+            FieldDoc {
+                help: help.into(),
+                example: example.into(),
+            },
+        );
+        self
+    }
+
     /// Apply this template's values to a collection of form fields
     pub fn apply_to_fields(&self, fields: &mut [FormField]) {
         for field in fields.iter_mut() {
@@ -109,8 +191,49 @@ impl AutomationTemplate {
     }
 }
 
+/// Just field values, no structure: applied on top of whatever template is loaded
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InputPreset {
+    pub name: String,
+    pub field_values: BTreeMap<String, String>,
+}
+
+impl InputPreset {
+    /// Capture the fields whose values differ from `base`, or every filled-in field without one
+    pub fn from_fields(
+        name: impl Into<String>,
+        fields: &[FormField],
+        base: Option<&AutomationTemplate>,
+    ) -> Self {
+        let field_values = fields
+            .iter()
+            .filter(|field| match base {
+                Some(template) => template.field_values.get(&field.name) != Some(&field.value),
+                None => !field.value.trim().is_empty(),
+            })
+            .map(|field| (field.name.clone(), field.value.clone()))
+            .collect();
+        Self {
+            name: name.into(),
+            field_values,
+        }
+    }
+
+    /// Set the fields this preset names, returning how many matched
+    pub fn apply_to_fields(&self, fields: &mut [FormField]) -> usize {
+        let mut applied = 0;
+        for field in fields.iter_mut() {
+            if let Some(value) = self.field_values.get(&field.name) {
+                field.value = value.clone();
+                applied += 1;
+            }
+        }
+        applied
+    }
+}
+
 /// Configuration for the target website and form
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WebsiteConfig {
     pub name: String,
     pub url: String,
@@ -121,9 +244,9 @@ pub struct WebsiteConfig {
     pub submit_selector: String,
 }
 
-impl WebsiteConfig {
+impl Default for WebsiteConfig {
     /// Hardcoded config for now - you can replace with your actual website details
-    pub fn default() -> Self {
+    fn default() -> Self {
         Self {
             name: "Company Portal".to_string(),
             url: "https://yourcompany.com".to_string(),
@@ -135,3 +258,61 @@ impl WebsiteConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_preset_layers_on_template() {
+        let template = AutomationTemplate::new("Quick Task", "")
+            .with_field("Project", "Daily")
+            .with_field("Priority", "Medium");
+        let mut fields = vec![
+            FormField::new("Project", "#project", FieldType::Text),
+            FormField::new("Priority", "#priority", FieldType::Select),
+            FormField::new("Email", "#email", FieldType::Email),
+        ];
+        template.apply_to_fields(&mut fields);
+        fields[1].value = "High".to_string();
+        fields[2].value = "ops@example.com".to_string();
+
+        // Only what differs from the template is captured
+        let preset = InputPreset::from_fields("On call", &fields, Some(&template));
+        assert_eq!(
+            preset.field_values.keys().collect::<Vec<_>>(),
+            vec!["Email", "Priority"]
+        );
+        let standalone = InputPreset::from_fields("All", &fields, None);
+        assert_eq!(standalone.field_values.len(), 3);
+
+        let mut fresh = vec![
+            FormField::new("Priority", "#priority", FieldType::Select).with_value("Low"),
+            FormField::new("Notes", "#notes", FieldType::Textarea),
+        ];
+        assert_eq!(preset.apply_to_fields(&mut fresh), 1);
+        assert_eq!(fresh[0].value, "High");
+        assert!(fresh[1].value.is_empty());
+    }
+
+    #[test]
+    fn test_field_docs_are_optional_in_template_files() {
+        // Templates saved before field docs existed still load
+        let old: AutomationTemplate =
+            serde_json::from_str(r#"{"name":"Old","description":"","field_values":{}}"#).unwrap();
+        assert!(old.field_docs.is_empty());
+        assert!(!serde_json::to_string(&old).unwrap().contains("field_docs"));
+
+        let template = AutomationTemplate::new("Deploy", "")
+            .with_field_doc("Ticket", "Jira key of the change", "OPS-1234")
+            .with_field_doc("Window", "", "22:00-23:00");
+        let json = serde_json::to_string(&template).unwrap();
+        let loaded: AutomationTemplate = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, template);
+        assert_eq!(
+            loaded.field_docs["Ticket"].hint(),
+            "Jira key of the change (e.g. OPS-1234)"
+        );
+        assert_eq!(loaded.field_docs["Window"].hint(), "e.g. 22:00-23:00");
+    }
+}
@@ -0,0 +1,73 @@
+/// One occurrence of a search query in a block of text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextMatch {
+    /// Zero-based line number
+    pub line: usize,
+    /// Character (not byte) range within the line
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Every case-insensitive occurrence of `query` in `text`, line by line.
+///
+/// Matches don't span lines and don't overlap; an empty query matches nothing.
+pub fn find_matches(text: &str, query: &str) -> Vec<TextMatch> {
+    let needle: Vec<char> = query.chars().map(fold_case).collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches = Vec::new();
+    for (line_index, line) in text.lines().enumerate() {
+        let haystack: Vec<char> = line.chars().map(fold_case).collect();
+        let mut start = 0;
+        while start + needle.len() <= haystack.len() {
+            if haystack[start..start + needle.len()] == needle[..] {
+                matches.push(TextMatch {
+                    line: line_index,
+                    start,
+                    end: start + needle.len(),
+                });
+                start += needle.len();
+            } else {
+                start += 1;
+            }
+        }
+    }
+    matches
+}
+
+/// Lowercase one character, keeping character offsets stable
+fn fold_case(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_matches_is_case_insensitive_per_line() {
+        let text = "{\n  \"Name\": \"Ünïcode name\",\n  \"id\": \"aaaa\"\n}";
+        let matches = find_matches(text, "NAME");
+        assert_eq!(
+            matches,
+            vec![
+                TextMatch {
+                    line: 1,
+                    start: 3,
+                    end: 7
+                },
+                TextMatch {
+                    line: 1,
+                    start: 19,
+                    end: 23
+                },
+            ]
+        );
+        // Non-overlapping, so "aa" occurs twice in "aaaa"
+        assert_eq!(find_matches(text, "aa").len(), 2);
+        assert!(find_matches(text, "").is_empty());
+        assert!(find_matches(text, "missing").is_empty());
+    }
+}
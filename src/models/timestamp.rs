@@ -0,0 +1,92 @@
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+
+/// How timestamps are shown in logs, run timelines and history
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TimestampFormat {
+    /// Show UTC instead of local time (to match server-side records)
+    #[serde(default)]
+    pub utc: bool,
+    /// Include the date, not just the time of day
+    #[serde(default)]
+    pub show_date: bool,
+}
+
+impl TimestampFormat {
+    /// Render a timestamp; UTC times carry a `Z` suffix so they can't be mistaken for local
+    pub fn format(&self, timestamp: &DateTime<Local>) -> String {
+        let pattern = if self.show_date {
+            "%Y-%m-%d %H:%M:%S"
+        } else {
+            "%H:%M:%S"
+        };
+
+        if self.utc {
+            format!("{}Z", timestamp.with_timezone(&Utc).format(pattern))
+        } else {
+            timestamp.format(pattern).to_string()
+        }
+    }
+
+    /// The same format, always including the date
+    pub fn with_date(self) -> Self {
+        Self {
+            show_date: true,
+            ..self
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match (self.utc, self.show_date) {
+            (false, false) => "Local time",
+            (false, true) => "Local date and time",
+            (true, false) => "UTC time",
+            (true, true) => "UTC date and time",
+        }
+    }
+
+    /// The next format in the cycle: local → local+date → UTC → UTC+date
+    pub fn next(&self) -> Self {
+        match (self.utc, self.show_date) {
+            (utc, false) => Self {
+                utc,
+                show_date: true,
+            },
+            (utc, true) => Self {
+                utc: !utc,
+                show_date: false,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_timestamp_format() {
+        let timestamp = Utc
+            .with_ymd_and_hms(2024, 3, 9, 23, 4, 5)
+            .unwrap()
+            .with_timezone(&Local);
+
+        let utc = TimestampFormat {
+            utc: true,
+            show_date: false,
+        };
+        assert_eq!(utc.format(&timestamp), "23:04:05Z");
+        assert_eq!(utc.with_date().format(&timestamp), "2024-03-09 23:04:05Z");
+        assert_eq!(
+            TimestampFormat::default().format(&timestamp),
+            timestamp.format("%H:%M:%S").to_string()
+        );
+
+        let mut format = TimestampFormat::default();
+        for _ in 0..4 {
+            format = format.next();
+        }
+        assert_eq!(format, TimestampFormat::default());
+    }
+}
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+
+/// Sizes of one request/response exchange, as sent and received
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferStats {
+    pub request_header_count: usize,
+    pub request_header_bytes: usize,
+    pub request_body_bytes: usize,
+    pub response_header_bytes: usize,
+    /// Body size on the wire (compressed when the server used a Content-Encoding),
+    /// when the server sent a Content-Length
+    #[serde(default)]
+    pub response_wire_bytes: Option<usize>,
+}
+
+impl TransferStats {
+    pub fn sent_bytes(&self) -> usize {
+        self.request_header_bytes + self.request_body_bytes
+    }
+
+    /// Header and body bytes received; `body_bytes` is the decoded body size
+    pub fn received_bytes(&self, body_bytes: usize) -> usize {
+        self.response_header_bytes + self.response_wire_bytes.unwrap_or(body_bytes)
+    }
+
+    /// Fold another exchange of the same request into this one (e.g. one more page)
+    pub fn add_exchange(&mut self, other: &TransferStats, body_bytes: usize) {
+        self.request_header_count = other.request_header_count;
+        self.request_header_bytes += other.request_header_bytes;
+        self.request_body_bytes += other.request_body_bytes;
+        self.response_header_bytes += other.response_header_bytes;
+        self.response_wire_bytes = Some(
+            self.response_wire_bytes.unwrap_or(0) + other.response_wire_bytes.unwrap_or(body_bytes),
+        );
+    }
+}
+
+/// Size of headers as written on the wire (`Name: value\r\n` per header)
+pub fn header_bytes<'a>(headers: impl IntoIterator<Item = (&'a str, &'a str)>) -> usize {
+    headers
+        .into_iter()
+        .map(|(name, value)| name.len() + value.len() + 4)
+        .sum()
+}
+
+/// Running totals for everything sent since the app started
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionTransferStats {
+    pub requests: usize,
+    pub failures: usize,
+    pub sent_bytes: u64,
+    pub received_bytes: u64,
+    /// Decoded response body bytes (what the body tab shows)
+    pub response_body_bytes: u64,
+    pub largest_response_bytes: usize,
+    pub total_duration_ms: u64,
+}
+
+impl SessionTransferStats {
+    /// Count a completed exchange
+    pub fn record(&mut self, stats: &TransferStats, body_bytes: usize, duration_ms: u64) {
+        self.requests += 1;
+        self.sent_bytes += stats.sent_bytes() as u64;
+        self.received_bytes += stats.received_bytes(body_bytes) as u64;
+        self.response_body_bytes += body_bytes as u64;
+        self.largest_response_bytes = self.largest_response_bytes.max(body_bytes);
+        self.total_duration_ms += duration_ms;
+    }
+
+    /// Count a request that got no response
+    pub fn record_failure(&mut self) {
+        self.requests += 1;
+        self.failures += 1;
+    }
+
+    pub fn average_duration_ms(&self) -> u64 {
+        let completed = (self.requests - self.failures) as u64;
+        self.total_duration_ms.checked_div(completed).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_transfer_totals() {
+        let compressed = TransferStats {
+            request_header_count: 1,
+            request_header_bytes: header_bytes([("Accept", "application/json")]),
+            request_body_bytes: 10,
+            response_header_bytes: 40,
+            response_wire_bytes: Some(300),
+        };
+        assert_eq!(compressed.request_header_bytes, 26);
+        assert_eq!(compressed.sent_bytes(), 36);
+        assert_eq!(compressed.received_bytes(1000), 340);
+
+        let mut session = SessionTransferStats::default();
+        session.record(&compressed, 1000, 120);
+        session.record(&TransferStats::default(), 50, 80);
+        session.record_failure();
+
+        assert_eq!(session.requests, 3);
+        assert_eq!(session.failures, 1);
+        assert_eq!(session.sent_bytes, 36);
+        assert_eq!(session.received_bytes, 390);
+        assert_eq!(session.response_body_bytes, 1050);
+        assert_eq!(session.largest_response_bytes, 1000);
+        assert_eq!(session.average_duration_ms(), 100);
+    }
+}
@@ -24,6 +24,14 @@ pub struct TreeNode {
 
     /// Depth in the tree (0 = root level)
     pub depth: usize,
+
+    /// Color label of a folder, from its settings
+    #[serde(default)]
+    pub color: Option<FolderColor>,
+
+    /// Folders with a higher weight sort above their siblings
+    #[serde(default)]
+    pub sort_weight: i32,
 }
 
 /// Type of tree node
@@ -35,6 +43,52 @@ pub enum NodeType {
     Template,
 }
 
+/// Color label a folder can be given, so e.g. production folders stand out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FolderColor {
+    Red,
+    Yellow,
+    Green,
+    Cyan,
+    Blue,
+    Magenta,
+}
+
+impl FolderColor {
+    const ALL: [FolderColor; 6] = [
+        FolderColor::Red,
+        FolderColor::Yellow,
+        FolderColor::Green,
+        FolderColor::Cyan,
+        FolderColor::Blue,
+        FolderColor::Magenta,
+    ];
+
+    /// The label after `current`: none → each color → none
+    pub fn cycle(current: Option<FolderColor>) -> Option<FolderColor> {
+        match current {
+            None => Some(Self::ALL[0]),
+            Some(color) => {
+                let index = Self::ALL.iter().position(|c| *c == color).unwrap_or(0);
+                Self::ALL.get(index + 1).copied()
+            }
+        }
+    }
+
+    /// Lowercase name, as stored in folder settings
+    pub fn name(self) -> &'static str {
+        match self {
+            FolderColor::Red => "red",
+            FolderColor::Yellow => "yellow",
+            FolderColor::Green => "green",
+            FolderColor::Cyan => "cyan",
+            FolderColor::Blue => "blue",
+            FolderColor::Magenta => "magenta",
+        }
+    }
+}
+
 impl TreeNode {
     /// Create a new folder node
     pub fn new_folder(name: String, path: String, depth: usize) -> Self {
@@ -46,6 +100,8 @@ impl TreeNode {
             is_expanded: depth == 0, // Expand root level by default
             is_selected: false,
             depth,
+            color: None,
+            sort_weight: 0,
         }
     }
 
@@ -59,6 +115,8 @@ impl TreeNode {
             is_expanded: false, // Templates can't be expanded
             is_selected: false,
             depth,
+            color: None,
+            sort_weight: 0,
         }
     }
 
@@ -81,16 +139,9 @@ impl TreeNode {
         }
     }
 
-    /// Sort children alphabetically (folders first, then templates)
+    /// Sort children (folders first by weight then name, then templates by name)
     pub fn sort_children(&mut self) {
-        self.children.sort_by(|a, b| {
-            // Folders come before templates
-            match (&a.node_type, &b.node_type) {
-                (NodeType::Folder, NodeType::Template) => std::cmp::Ordering::Less,
-                (NodeType::Template, NodeType::Folder) => std::cmp::Ordering::Greater,
-                _ => a.name.cmp(&b.name), // Same type, sort by name
-            }
-        });
+        self.children.sort_by(compare_nodes);
 
         // Recursively sort children
         for child in &mut self.children {
@@ -146,6 +197,26 @@ impl TreeNode {
         }
     }
 
+    /// Recursively collect the paths of expanded folders
+    fn collect_expanded(&self, expanded: &mut Vec<String>) {
+        if self.node_type == NodeType::Folder && self.is_expanded {
+            expanded.push(self.path.clone());
+        }
+        for child in &self.children {
+            child.collect_expanded(expanded);
+        }
+    }
+
+    /// Recursively expand the listed folders and collapse the others
+    fn apply_expanded(&mut self, expanded: &[String]) {
+        if self.node_type == NodeType::Folder {
+            self.is_expanded = expanded.contains(&self.path);
+        }
+        for child in &mut self.children {
+            child.apply_expanded(expanded);
+        }
+    }
+
     /// Get display icon for this node
     pub fn get_icon(&self) -> &'static str {
         match self.node_type {
@@ -182,6 +253,15 @@ pub struct TreeState {
 
     /// Currently focused node index (for keyboard navigation)
     pub focused_index: usize,
+
+    /// Whether a background scan is still streaming nodes into the tree
+    pub is_loading: bool,
+}
+
+impl Default for TreeState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl TreeState {
@@ -191,6 +271,7 @@ impl TreeState {
             roots: Vec::new(),
             selected_path: None,
             focused_index: 0,
+            is_loading: false,
         }
     }
 
@@ -221,6 +302,81 @@ impl TreeState {
         state
     }
 
+    /// Create an empty tree that is waiting for a background scan
+    pub fn loading() -> Self {
+        Self {
+            is_loading: true,
+            ..Self::new()
+        }
+    }
+
+    /// Merge a folder discovered by a background scan into the tree.
+    ///
+    /// Keeps the focused node in place (by path) as new nodes stream in.
+    pub fn add_discovered_folder(&mut self, folder_path: &str, item_names: &[String]) {
+        let focused_path = self.get_focused_node().map(|node| node.path.clone());
+
+        self.add_folder_path(folder_path);
+        if let Some(folder_node) = self.find_folder_mut(folder_path) {
+            let existing: Vec<String> = folder_node
+                .children
+                .iter()
+                .map(|child| child.name.clone())
+                .collect();
+            for name in item_names {
+                if !existing.contains(name) {
+                    self.add_template(folder_path, name);
+                }
+            }
+        }
+
+        // Re-sort the new folder and its parent level only
+        if let Some(folder_node) = self.find_folder_mut(folder_path) {
+            folder_node.children.sort_by(compare_nodes);
+        }
+        self.sort_siblings_of(folder_path);
+        self.refocus(focused_path);
+    }
+
+    /// Give a folder its color label and sort weight, moving it among its siblings
+    pub fn set_folder_style(&mut self, folder_path: &str, color: Option<FolderColor>, weight: i32) {
+        let focused_path = self.get_focused_node().map(|node| node.path.clone());
+        let Some(folder_node) = self.find_folder_mut(folder_path) else {
+            return;
+        };
+        if folder_node.color == color && folder_node.sort_weight == weight {
+            return;
+        }
+        folder_node.color = color;
+        folder_node.sort_weight = weight;
+        self.sort_siblings_of(folder_path);
+        self.refocus(focused_path);
+    }
+
+    /// Re-sort the level `path` sits on
+    fn sort_siblings_of(&mut self, path: &str) {
+        match path.rsplit_once('/') {
+            Some((parent_path, _)) => {
+                if let Some(parent) = self.find_folder_mut(parent_path) {
+                    parent.children.sort_by(compare_nodes);
+                }
+            }
+            None => self.roots.sort_by(compare_nodes),
+        }
+    }
+
+    /// Keep focus on the node at `path` after nodes moved around it
+    fn refocus(&mut self, path: Option<String>) {
+        if let Some(path) = path
+            && let Some(index) = self
+                .get_visible_nodes()
+                .iter()
+                .position(|node| node.path == path)
+        {
+            self.focused_index = index;
+        }
+    }
+
     /// Add a folder path to the tree
     fn add_folder_path(&mut self, path: &str) {
         let parts: Vec<&str> = path.split('/').collect();
@@ -289,6 +445,22 @@ impl TreeState {
         visible
     }
 
+    /// Paths of the folders currently expanded
+    pub fn expanded_folders(&self) -> Vec<String> {
+        let mut expanded = Vec::new();
+        for root in &self.roots {
+            root.collect_expanded(&mut expanded);
+        }
+        expanded
+    }
+
+    /// Expand exactly the listed folders, collapsing the rest
+    pub fn set_expanded_folders(&mut self, expanded: &[String]) {
+        for root in &mut self.roots {
+            root.apply_expanded(expanded);
+        }
+    }
+
     /// Toggle expansion of a node
     pub fn toggle_expansion(&mut self, path: &str) {
         for root in &mut self.roots {
@@ -321,6 +493,29 @@ impl TreeState {
         }
     }
 
+    /// Expand the ancestors of `path` and move focus onto it; false if it isn't in the tree
+    pub fn reveal(&mut self, path: &str) -> bool {
+        let parts: Vec<&str> = path.split('/').collect();
+        for depth in 1..parts.len() {
+            let ancestor = parts[..depth].join("/");
+            if let Some(node) = self.find_folder_mut(&ancestor) {
+                node.is_expanded = true;
+            }
+        }
+
+        match self
+            .get_visible_nodes()
+            .iter()
+            .position(|node| node.path == path)
+        {
+            Some(index) => {
+                self.focused_index = index;
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Move focus up/down for keyboard navigation
     pub fn move_focus(&mut self, direction: FocusDirection) {
         let visible_nodes = self.get_visible_nodes();
@@ -349,6 +544,19 @@ impl TreeState {
     }
 }
 
+/// Ordering used for tree nodes: folders first (heaviest first), then by name
+fn compare_nodes(a: &TreeNode, b: &TreeNode) -> std::cmp::Ordering {
+    match (&a.node_type, &b.node_type) {
+        (NodeType::Folder, NodeType::Template) => std::cmp::Ordering::Less,
+        (NodeType::Template, NodeType::Folder) => std::cmp::Ordering::Greater,
+        // Same type: pinned folders first, then by name
+        _ => b
+            .sort_weight
+            .cmp(&a.sort_weight)
+            .then_with(|| a.name.cmp(&b.name)),
+    }
+}
+
 /// Direction for focus movement
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FocusDirection {
@@ -387,4 +595,65 @@ mod tests {
         assert_eq!(state.roots[0].children[0].children.len(), 1);
         assert_eq!(state.roots[0].children[0].children[0].name, "Email");
     }
+
+    #[test]
+    fn test_add_discovered_folder_streams_in_order() {
+        let mut state = TreeState::loading();
+        state.add_discovered_folder("Reports", &["Weekly".to_string()]);
+        state.add_discovered_folder("Customer", &[]);
+        state.add_discovered_folder("Customer/Add", &["Email".to_string()]);
+        // Re-reporting a folder must not duplicate its items
+        state.add_discovered_folder("Reports", &["Weekly".to_string()]);
+
+        assert!(state.is_loading);
+        assert_eq!(state.roots.len(), 2);
+        assert_eq!(state.roots[0].name, "Customer");
+        assert_eq!(state.roots[0].children[0].name, "Add");
+        assert_eq!(state.roots[0].children[0].children[0].name, "Email");
+        assert_eq!(state.roots[1].children.len(), 1);
+    }
+
+    #[test]
+    fn test_expanded_folders_roundtrip() {
+        let mut state = TreeState::loading();
+        state.add_discovered_folder("Customer", &[]);
+        state.add_discovered_folder("Customer/Add", &["Email".to_string()]);
+        state.add_discovered_folder("Reports", &["Weekly".to_string()]);
+        state.toggle_expansion("Customer/Add");
+        state.toggle_expansion("Reports");
+        let expanded = state.expanded_folders();
+        assert_eq!(expanded, ["Customer", "Customer/Add"]);
+
+        // A rebuilt tree starts with only the top level expanded
+        let mut rebuilt = TreeState::loading();
+        rebuilt.add_discovered_folder("Customer", &[]);
+        rebuilt.add_discovered_folder("Customer/Add", &["Email".to_string()]);
+        rebuilt.add_discovered_folder("Reports", &["Weekly".to_string()]);
+        rebuilt.set_expanded_folders(&expanded);
+        assert_eq!(rebuilt.expanded_folders(), expanded);
+        assert_eq!(rebuilt.get_visible_nodes().len(), 4);
+    }
+
+    #[test]
+    fn test_weighted_folders_sort_above_alphabetical_order() {
+        let mut state = TreeState::loading();
+        state.add_discovered_folder("Alpha", &[]);
+        state.add_discovered_folder("Prod", &[]);
+        state.add_discovered_folder("Zeta", &[]);
+        state.focused_index = 0;
+
+        state.set_folder_style("Zeta", Some(FolderColor::Red), 5);
+        let names: Vec<&str> = state.roots.iter().map(|node| node.name.as_str()).collect();
+        assert_eq!(names, ["Zeta", "Alpha", "Prod"]);
+        assert_eq!(state.roots[0].color, Some(FolderColor::Red));
+        // Focus follows the node it was on
+        assert_eq!(state.get_focused_node().unwrap().name, "Alpha");
+
+        state.set_folder_style("Alpha", None, -1);
+        let names: Vec<&str> = state.roots.iter().map(|node| node.name.as_str()).collect();
+        assert_eq!(names, ["Zeta", "Prod", "Alpha"]);
+
+        assert_eq!(FolderColor::cycle(None), Some(FolderColor::Red));
+        assert_eq!(FolderColor::cycle(Some(FolderColor::Magenta)), None);
+    }
 }
@@ -0,0 +1,144 @@
+/// A step of the guided tutorial, completed by doing the real thing in the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TutorialStep {
+    CreateFolder,
+    SaveTemplate,
+    ImportCollection,
+    SendRequest,
+}
+
+/// Something the user did that a tutorial step waits for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TutorialEvent {
+    FolderCreated,
+    TemplateSaved,
+    CollectionImported,
+    ResponseReceived,
+}
+
+impl TutorialStep {
+    pub const ALL: [TutorialStep; 4] = [
+        TutorialStep::CreateFolder,
+        TutorialStep::SaveTemplate,
+        TutorialStep::ImportCollection,
+        TutorialStep::SendRequest,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            TutorialStep::CreateFolder => "Create a folder",
+            TutorialStep::SaveTemplate => "Save a template",
+            TutorialStep::ImportCollection => "Import a collection",
+            TutorialStep::SendRequest => "Send a request",
+        }
+    }
+
+    pub fn instructions(&self) -> &'static [&'static str] {
+        match self {
+            TutorialStep::CreateFolder => &[
+                "Switch to Automation mode, focus the templates tree and",
+                "open the new folder dialog. Type a name and confirm.",
+            ],
+            TutorialStep::SaveTemplate => &[
+                "Fill in a few form fields, then save them as a template -",
+                "pick the folder you just made and confirm.",
+            ],
+            TutorialStep::ImportCollection => &[
+                "Switch to HTTP mode and open the import dialog. Choose a",
+                "format (Postman, OpenAPI, ...), enter a file path, confirm.",
+            ],
+            TutorialStep::SendRequest => &[
+                "Select a request in the tree to load it into the editor",
+                "and send it. Any response completes the tutorial.",
+            ],
+        }
+    }
+
+    /// Keys the step needs, highlighted in the tutorial panel
+    pub fn keys(&self) -> &'static [&'static str] {
+        match self {
+            TutorialStep::CreateFolder => &["F1", "F5", "Ctrl+F", "Enter"],
+            TutorialStep::SaveTemplate => &["Ctrl+N", "Tab", "Enter"],
+            TutorialStep::ImportCollection => &["F4", "Ctrl+I", "F8", "Enter"],
+            TutorialStep::SendRequest => &["Enter", "F3", "Space"],
+        }
+    }
+
+    /// The event that completes this step
+    fn completed_by(&self) -> TutorialEvent {
+        match self {
+            TutorialStep::CreateFolder => TutorialEvent::FolderCreated,
+            TutorialStep::SaveTemplate => TutorialEvent::TemplateSaved,
+            TutorialStep::ImportCollection => TutorialEvent::CollectionImported,
+            TutorialStep::SendRequest => TutorialEvent::ResponseReceived,
+        }
+    }
+}
+
+/// Progress through the tutorial steps, in order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tutorial {
+    /// Index into `TutorialStep::ALL`; equal to its length once finished
+    step: usize,
+}
+
+impl Tutorial {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The step waiting to be done, `None` once all are complete
+    pub fn current(&self) -> Option<TutorialStep> {
+        TutorialStep::ALL.get(self.step).copied()
+    }
+
+    /// 1-based number of the current step
+    pub fn position(&self) -> usize {
+        (self.step + 1).min(TutorialStep::ALL.len())
+    }
+
+    pub fn finished(&self) -> bool {
+        self.current().is_none()
+    }
+
+    /// Advance when `event` is what the current step waits for; other events
+    /// are ignored so steps can't be completed out of order
+    pub fn record(&mut self, event: TutorialEvent) -> Option<TutorialStep> {
+        let step = self.current()?;
+        if step.completed_by() != event {
+            return None;
+        }
+        self.step += 1;
+        Some(step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tutorial_advances_only_on_the_expected_event() {
+        let mut tutorial = Tutorial::new();
+        assert_eq!(tutorial.current(), Some(TutorialStep::CreateFolder));
+
+        // Sending before the earlier steps are done doesn't skip ahead
+        assert_eq!(tutorial.record(TutorialEvent::ResponseReceived), None);
+        assert_eq!(tutorial.position(), 1);
+
+        assert_eq!(
+            tutorial.record(TutorialEvent::FolderCreated),
+            Some(TutorialStep::CreateFolder)
+        );
+        assert_eq!(tutorial.current(), Some(TutorialStep::SaveTemplate));
+        tutorial.record(TutorialEvent::TemplateSaved);
+        tutorial.record(TutorialEvent::CollectionImported);
+        assert_eq!(tutorial.position(), 4);
+        assert!(!tutorial.finished());
+
+        tutorial.record(TutorialEvent::ResponseReceived);
+        assert!(tutorial.finished());
+        assert_eq!(tutorial.position(), 4);
+        assert_eq!(tutorial.record(TutorialEvent::FolderCreated), None);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::app::AppMessage;
 use crate::models::{FormField, LogLevel, WebsiteConfig};
 use crate::modes::automation::Credentials;
+use crate::services::process_registry::ProcessRegistry;
 use anyhow::{Context, Result};
 use include_dir::{Dir, include_dir};
 use serde_json;
@@ -13,14 +14,28 @@ use tokio::sync::mpsc;
 // Embed the entire Python project directory in the binary
 static PYTHON_PROJECT: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/scripts");
 
+/// Entry scripts looked for in the embedded project, in order of preference
+const MAIN_SCRIPTS: [&str; 2] = ["automation_script.py", "main.py"];
+
 /// Browser automation engine that runs embedded Python projects
 pub struct BrowserEngine {
     message_sender: mpsc::UnboundedSender<AppMessage>,
+    processes: ProcessRegistry,
 }
 
 impl BrowserEngine {
+    /// Name of the script templates are run with in this build
+    pub fn main_script_name() -> Option<&'static str> {
+        MAIN_SCRIPTS
+            .into_iter()
+            .find(|name| PYTHON_PROJECT.get_file(name).is_some())
+    }
+
     pub fn new(message_sender: mpsc::UnboundedSender<AppMessage>) -> Self {
-        Self { message_sender }
+        Self {
+            message_sender,
+            processes: ProcessRegistry::default(),
+        }
     }
 
     /// Run the embedded Python automation project
@@ -49,10 +64,7 @@ impl BrowserEngine {
 
     /// Extract the embedded Python project to a temporary directory
     async fn extract_python_project(&self) -> Result<std::path::PathBuf> {
-        use std::env;
-
-        let temp_dir = env::temp_dir();
-        let project_dir = temp_dir.join(format!("automation_project_{}", std::process::id()));
+        let project_dir = self.processes.project_dir();
 
         self.log_progress("📦 Extracting embedded Python project...")
             .await;
@@ -132,18 +144,13 @@ impl BrowserEngine {
         self.log_progress("🐍 Launching Python automation...").await;
 
         // Find the main script (automation_script.py or main.py)
-        let main_script = project_dir.join("automation_script.py");
-        let alt_script = project_dir.join("main.py");
-
-        let script_path = if main_script.exists() {
-            main_script
-        } else if alt_script.exists() {
-            alt_script
-        } else {
-            return Err(anyhow::anyhow!(
-                "No main script found (automation_script.py or main.py)"
-            ));
-        };
+        let script_path = MAIN_SCRIPTS
+            .iter()
+            .map(|name| project_dir.join(name))
+            .find(|path| path.exists())
+            .ok_or_else(|| {
+                anyhow::anyhow!("No main script found (automation_script.py or main.py)")
+            })?;
 
         self.log_progress(format!(
             "📄 Running: {}",
@@ -152,16 +159,35 @@ impl BrowserEngine {
         .await;
 
         // Spawn Python process with the project directory as working directory
-        let mut child = Command::new("python3")
+        let mut command = Command::new("python3");
+        command
             .arg(&script_path)
             .arg("--json-input")
             .current_dir(project_dir) // Important: Set working directory for imports
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        // Own process group, so the browser drivers Python starts can be killed with it
+        #[cfg(unix)]
+        command.process_group(0);
+        let mut child = command
             .spawn()
             .context("Failed to spawn Python process. Make sure python3 is installed.")?;
 
+        // Tracked until it exits, so a crash of this app doesn't leave it running
+        let pid = child.id();
+        if let Some(pid) = pid
+            && let Err(e) =
+                self.processes
+                    .register(pid, &project_dir.display().to_string(), Some(project_dir))
+        {
+            let _ = self.message_sender.send(AppMessage::Log(
+                LogLevel::Warn,
+                format!("Failed to track the Python process: {:#}", e),
+            ));
+        }
+
         // Send data to Python via stdin
         if let Some(mut stdin) = child.stdin.take() {
             use tokio::io::AsyncWriteExt;
@@ -192,10 +218,11 @@ impl BrowserEngine {
         };
 
         // Wait for Python process to complete
-        let status = child
-            .wait()
-            .await
-            .context("Failed to wait for Python process")?;
+        let status = child.wait().await;
+        if let Some(pid) = pid {
+            let _ = self.processes.unregister(pid);
+        }
+        let status = status.context("Failed to wait for Python process")?;
 
         // Wait for output processing to complete
         if let Some(handle) = stdout_handle {
@@ -4,5 +4,5 @@ pub mod templates;
 
 // Re-export for convenience
 pub use browser::BrowserEngine;
-pub use state::{AutomationState, Credentials};
+pub use state::{AutomationState, Credentials, QueuedRun, RunStep};
 pub use templates::TemplateManager;
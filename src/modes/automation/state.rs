@@ -1,5 +1,6 @@
+use crate::models::stepper::NumberRange;
 use crate::models::{AutomationTemplate, FieldType, FormField, WebsiteConfig};
-use std::collections::HashMap;
+use chrono::{DateTime, Local};
 
 /// Current state of the automation mode
 #[derive(Debug, Clone)]
@@ -21,6 +22,37 @@ pub struct AutomationState {
 
     /// Website configuration
     pub website_config: WebsiteConfig,
+
+    /// Progress steps reported by the current (or last) run
+    pub run_steps: Vec<RunStep>,
+
+    /// When the current (or last) run started
+    pub run_started_at: Option<DateTime<Local>>,
+
+    /// Runs waiting for the current one to finish, in the order they will start
+    pub run_queue: Vec<QueuedRun>,
+
+    /// Counter giving each queued run its id
+    next_queued_id: u64,
+}
+
+/// An automation run started while another was running, waiting its turn
+#[derive(Debug, Clone)]
+pub struct QueuedRun {
+    pub id: u64,
+    /// Template the form came from, or the form's first value
+    pub label: String,
+    /// The form as it was when the run was queued
+    pub fields: Vec<FormField>,
+    pub website_config: WebsiteConfig,
+    pub queued_at: DateTime<Local>,
+}
+
+/// A progress step reported during an automation run
+#[derive(Debug, Clone)]
+pub struct RunStep {
+    pub at: DateTime<Local>,
+    pub message: String,
 }
 
 #[derive(Debug, Clone)]
@@ -29,6 +61,12 @@ pub struct Credentials {
     pub password: String,
 }
 
+impl Default for AutomationState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl AutomationState {
     pub fn new() -> Self {
         Self {
@@ -38,6 +76,10 @@ impl AutomationState {
             focused_field: 0,
             is_running: false,
             website_config: WebsiteConfig::default(),
+            run_steps: Vec::new(),
+            run_started_at: None,
+            run_queue: Vec::new(),
+            next_queued_id: 1,
         }
     }
 
@@ -69,6 +111,14 @@ impl AutomationState {
             // Optional text input example
             FormField::new("Contact Email", "#contact_email", FieldType::Email)
                 .with_required(false), // This will show as "(optional)"
+            // Number stepper example (+/- within the range)
+            FormField::new("Team Size", "#team_size", FieldType::Number)
+                .with_required(false)
+                .with_number_range(NumberRange::new(1, 50)),
+            // Checkbox example (Space toggles)
+            FormField::new("Notify Team", "#notify_team", FieldType::Checkbox)
+                .with_required(false)
+                .with_value("false"),
         ]
     }
 
@@ -80,7 +130,17 @@ impl AutomationState {
                 .with_field("Department", "Engineering")
                 .with_field("Priority Level", "Medium")
                 .with_field("Description", "Standard daily task submission")
-                .with_field("Contact Email", "user@company.com"),
+                .with_field("Contact Email", "user@company.com")
+                .with_field_doc(
+                    "Priority Level",
+                    "How soon the team should pick this up",
+                    "Low, Medium or High",
+                )
+                .with_field_doc(
+                    "Contact Email",
+                    "Who gets the confirmation",
+                    "user@company.com",
+                ),
             AutomationTemplate::new("Urgent Request", "High priority request template")
                 .with_field("Project Name", "Urgent Fix")
                 .with_field("Department", "Engineering")
@@ -98,10 +158,10 @@ impl AutomationState {
 
     /// Apply the currently selected template to the form fields
     pub fn apply_selected_template(&mut self) {
-        if let Some(template_index) = self.selected_template {
-            if let Some(template) = self.templates.get(template_index) {
-                template.apply_to_fields(&mut self.fields);
-            }
+        if let Some(template_index) = self.selected_template
+            && let Some(template) = self.templates.get(template_index)
+        {
+            template.apply_to_fields(&mut self.fields);
         }
     }
 
@@ -155,6 +215,58 @@ impl AutomationState {
         self.is_running = running;
     }
 
+    /// Clear the step timeline for a new run
+    pub fn start_run_timeline(&mut self) {
+        self.run_steps.clear();
+        self.run_started_at = Some(Local::now());
+    }
+
+    /// Add a step to the run timeline
+    pub fn record_run_step(&mut self, message: impl Into<String>) {
+        self.run_steps.push(RunStep {
+            at: Local::now(),
+            message: message.into(),
+        });
+    }
+
+    /// Queue a run of the current form, returning its place in the queue (1-based)
+    pub fn queue_run(&mut self, label: impl Into<String>) -> usize {
+        self.run_queue.push(QueuedRun {
+            id: self.next_queued_id,
+            label: label.into(),
+            fields: self.fields.clone(),
+            website_config: self.website_config.clone(),
+            queued_at: Local::now(),
+        });
+        self.next_queued_id += 1;
+        self.run_queue.len()
+    }
+
+    /// Take the run that starts next
+    pub fn take_next_queued_run(&mut self) -> Option<QueuedRun> {
+        (!self.run_queue.is_empty()).then(|| self.run_queue.remove(0))
+    }
+
+    /// Move a queued run one place earlier or later, returning where it ended up
+    pub fn move_queued_run(&mut self, index: usize, later: bool) -> usize {
+        let target = if later {
+            index + 1
+        } else {
+            index.wrapping_sub(1)
+        };
+        if index < self.run_queue.len() && target < self.run_queue.len() {
+            self.run_queue.swap(index, target);
+            target
+        } else {
+            index
+        }
+    }
+
+    /// Remove a queued run before it starts
+    pub fn cancel_queued_run(&mut self, index: usize) -> Option<QueuedRun> {
+        (index < self.run_queue.len()).then(|| self.run_queue.remove(index))
+    }
+
     /// Get validation errors for display
     pub fn get_validation_errors(&self) -> Vec<String> {
         let mut errors = Vec::new();
@@ -168,3 +280,35 @@ impl AutomationState {
         errors
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(state: &AutomationState) -> Vec<&str> {
+        state
+            .run_queue
+            .iter()
+            .map(|run| run.label.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_run_queue_reorders_and_cancels() {
+        let mut state = AutomationState::new();
+        assert_eq!(state.queue_run("first"), 1);
+        assert_eq!(state.queue_run("second"), 2);
+        assert_eq!(state.queue_run("third"), 3);
+
+        // Moving past either end leaves the queue as it was
+        assert_eq!(state.move_queued_run(0, false), 0);
+        assert_eq!(state.move_queued_run(2, true), 2);
+        assert_eq!(state.move_queued_run(2, false), 1);
+        assert_eq!(labels(&state), vec!["first", "third", "second"]);
+
+        assert_eq!(state.cancel_queued_run(1).unwrap().label, "third");
+        assert!(state.cancel_queued_run(5).is_none());
+        assert_eq!(state.take_next_queued_run().unwrap().label, "first");
+        assert_eq!(labels(&state), vec!["second"]);
+    }
+}
@@ -6,6 +6,12 @@ pub struct TemplateManager {
     templates: Vec<AutomationTemplate>,
 }
 
+impl Default for TemplateManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TemplateManager {
     pub fn new() -> Self {
         Self {
@@ -0,0 +1,334 @@
+use crate::models::http_client::{AssertionResult, HttpResponse};
+use crate::services::json_filter::apply_json_filter;
+use crate::services::json_schema::validate_schema;
+use crate::services::storage_migration::expand_user_path;
+use serde_json::Value;
+
+/// Longest actual value echoed back in a result message
+const MAX_MESSAGE_VALUE_CHARS: usize = 80;
+
+/// Check every assertion in `tests` against `response`.
+///
+/// One assertion per line, blank lines and `#` comments are skipped:
+///
+/// ```text
+/// status == 200                        # ==, !=, <, <=, >, >= or a class like 2xx
+/// header Content-Type contains json
+/// header X-Request-Id exists           # or `missing`
+/// json $.data.id == 42                 # JSONPath / jq filter, strings may be quoted
+/// body contains "ok"
+/// time < 500                           # milliseconds
+/// size <= 1048576                      # body bytes
+/// schema ~/schemas/user.json           # JSON Schema file, or inline: schema {"type": "object"}
+/// ```
+///
+/// A `schema` line reports each violation as its own failed result.
+pub fn evaluate_assertions(tests: &str, response: &HttpResponse) -> Vec<AssertionResult> {
+    tests
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .flat_map(|line| match next_word(line) {
+            ("schema", source) => check_schema(line, source, response),
+            _ => vec![evaluate_single(line, response)],
+        })
+        .collect()
+}
+
+fn evaluate_single(line: &str, response: &HttpResponse) -> AssertionResult {
+    let (passed, message) = match evaluate_line(line, response) {
+        Ok(outcome) => outcome,
+        Err(error) => (false, error),
+    };
+    AssertionResult {
+        assertion: line.to_string(),
+        passed,
+        message,
+    }
+}
+
+/// Validate the response body against the schema a `schema` line points at
+fn check_schema(line: &str, source: &str, response: &HttpResponse) -> Vec<AssertionResult> {
+    let inline = source.starts_with('{');
+    let label = if inline {
+        "schema (inline)".to_string()
+    } else {
+        line.to_string()
+    };
+    let failure = |message: String| {
+        vec![AssertionResult {
+            assertion: label.clone(),
+            passed: false,
+            message,
+        }]
+    };
+
+    let schema_text = if inline {
+        source.to_string()
+    } else if source.is_empty() {
+        return failure("Expected a schema file or inline JSON after 'schema'".to_string());
+    } else {
+        match std::fs::read_to_string(expand_user_path(source)) {
+            Ok(text) => text,
+            Err(e) => return failure(format!("Cannot read {}: {}", source, e)),
+        }
+    };
+    let schema: Value = match serde_json::from_str(&schema_text) {
+        Ok(schema) => schema,
+        Err(e) => return failure(format!("Invalid schema JSON: {}", e)),
+    };
+    let body: Value = match serde_json::from_str(&response.body) {
+        Ok(body) => body,
+        Err(_) => return failure("response body is not JSON".to_string()),
+    };
+
+    let violations = validate_schema(&schema, &body);
+    if violations.is_empty() {
+        return vec![AssertionResult {
+            assertion: label,
+            passed: true,
+            message: "matches schema".to_string(),
+        }];
+    }
+    violations
+        .into_iter()
+        .map(|violation| AssertionResult {
+            assertion: format!("schema {}", violation.path),
+            passed: false,
+            message: violation.message,
+        })
+        .collect()
+}
+
+/// Evaluate one assertion, returning whether it passed and what was found
+fn evaluate_line(line: &str, response: &HttpResponse) -> Result<(bool, String), String> {
+    let subject = next_word(line).0;
+    let (actual, rest) = resolve_subject(line, response)?;
+
+    let (operator, expected) = next_word(rest);
+    let expected = expected.trim();
+
+    match operator {
+        "exists" => Ok((actual.is_some(), found_message(actual.as_ref()))),
+        "missing" => Ok((actual.is_none(), found_message(actual.as_ref()))),
+        "" => Err("Expected an operator (==, !=, <, <=, >, >=, contains, exists, missing)".into()),
+        _ => {
+            if expected.is_empty() {
+                return Err(format!("Expected a value after '{}'", operator));
+            }
+            let Some(actual) = actual else {
+                return Ok((false, "not found".to_string()));
+            };
+            let passed = compare(&actual, operator, expected, subject == "status")?;
+            Ok((passed, found_message(Some(&actual))))
+        }
+    }
+}
+
+/// Read the value a line starts with (`status`, `header NAME`, `json PATH`, ...),
+/// returning it (None when absent) and the rest of the line
+pub(crate) fn resolve_subject<'a>(
+    line: &'a str,
+    response: &HttpResponse,
+) -> Result<(Option<Value>, &'a str), String> {
+    let (subject, rest) = next_word(line);
+
+    Ok(match subject {
+        "status" => (Some(Value::from(response.status_code)), rest),
+        "time" => (Some(Value::from(response.duration_ms)), rest),
+        "size" => (Some(Value::from(response.body.len())), rest),
+        "body" => (Some(Value::String(response.body.clone())), rest),
+        "header" => {
+            let (name, rest) = next_word(rest);
+            if name.is_empty() {
+                return Err("Expected a header name after 'header'".to_string());
+            }
+            let value = response
+                .header(name)
+                .map(|value| Value::String(value.to_string()));
+            (value, rest)
+        }
+        "json" => {
+            let (path, rest) = next_word(rest);
+            if path.is_empty() {
+                return Err("Expected a JSON path after 'json'".to_string());
+            }
+            let value = apply_json_filter(&response.body, path).map_err(|e| e.to_string())?;
+            // A path that matches nothing comes back as an empty array
+            let found = !matches!(&value, Value::Null)
+                && !matches!(&value, Value::Array(items) if items.is_empty());
+            (found.then_some(value), rest)
+        }
+        "" => return Err("Empty assertion".to_string()),
+        other => {
+            return Err(format!(
+                "Unknown subject '{}' (expected status, header, json, body, time, size or schema)",
+                other
+            ));
+        }
+    })
+}
+
+fn compare(
+    actual: &Value,
+    operator: &str,
+    expected: &str,
+    is_status: bool,
+) -> Result<bool, String> {
+    match operator {
+        "==" => Ok(values_equal(actual, expected, is_status)),
+        "!=" => Ok(!values_equal(actual, expected, is_status)),
+        "<" | "<=" | ">" | ">=" => {
+            let actual_number = as_number(actual)
+                .ok_or_else(|| format!("{} is not a number", display_value(actual)))?;
+            let expected_number = expected
+                .parse::<f64>()
+                .map_err(|_| format!("'{}' is not a number", expected))?;
+            Ok(match operator {
+                "<" => actual_number < expected_number,
+                "<=" => actual_number <= expected_number,
+                ">" => actual_number > expected_number,
+                _ => actual_number >= expected_number,
+            })
+        }
+        "contains" => Ok(match actual {
+            Value::Array(items) => items.iter().any(|item| values_equal(item, expected, false)),
+            _ => display_value(actual).contains(unquote(expected)),
+        }),
+        other => Err(format!("Unknown operator '{}'", other)),
+    }
+}
+
+/// Compare against the expected text, read as JSON when it parses (`42`, `true`, `"a b"`)
+fn values_equal(actual: &Value, expected: &str, is_status: bool) -> bool {
+    // `2xx` matches any status in that class
+    if is_status
+        && let Some(class) = expected.strip_suffix("xx")
+        && let (Ok(class), Some(code)) = (class.parse::<u64>(), actual.as_u64())
+    {
+        return code / 100 == class;
+    }
+
+    match serde_json::from_str::<Value>(expected) {
+        Ok(expected_value) if &expected_value == actual => true,
+        // Header values and other strings compare by their text (`42` matches "42")
+        _ => display_value(actual) == unquote(expected),
+    }
+}
+
+fn as_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => text.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Strings without their JSON quotes, everything else as compact JSON
+pub(crate) fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn unquote(text: &str) -> &str {
+    text.strip_prefix('"')
+        .and_then(|inner| inner.strip_suffix('"'))
+        .unwrap_or(text)
+}
+
+fn found_message(actual: Option<&Value>) -> String {
+    match actual {
+        Some(value) => {
+            let text = display_value(value);
+            if text.chars().count() > MAX_MESSAGE_VALUE_CHARS {
+                let shortened: String = text.chars().take(MAX_MESSAGE_VALUE_CHARS).collect();
+                format!("got {}…", shortened)
+            } else {
+                format!("got {}", text)
+            }
+        }
+        None => "not found".to_string(),
+    }
+}
+
+/// Split off the first whitespace-separated word
+fn next_word(text: &str) -> (&str, &str) {
+    let text = text.trim_start();
+    match text.split_once(char::is_whitespace) {
+        Some((word, rest)) => (word, rest.trim_start()),
+        None => (text, ""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::http_client::HttpHeader;
+    use crate::models::transfer::TransferStats;
+
+    #[test]
+    fn test_evaluate_assertions() {
+        let response = HttpResponse {
+            status_code: 201,
+            status_text: "Created".to_string(),
+            headers: vec![HttpHeader::new("Content-Type", "application/json")],
+            body: r#"{"data": {"id": 42, "name": "Alice", "tags": ["a", "b"]}}"#.to_string(),
+            body_bytes: Vec::new(),
+            content_type: "application/json".to_string(),
+            duration_ms: 120,
+            served_from_cache: false,
+            connection: Default::default(),
+            transfer: TransferStats::default(),
+            assertions: Vec::new(),
+            extracted: Vec::new(),
+            truncation: None,
+            timing: Default::default(),
+        };
+        let tests = "\
+# comments and blank lines are skipped
+
+status == 2xx
+status < 300
+header content-type contains json
+header X-Request-Id missing
+json $.data.id == 42
+json $.data.name == \"Alice\"
+json $.data.tags contains b
+json $.data.missing exists
+time < 100
+body contains Alice
+status equals 201";
+
+        let results = evaluate_assertions(tests, &response);
+        let outcomes: Vec<bool> = results.iter().map(|result| result.passed).collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                true, true, true, true, true, true, true, false, false, true, false
+            ]
+        );
+        assert_eq!(results[4].message, "got 42");
+        assert_eq!(results[7].message, "not found");
+        assert!(results[10].message.contains("Unknown operator"));
+
+        let schema = r#"schema {"type": "object", "properties": {"data": {"required": ["id", "email"], "properties": {"id": {"type": "string"}}}}}"#;
+        let results = evaluate_assertions(schema, &response);
+        let failures: Vec<(&str, &str)> = results
+            .iter()
+            .filter(|result| !result.passed)
+            .map(|result| (result.assertion.as_str(), result.message.as_str()))
+            .collect();
+        assert_eq!(
+            failures,
+            vec![
+                ("schema $.data", "missing required property 'email'"),
+                ("schema $.data.id", "expected string, got integer"),
+            ]
+        );
+        let results = evaluate_assertions(r#"schema {"type": "object"}"#, &response);
+        assert!(results[0].passed);
+        assert_eq!(results[0].assertion, "schema (inline)");
+    }
+}
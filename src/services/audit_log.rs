@@ -0,0 +1,166 @@
+use crate::models::config::{data_directory, is_read_only_storage};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Kinds of destructive or sensitive actions worth a permanent record
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    Delete,
+    Rename,
+    Overwrite,
+    Restore,
+    CredentialsStored,
+    CredentialsCleared,
+    ProductionSend,
+}
+
+impl AuditAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AuditAction::Delete => "delete",
+            AuditAction::Rename => "rename",
+            AuditAction::Overwrite => "overwrite",
+            AuditAction::Restore => "restore",
+            AuditAction::CredentialsStored => "credentials stored",
+            AuditAction::CredentialsCleared => "credentials cleared",
+            AuditAction::ProductionSend => "prod send",
+        }
+    }
+}
+
+/// One line of the audit log
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub at: DateTime<Local>,
+    pub action: AuditAction,
+    /// Items the action touched, e.g. the old and new path of a rename
+    #[serde(default)]
+    pub paths: Vec<String>,
+    #[serde(default)]
+    pub detail: String,
+}
+
+impl AuditEntry {
+    pub fn new(action: AuditAction, paths: Vec<String>, detail: impl Into<String>) -> Self {
+        Self {
+            at: Local::now(),
+            action,
+            paths,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Append-only record of deletes, renames, credential changes and production
+/// sends, one JSON object per line.
+///
+/// Nothing in the app rewrites or prunes this file.
+#[derive(Debug, Clone)]
+pub struct AuditLog {
+    path: PathBuf,
+}
+
+impl AuditLog {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    pub fn default_path() -> PathBuf {
+        data_directory().join("audit.log")
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn append(&self, entry: &AuditEntry) -> Result<()> {
+        if is_read_only_storage() {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create audit log directory")?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .context("Failed to open audit log")?;
+        let line = serde_json::to_string(entry)?;
+        writeln!(file, "{}", line).context("Failed to write audit log")?;
+        Ok(())
+    }
+
+    /// The last `limit` entries, oldest first; lines that don't parse are skipped
+    pub fn read_recent(&self, limit: usize) -> Result<Vec<AuditEntry>> {
+        let content = match std::fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e).context("Failed to read audit log"),
+        };
+        let entries: Vec<AuditEntry> = content
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+        let skip = entries.len().saturating_sub(limit);
+        Ok(entries.into_iter().skip(skip).collect())
+    }
+}
+
+/// Whether sends to this environment go on the record: its name has a `prod`
+/// or `production` word, e.g. `prod`, `Production EU` or `api-prod`
+pub fn is_production_environment(name: &str) -> bool {
+    name.split(|c: char| !c.is_alphanumeric())
+        .any(|word| matches!(word.to_ascii_lowercase().as_str(), "prod" | "production"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit_log_appends_and_reads_back() {
+        let temp = tempfile::tempdir().unwrap();
+        let log = AuditLog::new(temp.path().join("nested").join("audit.log"));
+        assert!(log.read_recent(10).unwrap().is_empty());
+
+        log.append(&AuditEntry::new(
+            AuditAction::Delete,
+            vec!["users/Get user".to_string()],
+            "HTTP request",
+        ))
+        .unwrap();
+        log.append(&AuditEntry::new(
+            AuditAction::Rename,
+            vec!["old".to_string(), "new".to_string()],
+            "",
+        ))
+        .unwrap();
+        // A damaged line doesn't hide the rest
+        let mut content = std::fs::read_to_string(log.path()).unwrap();
+        content.push_str("not json\n");
+        std::fs::write(log.path(), content).unwrap();
+        log.append(&AuditEntry::new(
+            AuditAction::ProductionSend,
+            vec![],
+            "prod",
+        ))
+        .unwrap();
+
+        let entries = log.read_recent(10).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].paths, ["users/Get user"]);
+        assert_eq!(entries[2].action, AuditAction::ProductionSend);
+        let last_two = log.read_recent(2).unwrap();
+        assert_eq!(last_two[0].action, AuditAction::Rename);
+
+        assert!(is_production_environment("prod"));
+        assert!(is_production_environment("Production EU"));
+        assert!(is_production_environment("api-prod"));
+        assert!(!is_production_environment("staging"));
+        assert!(!is_production_environment("product-demo"));
+    }
+}
@@ -0,0 +1,234 @@
+use crate::models::http_client::{HttpHeader, HttpRequest, HttpRequestBody};
+use crate::services::multipart::multipart_summary;
+use anyhow::{Context, Result, bail};
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+/// How long an auth script may run before the request is aborted
+const SCRIPT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Run a custom auth script and return the headers it computed.
+///
+/// The script receives the outgoing request as JSON on stdin
+/// (`method`, `url`, `headers`, `query_params`, `body`) and must print a JSON
+/// object of header names to values on stdout, e.g.
+/// `{"X-Signature": "...", "X-Timestamp": "..."}`.
+///
+/// `.py` scripts are run with `python3`; anything else is executed directly
+/// (so it needs a shebang and the executable bit).
+pub async fn compute_auth_headers(
+    script_path: &str,
+    request: &HttpRequest,
+) -> Result<Vec<HttpHeader>> {
+    let path = crate::services::storage_migration::expand_user_path(script_path);
+    if !path.is_file() {
+        bail!("Auth script not found: {}", path.display());
+    }
+
+    let mut command = if path.extension().is_some_and(|ext| ext == "py") {
+        let mut command = Command::new("python3");
+        command.arg(&path);
+        command
+    } else {
+        Command::new(&path)
+    };
+
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        command.current_dir(dir);
+    }
+
+    let payload = request_payload(request).to_string();
+    let output = run_script(command, payload, SCRIPT_TIMEOUT)
+        .await
+        .with_context(|| format!("Auth script {}", path.display()))?;
+
+    if !output.status.success() {
+        bail!(
+            "Auth script exited with {:?}: {}",
+            output.status.code(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    parse_script_headers(&String::from_utf8_lossy(&output.stdout), &path)
+}
+
+/// Run the script with `payload` on its stdin and collect its output. Feeding stdin
+/// counts against the timeout too: a script that never reads it would otherwise block
+/// the write for good once the pipe buffer fills up.
+async fn run_script(
+    mut command: Command,
+    payload: String,
+    timeout: Duration,
+) -> Result<std::process::Output> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .context("failed to start")?;
+
+    let stdin = child.stdin.take();
+    let send_payload = async move {
+        if let Some(mut stdin) = stdin {
+            stdin.write_all(payload.as_bytes()).await?;
+            stdin.shutdown().await?;
+        }
+        Ok::<_, std::io::Error>(())
+    };
+    let (sent, output) = tokio::time::timeout(timeout, async {
+        tokio::join!(send_payload, child.wait_with_output())
+    })
+    .await
+    .with_context(|| format!("timed out after {}s", timeout.as_secs_f32()))?;
+    let output = output.context("failed to wait for it")?;
+
+    // Scripts that don't need the request may exit without reading it
+    if let Err(e) = sent
+        && e.kind() != std::io::ErrorKind::BrokenPipe
+    {
+        return Err(e).context("failed to send it the request");
+    }
+    Ok(output)
+}
+
+/// JSON handed to the script on stdin
+fn request_payload(request: &HttpRequest) -> serde_json::Value {
+    let enabled_pairs = |pairs: Vec<(&str, &str)>| -> serde_json::Map<String, serde_json::Value> {
+        pairs
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), json!(value)))
+            .collect()
+    };
+
+    let body = match &request.body {
+        HttpRequestBody::None => String::new(),
+        HttpRequestBody::Text { content, .. }
+        | HttpRequestBody::Json { content }
+        | HttpRequestBody::Raw { content } => content.clone(),
+        HttpRequestBody::Form { fields } => fields
+            .iter()
+            .filter(|field| field.enabled)
+            .map(|field| format!("{}={}", field.name, field.value))
+            .collect::<Vec<_>>()
+            .join("&"),
+        HttpRequestBody::Multipart { parts } => multipart_summary(parts, "&"),
+        // Scripts can read the file themselves; it may be too large to pass along
+        HttpRequestBody::File { path, .. } => format!("@{}", path),
+    };
+
+    json!({
+        "method": request.method.as_str(),
+        "url": request.url,
+        "headers": enabled_pairs(
+            request
+                .headers
+                .iter()
+                .filter(|h| h.enabled)
+                .map(|h| (h.name.as_str(), h.value.as_str()))
+                .collect()
+        ),
+        "query_params": enabled_pairs(
+            request
+                .query_params
+                .iter()
+                .filter(|p| p.enabled)
+                .map(|p| (p.name.as_str(), p.value.as_str()))
+                .collect()
+        ),
+        "body": body,
+    })
+}
+
+/// Parse the `{"Header": "value"}` object printed by the script
+fn parse_script_headers(stdout: &str, path: &Path) -> Result<Vec<HttpHeader>> {
+    let headers: BTreeMap<String, serde_json::Value> = serde_json::from_str(stdout.trim())
+        .with_context(|| {
+            format!(
+                "Auth script {} must print a JSON object of headers",
+                path.display()
+            )
+        })?;
+
+    Ok(headers
+        .into_iter()
+        .map(|(name, value)| {
+            let value = match value {
+                serde_json::Value::String(text) => text,
+                other => other.to_string(),
+            };
+            HttpHeader::new(name, value)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_script_headers() {
+        let headers = parse_script_headers(
+            "{\"X-Signature\": \"abc123\", \"X-Timestamp\": 1700000000}\n",
+            Path::new("sign.py"),
+        )
+        .unwrap();
+
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers[0].name, "X-Signature");
+        assert_eq!(headers[0].value, "abc123");
+        assert_eq!(headers[1].value, "1700000000");
+
+        assert!(parse_script_headers("not json", Path::new("sign.py")).is_err());
+    }
+
+    /// A request whose JSON is far bigger than a pipe buffer
+    #[cfg(unix)]
+    fn large_request() -> HttpRequest {
+        let mut request = HttpRequest::new("Upload").with_url("https://api.example.com/upload");
+        request.body = HttpRequestBody::Raw {
+            content: "x".repeat(1024 * 1024),
+        };
+        request
+    }
+
+    #[cfg(unix)]
+    fn shell_script(dir: &Path, body: &str) -> std::path::PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join("sign.sh");
+        std::fs::write(&path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_script_that_never_reads_stdin_times_out() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = shell_script(temp.path(), "sleep 30");
+        let payload = request_payload(&large_request()).to_string();
+
+        let started = std::time::Instant::now();
+        let result = run_script(Command::new(&path), payload, Duration::from_millis(300)).await;
+        assert!(format!("{:#}", result.unwrap_err()).contains("timed out"));
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_script_may_exit_without_reading_stdin() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = shell_script(temp.path(), "echo '{\"X-Signature\": \"static\"}'");
+
+        let headers = compute_auth_headers(path.to_str().unwrap(), &large_request())
+            .await
+            .unwrap();
+        assert_eq!(headers[0].value, "static");
+    }
+}
@@ -7,6 +7,12 @@ pub struct AuthService {
     credentials: Arc<Mutex<Option<Credentials>>>,
 }
 
+impl Default for AuthService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl AuthService {
     /// Create a new auth service instance
     pub fn new() -> Self {
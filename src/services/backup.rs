@@ -0,0 +1,417 @@
+use crate::models::config::{data_directory, ensure_writable_storage};
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Timelike};
+use serde::{Deserialize, Serialize};
+use std::path::{Component, Path, PathBuf};
+
+/// Marks a file as a collections backup, so other JSON files are rejected early
+const BACKUP_FORMAT: &str = "tui_one-backup";
+
+/// Newest backup version this build can read
+const BACKUP_VERSION: u32 = 1;
+
+/// Archives are named `backup-<timestamp>.json`, so they sort by age
+const FILE_PREFIX: &str = "backup-";
+const FILE_EXTENSION: &str = "json";
+const FILE_TIMESTAMP: &str = "%Y%m%d-%H%M%S";
+
+/// Snapshot of one directory tree: its folders (kept so empty ones survive) and
+/// the text of every file, by path relative to the tree root
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BackupTree {
+    folders: Vec<String>,
+    files: Vec<BackupEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BackupEntry {
+    path: String,
+    content: String,
+}
+
+/// On-disk shape of a backup archive
+#[derive(Debug, Serialize, Deserialize)]
+struct BackupFile {
+    format: String,
+    version: u32,
+    created_at: DateTime<Local>,
+    templates: BackupTree,
+    collections: BackupTree,
+}
+
+/// An archive found in the backups directory
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackupInfo {
+    pub path: PathBuf,
+    pub created_at: DateTime<Local>,
+    pub size: u64,
+}
+
+/// What a backup or restore touched
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BackupSummary {
+    pub files: usize,
+    /// Files left out because they aren't text
+    pub skipped: usize,
+}
+
+/// Timestamped snapshots of the templates and http-collections directories
+pub struct BackupStore {
+    dir: PathBuf,
+}
+
+impl BackupStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    pub fn default_path() -> PathBuf {
+        data_directory().join("backups")
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Archives on disk, newest first
+    pub fn list(&self) -> Vec<BackupInfo> {
+        let Ok(entries) = std::fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+        let mut backups: Vec<(DateTime<Local>, u32, BackupInfo)> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.path();
+                let (created_at, sequence) = parse_file_name(&path)?;
+                let size = entry.metadata().map(|meta| meta.len()).unwrap_or_default();
+                let info = BackupInfo {
+                    path,
+                    created_at,
+                    size,
+                };
+                Some((created_at, sequence, info))
+            })
+            .collect();
+        backups
+            .sort_by_key(|(created_at, sequence, _)| std::cmp::Reverse((*created_at, *sequence)));
+        backups.into_iter().map(|(_, _, info)| info).collect()
+    }
+
+    /// When the newest archive was taken
+    pub fn latest(&self) -> Option<DateTime<Local>> {
+        self.list().first().map(|backup| backup.created_at)
+    }
+
+    /// Snapshot both trees into a new archive, then drop all but the newest `keep`
+    pub fn create(
+        &self,
+        templates_dir: &Path,
+        collections_dir: &Path,
+        keep: usize,
+    ) -> Result<(BackupInfo, BackupSummary)> {
+        ensure_writable_storage()?;
+        let mut summary = BackupSummary::default();
+        let file = BackupFile {
+            format: BACKUP_FORMAT.to_string(),
+            version: BACKUP_VERSION,
+            // Whole seconds, like the timestamp in the file name
+            created_at: Local::now().with_nanosecond(0).unwrap_or_else(Local::now),
+            templates: self.snapshot(templates_dir, &mut summary)?,
+            collections: self.snapshot(collections_dir, &mut summary)?,
+        };
+
+        std::fs::create_dir_all(&self.dir).context("Failed to create backups directory")?;
+        let path = self.unused_path(&file.created_at);
+        let temp_path = path.with_extension("tmp");
+        std::fs::write(&temp_path, serde_json::to_string(&file)?)
+            .with_context(|| format!("Failed to write {}", temp_path.display()))?;
+        std::fs::rename(&temp_path, &path)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        self.prune(keep)?;
+        let size = std::fs::metadata(&path)
+            .map(|meta| meta.len())
+            .unwrap_or_default();
+        Ok((
+            BackupInfo {
+                path,
+                created_at: file.created_at,
+                size,
+            },
+            summary,
+        ))
+    }
+
+    /// Delete all but the newest `keep` archives (0 keeps everything); returns how many went
+    pub fn prune(&self, keep: usize) -> Result<usize> {
+        if keep == 0 {
+            return Ok(0);
+        }
+        let mut removed = 0;
+        for backup in self.list().into_iter().skip(keep) {
+            std::fs::remove_file(&backup.path)
+                .with_context(|| format!("Failed to remove {}", backup.path.display()))?;
+            removed += 1;
+        }
+        Ok(removed)
+    }
+
+    /// Replace both trees with the contents of `backup`. The current state is
+    /// archived first, so a restore can itself be undone.
+    pub fn restore(
+        &self,
+        backup: &Path,
+        templates_dir: &Path,
+        collections_dir: &Path,
+    ) -> Result<BackupSummary> {
+        let content = std::fs::read_to_string(backup)
+            .with_context(|| format!("Failed to read {}", backup.display()))?;
+        let file: BackupFile =
+            serde_json::from_str(&content).context("Not a backup archive (invalid JSON)")?;
+        if file.format != BACKUP_FORMAT {
+            bail!("Not a backup archive (format '{}')", file.format);
+        }
+        if file.version > BACKUP_VERSION {
+            bail!(
+                "Backup version {} is newer than this app supports ({})",
+                file.version,
+                BACKUP_VERSION
+            );
+        }
+        for path in tree_paths(&file.templates).chain(tree_paths(&file.collections)) {
+            if !is_safe_relative_path(path) {
+                bail!("Backup contains an unsafe path '{}'", path);
+            }
+        }
+
+        self.create(templates_dir, collections_dir, 0)
+            .context("Failed to back up the current state before restoring")?;
+
+        let mut summary = BackupSummary::default();
+        restore_tree(&file.templates, templates_dir, &self.dir, &mut summary)?;
+        restore_tree(&file.collections, collections_dir, &self.dir, &mut summary)?;
+        Ok(summary)
+    }
+
+    fn snapshot(&self, root: &Path, summary: &mut BackupSummary) -> Result<BackupTree> {
+        let mut tree = BackupTree::default();
+        if root.is_dir() {
+            self.snapshot_dir(root, root, &mut tree, summary)?;
+        }
+        tree.folders.sort();
+        tree.files.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(tree)
+    }
+
+    fn snapshot_dir(
+        &self,
+        root: &Path,
+        dir: &Path,
+        tree: &mut BackupTree,
+        summary: &mut BackupSummary,
+    ) -> Result<()> {
+        let entries =
+            std::fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))?;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if is_hidden(&path) || path == self.dir {
+                continue;
+            }
+            let relative = relative_path(root, &path);
+            if path.is_dir() {
+                tree.folders.push(relative);
+                self.snapshot_dir(root, &path, tree, summary)?;
+            } else {
+                match std::fs::read_to_string(&path) {
+                    Ok(content) => {
+                        tree.files.push(BackupEntry {
+                            path: relative,
+                            content,
+                        });
+                        summary.files += 1;
+                    }
+                    Err(e) if e.kind() == std::io::ErrorKind::InvalidData => summary.skipped += 1,
+                    Err(e) => {
+                        return Err(e)
+                            .with_context(|| format!("Failed to read {}", path.display()));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Archive path for `created_at`, suffixed if a backup was already taken that second
+    fn unused_path(&self, created_at: &DateTime<Local>) -> PathBuf {
+        let stamp = created_at.format(FILE_TIMESTAMP).to_string();
+        let mut path = self
+            .dir
+            .join(format!("{}{}.{}", FILE_PREFIX, stamp, FILE_EXTENSION));
+        let mut counter = 2;
+        while path.exists() {
+            path = self.dir.join(format!(
+                "{}{}-{}.{}",
+                FILE_PREFIX, stamp, counter, FILE_EXTENSION
+            ));
+            counter += 1;
+        }
+        path
+    }
+}
+
+/// Clear the non-hidden contents of `root` and write the snapshot in their place
+fn restore_tree(
+    tree: &BackupTree,
+    root: &Path,
+    backups_dir: &Path,
+    summary: &mut BackupSummary,
+) -> Result<()> {
+    std::fs::create_dir_all(root)
+        .with_context(|| format!("Failed to create {}", root.display()))?;
+    let entries =
+        std::fs::read_dir(root).with_context(|| format!("Failed to read {}", root.display()))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if is_hidden(&path) || path == backups_dir {
+            continue;
+        }
+        let result = if path.is_dir() {
+            std::fs::remove_dir_all(&path)
+        } else {
+            std::fs::remove_file(&path)
+        };
+        result.with_context(|| format!("Failed to remove {}", path.display()))?;
+    }
+
+    for folder in &tree.folders {
+        let path = root.join(folder);
+        std::fs::create_dir_all(&path)
+            .with_context(|| format!("Failed to create {}", path.display()))?;
+    }
+    for file in &tree.files {
+        let path = root.join(&file.path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        std::fs::write(&path, &file.content)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        summary.files += 1;
+    }
+    Ok(())
+}
+
+fn tree_paths(tree: &BackupTree) -> impl Iterator<Item = &str> {
+    tree.folders
+        .iter()
+        .map(String::as_str)
+        .chain(tree.files.iter().map(|file| file.path.as_str()))
+}
+
+/// Only plain relative paths may be written back, never `..` or absolute ones
+fn is_safe_relative_path(path: &str) -> bool {
+    !path.is_empty()
+        && Path::new(path)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+}
+
+/// Dotfiles such as `.git` belong to whatever else manages the directory
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with('.'))
+}
+
+/// `/`-separated path of `path` below `root`, the same on every platform
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Creation time encoded in an archive's file name, and its position among
+/// backups taken within the same second
+fn parse_file_name(path: &Path) -> Option<(DateTime<Local>, u32)> {
+    if path.extension()? != FILE_EXTENSION {
+        return None;
+    }
+    let stem = path.file_stem()?.to_str()?.strip_prefix(FILE_PREFIX)?;
+    let stamp = stem.get(..15)?;
+    let sequence = match stem.get(15..)? {
+        "" => 1,
+        suffix => suffix.strip_prefix('-')?.parse().ok()?,
+    };
+    let naive = NaiveDateTime::parse_from_str(stamp, FILE_TIMESTAMP).ok()?;
+    Some((Local.from_local_datetime(&naive).earliest()?, sequence))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backup_prune_and_restore() {
+        let temp = tempfile::tempdir().unwrap();
+        let templates = temp.path().join("templates");
+        let collections = temp.path().join("http-collections");
+        std::fs::create_dir_all(templates.join("Customer/Add")).unwrap();
+        std::fs::create_dir_all(collections.join("api")).unwrap();
+        std::fs::create_dir_all(templates.join(".git")).unwrap();
+        std::fs::write(
+            templates.join("Customer/Add/Email.json"),
+            "{\"name\":\"Email\"}",
+        )
+        .unwrap();
+        std::fs::write(collections.join("api/users.json"), "{\"url\":\"/users\"}").unwrap();
+        std::fs::write(templates.join(".git/HEAD"), "ref: main").unwrap();
+
+        let store = BackupStore::new(temp.path().join("backups"));
+        let (first, summary) = store.create(&templates, &collections, 3).unwrap();
+        assert_eq!(summary.files, 2);
+        assert_eq!(store.list(), vec![first.clone()]);
+
+        // A bulk mistake: the folder is deleted and another request edited
+        std::fs::remove_dir_all(templates.join("Customer")).unwrap();
+        std::fs::write(collections.join("api/users.json"), "{}").unwrap();
+        std::fs::write(collections.join("api/orders.json"), "{}").unwrap();
+
+        let restored = store
+            .restore(&first.path, &templates, &collections)
+            .unwrap();
+        assert_eq!(restored.files, 2);
+        assert!(templates.join("Customer/Add/Email.json").exists());
+        assert!(!collections.join("api/orders.json").exists());
+        assert_eq!(
+            std::fs::read_to_string(collections.join("api/users.json")).unwrap(),
+            "{\"url\":\"/users\"}"
+        );
+        // Hidden entries are neither archived nor cleared
+        assert!(templates.join(".git/HEAD").exists());
+        // The pre-restore state was archived too
+        assert_eq!(store.list().len(), 2);
+
+        for _ in 0..3 {
+            store.create(&templates, &collections, 0).unwrap();
+        }
+        assert_eq!(store.prune(2).unwrap(), 3);
+        assert_eq!(store.list().len(), 2);
+
+        let bad = temp.path().join("backups/backup-20240101-000000.json");
+        std::fs::write(
+            &bad,
+            format!(
+                "{{\"format\":\"{}\",\"version\":1,\"created_at\":\"2024-01-01T00:00:00+00:00\",\
+                 \"templates\":{{\"folders\":[],\"files\":[{{\"path\":\"../escape\",\"content\":\"\"}}]}},\
+                 \"collections\":{{\"folders\":[],\"files\":[]}}}}",
+                BACKUP_FORMAT
+            ),
+        )
+        .unwrap();
+        assert!(store.restore(&bad, &templates, &collections).is_err());
+        assert!(!temp.path().join("escape").exists());
+    }
+}
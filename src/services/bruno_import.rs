@@ -0,0 +1,480 @@
+use crate::services::http_collection_storage::{
+    PostmanAuth, PostmanAuthItem, PostmanBody, PostmanCollection, PostmanFolder, PostmanFormData,
+    PostmanHeader, PostmanInfo, PostmanItem, PostmanQueryParam, PostmanRequest,
+    PostmanRequestDetails, PostmanUrl, PostmanUrlEncoded, PostmanUrlObject,
+};
+use anyhow::{Context, Result, bail};
+use std::path::Path;
+
+/// Extension of Bruno request files
+pub const BRU_EXTENSION: &str = "bru";
+
+/// Files Bruno keeps next to requests that describe a folder or the collection itself
+const BRUNO_SETTINGS_FILES: &[&str] = &["folder.bru", "collection.bru"];
+
+/// Directories of a Bruno collection that never hold requests
+const SKIPPED_DIRECTORIES: &[&str] = &["environments", "node_modules"];
+
+const HTTP_METHODS: &[&str] = &["get", "post", "put", "patch", "delete", "head", "options"];
+
+/// Whether `dir` is a Bruno collection: it has a `bruno.json` or holds `.bru` files
+pub fn is_bruno_collection(dir: &Path) -> bool {
+    if !dir.is_dir() {
+        return false;
+    }
+    dir.join("bruno.json").is_file()
+        || std::fs::read_dir(dir).is_ok_and(|entries| {
+            entries.flatten().any(|entry| {
+                entry.path().extension().and_then(|e| e.to_str()) == Some(BRU_EXTENSION)
+            })
+        })
+}
+
+/// Read a Bruno collection folder into a Postman collection so it goes through
+/// the same conversion and storage as Postman imports
+pub fn parse_bruno_collection(dir: &Path) -> Result<PostmanCollection> {
+    if !is_bruno_collection(dir) {
+        bail!("Not a Bruno collection (no bruno.json or .bru files)");
+    }
+
+    let name = std::fs::read_to_string(dir.join("bruno.json"))
+        .ok()
+        .and_then(|json| serde_json::from_str::<serde_json::Value>(&json).ok())
+        .and_then(|config| config.get("name")?.as_str().map(str::to_string))
+        .or_else(|| {
+            dir.file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        })
+        .unwrap_or_else(|| "Bruno Import".to_string());
+
+    Ok(PostmanCollection {
+        info: PostmanInfo {
+            postman_id: String::new(),
+            name,
+            description: None,
+            schema: String::new(),
+        },
+        item: read_folder(dir)?,
+        auth: None,
+        event: None,
+        variable: None,
+    })
+}
+
+/// Requests and subfolders of one collection directory, in Bruno's `seq` order
+fn read_folder(dir: &Path) -> Result<Vec<PostmanItem>> {
+    let mut requests: Vec<(u64, PostmanRequest)> = Vec::new();
+    let mut folders: Vec<PostmanItem> = Vec::new();
+
+    let mut entries: Vec<_> = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read {}", dir.display()))?
+        .flatten()
+        .map(|entry| entry.path())
+        .collect();
+    entries.sort();
+
+    for path in entries {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        if path.is_dir() {
+            if file_name.starts_with('.') || SKIPPED_DIRECTORIES.contains(&file_name.as_str()) {
+                continue;
+            }
+            let item = read_folder(&path)?;
+            if item.is_empty() {
+                continue;
+            }
+            let name = std::fs::read_to_string(path.join("folder.bru"))
+                .ok()
+                .and_then(|content| bru_blocks(&content).into_iter().find(|b| b.name == "meta"))
+                .and_then(|meta| meta.value("name"))
+                .unwrap_or(file_name);
+            folders.push(PostmanItem::Folder(PostmanFolder {
+                name,
+                item,
+                description: None,
+                auth: None,
+                event: None,
+            }));
+        } else if path.extension().and_then(|e| e.to_str()) == Some(BRU_EXTENSION)
+            && !BRUNO_SETTINGS_FILES.contains(&file_name.as_str())
+        {
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let fallback_name = file_name.trim_end_matches(".bru");
+            if let Some(request) = parse_bru_request(&content, fallback_name) {
+                requests.push(request);
+            }
+        }
+    }
+
+    requests.sort_by_key(|(seq, _)| *seq);
+    let mut items: Vec<PostmanItem> = requests
+        .into_iter()
+        .map(|(_, request)| PostmanItem::Request(request))
+        .collect();
+    items.extend(folders);
+    Ok(items)
+}
+
+/// A top-level `name { ... }` block of a `.bru` file
+struct BruBlock {
+    name: String,
+    /// Block body with the two-space indent removed
+    lines: Vec<String>,
+}
+
+impl BruBlock {
+    /// `key: value` pairs; a leading `~` marks a disabled entry
+    fn pairs(&self) -> Vec<(String, String, bool)> {
+        self.lines
+            .iter()
+            .filter_map(|line| {
+                let line = line.trim();
+                let (key, value) = line.split_once(':')?;
+                let (key, disabled) = match key.strip_prefix('~') {
+                    Some(key) => (key, true),
+                    None => (key, false),
+                };
+                Some((key.trim().to_string(), value.trim().to_string(), disabled))
+            })
+            .collect()
+    }
+
+    fn value(&self, key: &str) -> Option<String> {
+        self.pairs()
+            .into_iter()
+            .find(|(name, _, _)| name == key)
+            .map(|(_, value, _)| value)
+    }
+
+    fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+}
+
+fn bru_blocks(content: &str) -> Vec<BruBlock> {
+    let mut blocks = Vec::new();
+    let mut current: Option<BruBlock> = None;
+
+    for line in content.lines() {
+        match current.as_mut() {
+            None => {
+                if let Some(name) = line.trim_end().strip_suffix('{') {
+                    current = Some(BruBlock {
+                        name: name.trim().to_string(),
+                        lines: Vec::new(),
+                    });
+                }
+            }
+            // Only an unindented brace closes a block, so JSON bodies can contain braces
+            Some(_) if line.trim_end() == "}" => blocks.extend(current.take()),
+            Some(block) => block
+                .lines
+                .push(line.strip_prefix("  ").unwrap_or(line).to_string()),
+        }
+    }
+    blocks
+}
+
+/// Convert one `.bru` file; `None` for files that aren't HTTP requests
+fn parse_bru_request(content: &str, fallback_name: &str) -> Option<(u64, PostmanRequest)> {
+    let blocks = bru_blocks(content);
+    let block = |name: &str| blocks.iter().find(|block| block.name == name);
+
+    let meta = block("meta");
+    let name = meta
+        .and_then(|meta| meta.value("name"))
+        .unwrap_or_else(|| fallback_name.to_string());
+    let seq = meta
+        .and_then(|meta| meta.value("seq"))
+        .and_then(|seq| seq.parse().ok())
+        .unwrap_or(u64::MAX);
+
+    let method_block = blocks
+        .iter()
+        .find(|block| HTTP_METHODS.contains(&block.name.as_str()))?;
+    let url = method_block.value("url").unwrap_or_default();
+    let body_mode = method_block.value("body").unwrap_or_default();
+    let auth_mode = method_block.value("auth").unwrap_or_default();
+
+    let mut headers: Vec<PostmanHeader> = block("headers")
+        .map(|headers| {
+            headers
+                .pairs()
+                .into_iter()
+                .map(|(key, value, disabled)| PostmanHeader {
+                    key,
+                    value,
+                    disabled: Some(disabled),
+                    description: None,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Bruno sets the content type of text bodies itself; keep it explicit after import
+    let content_type = match body_mode.as_str() {
+        "json" => Some("application/json"),
+        "xml" => Some("application/xml"),
+        _ => None,
+    };
+    if let Some(content_type) = content_type
+        && !headers
+            .iter()
+            .any(|h| h.key.eq_ignore_ascii_case("content-type"))
+    {
+        headers.push(PostmanHeader {
+            key: "Content-Type".to_string(),
+            value: content_type.to_string(),
+            disabled: None,
+            description: None,
+        });
+    }
+
+    let query = block("params:query")
+        .map(|params| {
+            params
+                .pairs()
+                .into_iter()
+                .map(|(key, value, disabled)| PostmanQueryParam {
+                    key,
+                    value,
+                    disabled: Some(disabled),
+                    description: None,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let request = PostmanRequest {
+        name,
+        request: PostmanRequestDetails {
+            method: method_block.name.to_uppercase(),
+            header: Some(headers),
+            body: convert_body(&body_mode, |name| block(name)),
+            url: PostmanUrl::Object(PostmanUrlObject {
+                raw: url,
+                protocol: None,
+                host: None,
+                port: None,
+                path: None,
+                query: Some(query),
+                hash: None,
+            }),
+            auth: convert_auth(&auth_mode, |name| block(name)),
+            description: block("docs")
+                .map(|docs| docs.text().trim().to_string())
+                .filter(|docs| !docs.is_empty()),
+        },
+        response: None,
+        event: None,
+    };
+    Some((seq, request))
+}
+
+fn convert_body<'a>(
+    mode: &str,
+    block: impl Fn(&str) -> Option<&'a BruBlock>,
+) -> Option<PostmanBody> {
+    let mut body = PostmanBody {
+        mode: String::new(),
+        raw: None,
+        formdata: None,
+        urlencoded: None,
+        file: None,
+    };
+
+    match mode {
+        "json" | "text" | "xml" | "graphql" | "sparql" => {
+            body.mode = "raw".to_string();
+            body.raw = Some(block(&format!("body:{}", mode))?.text().trim().to_string());
+        }
+        "form-urlencoded" => {
+            body.mode = "urlencoded".to_string();
+            body.urlencoded = Some(
+                block("body:form-urlencoded")?
+                    .pairs()
+                    .into_iter()
+                    .map(|(key, value, disabled)| PostmanUrlEncoded {
+                        key,
+                        value,
+                        disabled: Some(disabled),
+                    })
+                    .collect(),
+            );
+        }
+        "multipart-form" => {
+            body.mode = "formdata".to_string();
+            body.formdata = Some(
+                block("body:multipart-form")?
+                    .pairs()
+                    .into_iter()
+                    .map(|(key, value, disabled)| {
+                        let file = value
+                            .strip_prefix("@file(")
+                            .and_then(|path| path.strip_suffix(')'))
+                            .map(|path| path.split('|').next().unwrap_or_default().to_string());
+                        PostmanFormData {
+                            key,
+                            value: if file.is_some() { String::new() } else { value },
+                            disabled: Some(disabled),
+                            field_type: Some(if file.is_some() { "file" } else { "text" }.into()),
+                            src: file.map(serde_json::Value::String),
+                        }
+                    })
+                    .collect(),
+            );
+        }
+        _ => return None,
+    }
+    Some(body)
+}
+
+fn convert_auth<'a>(
+    mode: &str,
+    block: impl Fn(&str) -> Option<&'a BruBlock>,
+) -> Option<PostmanAuth> {
+    let item = |key: &str, value: Option<String>| PostmanAuthItem {
+        key: key.to_string(),
+        value: value.unwrap_or_default(),
+        value_type: None,
+    };
+
+    match mode {
+        "bearer" => {
+            let auth = block("auth:bearer")?;
+            Some(PostmanAuth {
+                auth_type: "bearer".to_string(),
+                basic: None,
+                bearer: Some(vec![item("token", auth.value("token"))]),
+                apikey: None,
+            })
+        }
+        "basic" => {
+            let auth = block("auth:basic")?;
+            Some(PostmanAuth {
+                auth_type: "basic".to_string(),
+                basic: Some(vec![
+                    item("username", auth.value("username")),
+                    item("password", auth.value("password")),
+                ]),
+                bearer: None,
+                apikey: None,
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bruno_collection_becomes_nested_collection() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        std::fs::write(
+            dir.join("bruno.json"),
+            r#"{"version": "1", "name": "Shop"}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("health.bru"),
+            "meta {\n  name: Health\n  type: http\n  seq: 2\n}\n\nhead {\n  url: {{host}}/health\n  body: none\n  auth: none\n}\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(dir.join("users")).unwrap();
+        std::fs::create_dir_all(dir.join("environments")).unwrap();
+        std::fs::write(dir.join("environments/Local.bru"), "vars {\n  host: x\n}\n").unwrap();
+        std::fs::write(
+            dir.join("users/folder.bru"),
+            "meta {\n  name: User Admin\n}\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("users/create.bru"),
+            r#"meta {
+  name: Create User
+  type: http
+  seq: 1
+}
+
+post {
+  url: {{host}}/users
+  body: json
+  auth: bearer
+}
+
+params:query {
+  notify: true
+  ~dry_run: 1
+}
+
+headers {
+  X-Trace: abc
+}
+
+auth:bearer {
+  token: {{token}}
+}
+
+body:json {
+  {
+    "name": "Ada"
+  }
+}
+
+docs {
+  Creates a user.
+}
+"#,
+        )
+        .unwrap();
+
+        assert!(is_bruno_collection(dir));
+        let collection = parse_bruno_collection(dir).unwrap();
+        assert_eq!(collection.info.name, "Shop");
+        assert_eq!(collection.item.len(), 2);
+
+        let PostmanItem::Request(health) = &collection.item[0] else {
+            panic!("expected requests before folders");
+        };
+        assert_eq!(health.request.method, "HEAD");
+        assert!(health.request.body.is_none());
+
+        let PostmanItem::Folder(folder) = &collection.item[1] else {
+            panic!("expected a folder");
+        };
+        assert_eq!(folder.name, "User Admin");
+        assert_eq!(folder.item.len(), 1);
+        let PostmanItem::Request(create) = &folder.item[0] else {
+            panic!("expected a request");
+        };
+        let details = &create.request;
+        assert_eq!(create.name, "Create User");
+        assert_eq!(details.method, "POST");
+        assert_eq!(
+            details.body.as_ref().unwrap().raw.as_deref(),
+            Some("{\n  \"name\": \"Ada\"\n}")
+        );
+        let headers = details.header.as_ref().unwrap();
+        assert!(headers.iter().any(|h| h.key == "Content-Type"));
+        let PostmanUrl::Object(url) = &details.url else {
+            panic!("expected a url object");
+        };
+        assert_eq!(url.raw, "{{host}}/users");
+        let query = url.query.as_ref().unwrap();
+        assert_eq!(query[1].key, "dry_run");
+        assert_eq!(query[1].disabled, Some(true));
+        assert_eq!(
+            details.auth.as_ref().unwrap().bearer.as_ref().unwrap()[0].value,
+            "{{token}}"
+        );
+        assert_eq!(details.description.as_deref(), Some("Creates a user."));
+    }
+}
@@ -0,0 +1,313 @@
+use crate::models::KeyValuePair;
+use crate::models::http_client::{
+    ConnectionInfo, HttpAuth, HttpRequest, HttpRequestBody, HttpResponse,
+};
+use crate::models::secrets::is_secret_name;
+use crate::services::multipart::multipart_summary;
+use crate::services::snippet_generator::{SnippetFormat, generate_snippet};
+use chrono::{DateTime, Local};
+use serde::Serialize;
+
+/// Placeholder written in place of anything that looks like a credential
+pub const REDACTED: &str = "<redacted>";
+
+/// Output formats for a bug report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BugReportFormat {
+    Markdown,
+    Json,
+}
+
+impl BugReportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            BugReportFormat::Markdown => "md",
+            BugReportFormat::Json => "json",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BugReportPair {
+    pub name: String,
+    pub value: String,
+}
+
+/// A request/response pair with credentials redacted, ready to attach to an issue
+#[derive(Debug, Clone, Serialize)]
+pub struct BugReport {
+    pub request_name: String,
+    pub generated_at: DateTime<Local>,
+    pub environment: String,
+    pub environment_variables: Vec<BugReportPair>,
+    pub method: String,
+    pub url: String,
+    pub request_headers: Vec<BugReportPair>,
+    pub request_body: Option<String>,
+    pub curl: String,
+    pub status_code: u16,
+    pub status_text: String,
+    pub duration_ms: u64,
+    pub served_from_cache: bool,
+    pub connection: ConnectionInfo,
+    pub response_headers: Vec<BugReportPair>,
+    pub response_body: String,
+}
+
+impl BugReport {
+    pub fn new(
+        request: &HttpRequest,
+        response: &HttpResponse,
+        environment: &str,
+        environment_variables: &[KeyValuePair],
+    ) -> Self {
+        let request = redact_request(request);
+        let curl = generate_snippet(&request, SnippetFormat::Curl);
+
+        let mut request_headers: Vec<BugReportPair> = request
+            .headers
+            .iter()
+            .filter(|h| h.enabled)
+            .map(|h| pair(&h.name, &h.value))
+            .collect();
+        if !matches!(request.auth, HttpAuth::None) {
+            request_headers.push(pair("(auth)", request.auth.title()));
+        }
+
+        let request_body = match &request.body {
+            HttpRequestBody::None => None,
+            HttpRequestBody::Text { content, .. }
+            | HttpRequestBody::Json { content }
+            | HttpRequestBody::Raw { content } => Some(content.clone()),
+            HttpRequestBody::Form { fields } => Some(
+                fields
+                    .iter()
+                    .filter(|f| f.enabled)
+                    .map(|f| format!("{}={}", f.name, f.value))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+            HttpRequestBody::Multipart { parts } => Some(multipart_summary(parts, "\n")),
+            HttpRequestBody::File { path, .. } => Some(format!("@{}", path)),
+        };
+
+        Self {
+            request_name: request.name.clone(),
+            generated_at: Local::now(),
+            environment: environment.to_string(),
+            environment_variables: environment_variables
+                .iter()
+                .filter(|v| v.enabled)
+                .map(|v| {
+                    if v.secret {
+                        pair(&v.name, REDACTED)
+                    } else {
+                        redacted_pair(&v.name, &v.value)
+                    }
+                })
+                .collect(),
+            method: request.method.as_str().to_string(),
+            url: request.url.clone(),
+            request_headers,
+            request_body,
+            curl,
+            status_code: response.status_code,
+            status_text: response.status_text.clone(),
+            duration_ms: response.duration_ms,
+            served_from_cache: response.served_from_cache,
+            connection: response.connection.clone(),
+            response_headers: response
+                .headers
+                .iter()
+                .map(|h| redacted_pair(&h.name, &h.value))
+                .collect(),
+            response_body: response.body.clone(),
+        }
+    }
+
+    pub fn render(&self, format: BugReportFormat) -> String {
+        match format {
+            BugReportFormat::Markdown => self.to_markdown(),
+            BugReportFormat::Json => serde_json::to_string_pretty(self).unwrap_or_default(),
+        }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# {} — {} {}\n\n", self.request_name, self.method, self.url);
+        out.push_str(&format!(
+            "- **Status:** {} {}\n",
+            self.status_code, self.status_text
+        ));
+        out.push_str(&format!(
+            "- **Time:** {} ms{}\n",
+            self.duration_ms,
+            if self.served_from_cache {
+                " (served from cache)"
+            } else {
+                ""
+            }
+        ));
+        if !self.connection.http_version.is_empty() {
+            out.push_str(&format!(
+                "- **Connection:** {} via {}\n",
+                self.connection.http_version,
+                self.connection.proxy_label()
+            ));
+        }
+        if let Some(fingerprint) = &self.connection.certificate_sha256 {
+            out.push_str(&format!("- **Certificate SHA-256:** {}\n", fingerprint));
+        }
+        out.push_str(&format!("- **Environment:** {}\n", self.environment));
+        out.push_str(&format!(
+            "- **Captured:** {}\n\n",
+            self.generated_at.format("%Y-%m-%d %H:%M:%S %:z")
+        ));
+
+        out.push_str("## Request\n\n```sh\n");
+        out.push_str(&self.curl);
+        out.push_str("\n```\n\n");
+        push_pairs(&mut out, "Request headers", &self.request_headers);
+        if let Some(body) = &self.request_body {
+            out.push_str(&format!("### Request body\n\n```\n{}\n```\n\n", body));
+        }
+
+        out.push_str("## Response\n\n");
+        push_pairs(&mut out, "Response headers", &self.response_headers);
+        let fence = if self.response_body.trim_start().starts_with(['{', '[']) {
+            "json"
+        } else {
+            ""
+        };
+        out.push_str(&format!(
+            "### Response body\n\n```{}\n{}\n```\n\n",
+            fence, self.response_body
+        ));
+
+        push_pairs(
+            &mut out,
+            "Environment variables",
+            &self.environment_variables,
+        );
+        out
+    }
+}
+
+fn push_pairs(out: &mut String, title: &str, pairs: &[BugReportPair]) {
+    if pairs.is_empty() {
+        return;
+    }
+    out.push_str(&format!(
+        "### {}\n\n| Name | Value |\n| --- | --- |\n",
+        title
+    ));
+    for p in pairs {
+        out.push_str(&format!(
+            "| {} | {} |\n",
+            p.name.replace('|', "\\|"),
+            p.value.replace('|', "\\|")
+        ));
+    }
+    out.push('\n');
+}
+
+fn pair(name: &str, value: &str) -> BugReportPair {
+    BugReportPair {
+        name: name.to_string(),
+        value: value.to_string(),
+    }
+}
+
+fn redacted_pair(name: &str, value: &str) -> BugReportPair {
+    if is_secret_name(name) {
+        pair(name, REDACTED)
+    } else {
+        pair(name, value)
+    }
+}
+
+/// A copy of the request with credentials and secret-looking values replaced
+pub(crate) fn redact_request(request: &HttpRequest) -> HttpRequest {
+    let mut request = request.clone();
+    for header in &mut request.headers {
+        if is_secret_name(&header.name) {
+            header.value = REDACTED.to_string();
+        }
+    }
+    for param in &mut request.query_params {
+        if is_secret_name(&param.name) {
+            param.value = REDACTED.to_string();
+        }
+    }
+    match &mut request.auth {
+        HttpAuth::None | HttpAuth::Script { .. } => {}
+        HttpAuth::Basic { password, .. } => *password = REDACTED.to_string(),
+        HttpAuth::Bearer { token } => *token = REDACTED.to_string(),
+        HttpAuth::ApiKey { value, .. } => *value = REDACTED.to_string(),
+        HttpAuth::OAuth2ClientCredentials { client_secret, .. }
+        | HttpAuth::OAuth2AuthorizationCode { client_secret, .. } => {
+            if !client_secret.is_empty() {
+                *client_secret = REDACTED.to_string();
+            }
+        }
+    }
+    let tls = &mut request.settings.tls;
+    if !tls.client_cert_password.is_empty() {
+        tls.client_cert_password = REDACTED.to_string();
+    }
+    let proxy = &mut request.settings.proxy;
+    if !proxy.password.is_empty() {
+        proxy.password = REDACTED.to_string();
+    }
+    proxy.url = proxy.display_url();
+    request
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::http_client::HttpMethod;
+    use crate::models::transfer::TransferStats;
+
+    #[test]
+    fn test_bug_report_redacts_secrets() {
+        let mut request = HttpRequest::new("Login")
+            .with_method(HttpMethod::POST)
+            .with_url("https://api.example.com/login")
+            .with_header("X-Api-Key", "k-123")
+            .with_header("Accept", "application/json");
+        request.auth = HttpAuth::Bearer {
+            token: "t-456".to_string(),
+        };
+        let response = HttpResponse {
+            status_code: 500,
+            status_text: "Internal Server Error".to_string(),
+            headers: vec![],
+            body: "{\"error\": \"boom\"}".to_string(),
+            body_bytes: Vec::new(),
+            content_type: "application/json".to_string(),
+            duration_ms: 42,
+            served_from_cache: false,
+            connection: Default::default(),
+            transfer: TransferStats::default(),
+            assertions: Vec::new(),
+            extracted: Vec::new(),
+            truncation: None,
+            timing: Default::default(),
+        };
+        let environment = vec![
+            KeyValuePair::new("base_url", "https://api.example.com"),
+            KeyValuePair::new("client_secret", "s-789"),
+        ];
+
+        let report = BugReport::new(&request, &response, "Default", &environment);
+        for format in [BugReportFormat::Markdown, BugReportFormat::Json] {
+            let text = report.render(format);
+            assert!(!text.contains("k-123"));
+            assert!(!text.contains("t-456"));
+            assert!(!text.contains("s-789"));
+            assert!(text.contains("application/json"));
+            assert!(text.contains("https://api.example.com"));
+        }
+        assert!(report.to_markdown().contains("500 Internal Server Error"));
+    }
+}
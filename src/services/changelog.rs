@@ -0,0 +1,86 @@
+use crate::services::update_checker::is_newer_version;
+
+/// Changelog embedded at build time, shown in the What's New overlay
+const EMBEDDED_CHANGELOG: &str = include_str!("../../CHANGELOG.md");
+
+/// A single release section from the changelog
+#[derive(Debug, Clone)]
+pub struct ChangelogRelease {
+    pub version: String,
+    pub entries: Vec<String>,
+}
+
+/// Parse the embedded changelog into releases (newest first, as written)
+pub fn embedded_changelog() -> Vec<ChangelogRelease> {
+    parse_changelog(EMBEDDED_CHANGELOG)
+}
+
+/// Releases newer than `last_seen` and no newer than the running version
+pub fn releases_since(last_seen: Option<&str>) -> Vec<ChangelogRelease> {
+    let current = env!("CARGO_PKG_VERSION");
+
+    embedded_changelog()
+        .into_iter()
+        .filter(|release| !is_newer_version(&release.version, current))
+        .filter(|release| last_seen.is_none_or(|seen| is_newer_version(&release.version, seen)))
+        .collect()
+}
+
+/// Parse `## [x.y.z]` headings followed by `- entry` bullet lines
+fn parse_changelog(content: &str) -> Vec<ChangelogRelease> {
+    let mut releases: Vec<ChangelogRelease> = Vec::new();
+
+    for line in content.lines().map(str::trim) {
+        if let Some(heading) = line.strip_prefix("## ") {
+            let version = heading
+                .split_whitespace()
+                .next()
+                .unwrap_or("")
+                .trim_matches(|c| c == '[' || c == ']')
+                .to_string();
+            releases.push(ChangelogRelease {
+                version,
+                entries: Vec::new(),
+            });
+        } else if let Some(entry) = line.strip_prefix("- ")
+            && let Some(release) = releases.last_mut()
+        {
+            release.entries.push(entry.to_string());
+        }
+    }
+
+    releases
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_changelog_sections() {
+        let content = "# Changelog\n\nIntro text\n- not in a release\n\n## [1.1.0] - 2026-05-01\n\n- Tabs\n  - Indented entry\nProse is skipped\n\n## 1.0.0\n- First release\n";
+        let releases = parse_changelog(content);
+        let parsed: Vec<(&str, Vec<&str>)> = releases
+            .iter()
+            .map(|release| {
+                let entries = release.entries.iter().map(String::as_str).collect();
+                (release.version.as_str(), entries)
+            })
+            .collect();
+        assert_eq!(
+            parsed,
+            vec![
+                ("1.1.0", vec!["Tabs", "Indented entry"]),
+                ("1.0.0", vec!["First release"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_embedded_changelog_covers_the_running_version() {
+        let releases = embedded_changelog();
+        assert_eq!(releases[0].version, env!("CARGO_PKG_VERSION"));
+        assert!(!releases[0].entries.is_empty());
+        assert!(releases_since(Some(env!("CARGO_PKG_VERSION"))).is_empty());
+    }
+}
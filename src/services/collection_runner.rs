@@ -0,0 +1,200 @@
+use crate::models::http_client::{AssertionResult, HttpMethod, HttpRequest, HttpResponse};
+use crate::models::transfer::TransferStats;
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use std::future::Future;
+
+/// Outcome of one request in a collection run
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunResult {
+    /// Tree path of the stored request (used to open it from the results)
+    pub path: String,
+    pub name: String,
+    pub method: HttpMethod,
+    pub url: String,
+    /// `None` when the request failed before a response arrived
+    pub status_code: Option<u16>,
+    pub status_text: String,
+    pub duration_ms: u64,
+    /// Decoded response body size
+    pub size_bytes: usize,
+    pub transfer: TransferStats,
+    pub error: Option<String>,
+    /// Results of the request's assertions (empty when it has none)
+    pub assertions: Vec<AssertionResult>,
+}
+
+impl RunResult {
+    /// A request with assertions passes when all of them pass (so an expected
+    /// 404 can pass); one without passes when it got a 2xx or 3xx response
+    pub fn passed(&self) -> bool {
+        if self.status_code.is_none() {
+            return false;
+        }
+        if self.assertions.is_empty() {
+            self.status_code
+                .is_some_and(|code| (200..400).contains(&code))
+        } else {
+            self.assertions.iter().all(|assertion| assertion.passed)
+        }
+    }
+
+    pub fn failed_assertions(&self) -> impl Iterator<Item = &AssertionResult> {
+        self.assertions.iter().filter(|assertion| !assertion.passed)
+    }
+}
+
+/// A run over every request in one folder, filled in as results arrive
+#[derive(Debug, Clone)]
+pub struct CollectionRun {
+    pub folder: String,
+    /// Number of requests queued for the run
+    pub total: usize,
+    pub results: Vec<RunResult>,
+    pub finished: bool,
+    pub started_at: DateTime<Local>,
+}
+
+impl CollectionRun {
+    pub fn new(folder: impl Into<String>, total: usize) -> Self {
+        Self {
+            folder: folder.into(),
+            total,
+            results: Vec::new(),
+            finished: false,
+            started_at: Local::now(),
+        }
+    }
+
+    pub fn passed_count(&self) -> usize {
+        self.results.iter().filter(|result| result.passed()).count()
+    }
+
+    pub fn failed_count(&self) -> usize {
+        self.results.len() - self.passed_count()
+    }
+
+    /// Sum of the request durations (the run is sequential, so this is the run time)
+    pub fn total_duration_ms(&self) -> u64 {
+        self.results.iter().map(|result| result.duration_ms).sum()
+    }
+}
+
+/// Send `requests` (tree path + request) one after another, reporting each outcome.
+///
+/// `send` sends a single request; it is a parameter so the run can be tested
+/// without a server.
+pub async fn run_requests<F, Fut, R>(
+    requests: Vec<(String, HttpRequest)>,
+    mut send: F,
+    mut report: R,
+) where
+    F: FnMut(HttpRequest) -> Fut,
+    Fut: Future<Output = Result<HttpResponse>>,
+    R: FnMut(RunResult),
+{
+    for (path, request) in requests {
+        let name = request.name.clone();
+        let method = request.method.clone();
+        let url = request.url.clone();
+        let started = std::time::Instant::now();
+
+        let result = match send(request).await {
+            Ok(response) => RunResult {
+                path,
+                name,
+                method,
+                url,
+                status_code: Some(response.status_code),
+                status_text: response.status_text,
+                duration_ms: response.duration_ms,
+                size_bytes: response.body.len(),
+                transfer: response.transfer,
+                error: None,
+                assertions: response.assertions,
+            },
+            Err(error) => RunResult {
+                path,
+                name,
+                method,
+                url,
+                status_code: None,
+                status_text: String::new(),
+                duration_ms: started.elapsed().as_millis() as u64,
+                size_bytes: 0,
+                transfer: TransferStats::default(),
+                error: Some(format!("{:#}", error)),
+                assertions: Vec::new(),
+            },
+        };
+        report(result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    fn response(status_code: u16, status_text: &str) -> HttpResponse {
+        HttpResponse {
+            status_code,
+            status_text: status_text.to_string(),
+            headers: Vec::new(),
+            body: String::new(),
+            body_bytes: Vec::new(),
+            content_type: "application/json".to_string(),
+            duration_ms: 5,
+            served_from_cache: false,
+            connection: Default::default(),
+            transfer: TransferStats::default(),
+            assertions: Vec::new(),
+            extracted: Vec::new(),
+            truncation: None,
+            timing: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_requests_reports_each_result_in_order() {
+        let requests: Vec<(String, HttpRequest)> = ["ok", "missing", "expected", "down"]
+            .iter()
+            .map(|name| {
+                let request =
+                    HttpRequest::new(*name).with_url(format!("https://example.com/{}", name));
+                (format!("api/{}", name), request)
+            })
+            .collect();
+
+        let mut run = CollectionRun::new("api", requests.len());
+        run_requests(
+            requests,
+            |request| async move {
+                match request.name.as_str() {
+                    "ok" => Ok(response(200, "OK")),
+                    "missing" => Ok(response(404, "Not Found")),
+                    "expected" => Ok(HttpResponse {
+                        assertions: vec![AssertionResult {
+                            assertion: "status == 404".to_string(),
+                            passed: true,
+                            message: "got 404".to_string(),
+                        }],
+                        ..response(404, "Not Found")
+                    }),
+                    _ => Err(anyhow!("connection refused")),
+                }
+            },
+            |result| run.results.push(result),
+        )
+        .await;
+
+        let names: Vec<&str> = run.results.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["ok", "missing", "expected", "down"]);
+        assert_eq!(run.results[0].path, "api/ok");
+        assert_eq!(run.passed_count(), 2);
+        assert_eq!(run.failed_count(), 2);
+        assert!(run.results[2].passed());
+        assert_eq!(run.results[3].status_code, None);
+        assert!(run.results[3].error.as_deref().unwrap().contains("refused"));
+    }
+}
@@ -0,0 +1,498 @@
+use crate::models::http_client::{
+    DIRECT_PROXY, HttpAuth, HttpFormField, HttpHeader, HttpMethod, HttpMultipartPart,
+    HttpQueryParam, HttpRequest, HttpRequestBody, RequestSettings,
+};
+use anyhow::{Result, anyhow, bail};
+use reqwest::Url;
+
+/// curl options that take a value we don't use (skipped together with the value)
+const IGNORED_OPTIONS_WITH_VALUE: &[&str] = &[
+    "-o",
+    "--output",
+    "--connect-timeout",
+    "-w",
+    "--write-out",
+    "--retry",
+    "--cert-type",
+    "-c",
+    "--cookie-jar",
+    "--resolve",
+    "-T",
+    "--upload-file",
+];
+
+/// Split curl's `--cert file:password`, leaving a Windows drive letter alone
+fn split_cert_password(cert: &str) -> (&str, &str) {
+    let bytes = cert.as_bytes();
+    let skip = if bytes.len() > 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' {
+        2
+    } else {
+        0
+    };
+    match cert[skip..].find(':') {
+        Some(index) => (&cert[..skip + index], &cert[skip + index + 1..]),
+        None => (cert, ""),
+    }
+}
+
+/// Parse a curl command line (as copied from docs or browser devtools) into a request
+pub fn parse_curl_command(command: &str) -> Result<HttpRequest> {
+    let tokens = tokenize(command)?;
+    let mut tokens = tokens.into_iter();
+
+    match tokens.next().as_deref() {
+        Some("curl") => {}
+        _ => bail!("Command must start with 'curl'"),
+    }
+
+    let mut method: Option<HttpMethod> = None;
+    let mut url: Option<String> = None;
+    let mut headers: Vec<HttpHeader> = Vec::new();
+    let mut data: Vec<String> = Vec::new();
+    let mut urlencoded: Vec<(String, String)> = Vec::new();
+    let mut multipart: Vec<HttpMultipartPart> = Vec::new();
+    let mut upload_file: Option<String> = None;
+    let mut auth = HttpAuth::None;
+    let mut data_as_query = false;
+    let mut settings = RequestSettings::default();
+
+    while let Some(token) = tokens.next() {
+        // Short options may carry their value inline (-XPOST, -H'Accept: */*')
+        let (option, inline_value) = split_short_option(&token);
+        let mut value = |name: &str| -> Result<String> {
+            match &inline_value {
+                Some(value) => Ok(value.clone()),
+                None => tokens
+                    .next()
+                    .ok_or_else(|| anyhow!("Missing value for {}", name)),
+            }
+        };
+
+        match option.as_str() {
+            "-X" | "--request" => {
+                let name = value(&option)?;
+                method = Some(
+                    parse_method(&name).ok_or_else(|| anyhow!("Unsupported method: {}", name))?,
+                );
+            }
+            "-H" | "--header" => {
+                let header = value(&option)?;
+                let (name, header_value) = header
+                    .split_once(':')
+                    .ok_or_else(|| anyhow!("Invalid header: {}", header))?;
+                headers.push(HttpHeader::new(name.trim(), header_value.trim()));
+            }
+            "--data-binary" => {
+                // `@path` sends the file's bytes untouched
+                let binary = value(&option)?;
+                match binary.strip_prefix('@') {
+                    Some(path) => upload_file = Some(path.to_string()),
+                    None => data.push(binary),
+                }
+            }
+            "-T" | "--upload-file" => {
+                upload_file = Some(value(&option)?);
+                method.get_or_insert(HttpMethod::PUT);
+            }
+            "-d" | "--data" | "--data-raw" | "--data-ascii" => {
+                data.push(value(&option)?);
+            }
+            "--data-urlencode" => {
+                let pair = value(&option)?;
+                urlencoded.push(match pair.split_once('=') {
+                    Some((name, pair_value)) => (name.to_string(), pair_value.to_string()),
+                    None => (pair, String::new()),
+                });
+            }
+            "-F" | "--form" => multipart.push(parse_form_part(&value(&option)?)?),
+            "--form-string" => {
+                let pair = value(&option)?;
+                let (name, part_value) = pair
+                    .split_once('=')
+                    .ok_or_else(|| anyhow!("Invalid --form-string: {}", pair))?;
+                multipart.push(HttpMultipartPart::new(name, part_value));
+            }
+            "--json" => {
+                data.push(value(&option)?);
+                headers.push(HttpHeader::new("Content-Type", "application/json"));
+                headers.push(HttpHeader::new("Accept", "application/json"));
+            }
+            "-u" | "--user" => {
+                let credentials = value(&option)?;
+                let (username, password) = credentials
+                    .split_once(':')
+                    .unwrap_or((credentials.as_str(), ""));
+                auth = HttpAuth::Basic {
+                    username: username.to_string(),
+                    password: password.to_string(),
+                };
+            }
+            "-b" | "--cookie" => headers.push(HttpHeader::new("Cookie", value(&option)?)),
+            "-A" | "--user-agent" => headers.push(HttpHeader::new("User-Agent", value(&option)?)),
+            "-e" | "--referer" => headers.push(HttpHeader::new("Referer", value(&option)?)),
+            "--url" => url = Some(value(&option)?),
+            "-G" | "--get" => data_as_query = true,
+            "-I" | "--head" => method = Some(HttpMethod::HEAD),
+            "-k" | "--insecure" => settings.verify_ssl = false,
+            "-L" | "--location" => settings.follow_redirects = true,
+            "-m" | "--max-time" => {
+                let seconds = value(&option)?;
+                let seconds: f64 = seconds
+                    .parse()
+                    .map_err(|_| anyhow!("Invalid --max-time: {}", seconds))?;
+                settings.timeout_secs = seconds.ceil() as u64;
+            }
+            "--cacert" => settings.tls.ca_bundle = value(&option)?,
+            "-E" | "--cert" => {
+                let cert = value(&option)?;
+                let (path, password) = split_cert_password(&cert);
+                settings.tls.client_cert = path.to_string();
+                settings.tls.client_cert_password = password.to_string();
+            }
+            "--key" => settings.tls.client_key = value(&option)?,
+            "--pass" => settings.tls.client_cert_password = value(&option)?,
+            "-x" | "--proxy" => settings.proxy.url = value(&option)?,
+            "-U" | "--proxy-user" => {
+                let credentials = value(&option)?;
+                let (username, password) =
+                    credentials.split_once(':').unwrap_or((&credentials, ""));
+                settings.proxy.username = username.to_string();
+                settings.proxy.password = password.to_string();
+            }
+            // `*` skips every proxy, including the environment's
+            "--noproxy" => match value(&option)? {
+                hosts if hosts.trim() == "*" => settings.proxy.url = DIRECT_PROXY.to_string(),
+                hosts => settings.proxy.no_proxy = hosts,
+            },
+            "--max-redirs" => {
+                let count = value(&option)?;
+                settings.max_redirects = count
+                    .parse()
+                    .map_err(|_| anyhow!("Invalid --max-redirs: {}", count))?;
+            }
+            _ if IGNORED_OPTIONS_WITH_VALUE.contains(&option.as_str()) => {
+                value(&option)?;
+            }
+            _ if option.starts_with('-') && option.len() > 1 => {
+                // Flags such as -s, -v, --compressed don't affect the request
+            }
+            _ => {
+                if url.is_some() {
+                    bail!("Unexpected argument: {}", token);
+                }
+                url = Some(token.clone());
+            }
+        }
+    }
+
+    let url = url.ok_or_else(|| anyhow!("No URL found in curl command"))?;
+    let (base_url, mut query_params) = split_query(&url);
+
+    let has_data = !data.is_empty()
+        || !urlencoded.is_empty()
+        || !multipart.is_empty()
+        || upload_file.is_some();
+    let method = method.unwrap_or(if has_data && !data_as_query {
+        HttpMethod::POST
+    } else {
+        HttpMethod::GET
+    });
+
+    let content_type = headers
+        .iter()
+        .find(|h| h.name.eq_ignore_ascii_case("content-type"))
+        .map(|h| h.value.to_ascii_lowercase());
+
+    let body = if !multipart.is_empty() {
+        HttpRequestBody::Multipart { parts: multipart }
+    } else if let Some(path) = upload_file {
+        // A -H Content-Type is kept as a header and overrides the guessed type
+        HttpRequestBody::File {
+            path,
+            content_type: String::new(),
+        }
+    } else if !has_data {
+        HttpRequestBody::None
+    } else if data_as_query {
+        for part in &data {
+            query_params.extend(
+                decode_pairs(part)
+                    .into_iter()
+                    .map(|(name, value)| HttpQueryParam::new(name, value)),
+            );
+        }
+        query_params.extend(
+            urlencoded
+                .into_iter()
+                .map(|(name, value)| HttpQueryParam::new(name, value)),
+        );
+        HttpRequestBody::None
+    } else {
+        build_body(data, urlencoded, content_type.as_deref())
+    };
+
+    let mut request = HttpRequest::new(request_name(&base_url))
+        .with_method(method)
+        .with_url(base_url);
+    request.headers = headers;
+    request.query_params = query_params;
+    request.body = body;
+    request.auth = auth;
+    request.settings = settings;
+    request.description = "Imported from cURL".to_string();
+
+    Ok(request)
+}
+
+/// A `-F name=value` or `-F name=@path` part; `;type=` and similar suffixes of a file
+/// are dropped, and `<file` (send the file's text) is read as a file upload too
+fn parse_form_part(spec: &str) -> Result<HttpMultipartPart> {
+    let (name, value) = spec
+        .split_once('=')
+        .ok_or_else(|| anyhow!("Invalid -F value: {}", spec))?;
+    match value.strip_prefix('@').or_else(|| value.strip_prefix('<')) {
+        Some(path) => {
+            let path = path.split(';').next().unwrap_or(path);
+            Ok(HttpMultipartPart::file(name, path.trim_matches('"')))
+        }
+        None => Ok(HttpMultipartPart::new(name, value)),
+    }
+}
+
+/// Pick a body type for the collected `-d` / `--data-urlencode` values
+fn build_body(
+    data: Vec<String>,
+    urlencoded: Vec<(String, String)>,
+    content_type: Option<&str>,
+) -> HttpRequestBody {
+    let joined = data.join("&");
+    let looks_like_json = joined.trim_start().starts_with(['{', '[']);
+
+    if content_type.is_some_and(|ct| ct.contains("json"))
+        || (content_type.is_none() && looks_like_json)
+    {
+        return HttpRequestBody::Json { content: joined };
+    }
+
+    let is_form = content_type.is_none_or(|ct| ct.contains("x-www-form-urlencoded"));
+    if is_form && data.iter().all(|part| part.contains('=')) {
+        let mut fields: Vec<HttpFormField> = data
+            .iter()
+            .flat_map(|part| decode_pairs(part))
+            .map(|(name, value)| HttpFormField::new(name, value))
+            .collect();
+        fields.extend(
+            urlencoded
+                .into_iter()
+                .map(|(name, value)| HttpFormField::new(name, value)),
+        );
+        return HttpRequestBody::Form { fields };
+    }
+
+    match content_type {
+        Some(content_type) => HttpRequestBody::Text {
+            content: joined,
+            content_type: content_type.to_string(),
+        },
+        None => HttpRequestBody::Raw { content: joined },
+    }
+}
+
+/// Split a URL into its base and decoded query parameters
+fn split_query(url: &str) -> (String, Vec<HttpQueryParam>) {
+    match url.split_once('?') {
+        Some((base, query)) => (
+            base.to_string(),
+            decode_pairs(query)
+                .into_iter()
+                .map(|(name, value)| HttpQueryParam::new(name, value))
+                .collect(),
+        ),
+        None => (url.to_string(), Vec::new()),
+    }
+}
+
+/// Decode an `a=1&b=two%20words` string into pairs
+fn decode_pairs(encoded: &str) -> Vec<(String, String)> {
+    Url::parse(&format!("http://localhost/?{}", encoded))
+        .map(|url| url.query_pairs().into_owned().collect())
+        .unwrap_or_default()
+}
+
+/// A readable request name from the URL's last path segment
+fn request_name(url: &str) -> String {
+    Url::parse(url)
+        .ok()
+        .and_then(|parsed| {
+            let segment = parsed
+                .path_segments()?
+                .rev()
+                .find(|segment| !segment.is_empty())
+                .map(str::to_string);
+            segment.or_else(|| parsed.host_str().map(str::to_string))
+        })
+        .unwrap_or_else(|| "Imported cURL".to_string())
+}
+
+fn parse_method(name: &str) -> Option<HttpMethod> {
+    HttpMethod::all()
+        .into_iter()
+        .find(|method| method.as_str().eq_ignore_ascii_case(name))
+}
+
+/// Split `-XPOST` into ("-X", Some("POST")); long options and bare flags are returned as-is
+fn split_short_option(token: &str) -> (String, Option<String>) {
+    const SHORT_WITH_VALUE: &[char] = &['X', 'H', 'd', 'u', 'b', 'A', 'e', 'm'];
+
+    let mut chars = token.chars();
+    if chars.next() == Some('-')
+        && let Some(flag) = chars.next()
+        && SHORT_WITH_VALUE.contains(&flag)
+        && token.len() > 2
+    {
+        return (format!("-{}", flag), Some(token[2..].to_string()));
+    }
+    (token.to_string(), None)
+}
+
+/// Split a shell command into words, honouring quotes, escapes and line continuations
+fn tokenize(command: &str) -> Result<Vec<String>> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_token = false;
+    let mut chars = command.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                in_token = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => current.push(c),
+                        None => bail!("Unterminated single quote"),
+                    }
+                }
+            }
+            '"' => {
+                in_token = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c @ ('"' | '\\' | '$' | '`')) => current.push(c),
+                            Some('\n') => {}
+                            Some(c) => {
+                                current.push('\\');
+                                current.push(c);
+                            }
+                            None => bail!("Unterminated double quote"),
+                        },
+                        Some(c) => current.push(c),
+                        None => bail!("Unterminated double quote"),
+                    }
+                }
+            }
+            '\\' => match chars.next() {
+                // Line continuation
+                Some('\n') => {}
+                Some('\r') if chars.peek() == Some(&'\n') => {
+                    chars.next();
+                }
+                Some(c) => {
+                    in_token = true;
+                    current.push(c);
+                }
+                None => {}
+            },
+            c if c.is_whitespace() => {
+                if in_token {
+                    tokens.push(std::mem::take(&mut current));
+                    in_token = false;
+                }
+            }
+            c => {
+                in_token = true;
+                current.push(c);
+            }
+        }
+    }
+    if in_token {
+        tokens.push(current);
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_curl_command() {
+        let request = parse_curl_command(
+            "curl -X PATCH 'https://api.example.com/v1/users/42?expand=roles&q=a%20b' \\\n  \
+             -H 'Content-Type: application/json' -H \"Authorization: Bearer abc\" \\\n  \
+             --data-raw '{\"name\": \"Ada\"}' --compressed",
+        )
+        .unwrap();
+
+        assert_eq!(request.method, HttpMethod::PATCH);
+        assert_eq!(request.url, "https://api.example.com/v1/users/42");
+        assert_eq!(request.name, "42");
+        assert_eq!(request.query_params.len(), 2);
+        assert_eq!(request.query_params[1].value, "a b");
+        assert_eq!(request.headers.len(), 2);
+        assert_eq!(request.headers[1].value, "Bearer abc");
+        assert_eq!(
+            request.body,
+            HttpRequestBody::Json {
+                content: "{\"name\": \"Ada\"}".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_curl_form_and_auth() {
+        let request = parse_curl_command(
+            "curl -u admin:s3cret https://example.com/login -d user=ada \
+             --data-urlencode 'note=hello world'",
+        )
+        .unwrap();
+
+        assert_eq!(request.method, HttpMethod::POST);
+        assert_eq!(
+            request.auth,
+            HttpAuth::Basic {
+                username: "admin".to_string(),
+                password: "s3cret".to_string()
+            }
+        );
+        assert_eq!(
+            request.body,
+            HttpRequestBody::Form {
+                fields: vec![
+                    HttpFormField::new("user", "ada"),
+                    HttpFormField::new("note", "hello world"),
+                ]
+            }
+        );
+
+        let upload =
+            parse_curl_command("curl https://example.com/blobs --data-binary @./image.png")
+                .unwrap();
+        assert_eq!(upload.method, HttpMethod::POST);
+        assert_eq!(
+            upload.body,
+            HttpRequestBody::File {
+                path: "./image.png".to_string(),
+                content_type: String::new()
+            }
+        );
+        let put = parse_curl_command("curl -T dump.bin https://example.com/put").unwrap();
+        assert_eq!(put.method, HttpMethod::PUT);
+
+        assert!(parse_curl_command("wget https://example.com").is_err());
+        assert!(parse_curl_command("curl -s").is_err());
+    }
+}
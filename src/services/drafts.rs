@@ -0,0 +1,151 @@
+use crate::models::FormField;
+use crate::models::config::{data_directory, is_read_only_storage};
+use crate::models::http_client::HttpRequest;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How often the in-progress work is written to the drafts area
+pub const AUTOSAVE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A single automation form value captured in a draft
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DraftFieldValue {
+    pub name: String,
+    pub value: String,
+}
+
+/// Snapshot of the in-progress HTTP request and automation form
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DraftContent {
+    pub http_request_key: String,
+    pub http_request: HttpRequest,
+    pub automation_fields: Vec<DraftFieldValue>,
+}
+
+impl DraftContent {
+    /// Capture the current state of both modes
+    pub fn capture(
+        http_request_key: &str,
+        http_request: &HttpRequest,
+        automation_fields: &[FormField],
+    ) -> Self {
+        Self {
+            http_request_key: http_request_key.to_string(),
+            http_request: http_request.clone(),
+            automation_fields: automation_fields
+                .iter()
+                .map(|field| DraftFieldValue {
+                    name: field.name.clone(),
+                    value: field.value.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Copy the captured values back onto matching form fields (by name)
+    pub fn apply_to_fields(&self, fields: &mut [FormField]) {
+        for field in fields.iter_mut() {
+            if let Some(saved) = self.automation_fields.iter().find(|f| f.name == field.name) {
+                field.value = saved.value.clone();
+            }
+        }
+    }
+}
+
+/// A draft as stored on disk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Draft {
+    pub saved_at: DateTime<Local>,
+    pub content: DraftContent,
+}
+
+/// Autosave area for unsaved work, independent of collections and templates
+#[derive(Debug, Clone)]
+pub struct DraftStore {
+    path: PathBuf,
+}
+
+impl Default for DraftStore {
+    fn default() -> Self {
+        Self::new(data_directory().join("drafts").join("autosave.json"))
+    }
+}
+
+impl DraftStore {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Read the stored draft, if any
+    pub fn load(&self) -> Option<Draft> {
+        let content = std::fs::read_to_string(&self.path).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Write a draft, replacing the previous one atomically
+    pub fn save(&self, content: &DraftContent) -> Result<()> {
+        if is_read_only_storage() {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create drafts directory")?;
+        }
+
+        let draft = Draft {
+            saved_at: Local::now(),
+            content: content.clone(),
+        };
+        let temp_path = self.path.with_extension("json.tmp");
+        std::fs::write(&temp_path, serde_json::to_string_pretty(&draft)?)
+            .context("Failed to write draft")?;
+        std::fs::rename(&temp_path, &self.path).context("Failed to replace draft")?;
+
+        Ok(())
+    }
+
+    /// Remove the stored draft
+    pub fn discard(&self) -> Result<()> {
+        // Keep the draft for the next normal launch
+        if is_read_only_storage() {
+            return Ok(());
+        }
+        match std::fs::remove_file(&self.path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e).context("Failed to remove draft"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::FieldType;
+
+    #[test]
+    fn test_draft_roundtrip() {
+        let temp = tempfile::tempdir().unwrap();
+        let store = DraftStore::new(temp.path().join("drafts").join("autosave.json"));
+        assert!(store.load().is_none());
+
+        let mut fields = vec![FormField::new("Project Name", "#name", FieldType::Text)];
+        fields[0].value = "Apollo".to_string();
+        let request = HttpRequest::new("Users").with_url("https://api.example.com/users");
+        let content = DraftContent::capture("draft-1", &request, &fields);
+        store.save(&content).unwrap();
+
+        let draft = store.load().unwrap();
+        assert_eq!(draft.content, content);
+
+        fields[0].value.clear();
+        draft.content.apply_to_fields(&mut fields);
+        assert_eq!(fields[0].value, "Apollo");
+
+        store.discard().unwrap();
+        assert!(store.load().is_none());
+        store.discard().unwrap();
+    }
+}
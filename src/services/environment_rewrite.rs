@@ -0,0 +1,109 @@
+use crate::models::KeyValuePair;
+use crate::models::http_client::HttpRequest;
+use crate::services::pre_request_script::for_each_text_mut;
+
+/// Replace literal values of the `from` environment with the `to` environment's values for
+/// the same variable names, returning how many occurrences were rewritten.
+///
+/// `{{name}}` placeholders are left alone: they already follow whichever environment is
+/// active when the request is sent.
+pub fn rewrite_for_environment(
+    request: &mut HttpRequest,
+    from: &[KeyValuePair],
+    to: &[KeyValuePair],
+) -> usize {
+    let mut replacements: Vec<(&str, &str)> = from
+        .iter()
+        .filter(|variable| variable.enabled && !variable.value.is_empty())
+        .filter_map(|variable| {
+            let target = to
+                .iter()
+                .find(|candidate| candidate.enabled && candidate.name == variable.name)?;
+            (target.value != variable.value)
+                .then_some((variable.value.as_str(), target.value.as_str()))
+        })
+        .collect();
+    // Longest first, so `https://api.staging.example.com` wins over `staging`
+    replacements.sort_by_key(|(value, _)| std::cmp::Reverse(value.len()));
+
+    let mut count = 0;
+    for_each_text_mut(request, |text| {
+        count += replace_values(text, &replacements);
+    });
+    count
+}
+
+/// Replace each value in a single pass, so a replacement is never rewritten again
+fn replace_values(text: &mut String, replacements: &[(&str, &str)]) -> usize {
+    if replacements.is_empty() || text.is_empty() {
+        return 0;
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut count = 0;
+    let mut rest = text.as_str();
+    while !rest.is_empty() {
+        match replacements
+            .iter()
+            .find(|(value, _)| rest.starts_with(value))
+        {
+            Some((value, replacement)) => {
+                result.push_str(replacement);
+                rest = &rest[value.len()..];
+                count += 1;
+            }
+            None => {
+                let next = rest.chars().next().map_or(1, char::len_utf8);
+                result.push_str(&rest[..next]);
+                rest = &rest[next..];
+            }
+        }
+    }
+    if count > 0 {
+        *text = result;
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::http_client::HttpAuth;
+
+    #[test]
+    fn test_rewrite_for_environment() {
+        let staging = vec![
+            KeyValuePair::new("base_url", "https://staging.example.com"),
+            KeyValuePair::new("tenant", "staging"),
+            KeyValuePair::new("token", "stg-token"),
+            KeyValuePair::new("only_here", "keep-me"),
+        ];
+        let prod = vec![
+            KeyValuePair::new("base_url", "https://api.example.com"),
+            KeyValuePair::new("tenant", "prod"),
+            KeyValuePair::new("token", "prd-token"),
+        ];
+
+        let mut request = HttpRequest::new("List")
+            .with_url("https://staging.example.com/v1/staging/items")
+            .with_header("X-Tenant", "staging")
+            .with_header("X-Other", "keep-me {{base_url}}");
+        request.auth = HttpAuth::Bearer {
+            token: "stg-token".to_string(),
+        };
+
+        assert_eq!(rewrite_for_environment(&mut request, &staging, &prod), 4);
+        assert_eq!(request.url, "https://api.example.com/v1/prod/items");
+        assert_eq!(request.headers[0].value, "prod");
+        assert_eq!(request.headers[1].value, "keep-me {{base_url}}");
+        assert_eq!(
+            request.auth,
+            HttpAuth::Bearer {
+                token: "prd-token".to_string()
+            }
+        );
+
+        // Nothing left to rewrite the second time
+        assert_eq!(rewrite_for_environment(&mut request, &staging, &prod), 0);
+    }
+}
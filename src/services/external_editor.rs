@@ -0,0 +1,91 @@
+use anyhow::{Context, Result, bail};
+use std::io::Write;
+use std::process::Command;
+
+/// The user's editor: `$VISUAL`, then `$EDITOR`, then the platform default
+pub fn editor_command() -> String {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| {
+            if cfg!(windows) {
+                "notepad".to_string()
+            } else {
+                "vi".to_string()
+            }
+        })
+}
+
+/// Open `content` in `command` (e.g. `vim` or `code --wait`) and return what
+/// was saved once the editor exits.
+///
+/// The text goes through a temporary file named with `extension`, so editors
+/// pick the right syntax; the file is removed afterwards. The terminal has to
+/// be handed over to the editor before calling this.
+pub fn edit_text(command: &str, content: &str, extension: &str) -> Result<String> {
+    let mut words = command.split_whitespace();
+    let Some(program) = words.next() else {
+        bail!("No editor set ($VISUAL or $EDITOR)");
+    };
+
+    let path = std::env::temp_dir().join(format!(
+        "tui_one-edit-{}.{}",
+        uuid::Uuid::new_v4(),
+        extension
+    ));
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    // Bodies can carry tokens; keep the file private while it exists
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options
+        .open(&path)
+        .context("Failed to create a temporary file for the editor")?;
+    file.write_all(content.as_bytes())?;
+    drop(file);
+
+    let result = Command::new(program)
+        .args(words)
+        .arg(&path)
+        .status()
+        .with_context(|| format!("Failed to run {}", program))
+        .and_then(|status| {
+            if !status.success() {
+                bail!("{} exited with {}; nothing was changed", program, status);
+            }
+            std::fs::read_to_string(&path).context("Failed to read the edited file back")
+        });
+    let _ = std::fs::remove_file(&path);
+
+    // Editors end the file with a newline the field never had
+    result.map(|edited| match edited.strip_suffix('\n') {
+        Some(stripped) if !content.ends_with('\n') => {
+            stripped.strip_suffix('\r').unwrap_or(stripped).to_string()
+        }
+        _ => edited,
+    })
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_text_returns_saved_content() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // A stand-in editor that replaces the file's content
+        let temp = tempfile::tempdir().unwrap();
+        let script = temp.path().join("editor.sh");
+        std::fs::write(&script, "#!/bin/sh\nprintf '{\"a\": 2}\\n' > \"$1\"\n").unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let edited = edit_text(script.to_str().unwrap(), "{\"a\": 1}", "json").unwrap();
+        assert_eq!(edited, "{\"a\": 2}");
+
+        assert_eq!(edit_text("true", "keep\n", "txt").unwrap(), "keep\n");
+        assert!(edit_text("false", "{}", "json").is_err());
+        assert!(edit_text("  ", "{}", "json").is_err());
+    }
+}
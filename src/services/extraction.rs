@@ -0,0 +1,140 @@
+use crate::models::KeyValuePair;
+use crate::models::http_client::HttpResponse;
+use crate::services::assertions::{display_value, resolve_subject};
+
+/// Subjects a rule may start with; anything else is read as a JSON path
+const SUBJECTS: [&str; 6] = ["status", "header", "json", "body", "time", "size"];
+
+/// Variables captured from one response, plus the rules that could not be applied
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Extraction {
+    pub variables: Vec<KeyValuePair>,
+    pub errors: Vec<String>,
+}
+
+/// Apply every extraction rule in `rules` to `response`.
+///
+/// One `name = source` rule per line, blank lines and `#` comments are skipped:
+///
+/// ```text
+/// token = $.data.access_token          # JSONPath / jq filter on the body
+/// user_id = json .user.id
+/// request_id = header X-Request-Id
+/// last_status = status                 # also body, time and size
+/// ```
+///
+/// Strings are stored without their quotes, other JSON values as compact JSON.
+pub fn extract_variables(rules: &str, response: &HttpResponse) -> Extraction {
+    let mut extraction = Extraction::default();
+
+    for (index, line) in rules.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match extract_line(line, response) {
+            Ok(variable) => extraction.variables.push(variable),
+            Err(error) => extraction
+                .errors
+                .push(format!("Extract line {}: {}", index + 1, error)),
+        }
+    }
+
+    extraction
+}
+
+fn extract_line(line: &str, response: &HttpResponse) -> Result<KeyValuePair, String> {
+    let (name, source) = line
+        .split_once('=')
+        .ok_or_else(|| "Expected 'name = source'".to_string())?;
+    let (name, source) = (name.trim(), source.trim());
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err(format!("'{}' is not a variable name", name));
+    }
+    if source.is_empty() {
+        return Err(format!("Nothing to extract into '{}'", name));
+    }
+
+    let first_word = source.split_whitespace().next().unwrap_or_default();
+    let source = if SUBJECTS.contains(&first_word) {
+        source.to_string()
+    } else {
+        format!("json {}", source)
+    };
+
+    let (value, rest) = resolve_subject(&source, response)?;
+    if !rest.trim().is_empty() {
+        return Err(format!("Unexpected '{}' after the source", rest.trim()));
+    }
+    let value = value.ok_or_else(|| format!("{} not found in the response", source))?;
+
+    Ok(KeyValuePair::new(name, display_value(&value)))
+}
+
+/// Store captured variables in the environment, updating existing names in place
+pub fn apply_variables(environment: &mut Vec<KeyValuePair>, variables: &[KeyValuePair]) {
+    for variable in variables {
+        match environment.iter_mut().find(|var| var.name == variable.name) {
+            Some(existing) => {
+                existing.value = variable.value.clone();
+                existing.enabled = true;
+            }
+            None => environment.push(variable.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::http_client::HttpHeader;
+    use crate::models::transfer::TransferStats;
+
+    #[test]
+    fn test_extract_and_apply_variables() {
+        let response = HttpResponse {
+            status_code: 200,
+            status_text: "OK".to_string(),
+            headers: vec![HttpHeader::new("X-Request-Id", "req-7")],
+            body: r#"{"data": {"token": "abc", "user": {"id": 42}}}"#.to_string(),
+            body_bytes: Vec::new(),
+            content_type: "application/json".to_string(),
+            duration_ms: 15,
+            served_from_cache: false,
+            connection: Default::default(),
+            transfer: TransferStats::default(),
+            assertions: Vec::new(),
+            extracted: Vec::new(),
+            truncation: None,
+            timing: Default::default(),
+        };
+        let rules = "\
+# chained into the next request
+token = $.data.token
+user_id = json .data.user.id
+request_id = header X-Request-Id
+code = status
+missing = $.data.nope
+broken";
+
+        let extraction = extract_variables(rules, &response);
+        assert_eq!(
+            extraction.variables,
+            vec![
+                KeyValuePair::new("token", "abc"),
+                KeyValuePair::new("user_id", "42"),
+                KeyValuePair::new("request_id", "req-7"),
+                KeyValuePair::new("code", "200"),
+            ]
+        );
+        assert_eq!(extraction.errors.len(), 2);
+        assert!(extraction.errors[0].starts_with("Extract line 6:"));
+
+        let mut disabled = KeyValuePair::new("token", "old");
+        disabled.enabled = false;
+        let mut environment = vec![disabled, KeyValuePair::new("base", "https://api")];
+        apply_variables(&mut environment, &extraction.variables);
+        assert_eq!(environment.len(), 5);
+        assert_eq!(environment[0], KeyValuePair::new("token", "abc"));
+    }
+}
@@ -0,0 +1,211 @@
+use crate::models::storage_format::{is_item_file, read_item_file};
+use crate::models::{AppConfig, LogLevel};
+use crate::services::proxy::proxy_url;
+use crate::services::storage_migration::expand_user_path;
+use crate::services::{StoredHttpRequest, StoredTemplate};
+use serde::de::DeserializeOwned;
+use std::path::Path;
+
+/// What was found on launch, so problems show up before they interrupt a task
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthReport {
+    pub templates: usize,
+    pub requests: usize,
+    pub environment: String,
+    pub environment_variables: usize,
+    /// `python3 --version`, or why it couldn't be run
+    pub python: Result<String, String>,
+    pub config_warnings: Vec<String>,
+    /// Item files that could not be parsed, with the reason
+    pub broken_files: Vec<String>,
+}
+
+impl HealthReport {
+    /// Number of things that need the user's attention
+    pub fn problem_count(&self) -> usize {
+        self.config_warnings.len() + self.broken_files.len() + usize::from(self.python.is_err())
+    }
+
+    /// Log-ready lines: an overview, then one line per problem
+    pub fn summary_lines(&self) -> Vec<(LogLevel, String)> {
+        let mut lines = vec![(
+            LogLevel::Info,
+            format!(
+                "Startup check: {} templates, {} requests, environment '{}' ({} variables)",
+                self.templates, self.requests, self.environment, self.environment_variables
+            ),
+        )];
+        match &self.python {
+            Ok(version) => lines.push((LogLevel::Info, format!("Python: {}", version))),
+            Err(error) => lines.push((
+                LogLevel::Warn,
+                format!("Python unavailable, automation won't run: {}", error),
+            )),
+        }
+        for warning in &self.config_warnings {
+            lines.push((LogLevel::Warn, format!("Config: {}", warning)));
+        }
+        for broken in &self.broken_files {
+            lines.push((LogLevel::Warn, format!("Broken file: {}", broken)));
+        }
+        lines
+    }
+}
+
+/// Count and parse every stored item, check python and the config; blocking
+pub fn run_health_check(config: &AppConfig, config_error: Option<String>) -> HealthReport {
+    let mut broken_files = Vec::new();
+    let templates =
+        scan_items::<StoredTemplate>(config.get_templates_directory(), &mut broken_files);
+    let requests =
+        scan_items::<StoredHttpRequest>(&config.get_collections_directory(), &mut broken_files);
+    broken_files.sort();
+
+    HealthReport {
+        templates,
+        requests,
+        environment: config.active_environment_name().to_string(),
+        environment_variables: config
+            .environment_variables_of(config.active_environment_name())
+            .map_or(0, |variables| variables.len()),
+        python: python_version(),
+        config_warnings: config_warnings(config, config_error),
+        broken_files,
+    }
+}
+
+fn python_version() -> Result<String, String> {
+    let output = std::process::Command::new("python3")
+        .arg("--version")
+        .output()
+        .map_err(|e| format!("python3 not found ({})", e))?;
+    if !output.status.success() {
+        return Err(format!("python3 --version exited with {}", output.status));
+    }
+    // Older versions print to stderr
+    let text = if output.stdout.is_empty() {
+        output.stderr
+    } else {
+        output.stdout
+    };
+    Ok(String::from_utf8_lossy(&text).trim().to_string())
+}
+
+fn config_warnings(config: &AppConfig, config_error: Option<String>) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if let Some(error) = config_error {
+        warnings.push(format!(
+            "config file could not be loaded, using defaults ({})",
+            error
+        ));
+    }
+    if let Some(active) = &config.active_environment
+        && config.environment_variables_of(active).is_none()
+    {
+        warnings.push(format!("active environment '{}' does not exist", active));
+    }
+    let directories = [
+        ("templates", &config.custom_templates_dir),
+        ("collections", &config.custom_collections_dir),
+    ];
+    for (what, dir) in directories {
+        if let Some(dir) = dir
+            && !dir.is_dir()
+        {
+            warnings.push(format!("{} directory {} is missing", what, dir.display()));
+        }
+    }
+    let certificates = [
+        ("CA bundle", &config.tls.ca_bundle),
+        ("client certificate", &config.tls.client_cert),
+        ("client key", &config.tls.client_key),
+    ];
+    for (what, path) in certificates {
+        if !path.trim().is_empty() && !expand_user_path(path).is_file() {
+            warnings.push(format!("{} {} not found", what, path));
+        }
+    }
+    let proxy = config.active_proxy();
+    if !proxy.is_empty()
+        && !proxy.is_direct()
+        && let Err(e) = proxy_url(&proxy.url)
+    {
+        warnings.push(format!("proxy: {}", e));
+    }
+    warnings
+}
+
+/// Count the items below `root` that parse, recording the ones that don't
+fn scan_items<T: DeserializeOwned>(root: &Path, broken: &mut Vec<String>) -> usize {
+    let mut count = 0;
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for path in entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+        {
+            if path.is_dir() {
+                pending.push(path);
+            } else if is_item_file(&path) {
+                match read_item_file::<T>(&path) {
+                    Ok(_) => count += 1,
+                    Err(e) => {
+                        let shown = path.strip_prefix(root).unwrap_or(&path);
+                        broken.push(format!("{} ({:#})", shown.display(), e));
+                    }
+                }
+            }
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_health_check_counts_items_and_reports_problems() {
+        let temp = tempfile::tempdir().unwrap();
+        let templates = temp.path().join("templates");
+        let collections = temp.path().join("collections");
+        std::fs::create_dir_all(templates.join("Daily")).unwrap();
+        std::fs::create_dir_all(&collections).unwrap();
+
+        let stored = StoredTemplate::new(crate::models::AutomationTemplate::new("Standup", ""));
+        crate::models::storage_format::write_item_file(
+            &templates.join("Daily").join("Standup.json"),
+            &stored,
+        )
+        .unwrap();
+        std::fs::write(templates.join("Broken.json"), "{ not json").unwrap();
+
+        let mut config = AppConfig {
+            custom_templates_dir: Some(templates),
+            custom_collections_dir: Some(collections),
+            active_environment: Some("Staging".to_string()),
+            ..AppConfig::default()
+        };
+        config.tls.ca_bundle = temp.path().join("ca.pem").display().to_string();
+
+        let report = run_health_check(&config, Some("Failed to parse config file".to_string()));
+        assert_eq!(report.templates, 1);
+        assert_eq!(report.requests, 0);
+        assert_eq!(report.broken_files.len(), 1);
+        assert!(report.broken_files[0].starts_with("Broken.json"));
+        assert_eq!(report.config_warnings.len(), 3);
+        assert!(report.config_warnings[1].contains("'Staging'"));
+        assert!(report.problem_count() >= 4);
+
+        let lines = report.summary_lines();
+        assert!(lines[0].1.contains("1 templates, 0 requests"));
+        assert!(
+            lines
+                .iter()
+                .any(|(level, line)| *level == LogLevel::Warn && line.starts_with("Broken file"))
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use crate::models::config::{HistoryRetention, data_directory, is_read_only_storage};
+use crate::models::http_client::HttpRequest;
+use crate::models::search::{MAX_SEARCH_RESULTS, fuzzy_score};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Local};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::PathBuf;
+
+/// One sent request and the outcome it got
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub sent_at: DateTime<Local>,
+    pub request: HttpRequest,
+    /// `None` when the request failed before a response arrived
+    pub status_code: Option<u16>,
+    pub status_text: String,
+    pub duration_ms: u64,
+    pub size_bytes: usize,
+    #[serde(default)]
+    pub error: Option<String>,
+    /// Response body, kept when it's small enough to diff against later
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_body: Option<String>,
+    #[serde(default)]
+    pub content_type: String,
+}
+
+/// Bodies larger than this aren't stored in the history
+pub const MAX_HISTORY_BODY_BYTES: usize = 256 * 1024;
+
+/// Append-only request history (one JSON entry per line), oldest first
+#[derive(Debug, Clone)]
+pub struct HistoryStorage {
+    path: PathBuf,
+}
+
+impl Default for HistoryStorage {
+    fn default() -> Self {
+        Self::new(data_directory().join("history").join("history.jsonl"))
+    }
+}
+
+impl HistoryStorage {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Read every entry; lines that no longer parse are skipped
+    pub fn load(&self) -> Vec<HistoryEntry> {
+        let Ok(content) = std::fs::read_to_string(&self.path) else {
+            return Vec::new();
+        };
+        content
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect()
+    }
+
+    /// Append one entry without rewriting the file
+    pub fn append(&self, entry: &HistoryEntry) -> Result<()> {
+        if is_read_only_storage() {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create history directory")?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .context("Failed to open history file")?;
+        writeln!(file, "{}", serde_json::to_string(entry)?).context("Failed to write history")?;
+        Ok(())
+    }
+
+    /// Replace the whole history (after pruning or deleting entries)
+    pub fn rewrite(&self, entries: &[HistoryEntry]) -> Result<()> {
+        if is_read_only_storage() {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create history directory")?;
+        }
+        let mut content = String::new();
+        for entry in entries {
+            content.push_str(&serde_json::to_string(entry)?);
+            content.push('\n');
+        }
+        let temp_path = self.path.with_extension("jsonl.tmp");
+        std::fs::write(&temp_path, content).context("Failed to write history")?;
+        std::fs::rename(&temp_path, &self.path).context("Failed to replace history")?;
+        Ok(())
+    }
+}
+
+/// Drop entries beyond the retention limits; returns whether anything was removed
+pub fn prune_history(entries: &mut Vec<HistoryEntry>, retention: HistoryRetention) -> bool {
+    let before = entries.len();
+
+    if retention.max_age_days > 0 {
+        let cutoff = Local::now() - Duration::days(i64::from(retention.max_age_days));
+        entries.retain(|entry| entry.sent_at >= cutoff);
+    }
+    if entries.len() > retention.max_entries {
+        let excess = entries.len() - retention.max_entries;
+        entries.drain(..excess);
+    }
+
+    entries.len() != before
+}
+
+/// Recent distinct sends (by method and URL) matching a fuzzy `query`, as indices.
+///
+/// Newest first when the query is empty, otherwise best match first.
+pub fn search_recent_sends(entries: &[HistoryEntry], query: &str) -> Vec<usize> {
+    let mut seen = std::collections::HashSet::new();
+    let mut scored: Vec<(u32, usize)> = entries
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, entry)| seen.insert((entry.request.method.as_str(), &entry.request.url)))
+        .filter_map(|(index, entry)| {
+            let status = entry
+                .status_code
+                .map_or_else(|| "ERR".to_string(), |code| code.to_string());
+            let text = format!(
+                "{} {} {} {}",
+                entry.request.method.as_str(),
+                entry.request.url,
+                entry.request.name,
+                status
+            );
+            fuzzy_score(query, &text).map(|score| (score, index))
+        })
+        .collect();
+    // Stable, so equal scores stay newest first
+    scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+    scored
+        .into_iter()
+        .take(MAX_SEARCH_RESULTS)
+        .map(|(_, index)| index)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, days_ago: i64) -> HistoryEntry {
+        HistoryEntry {
+            sent_at: Local::now() - Duration::days(days_ago),
+            request: HttpRequest::new(name),
+            status_code: Some(200),
+            status_text: "OK".to_string(),
+            duration_ms: 12,
+            size_bytes: 34,
+            error: None,
+            response_body: Some("{}".to_string()),
+            content_type: "application/json".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_history_append_and_prune() {
+        let temp = tempfile::tempdir().unwrap();
+        let storage = HistoryStorage::new(temp.path().join("history").join("history.jsonl"));
+        assert!(storage.load().is_empty());
+
+        for (name, days_ago) in [("old", 40), ("a", 2), ("b", 1), ("c", 0)] {
+            storage.append(&entry(name, days_ago)).unwrap();
+        }
+        let mut entries = storage.load();
+        assert_eq!(entries.len(), 4);
+
+        let retention = HistoryRetention {
+            max_entries: 2,
+            max_age_days: 30,
+        };
+        assert!(prune_history(&mut entries, retention));
+        let names: Vec<&str> = entries.iter().map(|e| e.request.name.as_str()).collect();
+        assert_eq!(names, vec!["b", "c"]);
+
+        storage.rewrite(&entries).unwrap();
+        assert_eq!(storage.load(), entries);
+        assert!(!prune_history(&mut entries, retention));
+    }
+
+    #[test]
+    fn test_search_recent_sends() {
+        let mut entries = vec![
+            entry("List users", 3),
+            entry("Health", 2),
+            entry("List users again", 1),
+        ];
+        entries[0].request.url = "https://api.example.com/users".to_string();
+        entries[1].request.url = "https://api.example.com/health".to_string();
+        entries[2].request.url = "https://api.example.com/users".to_string();
+
+        // The same method and URL is listed once, newest first
+        assert_eq!(search_recent_sends(&entries, ""), vec![2, 1]);
+        assert_eq!(search_recent_sends(&entries, "hlth"), vec![1]);
+        assert_eq!(search_recent_sends(&entries, "get users 200"), vec![2]);
+        assert!(search_recent_sends(&entries, "delete").is_empty());
+    }
+}
@@ -1,9 +1,20 @@
+use crate::models::folder_settings::{FOLDER_SETTINGS_STEM, FolderInheritance, FolderSettings};
+use crate::models::storage_format::{
+    find_item_file, is_item_file, item_file_path, read_folder_readme, read_item_file,
+    write_item_file,
+};
 use crate::models::{AppConfig, http_client::*};
+use crate::services::bruno_import::parse_bruno_collection;
+use crate::services::insomnia_import::parse_insomnia_export;
+use crate::services::openapi_import::parse_openapi_spec;
+use crate::services::postman_import::{
+    PostmanImportProgress, import_parsed_collection, import_postman_streaming,
+};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use uuid::Uuid;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 
 /// Postman Collection v2.1 format structures
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +37,7 @@ pub struct PostmanInfo {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
 pub enum PostmanItem {
     Request(PostmanRequest),
     Folder(PostmanFolder),
@@ -98,15 +110,45 @@ pub struct PostmanBody {
     pub raw: Option<String>,
     pub formdata: Option<Vec<PostmanFormData>>,
     pub urlencoded: Option<Vec<PostmanUrlEncoded>>,
+    pub file: Option<PostmanFile>,
+}
+
+/// Body of mode "file": a binary file sent as-is
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostmanFile {
+    pub src: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PostmanFormData {
     pub key: String,
+    #[serde(default)]
     pub value: String,
     pub disabled: Option<bool>,
     #[serde(rename = "type")]
     pub field_type: Option<String>, // "text", "file"
+    /// Path of a file field: a string, or a list of paths
+    #[serde(default)]
+    pub src: Option<serde_json::Value>,
+}
+
+impl PostmanFormData {
+    fn is_file(&self) -> bool {
+        self.field_type.as_deref() == Some("file")
+    }
+
+    /// First path of a file field
+    fn file_path(&self) -> String {
+        match &self.src {
+            Some(serde_json::Value::String(path)) => path.clone(),
+            Some(serde_json::Value::Array(paths)) => paths
+                .first()
+                .and_then(|path| path.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            _ => String::new(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -201,7 +243,14 @@ impl StoredHttpRequest {
 
     pub fn mark_as_used(&mut self) {
         self.last_used_at = Some(chrono::Utc::now());
-        self.modified_at = chrono::Utc::now();
+    }
+
+    /// Replace the request data, bumping `modified_at` only when it actually changed
+    pub fn update_request(&mut self, request: HttpRequest) {
+        if self.request != request {
+            self.request = request;
+            self.modified_at = chrono::Utc::now();
+        }
     }
 }
 
@@ -217,6 +266,9 @@ impl HttpCollectionStorage {
 
     /// Initialize the storage (create directories, demo collections, etc.)
     pub fn initialize(&self) -> Result<()> {
+        if crate::models::config::is_read_only_storage() {
+            return Ok(());
+        }
         self.ensure_collections_directory()?;
         self.create_demo_collections_if_needed()?;
         Ok(())
@@ -232,11 +284,7 @@ impl HttpCollectionStorage {
 
     /// Get the HTTP collections directory
     pub fn get_collections_directory(&self) -> PathBuf {
-        self.config
-            .get_templates_directory()
-            .parent()
-            .unwrap_or_else(|| Path::new("."))
-            .join("http-collections")
+        self.config.get_collections_directory()
     }
 
     /// Save an HTTP request to disk
@@ -246,8 +294,6 @@ impl HttpCollectionStorage {
         request_name: &str,
         request: HttpRequest,
     ) -> Result<PathBuf> {
-        let stored_request = StoredHttpRequest::new(request);
-
         // Build the full path
         let collections_dir = self.get_collections_directory();
         let folder_dir = collections_dir.join(folder_path);
@@ -255,47 +301,50 @@ impl HttpCollectionStorage {
         // Create the folder if it doesn't exist
         std::fs::create_dir_all(&folder_dir).context("Failed to create collection folder")?;
 
-        // Create the filename (sanitize the name)
-        let filename = sanitize_filename(request_name) + ".json";
-        let file_path = folder_dir.join(filename);
-
-        // Serialize and save
-        let json_content = serde_json::to_string_pretty(&stored_request)
-            .context("Failed to serialize HTTP request")?;
+        // Overwrite the existing file in its format, new requests use the configured one
+        let file_path = item_file_path(
+            &folder_dir,
+            &sanitize_filename(request_name),
+            self.config.storage_format,
+        );
+
+        // Saving over an existing request keeps its metadata, so unchanged saves are no-ops
+        let stored_request = match read_item_file::<StoredHttpRequest>(&file_path) {
+            Ok(mut existing) => {
+                existing.update_request(request);
+                existing
+            }
+            Err(_) => StoredHttpRequest::new(request),
+        };
 
-        std::fs::write(&file_path, json_content).context("Failed to write request file")?;
+        write_item_file(&file_path, &stored_request).context("Failed to write request file")?;
 
         Ok(file_path)
     }
 
-    /// Load a specific HTTP request from disk
-    pub fn load_request(&self, folder_path: &str, request_name: &str) -> Result<StoredHttpRequest> {
-        let collections_dir = self.get_collections_directory();
-        let filename = sanitize_filename(request_name) + ".json";
-        let file_path = collections_dir.join(folder_path).join(filename);
-
-        let json_content =
-            std::fs::read_to_string(&file_path).context("Failed to read request file")?;
-
-        let mut stored_request: StoredHttpRequest =
-            serde_json::from_str(&json_content).context("Failed to parse request file")?;
-
-        // Mark as used
-        stored_request.mark_as_used();
+    /// Path of a stored request, whichever format it was saved in
+    fn request_file(&self, folder_path: &str, request_name: &str) -> Result<PathBuf> {
+        let folder_dir = self.get_collections_directory().join(folder_path);
+        find_item_file(&folder_dir, &sanitize_filename(request_name))
+            .with_context(|| format!("Request '{}' not found", request_name))
+    }
 
-        // Save the updated usage info
-        let json_content = serde_json::to_string_pretty(&stored_request)
-            .context("Failed to serialize updated request")?;
-        std::fs::write(&file_path, json_content).context("Failed to update request file")?;
+    /// Read a request without touching its usage info (for search and previews)
+    pub fn read_request(&self, folder_path: &str, request_name: &str) -> Result<StoredHttpRequest> {
+        let file_path = self.request_file(folder_path, request_name)?;
+        read_item_file(&file_path).context("Failed to parse request file")
+    }
 
-        Ok(stored_request)
+    /// Load a specific HTTP request from disk.
+    ///
+    /// The file is not rewritten, so opening a request never shows up as a change in git.
+    pub fn load_request(&self, folder_path: &str, request_name: &str) -> Result<StoredHttpRequest> {
+        self.read_request(folder_path, request_name)
     }
 
     /// Delete an HTTP request from disk
     pub fn delete_request(&self, folder_path: &str, request_name: &str) -> Result<()> {
-        let collections_dir = self.get_collections_directory();
-        let filename = sanitize_filename(request_name) + ".json";
-        let file_path = collections_dir.join(folder_path).join(filename);
+        let file_path = self.request_file(folder_path, request_name)?;
 
         std::fs::remove_file(&file_path).context("Failed to delete request file")?;
         Ok(())
@@ -316,17 +365,79 @@ impl HttpCollectionStorage {
             let entry = entry?;
             let path = entry.path();
 
-            if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("json") {
-                if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
-                    requests.push(stem.to_string());
-                }
+            if is_item_file(&path)
+                && let Some(stem) = path.file_stem().and_then(|s| s.to_str())
+            {
+                requests.push(stem.to_string());
             }
         }
 
         requests.sort();
+        requests.dedup();
         Ok(requests)
     }
 
+    /// Settings of a collection folder; defaults when it has none or they don't parse
+    pub fn read_folder_settings(&self, folder_path: &str) -> FolderSettings {
+        let folder_dir = self.get_collections_directory().join(folder_path);
+        find_item_file(&folder_dir, FOLDER_SETTINGS_STEM)
+            .and_then(|path| read_item_file(&path).ok())
+            .unwrap_or_default()
+    }
+
+    /// Save a folder's settings; empty settings remove the settings file
+    pub fn save_folder_settings(&self, folder_path: &str, settings: &FolderSettings) -> Result<()> {
+        let folder_dir = self.get_collections_directory().join(folder_path);
+        if settings.is_empty() {
+            if let Some(path) = find_item_file(&folder_dir, FOLDER_SETTINGS_STEM) {
+                std::fs::remove_file(path).context("Failed to remove folder settings")?;
+            }
+            return Ok(());
+        }
+        let file_path = item_file_path(
+            &folder_dir,
+            FOLDER_SETTINGS_STEM,
+            self.config.storage_format,
+        );
+        write_item_file(&file_path, settings).context("Failed to write folder settings")
+    }
+
+    /// The folder's README.md, shown while the folder is focused in the tree
+    pub fn read_folder_readme(&self, folder_path: &str) -> Option<String> {
+        read_folder_readme(&self.get_collections_directory().join(folder_path))
+    }
+
+    /// Environment bound to `folder_path` or the nearest folder above it, with that folder
+    pub fn folder_environment(&self, folder_path: &str) -> Option<(String, String)> {
+        let mut folder = folder_path.to_string();
+        loop {
+            if let Some(environment) = self.read_folder_settings(&folder).environment {
+                return Some((folder, environment));
+            }
+            match folder.rfind('/') {
+                Some(pos) => folder.truncate(pos),
+                None if !folder.is_empty() => folder.clear(),
+                None => return None,
+            }
+        }
+    }
+
+    /// Auth and variables requests in `folder_path` inherit from it and the folders above it
+    pub fn folder_inheritance(&self, folder_path: &str) -> FolderInheritance {
+        let mut inheritance = FolderInheritance::default();
+        // From the root down, so deeper folders override the ones above them
+        let mut folder = String::new();
+        inheritance.add_folder(&folder, self.read_folder_settings(&folder));
+        for part in folder_path.split('/').filter(|part| !part.is_empty()) {
+            if !folder.is_empty() {
+                folder.push('/');
+            }
+            folder.push_str(part);
+            inheritance.add_folder(&folder, self.read_folder_settings(&folder));
+        }
+        inheritance
+    }
+
     /// Get all folders in the collections directory
     pub fn list_all_folders(&self) -> Result<Vec<String>> {
         let collections_dir = self.get_collections_directory();
@@ -375,75 +486,72 @@ impl HttpCollectionStorage {
         Ok(())
     }
 
-    /// Import a Postman collection from file
+    /// Import a Postman collection from file, streaming it item by item
     pub fn import_postman_collection(&self, file_path: &Path) -> Result<()> {
-        let json_content =
-            std::fs::read_to_string(file_path).context("Failed to read Postman collection file")?;
-
-        let collection: PostmanCollection =
-            serde_json::from_str(&json_content).context("Failed to parse Postman collection")?;
-
-        self.process_postman_collection(&collection, "")?;
-
+        import_postman_streaming(
+            self,
+            file_path,
+            Arc::new(AtomicBool::new(false)),
+            None,
+            |_| {},
+        )?;
         Ok(())
     }
 
-    /// Process a Postman collection and save items
-    fn process_postman_collection(
-        &self,
-        collection: &PostmanCollection,
-        base_path: &str,
-    ) -> Result<()> {
-        let collection_path = if base_path.is_empty() {
-            sanitize_filename(&collection.info.name)
-        } else {
-            format!("{}/{}", base_path, sanitize_filename(&collection.info.name))
-        };
-
-        // Create collection folder
-        let collections_dir = self.get_collections_directory();
-        let folder_path = collections_dir.join(&collection_path);
-        std::fs::create_dir_all(&folder_path)?;
+    /// Import an OpenAPI 3.x / Swagger 2.0 spec (JSON or YAML) as a folder of requests
+    pub fn import_openapi(&self, file_path: &Path) -> Result<()> {
+        let content =
+            std::fs::read_to_string(file_path).context("Failed to read OpenAPI spec file")?;
+        let spec = parse_openapi_spec(&content).context("Failed to parse OpenAPI spec")?;
+
+        let collection_path = sanitize_filename(&spec.title);
+        for (tag, requests) in &spec.folders {
+            let folder_path = if tag.is_empty() {
+                collection_path.clone()
+            } else {
+                format!("{}/{}", collection_path, sanitize_filename(tag))
+            };
+
+            // Operations without a summary can share a name; keep every one of them
+            let mut used_names: Vec<String> = Vec::new();
+            for request in requests {
+                let mut name = request.name.clone();
+                let mut suffix = 2;
+                while used_names.contains(&sanitize_filename(&name)) {
+                    name = format!("{} ({})", request.name, suffix);
+                    suffix += 1;
+                }
+                used_names.push(sanitize_filename(&name));
 
-        // Process all items in the collection
-        for item in &collection.item {
-            self.process_postman_item(item, &collection_path)?;
+                let mut request = request.clone();
+                request.name = name.clone();
+                self.save_request(&folder_path, &name, request)?;
+            }
         }
 
         Ok(())
     }
 
-    /// Process a Postman item (request or folder)
-    fn process_postman_item(&self, item: &PostmanItem, current_path: &str) -> Result<()> {
-        match item {
-            PostmanItem::Request(postman_request) => {
-                // Convert Postman request to our HttpRequest format
-                let http_request = self.convert_postman_request(postman_request)?;
-                self.save_request(current_path, &postman_request.name, http_request)?;
-            }
-            PostmanItem::Folder(postman_folder) => {
-                let folder_path = format!(
-                    "{}/{}",
-                    current_path,
-                    sanitize_filename(&postman_folder.name)
-                );
-
-                // Create subfolder
-                let collections_dir = self.get_collections_directory();
-                let full_folder_path = collections_dir.join(&folder_path);
-                std::fs::create_dir_all(&full_folder_path)?;
-
-                // Process items in the subfolder
-                for sub_item in &postman_folder.item {
-                    self.process_postman_item(sub_item, &folder_path)?;
-                }
-            }
-        }
-        Ok(())
+    /// Import an Insomnia v4 export (JSON or YAML) as a folder of requests
+    pub fn import_insomnia(&self, file_path: &Path) -> Result<PostmanImportProgress> {
+        let content =
+            std::fs::read_to_string(file_path).context("Failed to read Insomnia export file")?;
+        let collection =
+            parse_insomnia_export(&content).context("Failed to parse Insomnia export")?;
+        import_parsed_collection(self, &collection)
+    }
+
+    /// Import a Bruno collection directory (`.bru` files) as a folder of requests
+    pub fn import_bruno(&self, dir: &Path) -> Result<PostmanImportProgress> {
+        let collection = parse_bruno_collection(dir).context("Failed to read Bruno collection")?;
+        import_parsed_collection(self, &collection)
     }
 
     /// Convert Postman request to our HttpRequest format
-    fn convert_postman_request(&self, postman_request: &PostmanRequest) -> Result<HttpRequest> {
+    pub(crate) fn convert_postman_request(
+        &self,
+        postman_request: &PostmanRequest,
+    ) -> Result<HttpRequest> {
         let details = &postman_request.request;
 
         // Convert method
@@ -499,6 +607,26 @@ impl HttpCollectionStorage {
                 "raw" => HttpRequestBody::Raw {
                     content: b.raw.clone().unwrap_or_default(),
                 },
+                // Postman sends form-data as multipart, which only matters once a file is attached
+                "formdata" if b.formdata.iter().flatten().any(PostmanFormData::is_file) => {
+                    HttpRequestBody::Multipart {
+                        parts: b
+                            .formdata
+                            .iter()
+                            .flatten()
+                            .map(|f| crate::models::http_client::HttpMultipartPart {
+                                name: f.key.clone(),
+                                value: if f.is_file() {
+                                    f.file_path()
+                                } else {
+                                    f.value.clone()
+                                },
+                                enabled: !f.disabled.unwrap_or(false),
+                                is_file: f.is_file(),
+                            })
+                            .collect(),
+                    }
+                }
                 "formdata" => HttpRequestBody::Form {
                     fields: b.formdata.as_ref().map_or_else(Vec::new, |fields| {
                         fields
@@ -511,6 +639,26 @@ impl HttpCollectionStorage {
                             .collect()
                     }),
                 },
+                "urlencoded" => HttpRequestBody::Form {
+                    fields: b
+                        .urlencoded
+                        .iter()
+                        .flatten()
+                        .map(|f| crate::models::http_client::HttpFormField {
+                            name: f.key.clone(),
+                            value: f.value.clone(),
+                            enabled: !f.disabled.unwrap_or(false),
+                        })
+                        .collect(),
+                },
+                "file" => HttpRequestBody::File {
+                    path: b
+                        .file
+                        .as_ref()
+                        .and_then(|file| file.src.clone())
+                        .unwrap_or_default(),
+                    content_type: String::new(),
+                },
                 _ => HttpRequestBody::None,
             });
 
@@ -518,36 +666,7 @@ impl HttpCollectionStorage {
         let auth = details
             .auth
             .as_ref()
-            .map_or(HttpAuth::None, |a| match a.auth_type.as_str() {
-                "bearer" => {
-                    if let Some(bearer_items) = &a.bearer {
-                        if let Some(token_item) =
-                            bearer_items.iter().find(|item| item.key == "token")
-                        {
-                            return HttpAuth::Bearer {
-                                token: token_item.value.clone(),
-                            };
-                        }
-                    }
-                    HttpAuth::None
-                }
-                "basic" => {
-                    let username = a
-                        .basic
-                        .as_ref()
-                        .and_then(|items| items.iter().find(|item| item.key == "username"))
-                        .map(|item| item.value.clone())
-                        .unwrap_or_default();
-                    let password = a
-                        .basic
-                        .as_ref()
-                        .and_then(|items| items.iter().find(|item| item.key == "password"))
-                        .map(|item| item.value.clone())
-                        .unwrap_or_default();
-                    HttpAuth::Basic { username, password }
-                }
-                _ => HttpAuth::None,
-            });
+            .map_or(HttpAuth::None, convert_postman_auth);
 
         Ok(HttpRequest {
             name: postman_request.name.clone(),
@@ -558,13 +677,16 @@ impl HttpCollectionStorage {
             body,
             auth,
             description: details.description.clone().unwrap_or_default(),
+            pagination: Default::default(),
+            pre_request_script: String::new(),
+            tests: String::new(),
+            extract: String::new(),
+            settings: RequestSettings::default(),
         })
     }
 
     /// Create demo collections if none exist
     fn create_demo_collections_if_needed(&self) -> Result<()> {
-        let collections_dir = self.get_collections_directory();
-
         // Check if any collections already exist
         let existing_folders = self.list_all_folders().unwrap_or_default();
         if !existing_folders.is_empty() {
@@ -618,10 +740,10 @@ impl HttpCollectionStorage {
         let path = self.get_collections_directory();
 
         // Try to show a shortened version for common directories
-        if let Some(home_dir) = dirs::home_dir() {
-            if let Ok(relative) = path.strip_prefix(&home_dir) {
-                return format!("~/{}", relative.display());
-            }
+        if let Some(home_dir) = dirs::home_dir()
+            && let Ok(relative) = path.strip_prefix(&home_dir)
+        {
+            return format!("~/{}", relative.display());
         }
 
         path.display().to_string()
@@ -629,7 +751,7 @@ impl HttpCollectionStorage {
 }
 
 /// Sanitize a filename by removing/replacing invalid characters
-fn sanitize_filename(name: &str) -> String {
+pub(crate) fn sanitize_filename(name: &str) -> String {
     name.chars()
         .map(|c| match c {
             '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
@@ -637,3 +759,86 @@ fn sanitize_filename(name: &str) -> String {
         })
         .collect()
 }
+
+/// Postman auth as ours; types we don't support become no auth
+pub(crate) fn convert_postman_auth(auth: &PostmanAuth) -> HttpAuth {
+    let item = |items: &Option<Vec<PostmanAuthItem>>, key: &str| {
+        items
+            .as_ref()
+            .and_then(|items| items.iter().find(|item| item.key == key))
+            .map(|item| item.value.clone())
+    };
+    match auth.auth_type.as_str() {
+        "bearer" => {
+            item(&auth.bearer, "token").map_or(HttpAuth::None, |token| HttpAuth::Bearer { token })
+        }
+        "basic" => HttpAuth::Basic {
+            username: item(&auth.basic, "username").unwrap_or_default(),
+            password: item(&auth.basic, "password").unwrap_or_default(),
+        },
+        _ => HttpAuth::None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_folder_environment_is_inherited_by_subfolders() {
+        let temp = tempfile::tempdir().unwrap();
+        let storage = HttpCollectionStorage::new(AppConfig {
+            custom_collections_dir: Some(temp.path().to_path_buf()),
+            ..AppConfig::default()
+        });
+        storage
+            .save_request("prod/users", "List", HttpRequest::new("List"))
+            .unwrap();
+        assert_eq!(storage.folder_environment("prod/users"), None);
+
+        let settings = FolderSettings {
+            environment: Some("Production".to_string()),
+            ..FolderSettings::default()
+        };
+        storage.save_folder_settings("prod", &settings).unwrap();
+        assert_eq!(storage.read_folder_settings("prod"), settings);
+        assert_eq!(
+            storage.folder_environment("prod/users"),
+            Some(("prod".to_string(), "Production".to_string()))
+        );
+        // The settings file is not a request
+        assert!(storage.list_requests_in_folder("prod").unwrap().is_empty());
+
+        storage
+            .save_folder_settings("prod", &FolderSettings::default())
+            .unwrap();
+        assert_eq!(storage.folder_environment("prod/users"), None);
+        assert_eq!(
+            std::fs::read_dir(temp.path().join("prod")).unwrap().count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_folder_readme_is_not_a_request() {
+        let temp = tempfile::tempdir().unwrap();
+        let storage = HttpCollectionStorage::new(AppConfig {
+            custom_collections_dir: Some(temp.path().to_path_buf()),
+            ..AppConfig::default()
+        });
+        storage
+            .save_request("payments", "Charge", HttpRequest::new("Charge"))
+            .unwrap();
+        assert_eq!(storage.read_folder_readme("payments"), None);
+
+        std::fs::write(temp.path().join("payments/Readme.md"), "# Payments\n").unwrap();
+        assert_eq!(
+            storage.read_folder_readme("payments").as_deref(),
+            Some("# Payments\n")
+        );
+        assert_eq!(
+            storage.list_requests_in_folder("payments").unwrap(),
+            vec!["Charge".to_string()]
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use crate::models::image_info::{ImageFormat, ImageInfo};
+use crate::services::system_clipboard::base64_encode;
+use ratatui::layout::Rect;
+use std::cell::Cell;
+use std::io::{self, Write};
+
+/// Terminal graphics protocols that take PNG/JPEG bytes as they are. Sixel needs the
+/// pixels decoded first, so sixel-only terminals get the metadata view instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+    Kitty,
+    ITerm2,
+}
+
+impl GraphicsProtocol {
+    /// The protocol the terminal speaks, judged from the environment
+    pub fn detect() -> Option<Self> {
+        detect_from(|name| std::env::var(name).ok())
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GraphicsProtocol::Kitty => "kitty",
+            GraphicsProtocol::ITerm2 => "iTerm2",
+        }
+    }
+
+    /// Kitty only decodes PNG itself; iTerm2 takes any common format
+    pub fn supports(&self, format: ImageFormat) -> bool {
+        match self {
+            GraphicsProtocol::Kitty => format == ImageFormat::Png,
+            GraphicsProtocol::ITerm2 => true,
+        }
+    }
+}
+
+fn detect_from(var: impl Fn(&str) -> Option<String>) -> Option<GraphicsProtocol> {
+    // Escapes would need wrapping to pass through a multiplexer
+    if var("TMUX").is_some() || var("STY").is_some() {
+        return None;
+    }
+    let term = var("TERM").unwrap_or_default();
+    let program = var("TERM_PROGRAM").unwrap_or_default();
+    if var("KITTY_WINDOW_ID").is_some() || term.contains("kitty") || program == "ghostty" {
+        Some(GraphicsProtocol::Kitty)
+    } else if matches!(program.as_str(), "iTerm.app" | "WezTerm")
+        || var("LC_TERMINAL").as_deref() == Some("iTerm2")
+    {
+        Some(GraphicsProtocol::ITerm2)
+    } else {
+        None
+    }
+}
+
+/// Cells the image takes when fitted into `cols` x `rows`, keeping its aspect ratio.
+/// Cells are taken to be twice as tall as they are wide.
+pub fn fit_cells(info: &ImageInfo, cols: u16, rows: u16) -> (u16, u16) {
+    let (width, height) = (info.width.max(1) as f64, info.height.max(1) as f64);
+    let scale = (cols as f64 / width).min(rows as f64 * 2.0 / height);
+    let fitted_cols = (width * scale).round().clamp(1.0, cols.max(1) as f64);
+    let fitted_rows = (height * scale / 2.0)
+        .round()
+        .clamp(1.0, rows.max(1) as f64);
+    (fitted_cols as u16, fitted_rows as u16)
+}
+
+/// Image id used for kitty placements, so the old one can be deleted
+const KITTY_IMAGE_ID: u32 = 7_331;
+/// Kitty takes the base64 payload in chunks of at most this many bytes
+const KITTY_CHUNK: usize = 4096;
+
+/// An inline image drawn over the response pane, outside ratatui's buffer.
+///
+/// The renderer records where the image goes during a frame; after the frame is
+/// drawn `sync` sends it to the terminal, only when something changed.
+#[derive(Debug, Default)]
+pub struct ImagePreview {
+    pub protocol: Option<GraphicsProtocol>,
+    /// Where the image should go this frame; reset at the start of each frame
+    area: Cell<Option<Rect>>,
+    /// Area and body (address and length) currently on screen
+    shown: Option<(Rect, usize, usize)>,
+}
+
+impl ImagePreview {
+    pub fn new(protocol: Option<GraphicsProtocol>) -> Self {
+        Self {
+            protocol,
+            ..Self::default()
+        }
+    }
+
+    /// Ask for the image to be drawn in `area` this frame (`None` hides it)
+    pub fn place(&self, area: Option<Rect>) {
+        self.area.set(area);
+    }
+
+    pub fn area(&self) -> Option<Rect> {
+        self.area.get()
+    }
+
+    /// The screen was wiped behind our back (e.g. handed to $EDITOR); draw the
+    /// image again after the next frame
+    pub fn forget(&mut self) {
+        self.shown = None;
+    }
+
+    /// Draw, move or remove the image after a frame. Returns true when the terminal
+    /// needs a full redraw to wipe an iTerm2 image, which ratatui doesn't know about.
+    pub fn sync(
+        &mut self,
+        out: &mut impl Write,
+        image: Option<(&ImageInfo, &[u8])>,
+    ) -> io::Result<bool> {
+        let Some(protocol) = self.protocol else {
+            return Ok(false);
+        };
+        let target = self.area.get().zip(image);
+        let wanted = target.map(|(area, (_, bytes))| (area, bytes.as_ptr() as usize, bytes.len()));
+        if wanted == self.shown {
+            return Ok(false);
+        }
+
+        let mut redraw = false;
+        if self.shown.take().is_some() {
+            match protocol {
+                GraphicsProtocol::Kitty => {
+                    write!(out, "\x1b_Ga=d,d=I,i={},q=2\x1b\\", KITTY_IMAGE_ID)?;
+                }
+                GraphicsProtocol::ITerm2 => redraw = true,
+            }
+        }
+        // After a redraw the next frame puts the image back
+        if let Some((area, (info, bytes))) = target
+            && !redraw
+        {
+            write!(out, "\x1b[{};{}H", area.y + 1, area.x + 1)?;
+            let payload = base64_encode(bytes);
+            match protocol {
+                GraphicsProtocol::Kitty => {
+                    let (cols, rows) = fit_cells(info, area.width, area.height);
+                    let chunks: Vec<&[u8]> = payload.as_bytes().chunks(KITTY_CHUNK).collect();
+                    for (index, chunk) in chunks.iter().enumerate() {
+                        let more = u8::from(index + 1 < chunks.len());
+                        if index == 0 {
+                            write!(
+                                out,
+                                "\x1b_Ga=T,f=100,t=d,i={},c={},r={},C=1,q=2,m={};",
+                                KITTY_IMAGE_ID, cols, rows, more
+                            )?;
+                        } else {
+                            write!(out, "\x1b_Gm={};", more)?;
+                        }
+                        out.write_all(chunk)?;
+                        write!(out, "\x1b\\")?;
+                    }
+                }
+                GraphicsProtocol::ITerm2 => {
+                    write!(
+                        out,
+                        "\x1b]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=1:{}\x07",
+                        bytes.len(),
+                        area.width,
+                        area.height,
+                        payload
+                    )?;
+                }
+            }
+            self.shown = wanted;
+        }
+        out.flush()?;
+        Ok(redraw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_protocol_and_fit_cells() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert_eq!(
+            detect_from(env(&[("TERM", "xterm-kitty")])),
+            Some(GraphicsProtocol::Kitty)
+        );
+        assert_eq!(
+            detect_from(env(&[("TERM_PROGRAM", "iTerm.app")])),
+            Some(GraphicsProtocol::ITerm2)
+        );
+        assert_eq!(
+            detect_from(env(&[("TERM", "xterm-kitty"), ("TMUX", "/tmp/tmux")])),
+            None
+        );
+        assert_eq!(detect_from(env(&[("TERM", "xterm-256color")])), None);
+
+        let wide = ImageInfo {
+            format: ImageFormat::Png,
+            width: 800,
+            height: 200,
+        };
+        // Width-bound: 80 columns, a quarter as many half-height rows
+        assert_eq!(fit_cells(&wide, 80, 40), (80, 10));
+        let tall = ImageInfo {
+            width: 100,
+            height: 400,
+            ..wide
+        };
+        assert_eq!(fit_cells(&tall, 80, 20), (10, 20));
+    }
+}
@@ -0,0 +1,407 @@
+use crate::services::http_collection_storage::{
+    PostmanAuth, PostmanAuthItem, PostmanBody, PostmanCollection, PostmanFile, PostmanFolder,
+    PostmanFormData, PostmanHeader, PostmanInfo, PostmanItem, PostmanQueryParam, PostmanRequest,
+    PostmanRequestDetails, PostmanUrl, PostmanUrlEncoded, PostmanUrlObject,
+};
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use std::collections::HashSet;
+
+/// Quick check used by the import dialog to recognise an Insomnia export
+pub fn looks_like_insomnia_export(content: &str) -> bool {
+    content.contains("__export_format") && content.contains("resources")
+}
+
+/// Parse an Insomnia export (v4, JSON or YAML) into a Postman collection so it
+/// goes through the same conversion and storage as Postman imports
+pub fn parse_insomnia_export(content: &str) -> Result<PostmanCollection> {
+    let export: InsomniaExport = match serde_json::from_str(content) {
+        Ok(export) => export,
+        Err(_) => serde_yaml::from_str(content)
+            .context("Export is neither valid JSON nor YAML Insomnia data")?,
+    };
+    if export.export_type.as_deref() != Some("export") {
+        bail!("Not an Insomnia export (missing \"_type\": \"export\")");
+    }
+    if export.export_format.is_some_and(|format| format < 4) {
+        bail!(
+            "Unsupported Insomnia export format {}, re-export with Insomnia v4 or later",
+            export.export_format.unwrap_or_default()
+        );
+    }
+
+    let workspace = export
+        .resources
+        .iter()
+        .find(|resource| resource.resource_type == "workspace");
+    let name = workspace
+        .map(|workspace| workspace.name.clone())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "Insomnia Import".to_string());
+
+    // Anything not nested in a request group sits at the top of the collection
+    let group_ids: HashSet<&str> = export
+        .resources
+        .iter()
+        .filter(|resource| resource.resource_type == "request_group")
+        .map(|resource| resource.id.as_str())
+        .collect();
+    let top_level: Vec<&InsomniaResource> = export
+        .resources
+        .iter()
+        .filter(|resource| {
+            resource
+                .parent_id
+                .as_deref()
+                .is_none_or(|parent| !group_ids.contains(parent))
+        })
+        .collect();
+
+    if !export
+        .resources
+        .iter()
+        .any(|r| r.resource_type == "request")
+    {
+        bail!("Insomnia export contains no HTTP requests");
+    }
+
+    Ok(PostmanCollection {
+        info: PostmanInfo {
+            postman_id: workspace.map(|w| w.id.clone()).unwrap_or_default(),
+            name,
+            description: workspace.and_then(|w| w.description.clone()),
+            schema: String::new(),
+        },
+        item: convert_items(&export.resources, top_level),
+        auth: None,
+        event: None,
+        variable: None,
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct InsomniaExport {
+    #[serde(rename = "_type")]
+    export_type: Option<String>,
+    #[serde(rename = "__export_format")]
+    export_format: Option<u32>,
+    #[serde(default)]
+    resources: Vec<InsomniaResource>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InsomniaResource {
+    #[serde(rename = "_id", default)]
+    id: String,
+    #[serde(rename = "_type")]
+    resource_type: String,
+    #[serde(rename = "parentId")]
+    parent_id: Option<String>,
+    #[serde(default)]
+    name: String,
+    description: Option<String>,
+    #[serde(rename = "metaSortKey")]
+    sort_key: Option<f64>,
+    #[serde(default)]
+    method: String,
+    #[serde(default)]
+    url: String,
+    #[serde(default)]
+    headers: Vec<InsomniaPair>,
+    #[serde(default)]
+    parameters: Vec<InsomniaPair>,
+    body: Option<InsomniaBody>,
+    authentication: Option<InsomniaAuth>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InsomniaPair {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    value: String,
+    #[serde(default)]
+    disabled: bool,
+    #[serde(rename = "type")]
+    pair_type: Option<String>,
+    #[serde(rename = "fileName")]
+    file_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InsomniaBody {
+    #[serde(rename = "mimeType")]
+    mime_type: Option<String>,
+    text: Option<String>,
+    #[serde(default)]
+    params: Vec<InsomniaPair>,
+    #[serde(rename = "fileName")]
+    file_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InsomniaAuth {
+    #[serde(rename = "type")]
+    auth_type: Option<String>,
+    #[serde(default)]
+    disabled: bool,
+    token: Option<String>,
+    username: Option<String>,
+    password: Option<String>,
+}
+
+/// Requests and request groups among `children`, in Insomnia's sidebar order
+fn convert_items(
+    resources: &[InsomniaResource],
+    mut children: Vec<&InsomniaResource>,
+) -> Vec<PostmanItem> {
+    children.sort_by(|a, b| {
+        a.sort_key
+            .unwrap_or_default()
+            .total_cmp(&b.sort_key.unwrap_or_default())
+    });
+
+    children
+        .into_iter()
+        .filter_map(|resource| match resource.resource_type.as_str() {
+            "request" => Some(PostmanItem::Request(convert_request(resource))),
+            "request_group" => {
+                let nested = resources
+                    .iter()
+                    .filter(|child| child.parent_id.as_deref() == Some(resource.id.as_str()))
+                    .collect();
+                Some(PostmanItem::Folder(PostmanFolder {
+                    name: resource.name.clone(),
+                    item: convert_items(resources, nested),
+                    description: resource.description.clone(),
+                    auth: None,
+                    event: None,
+                }))
+            }
+            // Environments, cookie jars, specs and gRPC/WebSocket requests aren't imported
+            _ => None,
+        })
+        .collect()
+}
+
+fn convert_request(resource: &InsomniaResource) -> PostmanRequest {
+    let url = convert_template(&resource.url);
+    let query = resource
+        .parameters
+        .iter()
+        .map(|param| PostmanQueryParam {
+            key: convert_template(&param.name),
+            value: convert_template(&param.value),
+            disabled: Some(param.disabled),
+            description: None,
+        })
+        .collect();
+
+    PostmanRequest {
+        name: resource.name.clone(),
+        request: PostmanRequestDetails {
+            method: resource.method.clone(),
+            header: Some(
+                resource
+                    .headers
+                    .iter()
+                    .map(|header| PostmanHeader {
+                        key: convert_template(&header.name),
+                        value: convert_template(&header.value),
+                        disabled: Some(header.disabled),
+                        description: None,
+                    })
+                    .collect(),
+            ),
+            body: resource.body.as_ref().and_then(convert_body),
+            url: PostmanUrl::Object(PostmanUrlObject {
+                raw: url,
+                protocol: None,
+                host: None,
+                port: None,
+                path: None,
+                query: Some(query),
+                hash: None,
+            }),
+            auth: resource.authentication.as_ref().and_then(convert_auth),
+            description: resource.description.clone().filter(|d| !d.is_empty()),
+        },
+        response: None,
+        event: None,
+    }
+}
+
+fn convert_body(body: &InsomniaBody) -> Option<PostmanBody> {
+    let mime_type = body.mime_type.as_deref().unwrap_or_default();
+    let mut converted = PostmanBody {
+        mode: String::new(),
+        raw: None,
+        formdata: None,
+        urlencoded: None,
+        file: None,
+    };
+
+    match mime_type {
+        "multipart/form-data" => {
+            converted.mode = "formdata".to_string();
+            converted.formdata = Some(
+                body.params
+                    .iter()
+                    .map(|param| {
+                        let is_file = param.pair_type.as_deref() == Some("file");
+                        PostmanFormData {
+                            key: convert_template(&param.name),
+                            value: if is_file {
+                                String::new()
+                            } else {
+                                convert_template(&param.value)
+                            },
+                            disabled: Some(param.disabled),
+                            field_type: Some(if is_file { "file" } else { "text" }.to_string()),
+                            src: param
+                                .file_name
+                                .clone()
+                                .filter(|_| is_file)
+                                .map(serde_json::Value::String),
+                        }
+                    })
+                    .collect(),
+            );
+        }
+        "application/x-www-form-urlencoded" => {
+            converted.mode = "urlencoded".to_string();
+            converted.urlencoded = Some(
+                body.params
+                    .iter()
+                    .map(|param| PostmanUrlEncoded {
+                        key: convert_template(&param.name),
+                        value: convert_template(&param.value),
+                        disabled: Some(param.disabled),
+                    })
+                    .collect(),
+            );
+        }
+        "application/octet-stream" => {
+            converted.mode = "file".to_string();
+            converted.file = Some(PostmanFile {
+                src: body.file_name.clone(),
+            });
+        }
+        _ => {
+            let text = body.text.as_deref().filter(|text| !text.is_empty())?;
+            converted.mode = "raw".to_string();
+            converted.raw = Some(convert_template(text));
+        }
+    }
+    Some(converted)
+}
+
+fn convert_auth(auth: &InsomniaAuth) -> Option<PostmanAuth> {
+    if auth.disabled {
+        return None;
+    }
+    let item = |key: &str, value: &Option<String>| PostmanAuthItem {
+        key: key.to_string(),
+        value: convert_template(value.as_deref().unwrap_or_default()),
+        value_type: None,
+    };
+
+    match auth.auth_type.as_deref()? {
+        "bearer" => Some(PostmanAuth {
+            auth_type: "bearer".to_string(),
+            basic: None,
+            bearer: Some(vec![item("token", &auth.token)]),
+            apikey: None,
+        }),
+        "basic" => Some(PostmanAuth {
+            auth_type: "basic".to_string(),
+            basic: Some(vec![
+                item("username", &auth.username),
+                item("password", &auth.password),
+            ]),
+            bearer: None,
+            apikey: None,
+        }),
+        _ => None,
+    }
+}
+
+/// Rewrite Insomnia's `{{ _.name }}` variable tags as our `{{name}}`; template
+/// tags (`{% ... %}`) are left for the user to replace
+fn convert_template(text: &str) -> String {
+    let mut converted = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let inner = rest[start + 2..start + 2 + len].trim();
+        let inner = inner.strip_prefix("_.").unwrap_or(inner);
+        converted.push_str(&rest[..start]);
+        converted.push_str("{{");
+        converted.push_str(inner);
+        converted.push_str("}}");
+        rest = &rest[start + 2 + len + 2..];
+    }
+    converted.push_str(rest);
+    converted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insomnia_export_becomes_nested_collection() {
+        let export = r#"{
+            "_type": "export",
+            "__export_format": 4,
+            "resources": [
+                {"_id": "req_2", "_type": "request", "parentId": "fld_1", "name": "Create",
+                 "method": "POST", "url": "{{ _.base_url }}/users", "metaSortKey": -2,
+                 "body": {"mimeType": "application/json", "text": "{\"name\": \"{{ _.user }}\"}"},
+                 "headers": [{"name": "Content-Type", "value": "application/json"}],
+                 "authentication": {"type": "bearer", "token": "{{ _.token }}"}},
+                {"_id": "req_1", "_type": "request", "parentId": "wrk_1", "name": "Health",
+                 "method": "GET", "url": "https://example.com/health",
+                 "parameters": [{"name": "verbose", "value": "1", "disabled": true}]},
+                {"_id": "fld_1", "_type": "request_group", "parentId": "wrk_1", "name": "Users"},
+                {"_id": "wrk_1", "_type": "workspace", "name": "Shop API"},
+                {"_id": "env_1", "_type": "environment", "parentId": "wrk_1", "name": "Base"}
+            ]
+        }"#;
+        assert!(looks_like_insomnia_export(export));
+
+        let collection = parse_insomnia_export(export).unwrap();
+        assert_eq!(collection.info.name, "Shop API");
+        assert_eq!(collection.item.len(), 2);
+
+        let PostmanItem::Folder(folder) = &collection.item[1] else {
+            panic!("expected the request group as a folder");
+        };
+        assert_eq!(folder.name, "Users");
+        let PostmanItem::Request(create) = &folder.item[0] else {
+            panic!("expected a request");
+        };
+        let PostmanUrl::Object(url) = &create.request.url else {
+            panic!("expected a url object");
+        };
+        assert_eq!(url.raw, "{{base_url}}/users");
+        let body = create.request.body.as_ref().unwrap();
+        assert_eq!(body.mode, "raw");
+        assert_eq!(body.raw.as_deref(), Some("{\"name\": \"{{user}}\"}"));
+        let auth = create.request.auth.as_ref().unwrap();
+        assert_eq!(auth.bearer.as_ref().unwrap()[0].value, "{{token}}");
+
+        let PostmanItem::Request(health) = &collection.item[0] else {
+            panic!("expected a request");
+        };
+        let PostmanUrl::Object(url) = &health.request.url else {
+            panic!("expected a url object");
+        };
+        assert_eq!(url.query.as_ref().unwrap()[0].disabled, Some(true));
+
+        assert!(parse_insomnia_export(r#"{"_type": "export", "resources": []}"#).is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use crate::models::AutomationTemplate;
+use crate::models::http_client::HttpRequest;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// One field that differs between a saved item and what would replace it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldChange {
+    Added {
+        field: String,
+        value: String,
+    },
+    Removed {
+        field: String,
+        value: String,
+    },
+    Modified {
+        field: String,
+        old: String,
+        new: String,
+    },
+}
+
+impl FieldChange {
+    /// `+`, `-` or `~` followed by the change, e.g. `~ url: old → new`
+    pub fn describe(&self) -> String {
+        match self {
+            FieldChange::Added { field, value } => format!("+ {}: {}", field, value),
+            FieldChange::Removed { field, value } => format!("- {}: {}", field, value),
+            FieldChange::Modified { field, old, new } => {
+                format!("~ {}: {} → {}", field, old, new)
+            }
+        }
+    }
+}
+
+/// Field-by-field changes from `old` to `new`, in field order
+pub fn diff_fields(
+    old: &BTreeMap<String, String>,
+    new: &BTreeMap<String, String>,
+) -> Vec<FieldChange> {
+    let mut fields: Vec<&String> = old.keys().chain(new.keys()).collect();
+    fields.sort();
+    fields.dedup();
+
+    fields
+        .into_iter()
+        .filter_map(|field| match (old.get(field), new.get(field)) {
+            (Some(old), Some(new)) if old != new => Some(FieldChange::Modified {
+                field: field.clone(),
+                old: old.clone(),
+                new: new.clone(),
+            }),
+            (Some(value), None) => Some(FieldChange::Removed {
+                field: field.clone(),
+                value: value.clone(),
+            }),
+            (None, Some(value)) => Some(FieldChange::Added {
+                field: field.clone(),
+                value: value.clone(),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Template values (and description) keyed by field name
+pub fn template_fields(template: &AutomationTemplate) -> BTreeMap<String, String> {
+    let mut fields = template.field_values.clone();
+    if !template.description.is_empty() {
+        fields.insert("(description)".to_string(), template.description.clone());
+    }
+    fields
+}
+
+/// A request flattened to `path: value`; headers, params and other named rows are
+/// keyed by name (`headers[Accept].value`) so reordering doesn't show as a change
+pub fn request_fields(request: &HttpRequest) -> BTreeMap<String, String> {
+    let mut fields = BTreeMap::new();
+    if let Ok(value) = serde_json::to_value(request) {
+        flatten(&value, String::new(), &mut fields);
+    }
+    fields
+}
+
+fn flatten(value: &Value, path: String, fields: &mut BTreeMap<String, String>) {
+    match value {
+        Value::Null => {}
+        Value::Object(map) => {
+            for (key, child) in map {
+                let child_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                flatten(child, child_path, fields);
+            }
+        }
+        Value::Array(items) => {
+            let mut seen: BTreeMap<String, usize> = BTreeMap::new();
+            for (index, item) in items.iter().enumerate() {
+                let name = item.get("name").and_then(Value::as_str);
+                let key = match name {
+                    Some(name) => {
+                        let count = seen.entry(name.to_string()).or_default();
+                        *count += 1;
+                        if *count == 1 {
+                            name.to_string()
+                        } else {
+                            format!("{}#{}", name, count)
+                        }
+                    }
+                    None => index.to_string(),
+                };
+                let item_path = format!("{}[{}]", path, key);
+                match (name, item) {
+                    // The name is already in the path
+                    (Some(_), Value::Object(map)) => {
+                        for (field, child) in map.iter().filter(|(field, _)| *field != "name") {
+                            flatten(child, format!("{}.{}", item_path, field), fields);
+                        }
+                    }
+                    _ => flatten(item, item_path, fields),
+                }
+            }
+        }
+        Value::String(text) => {
+            fields.insert(path, text.clone());
+        }
+        other => {
+            fields.insert(path, other.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_field_changes() {
+        let saved = HttpRequest::new("Users")
+            .with_url("https://api.example.com/users")
+            .with_header("Accept", "application/json")
+            .with_header("X-Team", "payments");
+        // Someone else's header is gone, one value changed and a new header arrived
+        let replacement = HttpRequest::new("Users")
+            .with_url("https://api.example.com/v2/users")
+            .with_header("X-Trace", "1")
+            .with_header("Accept", "application/json");
+
+        let changes = diff_fields(&request_fields(&saved), &request_fields(&replacement));
+        let described: Vec<String> = changes.iter().map(FieldChange::describe).collect();
+        assert!(described.contains(&"- headers[X-Team].value: payments".to_string()));
+        assert!(described.contains(&"+ headers[X-Trace].value: 1".to_string()));
+        assert!(described.contains(
+            &"~ url: https://api.example.com/users → https://api.example.com/v2/users".to_string()
+        ));
+        // Reordering Accept isn't a change
+        assert!(!described.iter().any(|line| line.contains("Accept")));
+
+        assert!(diff_fields(&request_fields(&saved), &request_fields(&saved)).is_empty());
+    }
+}
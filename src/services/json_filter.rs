@@ -0,0 +1,391 @@
+use anyhow::{Result, anyhow, bail};
+use serde_json::Value;
+
+/// One step of a filter path
+#[derive(Debug, Clone, PartialEq)]
+enum Step {
+    Key(String),
+    /// Array index; negative counts from the end
+    Index(i64),
+    /// Every element of an array or value of an object (`[*]`, `.*`, jq `[]`)
+    Wildcard,
+    /// The value itself and everything below it (`..`)
+    Descendants,
+    Slice(Option<i64>, Option<i64>),
+    /// jq `length`
+    Length,
+    /// jq `keys`
+    Keys,
+}
+
+/// Apply a JSONPath (`$.items[0].name`, `$..id`) or jq-style (`.items[] | .name`)
+/// expression to a JSON body
+///
+/// A single match is returned as-is; several matches are collected into an array.
+pub fn apply_json_filter(body: &str, expression: &str) -> Result<Value> {
+    let document: Value =
+        serde_json::from_str(body).map_err(|e| anyhow!("Response is not JSON: {}", e))?;
+    filter_json_value(&document, expression)
+}
+
+/// `apply_json_filter` for a body that is already parsed
+pub fn filter_json_value(document: &Value, expression: &str) -> Result<Value> {
+    let mut current = vec![document.clone()];
+    for stage in split_stages(expression) {
+        let steps = parse_stage(stage.trim())?;
+        current = current
+            .iter()
+            .flat_map(|value| apply_steps(value, &steps))
+            .collect();
+    }
+
+    Ok(match current.len() {
+        1 => current.remove(0),
+        _ => Value::Array(current),
+    })
+}
+
+/// JSONPath of the node on each line of `serde_json::to_string_pretty(value)`
+///
+/// Opening and closing brackets map to their container, so every line has a path.
+pub fn json_path_lines(value: &Value) -> Vec<String> {
+    let mut lines = Vec::new();
+    collect_path_lines(value, "$".to_string(), &mut lines);
+    lines
+}
+
+fn collect_path_lines(value: &Value, path: String, lines: &mut Vec<String>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            lines.push(path.clone());
+            for (key, child) in map {
+                collect_path_lines(child, member_path(&path, key), lines);
+            }
+            lines.push(path);
+        }
+        Value::Array(items) if !items.is_empty() => {
+            lines.push(path.clone());
+            for (index, child) in items.iter().enumerate() {
+                collect_path_lines(child, format!("{}[{}]", path, index), lines);
+            }
+            lines.push(path);
+        }
+        _ => lines.push(path),
+    }
+}
+
+/// `path.key`, or `path['key']` when the key isn't a plain name
+pub fn member_path(path: &str, key: &str) -> String {
+    let plain = !key.is_empty()
+        && !key.starts_with(|c: char| c.is_ascii_digit())
+        && key
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-');
+    if plain {
+        format!("{}.{}", path, key)
+    } else if key.contains('\'') {
+        format!("{}[\"{}\"]", path, key)
+    } else {
+        format!("{}['{}']", path, key)
+    }
+}
+
+/// Split on `|` outside of brackets and quotes (jq pipes)
+fn split_stages(expression: &str) -> Vec<&str> {
+    let mut stages = Vec::new();
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+
+    for (index, c) in expression.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '[') => depth += 1,
+            (None, ']') => depth = depth.saturating_sub(1),
+            (None, '|') if depth == 0 => {
+                stages.push(&expression[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    stages.push(&expression[start..]);
+    stages
+}
+
+fn parse_stage(stage: &str) -> Result<Vec<Step>> {
+    match stage {
+        "length" => return Ok(vec![Step::Length]),
+        "keys" => return Ok(vec![Step::Keys]),
+        _ => {}
+    }
+
+    let chars: Vec<char> = stage.chars().collect();
+    let mut steps = Vec::new();
+    let mut index = 0;
+
+    if chars.first() == Some(&'$') {
+        index = 1;
+    }
+
+    while index < chars.len() {
+        match chars[index] {
+            '.' if chars.get(index + 1) == Some(&'.') => {
+                steps.push(Step::Descendants);
+                index += 2;
+                // `..name` / `..*` continue straight into the next step
+                if index < chars.len() && chars[index] != '[' {
+                    index = parse_member(&chars, index, &mut steps)?;
+                }
+            }
+            '.' => {
+                index += 1;
+                if index < chars.len() && chars[index] != '[' {
+                    index = parse_member(&chars, index, &mut steps)?;
+                }
+            }
+            '[' => {
+                let end = find_closing_bracket(&chars, index)?;
+                let inner: String = chars[index + 1..end].iter().collect();
+                steps.push(parse_bracket(inner.trim())?);
+                index = end + 1;
+            }
+            c if c.is_whitespace() => index += 1,
+            // A bare name at the start (`items[0]`) reads as `.items[0]`
+            _ if steps.is_empty() && index <= 1 => {
+                index = parse_member(&chars, index, &mut steps)?;
+            }
+            c => bail!("Unexpected '{}' at position {}", c, index + 1),
+        }
+    }
+
+    Ok(steps)
+}
+
+/// Parse a `name` or `*` member after a dot; returns the index after it
+fn parse_member(chars: &[char], start: usize, steps: &mut Vec<Step>) -> Result<usize> {
+    if chars[start] == '*' {
+        steps.push(Step::Wildcard);
+        return Ok(start + 1);
+    }
+
+    let mut end = start;
+    while end < chars.len() && (chars[end].is_alphanumeric() || "_-$@".contains(chars[end])) {
+        end += 1;
+    }
+    if end == start {
+        bail!("Expected a field name at position {}", start + 1);
+    }
+    steps.push(Step::Key(chars[start..end].iter().collect()));
+    Ok(end)
+}
+
+fn find_closing_bracket(chars: &[char], open: usize) -> Result<usize> {
+    let mut quote: Option<char> = None;
+    for (index, &c) in chars.iter().enumerate().skip(open + 1) {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, ']') => return Ok(index),
+            _ => {}
+        }
+    }
+    bail!("Missing ']' for '[' at position {}", open + 1)
+}
+
+fn parse_bracket(inner: &str) -> Result<Step> {
+    if inner.is_empty() || inner == "*" {
+        return Ok(Step::Wildcard);
+    }
+
+    let quoted = (inner.starts_with('"') && inner.ends_with('"'))
+        || (inner.starts_with('\'') && inner.ends_with('\''));
+    if quoted && inner.len() >= 2 {
+        return Ok(Step::Key(inner[1..inner.len() - 1].to_string()));
+    }
+
+    if let Some((from, to)) = inner.split_once(':') {
+        let bound = |text: &str| -> Result<Option<i64>> {
+            let text = text.trim();
+            if text.is_empty() {
+                Ok(None)
+            } else {
+                text.parse()
+                    .map(Some)
+                    .map_err(|_| anyhow!("Invalid slice bound '{}'", text))
+            }
+        };
+        return Ok(Step::Slice(bound(from)?, bound(to)?));
+    }
+
+    inner
+        .parse()
+        .map(Step::Index)
+        .map_err(|_| anyhow!("Invalid index '{}' (quote field names: ['name'])", inner))
+}
+
+fn apply_steps(value: &Value, steps: &[Step]) -> Vec<Value> {
+    let mut current = vec![value.clone()];
+    for step in steps {
+        current = current
+            .iter()
+            .flat_map(|value| apply_step(value, step))
+            .collect();
+    }
+    current
+}
+
+fn apply_step(value: &Value, step: &Step) -> Vec<Value> {
+    match step {
+        Step::Key(key) => value.get(key).cloned().into_iter().collect(),
+        Step::Index(index) => match value {
+            Value::Array(items) => resolve_index(*index, items.len())
+                .and_then(|index| items.get(index))
+                .cloned()
+                .into_iter()
+                .collect(),
+            _ => Vec::new(),
+        },
+        Step::Wildcard => match value {
+            Value::Array(items) => items.clone(),
+            Value::Object(map) => map.values().cloned().collect(),
+            _ => Vec::new(),
+        },
+        Step::Descendants => {
+            let mut found = Vec::new();
+            collect_descendants(value, &mut found);
+            found
+        }
+        Step::Slice(from, to) => match value {
+            Value::Array(items) => {
+                let len = items.len();
+                let from = from.map_or(0, |i| clamp_index(i, len));
+                let to = to.map_or(len, |i| clamp_index(i, len));
+                items
+                    .get(from..to.max(from))
+                    .map(|slice| slice.to_vec())
+                    .unwrap_or_default()
+            }
+            _ => Vec::new(),
+        },
+        Step::Length => {
+            let length = match value {
+                Value::Array(items) => items.len(),
+                Value::Object(map) => map.len(),
+                Value::String(text) => text.chars().count(),
+                Value::Null => 0,
+                _ => return Vec::new(),
+            };
+            vec![Value::from(length)]
+        }
+        Step::Keys => match value {
+            Value::Object(map) => vec![Value::Array(
+                map.keys().map(|key| Value::String(key.clone())).collect(),
+            )],
+            _ => Vec::new(),
+        },
+    }
+}
+
+fn collect_descendants(value: &Value, found: &mut Vec<Value>) {
+    found.push(value.clone());
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .for_each(|item| collect_descendants(item, found)),
+        Value::Object(map) => map
+            .values()
+            .for_each(|item| collect_descendants(item, found)),
+        _ => {}
+    }
+}
+
+fn resolve_index(index: i64, len: usize) -> Option<usize> {
+    if index < 0 {
+        len.checked_sub(index.unsigned_abs() as usize)
+    } else {
+        Some(index as usize)
+    }
+}
+
+fn clamp_index(index: i64, len: usize) -> usize {
+    resolve_index(index, len).unwrap_or(0).min(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_apply_json_filter() {
+        let body = r#"{
+            "data": {
+                "items": [
+                    {"id": 1, "name": "alpha", "tags": ["a"]},
+                    {"id": 2, "name": "beta", "tags": []},
+                    {"id": 3, "name": "gamma"}
+                ]
+            }
+        }"#;
+
+        assert_eq!(
+            apply_json_filter(body, "$.data.items[0].name").unwrap(),
+            json!("alpha")
+        );
+        assert_eq!(
+            apply_json_filter(body, "$.data.items[*].id").unwrap(),
+            json!([1, 2, 3])
+        );
+        assert_eq!(apply_json_filter(body, "$..id").unwrap(), json!([1, 2, 3]));
+        assert_eq!(
+            apply_json_filter(body, "$['data']['items'][-1].name").unwrap(),
+            json!("gamma")
+        );
+        assert_eq!(
+            apply_json_filter(body, "$.data.items[1:].name").unwrap(),
+            json!(["beta", "gamma"])
+        );
+        assert_eq!(
+            apply_json_filter(body, ".data.items[] | .name").unwrap(),
+            json!(["alpha", "beta", "gamma"])
+        );
+        assert_eq!(
+            apply_json_filter(body, ".data.items | length").unwrap(),
+            json!(3)
+        );
+        assert_eq!(
+            apply_json_filter(body, "data.items[0] | keys").unwrap(),
+            json!(["id", "name", "tags"])
+        );
+        assert_eq!(apply_json_filter(body, ".missing").unwrap(), json!([]));
+        assert!(apply_json_filter(body, ".data.items[x]").is_err());
+        assert!(apply_json_filter("not json", ".a").is_err());
+    }
+
+    #[test]
+    fn test_json_path_lines_follow_pretty_print() {
+        let value = json!({"data": {"items": [{"id": 1}, []], "odd key": null}});
+        let pretty = serde_json::to_string_pretty(&value).unwrap();
+        let paths = json_path_lines(&value);
+        assert_eq!(paths.len(), pretty.lines().count());
+
+        let line_of = |needle: &str| pretty.lines().position(|l| l.contains(needle)).unwrap();
+        assert_eq!(paths[line_of("\"id\"")], "$.data.items[0].id");
+        assert_eq!(paths[line_of("[]")], "$.data.items[1]");
+        assert_eq!(paths[line_of("odd key")], "$.data['odd key']");
+        assert_eq!(paths[0], "$");
+
+        // Copied paths resolve back to the node
+        let body = value.to_string();
+        for (path, expected) in [
+            ("$.data.items[0].id", json!(1)),
+            ("$.data['odd key']", json!(null)),
+        ] {
+            assert_eq!(apply_json_filter(&body, path).unwrap(), expected);
+        }
+    }
+}
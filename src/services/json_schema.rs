@@ -0,0 +1,346 @@
+use serde_json::Value;
+
+/// Deepest `$ref` chain followed before giving up on a recursive schema
+const MAX_REF_DEPTH: usize = 32;
+
+/// One place where a document breaks its schema
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaViolation {
+    /// JSONPath of the offending value (`$.data.items[2].id`)
+    pub path: String,
+    pub message: String,
+}
+
+/// Check `instance` against a JSON Schema, returning every violation found.
+///
+/// Covers the keywords contract checks lean on: `type`, `enum`, `const`,
+/// `properties`, `required`, `additionalProperties`, `items`, `minItems`,
+/// `maxItems`, `uniqueItems`, `minLength`, `maxLength`, `minimum`, `maximum`,
+/// `exclusiveMinimum`, `exclusiveMaximum`, `multipleOf`, `allOf`, `anyOf`,
+/// `oneOf`, `not` and local `$ref`s (`#/definitions/...`, `#/$defs/...`).
+/// Other keywords (`format`, `pattern`, ...) are ignored.
+pub fn validate_schema(schema: &Value, instance: &Value) -> Vec<SchemaViolation> {
+    let mut validator = Validator {
+        root: schema,
+        violations: Vec::new(),
+    };
+    validator.check(schema, instance, "$", 0);
+    validator.violations
+}
+
+struct Validator<'a> {
+    root: &'a Value,
+    violations: Vec<SchemaViolation>,
+}
+
+impl<'a> Validator<'a> {
+    fn fail(&mut self, path: &str, message: String) {
+        self.violations.push(SchemaViolation {
+            path: path.to_string(),
+            message,
+        });
+    }
+
+    /// Whether `instance` matches `schema`, without recording anything
+    fn matches(&self, schema: &'a Value, instance: &Value, depth: usize) -> bool {
+        let mut probe = Validator {
+            root: self.root,
+            violations: Vec::new(),
+        };
+        probe.check(schema, instance, "$", depth);
+        probe.violations.is_empty()
+    }
+
+    fn check(&mut self, schema: &'a Value, instance: &Value, path: &str, depth: usize) {
+        let schema = match schema {
+            Value::Bool(true) => return,
+            Value::Bool(false) => {
+                self.fail(path, "no value is allowed here".to_string());
+                return;
+            }
+            Value::Object(schema) => schema,
+            _ => return,
+        };
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            if depth >= MAX_REF_DEPTH {
+                self.fail(path, format!("$ref '{}' nests too deeply", reference));
+                return;
+            }
+            match resolve_ref(self.root, reference) {
+                Some(target) => self.check(target, instance, path, depth + 1),
+                None => self.fail(path, format!("cannot resolve $ref '{}'", reference)),
+            }
+        }
+
+        if let Some(expected) = schema.get("type")
+            && !type_matches(expected, instance)
+        {
+            let expected = match expected {
+                Value::Array(types) => types
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+                    .join(" or "),
+                other => other.as_str().unwrap_or_default().to_string(),
+            };
+            self.fail(
+                path,
+                format!("expected {}, got {}", expected, type_name(instance)),
+            );
+            // Keywords for the expected type would only repeat the mismatch
+            return;
+        }
+
+        if let Some(Value::Array(allowed)) = schema.get("enum")
+            && !allowed.contains(instance)
+        {
+            let allowed: Vec<String> = allowed.iter().map(Value::to_string).collect();
+            self.fail(
+                path,
+                format!("{} is not one of {}", instance, allowed.join(", ")),
+            );
+        }
+        if let Some(expected) = schema.get("const")
+            && expected != instance
+        {
+            self.fail(path, format!("expected {}, got {}", expected, instance));
+        }
+
+        match instance {
+            Value::Object(object) => {
+                if let Some(Value::Array(required)) = schema.get("required") {
+                    for name in required.iter().filter_map(Value::as_str) {
+                        if !object.contains_key(name) {
+                            self.fail(path, format!("missing required property '{}'", name));
+                        }
+                    }
+                }
+                let properties = schema.get("properties").and_then(Value::as_object);
+                for (name, value) in object {
+                    let child_path = format!("{}.{}", path, name);
+                    match properties.and_then(|properties| properties.get(name)) {
+                        Some(property) => self.check(property, value, &child_path, depth),
+                        None => match schema.get("additionalProperties") {
+                            Some(Value::Bool(false)) => {
+                                self.fail(path, format!("unexpected property '{}'", name));
+                            }
+                            Some(additional) => self.check(additional, value, &child_path, depth),
+                            None => {}
+                        },
+                    }
+                }
+            }
+            Value::Array(items) => {
+                if let Some(min) = schema.get("minItems").and_then(Value::as_u64)
+                    && (items.len() as u64) < min
+                {
+                    self.fail(
+                        path,
+                        format!("expected at least {} items, got {}", min, items.len()),
+                    );
+                }
+                if let Some(max) = schema.get("maxItems").and_then(Value::as_u64)
+                    && items.len() as u64 > max
+                {
+                    self.fail(
+                        path,
+                        format!("expected at most {} items, got {}", max, items.len()),
+                    );
+                }
+                if schema.get("uniqueItems") == Some(&Value::Bool(true))
+                    && let Some(index) =
+                        (1..items.len()).find(|&index| items[..index].contains(&items[index]))
+                {
+                    self.fail(path, format!("item {} is a duplicate", index));
+                }
+                if let Some(item_schema) = schema.get("items") {
+                    for (index, item) in items.iter().enumerate() {
+                        self.check(item_schema, item, &format!("{}[{}]", path, index), depth);
+                    }
+                }
+            }
+            Value::String(text) => {
+                let length = text.chars().count() as u64;
+                if let Some(min) = schema.get("minLength").and_then(Value::as_u64)
+                    && length < min
+                {
+                    self.fail(
+                        path,
+                        format!("expected at least {} characters, got {}", min, length),
+                    );
+                }
+                if let Some(max) = schema.get("maxLength").and_then(Value::as_u64)
+                    && length > max
+                {
+                    self.fail(
+                        path,
+                        format!("expected at most {} characters, got {}", max, length),
+                    );
+                }
+            }
+            Value::Number(number) => {
+                let value = number.as_f64().unwrap_or_default();
+                let bound = |keyword: &str| schema.get(keyword).and_then(Value::as_f64);
+                if let Some(min) = bound("minimum")
+                    && value < min
+                {
+                    self.fail(path, format!("{} is below the minimum {}", number, min));
+                }
+                if let Some(max) = bound("maximum")
+                    && value > max
+                {
+                    self.fail(path, format!("{} is above the maximum {}", number, max));
+                }
+                if let Some(min) = bound("exclusiveMinimum")
+                    && value <= min
+                {
+                    self.fail(path, format!("{} must be greater than {}", number, min));
+                }
+                if let Some(max) = bound("exclusiveMaximum")
+                    && value >= max
+                {
+                    self.fail(path, format!("{} must be less than {}", number, max));
+                }
+                if let Some(step) = bound("multipleOf")
+                    && step > 0.0
+                    && ((value / step).round() * step - value).abs() > f64::EPSILON * value.abs()
+                {
+                    self.fail(path, format!("{} is not a multiple of {}", number, step));
+                }
+            }
+            _ => {}
+        }
+
+        if let Some(Value::Array(all)) = schema.get("allOf") {
+            for sub in all {
+                self.check(sub, instance, path, depth);
+            }
+        }
+        if let Some(Value::Array(any)) = schema.get("anyOf")
+            && !any.iter().any(|sub| self.matches(sub, instance, depth))
+        {
+            self.fail(path, "matches none of the anyOf schemas".to_string());
+        }
+        if let Some(Value::Array(one)) = schema.get("oneOf") {
+            let matching = one
+                .iter()
+                .filter(|sub| self.matches(sub, instance, depth))
+                .count();
+            if matching != 1 {
+                self.fail(
+                    path,
+                    format!(
+                        "matches {} of the oneOf schemas, expected exactly 1",
+                        matching
+                    ),
+                );
+            }
+        }
+        if let Some(not) = schema.get("not")
+            && self.matches(not, instance, depth)
+        {
+            self.fail(path, "matches the schema under 'not'".to_string());
+        }
+    }
+}
+
+/// Follow a local `$ref` (`#`, `#/definitions/User`) to the schema it names
+fn resolve_ref<'a>(root: &'a Value, reference: &str) -> Option<&'a Value> {
+    let pointer = reference.strip_prefix('#')?;
+    let pointer = pointer.replace("%25", "%").replace("%22", "\"");
+    root.pointer(&pointer)
+}
+
+fn type_matches(expected: &Value, instance: &Value) -> bool {
+    match expected {
+        Value::String(name) => is_type(name, instance),
+        Value::Array(names) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|name| is_type(name, instance)),
+        _ => true,
+    }
+}
+
+fn is_type(name: &str, instance: &Value) -> bool {
+    match name {
+        "null" => instance.is_null(),
+        "boolean" => instance.is_boolean(),
+        "object" => instance.is_object(),
+        "array" => instance.is_array(),
+        "string" => instance.is_string(),
+        "number" => instance.is_number(),
+        // 1.0 counts as an integer, as the spec asks
+        "integer" => instance
+            .as_f64()
+            .is_some_and(|number| number.fract() == 0.0),
+        _ => true,
+    }
+}
+
+fn type_name(instance: &Value) -> &'static str {
+    match instance {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Object(_) => "object",
+        Value::Array(_) => "array",
+        Value::String(_) => "string",
+        Value::Number(number) if number.is_f64() => "number",
+        Value::Number(_) => "integer",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_validate_schema_reports_each_violation_with_its_path() {
+        let schema = json!({
+            "type": "object",
+            "required": ["id", "name"],
+            "additionalProperties": false,
+            "properties": {
+                "id": {"type": "integer", "minimum": 1},
+                "name": {"type": "string", "minLength": 1},
+                "role": {"enum": ["admin", "user"]},
+                "tags": {"type": "array", "items": {"$ref": "#/$defs/tag"}}
+            },
+            "$defs": {"tag": {"type": "string"}}
+        });
+
+        let valid = json!({"id": 7, "name": "Alice", "role": "admin", "tags": ["x"]});
+        assert!(validate_schema(&schema, &valid).is_empty());
+
+        let invalid = json!({"extra": 1, "id": "7", "name": "", "role": "root", "tags": [1]});
+        let messages: Vec<String> = validate_schema(&schema, &invalid)
+            .into_iter()
+            .map(|violation| format!("{}: {}", violation.path, violation.message))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "$: unexpected property 'extra'",
+                "$.id: expected integer, got string",
+                "$.name: expected at least 1 characters, got 0",
+                "$.role: \"root\" is not one of \"admin\", \"user\"",
+                "$.tags[0]: expected string, got integer",
+            ]
+        );
+
+        let missing = json!({"id": 0});
+        let messages: Vec<String> = validate_schema(&schema, &missing)
+            .into_iter()
+            .map(|violation| violation.message)
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "missing required property 'name'",
+                "0 is below the minimum 1"
+            ]
+        );
+    }
+}
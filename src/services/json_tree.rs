@@ -0,0 +1,164 @@
+use crate::services::json_filter::member_path;
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// One visible row of a JSON document shown as a collapsible tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonTreeRow {
+    /// JSONPath of the node, e.g. `$.items[0].name`
+    pub path: String,
+    /// JSON Pointer of the node, for looking its value up again
+    pub pointer: String,
+    pub depth: usize,
+    /// Object key or `[index]` leading to the node; `None` for the root
+    pub label: Option<String>,
+    /// Scalar value, or `{3 keys}` / `[5 items]` for containers
+    pub summary: String,
+    /// Non-empty object or array that can be expanded and collapsed
+    pub expandable: bool,
+    pub expanded: bool,
+}
+
+impl JsonTreeRow {
+    /// Expand marker shown before the label
+    pub fn marker(&self) -> &'static str {
+        match (self.expandable, self.expanded) {
+            (false, _) => " ",
+            (true, true) => "▼",
+            (true, false) => "▶",
+        }
+    }
+
+    /// The row as plain text, exactly as the tree viewer draws it
+    pub fn text(&self) -> String {
+        let indent = "  ".repeat(self.depth);
+        match &self.label {
+            Some(label) => format!("{}{} {}: {}", indent, self.marker(), label, self.summary),
+            None => format!("{}{} {}", indent, self.marker(), self.summary),
+        }
+    }
+}
+
+/// Rows of `value` as a tree, skipping the children of collapsed paths
+pub fn json_tree_rows(value: &Value, collapsed: &HashSet<String>) -> Vec<JsonTreeRow> {
+    let mut rows = Vec::new();
+    collect_rows(
+        value,
+        "$".to_string(),
+        String::new(),
+        None,
+        0,
+        collapsed,
+        &mut rows,
+    );
+    rows
+}
+
+fn collect_rows(
+    value: &Value,
+    path: String,
+    pointer: String,
+    label: Option<String>,
+    depth: usize,
+    collapsed: &HashSet<String>,
+    rows: &mut Vec<JsonTreeRow>,
+) {
+    let (summary, expandable) = match value {
+        Value::Object(map) => (count_label(map.len(), "key", "{", "}"), !map.is_empty()),
+        Value::Array(items) => (
+            count_label(items.len(), "item", "[", "]"),
+            !items.is_empty(),
+        ),
+        scalar => (scalar.to_string(), false),
+    };
+    let expanded = expandable && !collapsed.contains(&path);
+    rows.push(JsonTreeRow {
+        path: path.clone(),
+        pointer: pointer.clone(),
+        depth,
+        label,
+        summary,
+        expandable,
+        expanded,
+    });
+    if !expanded {
+        return;
+    }
+
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                collect_rows(
+                    child,
+                    member_path(&path, key),
+                    format!("{}/{}", pointer, key.replace('~', "~0").replace('/', "~1")),
+                    Some(key.clone()),
+                    depth + 1,
+                    collapsed,
+                    rows,
+                );
+            }
+        }
+        Value::Array(items) => {
+            for (index, child) in items.iter().enumerate() {
+                collect_rows(
+                    child,
+                    format!("{}[{}]", path, index),
+                    format!("{}/{}", pointer, index),
+                    Some(format!("[{}]", index)),
+                    depth + 1,
+                    collapsed,
+                    rows,
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `{3 keys}`, `[1 item]`, `{}`
+fn count_label(count: usize, noun: &str, open: &str, close: &str) -> String {
+    match count {
+        0 => format!("{}{}", open, close),
+        1 => format!("{}1 {}{}", open, noun, close),
+        n => format!("{}{} {}s{}", open, n, noun, close),
+    }
+}
+
+/// The node's value for the clipboard: strings unquoted, everything else as JSON
+pub fn json_tree_value(value: &Value, pointer: &str) -> Option<String> {
+    match value.pointer(pointer)? {
+        Value::String(text) => Some(text.clone()),
+        scalar @ (Value::Null | Value::Bool(_) | Value::Number(_)) => Some(scalar.to_string()),
+        container => serde_json::to_string_pretty(container).ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_tree_rows_hide_collapsed_children() {
+        let document = json!({"items": [{"name": "a/b"}], "total": 1});
+        let rows = json_tree_rows(&document, &HashSet::new());
+        let paths: Vec<&str> = rows.iter().map(|row| row.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["$", "$.items", "$.items[0]", "$.items[0].name", "$.total"]
+        );
+        assert_eq!(rows[1].text(), "  ▼ items: [1 item]");
+        assert_eq!(rows[3].text(), "        name: \"a/b\"");
+        assert_eq!(
+            json_tree_value(&document, &rows[3].pointer).as_deref(),
+            Some("a/b")
+        );
+
+        let collapsed = HashSet::from(["$.items".to_string()]);
+        let rows = json_tree_rows(&document, &collapsed);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[1].marker(), "▶");
+        assert_eq!(rows[2].path, "$.total");
+    }
+}
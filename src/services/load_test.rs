@@ -0,0 +1,276 @@
+use crate::models::http_client::{HttpMethod, HttpRequest, HttpResponse};
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+/// Upper bounds so a typo can't start a million requests
+pub const MAX_ITERATIONS: usize = 10_000;
+pub const MAX_CONCURRENCY: usize = 200;
+
+/// How many times to send the request and how many sends may overlap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadTestSettings {
+    pub iterations: usize,
+    pub concurrency: usize,
+}
+
+impl Default for LoadTestSettings {
+    fn default() -> Self {
+        Self {
+            iterations: 100,
+            concurrency: 10,
+        }
+    }
+}
+
+/// Outcome of one send during a load test
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadSample {
+    pub duration_ms: u64,
+    /// `None` when the send failed before a response arrived
+    pub status_code: Option<u16>,
+    pub error: Option<String>,
+}
+
+impl LoadSample {
+    /// Connection errors and 4xx/5xx responses count as errors
+    pub fn failed(&self) -> bool {
+        !self
+            .status_code
+            .is_some_and(|code| (200..400).contains(&code))
+    }
+}
+
+/// A load test of one request, filled in as samples arrive
+#[derive(Debug, Clone)]
+pub struct LoadTest {
+    pub request_name: String,
+    pub method: HttpMethod,
+    pub url: String,
+    pub settings: LoadTestSettings,
+    pub samples: Vec<LoadSample>,
+    pub started_at: Instant,
+    /// Wall time of the whole test, set once it finished or was cancelled
+    pub elapsed_ms: Option<u64>,
+    pub cancelled: bool,
+}
+
+impl LoadTest {
+    pub fn new(request: &HttpRequest, settings: LoadTestSettings) -> Self {
+        Self {
+            request_name: request.name.clone(),
+            method: request.method.clone(),
+            url: request.url.clone(),
+            settings,
+            samples: Vec::new(),
+            started_at: Instant::now(),
+            elapsed_ms: None,
+            cancelled: false,
+        }
+    }
+
+    pub fn finished(&self) -> bool {
+        self.elapsed_ms.is_some()
+    }
+
+    pub fn finish(&mut self, cancelled: bool) {
+        self.elapsed_ms = Some(self.started_at.elapsed().as_millis() as u64);
+        self.cancelled = cancelled;
+    }
+
+    /// Wall time so far, or of the whole test once finished
+    pub fn wall_time_ms(&self) -> u64 {
+        self.elapsed_ms
+            .unwrap_or_else(|| self.started_at.elapsed().as_millis() as u64)
+    }
+
+    /// Latency below which `percent` of the sends completed (nearest rank)
+    pub fn percentile(&self, percent: f64) -> Option<u64> {
+        let mut durations: Vec<u64> = self
+            .samples
+            .iter()
+            .map(|sample| sample.duration_ms)
+            .collect();
+        if durations.is_empty() {
+            return None;
+        }
+        durations.sort_unstable();
+        let rank = (percent / 100.0 * durations.len() as f64).ceil() as usize;
+        Some(durations[rank.clamp(1, durations.len()) - 1])
+    }
+
+    pub fn error_count(&self) -> usize {
+        self.samples.iter().filter(|sample| sample.failed()).count()
+    }
+
+    /// Share of the sends that failed, from 0.0 to 1.0
+    pub fn error_rate(&self) -> f64 {
+        if self.samples.is_empty() {
+            0.0
+        } else {
+            self.error_count() as f64 / self.samples.len() as f64
+        }
+    }
+
+    /// Completed sends per second of wall time
+    pub fn throughput(&self) -> f64 {
+        let seconds = self.wall_time_ms() as f64 / 1000.0;
+        if seconds <= 0.0 {
+            0.0
+        } else {
+            self.samples.len() as f64 / seconds
+        }
+    }
+
+    /// How often each status code (or `ERR` for failed sends) came back
+    pub fn status_counts(&self) -> BTreeMap<String, usize> {
+        let mut counts = BTreeMap::new();
+        for sample in &self.samples {
+            let key = sample
+                .status_code
+                .map_or_else(|| "ERR".to_string(), |code| code.to_string());
+            *counts.entry(key).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Most recent connection error, shown below the numbers
+    pub fn last_error(&self) -> Option<&str> {
+        self.samples
+            .iter()
+            .rev()
+            .find_map(|sample| sample.error.as_deref())
+    }
+}
+
+/// Send `request` `settings.iterations` times with at most `settings.concurrency`
+/// sends in flight, reporting each outcome as it completes.
+///
+/// `send` sends a single request; it is a parameter so the runner can be tested
+/// without a server.
+pub async fn run_load_test<F, Fut, R>(
+    request: HttpRequest,
+    settings: LoadTestSettings,
+    send: F,
+    report: R,
+) where
+    F: Fn(HttpRequest) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<HttpResponse>> + Send,
+    R: Fn(LoadSample) + Send + Sync + 'static,
+{
+    let iterations = settings.iterations;
+    let next = Arc::new(AtomicUsize::new(0));
+    let send = Arc::new(send);
+    let report = Arc::new(report);
+
+    let mut workers = tokio::task::JoinSet::new();
+    for _ in 0..settings.concurrency.clamp(1, iterations.max(1)) {
+        let next = next.clone();
+        let send = send.clone();
+        let report = report.clone();
+        let request = request.clone();
+        workers.spawn(async move {
+            while next.fetch_add(1, Ordering::Relaxed) < iterations {
+                let started = Instant::now();
+                let sample = match send(request.clone()).await {
+                    Ok(response) => LoadSample {
+                        duration_ms: response.duration_ms,
+                        status_code: Some(response.status_code),
+                        error: None,
+                    },
+                    Err(error) => LoadSample {
+                        duration_ms: started.elapsed().as_millis() as u64,
+                        status_code: None,
+                        error: Some(format!("{:#}", error)),
+                    },
+                };
+                report(sample);
+            }
+        });
+    }
+    while workers.join_next().await.is_some() {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::transfer::TransferStats;
+    use anyhow::anyhow;
+    use std::sync::Mutex;
+
+    #[tokio::test]
+    async fn test_load_test_sends_every_iteration_and_summarizes() {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let sent = Arc::new(AtomicUsize::new(0));
+        let request = HttpRequest::new("Users").with_url("https://example.com/users");
+        let settings = LoadTestSettings {
+            iterations: 20,
+            concurrency: 4,
+        };
+        let test = Arc::new(Mutex::new(LoadTest::new(&request, settings)));
+
+        let (counter, max, total) = (in_flight.clone(), peak.clone(), sent.clone());
+        let results = test.clone();
+        run_load_test(
+            request,
+            settings,
+            move |_| {
+                let (counter, max, total) = (counter.clone(), max.clone(), total.clone());
+                async move {
+                    let now = counter.fetch_add(1, Ordering::SeqCst) + 1;
+                    max.fetch_max(now, Ordering::SeqCst);
+                    tokio::task::yield_now().await;
+                    counter.fetch_sub(1, Ordering::SeqCst);
+                    let index = total.fetch_add(1, Ordering::SeqCst);
+                    if index % 5 == 4 {
+                        return Err(anyhow!("connection reset"));
+                    }
+                    Ok(HttpResponse {
+                        status_code: 200,
+                        status_text: "OK".to_string(),
+                        headers: Vec::new(),
+                        body: String::new(),
+                        body_bytes: Vec::new(),
+                        content_type: String::new(),
+                        duration_ms: (index as u64 + 1) * 10,
+                        served_from_cache: false,
+                        connection: Default::default(),
+                        transfer: TransferStats::default(),
+                        assertions: Vec::new(),
+                        extracted: Vec::new(),
+                        truncation: None,
+                        timing: Default::default(),
+                    })
+                }
+            },
+            move |sample| results.lock().unwrap().samples.push(sample),
+        )
+        .await;
+
+        assert_eq!(sent.load(Ordering::SeqCst), 20);
+        assert!(peak.load(Ordering::SeqCst) <= 4);
+        let mut test = test.lock().unwrap().clone();
+        test.finish(false);
+        assert_eq!(test.samples.len(), 20);
+        assert_eq!(test.error_count(), 4);
+        assert!((test.error_rate() - 0.2).abs() < f64::EPSILON);
+        assert_eq!(test.status_counts().get("200"), Some(&16));
+        assert_eq!(test.last_error(), Some("connection reset"));
+
+        let mut durations = LoadTest::new(&HttpRequest::new("x"), settings);
+        durations.samples = (1..=100)
+            .map(|ms| LoadSample {
+                duration_ms: ms,
+                status_code: Some(200),
+                error: None,
+            })
+            .collect();
+        assert_eq!(durations.percentile(50.0), Some(50));
+        assert_eq!(durations.percentile(95.0), Some(95));
+        assert_eq!(durations.percentile(99.0), Some(99));
+    }
+}
@@ -1,8 +1,52 @@
+pub mod assertions;
+pub mod audit_log;
+pub mod auth_script;
 pub mod auth_service;
+pub mod backup;
+pub mod bruno_import;
+pub mod bug_report;
+pub mod changelog;
+pub mod collection_runner;
+pub mod curl_import;
+pub mod drafts;
+pub mod environment_rewrite;
+pub mod external_editor;
+pub mod extraction;
+pub mod health_check;
+pub mod history_storage;
 pub mod http_collection_storage;
+pub mod image_preview;
+pub mod insomnia_import;
+pub mod item_diff;
+pub mod json_filter;
+pub mod json_schema;
+pub mod json_tree;
+pub mod load_test;
+pub mod multipart;
+pub mod oauth2;
+pub mod openapi_import;
+pub mod pagination;
+pub mod postman_import;
+pub mod pre_request_script;
+pub mod process_registry;
+pub mod proxy;
+pub mod raw_headers;
+pub mod request_log;
+pub mod response_cache;
+pub mod response_diff;
+pub mod session;
+pub mod snippet_generator;
+pub mod storage_migration;
+pub mod system_clipboard;
+pub mod template_bundle;
 pub mod template_storage;
+pub mod timing;
+pub mod tls;
+pub mod tree_loader;
+pub mod update_checker;
 
 // Re-export for convenience
 pub use auth_service::AuthService;
 pub use http_collection_storage::{HttpCollectionStorage, PostmanCollection, StoredHttpRequest};
 pub use template_storage::{StoredTemplate, TemplateStorage}; // NEW
+pub use tree_loader::spawn_tree_scan;
@@ -0,0 +1,158 @@
+use crate::models::http_client::HttpMultipartPart;
+use crate::services::storage_migration::expand_user_path;
+use anyhow::{Context, Result};
+use std::path::Path;
+
+/// An encoded multipart/form-data body and the Content-Type header announcing its boundary
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultipartBody {
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Encode the enabled parts, reading file parts from disk
+pub fn encode_multipart(parts: &[HttpMultipartPart]) -> Result<MultipartBody> {
+    let boundary = format!("----tui_one{}", uuid::Uuid::new_v4().simple());
+    encode_with_boundary(parts, &boundary)
+}
+
+fn encode_with_boundary(parts: &[HttpMultipartPart], boundary: &str) -> Result<MultipartBody> {
+    let mut bytes = Vec::new();
+    for part in parts.iter().filter(|part| part.enabled) {
+        bytes.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+        let disposition = format!(
+            "Content-Disposition: form-data; name=\"{}\"",
+            quote(&part.name)
+        );
+        if part.is_file {
+            let path = expand_user_path(&part.value);
+            let content = std::fs::read(&path).with_context(|| {
+                format!(
+                    "Failed to read file for part '{}': {}",
+                    part.name,
+                    path.display()
+                )
+            })?;
+            let filename = path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            bytes.extend_from_slice(
+                format!(
+                    "{}; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+                    disposition,
+                    quote(&filename),
+                    guess_content_type(&path)
+                )
+                .as_bytes(),
+            );
+            bytes.extend_from_slice(&content);
+        } else {
+            bytes.extend_from_slice(format!("{}\r\n\r\n", disposition).as_bytes());
+            bytes.extend_from_slice(part.value.as_bytes());
+        }
+        bytes.extend_from_slice(b"\r\n");
+    }
+    bytes.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+
+    Ok(MultipartBody {
+        content_type: format!("multipart/form-data; boundary={}", boundary),
+        bytes,
+    })
+}
+
+/// Enabled parts as `name=value` lines, file parts as curl's `name=@path`
+pub fn multipart_summary(parts: &[HttpMultipartPart], separator: &str) -> String {
+    parts
+        .iter()
+        .filter(|part| part.enabled)
+        .map(|part| {
+            let marker = if part.is_file { "@" } else { "" };
+            format!("{}={}{}", part.name, marker, part.value)
+        })
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Percent-encode what would break a quoted header parameter, as browsers do
+fn quote(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Content type of a file body: the one given, else guessed from the path
+pub fn file_content_type(path: &str, content_type: &str) -> String {
+    if content_type.trim().is_empty() {
+        guess_content_type(Path::new(path)).to_string()
+    } else {
+        content_type.trim().to_string()
+    }
+}
+
+/// Content type of a file part, from its extension
+pub fn guess_content_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| extension.to_ascii_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "gz" | "tgz" => "application/gzip",
+        "txt" | "log" | "md" => "text/plain",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        "webp" => "image/webp",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_text_and_file_parts() {
+        let temp = tempfile::tempdir().unwrap();
+        let report = temp.path().join("report.csv");
+        std::fs::write(&report, "a,b\n1,2\n").unwrap();
+
+        let mut skipped = HttpMultipartPart::new("draft", "yes");
+        skipped.enabled = false;
+        let parts = vec![
+            HttpMultipartPart::new("title", "Q3 \"final\""),
+            HttpMultipartPart::file("upload", report.display().to_string()),
+            skipped,
+        ];
+
+        let body = encode_with_boundary(&parts, "XYZ").unwrap();
+        assert_eq!(body.content_type, "multipart/form-data; boundary=XYZ");
+        assert_eq!(
+            String::from_utf8(body.bytes).unwrap(),
+            "--XYZ\r\n\
+             Content-Disposition: form-data; name=\"title\"\r\n\r\n\
+             Q3 \"final\"\r\n\
+             --XYZ\r\n\
+             Content-Disposition: form-data; name=\"upload\"; filename=\"report.csv\"\r\n\
+             Content-Type: text/csv\r\n\r\n\
+             a,b\n1,2\n\r\n\
+             --XYZ--\r\n"
+        );
+
+        let missing = vec![HttpMultipartPart::file(
+            "upload",
+            temp.path().join("gone.bin").display().to_string(),
+        )];
+        let error = encode_multipart(&missing).unwrap_err().to_string();
+        assert!(error.contains("part 'upload'"), "{}", error);
+    }
+}
@@ -0,0 +1,528 @@
+use crate::models::http_client::{HttpAuth, ProxySettings};
+use crate::services::proxy::apply_proxy;
+use crate::services::system_clipboard::base64_encode;
+use anyhow::{Context, Result, anyhow, bail};
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Tokens are refreshed this long before they expire, so a request never
+/// races the expiry on its way to the server
+const EXPIRY_MARGIN_SECONDS: i64 = 60;
+
+/// How long to wait for the user to finish signing in
+const AUTHORIZATION_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Path the browser is sent back to after signing in
+const CALLBACK_PATH: &str = "/callback";
+
+/// Access tokens fetched this session, keyed by `OAuth2Config::cache_key`
+pub type OAuth2TokenCache = Arc<Mutex<HashMap<String, OAuth2Token>>>;
+
+/// How the access token is obtained
+#[derive(Debug, Clone, PartialEq)]
+pub enum OAuth2Grant {
+    ClientCredentials,
+    AuthorizationCode {
+        auth_url: String,
+        redirect_port: String,
+    },
+}
+
+/// The OAuth 2.0 settings of a request's auth, with `{{variables}}` already filled in
+#[derive(Debug, Clone, PartialEq)]
+pub struct OAuth2Config {
+    pub grant: OAuth2Grant,
+    pub token_url: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub scope: String,
+    /// Proxy the token endpoint is reached through (the request's own)
+    pub proxy: ProxySettings,
+}
+
+impl OAuth2Config {
+    /// `None` unless `auth` is one of the OAuth 2.0 variants
+    pub fn from_auth(auth: &HttpAuth) -> Option<Self> {
+        match auth {
+            HttpAuth::OAuth2ClientCredentials {
+                token_url,
+                client_id,
+                client_secret,
+                scope,
+            } => Some(Self {
+                grant: OAuth2Grant::ClientCredentials,
+                token_url: token_url.trim().to_string(),
+                client_id: client_id.trim().to_string(),
+                client_secret: client_secret.clone(),
+                scope: scope.trim().to_string(),
+                proxy: ProxySettings::default(),
+            }),
+            HttpAuth::OAuth2AuthorizationCode {
+                auth_url,
+                token_url,
+                client_id,
+                client_secret,
+                scope,
+                redirect_port,
+            } => Some(Self {
+                grant: OAuth2Grant::AuthorizationCode {
+                    auth_url: auth_url.trim().to_string(),
+                    redirect_port: redirect_port.trim().to_string(),
+                },
+                token_url: token_url.trim().to_string(),
+                client_id: client_id.trim().to_string(),
+                client_secret: client_secret.clone(),
+                scope: scope.trim().to_string(),
+                proxy: ProxySettings::default(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Requests sharing a client and scope share its token
+    pub fn cache_key(&self) -> String {
+        let grant = match self.grant {
+            OAuth2Grant::ClientCredentials => "client_credentials",
+            OAuth2Grant::AuthorizationCode { .. } => "authorization_code",
+        };
+        format!(
+            "{}|{}|{}|{}",
+            grant, self.token_url, self.client_id, self.scope
+        )
+    }
+}
+
+/// An access token and what is needed to renew it
+#[derive(Debug, Clone, PartialEq)]
+pub struct OAuth2Token {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    /// `None` when the server didn't say how long the token lives
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl OAuth2Token {
+    /// Expired, or about to be
+    pub fn needs_refresh(&self) -> bool {
+        self.expires_at.is_some_and(|expires_at| {
+            expires_at - chrono::Duration::seconds(EXPIRY_MARGIN_SECONDS) <= Utc::now()
+        })
+    }
+}
+
+/// Token endpoint reply (RFC 6749 section 5.1)
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    refresh_token: Option<String>,
+    #[serde(default)]
+    expires_in: Option<i64>,
+}
+
+/// Token endpoint error reply (RFC 6749 section 5.2)
+#[derive(Debug, Deserialize)]
+struct TokenError {
+    error: String,
+    #[serde(default)]
+    error_description: Option<String>,
+}
+
+/// A valid access token for `config`: the cached one while it lasts, then a
+/// refreshed one, and only then a new one from the token endpoint.
+///
+/// `notify` receives progress worth showing the user, such as the sign-in URL
+/// of the authorization code flow.
+pub async fn access_token(
+    config: &OAuth2Config,
+    cache: &OAuth2TokenCache,
+    notify: impl Fn(String),
+) -> Result<String> {
+    let key = config.cache_key();
+    let cached = cache.lock().unwrap().get(&key).cloned();
+    if let Some(token) = &cached
+        && !token.needs_refresh()
+    {
+        return Ok(token.access_token.clone());
+    }
+
+    let refreshed = match cached.and_then(|token| token.refresh_token) {
+        Some(refresh_token) => match refresh(config, &refresh_token).await {
+            Ok(mut token) => {
+                // Servers may keep the old refresh token valid without resending it
+                token.refresh_token.get_or_insert(refresh_token);
+                notify("OAuth 2.0 access token refreshed".to_string());
+                Some(token)
+            }
+            Err(e) => {
+                notify(format!(
+                    "OAuth 2.0 refresh failed ({}), signing in again",
+                    e
+                ));
+                None
+            }
+        },
+        None => None,
+    };
+    let token = match refreshed {
+        Some(token) => token,
+        None => acquire(config, &notify).await?,
+    };
+
+    cache.lock().unwrap().insert(key, token.clone());
+    Ok(token.access_token)
+}
+
+/// Fetch a brand new token with the configured grant
+async fn acquire(config: &OAuth2Config, notify: &impl Fn(String)) -> Result<OAuth2Token> {
+    match &config.grant {
+        OAuth2Grant::ClientCredentials => {
+            let mut params = vec![("grant_type", "client_credentials")];
+            if !config.scope.is_empty() {
+                params.push(("scope", &config.scope));
+            }
+            let token = request_token(config, params).await?;
+            notify("OAuth 2.0 access token acquired (client credentials)".to_string());
+            Ok(token)
+        }
+        OAuth2Grant::AuthorizationCode {
+            auth_url,
+            redirect_port,
+        } => authorize_interactively(config, auth_url, redirect_port, notify).await,
+    }
+}
+
+async fn refresh(config: &OAuth2Config, refresh_token: &str) -> Result<OAuth2Token> {
+    let mut params = vec![
+        ("grant_type", "refresh_token"),
+        ("refresh_token", refresh_token),
+    ];
+    if !config.scope.is_empty() {
+        params.push(("scope", &config.scope));
+    }
+    request_token(config, params).await
+}
+
+/// Authorization code flow with PKCE: send the user to the sign-in page and
+/// catch the redirect on a local listener
+async fn authorize_interactively(
+    config: &OAuth2Config,
+    auth_url: &str,
+    redirect_port: &str,
+    notify: &impl Fn(String),
+) -> Result<OAuth2Token> {
+    let port: u16 = redirect_port
+        .parse()
+        .with_context(|| format!("Redirect port '{}' is not a port number", redirect_port))?;
+    let redirect_uri = format!("http://127.0.0.1:{}{}", port, CALLBACK_PATH);
+    let verifier = format!(
+        "{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    );
+    let state = uuid::Uuid::new_v4().simple().to_string();
+
+    let mut url =
+        Url::parse(auth_url).with_context(|| format!("Invalid auth URL '{}'", auth_url))?;
+    {
+        let mut query = url.query_pairs_mut();
+        query
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &config.client_id)
+            .append_pair("redirect_uri", &redirect_uri)
+            .append_pair("state", &state)
+            .append_pair("code_challenge", &pkce_challenge(&verifier))
+            .append_pair("code_challenge_method", "S256");
+        if !config.scope.is_empty() {
+            query.append_pair("scope", &config.scope);
+        }
+    }
+
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .await
+        .with_context(|| format!("Could not listen for the redirect on port {}", port))?;
+    open_browser(url.as_str());
+    notify(format!(
+        "Sign in to authorize (waiting on {}): {}",
+        redirect_uri, url
+    ));
+
+    let code = tokio::time::timeout(AUTHORIZATION_TIMEOUT, wait_for_callback(&listener, &state))
+        .await
+        .map_err(|_| anyhow!("Timed out waiting for the sign-in to finish"))??;
+
+    let token = request_token(
+        config,
+        vec![
+            ("grant_type", "authorization_code"),
+            ("code", &code),
+            ("redirect_uri", &redirect_uri),
+            ("code_verifier", &verifier),
+        ],
+    )
+    .await?;
+    notify("OAuth 2.0 access token acquired (authorization code)".to_string());
+    Ok(token)
+}
+
+/// POST to the token endpoint; the client authenticates in the form body
+async fn request_token(
+    config: &OAuth2Config,
+    mut params: Vec<(&str, &str)>,
+) -> Result<OAuth2Token> {
+    if config.token_url.is_empty() {
+        bail!("OAuth 2.0 token URL is empty");
+    }
+    params.push(("client_id", &config.client_id));
+    if !config.client_secret.is_empty() {
+        params.push(("client_secret", &config.client_secret));
+    }
+
+    let response = apply_proxy(reqwest::Client::builder(), &config.proxy)?
+        .build()
+        .context("Failed to build HTTP client")?
+        .post(&config.token_url)
+        .header("Accept", "application/json")
+        .form(&params)
+        .send()
+        .await
+        .with_context(|| format!("Token request to {} failed", config.token_url))?;
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    parse_token_response(status.as_u16(), &body)
+}
+
+fn parse_token_response(status: u16, body: &str) -> Result<OAuth2Token> {
+    if let Ok(error) = serde_json::from_str::<TokenError>(body) {
+        match error.error_description {
+            Some(description) => bail!("{}: {}", error.error, description),
+            None => bail!("{}", error.error),
+        }
+    }
+    if !(200..300).contains(&status) {
+        bail!("Token endpoint returned {}: {}", status, body.trim());
+    }
+
+    let reply: TokenResponse =
+        serde_json::from_str(body).context("Token endpoint reply has no access_token")?;
+    Ok(OAuth2Token {
+        access_token: reply.access_token,
+        refresh_token: reply.refresh_token,
+        expires_at: reply
+            .expires_in
+            .map(|seconds| Utc::now() + chrono::Duration::seconds(seconds)),
+    })
+}
+
+/// S256 code challenge: unpadded base64url of the verifier's SHA-256
+fn pkce_challenge(verifier: &str) -> String {
+    base64_encode(&Sha256::digest(verifier.as_bytes()))
+        .trim_end_matches('=')
+        .replace('+', "-")
+        .replace('/', "_")
+}
+
+/// Serve the redirect until it brings an authorization code for `state`
+async fn wait_for_callback(listener: &TcpListener, state: &str) -> Result<String> {
+    loop {
+        let (mut stream, _) = listener.accept().await?;
+
+        let mut request = Vec::new();
+        let mut buffer = [0u8; 1024];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") && request.len() < 16 * 1024 {
+            let read = stream.read(&mut buffer).await?;
+            if read == 0 {
+                break;
+            }
+            request.extend_from_slice(&buffer[..read]);
+        }
+        let request = String::from_utf8_lossy(&request);
+        let target = request
+            .lines()
+            .next()
+            .and_then(|line| line.split_whitespace().nth(1))
+            .unwrap_or("/");
+
+        // Browsers also ask for /favicon.ico and the like
+        let url = Url::parse(&format!("http://127.0.0.1{}", target))?;
+        if url.path() != CALLBACK_PATH {
+            let _ = stream
+                .write_all(
+                    b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                )
+                .await;
+            continue;
+        }
+
+        let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        let outcome = if let Some(error) = params.get("error") {
+            Err(anyhow!(
+                "Authorization denied: {}",
+                params.get("error_description").unwrap_or(error)
+            ))
+        } else if params.get("state").map(String::as_str) != Some(state) {
+            Err(anyhow!("Authorization redirect carried the wrong state"))
+        } else {
+            params
+                .get("code")
+                .cloned()
+                .ok_or_else(|| anyhow!("Authorization redirect had no code"))
+        };
+
+        let message = match &outcome {
+            Ok(_) => "Signed in. You can close this tab and return to the terminal.".to_string(),
+            Err(e) => format!("Sign-in failed: {}", e),
+        };
+        let page = format!(
+            "<!DOCTYPE html><html><body><p>{}</p></body></html>",
+            message
+        );
+        let _ = stream
+            .write_all(
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    page.len(),
+                    page
+                )
+                .as_bytes(),
+            )
+            .await;
+        return outcome;
+    }
+}
+
+/// Best effort; the URL is also logged for terminals without a browser
+fn open_browser(url: &str) {
+    let mut command = if cfg!(target_os = "macos") {
+        std::process::Command::new("open")
+    } else if cfg!(windows) {
+        let mut command = std::process::Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        std::process::Command::new("xdg-open")
+    };
+    let _ = command
+        .arg(url)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Token endpoint answering each grant with a numbered token that expires
+    /// inside the refresh margin, so every use after the first refreshes
+    async fn token_server() -> (String, tokio::task::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/token", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let mut bodies = Vec::new();
+            for n in 1..=2 {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 4096];
+                loop {
+                    let read = stream.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                    let text = String::from_utf8_lossy(&request).to_string();
+                    if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                        let length: usize = head
+                            .lines()
+                            .find_map(|line| {
+                                line.to_ascii_lowercase()
+                                    .strip_prefix("content-length:")
+                                    .map(|v| v.trim().parse().unwrap())
+                            })
+                            .unwrap_or(0);
+                        if body.len() >= length {
+                            bodies.push(body.to_string());
+                            break;
+                        }
+                    }
+                }
+                let reply = format!(
+                    r#"{{"access_token": "t{}", "refresh_token": "r{}", "expires_in": 30}}"#,
+                    n, n
+                );
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    reply.len(),
+                    reply
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+            bodies
+        });
+        (url, handle)
+    }
+
+    #[tokio::test]
+    async fn test_client_credentials_token_is_cached_and_refreshed() {
+        let (token_url, server) = token_server().await;
+        let auth = HttpAuth::OAuth2ClientCredentials {
+            token_url,
+            client_id: "cli".to_string(),
+            client_secret: "s3cret".to_string(),
+            scope: "read".to_string(),
+        };
+        let config = OAuth2Config::from_auth(&auth).unwrap();
+        let cache = OAuth2TokenCache::default();
+
+        assert_eq!(access_token(&config, &cache, |_| {}).await.unwrap(), "t1");
+        // Expires within the margin, so the refresh token is used
+        assert_eq!(access_token(&config, &cache, |_| {}).await.unwrap(), "t2");
+
+        let bodies = server.await.unwrap();
+        assert_eq!(
+            bodies[0],
+            "grant_type=client_credentials&scope=read&client_id=cli&client_secret=s3cret"
+        );
+        assert!(bodies[1].starts_with("grant_type=refresh_token&refresh_token=r1&"));
+
+        // A token with time left is served from the cache
+        cache
+            .lock()
+            .unwrap()
+            .get_mut(&config.cache_key())
+            .unwrap()
+            .expires_at = Some(Utc::now() + chrono::Duration::hours(1));
+        assert_eq!(access_token(&config, &cache, |_| {}).await.unwrap(), "t2");
+    }
+
+    #[tokio::test]
+    async fn test_callback_checks_state_and_returns_code() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let browser = tokio::spawn(async move {
+            for target in ["/favicon.ico", "/callback?code=abc%2F1&state=xyz"] {
+                let mut stream = tokio::net::TcpStream::connect(address).await.unwrap();
+                stream
+                    .write_all(format!("GET {} HTTP/1.1\r\nHost: x\r\n\r\n", target).as_bytes())
+                    .await
+                    .unwrap();
+                let mut reply = String::new();
+                stream.read_to_string(&mut reply).await.unwrap();
+            }
+        });
+
+        assert_eq!(wait_for_callback(&listener, "xyz").await.unwrap(), "abc/1");
+        browser.await.unwrap();
+
+        assert!(parse_token_response(400, r#"{"error": "invalid_client"}"#).is_err());
+        assert_eq!(
+            pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+}
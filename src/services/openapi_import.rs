@@ -0,0 +1,620 @@
+use crate::models::http_client::{
+    ApiKeyLocation, HttpAuth, HttpFormField, HttpHeader, HttpMethod, HttpMultipartPart,
+    HttpQueryParam, HttpRequest, HttpRequestBody, RequestSettings,
+};
+use anyhow::{Context, Result, bail};
+use serde_json::{Map, Value};
+
+/// How deep `$ref`s and nested schemas are followed when building example bodies
+const MAX_SCHEMA_DEPTH: usize = 8;
+
+/// Methods an OpenAPI path item can define, in the order they are imported
+const OPERATION_METHODS: &[(&str, HttpMethod)] = &[
+    ("get", HttpMethod::GET),
+    ("post", HttpMethod::POST),
+    ("put", HttpMethod::PUT),
+    ("patch", HttpMethod::PATCH),
+    ("delete", HttpMethod::DELETE),
+    ("head", HttpMethod::HEAD),
+    ("options", HttpMethod::OPTIONS),
+];
+
+/// Requests generated from an OpenAPI spec, grouped by tag
+#[derive(Debug, Clone)]
+pub struct OpenApiImport {
+    pub title: String,
+    pub description: Option<String>,
+    /// (folder name, requests); untagged operations use an empty folder name
+    pub folders: Vec<(String, Vec<HttpRequest>)>,
+}
+
+impl OpenApiImport {
+    pub fn request_count(&self) -> usize {
+        self.folders
+            .iter()
+            .map(|(_, requests)| requests.len())
+            .sum()
+    }
+
+    pub fn folder_count(&self) -> usize {
+        self.folders
+            .iter()
+            .filter(|(name, _)| !name.is_empty())
+            .count()
+    }
+}
+
+/// Parse an OpenAPI 3.x (or Swagger 2.0) spec in JSON or YAML
+pub fn parse_openapi_spec(content: &str) -> Result<OpenApiImport> {
+    let spec: Value = match serde_json::from_str(content) {
+        Ok(value) => value,
+        Err(_) => serde_yaml::from_str(content).context("Spec is neither valid JSON nor YAML")?,
+    };
+
+    let is_swagger = spec.get("swagger").and_then(Value::as_str) == Some("2.0");
+    match spec.get("openapi").and_then(Value::as_str) {
+        Some(version) if version.starts_with('3') => {}
+        Some(version) => bail!("Unsupported OpenAPI version {}", version),
+        None if is_swagger => {}
+        None => bail!("Not an OpenAPI document (missing 'openapi' field)"),
+    }
+
+    let info = spec.get("info");
+    let title = info
+        .and_then(|i| i.get("title"))
+        .and_then(Value::as_str)
+        .unwrap_or("OpenAPI Import")
+        .to_string();
+    let description = info
+        .and_then(|i| i.get("description"))
+        .and_then(Value::as_str)
+        .map(str::to_string);
+
+    let base_url = if is_swagger {
+        swagger_base_url(&spec)
+    } else {
+        openapi_base_url(&spec)
+    };
+
+    let Some(paths) = spec.get("paths").and_then(Value::as_object) else {
+        bail!("Spec has no paths");
+    };
+
+    let mut folders: Vec<(String, Vec<HttpRequest>)> = Vec::new();
+    for (path, path_item) in paths {
+        let path_item = resolve(&spec, path_item);
+        let shared_params = path_item
+            .get("parameters")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+
+        for (key, method) in OPERATION_METHODS {
+            let Some(operation) = path_item.get(*key) else {
+                continue;
+            };
+            let request = convert_operation(
+                &spec,
+                &base_url,
+                path,
+                method.clone(),
+                operation,
+                &shared_params,
+                is_swagger,
+            );
+            let folder = operation
+                .get("tags")
+                .and_then(Value::as_array)
+                .and_then(|tags| tags.first())
+                .and_then(Value::as_str)
+                .unwrap_or("")
+                .to_string();
+
+            match folders.iter_mut().find(|(name, _)| *name == folder) {
+                Some((_, requests)) => requests.push(request),
+                None => folders.push((folder, vec![request])),
+            }
+        }
+    }
+
+    Ok(OpenApiImport {
+        title,
+        description,
+        folders,
+    })
+}
+
+/// First server URL with its variables replaced by their defaults
+fn openapi_base_url(spec: &Value) -> String {
+    let Some(server) = spec
+        .get("servers")
+        .and_then(Value::as_array)
+        .and_then(|servers| servers.first())
+    else {
+        return "{{baseUrl}}".to_string();
+    };
+
+    let mut url = server
+        .get("url")
+        .and_then(Value::as_str)
+        .unwrap_or("{{baseUrl}}")
+        .to_string();
+    if let Some(variables) = server.get("variables").and_then(Value::as_object) {
+        for (name, variable) in variables {
+            if let Some(default) = variable.get("default").and_then(Value::as_str) {
+                url = url.replace(&format!("{{{}}}", name), default);
+            }
+        }
+    }
+    // Relative server URLs need a host from the environment
+    if url.starts_with('/') {
+        url = format!("{{{{baseUrl}}}}{}", url);
+    }
+    url.trim_end_matches('/').to_string()
+}
+
+fn swagger_base_url(spec: &Value) -> String {
+    let Some(host) = spec.get("host").and_then(Value::as_str) else {
+        return "{{baseUrl}}".to_string();
+    };
+    let scheme = spec
+        .get("schemes")
+        .and_then(Value::as_array)
+        .and_then(|schemes| schemes.first())
+        .and_then(Value::as_str)
+        .unwrap_or("https");
+    let base_path = spec.get("basePath").and_then(Value::as_str).unwrap_or("");
+    format!("{}://{}{}", scheme, host, base_path.trim_end_matches('/'))
+}
+
+fn convert_operation(
+    spec: &Value,
+    base_url: &str,
+    path: &str,
+    method: HttpMethod,
+    operation: &Value,
+    shared_params: &[Value],
+    is_swagger: bool,
+) -> HttpRequest {
+    let name = operation
+        .get("summary")
+        .and_then(Value::as_str)
+        .or_else(|| operation.get("operationId").and_then(Value::as_str))
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("{} {}", method.as_str(), path));
+    let description = operation
+        .get("description")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+
+    // Operation-level parameters override path-level ones with the same name and location
+    let mut params: Vec<&Value> = Vec::new();
+    let operation_params = operation
+        .get("parameters")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    for param in shared_params.iter().chain(operation_params) {
+        let param = resolve(spec, param);
+        let key = (param.get("name"), param.get("in"));
+        params.retain(|existing| (existing.get("name"), existing.get("in")) != key);
+        params.push(param);
+    }
+
+    let mut url_path = path.to_string();
+    let mut query_params = Vec::new();
+    let mut headers = Vec::new();
+    let mut body = HttpRequestBody::None;
+
+    for param in params {
+        let name = param.get("name").and_then(Value::as_str).unwrap_or("");
+        let required = param
+            .get("required")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        match param.get("in").and_then(Value::as_str).unwrap_or("") {
+            "path" => {
+                let value =
+                    parameter_example(spec, param).unwrap_or_else(|| format!("{{{{{}}}}}", name));
+                url_path = url_path.replace(&format!("{{{}}}", name), &value);
+            }
+            "query" => query_params.push(HttpQueryParam {
+                name: name.to_string(),
+                value: parameter_example(spec, param).unwrap_or_default(),
+                enabled: required,
+            }),
+            "header" => headers.push(HttpHeader {
+                name: name.to_string(),
+                value: parameter_example(spec, param).unwrap_or_default(),
+                enabled: required,
+            }),
+            // Swagger 2.0 puts the request body in a parameter
+            "body" if is_swagger => {
+                if let Some(schema) = param.get("schema") {
+                    body = json_body(&example_from_schema(spec, schema, 0));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(request_body) = operation.get("requestBody") {
+        let request_body = resolve(spec, request_body);
+        if let Some(content) = request_body.get("content").and_then(Value::as_object) {
+            body = convert_request_body(spec, content, &mut headers);
+        }
+    }
+
+    HttpRequest {
+        name,
+        method,
+        url: format!("{}{}", base_url, url_path),
+        headers,
+        query_params,
+        body,
+        auth: convert_security(spec, operation),
+        description,
+        pagination: Default::default(),
+        pre_request_script: String::new(),
+        tests: String::new(),
+        extract: String::new(),
+        settings: RequestSettings::default(),
+    }
+}
+
+fn convert_request_body(
+    spec: &Value,
+    content: &Map<String, Value>,
+    headers: &mut Vec<HttpHeader>,
+) -> HttpRequestBody {
+    if let Some((_, media)) = content
+        .iter()
+        .find(|(media_type, _)| media_type.contains("json"))
+    {
+        return json_body(&media_example(spec, media));
+    }
+
+    if let Some(media) = content.get("application/x-www-form-urlencoded") {
+        let example = media_example(spec, media);
+        let fields = example
+            .as_object()
+            .map(|object| {
+                object
+                    .iter()
+                    .map(|(name, value)| HttpFormField::new(name, scalar_to_string(value)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        return HttpRequestBody::Form { fields };
+    }
+
+    if let Some(media) = content.get("multipart/form-data") {
+        let example = media_example(spec, media);
+        let properties = media
+            .get("schema")
+            .map(|schema| resolve(spec, schema))
+            .and_then(|schema| schema.get("properties"))
+            .and_then(Value::as_object);
+        // `format: binary` properties are uploads; their path is left for the user to fill in
+        let is_binary = |name: &str| {
+            properties
+                .and_then(|properties| properties.get(name))
+                .map(|property| resolve(spec, property))
+                .and_then(|property| property.get("format"))
+                .and_then(Value::as_str)
+                .is_some_and(|format| format == "binary")
+        };
+        let parts = example
+            .as_object()
+            .map(|object| {
+                object
+                    .iter()
+                    .map(|(name, value)| {
+                        if is_binary(name) {
+                            HttpMultipartPart::file(name, "")
+                        } else {
+                            HttpMultipartPart::new(name, scalar_to_string(value))
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        return HttpRequestBody::Multipart { parts };
+    }
+
+    match content.iter().next() {
+        Some((media_type, media)) => {
+            headers.push(HttpHeader::new("Content-Type", media_type));
+            let example = media_example(spec, media);
+            HttpRequestBody::Text {
+                content: scalar_to_string(&example),
+                content_type: media_type.clone(),
+            }
+        }
+        None => HttpRequestBody::None,
+    }
+}
+
+fn json_body(example: &Value) -> HttpRequestBody {
+    HttpRequestBody::Json {
+        content: serde_json::to_string_pretty(example).unwrap_or_default(),
+    }
+}
+
+/// Map the first security requirement onto the request's auth settings
+fn convert_security(spec: &Value, operation: &Value) -> HttpAuth {
+    let requirement = operation
+        .get("security")
+        .or_else(|| spec.get("security"))
+        .and_then(Value::as_array)
+        .and_then(|requirements| requirements.first())
+        .and_then(Value::as_object)
+        .and_then(|requirement| requirement.keys().next().cloned());
+    let Some(scheme_name) = requirement else {
+        return HttpAuth::None;
+    };
+
+    let scheme = spec
+        .pointer(&format!("/components/securitySchemes/{}", scheme_name))
+        .or_else(|| spec.pointer(&format!("/securityDefinitions/{}", scheme_name)));
+    let Some(scheme) = scheme else {
+        return HttpAuth::None;
+    };
+
+    let scheme_type = scheme.get("type").and_then(Value::as_str).unwrap_or("");
+    let http_scheme = scheme
+        .get("scheme")
+        .and_then(Value::as_str)
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    match (scheme_type, http_scheme.as_str()) {
+        ("http", "basic") | ("basic", _) => HttpAuth::Basic {
+            username: String::new(),
+            password: String::new(),
+        },
+        ("http", "bearer") | ("oauth2", _) | ("openIdConnect", _) => HttpAuth::Bearer {
+            token: String::new(),
+        },
+        ("apiKey", _) => HttpAuth::ApiKey {
+            key: scheme
+                .get("name")
+                .and_then(Value::as_str)
+                .unwrap_or("X-API-Key")
+                .to_string(),
+            value: String::new(),
+            location: if scheme.get("in").and_then(Value::as_str) == Some("query") {
+                ApiKeyLocation::QueryParam
+            } else {
+                ApiKeyLocation::Header
+            },
+        },
+        _ => HttpAuth::None,
+    }
+}
+
+/// Example value for a parameter, from `example`, `examples` or its schema
+fn parameter_example(spec: &Value, param: &Value) -> Option<String> {
+    let value = param
+        .get("example")
+        .cloned()
+        .or_else(|| first_named_example(spec, param))
+        .or_else(|| param.get("default").cloned())
+        .or_else(|| {
+            let schema = param.get("schema")?;
+            let schema = resolve(spec, schema);
+            schema
+                .get("example")
+                .or_else(|| schema.get("default"))
+                .or_else(|| schema.get("enum").and_then(|e| e.get(0)))
+                .cloned()
+        })?;
+    Some(scalar_to_string(&value))
+}
+
+/// Example body for a media type object
+fn media_example(spec: &Value, media: &Value) -> Value {
+    media
+        .get("example")
+        .cloned()
+        .or_else(|| first_named_example(spec, media))
+        .or_else(|| {
+            media
+                .get("schema")
+                .map(|schema| example_from_schema(spec, schema, 0))
+        })
+        .unwrap_or(Value::Null)
+}
+
+fn first_named_example(spec: &Value, object: &Value) -> Option<Value> {
+    let (_, example) = object.get("examples")?.as_object()?.iter().next()?;
+    resolve(spec, example).get("value").cloned()
+}
+
+/// Build a representative value from a JSON schema
+fn example_from_schema(spec: &Value, schema: &Value, depth: usize) -> Value {
+    if depth > MAX_SCHEMA_DEPTH {
+        return Value::Null;
+    }
+    let schema = resolve(spec, schema);
+
+    if let Some(example) = schema.get("example").or_else(|| schema.get("default")) {
+        return example.clone();
+    }
+    if let Some(first) = schema.get("enum").and_then(|e| e.get(0)) {
+        return first.clone();
+    }
+    if let Some(parts) = schema.get("allOf").and_then(Value::as_array) {
+        let mut merged = Map::new();
+        for part in parts {
+            if let Value::Object(object) = example_from_schema(spec, part, depth + 1) {
+                merged.extend(object);
+            }
+        }
+        return Value::Object(merged);
+    }
+    if let Some(first) = schema
+        .get("oneOf")
+        .or_else(|| schema.get("anyOf"))
+        .and_then(|options| options.get(0))
+    {
+        return example_from_schema(spec, first, depth + 1);
+    }
+
+    let schema_type = match schema.get("type") {
+        Some(Value::String(t)) => t.as_str(),
+        // OpenAPI 3.1 allows a list of types, e.g. ["string", "null"]
+        Some(Value::Array(types)) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|t| *t != "null")
+            .unwrap_or("null"),
+        _ if schema.get("properties").is_some() => "object",
+        _ => "",
+    };
+
+    match schema_type {
+        "object" => {
+            let mut object = Map::new();
+            if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+                for (name, property) in properties {
+                    object.insert(name.clone(), example_from_schema(spec, property, depth + 1));
+                }
+            }
+            Value::Object(object)
+        }
+        "array" => match schema.get("items") {
+            Some(items) => Value::Array(vec![example_from_schema(spec, items, depth + 1)]),
+            None => Value::Array(Vec::new()),
+        },
+        "string" => Value::String(
+            match schema.get("format").and_then(Value::as_str) {
+                Some("date") => "2024-01-01",
+                Some("date-time") => "2024-01-01T00:00:00Z",
+                Some("email") => "user@example.com",
+                Some("uuid") => "00000000-0000-0000-0000-000000000000",
+                Some("uri") | Some("url") => "https://example.com",
+                _ => "string",
+            }
+            .to_string(),
+        ),
+        "integer" => Value::from(0),
+        "number" => Value::from(0.0),
+        "boolean" => Value::Bool(true),
+        _ => Value::Null,
+    }
+}
+
+/// Follow a local `$ref` (e.g. `#/components/schemas/Pet`); other values are returned as-is
+fn resolve<'a>(spec: &'a Value, value: &'a Value) -> &'a Value {
+    let mut current = value;
+    for _ in 0..MAX_SCHEMA_DEPTH {
+        let Some(reference) = current.get("$ref").and_then(Value::as_str) else {
+            break;
+        };
+        match reference
+            .strip_prefix('#')
+            .and_then(|pointer| spec.pointer(pointer))
+        {
+            Some(target) => current = target,
+            None => break,
+        }
+    }
+    current
+}
+
+fn scalar_to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PETSTORE: &str = r#"
+openapi: 3.0.3
+info:
+  title: Petstore
+servers:
+  - url: https://{region}.example.com/v1
+    variables:
+      region:
+        default: eu
+paths:
+  /pets/{petId}:
+    parameters:
+      - name: petId
+        in: path
+        required: true
+        schema:
+          type: integer
+          example: 7
+    get:
+      tags: [pets]
+      summary: Get a pet
+      parameters:
+        - name: fields
+          in: query
+          schema:
+            type: string
+            default: name
+  /pets:
+    post:
+      tags: [pets]
+      operationId: createPet
+      security:
+        - token: []
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: '#/components/schemas/Pet'
+  /health:
+    get: {}
+components:
+  securitySchemes:
+    token:
+      type: http
+      scheme: bearer
+  schemas:
+    Pet:
+      type: object
+      properties:
+        name:
+          type: string
+        tags:
+          type: array
+          items:
+            type: string
+"#;
+
+    #[test]
+    fn test_parse_openapi_spec() {
+        let import = parse_openapi_spec(PETSTORE).unwrap();
+        assert_eq!(import.title, "Petstore");
+        assert_eq!(import.request_count(), 3);
+        assert_eq!(import.folder_count(), 1);
+
+        let pets = &import.folders.iter().find(|(n, _)| n == "pets").unwrap().1;
+        let get_pet = pets.iter().find(|r| r.name == "Get a pet").unwrap();
+        assert_eq!(get_pet.url, "https://eu.example.com/v1/pets/7");
+        assert_eq!(get_pet.query_params[0].value, "name");
+        assert!(!get_pet.query_params[0].enabled);
+
+        let create_pet = pets.iter().find(|r| r.name == "createPet").unwrap();
+        assert_eq!(create_pet.method, HttpMethod::POST);
+        assert!(matches!(create_pet.auth, HttpAuth::Bearer { .. }));
+        let HttpRequestBody::Json { content } = &create_pet.body else {
+            panic!("expected a JSON body");
+        };
+        let body: Value = serde_json::from_str(content).unwrap();
+        assert_eq!(body["tags"][0], "string");
+
+        let untagged = &import.folders.iter().find(|(n, _)| n.is_empty()).unwrap().1;
+        assert_eq!(untagged[0].name, "GET /health");
+    }
+}
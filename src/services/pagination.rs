@@ -0,0 +1,335 @@
+use crate::models::http_client::{HttpRequest, HttpResponse, PaginationRule, PaginationSettings};
+use crate::models::transfer::TransferStats;
+use anyhow::{Context, Result, bail};
+use serde_json::{Value, json};
+use std::future::Future;
+
+/// Items collected from every page of a list endpoint
+#[derive(Debug, Clone)]
+pub struct PaginatedResult {
+    pub pages: usize,
+    pub items: Vec<Value>,
+    pub last_status: u16,
+    pub total_ms: u64,
+    /// Whether the walk stopped at `max_pages` while more pages were available
+    pub truncated: bool,
+    /// Bytes sent and received over every page
+    pub transfer: TransferStats,
+}
+
+impl PaginatedResult {
+    /// Turn the aggregate into a response the viewer (and export) can show
+    pub fn into_response(self) -> HttpResponse {
+        let document = json!({
+            "pages": self.pages,
+            "item_count": self.items.len(),
+            "truncated": self.truncated,
+            "items": self.items,
+        });
+
+        HttpResponse {
+            status_code: self.last_status,
+            status_text: format!("{} page(s) aggregated", self.pages),
+            headers: Vec::new(),
+            body: serde_json::to_string_pretty(&document).unwrap_or_default(),
+            body_bytes: Vec::new(),
+            content_type: "application/json".to_string(),
+            duration_ms: self.total_ms,
+            served_from_cache: false,
+            connection: Default::default(),
+            transfer: self.transfer,
+            assertions: Vec::new(),
+            extracted: Vec::new(),
+            truncation: None,
+            timing: Default::default(),
+        }
+    }
+}
+
+/// Fetch pages of `request` following its pagination rule, up to `max_pages`.
+///
+/// `fetch` sends a single request; it is a parameter so the walk can be tested
+/// without a server. `progress` hears the page and item counts after every page.
+pub async fn walk_pages<F, Fut, P>(
+    request: HttpRequest,
+    mut fetch: F,
+    mut progress: P,
+) -> Result<PaginatedResult>
+where
+    F: FnMut(HttpRequest) -> Fut,
+    Fut: Future<Output = Result<HttpResponse>>,
+    P: FnMut(usize, usize),
+{
+    let settings: PaginationSettings = request.pagination.clone();
+    if settings.rule == PaginationRule::None {
+        bail!("No pagination rule configured (set one on the Settings tab)");
+    }
+
+    let mut result = PaginatedResult {
+        pages: 0,
+        items: Vec::new(),
+        last_status: 0,
+        total_ms: 0,
+        truncated: false,
+        transfer: TransferStats::default(),
+    };
+
+    let mut next_request = request;
+    let mut page_number = match &settings.rule {
+        PaginationRule::PageParam {
+            page_param,
+            start_page,
+        } => {
+            next_request.set_query_param(page_param, start_page.to_string());
+            *start_page
+        }
+        _ => 0,
+    };
+
+    loop {
+        let response = fetch(next_request.clone()).await?;
+        result.pages += 1;
+        result.last_status = response.status_code;
+        result.total_ms += response.duration_ms;
+        result
+            .transfer
+            .add_exchange(&response.transfer, response.body.len());
+
+        if !response.is_success() {
+            bail!(
+                "Page {} failed with HTTP {} {}",
+                result.pages,
+                response.status_code,
+                response.status_text
+            );
+        }
+
+        let body: Value = serde_json::from_str(&response.body)
+            .with_context(|| format!("Page {} is not valid JSON", result.pages))?;
+
+        let page_items = match value_at_path(&body, &settings.items_path) {
+            Some(Value::Array(items)) => items.clone(),
+            Some(Value::Null) | None => Vec::new(),
+            Some(other) => vec![other.clone()],
+        };
+        let page_was_empty = page_items.is_empty();
+        result.items.extend(page_items);
+        progress(result.pages, result.items.len());
+
+        // Work out where the next page lives, if anywhere
+        let has_next = match &settings.rule {
+            PaginationRule::None => false,
+            PaginationRule::LinkHeader => {
+                match response.header("link").and_then(next_link_from_header) {
+                    Some(url) => {
+                        // The link already carries the full query string
+                        next_request.url = url;
+                        next_request.query_params.clear();
+                        true
+                    }
+                    None => false,
+                }
+            }
+            PaginationRule::NextUrlField { next_path } => match value_at_path(&body, next_path) {
+                Some(Value::String(url)) if !url.is_empty() => {
+                    next_request.url = resolve_next_url(&next_request.url, url)?;
+                    next_request.query_params.clear();
+                    true
+                }
+                _ => false,
+            },
+            PaginationRule::CursorField {
+                cursor_path,
+                cursor_param,
+            } => match value_at_path(&body, cursor_path) {
+                Some(Value::String(cursor)) if !cursor.is_empty() => {
+                    next_request.set_query_param(cursor_param, cursor.clone());
+                    true
+                }
+                Some(Value::Number(cursor)) => {
+                    next_request.set_query_param(cursor_param, cursor.to_string());
+                    true
+                }
+                _ => false,
+            },
+            PaginationRule::PageParam { page_param, .. } => {
+                if page_was_empty {
+                    false
+                } else {
+                    page_number += 1;
+                    next_request.set_query_param(page_param, page_number.to_string());
+                    true
+                }
+            }
+        };
+
+        if !has_next {
+            break;
+        }
+        if result.pages >= settings.max_pages {
+            result.truncated = true;
+            break;
+        }
+    }
+
+    Ok(result)
+}
+
+/// Extract the `rel="next"` URL from an RFC 8288 `Link` header
+pub fn next_link_from_header(header: &str) -> Option<String> {
+    header.split(',').find_map(|link| {
+        let mut parts = link.split(';');
+        let url = parts.next()?.trim();
+        let is_next = parts.any(|param| {
+            let param = param.trim().replace(' ', "");
+            param == "rel=\"next\"" || param == "rel=next"
+        });
+
+        if is_next {
+            Some(
+                url.trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .to_string(),
+            )
+        } else {
+            None
+        }
+    })
+}
+
+/// Turn a next-page link into a full URL; relative ones (`/users?page=2`,
+/// `?page=2`) are taken relative to the page that returned them
+fn resolve_next_url(current: &str, next: &str) -> Result<String> {
+    if next.starts_with("http://") || next.starts_with("https://") {
+        return Ok(next.to_string());
+    }
+    let base = reqwest::Url::parse(current)
+        .with_context(|| format!("Cannot resolve next page '{}' against '{}'", next, current))?;
+    let url = base
+        .join(next)
+        .with_context(|| format!("Invalid next page URL '{}'", next))?;
+    Ok(url.to_string())
+}
+
+/// Follow a dotted path (`data.items`, `results.0.id`) into a JSON value
+fn value_at_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(value, |current, segment| match current {
+            Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+            _ => current.get(segment),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(body: Value) -> HttpResponse {
+        HttpResponse {
+            status_code: 200,
+            status_text: "OK".to_string(),
+            headers: Vec::new(),
+            body: body.to_string(),
+            body_bytes: Vec::new(),
+            content_type: "application/json".to_string(),
+            duration_ms: 1,
+            served_from_cache: false,
+            connection: Default::default(),
+            transfer: TransferStats::default(),
+            assertions: Vec::new(),
+            extracted: Vec::new(),
+            truncation: None,
+            timing: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_walk_pages_follows_cursor() {
+        let mut request = HttpRequest::new("Users").with_url("https://api.example.com/users");
+        request.pagination = PaginationSettings {
+            rule: PaginationRule::CursorField {
+                cursor_path: "meta.next".to_string(),
+                cursor_param: "after".to_string(),
+            },
+            items_path: "data".to_string(),
+            max_pages: 10,
+            auto_follow: false,
+        };
+
+        let result = walk_pages(
+            request,
+            |req| async move {
+                let after = req
+                    .query_params
+                    .iter()
+                    .find(|p| p.name == "after")
+                    .map(|p| p.value.clone());
+                Ok(match after.as_deref() {
+                    None => page(json!({"data": [1, 2], "meta": {"next": "c2"}})),
+                    Some("c2") => page(json!({"data": [3], "meta": {"next": null}})),
+                    other => panic!("unexpected cursor {:?}", other),
+                })
+            },
+            |_, _| {},
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.pages, 2);
+        assert_eq!(result.items, vec![json!(1), json!(2), json!(3)]);
+        assert!(!result.truncated);
+    }
+
+    #[tokio::test]
+    async fn test_walk_pages_follows_next_url_field() {
+        let mut request = HttpRequest::new("Users").with_url("https://api.example.com/users");
+        request.pagination = PaginationSettings {
+            rule: PaginationRule::NextUrlField {
+                next_path: "next_page_url".to_string(),
+            },
+            items_path: "data".to_string(),
+            max_pages: 10,
+            auto_follow: true,
+        };
+
+        let mut counts = Vec::new();
+        let result = walk_pages(
+            request,
+            |req| async move {
+                Ok(match req.url.as_str() {
+                    "https://api.example.com/users" => page(json!({
+                        "data": [1, 2],
+                        "next_page_url": "https://api.example.com/users?page=2"
+                    })),
+                    // Relative links resolve against the page they came from
+                    "https://api.example.com/users?page=2" => {
+                        page(json!({"data": [3], "next_page_url": "/users?page=3"}))
+                    }
+                    "https://api.example.com/users?page=3" => {
+                        page(json!({"data": [4], "next_page_url": null}))
+                    }
+                    other => panic!("unexpected page {}", other),
+                })
+            },
+            |pages, items| counts.push((pages, items)),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.pages, 3);
+        assert_eq!(result.items, vec![json!(1), json!(2), json!(3), json!(4)]);
+        assert_eq!(counts, vec![(1, 2), (2, 3), (3, 4)]);
+    }
+
+    #[test]
+    fn test_next_link_from_header() {
+        let header = "<https://api.example.com/users?page=1>; rel=\"prev\", \
+                      <https://api.example.com/users?page=3>; rel=\"next\"";
+        assert_eq!(
+            next_link_from_header(header).as_deref(),
+            Some("https://api.example.com/users?page=3")
+        );
+        assert_eq!(next_link_from_header("<https://x>; rel=\"last\""), None);
+    }
+}
@@ -0,0 +1,586 @@
+use crate::models::KeyValuePair;
+use crate::models::http_client::HttpAuth;
+use crate::services::http_collection_storage::{
+    HttpCollectionStorage, PostmanAuth, PostmanCollection, PostmanFolder, PostmanInfo, PostmanItem,
+    PostmanRequest, PostmanRequestDetails, PostmanVariable, convert_postman_auth,
+    sanitize_filename,
+};
+use anyhow::{Context, Result, bail};
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use std::cell::Cell;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::path::Path;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Give up on an import that is still running after this long
+pub const POSTMAN_IMPORT_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Files at least this big are not fully parsed for the import dialog preview
+pub const LARGE_IMPORT_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Minimum time between two progress reports
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Progress of a streaming Postman import
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PostmanImportProgress {
+    pub bytes_read: u64,
+    pub total_bytes: u64,
+    pub requests: usize,
+    pub folders: usize,
+}
+
+impl PostmanImportProgress {
+    /// Fraction of the file parsed so far (0.0 - 1.0)
+    pub fn ratio(&self) -> f64 {
+        if self.total_bytes == 0 {
+            0.0
+        } else {
+            (self.bytes_read as f64 / self.total_bytes as f64).min(1.0)
+        }
+    }
+}
+
+/// Import a Postman collection without loading the whole file into memory.
+///
+/// Requests are written to disk as soon as they are parsed and saved example
+/// responses are skipped, so memory stays flat even for org-wide dumps.
+/// `report` is called every [`PROGRESS_INTERVAL`] while parsing. Setting
+/// `cancel` or running past `timeout` stops the import; requests written up
+/// to that point are kept.
+pub fn import_postman_streaming(
+    storage: &HttpCollectionStorage,
+    file_path: &Path,
+    cancel: Arc<AtomicBool>,
+    timeout: Option<Duration>,
+    mut report: impl FnMut(PostmanImportProgress),
+) -> Result<PostmanImportProgress> {
+    let file = File::open(file_path).context("Failed to read Postman collection file")?;
+    let total_bytes = file.metadata().map(|m| m.len()).unwrap_or(0);
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+    let bytes_read = Rc::new(Cell::new(0));
+    let reader = ProgressReader {
+        inner: file,
+        bytes_read: Rc::clone(&bytes_read),
+        cancel: Arc::clone(&cancel),
+        deadline,
+    };
+
+    let mut sink = ImportSink {
+        storage,
+        progress: PostmanImportProgress {
+            total_bytes,
+            ..Default::default()
+        },
+        bytes_read,
+        report: &mut report,
+        last_report: Instant::now(),
+    };
+
+    let mut deserializer =
+        serde_json::Deserializer::from_reader(BufReader::with_capacity(64 * 1024, reader));
+    let result = CollectionSeed { sink: &mut sink }
+        .deserialize(&mut deserializer)
+        .and_then(|()| deserializer.end());
+
+    // The reader aborts with an I/O error; report the reason rather than a parse failure
+    if cancel.load(Ordering::Relaxed) {
+        bail!(
+            "Import cancelled after {} requests (already imported requests were kept)",
+            sink.progress.requests
+        );
+    }
+    if let Some(deadline) = deadline
+        && result.is_err()
+        && Instant::now() >= deadline
+    {
+        bail!(
+            "Import timed out after {}s ({} requests imported)",
+            timeout.unwrap_or_default().as_secs(),
+            sink.progress.requests
+        );
+    }
+    result.context("Failed to parse Postman collection")?;
+
+    sink.report_now();
+    Ok(sink.progress)
+}
+
+/// Save an already parsed collection, such as one converted from Insomnia or Bruno.
+///
+/// Goes through the same folder layout and request conversion as a streamed import.
+pub fn import_parsed_collection(
+    storage: &HttpCollectionStorage,
+    collection: &PostmanCollection,
+) -> Result<PostmanImportProgress> {
+    let mut report = |_| {};
+    let mut sink = ImportSink {
+        storage,
+        progress: PostmanImportProgress::default(),
+        bytes_read: Rc::new(Cell::new(0)),
+        report: &mut report,
+        last_report: Instant::now(),
+    };
+
+    let path = sanitize_filename(&collection.info.name);
+    sink.create_folder(&path)?;
+    sink.save_folder_settings(
+        &path,
+        collection.auth.as_ref(),
+        collection.variable.as_deref().unwrap_or_default(),
+    )?;
+    for item in &collection.item {
+        sink.save_item(item, &path)?;
+    }
+    Ok(sink.progress)
+}
+
+/// Read only the `info` block at the start of a Postman collection.
+///
+/// Used to preview huge files without parsing all of their items.
+pub fn read_postman_info(file_path: &Path) -> Result<PostmanInfo> {
+    let file = File::open(file_path).context("Failed to read Postman collection file")?;
+    let mut deserializer = serde_json::Deserializer::from_reader(BufReader::new(file));
+
+    let mut info = None;
+    let result = deserializer.deserialize_map(InfoVisitor { info: &mut info });
+
+    // Stopping after `info` leaves the object unfinished, which serde_json reports as an error
+    match info {
+        Some(info) => Ok(info),
+        None => {
+            result.context("Failed to parse Postman collection")?;
+            bail!("Postman collection has no info block")
+        }
+    }
+}
+
+/// Counts bytes read and aborts reading once cancelled or past the deadline
+struct ProgressReader<R> {
+    inner: R,
+    bytes_read: Rc<Cell<u64>>,
+    cancel: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl<R: Read> Read for ProgressReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.cancel.load(Ordering::Relaxed) {
+            return Err(io::Error::other("import cancelled"));
+        }
+        if let Some(deadline) = self.deadline
+            && Instant::now() >= deadline
+        {
+            return Err(io::Error::new(io::ErrorKind::TimedOut, "import timed out"));
+        }
+
+        let read = self.inner.read(buf)?;
+        self.bytes_read.set(self.bytes_read.get() + read as u64);
+        Ok(read)
+    }
+}
+
+/// Writes parsed items to storage and keeps track of progress
+struct ImportSink<'a> {
+    storage: &'a HttpCollectionStorage,
+    progress: PostmanImportProgress,
+    bytes_read: Rc<Cell<u64>>,
+    report: &'a mut dyn FnMut(PostmanImportProgress),
+    last_report: Instant,
+}
+
+impl ImportSink<'_> {
+    fn create_folder(&mut self, folder_path: &str) -> Result<()> {
+        let dir = self.storage.get_collections_directory().join(folder_path);
+        std::fs::create_dir_all(&dir).context("Failed to create collection folder")?;
+        Ok(())
+    }
+
+    /// Keep the auth and variables of a collection or folder for the requests inside it
+    fn save_folder_settings(
+        &mut self,
+        folder_path: &str,
+        auth: Option<&PostmanAuth>,
+        variables: &[PostmanVariable],
+    ) -> Result<()> {
+        let mut settings = self.storage.read_folder_settings(folder_path);
+        if let Some(auth) = auth.map(convert_postman_auth)
+            && auth != HttpAuth::None
+        {
+            settings.auth = Some(auth);
+        }
+        settings.variables.extend(
+            variables
+                .iter()
+                .map(|variable| KeyValuePair::new(&variable.key, &variable.value)),
+        );
+        if settings.is_empty() {
+            return Ok(());
+        }
+        self.storage.save_folder_settings(folder_path, &settings)
+    }
+
+    fn save_request(&mut self, folder_path: &str, request: &PostmanRequest) -> Result<()> {
+        let http_request = self.storage.convert_postman_request(request)?;
+        self.storage
+            .save_request(folder_path, &request.name, http_request)?;
+        self.progress.requests += 1;
+
+        if self.last_report.elapsed() >= PROGRESS_INTERVAL {
+            self.report_now();
+        }
+        Ok(())
+    }
+
+    /// Save an item that had to be buffered (its name came after its children)
+    fn save_item(&mut self, item: &PostmanItem, folder_path: &str) -> Result<()> {
+        match item {
+            PostmanItem::Request(request) => self.save_request(folder_path, request),
+            PostmanItem::Folder(folder) => {
+                let sub_path = format!("{}/{}", folder_path, sanitize_filename(&folder.name));
+                self.create_folder(&sub_path)?;
+                self.save_folder_settings(&sub_path, folder.auth.as_ref(), &[])?;
+                self.progress.folders += 1;
+                for sub_item in &folder.item {
+                    self.save_item(sub_item, &sub_path)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn report_now(&mut self) {
+        self.progress.bytes_read = self.bytes_read.get().min(self.progress.total_bytes);
+        (self.report)(self.progress);
+        self.last_report = Instant::now();
+    }
+}
+
+/// The top-level collection object
+struct CollectionSeed<'s, 'a> {
+    sink: &'s mut ImportSink<'a>,
+}
+
+impl<'de> DeserializeSeed<'de> for CollectionSeed<'_, '_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for CollectionSeed<'_, '_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a Postman collection object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let mut collection_path: Option<String> = None;
+        let mut pending: Vec<PostmanItem> = Vec::new();
+        let mut auth: Option<PostmanAuth> = None;
+        let mut variables: Vec<PostmanVariable> = Vec::new();
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "info" => {
+                    let info: PostmanInfo = map.next_value()?;
+                    let path = sanitize_filename(&info.name);
+                    self.sink.create_folder(&path).map_err(de::Error::custom)?;
+                    for item in pending.drain(..) {
+                        self.sink
+                            .save_item(&item, &path)
+                            .map_err(de::Error::custom)?;
+                    }
+                    collection_path = Some(path);
+                }
+                "item" => match &collection_path {
+                    Some(path) => map.next_value_seed(ItemsSeed {
+                        sink: &mut *self.sink,
+                        folder_path: path,
+                    })?,
+                    // Items before `info` are rare, buffer them until we know the folder name
+                    None => pending = map.next_value()?,
+                },
+                "auth" => auth = map.next_value()?,
+                "variable" => variables = map.next_value::<Option<_>>()?.unwrap_or_default(),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        let Some(path) = collection_path else {
+            return Err(de::Error::missing_field("info"));
+        };
+        self.sink
+            .save_folder_settings(&path, auth.as_ref(), &variables)
+            .map_err(de::Error::custom)
+    }
+}
+
+/// An `item` array, saved element by element
+struct ItemsSeed<'s, 'a> {
+    sink: &'s mut ImportSink<'a>,
+    folder_path: &'s str,
+}
+
+impl<'de> DeserializeSeed<'de> for ItemsSeed<'_, '_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ItemsSeed<'_, '_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an array of Postman items")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while seq
+            .next_element_seed(ItemSeed {
+                sink: &mut *self.sink,
+                folder_path: self.folder_path,
+            })?
+            .is_some()
+        {}
+        Ok(())
+    }
+}
+
+/// A single request or folder; folders stream their children straight to disk
+struct ItemSeed<'s, 'a> {
+    sink: &'s mut ImportSink<'a>,
+    folder_path: &'s str,
+}
+
+impl<'de> DeserializeSeed<'de> for ItemSeed<'_, '_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ItemSeed<'_, '_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a Postman request or folder")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let mut name: Option<String> = None;
+        let mut details: Option<PostmanRequestDetails> = None;
+        let mut streamed_folder = false;
+        let mut pending: Option<Vec<PostmanItem>> = None;
+        let mut auth: Option<PostmanAuth> = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "name" => name = Some(map.next_value()?),
+                "request" => details = Some(map.next_value()?),
+                "item" => match &name {
+                    Some(folder_name) => {
+                        let sub_path =
+                            format!("{}/{}", self.folder_path, sanitize_filename(folder_name));
+                        self.sink
+                            .create_folder(&sub_path)
+                            .map_err(de::Error::custom)?;
+                        self.sink.progress.folders += 1;
+                        map.next_value_seed(ItemsSeed {
+                            sink: &mut *self.sink,
+                            folder_path: &sub_path,
+                        })?;
+                        streamed_folder = true;
+                    }
+                    None => pending = Some(map.next_value()?),
+                },
+                // Folder auth can come after its items; it is saved once the folder is done
+                "auth" => auth = map.next_value()?,
+                // Example responses and scripts are not imported, skip them without allocating
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        let name = name.ok_or_else(|| de::Error::missing_field("name"))?;
+        if let Some(details) = details {
+            let request = PostmanRequest {
+                name,
+                request: details,
+                response: None,
+                event: None,
+            };
+            self.sink
+                .save_request(self.folder_path, &request)
+                .map_err(de::Error::custom)
+        } else if let Some(items) = pending {
+            let folder = PostmanItem::Folder(PostmanFolder {
+                name,
+                item: items,
+                description: None,
+                auth,
+                event: None,
+            });
+            self.sink
+                .save_item(&folder, self.folder_path)
+                .map_err(de::Error::custom)
+        } else if streamed_folder {
+            let sub_path = format!("{}/{}", self.folder_path, sanitize_filename(&name));
+            self.sink
+                .save_folder_settings(&sub_path, auth.as_ref(), &[])
+                .map_err(de::Error::custom)
+        } else {
+            Err(de::Error::custom(format!(
+                "item '{}' is neither a request nor a folder",
+                name
+            )))
+        }
+    }
+}
+
+/// Stops at the `info` block of a collection
+struct InfoVisitor<'a> {
+    info: &'a mut Option<PostmanInfo>,
+}
+
+impl<'de> Visitor<'de> for InfoVisitor<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a Postman collection object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "info" {
+                *self.info = Some(map.next_value()?);
+                return Ok(());
+            }
+            map.next_value::<IgnoredAny>()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::AppConfig;
+
+    const COLLECTION: &str = r#"{
+        "item": [{"name": "Early", "request": {"method": "GET", "url": "https://example.com/early"}}],
+        "info": {"_postman_id": "1", "name": "Big API", "schema": "v2.1"},
+        "variable": [{"key": "host", "value": "example.com"}]
+    }"#;
+
+    fn storage_in(dir: &Path) -> HttpCollectionStorage {
+        HttpCollectionStorage::new(AppConfig {
+            custom_collections_dir: Some(dir.to_path_buf()),
+            ..AppConfig::default()
+        })
+    }
+
+    #[test]
+    fn test_streaming_import_writes_nested_items() {
+        let temp = tempfile::tempdir().unwrap();
+        let file = temp.path().join("collection.json");
+        let json = r#"{
+            "info": {"_postman_id": "1", "name": "Big API", "schema": "v2.1"},
+            "item": [
+                {"name": "Users", "item": [
+                    {"name": "List", "request": {"method": "GET", "url": {"raw": "https://example.com/users"}},
+                     "response": [{"name": "Example", "body": "huge example body"}]},
+                    {"item": [{"name": "Get", "request": {"method": "GET", "url": "https://example.com/users/1"}}],
+                     "name": "Admin",
+                     "auth": {"type": "bearer", "bearer": [{"key": "token", "value": "{{adminToken}}"}]}}
+                ]},
+                {"name": "Health", "request": {"method": "HEAD", "url": "https://example.com/health"}}
+            ]
+        }"#;
+        std::fs::write(&file, json).unwrap();
+
+        let collections = temp.path().join("collections");
+        let storage = storage_in(&collections);
+        let mut reports = 0;
+        let progress = import_postman_streaming(
+            &storage,
+            &file,
+            Arc::new(AtomicBool::new(false)),
+            Some(POSTMAN_IMPORT_TIMEOUT),
+            |_| reports += 1,
+        )
+        .unwrap();
+
+        assert_eq!(progress.requests, 3);
+        assert_eq!(progress.folders, 2);
+        assert_eq!(progress.bytes_read, progress.total_bytes);
+        assert!(reports >= 1);
+        assert!(collections.join("Big API/Users/List.json").exists());
+        assert!(collections.join("Big API/Users/Admin/Get.json").exists());
+        assert!(collections.join("Big API/Health.json").exists());
+
+        // Folder auth is kept for the requests inside, not dropped
+        let inherited = storage.folder_inheritance("Big API/Users/Admin");
+        assert_eq!(
+            inherited.auth,
+            Some((
+                "Big API/Users/Admin".to_string(),
+                HttpAuth::Bearer {
+                    token: "{{adminToken}}".to_string()
+                }
+            ))
+        );
+        assert!(storage.folder_inheritance("Big API/Users").auth.is_none());
+
+        let info = read_postman_info(&file).unwrap();
+        assert_eq!(info.name, "Big API");
+    }
+
+    #[test]
+    fn test_streaming_import_buffers_items_before_info_and_cancels() {
+        let temp = tempfile::tempdir().unwrap();
+        let file = temp.path().join("collection.json");
+        std::fs::write(&file, COLLECTION).unwrap();
+        let collections = temp.path().join("collections");
+        let storage = storage_in(&collections);
+
+        let progress = import_postman_streaming(
+            &storage,
+            &file,
+            Arc::new(AtomicBool::new(false)),
+            None,
+            |_| {},
+        )
+        .unwrap();
+        assert_eq!(progress.requests, 1);
+        assert!(collections.join("Big API/Early.json").exists());
+        assert_eq!(
+            storage.read_folder_settings("Big API").variables,
+            [KeyValuePair::new("host", "example.com")]
+        );
+
+        let cancelled = import_postman_streaming(
+            &storage,
+            &file,
+            Arc::new(AtomicBool::new(true)),
+            None,
+            |_| {},
+        )
+        .unwrap_err();
+        assert!(cancelled.to_string().contains("cancelled"));
+    }
+}
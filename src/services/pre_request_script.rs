@@ -0,0 +1,591 @@
+use crate::models::KeyValuePair;
+use crate::models::http_client::{HttpAuth, HttpHeader, HttpRequest, HttpRequestBody};
+use crate::services::system_clipboard::base64_encode;
+use anyhow::{Result, anyhow, bail};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+/// A request ready to send, after its pre-request script and `{{variable}}` substitution
+#[derive(Debug, Clone)]
+pub struct PreparedRequest {
+    pub request: HttpRequest,
+    /// Variables the script set (environment variables are not included)
+    pub variables: BTreeMap<String, String>,
+}
+
+/// Run the request's pre-request script, then replace `{{name}}` placeholders with
+/// script variables and enabled environment variables (script variables win).
+///
+/// The script is one statement per line (`#` starts a comment):
+///
+/// ```text
+/// set ts = now()
+/// set sig = hmac_sha256(env("SECRET"), method() + "\n" + url() + "\n" + ts)
+/// header X-Timestamp = ts
+/// header X-Signature = sig
+/// query page = "1"
+/// remove header X-Debug
+/// remove query debug
+/// ```
+///
+/// Expressions join string literals, numbers, variables and function calls with `+`.
+/// Functions: `now()`, `now_ms()`, `iso_now()`, `uuid()`, `env(name)`, `method()`,
+/// `url()`, `body()`, `header(name)`, `upper(s)`, `lower(s)`, `base64(s)`, `sha256(s)`
+/// and `hmac_sha256(key, message)` (hex digests).
+pub fn prepare_request(
+    request: &HttpRequest,
+    environment: &[KeyValuePair],
+) -> Result<PreparedRequest> {
+    let mut context = ScriptContext {
+        request: request.clone(),
+        variables: BTreeMap::new(),
+        environment: environment
+            .iter()
+            .filter(|var| var.enabled && !var.name.is_empty())
+            .map(|var| (var.name.clone(), var.value.clone()))
+            .collect(),
+    };
+
+    for (index, line) in request.pre_request_script.lines().enumerate() {
+        context
+            .run_statement(line)
+            .map_err(|e| anyhow!("Pre-request script line {}: {}", index + 1, e))?;
+    }
+
+    let mut values = context.environment;
+    values.extend(context.variables.clone());
+    let mut prepared = context.request;
+    substitute_variables(&mut prepared, &values);
+
+    Ok(PreparedRequest {
+        request: prepared,
+        variables: context.variables,
+    })
+}
+
+/// Replace `{{name}}` in every text part of the request; unknown names are left as-is
+pub fn substitute_variables(request: &mut HttpRequest, values: &BTreeMap<String, String>) {
+    if values.is_empty() {
+        return;
+    }
+    for_each_text_mut(request, |text| {
+        if text.contains("{{") {
+            *text = substitute(text, values);
+        }
+    });
+}
+
+/// Call `apply` on every text part of the request: URL, headers, params, body, auth and tests
+pub fn for_each_text_mut(request: &mut HttpRequest, mut apply: impl FnMut(&mut String)) {
+    apply(&mut request.url);
+    for header in &mut request.headers {
+        apply(&mut header.name);
+        apply(&mut header.value);
+    }
+    for param in &mut request.query_params {
+        apply(&mut param.name);
+        apply(&mut param.value);
+    }
+    match &mut request.body {
+        HttpRequestBody::None => {}
+        HttpRequestBody::Text { content, .. }
+        | HttpRequestBody::Json { content }
+        | HttpRequestBody::Raw { content } => apply(content),
+        HttpRequestBody::Form { fields } => {
+            for field in fields {
+                apply(&mut field.name);
+                apply(&mut field.value);
+            }
+        }
+        HttpRequestBody::Multipart { parts } => {
+            for part in parts {
+                apply(&mut part.name);
+                apply(&mut part.value);
+            }
+        }
+        HttpRequestBody::File { path, content_type } => {
+            apply(path);
+            apply(content_type);
+        }
+    }
+    match &mut request.auth {
+        HttpAuth::None | HttpAuth::Script { .. } => {}
+        HttpAuth::Basic { username, password } => {
+            apply(username);
+            apply(password);
+        }
+        HttpAuth::Bearer { token } => apply(token),
+        HttpAuth::ApiKey { key, value, .. } => {
+            apply(key);
+            apply(value);
+        }
+        HttpAuth::OAuth2ClientCredentials { .. } | HttpAuth::OAuth2AuthorizationCode { .. } => {
+            for index in 0.. {
+                let Some(field) = request.auth.field_mut(index) else {
+                    break;
+                };
+                apply(field);
+            }
+        }
+    }
+    apply(&mut request.tests);
+}
+
+fn substitute(text: &str, values: &BTreeMap<String, String>) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find("{{") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        match after.find("}}") {
+            Some(end) => {
+                let name = after[..end].trim();
+                match values.get(name) {
+                    Some(value) => result.push_str(value),
+                    None => result.push_str(&rest[start..start + 2 + end + 2]),
+                }
+                rest = &after[end + 2..];
+            }
+            None => {
+                result.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+struct ScriptContext {
+    request: HttpRequest,
+    variables: BTreeMap<String, String>,
+    environment: BTreeMap<String, String>,
+}
+
+impl ScriptContext {
+    fn run_statement(&mut self, line: &str) -> Result<()> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(());
+        }
+
+        let (keyword, rest) = split_word(line);
+        match keyword {
+            "set" => {
+                let (name, value) = self.assignment(rest)?;
+                self.variables.insert(name, value);
+            }
+            "header" => {
+                let (name, value) = self.assignment(rest)?;
+                match self
+                    .request
+                    .headers
+                    .iter_mut()
+                    .find(|h| h.name.eq_ignore_ascii_case(&name))
+                {
+                    Some(header) => {
+                        header.value = value;
+                        header.enabled = true;
+                    }
+                    None => self.request.headers.push(HttpHeader::new(name, value)),
+                }
+            }
+            "query" => {
+                let (name, value) = self.assignment(rest)?;
+                self.request.set_query_param(&name, value);
+            }
+            "remove" => {
+                let (target, name) = split_word(rest);
+                let name = name.trim();
+                if name.is_empty() {
+                    bail!("expected a name after 'remove {}'", target);
+                }
+                match target {
+                    "header" => self
+                        .request
+                        .headers
+                        .retain(|h| !h.name.eq_ignore_ascii_case(name)),
+                    "query" => self.request.query_params.retain(|p| p.name != name),
+                    _ => bail!("expected 'remove header' or 'remove query'"),
+                }
+            }
+            other => bail!(
+                "unknown statement '{}' (use set, header, query or remove)",
+                other
+            ),
+        }
+        Ok(())
+    }
+
+    /// `name = expression`
+    fn assignment(&self, text: &str) -> Result<(String, String)> {
+        let (name, expression) = text
+            .split_once('=')
+            .ok_or_else(|| anyhow!("expected 'name = value'"))?;
+        let name = name.trim();
+        if name.is_empty() {
+            bail!("missing name before '='");
+        }
+        Ok((name.to_string(), self.evaluate(expression)?))
+    }
+
+    fn evaluate(&self, expression: &str) -> Result<String> {
+        let tokens = tokenize(expression)?;
+        let mut parser = Parser {
+            tokens: &tokens,
+            position: 0,
+            context: self,
+        };
+        let value = parser.expression()?;
+        if parser.position < tokens.len() {
+            bail!("unexpected {:?}", tokens[parser.position]);
+        }
+        Ok(value)
+    }
+
+    fn lookup(&self, name: &str) -> Result<String> {
+        self.variables
+            .get(name)
+            .or_else(|| self.environment.get(name))
+            .cloned()
+            .ok_or_else(|| anyhow!("unknown variable '{}'", name))
+    }
+
+    /// Text with the `{{variables}}` known so far filled in
+    fn resolve(&self, text: &str) -> String {
+        let mut values = self.environment.clone();
+        values.extend(self.variables.clone());
+        substitute(text, &values)
+    }
+
+    fn call(&self, function: &str, args: Vec<String>) -> Result<String> {
+        let expect = |count: usize| -> Result<()> {
+            if args.len() == count {
+                Ok(())
+            } else {
+                bail!("{}() takes {} argument(s)", function, count)
+            }
+        };
+
+        let value = match function {
+            "now" => {
+                expect(0)?;
+                chrono::Utc::now().timestamp().to_string()
+            }
+            "now_ms" => {
+                expect(0)?;
+                chrono::Utc::now().timestamp_millis().to_string()
+            }
+            "iso_now" => {
+                expect(0)?;
+                chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+            }
+            "uuid" => {
+                expect(0)?;
+                uuid::Uuid::new_v4().to_string()
+            }
+            "env" => {
+                expect(1)?;
+                self.environment
+                    .get(&args[0])
+                    .cloned()
+                    .ok_or_else(|| anyhow!("environment variable '{}' is not set", args[0]))?
+            }
+            "method" => {
+                expect(0)?;
+                self.request.method.as_str().to_string()
+            }
+            "url" => {
+                expect(0)?;
+                self.resolve(&self.request.url)
+            }
+            "body" => {
+                expect(0)?;
+                match &self.request.body {
+                    HttpRequestBody::None
+                    | HttpRequestBody::Form { .. }
+                    | HttpRequestBody::Multipart { .. }
+                    | HttpRequestBody::File { .. } => String::new(),
+                    HttpRequestBody::Text { content, .. }
+                    | HttpRequestBody::Json { content }
+                    | HttpRequestBody::Raw { content } => self.resolve(content),
+                }
+            }
+            "header" => {
+                expect(1)?;
+                self.request
+                    .headers
+                    .iter()
+                    .find(|h| h.enabled && h.name.eq_ignore_ascii_case(&args[0]))
+                    .map(|h| self.resolve(&h.value))
+                    .unwrap_or_default()
+            }
+            "upper" => {
+                expect(1)?;
+                args[0].to_uppercase()
+            }
+            "lower" => {
+                expect(1)?;
+                args[0].to_lowercase()
+            }
+            "base64" => {
+                expect(1)?;
+                base64_encode(args[0].as_bytes())
+            }
+            "sha256" => {
+                expect(1)?;
+                to_hex(&Sha256::digest(args[0].as_bytes()))
+            }
+            "hmac_sha256" => {
+                expect(2)?;
+                to_hex(&hmac_sha256(args[0].as_bytes(), args[1].as_bytes()))
+            }
+            other => bail!("unknown function '{}'", other),
+        };
+        Ok(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Text(String),
+    Name(String),
+    Plus,
+    Comma,
+    Open,
+    Close,
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            ' ' | '\t' => {}
+            '+' => tokens.push(Token::Plus),
+            ',' => tokens.push(Token::Comma),
+            '(' => tokens.push(Token::Open),
+            ')' => tokens.push(Token::Close),
+            '"' => {
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some('n') => text.push('\n'),
+                            Some('t') => text.push('\t'),
+                            Some(other) => text.push(other),
+                            None => bail!("unterminated string"),
+                        },
+                        Some(other) => text.push(other),
+                        None => bail!("unterminated string"),
+                    }
+                }
+                tokens.push(Token::Text(text));
+            }
+            c if c.is_ascii_digit() => {
+                let mut number = c.to_string();
+                while let Some(&next) = chars.peek() {
+                    if !(next.is_ascii_digit() || next == '.') {
+                        break;
+                    }
+                    number.push(next);
+                    chars.next();
+                }
+                tokens.push(Token::Text(number));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut name = c.to_string();
+                while let Some(&next) = chars.peek() {
+                    if !(next.is_alphanumeric() || next == '_') {
+                        break;
+                    }
+                    name.push(next);
+                    chars.next();
+                }
+                tokens.push(Token::Name(name));
+            }
+            other => bail!("unexpected character '{}'", other),
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    position: usize,
+    context: &'a ScriptContext,
+}
+
+impl Parser<'_> {
+    /// term (`+` term)*
+    fn expression(&mut self) -> Result<String> {
+        let mut value = self.term()?;
+        while self.tokens.get(self.position) == Some(&Token::Plus) {
+            self.position += 1;
+            value.push_str(&self.term()?);
+        }
+        Ok(value)
+    }
+
+    fn term(&mut self) -> Result<String> {
+        let token = self
+            .tokens
+            .get(self.position)
+            .cloned()
+            .ok_or_else(|| anyhow!("expected a value"))?;
+        self.position += 1;
+
+        match token {
+            Token::Text(text) => Ok(text),
+            Token::Name(name) if self.tokens.get(self.position) == Some(&Token::Open) => {
+                self.position += 1;
+                let mut args = Vec::new();
+                if self.tokens.get(self.position) == Some(&Token::Close) {
+                    self.position += 1;
+                } else {
+                    loop {
+                        args.push(self.expression()?);
+                        match self.tokens.get(self.position) {
+                            Some(Token::Comma) => self.position += 1,
+                            Some(Token::Close) => {
+                                self.position += 1;
+                                break;
+                            }
+                            _ => bail!("expected ',' or ')' in call to {}()", name),
+                        }
+                    }
+                }
+                self.context.call(&name, args)
+            }
+            Token::Name(name) => self.context.lookup(&name),
+            Token::Open => {
+                let value = self.expression()?;
+                if self.tokens.get(self.position) != Some(&Token::Close) {
+                    bail!("expected ')'");
+                }
+                self.position += 1;
+                Ok(value)
+            }
+            other => bail!("unexpected {:?}", other),
+        }
+    }
+}
+
+fn split_word(text: &str) -> (&str, &str) {
+    let text = text.trim_start();
+    match text.find(char::is_whitespace) {
+        Some(end) => (&text[..end], &text[end..]),
+        None => (text, ""),
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// HMAC-SHA256 (RFC 2104)
+fn hmac_sha256(key: &[u8], message: &[u8]) -> Vec<u8> {
+    const BLOCK_SIZE: usize = 64;
+
+    let mut block_key = if key.len() > BLOCK_SIZE {
+        Sha256::digest(key).to_vec()
+    } else {
+        key.to_vec()
+    };
+    block_key.resize(BLOCK_SIZE, 0);
+
+    let mut inner = Sha256::new();
+    inner.update(block_key.iter().map(|b| b ^ 0x36).collect::<Vec<u8>>());
+    inner.update(message);
+    let inner_hash = inner.finalize();
+
+    let mut outer = Sha256::new();
+    outer.update(block_key.iter().map(|b| b ^ 0x5c).collect::<Vec<u8>>());
+    outer.update(inner_hash);
+    outer.finalize().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::http_client::HttpMethod;
+
+    #[test]
+    fn test_prepare_request_runs_script_and_substitutes() {
+        let mut request = HttpRequest::new("Signed")
+            .with_url("https://{{host}}/orders")
+            .with_method(HttpMethod::POST);
+        request.headers.push(HttpHeader::new("X-Debug", "1"));
+        request.body = HttpRequestBody::Json {
+            content: "{\"trace\": \"{{trace_id}}\"}".to_string(),
+        };
+        request.pre_request_script = [
+            "# sign the request",
+            "set trace_id = \"t-\" + upper(\"abc\")",
+            "set sig = hmac_sha256(env(\"SECRET\"), method() + \" \" + url())",
+            "header X-Signature = sig",
+            "header X-Key = sha256(\"\")",
+            "query page = 2",
+            "remove header x-debug",
+        ]
+        .join("\n");
+
+        let environment = vec![
+            KeyValuePair::new("host", "api.example.com"),
+            KeyValuePair::new("SECRET", "key"),
+        ];
+        let prepared = prepare_request(&request, &environment).unwrap();
+        let sent = &prepared.request;
+
+        assert_eq!(sent.url, "https://api.example.com/orders");
+        assert_eq!(
+            sent.body,
+            HttpRequestBody::Json {
+                content: "{\"trace\": \"t-ABC\"}".to_string()
+            }
+        );
+        assert_eq!(prepared.variables["trace_id"], "t-ABC");
+        assert!(sent.headers.iter().all(|h| h.name != "X-Debug"));
+        assert_eq!(
+            sent.headers
+                .iter()
+                .find(|h| h.name == "X-Key")
+                .map(|h| h.value.as_str()),
+            Some("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+        );
+        // Request parts read by the script already have their variables filled in
+        assert_eq!(
+            sent.headers
+                .iter()
+                .find(|h| h.name == "X-Signature")
+                .map(|h| h.value.clone()),
+            Some(to_hex(&hmac_sha256(
+                b"key",
+                b"POST https://api.example.com/orders"
+            )))
+        );
+        assert_eq!(sent.query_params[0].value, "2");
+
+        // RFC 4231 test case 2
+        assert_eq!(
+            to_hex(&hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_script_errors_name_the_line() {
+        let mut request = HttpRequest::new("Broken");
+        request.pre_request_script = "set a = \"ok\"\nset b = missing_var".to_string();
+        let error = prepare_request(&request, &[]).unwrap_err().to_string();
+        assert!(error.contains("line 2"), "{}", error);
+        assert!(error.contains("missing_var"), "{}", error);
+
+        request.pre_request_script = "header X = nope(".to_string();
+        assert!(prepare_request(&request, &[]).is_err());
+    }
+}
@@ -0,0 +1,343 @@
+use crate::models::config::{cache_directory, is_read_only_storage};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Prefix of the temp directories the embedded Python project is extracted to
+pub const PROJECT_DIR_PREFIX: &str = "automation_project_";
+
+/// A child process (and its process group) started by one app instance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrackedProcess {
+    pub pid: u32,
+    /// PID of the app that started it; once that is gone the child is an orphan
+    pub owner_pid: u32,
+    /// Text found in the process's command line, so a reused PID is left alone
+    pub marker: String,
+    /// Temp directory to delete along with the process
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_dir: Option<PathBuf>,
+    pub started_at: DateTime<Local>,
+}
+
+/// What startup or shutdown cleanup got rid of
+#[derive(Debug, Default, PartialEq)]
+pub struct ProcessCleanup {
+    pub killed: Vec<u32>,
+    pub removed_dirs: usize,
+}
+
+impl ProcessCleanup {
+    pub fn is_empty(&self) -> bool {
+        self.killed.is_empty() && self.removed_dirs == 0
+    }
+}
+
+/// Runtime list of the Python and browser driver processes automation spawned
+#[derive(Debug, Clone)]
+pub struct ProcessRegistry {
+    path: PathBuf,
+    temp_dir: PathBuf,
+}
+
+impl Default for ProcessRegistry {
+    fn default() -> Self {
+        Self::new(
+            cache_directory().join("processes.json"),
+            std::env::temp_dir(),
+        )
+    }
+}
+
+impl ProcessRegistry {
+    pub fn new(path: PathBuf, temp_dir: PathBuf) -> Self {
+        Self { path, temp_dir }
+    }
+
+    /// Where this app instance extracts the Python project
+    pub fn project_dir(&self) -> PathBuf {
+        self.temp_dir
+            .join(format!("{}{}", PROJECT_DIR_PREFIX, std::process::id()))
+    }
+
+    /// Remember a child so it can be killed if this app dies before it does
+    pub fn register(&self, pid: u32, marker: &str, project_dir: Option<&Path>) -> Result<()> {
+        self.update(|entries| {
+            entries.retain(|entry| entry.pid != pid);
+            entries.push(TrackedProcess {
+                pid,
+                owner_pid: std::process::id(),
+                marker: marker.to_string(),
+                project_dir: project_dir.map(Path::to_path_buf),
+                started_at: Local::now(),
+            });
+        })
+    }
+
+    /// Forget a child that exited on its own
+    pub fn unregister(&self, pid: u32) -> Result<()> {
+        self.update(|entries| entries.retain(|entry| entry.pid != pid))
+    }
+
+    /// Kill children left behind by app instances that are no longer running
+    /// and delete project directories nobody owns anymore
+    pub fn reap_orphans(&self) -> ProcessCleanup {
+        let current = std::process::id();
+        self.release(|entry| entry.owner_pid != current && !is_alive(entry.owner_pid))
+    }
+
+    /// Kill the children this app instance still has running (used on exit)
+    pub fn release_own(&self) -> ProcessCleanup {
+        let current = std::process::id();
+        self.release(|entry| entry.owner_pid == current)
+    }
+
+    fn release(&self, should_release: impl Fn(&TrackedProcess) -> bool) -> ProcessCleanup {
+        let mut cleanup = ProcessCleanup::default();
+        let mut released = Vec::new();
+        let _ = self.update(|entries| {
+            let (matching, kept): (Vec<_>, Vec<_>) =
+                entries.drain(..).partition(|entry| should_release(entry));
+            *entries = kept;
+            released = matching;
+        });
+
+        for entry in &released {
+            if runs(entry.pid, &entry.marker) && terminate(entry.pid) {
+                cleanup.killed.push(entry.pid);
+            }
+            if let Some(dir) = &entry.project_dir
+                && std::fs::remove_dir_all(dir).is_ok()
+            {
+                cleanup.removed_dirs += 1;
+            }
+        }
+        cleanup.removed_dirs += self.remove_stale_project_dirs();
+        cleanup
+    }
+
+    /// Delete `automation_project_<pid>` directories whose app isn't running,
+    /// including ones a killed app never got to register
+    fn remove_stale_project_dirs(&self) -> usize {
+        let Ok(entries) = std::fs::read_dir(&self.temp_dir) else {
+            return 0;
+        };
+        let current = std::process::id();
+        entries
+            .flatten()
+            .filter(|entry| {
+                let name = entry.file_name();
+                let owner = name
+                    .to_str()
+                    .and_then(|name| name.strip_prefix(PROJECT_DIR_PREFIX))
+                    .and_then(|pid| pid.parse::<u32>().ok());
+                owner.is_some_and(|owner| owner != current && !is_alive(owner))
+            })
+            .filter(|entry| std::fs::remove_dir_all(entry.path()).is_ok())
+            .count()
+    }
+
+    fn load(&self) -> Vec<TrackedProcess> {
+        std::fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Read, change and write back the registry while holding its lock, so app
+    /// instances starting or exiting at the same time don't drop each other's entries
+    fn update(&self, change: impl FnOnce(&mut Vec<TrackedProcess>)) -> Result<()> {
+        if is_read_only_storage() {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create cache directory")?;
+        }
+        let lock = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(self.path.with_extension("json.lock"))
+            .context("Failed to open the process registry lock")?;
+        lock.lock().context("Failed to lock the process registry")?;
+
+        let mut entries = self.load();
+        let before = entries.clone();
+        change(&mut entries);
+        if entries == before {
+            return Ok(());
+        }
+        self.save(&entries)
+    }
+
+    /// Replace the registry file in one step; callers hold the lock
+    fn save(&self, entries: &[TrackedProcess]) -> Result<()> {
+        if is_read_only_storage() {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create cache directory")?;
+        }
+        let temp_path = self
+            .path
+            .with_extension(format!("json.{}.tmp", std::process::id()));
+        std::fs::write(&temp_path, serde_json::to_string_pretty(entries)?)
+            .context("Failed to write the process registry")?;
+        std::fs::rename(&temp_path, &self.path)
+            .context("Failed to replace the process registry")?;
+        Ok(())
+    }
+}
+
+/// Whether a process with this PID exists
+#[cfg(unix)]
+fn is_alive(pid: u32) -> bool {
+    process_command(pid).is_some()
+}
+
+/// Whether a process with this PID exists
+#[cfg(windows)]
+fn is_alive(pid: u32) -> bool {
+    let output = Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {}", pid), "/FO", "CSV", "/NH"])
+        .output();
+    output.is_ok_and(|output| {
+        String::from_utf8_lossy(&output.stdout).contains(&format!("\"{}\"", pid))
+    })
+}
+
+/// Whether `pid` is still the process we started, not an unrelated one that reused its
+/// PID: its command line has to name the marker (the project directory it runs from)
+fn runs(pid: u32, marker: &str) -> bool {
+    !marker.is_empty() && process_command(pid).is_some_and(|command| command.contains(marker))
+}
+
+/// Command line of a running process
+#[cfg(unix)]
+fn process_command(pid: u32) -> Option<String> {
+    let output = Command::new("ps")
+        .args(["-o", "command=", "-p", &pid.to_string()])
+        .output()
+        .ok()?;
+    let command = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !command.is_empty()).then_some(command)
+}
+
+/// Command line of a running process; `tasklist` only has image names, so ask CIM
+#[cfg(windows)]
+fn process_command(pid: u32) -> Option<String> {
+    let query = format!(
+        "(Get-CimInstance Win32_Process -Filter 'ProcessId={}').CommandLine",
+        pid
+    );
+    let output = Command::new("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", &query])
+        .output()
+        .ok()?;
+    let command = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !command.is_empty()).then_some(command)
+}
+
+/// Kill a process together with the browser drivers it started
+#[cfg(unix)]
+fn terminate(pid: u32) -> bool {
+    // Automation children lead their own process group, so this reaches the drivers too
+    Command::new("kill")
+        .args(["-TERM", "--", &format!("-{}", pid)])
+        .status()
+        .is_ok_and(|status| status.success())
+        || Command::new("kill")
+            .args(["-TERM", &pid.to_string()])
+            .status()
+            .is_ok_and(|status| status.success())
+}
+
+#[cfg(windows)]
+fn terminate(pid: u32) -> bool {
+    Command::new("taskkill")
+        .args(["/PID", &pid.to_string(), "/T", "/F"])
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reap_orphans_forgets_dead_owners_and_removes_their_dirs() {
+        let temp = tempfile::tempdir().unwrap();
+        let registry = ProcessRegistry::new(temp.path().join("processes.json"), temp.path().into());
+
+        // A PID that has certainly exited
+        let mut finished = Command::new("sh").arg("-c").arg("exit 0").spawn().unwrap();
+        let dead_pid = finished.id();
+        finished.wait().unwrap();
+
+        let orphan_dir = temp
+            .path()
+            .join(format!("{}{}", PROJECT_DIR_PREFIX, dead_pid));
+        std::fs::create_dir_all(orphan_dir.join("src")).unwrap();
+        let own_dir = registry.project_dir();
+        std::fs::create_dir_all(&own_dir).unwrap();
+
+        registry
+            .save(&[TrackedProcess {
+                pid: dead_pid,
+                owner_pid: dead_pid,
+                marker: orphan_dir.display().to_string(),
+                project_dir: Some(orphan_dir.clone()),
+                started_at: Local::now(),
+            }])
+            .unwrap();
+        registry
+            .register(std::process::id(), "tui_one", None)
+            .unwrap();
+
+        let cleanup = registry.reap_orphans();
+        assert_eq!(cleanup.killed, Vec::<u32>::new());
+        assert_eq!(cleanup.removed_dirs, 1);
+        assert!(!orphan_dir.exists());
+        // This instance's own entry and project directory are left alone
+        assert!(own_dir.exists());
+        let remaining: Vec<u32> = registry.load().iter().map(|entry| entry.pid).collect();
+        assert_eq!(remaining, vec![std::process::id()]);
+
+        registry.unregister(std::process::id()).unwrap();
+        assert!(registry.load().is_empty());
+    }
+
+    #[test]
+    fn test_concurrent_registrations_are_all_kept() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("processes.json");
+
+        // Each thread stands in for another app instance with its own registry handle
+        let threads: Vec<_> = (1..=16u32)
+            .map(|pid| {
+                let registry = ProcessRegistry::new(path.clone(), temp.path().into());
+                std::thread::spawn(move || registry.register(pid, "marker", None).unwrap())
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let registry = ProcessRegistry::new(path, temp.path().into());
+        let mut pids: Vec<u32> = registry.load().iter().map(|entry| entry.pid).collect();
+        pids.sort();
+        assert_eq!(pids, (1..=16).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_a_process_without_the_marker_is_not_ours() {
+        assert!(runs(std::process::id(), "tui_one"));
+        assert!(!runs(
+            std::process::id(),
+            "/tmp/automation-project-that-is-not-running"
+        ));
+        assert!(!runs(std::process::id(), ""));
+    }
+}
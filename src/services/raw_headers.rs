@@ -0,0 +1,133 @@
+use crate::models::http_client::HttpHeader;
+use anyhow::{Result, bail};
+
+/// Headers read from a pasted block, plus the lines that were left out on purpose
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ParsedHeaders {
+    pub headers: Vec<HttpHeader>,
+    /// Names of headers dropped because the client sets them itself
+    pub skipped: Vec<String>,
+}
+
+/// Headers the client computes from the request; a pasted copy would only go stale
+const COMPUTED_HEADERS: &[&str] = &["content-length", "transfer-encoding"];
+
+/// Write headers as `Name: Value` lines; disabled ones are commented out with `#`
+pub fn format_raw_headers(headers: &[HttpHeader]) -> String {
+    headers
+        .iter()
+        .map(|header| {
+            let prefix = if header.enabled { "" } else { "# " };
+            format!("{}{}: {}", prefix, header.name, header.value)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parse `Name: Value` lines, as copied from browser devtools or a raw HTTP request
+///
+/// Blank lines, the request line (`GET /path HTTP/1.1`) and HTTP/2 pseudo-headers
+/// (`:authority: ...`) are ignored. Indented lines continue the previous value.
+/// Lines starting with `#` become disabled headers, or are skipped as comments.
+pub fn parse_raw_headers(text: &str) -> Result<ParsedHeaders> {
+    let mut parsed = ParsedHeaders::default();
+    let mut last_kept = false;
+
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if line.starts_with([' ', '\t']) {
+            match parsed.headers.last_mut() {
+                Some(header) if last_kept => {
+                    header.value = format!("{} {}", header.value, line.trim());
+                }
+                Some(_) => {}
+                None => bail!("Line {}: continues a header that isn't there", index + 1),
+            }
+            continue;
+        }
+
+        let line = line.trim();
+        let (enabled, line) = match line.strip_prefix('#') {
+            Some(rest) => (false, rest.trim_start()),
+            None => (true, line),
+        };
+        if line.starts_with(':') || is_request_line(line) {
+            last_kept = false;
+            continue;
+        }
+
+        let header = line
+            .split_once(':')
+            .map(|(name, value)| (name.trim(), value))
+            .filter(|(name, _)| !name.is_empty() && !name.contains(char::is_whitespace));
+        let Some((name, value)) = header else {
+            // A commented-out line that isn't a header is just a comment
+            if !enabled {
+                last_kept = false;
+                continue;
+            }
+            bail!("Line {}: expected 'Name: Value'", index + 1);
+        };
+
+        if COMPUTED_HEADERS.contains(&name.to_ascii_lowercase().as_str()) {
+            parsed.skipped.push(name.to_string());
+            last_kept = false;
+            continue;
+        }
+        parsed
+            .headers
+            .push(HttpHeader::new(name, value.trim()).with_enabled(enabled));
+        last_kept = true;
+    }
+
+    Ok(parsed)
+}
+
+/// `GET /path HTTP/1.1` at the top of a raw request
+fn is_request_line(line: &str) -> bool {
+    let mut words = line.split_whitespace();
+    matches!(
+        (words.next(), words.next(), words.next(), words.next()),
+        (Some(_), Some(_), Some(version), None) if version.starts_with("HTTP/")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_devtools_header_block() {
+        let block = "GET /api/users HTTP/1.1\n\
+                     :authority: api.example.com\n\
+                     Accept: application/json\n\
+                     Authorization: Bearer abc:def\n\
+                     Content-Length: 42\n\
+                     \n\
+                     # Copied from devtools\n\
+                     # X-Debug: 1\n\
+                     X-Long: first\n\
+                     \tsecond";
+        let parsed = parse_raw_headers(block).unwrap();
+        assert_eq!(
+            parsed.headers,
+            [
+                HttpHeader::new("Accept", "application/json"),
+                HttpHeader::new("Authorization", "Bearer abc:def"),
+                HttpHeader::new("X-Debug", "1").with_enabled(false),
+                HttpHeader::new("X-Long", "first second"),
+            ]
+        );
+        assert_eq!(parsed.skipped, ["Content-Length"]);
+
+        // Formatting and parsing again gives the same headers
+        let reparsed = parse_raw_headers(&format_raw_headers(&parsed.headers)).unwrap();
+        assert_eq!(reparsed.headers, parsed.headers);
+
+        assert!(parse_raw_headers("Accept application/json").is_err());
+        assert!(parse_raw_headers("Bad Name: x").is_err());
+        assert!(parse_raw_headers("").unwrap().headers.is_empty());
+    }
+}
@@ -0,0 +1,114 @@
+use crate::models::config::{data_directory, is_read_only_storage};
+use crate::models::http_client::HttpRequest;
+use crate::services::bug_report::redact_request;
+use crate::services::snippet_generator::{SnippetFormat, generate_snippet};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Append-only log of every request sent, each as a curl command that replays it.
+///
+/// Kept apart from the app log so it can be grepped or shared on its own.
+#[derive(Debug, Clone)]
+pub struct RequestLog {
+    path: PathBuf,
+    /// Write credentials as-is instead of `<redacted>`
+    include_secrets: bool,
+}
+
+impl RequestLog {
+    pub fn new(path: PathBuf, include_secrets: bool) -> Self {
+        Self {
+            path,
+            include_secrets,
+        }
+    }
+
+    pub fn default_path() -> PathBuf {
+        data_directory().join("history").join("requests.log")
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Record one send: `request` is the resolved request (variables filled in,
+    /// auth applied) and `outcome` a one-line summary of what came back
+    pub fn append(
+        &self,
+        sent_at: DateTime<Local>,
+        request: &HttpRequest,
+        outcome: &str,
+    ) -> Result<()> {
+        if is_read_only_storage() {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create request log directory")?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .context("Failed to open request log")?;
+        file.write_all(format_entry(sent_at, request, outcome, self.include_secrets).as_bytes())
+            .context("Failed to write request log")?;
+        Ok(())
+    }
+}
+
+/// A header line, the curl command and a blank separator line
+fn format_entry(
+    sent_at: DateTime<Local>,
+    request: &HttpRequest,
+    outcome: &str,
+    include_secrets: bool,
+) -> String {
+    let request = if include_secrets {
+        request.clone()
+    } else {
+        redact_request(request)
+    };
+    format!(
+        "# {}  {}  -> {}\n{}\n\n",
+        sent_at.format("%Y-%m-%d %H:%M:%S%.3f %:z"),
+        request.name,
+        outcome,
+        generate_snippet(&request, SnippetFormat::Curl).trim_end()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::http_client::HttpMethod;
+
+    #[test]
+    fn test_request_log_appends_replayable_entries() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("requests.log");
+        let request = HttpRequest::new("Create user")
+            .with_method(HttpMethod::POST)
+            .with_url("https://api.example.com/users")
+            .with_header("Authorization", "Bearer t-123")
+            .with_header("Accept", "application/json");
+
+        RequestLog::new(path.clone(), false)
+            .append(Local::now(), &request, "201 Created (12 ms)")
+            .unwrap();
+        RequestLog::new(path.clone(), true)
+            .append(Local::now(), &request, "failed: connection refused")
+            .unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let entries: Vec<&str> = content.split("\n\n").filter(|e| !e.is_empty()).collect();
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].contains("Create user  -> 201 Created (12 ms)"));
+        assert!(entries[0].contains("curl -X POST"));
+        assert!(entries[0].contains("<redacted>"));
+        assert!(!entries[0].contains("t-123"));
+        assert!(entries[1].contains("Bearer t-123"));
+        assert!(entries[1].contains("Accept: application/json"));
+    }
+}
@@ -0,0 +1,156 @@
+use crate::models::config::{cache_directory, is_read_only_storage};
+use crate::models::http_client::{HttpMethod, HttpRequest, HttpResponse};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+
+/// A cached response together with its validators
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub response: HttpResponse,
+}
+
+impl CacheEntry {
+    /// Conditional request headers for revalidating this entry
+    pub fn conditional_headers(&self) -> Vec<(&'static str, String)> {
+        let mut headers = Vec::new();
+        if let Some(etag) = &self.etag {
+            headers.push(("If-None-Match", etag.clone()));
+        }
+        if let Some(last_modified) = &self.last_modified {
+            headers.push(("If-Modified-Since", last_modified.clone()));
+        }
+        headers
+    }
+}
+
+/// On-disk cache of responses that carry an ETag or Last-Modified header.
+///
+/// Only GET and HEAD requests are cached; each entry is a JSON file named after
+/// a hash of the method, URL and enabled query parameters.
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    directory: PathBuf,
+}
+
+impl Default for ResponseCache {
+    fn default() -> Self {
+        Self::new(cache_directory().join("responses"))
+    }
+}
+
+impl ResponseCache {
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    /// Cache key for a request, or None if the method is not cacheable
+    pub fn cache_key(request: &HttpRequest) -> Option<String> {
+        if !matches!(request.method, HttpMethod::GET | HttpMethod::HEAD) {
+            return None;
+        }
+
+        let mut params: Vec<(&str, &str)> = request
+            .query_params
+            .iter()
+            .filter(|p| p.enabled)
+            .map(|p| (p.name.as_str(), p.value.as_str()))
+            .collect();
+        params.sort();
+
+        let mut hasher = Sha256::new();
+        hasher.update(request.method.as_str());
+        hasher.update(b"\n");
+        hasher.update(request.url.trim());
+        for (name, value) in params {
+            hasher.update(b"\n");
+            hasher.update(name);
+            hasher.update(b"=");
+            hasher.update(value);
+        }
+
+        Some(format!("{:x}", hasher.finalize()))
+    }
+
+    /// Find a cached entry
+    pub fn lookup(&self, key: &str) -> Option<CacheEntry> {
+        let content = std::fs::read_to_string(self.entry_path(key)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Store a successful response if it has validators; returns whether it was cached
+    pub fn store(&self, key: &str, response: &HttpResponse) -> Result<bool> {
+        let etag = response.header("etag").map(str::to_string);
+        let last_modified = response.header("last-modified").map(str::to_string);
+
+        if (etag.is_none() && last_modified.is_none()) || is_read_only_storage() {
+            return Ok(false);
+        }
+
+        let entry = CacheEntry {
+            etag,
+            last_modified,
+            response: HttpResponse {
+                served_from_cache: false,
+                connection: Default::default(),
+                ..response.clone()
+            },
+        };
+
+        std::fs::create_dir_all(&self.directory)
+            .context("Failed to create response cache directory")?;
+        std::fs::write(self.entry_path(key), serde_json::to_string(&entry)?)
+            .context("Failed to write response cache entry")?;
+
+        Ok(true)
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.directory.join(format!("{}.json", key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::http_client::HttpHeader;
+    use crate::models::transfer::TransferStats;
+
+    #[test]
+    fn test_store_and_revalidate() {
+        let temp = tempfile::tempdir().unwrap();
+        let cache = ResponseCache::new(temp.path().to_path_buf());
+
+        let request = HttpRequest::new("Users").with_url("https://api.example.com/users");
+        let key = ResponseCache::cache_key(&request).unwrap();
+        assert!(ResponseCache::cache_key(&request.clone().with_method(HttpMethod::POST)).is_none());
+
+        let response = HttpResponse {
+            status_code: 200,
+            status_text: "OK".to_string(),
+            headers: vec![HttpHeader::new("ETag", "\"v1\"")],
+            body: "[]".to_string(),
+            body_bytes: Vec::new(),
+            content_type: "application/json".to_string(),
+            duration_ms: 5,
+            served_from_cache: false,
+            connection: Default::default(),
+            transfer: TransferStats::default(),
+            assertions: Vec::new(),
+            extracted: Vec::new(),
+            truncation: None,
+            timing: Default::default(),
+        };
+        assert!(cache.store(&key, &response).unwrap());
+
+        let entry = cache.lookup(&key).unwrap();
+        assert_eq!(entry.response.body, "[]");
+        assert_eq!(
+            entry.conditional_headers(),
+            vec![("If-None-Match", "\"v1\"".to_string())]
+        );
+    }
+}
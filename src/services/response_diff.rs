@@ -0,0 +1,223 @@
+/// Above this many line comparisons the changed middle is shown as removed + added
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// How a line differs between the old and new text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffKind {
+    Unchanged,
+    Added,
+    Removed,
+    /// Replaced in place; holds the old line
+    Changed(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    pub kind: DiffKind,
+    /// The new line (the old one for `Removed`)
+    pub text: String,
+}
+
+/// Line diff of two response bodies, with labels for what was compared
+#[derive(Debug, Clone)]
+pub struct ResponseDiff {
+    pub old_label: String,
+    pub new_label: String,
+    pub lines: Vec<DiffLine>,
+}
+
+impl ResponseDiff {
+    pub fn new(
+        old_label: impl Into<String>,
+        old_text: &str,
+        new_label: impl Into<String>,
+        new_text: &str,
+    ) -> Self {
+        Self {
+            old_label: old_label.into(),
+            new_label: new_label.into(),
+            lines: diff_lines(old_text, new_text),
+        }
+    }
+
+    /// Counts of (added, removed, changed) lines
+    pub fn summary(&self) -> (usize, usize, usize) {
+        self.lines
+            .iter()
+            .fold((0, 0, 0), |(added, removed, changed), line| {
+                match line.kind {
+                    DiffKind::Added => (added + 1, removed, changed),
+                    DiffKind::Removed => (added, removed + 1, changed),
+                    DiffKind::Changed(_) => (added, removed, changed + 1),
+                    DiffKind::Unchanged => (added, removed, changed),
+                }
+            })
+    }
+
+    pub fn is_identical(&self) -> bool {
+        self.summary() == (0, 0, 0)
+    }
+
+    /// Index of the first line of the next (or previous) block of differences
+    pub fn next_change(&self, from: usize, forward: bool) -> Option<usize> {
+        let is_change = |index: usize| self.lines[index].kind != DiffKind::Unchanged;
+        let starts_block = |index: usize| is_change(index) && (index == 0 || !is_change(index - 1));
+
+        if forward {
+            (from + 1..self.lines.len()).find(|&index| starts_block(index))
+        } else {
+            (0..from.min(self.lines.len()))
+                .rev()
+                .find(|&index| starts_block(index))
+        }
+    }
+}
+
+/// Diff two texts line by line (longest common subsequence), pairing
+/// removals followed by additions into changed lines
+pub fn diff_lines(old_text: &str, new_text: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old_text.lines().collect();
+    let new: Vec<&str> = new_text.lines().collect();
+
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    let mut raw = Vec::with_capacity(old.len().max(new.len()));
+    raw.extend(old[..prefix].iter().map(|line| unchanged(line)));
+    raw.extend(diff_middle(old_middle, new_middle));
+    raw.extend(old[old.len() - suffix..].iter().map(|line| unchanged(line)));
+
+    pair_changes(raw)
+}
+
+fn unchanged(line: &str) -> DiffLine {
+    DiffLine {
+        kind: DiffKind::Unchanged,
+        text: line.to_string(),
+    }
+}
+
+fn diff_middle(old: &[&str], new: &[&str]) -> Vec<DiffLine> {
+    let removed = |line: &&str| DiffLine {
+        kind: DiffKind::Removed,
+        text: line.to_string(),
+    };
+    let added = |line: &&str| DiffLine {
+        kind: DiffKind::Added,
+        text: line.to_string(),
+    };
+
+    if old.len().saturating_mul(new.len()) > MAX_DIFF_CELLS {
+        return old
+            .iter()
+            .map(removed)
+            .chain(new.iter().map(added))
+            .collect();
+    }
+
+    // lcs[i][j] = length of the common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0u32; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::new();
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            lines.push(unchanged(old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            lines.push(removed(&old[i]));
+            i += 1;
+        } else {
+            lines.push(added(&new[j]));
+            j += 1;
+        }
+    }
+    lines.extend(old[i..].iter().map(removed));
+    lines.extend(new[j..].iter().map(added));
+    lines
+}
+
+/// Turn each run of removals directly followed by additions into changed lines
+fn pair_changes(lines: Vec<DiffLine>) -> Vec<DiffLine> {
+    let mut result = Vec::with_capacity(lines.len());
+    let mut index = 0;
+
+    while index < lines.len() {
+        if lines[index].kind != DiffKind::Removed {
+            result.push(lines[index].clone());
+            index += 1;
+            continue;
+        }
+
+        let removed_end = (index..lines.len())
+            .find(|&i| lines[i].kind != DiffKind::Removed)
+            .unwrap_or(lines.len());
+        let added_end = (removed_end..lines.len())
+            .find(|&i| lines[i].kind != DiffKind::Added)
+            .unwrap_or(lines.len());
+
+        let removed = &lines[index..removed_end];
+        let added = &lines[removed_end..added_end];
+        let paired = removed.len().min(added.len());
+
+        for (old, new) in removed.iter().zip(added) {
+            result.push(DiffLine {
+                kind: DiffKind::Changed(old.text.clone()),
+                text: new.text.clone(),
+            });
+        }
+        result.extend_from_slice(&removed[paired..]);
+        result.extend_from_slice(&added[paired..]);
+        index = added_end;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_lines_marks_added_removed_and_changed() {
+        let old = "{\n  \"id\": 1,\n  \"name\": \"old\",\n  \"gone\": true\n}";
+        let new = "{\n  \"id\": 1,\n  \"name\": \"new\",\n  \"extra\": 2,\n  \"more\": 3\n}";
+
+        let diff = ResponseDiff::new("previous", old, "current", new);
+        let kinds: Vec<&DiffKind> = diff.lines.iter().map(|line| &line.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                &DiffKind::Unchanged,
+                &DiffKind::Unchanged,
+                &DiffKind::Changed("  \"name\": \"old\",".to_string()),
+                &DiffKind::Changed("  \"gone\": true".to_string()),
+                &DiffKind::Added,
+                &DiffKind::Unchanged,
+            ]
+        );
+        assert_eq!(diff.summary(), (1, 0, 2));
+        assert_eq!(diff.next_change(0, true), Some(2));
+        assert_eq!(diff.next_change(5, false), Some(2));
+
+        assert!(ResponseDiff::new("a", "same\ntext", "b", "same\ntext").is_identical());
+    }
+}
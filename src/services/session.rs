@@ -0,0 +1,90 @@
+use crate::models::config::{data_directory, is_read_only_storage};
+use crate::models::http::OpenRequestTab;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Request tabs open when the app last ran
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RequestSession {
+    pub tabs: Vec<OpenRequestTab>,
+    /// Index of the tab that was in the editor
+    #[serde(default)]
+    pub active: usize,
+}
+
+/// Where the open request tabs are kept between launches
+#[derive(Debug, Clone)]
+pub struct SessionStore {
+    path: PathBuf,
+}
+
+impl Default for SessionStore {
+    fn default() -> Self {
+        Self::new(data_directory().join("session.json"))
+    }
+}
+
+impl SessionStore {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Read the saved session; a missing or unreadable file starts a fresh one
+    pub fn load(&self) -> Option<RequestSession> {
+        let content = std::fs::read_to_string(&self.path).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Write the session, replacing the previous one atomically
+    pub fn save(&self, session: &RequestSession) -> Result<()> {
+        if is_read_only_storage() {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create data directory")?;
+        }
+
+        let temp_path = self.path.with_extension("json.tmp");
+        std::fs::write(&temp_path, serde_json::to_string_pretty(session)?)
+            .context("Failed to write session")?;
+        std::fs::rename(&temp_path, &self.path).context("Failed to replace session")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::http::HttpState;
+    use crate::models::http_client::HttpRequest;
+
+    #[test]
+    fn test_session_roundtrip() {
+        let temp = tempfile::tempdir().unwrap();
+        let store = SessionStore::new(temp.path().join("session.json"));
+        assert!(store.load().is_none());
+
+        let mut state = HttpState::new();
+        state.load_request("api/users", HttpRequest::new("Users"));
+        state.rename_active_tab("Users (prod)");
+        state.current_request.url = "https://api.example.com/users".to_string();
+        let session = RequestSession {
+            tabs: state.session_tabs(),
+            active: state.active_tab,
+        };
+        store.save(&session).unwrap();
+
+        let loaded = store.load().unwrap();
+        assert_eq!(loaded, session);
+
+        let mut restored = HttpState::new();
+        restored.restore_tabs(loaded.tabs, loaded.active);
+        assert_eq!(restored.tab_label(1), "Users (prod)");
+        assert_eq!(
+            restored.current_request.url,
+            "https://api.example.com/users"
+        );
+    }
+}
@@ -0,0 +1,572 @@
+use crate::models::ApiKeyLocation;
+use crate::models::http_client::{
+    HttpAuth, HttpMethod, HttpRequest, HttpRequestBody, RequestSettings,
+};
+use crate::services::multipart::file_content_type;
+use crate::services::tls::is_pkcs12;
+use reqwest::Url;
+
+/// Languages/tools a request can be exported to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnippetFormat {
+    Curl,
+    PythonRequests,
+    JavaScriptFetch,
+    RustReqwest,
+}
+
+impl SnippetFormat {
+    pub fn all() -> Vec<SnippetFormat> {
+        vec![
+            SnippetFormat::Curl,
+            SnippetFormat::PythonRequests,
+            SnippetFormat::JavaScriptFetch,
+            SnippetFormat::RustReqwest,
+        ]
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            SnippetFormat::Curl => "cURL",
+            SnippetFormat::PythonRequests => "Python (requests)",
+            SnippetFormat::JavaScriptFetch => "JavaScript (fetch)",
+            SnippetFormat::RustReqwest => "Rust (reqwest)",
+        }
+    }
+}
+
+/// Generate a snippet that sends `request` the same way the client would
+pub fn generate_snippet(request: &HttpRequest, format: SnippetFormat) -> String {
+    let parts = SnippetParts::from_request(request);
+    match format {
+        SnippetFormat::Curl => curl_snippet(&parts),
+        SnippetFormat::PythonRequests => python_snippet(&parts),
+        SnippetFormat::JavaScriptFetch => fetch_snippet(&parts),
+        SnippetFormat::RustReqwest => reqwest_snippet(&parts),
+    }
+}
+
+/// Body in the shape every generator understands
+enum SnippetBody {
+    None,
+    Text(String),
+    Form(Vec<(String, String)>),
+    /// Name, text value or file path, and whether it's a file
+    Multipart(Vec<(String, String, bool)>),
+    /// Path of a file sent as the raw body
+    File(String),
+}
+
+/// The effective request: URL with query string, headers including auth and content type
+struct SnippetParts {
+    method: HttpMethod,
+    url: String,
+    headers: Vec<(String, String)>,
+    basic_auth: Option<(String, String)>,
+    body: SnippetBody,
+    settings: RequestSettings,
+    /// Auth that can't be expressed statically (script auth)
+    note: Option<String>,
+}
+
+impl SnippetParts {
+    fn from_request(request: &HttpRequest) -> Self {
+        let mut query: Vec<(String, String)> = request
+            .query_params
+            .iter()
+            .filter(|p| p.enabled)
+            .map(|p| (p.name.clone(), p.value.clone()))
+            .collect();
+        let mut headers: Vec<(String, String)> = request
+            .headers
+            .iter()
+            .filter(|h| h.enabled)
+            .map(|h| (h.name.clone(), h.value.clone()))
+            .collect();
+        let has_header = |headers: &[(String, String)], name: &str| {
+            headers.iter().any(|(n, _)| n.eq_ignore_ascii_case(name))
+        };
+
+        let mut basic_auth = None;
+        let mut note = None;
+        match &request.auth {
+            HttpAuth::None => {}
+            HttpAuth::Basic { username, password } => {
+                basic_auth = Some((username.clone(), password.clone()));
+            }
+            HttpAuth::Bearer { token } => {
+                headers.push(("Authorization".to_string(), format!("Bearer {}", token)));
+            }
+            HttpAuth::ApiKey {
+                key,
+                value,
+                location,
+            } => match location {
+                ApiKeyLocation::Header => headers.push((key.clone(), value.clone())),
+                ApiKeyLocation::QueryParam => query.push((key.clone(), value.clone())),
+            },
+            HttpAuth::Script { script_path } => {
+                note = Some(format!(
+                    "Auth headers are computed by {} at send time; add them here",
+                    script_path
+                ));
+            }
+            HttpAuth::OAuth2ClientCredentials { token_url, .. }
+            | HttpAuth::OAuth2AuthorizationCode { token_url, .. } => {
+                headers.push((
+                    "Authorization".to_string(),
+                    "Bearer <access token>".to_string(),
+                ));
+                note = Some(format!(
+                    "Fetch an OAuth 2.0 access token from {} and fill it in",
+                    token_url
+                ));
+            }
+        }
+
+        let body = match &request.body {
+            HttpRequestBody::None => SnippetBody::None,
+            HttpRequestBody::Json { content } => {
+                if !has_header(&headers, "content-type") {
+                    headers.push(("Content-Type".to_string(), "application/json".to_string()));
+                }
+                SnippetBody::Text(content.clone())
+            }
+            HttpRequestBody::Text {
+                content,
+                content_type,
+            } => {
+                if !has_header(&headers, "content-type") {
+                    headers.push(("Content-Type".to_string(), content_type.clone()));
+                }
+                SnippetBody::Text(content.clone())
+            }
+            HttpRequestBody::Raw { content } => SnippetBody::Text(content.clone()),
+            HttpRequestBody::Form { fields } => SnippetBody::Form(
+                fields
+                    .iter()
+                    .filter(|f| f.enabled)
+                    .map(|f| (f.name.clone(), f.value.clone()))
+                    .collect(),
+            ),
+            HttpRequestBody::Multipart { parts } => SnippetBody::Multipart(
+                parts
+                    .iter()
+                    .filter(|p| p.enabled)
+                    .map(|p| (p.name.clone(), p.value.clone(), p.is_file))
+                    .collect(),
+            ),
+            HttpRequestBody::File { path, content_type } => {
+                if !has_header(&headers, "content-type") {
+                    headers.push((
+                        "Content-Type".to_string(),
+                        file_content_type(path, content_type),
+                    ));
+                }
+                SnippetBody::File(path.clone())
+            }
+        };
+
+        Self {
+            method: request.method.clone(),
+            url: url_with_query(&request.url, &query),
+            headers,
+            basic_auth,
+            body,
+            settings: request.settings.clone(),
+            note,
+        }
+    }
+}
+
+/// Append encoded query parameters to a URL
+fn url_with_query(url: &str, query: &[(String, String)]) -> String {
+    if query.is_empty() {
+        return url.to_string();
+    }
+    match Url::parse(url) {
+        Ok(mut parsed) => {
+            parsed.query_pairs_mut().extend_pairs(query);
+            parsed.to_string()
+        }
+        Err(_) => {
+            // Not a full URL yet (e.g. still contains variables); join by hand
+            let separator = if url.contains('?') { '&' } else { '?' };
+            let joined: Vec<String> = query
+                .iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect();
+            format!("{}{}{}", url, separator, joined.join("&"))
+        }
+    }
+}
+
+/// Quote a value for a POSIX shell
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// A double-quoted string literal valid in Python and JavaScript
+fn quoted(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+fn curl_snippet(parts: &SnippetParts) -> String {
+    let mut lines = Vec::new();
+    let mut first = String::from("curl");
+    match parts.method {
+        HttpMethod::GET => {}
+        HttpMethod::HEAD => first.push_str(" -I"),
+        ref method => first.push_str(&format!(" -X {}", method.as_str())),
+    }
+    first.push_str(&format!(" {}", shell_quote(&parts.url)));
+    lines.push(first);
+
+    // Transport settings, so the command behaves like the request does here
+    let settings = &parts.settings;
+    let mut flags = Vec::new();
+    if settings.follow_redirects {
+        flags.push(format!("-L --max-redirs {}", settings.max_redirects));
+    }
+    if settings.timeout_secs > 0 {
+        flags.push(format!("--max-time {}", settings.timeout_secs));
+    }
+    if !settings.verify_ssl {
+        flags.push("-k".to_string());
+    }
+    if !settings.user_agent.trim().is_empty() {
+        flags.push(format!("-A {}", shell_quote(&settings.user_agent)));
+    }
+    let tls = &settings.tls;
+    if !tls.ca_bundle.trim().is_empty() {
+        flags.push(format!("--cacert {}", shell_quote(&tls.ca_bundle)));
+    }
+    if !tls.client_cert.trim().is_empty() {
+        // curl takes a certificate password after a colon
+        let cert = if tls.client_cert_password.is_empty() {
+            tls.client_cert.clone()
+        } else {
+            format!("{}:{}", tls.client_cert, tls.client_cert_password)
+        };
+        flags.push(format!("--cert {}", shell_quote(&cert)));
+        if is_pkcs12(&tls.client_cert) {
+            flags.push("--cert-type P12".to_string());
+        }
+    }
+    if !tls.client_key.trim().is_empty() {
+        flags.push(format!("--key {}", shell_quote(&tls.client_key)));
+    }
+    let proxy = &settings.proxy;
+    if proxy.is_direct() {
+        flags.push("--noproxy '*'".to_string());
+    } else if !proxy.is_empty() {
+        flags.push(format!("-x {}", shell_quote(proxy.url.trim())));
+        if !proxy.username.is_empty() {
+            flags.push(format!(
+                "-U {}",
+                shell_quote(&format!("{}:{}", proxy.username, proxy.password))
+            ));
+        }
+        if !proxy.no_proxy.trim().is_empty() {
+            flags.push(format!("--noproxy {}", shell_quote(&proxy.no_proxy)));
+        }
+    }
+    if !flags.is_empty() {
+        lines.push(format!("  {}", flags.join(" ")));
+    }
+
+    for (name, value) in &parts.headers {
+        lines.push(format!(
+            "  -H {}",
+            shell_quote(&format!("{}: {}", name, value))
+        ));
+    }
+    if let Some((username, password)) = &parts.basic_auth {
+        lines.push(format!(
+            "  -u {}",
+            shell_quote(&format!("{}:{}", username, password))
+        ));
+    }
+    match &parts.body {
+        SnippetBody::None => {}
+        SnippetBody::Text(content) => lines.push(format!("  --data-raw {}", shell_quote(content))),
+        SnippetBody::Form(fields) => {
+            for (name, value) in fields {
+                lines.push(format!(
+                    "  --data-urlencode {}",
+                    shell_quote(&format!("{}={}", name, value))
+                ));
+            }
+        }
+        SnippetBody::Multipart(parts) => {
+            // --form-string keeps a leading @ or < in text values literal
+            for (name, value, is_file) in parts {
+                lines.push(if *is_file {
+                    format!("  -F {}", shell_quote(&format!("{}=@{}", name, value)))
+                } else {
+                    format!(
+                        "  --form-string {}",
+                        shell_quote(&format!("{}={}", name, value))
+                    )
+                });
+            }
+        }
+        SnippetBody::File(path) => lines.push(format!(
+            "  --data-binary {}",
+            shell_quote(&format!("@{}", path))
+        )),
+    }
+
+    let command = lines.join(" \\\n");
+    match &parts.note {
+        Some(note) => format!("# {}\n{}", note, command),
+        None => command,
+    }
+}
+
+fn python_snippet(parts: &SnippetParts) -> String {
+    let mut out = String::from("import requests\n\n");
+    if let Some(note) = &parts.note {
+        out.push_str(&format!("# {}\n", note));
+    }
+    out.push_str("response = requests.request(\n");
+    out.push_str(&format!("    {},\n", quoted(parts.method.as_str())));
+    out.push_str(&format!("    {},\n", quoted(&parts.url)));
+    if !parts.headers.is_empty() {
+        out.push_str("    headers={\n");
+        for (name, value) in &parts.headers {
+            out.push_str(&format!("        {}: {},\n", quoted(name), quoted(value)));
+        }
+        out.push_str("    },\n");
+    }
+    if let Some((username, password)) = &parts.basic_auth {
+        out.push_str(&format!(
+            "    auth=({}, {}),\n",
+            quoted(username),
+            quoted(password)
+        ));
+    }
+    match &parts.body {
+        SnippetBody::None => {}
+        SnippetBody::Text(content) => out.push_str(&format!("    data={},\n", quoted(content))),
+        SnippetBody::Form(fields) => {
+            out.push_str("    data={\n");
+            for (name, value) in fields {
+                out.push_str(&format!("        {}: {},\n", quoted(name), quoted(value)));
+            }
+            out.push_str("    },\n");
+        }
+        SnippetBody::Multipart(parts) => {
+            // requests only sends multipart when `files` is set
+            out.push_str("    files={\n");
+            for (name, value, is_file) in parts {
+                let value = if *is_file {
+                    format!("open({}, \"rb\")", quoted(value))
+                } else {
+                    format!("(None, {})", quoted(value))
+                };
+                out.push_str(&format!("        {}: {},\n", quoted(name), value));
+            }
+            out.push_str("    },\n");
+        }
+        SnippetBody::File(path) => {
+            out.push_str(&format!("    data=open({}, \"rb\"),\n", quoted(path)))
+        }
+    }
+    out.push_str(")\n\nprint(response.status_code)\nprint(response.text)\n");
+    out
+}
+
+fn fetch_snippet(parts: &SnippetParts) -> String {
+    let mut out = String::new();
+    if let Some(note) = &parts.note {
+        out.push_str(&format!("// {}\n", note));
+    }
+    if let SnippetBody::Multipart(form_parts) = &parts.body {
+        // File parts are read with Node's fs; a browser would take them from an <input>
+        if form_parts.iter().any(|(_, _, is_file)| *is_file) {
+            out.push_str("import { openAsBlob } from \"node:fs\";\n\n");
+        }
+        out.push_str("const form = new FormData();\n");
+        for (name, value, is_file) in form_parts {
+            if *is_file {
+                let file_name = std::path::Path::new(value)
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                out.push_str(&format!(
+                    "form.append({}, await openAsBlob({}), {});\n",
+                    quoted(name),
+                    quoted(value),
+                    quoted(&file_name)
+                ));
+            } else {
+                out.push_str(&format!(
+                    "form.append({}, {});\n",
+                    quoted(name),
+                    quoted(value)
+                ));
+            }
+        }
+        out.push('\n');
+    }
+    if let SnippetBody::File(_) = &parts.body {
+        out.push_str("import { openAsBlob } from \"node:fs\";\n\n");
+    }
+    out.push_str(&format!(
+        "const response = await fetch({}, {{\n",
+        quoted(&parts.url)
+    ));
+    out.push_str(&format!("  method: {},\n", quoted(parts.method.as_str())));
+
+    let has_headers = !parts.headers.is_empty() || parts.basic_auth.is_some();
+    if has_headers {
+        out.push_str("  headers: {\n");
+        for (name, value) in &parts.headers {
+            out.push_str(&format!("    {}: {},\n", quoted(name), quoted(value)));
+        }
+        if let Some((username, password)) = &parts.basic_auth {
+            out.push_str(&format!(
+                "    \"Authorization\": \"Basic \" + btoa({}),\n",
+                quoted(&format!("{}:{}", username, password))
+            ));
+        }
+        out.push_str("  },\n");
+    }
+    match &parts.body {
+        SnippetBody::None => {}
+        SnippetBody::Text(content) => out.push_str(&format!("  body: {},\n", quoted(content))),
+        SnippetBody::Form(fields) => {
+            out.push_str("  body: new URLSearchParams({\n");
+            for (name, value) in fields {
+                out.push_str(&format!("    {}: {},\n", quoted(name), quoted(value)));
+            }
+            out.push_str("  }),\n");
+        }
+        SnippetBody::Multipart(_) => out.push_str("  body: form,\n"),
+        SnippetBody::File(path) => {
+            out.push_str(&format!("  body: await openAsBlob({}),\n", quoted(path)))
+        }
+    }
+    out.push_str("});\n\nconsole.log(response.status, await response.text());\n");
+    out
+}
+
+fn reqwest_snippet(parts: &SnippetParts) -> String {
+    let mut out = String::new();
+    if let Some(note) = &parts.note {
+        out.push_str(&format!("// {}\n", note));
+    }
+    out.push_str("let client = reqwest::Client::new();\n");
+    out.push_str("let response = client\n");
+    out.push_str(&format!(
+        "    .request(reqwest::Method::{}, {:?})\n",
+        parts.method.as_str(),
+        parts.url
+    ));
+    for (name, value) in &parts.headers {
+        out.push_str(&format!("    .header({:?}, {:?})\n", name, value));
+    }
+    if let Some((username, password)) = &parts.basic_auth {
+        out.push_str(&format!(
+            "    .basic_auth({:?}, Some({:?}))\n",
+            username, password
+        ));
+    }
+    match &parts.body {
+        SnippetBody::None => {}
+        SnippetBody::Text(content) => out.push_str(&format!("    .body({:?})\n", content)),
+        SnippetBody::Form(fields) => {
+            let pairs: Vec<String> = fields
+                .iter()
+                .map(|(name, value)| format!("({:?}, {:?})", name, value))
+                .collect();
+            out.push_str(&format!("    .form(&[{}])\n", pairs.join(", ")));
+        }
+        SnippetBody::Multipart(parts) => {
+            out.push_str("    .multipart(\n        reqwest::multipart::Form::new()\n");
+            for (name, value, is_file) in parts {
+                if *is_file {
+                    out.push_str(&format!(
+                        "            .file({:?}, {:?})\n            .await?\n",
+                        name, value
+                    ));
+                } else {
+                    out.push_str(&format!("            .text({:?}, {:?})\n", name, value));
+                }
+            }
+            out.push_str("    )\n");
+        }
+        SnippetBody::File(path) => out.push_str(&format!(
+            "    .body(tokio::fs::File::open({:?}).await?)\n",
+            path
+        )),
+    }
+    out.push_str("    .send()\n    .await?;\n\n");
+    out.push_str("println!(\"{} {}\", response.status(), response.text().await?);\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::http_client::{HttpMultipartPart, HttpQueryParam};
+
+    #[test]
+    fn test_curl_snippet_round_trips() {
+        let mut request = HttpRequest::new("Create")
+            .with_method(HttpMethod::POST)
+            .with_url("https://api.example.com/items")
+            .with_header("X-Trace", "it's me");
+        request.query_params = vec![HttpQueryParam::new("dry run", "true")];
+        request.body = HttpRequestBody::Json {
+            content: "{\"name\": \"Ada\"}".to_string(),
+        };
+        request.auth = HttpAuth::Bearer {
+            token: "abc".to_string(),
+        };
+
+        request.settings.timeout_secs = 5;
+        request.settings.verify_ssl = false;
+        request.settings.max_redirects = 3;
+        request.settings.tls.ca_bundle = "/etc/ssl/corp ca.pem".to_string();
+        request.settings.tls.client_cert = "me.p12".to_string();
+        request.settings.tls.client_cert_password = "s3cret".to_string();
+        request.settings.proxy.url = "socks5h://jump:1080".to_string();
+        request.settings.proxy.username = "me".to_string();
+        request.settings.proxy.password = "p:w".to_string();
+        request.settings.proxy.no_proxy = "localhost,.internal".to_string();
+
+        let snippet = generate_snippet(&request, SnippetFormat::Curl);
+        let parsed = crate::services::curl_import::parse_curl_command(&snippet).unwrap();
+        assert_eq!(parsed.settings, request.settings);
+
+        assert_eq!(parsed.method, HttpMethod::POST);
+        assert_eq!(parsed.url, "https://api.example.com/items");
+        assert_eq!(parsed.query_params[0].name, "dry run");
+        assert_eq!(parsed.headers[0].value, "it's me");
+        assert_eq!(parsed.headers[1].value, "Bearer abc");
+        assert_eq!(parsed.body, request.body);
+
+        let python = generate_snippet(&request, SnippetFormat::PythonRequests);
+        assert!(python.contains("\"Authorization\": \"Bearer abc\""));
+        let rust = generate_snippet(&request, SnippetFormat::RustReqwest);
+        assert!(rust.contains(
+            ".request(reqwest::Method::POST, \"https://api.example.com/items?dry+run=true\")"
+        ));
+
+        let mut upload = HttpRequest::new("Upload").with_url("https://api.example.com/files");
+        upload.body = HttpRequestBody::Multipart {
+            parts: vec![
+                HttpMultipartPart::new("note", "@not a file"),
+                HttpMultipartPart::file("doc", "/tmp/q3 report.pdf"),
+            ],
+        };
+        let snippet = generate_snippet(&upload, SnippetFormat::Curl);
+        let parsed = crate::services::curl_import::parse_curl_command(&snippet).unwrap();
+        assert_eq!(parsed.method, HttpMethod::POST);
+        assert_eq!(parsed.body, upload.body);
+        let python = generate_snippet(&upload, SnippetFormat::PythonRequests);
+        assert!(python.contains("\"doc\": open(\"/tmp/q3 report.pdf\", \"rb\")"));
+    }
+}
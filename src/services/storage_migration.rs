@@ -0,0 +1,227 @@
+use crate::models::AppConfig;
+use crate::models::config::{CURRENT_LAYOUT_VERSION, data_directory, legacy_data_directory};
+use anyhow::{Context, Result, bail};
+use std::path::{Path, PathBuf};
+
+/// Expand a user-entered directory path (supports a leading `~`)
+pub fn expand_user_path(input: &str) -> PathBuf {
+    let trimmed = input.trim();
+
+    if let Some(rest) = trimmed.strip_prefix("~")
+        && let Some(home_dir) = dirs::home_dir()
+    {
+        return home_dir.join(rest.trim_start_matches(['/', '\\']));
+    }
+
+    PathBuf::from(trimmed)
+}
+
+/// Make sure a directory exists and that we can write to it
+pub fn validate_writable_directory(dir: &Path) -> Result<()> {
+    if dir.as_os_str().is_empty() {
+        bail!("Directory cannot be empty");
+    }
+
+    if dir.exists() && !dir.is_dir() {
+        bail!("{} is not a directory", dir.display());
+    }
+
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("Cannot create directory {}", dir.display()))?;
+
+    // Probe write access with a throwaway file
+    let probe = dir.join(".write-test");
+    std::fs::write(&probe, b"ok")
+        .with_context(|| format!("Directory {} is not writable", dir.display()))?;
+    let _ = std::fs::remove_file(&probe);
+
+    Ok(())
+}
+
+/// Move everything inside `from` into `to`, returning the number of top-level entries moved.
+///
+/// Entries that already exist in the destination are left untouched (and stay in
+/// the source) so nothing is ever overwritten.
+pub fn migrate_directory(from: &Path, to: &Path) -> Result<usize> {
+    if !from.exists() {
+        return Ok(0);
+    }
+
+    if to.starts_with(from) {
+        bail!(
+            "Cannot move {} into its own subdirectory {}",
+            from.display(),
+            to.display()
+        );
+    }
+
+    std::fs::create_dir_all(to).context("Failed to create destination directory")?;
+
+    let mut moved = 0;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let source = entry.path();
+        let target = to.join(entry.file_name());
+
+        if target.exists() {
+            continue;
+        }
+
+        move_entry(&source, &target, |from, to| std::fs::rename(from, to))?;
+        moved += 1;
+    }
+
+    Ok(moved)
+}
+
+/// Move one file or directory, copying it when `rename` fails (it does across
+/// filesystems). A copy that fails halfway is removed again, so the entry stays
+/// whole in the source and a later attempt moves it instead of skipping it.
+fn move_entry(
+    source: &Path,
+    target: &Path,
+    rename: impl Fn(&Path, &Path) -> std::io::Result<()>,
+) -> Result<()> {
+    if rename(source, target).is_ok() {
+        return Ok(());
+    }
+
+    if let Err(e) = copy_recursive(source, target) {
+        let _ = if target.is_dir() {
+            std::fs::remove_dir_all(target)
+        } else {
+            std::fs::remove_file(target)
+        };
+        return Err(e);
+    }
+
+    if source.is_dir() {
+        std::fs::remove_dir_all(source)?;
+    } else {
+        std::fs::remove_file(source)?;
+    }
+    Ok(())
+}
+
+/// One-time move from the legacy layout (templates and collections stored next to
+/// the config file) to the platform data directory.
+///
+/// Returns a summary of what was moved, if anything. Custom directories chosen by
+/// the user are never touched.
+pub fn migrate_legacy_layout(config: &mut AppConfig) -> Result<Option<String>> {
+    if config.layout_version >= CURRENT_LAYOUT_VERSION {
+        return Ok(None);
+    }
+
+    let legacy_dir = legacy_data_directory();
+    let legacy_templates = legacy_dir.join("templates");
+    let new_templates = data_directory().join("templates");
+    let mut summary = None;
+
+    if config.custom_templates_dir.is_none()
+        && config.templates_directory == legacy_templates
+        && new_templates != legacy_templates
+    {
+        let legacy_collections = config.get_collections_directory();
+
+        let mut moved = migrate_directory(&legacy_templates, &new_templates)?;
+        config.templates_directory = new_templates;
+
+        if config.custom_collections_dir.is_none() {
+            moved += migrate_directory(&legacy_collections, &config.get_collections_directory())?;
+            let _ = std::fs::remove_dir(&legacy_collections);
+        }
+
+        // Only removes the old directory if everything was moved out of it
+        let _ = std::fs::remove_dir(&legacy_templates);
+
+        summary = Some(format!(
+            "Migrated {} item(s) from {} to {}",
+            moved,
+            legacy_dir.display(),
+            data_directory().display()
+        ));
+    }
+
+    config.layout_version = CURRENT_LAYOUT_VERSION;
+    config.save()?;
+
+    Ok(summary)
+}
+
+/// Recursively copy a file or directory
+fn copy_recursive(source: &Path, target: &Path) -> Result<()> {
+    if source.is_dir() {
+        std::fs::create_dir_all(target)?;
+        for entry in std::fs::read_dir(source)? {
+            let entry = entry?;
+            copy_recursive(&entry.path(), &target.join(entry.file_name()))?;
+        }
+    } else {
+        std::fs::copy(source, target)
+            .with_context(|| format!("Failed to copy {}", source.display()))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate_directory_moves_without_overwriting() {
+        let temp = tempfile::tempdir().unwrap();
+        let from = temp.path().join("old");
+        let to = temp.path().join("new");
+
+        std::fs::create_dir_all(from.join("Customer/Add")).unwrap();
+        std::fs::write(from.join("Customer/Add/Email.json"), "{}").unwrap();
+        std::fs::write(from.join("Keep.json"), "old").unwrap();
+        std::fs::create_dir_all(&to).unwrap();
+        std::fs::write(to.join("Keep.json"), "new").unwrap();
+
+        let moved = migrate_directory(&from, &to).unwrap();
+
+        assert_eq!(moved, 1);
+        assert!(to.join("Customer/Add/Email.json").exists());
+        assert_eq!(
+            std::fs::read_to_string(to.join("Keep.json")).unwrap(),
+            "new"
+        );
+        assert!(from.join("Keep.json").exists());
+    }
+
+    #[test]
+    fn test_migrate_directory_rejects_nested_target() {
+        let temp = tempfile::tempdir().unwrap();
+        let from = temp.path().join("old");
+        std::fs::create_dir_all(&from).unwrap();
+
+        assert!(migrate_directory(&from, &from.join("inner")).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_failed_copy_leaves_the_entry_whole_in_the_source() {
+        let temp = tempfile::tempdir().unwrap();
+        let source = temp.path().join("old/Customer");
+        let target = temp.path().join("new/Customer");
+        std::fs::create_dir_all(&source).unwrap();
+        std::fs::create_dir_all(target.parent().unwrap()).unwrap();
+        std::fs::write(source.join("Add.json"), "{}").unwrap();
+        // Copying follows the link and fails partway through the folder
+        std::os::unix::fs::symlink(temp.path().join("missing"), source.join("z-link")).unwrap();
+        let cross_device = |_: &Path, _: &Path| Err(std::io::ErrorKind::CrossesDevices.into());
+
+        assert!(move_entry(&source, &target, cross_device).is_err());
+        assert!(!target.exists());
+        assert!(source.join("Add.json").exists());
+
+        // Once the copy can succeed the whole folder moves
+        std::fs::remove_file(source.join("z-link")).unwrap();
+        move_entry(&source, &target, cross_device).unwrap();
+        assert!(target.join("Add.json").exists());
+        assert!(!source.exists());
+    }
+}
@@ -0,0 +1,94 @@
+use anyhow::{Result, bail};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Clipboard helpers tried in order; the first one that runs successfully wins
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[
+    ("pbcopy", &[]),
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+    ("clip.exe", &[]),
+];
+
+/// Copy text to the OS clipboard, returning the mechanism that was used.
+///
+/// Falls back to an OSC 52 escape sequence, which most modern terminals (and
+/// tmux with `set-clipboard on`) forward to the local clipboard, even over SSH.
+pub fn copy_to_clipboard(text: &str) -> Result<&'static str> {
+    for (program, args) in CLIPBOARD_COMMANDS {
+        if pipe_to_command(program, args, text).is_ok() {
+            return Ok(program);
+        }
+    }
+
+    let mut stdout = std::io::stdout();
+    if write!(stdout, "\x1b]52;c;{}\x07", base64_encode(text.as_bytes())).is_ok()
+        && stdout.flush().is_ok()
+    {
+        return Ok("terminal (OSC 52)");
+    }
+
+    bail!("No clipboard tool found (install wl-copy, xclip or xsel)")
+}
+
+fn pipe_to_command(program: &str, args: &[&str], text: &str) -> Result<()> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+
+    if !child.wait()?.success() {
+        bail!("{} exited with an error", program);
+    }
+    Ok(())
+}
+
+/// Standard base64 (with padding), as required by OSC 52
+pub fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+
+        encoded.push(ALPHABET[(n >> 18) as usize & 63] as char);
+        encoded.push(ALPHABET[(n >> 12) as usize & 63] as char);
+        encoded.push(if chunk.len() > 1 {
+            ALPHABET[(n >> 6) as usize & 63] as char
+        } else {
+            '='
+        });
+        encoded.push(if chunk.len() > 2 {
+            ALPHABET[n as usize & 63] as char
+        } else {
+            '='
+        });
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_encode() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"user:pass"), "dXNlcjpwYXNz");
+    }
+}
@@ -0,0 +1,114 @@
+use crate::models::{AutomationTemplate, WebsiteConfig};
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Marks a file as a template bundle, so other JSON files are rejected early
+const BUNDLE_FORMAT: &str = "tui_one-template-bundle";
+
+/// Newest bundle version this build can read
+const BUNDLE_VERSION: u32 = 1;
+
+/// File extension used when exporting bundles
+pub const BUNDLE_EXTENSION: &str = "tuibundle";
+
+/// Everything a team needs to run a shared template
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundlePayload {
+    pub template: AutomationTemplate,
+    pub website: WebsiteConfig,
+    /// Entry script of the automation project the template was made for
+    pub script: String,
+    pub exported_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// On-disk shape of a bundle; the payload stays raw JSON so the checksum is
+/// computed over exactly what was received
+#[derive(Debug, Serialize, Deserialize)]
+struct BundleFile {
+    format: String,
+    version: u32,
+    checksum: String,
+    payload: serde_json::Value,
+}
+
+/// Serialize a template, its website config and script name into a single
+/// checksummed file
+pub fn export_bundle(
+    template: &AutomationTemplate,
+    website: &WebsiteConfig,
+    script: &str,
+) -> Result<String> {
+    let payload = serde_json::to_value(BundlePayload {
+        template: template.clone(),
+        website: website.clone(),
+        script: script.to_string(),
+        exported_at: chrono::Utc::now(),
+    })?;
+    let file = BundleFile {
+        format: BUNDLE_FORMAT.to_string(),
+        version: BUNDLE_VERSION,
+        checksum: payload_checksum(&payload)?,
+        payload,
+    };
+    Ok(serde_json::to_string_pretty(&file)? + "\n")
+}
+
+/// Parse a bundle, refusing it if it was edited or damaged after export
+pub fn import_bundle(content: &str) -> Result<BundlePayload> {
+    let file: BundleFile =
+        serde_json::from_str(content).context("Not a template bundle (invalid JSON)")?;
+    if file.format != BUNDLE_FORMAT {
+        bail!("Not a template bundle (format '{}')", file.format);
+    }
+    if file.version > BUNDLE_VERSION {
+        bail!(
+            "Bundle version {} is newer than this app supports ({})",
+            file.version,
+            BUNDLE_VERSION
+        );
+    }
+    if payload_checksum(&file.payload)? != file.checksum {
+        bail!("Checksum mismatch: the bundle was modified or corrupted after export");
+    }
+    serde_json::from_value(file.payload).context("Bundle payload is incomplete")
+}
+
+/// `sha256:<hex>` of the payload's compact JSON; object keys are sorted, so the
+/// digest doesn't depend on how the file was pretty-printed
+fn payload_checksum(payload: &serde_json::Value) -> Result<String> {
+    let digest = Sha256::digest(serde_json::to_string(payload)?.as_bytes());
+    let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+    Ok(format!("sha256:{}", hex))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundle_round_trip_and_tamper_detection() {
+        let template = AutomationTemplate::new("Daily Task", "Standard submission")
+            .with_field("Title", "Daily standup")
+            .with_field("Priority", "High");
+        let website = WebsiteConfig::default();
+
+        let exported = export_bundle(&template, &website, "automation_script.py").unwrap();
+        let payload = import_bundle(&exported).unwrap();
+        assert_eq!(payload.template, template);
+        assert_eq!(payload.website, website);
+        assert_eq!(payload.script, "automation_script.py");
+
+        // Re-indenting the file keeps it valid
+        let value: serde_json::Value = serde_json::from_str(&exported).unwrap();
+        assert!(import_bundle(&serde_json::to_string(&value).unwrap()).is_ok());
+
+        let tampered = exported.replace("Daily standup", "Drop tables");
+        let error = import_bundle(&tampered).unwrap_err().to_string();
+        assert!(error.contains("Checksum mismatch"), "{}", error);
+
+        let future = exported.replace("\"version\": 1", "\"version\": 2");
+        assert!(import_bundle(&future).is_err());
+        assert!(import_bundle("{\"name\": \"collection\"}").is_err());
+    }
+}
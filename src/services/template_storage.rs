@@ -1,7 +1,10 @@
-use crate::models::{AppConfig, AutomationTemplate, FormField};
+use crate::models::storage_format::{
+    find_item_file, is_item_file, item_file_path, read_folder_readme, read_item_file,
+    write_item_file,
+};
+use crate::models::{AppConfig, AutomationTemplate};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 /// A template file with metadata
@@ -43,13 +46,14 @@ impl StoredTemplate {
     /// Mark template as used (updates last_used_at)
     pub fn mark_as_used(&mut self) {
         self.last_used_at = Some(chrono::Utc::now());
-        self.modified_at = chrono::Utc::now();
     }
 
-    /// Update the template data
+    /// Update the template data, bumping `modified_at` only when it actually changed
     pub fn update_template(&mut self, new_template: AutomationTemplate) {
-        self.template = new_template;
-        self.modified_at = chrono::Utc::now();
+        if self.template != new_template {
+            self.template = new_template;
+            self.modified_at = chrono::Utc::now();
+        }
     }
 }
 
@@ -66,6 +70,9 @@ impl TemplateStorage {
 
     /// Initialize the storage (create directories, demo templates, etc.)
     pub fn initialize(&self) -> Result<()> {
+        if crate::models::config::is_read_only_storage() {
+            return Ok(());
+        }
         crate::models::config::ensure_templates_directory(&self.config)?;
 
         // Create some demo templates if none exist
@@ -81,8 +88,6 @@ impl TemplateStorage {
         template_name: &str,
         template: AutomationTemplate,
     ) -> Result<PathBuf> {
-        let stored_template = StoredTemplate::new(template);
-
         // Build the full path
         let templates_dir = self.config.get_templates_directory();
         let folder_dir = templates_dir.join(folder_path);
@@ -90,47 +95,50 @@ impl TemplateStorage {
         // Create the folder if it doesn't exist
         std::fs::create_dir_all(&folder_dir).context("Failed to create template folder")?;
 
-        // Create the filename (sanitize the name)
-        let filename = sanitize_filename(template_name) + ".json";
-        let file_path = folder_dir.join(filename);
+        // Overwrite the existing file in its format, new templates use the configured one
+        let file_path = item_file_path(
+            &folder_dir,
+            &sanitize_filename(template_name),
+            self.config.storage_format,
+        );
 
-        // Serialize and save
-        let json_content = serde_json::to_string_pretty(&stored_template)
-            .context("Failed to serialize template")?;
+        // Saving over an existing template keeps its metadata, so unchanged saves are no-ops
+        let stored_template = match read_item_file::<StoredTemplate>(&file_path) {
+            Ok(mut existing) => {
+                existing.update_template(template);
+                existing
+            }
+            Err(_) => StoredTemplate::new(template),
+        };
 
-        std::fs::write(&file_path, json_content).context("Failed to write template file")?;
+        write_item_file(&file_path, &stored_template).context("Failed to write template file")?;
 
         Ok(file_path)
     }
 
-    /// Load a specific template from disk
-    pub fn load_template(&self, folder_path: &str, template_name: &str) -> Result<StoredTemplate> {
-        let templates_dir = self.config.get_templates_directory();
-        let filename = sanitize_filename(template_name) + ".json";
-        let file_path = templates_dir.join(folder_path).join(filename);
-
-        let json_content =
-            std::fs::read_to_string(&file_path).context("Failed to read template file")?;
-
-        let mut stored_template: StoredTemplate =
-            serde_json::from_str(&json_content).context("Failed to parse template file")?;
-
-        // Mark as used
-        stored_template.mark_as_used();
+    /// Path of a stored template, whichever format it was saved in
+    fn template_file(&self, folder_path: &str, template_name: &str) -> Result<PathBuf> {
+        let folder_dir = self.config.get_templates_directory().join(folder_path);
+        find_item_file(&folder_dir, &sanitize_filename(template_name))
+            .with_context(|| format!("Template '{}' not found", template_name))
+    }
 
-        // Save the updated usage info
-        let json_content = serde_json::to_string_pretty(&stored_template)
-            .context("Failed to serialize updated template")?;
-        std::fs::write(&file_path, json_content).context("Failed to update template file")?;
+    /// Read a template without touching its usage info (for search and previews)
+    pub fn read_template(&self, folder_path: &str, template_name: &str) -> Result<StoredTemplate> {
+        let file_path = self.template_file(folder_path, template_name)?;
+        read_item_file(&file_path).context("Failed to parse template file")
+    }
 
-        Ok(stored_template)
+    /// Load a specific template from disk.
+    ///
+    /// The file is not rewritten, so opening a template never shows up as a change in git.
+    pub fn load_template(&self, folder_path: &str, template_name: &str) -> Result<StoredTemplate> {
+        self.read_template(folder_path, template_name)
     }
 
     /// Delete a template from disk
     pub fn delete_template(&self, folder_path: &str, template_name: &str) -> Result<()> {
-        let templates_dir = self.config.get_templates_directory();
-        let filename = sanitize_filename(template_name) + ".json";
-        let file_path = templates_dir.join(folder_path).join(filename);
+        let file_path = self.template_file(folder_path, template_name)?;
 
         std::fs::remove_file(&file_path).context("Failed to delete template file")?;
 
@@ -152,23 +160,29 @@ impl TemplateStorage {
             let entry = entry?;
             let path = entry.path();
 
-            if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("json") {
-                if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
-                    templates.push(stem.to_string());
-                }
+            if is_item_file(&path)
+                && let Some(stem) = path.file_stem().and_then(|s| s.to_str())
+            {
+                templates.push(stem.to_string());
             }
         }
 
         templates.sort();
+        templates.dedup();
         Ok(templates)
     }
 
+    /// The folder's README.md, shown while the folder is focused in the tree
+    pub fn read_folder_readme(&self, folder_path: &str) -> Option<String> {
+        read_folder_readme(&self.config.get_templates_directory().join(folder_path))
+    }
+
     /// Get all folders in the templates directory
     pub fn list_all_folders(&self) -> Result<Vec<String>> {
         let templates_dir = self.config.get_templates_directory();
         let mut folders = Vec::new();
 
-        self.scan_folders_recursive(&templates_dir, "", &mut folders)?;
+        self.scan_folders_recursive(templates_dir, "", &mut folders)?;
 
         folders.sort();
         Ok(folders)
@@ -213,8 +227,6 @@ impl TemplateStorage {
 
     /// Create demo templates if the templates directory is empty
     fn create_demo_templates_if_needed(&self) -> Result<()> {
-        let templates_dir = self.config.get_templates_directory();
-
         // Check if any templates already exist
         let existing_folders = self.list_all_folders().unwrap_or_default();
         if !existing_folders.is_empty() {
@@ -334,4 +346,38 @@ mod tests {
         assert_eq!(stored.version, "1.0");
         assert!(stored.last_used_at.is_none());
     }
+
+    #[test]
+    fn test_load_and_unchanged_save_leave_file_untouched() {
+        let temp = tempfile::tempdir().unwrap();
+        let storage = TemplateStorage::new(AppConfig {
+            custom_templates_dir: Some(temp.path().to_path_buf()),
+            ..AppConfig::default()
+        });
+        let template = AutomationTemplate::new("Deploy", "Nightly")
+            .with_field("zeta", "1")
+            .with_field("alpha", "2");
+
+        let path = storage
+            .save_template("Ops", "Deploy", template.clone())
+            .unwrap();
+        let original = std::fs::read_to_string(&path).unwrap();
+        assert!(original.find("alpha").unwrap() < original.find("zeta").unwrap());
+        assert!(original.ends_with('\n'));
+
+        storage.load_template("Ops", "Deploy").unwrap();
+        storage
+            .save_template("Ops", "Deploy", template.clone())
+            .unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), original);
+
+        // A real edit bumps modified_at but keeps created_at
+        let created_at = storage.read_template("Ops", "Deploy").unwrap().created_at;
+        storage
+            .save_template("Ops", "Deploy", template.with_field("alpha", "3"))
+            .unwrap();
+        let edited = storage.read_template("Ops", "Deploy").unwrap();
+        assert_eq!(edited.created_at, created_at);
+        assert!(edited.modified_at > created_at);
+    }
 }
@@ -0,0 +1,144 @@
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use std::future::Future;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tower_layer::Layer;
+use tower_service::Service;
+
+/// Add `took` to a running total shared with the client
+fn add_elapsed(elapsed: &Mutex<Option<Duration>>, took: Duration) {
+    let mut elapsed = elapsed.lock().unwrap();
+    *elapsed = Some(elapsed.unwrap_or_default() + took);
+}
+
+/// Resolves host names like reqwest's default resolver (the system's getaddrinfo), adding
+/// up the time spent so the response can show a DNS phase
+#[derive(Debug, Clone, Default)]
+pub struct TimedResolver {
+    elapsed: Arc<Mutex<Option<Duration>>>,
+}
+
+impl TimedResolver {
+    /// Time spent resolving so far; `None` if the client never looked a name up
+    pub fn elapsed(&self) -> Option<Duration> {
+        *self.elapsed.lock().unwrap()
+    }
+}
+
+impl Resolve for TimedResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let elapsed = self.elapsed.clone();
+        Box::pin(async move {
+            let start = Instant::now();
+            // reqwest fills in the port of the URL (or proxy) being connected to
+            let result = tokio::net::lookup_host((name.as_str(), 0)).await;
+            add_elapsed(&elapsed, start.elapsed());
+            let addrs: Vec<SocketAddr> = result?.collect();
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// Connector layer adding up the time spent opening connections: name lookup, TCP
+/// connect and, for HTTPS, the TLS handshake, which reqwest all does in one call
+#[derive(Debug, Clone, Default)]
+pub struct TimedConnector {
+    elapsed: Arc<Mutex<Option<Duration>>>,
+}
+
+impl TimedConnector {
+    /// Time spent connecting so far; `None` if the client never opened a connection
+    pub fn elapsed(&self) -> Option<Duration> {
+        *self.elapsed.lock().unwrap()
+    }
+}
+
+impl<S> Layer<S> for TimedConnector {
+    type Service = TimedConnection<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TimedConnection {
+            inner,
+            elapsed: self.elapsed.clone(),
+        }
+    }
+}
+
+/// The connector service wrapped by [`TimedConnector`]
+#[derive(Debug, Clone)]
+pub struct TimedConnection<S> {
+    inner: S,
+    elapsed: Arc<Mutex<Option<Duration>>>,
+}
+
+impl<S, Request> Service<Request> for TimedConnection<S>
+where
+    S: Service<Request>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<S::Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        let elapsed = self.elapsed.clone();
+        let start = Instant::now();
+        let connecting = self.inner.call(request);
+        Box::pin(async move {
+            let result = connecting.await;
+            add_elapsed(&elapsed, start.elapsed());
+            result
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_timed_resolver_records_lookups() {
+        let resolver = TimedResolver::default();
+        assert_eq!(resolver.elapsed(), None);
+
+        let addrs: Vec<SocketAddr> = resolver
+            .resolve("localhost".parse().unwrap())
+            .await
+            .unwrap()
+            .collect();
+        assert!(addrs.iter().all(|addr| addr.ip().is_loopback()));
+        assert!(!addrs.is_empty());
+        assert!(resolver.elapsed().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_timed_connector_records_connections() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            use tokio::io::AsyncWriteExt;
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let _ = socket
+                .write_all(b"HTTP/1.1 204 No Content\r\nContent-Length: 0\r\n\r\n")
+                .await;
+        });
+
+        let connector = TimedConnector::default();
+        assert_eq!(connector.elapsed(), None);
+        let client = reqwest::Client::builder()
+            .connector_layer(connector.clone())
+            .build()
+            .unwrap();
+        let response = client.get(&url).send().await.unwrap();
+
+        assert_eq!(response.status(), reqwest::StatusCode::NO_CONTENT);
+        assert!(connector.elapsed().is_some());
+    }
+}
@@ -0,0 +1,143 @@
+use crate::models::http_client::TlsSettings;
+use crate::services::storage_migration::expand_user_path;
+use anyhow::{Context, Result, bail};
+use reqwest::{Certificate, ClientBuilder, Identity};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+/// Trust the configured CA bundle and present the client certificate, if any
+pub fn apply_tls(mut builder: ClientBuilder, tls: &TlsSettings) -> Result<ClientBuilder> {
+    if !tls.ca_bundle.trim().is_empty() {
+        for certificate in load_ca_bundle(&tls.ca_bundle)? {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+    if !tls.client_cert.trim().is_empty() {
+        builder = builder.identity(load_identity(tls)?);
+    }
+    Ok(builder)
+}
+
+/// Every certificate in a PEM bundle, or the single certificate of a DER file
+fn load_ca_bundle(path: &str) -> Result<Vec<Certificate>> {
+    let bytes = read_file(path, "CA bundle")?;
+    let certificates = if bytes.starts_with(b"-----BEGIN") || !is_binary(&bytes) {
+        Certificate::from_pem_bundle(&bytes)
+    } else {
+        Certificate::from_der(&bytes).map(|certificate| vec![certificate])
+    }
+    .with_context(|| format!("Invalid CA bundle {}", path))?;
+
+    if certificates.is_empty() {
+        bail!("No certificates found in CA bundle {}", path);
+    }
+    Ok(certificates)
+}
+
+/// Client identity from a PKCS#12 bundle or a PEM certificate and key
+fn load_identity(tls: &TlsSettings) -> Result<Identity> {
+    let cert = read_file(&tls.client_cert, "client certificate")?;
+    if is_pkcs12(&tls.client_cert) {
+        return Identity::from_pkcs12_der(&cert, &tls.client_cert_password)
+            .with_context(|| format!("Invalid PKCS#12 bundle {}", tls.client_cert));
+    }
+
+    // A single PEM file may hold both the certificate and its key
+    let key = if tls.client_key.trim().is_empty() {
+        cert.clone()
+    } else {
+        read_file(&tls.client_key, "client key")?
+    };
+    Identity::from_pkcs8_pem(&cert, &key).with_context(|| {
+        format!(
+            "Invalid client certificate {} (expects a PEM certificate and PKCS#8 key)",
+            tls.client_cert
+        )
+    })
+}
+
+/// `.p12` and `.pfx` files are PKCS#12 bundles, anything else is read as PEM
+pub fn is_pkcs12(path: &str) -> bool {
+    Path::new(path.trim())
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| {
+            extension.eq_ignore_ascii_case("p12") || extension.eq_ignore_ascii_case("pfx")
+        })
+}
+
+/// SHA-256 fingerprint of a DER certificate, in the `AB:CD:…` form browsers show
+pub fn certificate_fingerprint(der: &[u8]) -> String {
+    Sha256::digest(der)
+        .iter()
+        .map(|byte| format!("{:02X}", byte))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+fn read_file(path: &str, what: &str) -> Result<Vec<u8>> {
+    let expanded = expand_user_path(path);
+    std::fs::read(&expanded)
+        .with_context(|| format!("Failed to read {} {}", what, expanded.display()))
+}
+
+fn is_binary(bytes: &[u8]) -> bool {
+    bytes
+        .iter()
+        .take(512)
+        .any(|byte| !byte.is_ascii() || (byte.is_ascii_control() && !byte.is_ascii_whitespace()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tls_settings_merge_and_load_errors() {
+        let global = TlsSettings {
+            ca_bundle: "/etc/corp/ca.pem".to_string(),
+            client_cert: "/etc/corp/me.p12".to_string(),
+            client_key: String::new(),
+            client_cert_password: "hunter2".to_string(),
+        };
+        let own = TlsSettings {
+            client_cert: "client.pem".to_string(),
+            client_key: "client.key".to_string(),
+            ..TlsSettings::default()
+        };
+        let merged = own.or(&global);
+        assert_eq!(merged.ca_bundle, "/etc/corp/ca.pem");
+        assert_eq!(merged.client_cert, "client.pem");
+        // The global password belongs to the global bundle, not to this certificate
+        assert!(merged.client_cert_password.is_empty());
+        assert_eq!(TlsSettings::default().or(&global), global);
+
+        let fingerprint = certificate_fingerprint(b"not really DER");
+        assert_eq!(fingerprint.len(), 32 * 3 - 1);
+        assert!(
+            fingerprint
+                .chars()
+                .all(|c| c == ':' || c.is_ascii_hexdigit())
+        );
+
+        assert!(is_pkcs12("~/certs/Me.PFX"));
+        assert!(!is_pkcs12("client.pem"));
+        assert!(apply_tls(reqwest::Client::builder(), &TlsSettings::default()).is_ok());
+
+        let temp = tempfile::tempdir().unwrap();
+        let missing = TlsSettings {
+            ca_bundle: temp.path().join("missing.pem").display().to_string(),
+            ..TlsSettings::default()
+        };
+        let error = apply_tls(reqwest::Client::builder(), &missing).unwrap_err();
+        assert!(format!("{:#}", error).contains("Failed to read CA bundle"));
+
+        let empty = temp.path().join("empty.pem");
+        std::fs::write(&empty, "not a certificate\n").unwrap();
+        let no_certs = TlsSettings {
+            ca_bundle: empty.display().to_string(),
+            ..TlsSettings::default()
+        };
+        assert!(apply_tls(reqwest::Client::builder(), &no_certs).is_err());
+    }
+}
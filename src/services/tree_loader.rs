@@ -0,0 +1,134 @@
+use crate::app::{AppMessage, AppMode};
+use crate::models::{AppConfig, FolderColor, LogLevel};
+use crate::services::{HttpCollectionStorage, TemplateStorage};
+use anyhow::Result;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use tokio::sync::mpsc;
+
+/// Scan the collections directory for `mode` in the background.
+///
+/// Folders are streamed back as `AppMessage::TreeFolderLoaded` as soon as they
+/// are discovered (breadth-first, so top-level folders show up first), followed
+/// by a single `AppMessage::TreeLoadComplete`. Every message carries
+/// `generation` so the app can ignore scans that were superseded.
+///
+/// When `initialize` is set the storage is initialized (directories and demo
+/// content) before scanning, which keeps that work off the startup path too.
+pub fn spawn_tree_scan(
+    config: AppConfig,
+    mode: AppMode,
+    generation: u64,
+    initialize: bool,
+    sender: mpsc::UnboundedSender<AppMessage>,
+) {
+    tokio::task::spawn_blocking(move || {
+        if let Err(e) = scan_collections(&config, &mode, generation, initialize, &sender) {
+            let _ = sender.send(AppMessage::Log(
+                LogLevel::Error,
+                format!("Failed to load collections: {}", e),
+            ));
+        }
+
+        let _ = sender.send(AppMessage::TreeLoadComplete { generation });
+    });
+}
+
+/// Walk the storage root and report every folder with its items
+fn scan_collections(
+    config: &AppConfig,
+    mode: &AppMode,
+    generation: u64,
+    initialize: bool,
+    sender: &mpsc::UnboundedSender<AppMessage>,
+) -> Result<()> {
+    match mode {
+        AppMode::Automation => {
+            let storage = TemplateStorage::new(config.clone());
+            if initialize {
+                storage.initialize()?;
+            }
+            let root = config.get_templates_directory().clone();
+            walk_folders(
+                &root,
+                generation,
+                sender,
+                |folder| storage.list_templates_in_folder(folder),
+                |_| (None, 0),
+            )
+        }
+        AppMode::Http => {
+            let storage = HttpCollectionStorage::new(config.clone());
+            if initialize {
+                storage.initialize()?;
+            }
+            let root = storage.get_collections_directory();
+            walk_folders(
+                &root,
+                generation,
+                sender,
+                |folder| storage.list_requests_in_folder(folder),
+                |folder| {
+                    let settings = storage.read_folder_settings(folder);
+                    (settings.color, settings.sort_weight)
+                },
+            )
+        }
+    }
+}
+
+/// Breadth-first folder walk, sending each folder as it is listed with its color and weight
+fn walk_folders(
+    root: &Path,
+    generation: u64,
+    sender: &mpsc::UnboundedSender<AppMessage>,
+    list_items: impl Fn(&str) -> Result<Vec<String>>,
+    folder_style: impl Fn(&str) -> (Option<FolderColor>, i32),
+) -> Result<()> {
+    let mut queue: VecDeque<(PathBuf, String)> = VecDeque::new();
+    queue.push_back((root.to_path_buf(), String::new()));
+
+    while let Some((dir, folder_path)) = queue.pop_front() {
+        let items = list_items(&folder_path).unwrap_or_default();
+
+        // Root-level items have no folder node to live in, so only report real folders
+        if !folder_path.is_empty() {
+            let (color, sort_weight) = folder_style(&folder_path);
+            let message = AppMessage::TreeFolderLoaded {
+                generation,
+                folder: folder_path.clone(),
+                items,
+                color,
+                sort_weight,
+            };
+            if sender.send(message).is_err() {
+                // The app is gone, nobody is listening anymore
+                return Ok(());
+            }
+        }
+
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+
+        let mut subfolders: Vec<(PathBuf, String)> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .filter_map(|path| {
+                let name = path.file_name()?.to_str()?.to_string();
+                let child_path = if folder_path.is_empty() {
+                    name
+                } else {
+                    format!("{}/{}", folder_path, name)
+                };
+                Some((path, child_path))
+            })
+            .collect();
+
+        subfolders.sort_by(|a, b| a.1.cmp(&b.1));
+        queue.extend(subfolders);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use crate::app::AppMessage;
+use crate::models::LogLevel;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::cmp::Ordering;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Release feed queried by the update checker
+pub const RELEASE_FEED_URL: &str =
+    "https://api.github.com/repos/cecarbs/dev_toolkit_1/releases/latest";
+
+/// Maximum number of changelog lines shown in the update notice
+const MAX_HIGHLIGHTS: usize = 5;
+
+/// Information about a newer release
+#[derive(Debug, Clone)]
+pub struct UpdateInfo {
+    pub version: String,
+    pub highlights: Vec<String>,
+    pub url: String,
+}
+
+/// Subset of the GitHub release payload we care about
+#[derive(Debug, Deserialize)]
+struct ReleaseFeedEntry {
+    tag_name: String,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    html_url: String,
+}
+
+/// Check the release feed in the background and report a newer version, if any.
+///
+/// Failures are only logged at debug level - an offline machine should not
+/// produce noise on every startup.
+pub fn spawn_update_check(
+    current_version: &'static str,
+    sender: mpsc::UnboundedSender<AppMessage>,
+) {
+    tokio::spawn(async move {
+        match fetch_latest_release().await {
+            Ok(release) => {
+                if is_newer_version(&release.tag_name, current_version) {
+                    let _ = sender.send(AppMessage::UpdateAvailable(UpdateInfo {
+                        version: release.tag_name.trim_start_matches('v').to_string(),
+                        highlights: extract_highlights(release.body.as_deref().unwrap_or("")),
+                        url: release.html_url,
+                    }));
+                }
+            }
+            Err(e) => {
+                let _ = sender.send(AppMessage::Log(
+                    LogLevel::Debug,
+                    format!("Update check failed: {}", e),
+                ));
+            }
+        }
+    });
+}
+
+/// Fetch the latest release from the feed
+async fn fetch_latest_release() -> Result<ReleaseFeedEntry> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .user_agent(concat!("automation-toolkit/", env!("CARGO_PKG_VERSION")))
+        .build()?;
+
+    let response = client
+        .get(RELEASE_FEED_URL)
+        .send()
+        .await
+        .context("Failed to reach release feed")?
+        .error_for_status()?;
+
+    response
+        .json::<ReleaseFeedEntry>()
+        .await
+        .context("Failed to parse release feed")
+}
+
+/// Compare version strings the semver way (a leading `v` and build metadata are
+/// ignored): a pre-release such as `1.2.0-rc1` comes before `1.2.0`
+pub fn is_newer_version(candidate: &str, current: &str) -> bool {
+    compare_versions(candidate, current) == Ordering::Greater
+}
+
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    let (a_core, a_pre) = parse_version(a);
+    let (b_core, b_pre) = parse_version(b);
+    a_core.cmp(&b_core).then_with(|| match (a_pre, b_pre) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a_pre), Some(b_pre)) => a_pre.cmp(&b_pre),
+    })
+}
+
+/// A dot-separated pre-release part; numbers sort before words, as in semver
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum PreRelease {
+    Number(u64),
+    Word(String),
+}
+
+fn parse_version(version: &str) -> (Vec<u64>, Option<Vec<PreRelease>>) {
+    let version = version.trim().trim_start_matches('v');
+    let version = version.split('+').next().unwrap_or("");
+    let (core, pre) = match version.split_once('-') {
+        Some((core, pre)) => (core, Some(pre)),
+        None => (version, None),
+    };
+
+    let mut parts: Vec<u64> = core
+        .split('.')
+        .map(|part| part.parse().unwrap_or(0))
+        .collect();
+    // "1.2" and "1.2.0" are the same version
+    while parts.last() == Some(&0) {
+        parts.pop();
+    }
+
+    let pre = pre.map(|pre| {
+        pre.split('.')
+            .map(|part| match part.parse() {
+                Ok(number) => PreRelease::Number(number),
+                Err(_) => PreRelease::Word(part.to_string()),
+            })
+            .collect()
+    });
+    (parts, pre)
+}
+
+/// Pick the bullet points out of a markdown changelog
+fn extract_highlights(body: &str) -> Vec<String> {
+    body.lines()
+        .map(str::trim)
+        .filter_map(|line| line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")))
+        .map(|line| line.to_string())
+        .take(MAX_HIGHLIGHTS)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_newer_version_orders_releases_and_pre_releases() {
+        assert!(is_newer_version("v1.3.0", "1.2.9"));
+        assert!(is_newer_version("1.10", "1.9.1"));
+        assert!(!is_newer_version("1.2", "1.2.0"));
+        assert!(!is_newer_version("1.2.0+build.7", "1.2.0"));
+
+        // The release supersedes its release candidates
+        assert!(is_newer_version("1.2.0", "1.2.0-rc1"));
+        assert!(!is_newer_version("1.2.0-rc1", "1.2.0"));
+        assert!(is_newer_version("1.2.0-rc.2", "1.2.0-rc.1"));
+        assert!(is_newer_version("1.2.0-beta", "1.2.0-alpha"));
+        assert!(is_newer_version("1.2.0-alpha.beta", "1.2.0-alpha.1"));
+        assert!(is_newer_version("1.2.0-rc1", "1.1.9"));
+    }
+
+    #[test]
+    fn test_extract_highlights_keeps_the_first_bullets() {
+        let body =
+            "## What's new\n\n- Faster startup\n* Dark theme\nPlain text\n- a\n- b\n- c\n- d";
+        assert_eq!(
+            extract_highlights(body),
+            vec!["Faster startup", "Dark theme", "a", "b", "c"]
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use crate::app::App;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// Render the audit log of destructive and sensitive actions, newest first
+pub fn render_audit_log_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let Some(entries) = &app.audit_log_entries else {
+        return;
+    };
+    let popup_area = centered_rect(80, 70, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),    // Entries
+            Constraint::Length(3), // File location and instructions
+        ])
+        .split(popup_area);
+
+    let items: Vec<ListItem> = if entries.is_empty() {
+        vec![ListItem::new(Span::styled(
+            "Nothing recorded yet - deletes, renames, overwrites, restores, credential changes and prod sends show up here",
+            Style::default().fg(Color::Gray),
+        ))]
+    } else {
+        entries
+            .iter()
+            .rev()
+            .map(|entry| {
+                let mut spans = vec![
+                    Span::styled(
+                        format!("{}  ", app.config.timestamp_format.format(&entry.at)),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(
+                        format!("{:<20}", entry.action.label()),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                    Span::styled(entry.paths.join(" → "), Style::default().fg(Color::White)),
+                ];
+                if !entry.detail.is_empty() {
+                    spans.push(Span::styled(
+                        format!("  {}", entry.detail),
+                        Style::default().fg(Color::Gray),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect()
+    };
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("🛡 Audit Log ({})", entries.len()))
+                .title_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::Blue)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        );
+    let mut list_state = ListState::default();
+    if !entries.is_empty() {
+        list_state.select(Some(app.audit_log_scroll));
+    }
+    f.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    let instructions = Paragraph::new(format!(
+        "j/k: Scroll  |  Esc: Close  |  {}",
+        app.audit_log.path().display()
+    ))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Controls")
+            .style(Style::default().bg(Color::DarkGray)),
+    )
+    .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[1]);
+}
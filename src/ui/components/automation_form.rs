@@ -2,9 +2,10 @@ use crate::app::{App, FocusedPane, InputMode};
 use crate::models::{FieldType, FormField};
 use crate::modes::automation::AutomationState;
 use crate::services::AuthService;
+use crate::ui::components::field_widgets::{checkbox_span, stepper_spans};
+use crate::ui::layout::centered_rect;
 use ratatui::layout::{Margin, Position};
 use ratatui::style::Modifier;
-use ratatui::widgets::BorderType;
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
@@ -89,23 +90,24 @@ fn render_form_fields(f: &mut Frame, area: Rect, state: &AutomationState, app: &
     f.render_widget(list, area);
 
     // Set cursor position when in edit mode
-    if app.input_mode == InputMode::Edit && app.focused_pane == FocusedPane::Form {
-        if let Some(_focused_field) = state.get_focused_field() {
-            // Calculate cursor position on screen
-            let list_inner = area.inner(Margin {
-                horizontal: 1,
-                vertical: 1,
-            });
-
-            // Y position: list top + focused field index
-            let cursor_y = list_inner.y + state.focused_field as u16;
-
-            // X position: list left + label width + ": " + cursor index in field
-            let label_width = 18; // From your format string "{:18}"
-            let cursor_x = list_inner.x + label_width + 2 + app.form_field_cursor_index as u16;
-
-            f.set_cursor_position(Position::new(cursor_x, cursor_y));
-        }
+    if app.input_mode == InputMode::Edit
+        && app.focused_pane == FocusedPane::Form
+        && let Some(_focused_field) = state.get_focused_field()
+    {
+        // Calculate cursor position on screen
+        let list_inner = area.inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        });
+
+        // Y position: list top + focused field index (the hint line sits below it)
+        let cursor_y = list_inner.y + state.focused_field as u16;
+
+        // X position: list left + label width + ": " + cursor index in field
+        let label_width = 18; // From your format string "{:18}"
+        let cursor_x = list_inner.x + label_width + 2 + app.form_field_cursor_index as u16;
+
+        f.set_cursor_position(Position::new(cursor_x, cursor_y));
     }
 }
 
@@ -190,16 +192,40 @@ fn render_field_item<'a>(
         Style::default()
     };
 
-    let line = Line::from(vec![
+    let mut spans = vec![
         Span::styled(format!("{:18}", field.get_display_label()), label_style),
         Span::raw(": "),
-        Span::styled(display_value, value_style.patch(background_style)),
-        mode_indicator,
-    ]);
+    ];
+    match (&field.field_type, field.number_range) {
+        (FieldType::Checkbox, _) => {
+            spans.push(checkbox_span(field.is_checked()).patch_style(background_style));
+        }
+        (FieldType::Number, Some(range)) if !is_editing => {
+            spans.extend(stepper_spans(
+                &display_value,
+                range,
+                value_style.patch(background_style),
+            ));
+        }
+        _ => spans.push(Span::styled(
+            display_value,
+            value_style.patch(background_style),
+        )),
+    }
+    spans.push(mode_indicator);
+    let mut lines = vec![Line::from(spans)];
+
+    // The focused field's help from the loaded template, right under it
+    if is_focused && let Some(hint) = app.field_hint(&field.name) {
+        lines.push(Line::from(Span::styled(
+            format!("{:20}ℹ {}", "", hint),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
 
-    ListItem::new(line)
+    ListItem::new(lines)
 }
-/// Render a single form field item with appropriate input type
+// Render a single form field item with appropriate input type
 // fn render_field_item<'a>(
 //     field: &'a FormField,
 //     index: usize,
@@ -458,7 +484,12 @@ pub fn render_login_popup(f: &mut Frame, area: Rect, app: &App) {
         .split(popup_area);
 
     // Title
-    let title = Paragraph::new("🔐 Login Required")
+    let title = if app.session_locked {
+        "🔒 Session locked after inactivity; log in again"
+    } else {
+        "🔐 Login Required"
+    };
+    let title = Paragraph::new(title)
         .block(
             Block::default()
                 .borders(Borders::ALL)
@@ -595,7 +626,7 @@ pub fn render_login_popup(f: &mut Frame, area: Rect, app: &App) {
         .style(Style::default().fg(Color::White));
     f.render_widget(help, chunks[4]);
 }
-/// Render login popup modal
+// Render login popup modal
 // pub fn render_login_popup(f: &mut Frame, area: Rect, app: &crate::app::App) {
 //     let popup_area = centered_rect(50, 40, area);
 //
@@ -681,24 +712,3 @@ pub fn render_login_popup(f: &mut Frame, area: Rect, app: &App) {
 //         .style(Style::default().fg(Color::Gray));
 //     f.render_widget(instructions, chunks[4]);
 // }
-
-/// Helper function to create a centered rectangle
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
-}
@@ -0,0 +1,109 @@
+use crate::app::App;
+use crate::services::backup::BackupStore;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+/// Render the restore picker: backups newest first, with a confirmation step
+pub fn render_backup_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let Some(dialog) = app.backup_dialog.as_ref() else {
+        return;
+    };
+    let popup_area = centered_rect(60, 60, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),    // Backups
+            Constraint::Length(4), // Warning, error or hint
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let items: Vec<ListItem> = if dialog.backups.is_empty() {
+        vec![ListItem::new(Span::styled(
+            "No backups yet (b: back up now)",
+            Style::default().fg(Color::Gray),
+        ))]
+    } else {
+        dialog
+            .backups
+            .iter()
+            .map(|backup| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        app.format_timestamp(&backup.created_at),
+                        Style::default().fg(Color::White),
+                    ),
+                    Span::styled(
+                        format!("  {:.1} KB", backup.size as f64 / 1024.0),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ]))
+            })
+            .collect()
+    };
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    "💾 Restore Backup ({})",
+                    BackupStore::default_path().display()
+                ))
+                .title_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::Blue)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("▶ ");
+    let mut state = ListState::default();
+    if !dialog.backups.is_empty() {
+        state.select(Some(dialog.selected));
+    }
+    f.render_stateful_widget(list, chunks[0], &mut state);
+
+    let status = if let Some(error) = &dialog.error {
+        Span::styled(error.clone(), Style::default().fg(Color::Red))
+    } else if dialog.confirming {
+        Span::styled(
+            "Enter again to replace all templates and collections with this backup \
+             (the current state is backed up first)",
+            Style::default().fg(Color::Yellow),
+        )
+    } else {
+        Span::styled(
+            format!("Schedule: {}", app.config.backup_schedule.label()),
+            Style::default().fg(Color::Gray),
+        )
+    };
+    let status = Paragraph::new(Line::from(status))
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().bg(Color::DarkGray)),
+        );
+    f.render_widget(status, chunks[1]);
+
+    let instructions =
+        Paragraph::new("↑/↓: Select  |  Enter: Restore  |  b: Back up now  |  Esc: Close")
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Controls")
+                    .style(Style::default().bg(Color::DarkGray)),
+            )
+            .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[2]);
+}
@@ -0,0 +1,126 @@
+use crate::app::App;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+/// Render "Clone to folder…": target folder, environment rewrite and a preview of the copy
+pub fn render_clone_request_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let Some(dialog) = app.clone_request_dialog.as_ref() else {
+        return;
+    };
+    let popup_area = centered_rect(70, 70, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),    // Folders
+            Constraint::Length(3), // Environment
+            Constraint::Length(5), // Preview
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let items: Vec<ListItem> = dialog
+        .folders
+        .iter()
+        .map(|folder| {
+            let label = if folder.is_empty() {
+                "/ (root)".to_string()
+            } else {
+                format!("📁 {}", folder)
+            };
+            ListItem::new(Line::from(Span::styled(
+                label,
+                Style::default().fg(Color::Cyan),
+            )))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("📋 Clone '{}' to folder", dialog.source_path))
+                .title_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+        );
+    let mut list_state = ListState::default().with_selected(Some(dialog.selected_folder));
+    f.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    let target = match &dialog.target_environment {
+        Some(environment) => Span::styled(environment.clone(), Style::default().fg(Color::Green)),
+        None => Span::styled("unchanged", Style::default().fg(Color::Gray)),
+    };
+    let environment = Paragraph::new(Line::from(vec![
+        Span::styled(
+            dialog.source_environment.clone(),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::raw(" → "),
+        target,
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Environment (e: change)")
+            .style(Style::default().bg(Color::DarkGray)),
+    );
+    f.render_widget(environment, chunks[1]);
+
+    let preview_lines = match app.clone_request_preview() {
+        Some((request, rewritten)) => {
+            let mut lines = vec![Line::from(vec![
+                Span::styled(
+                    format!("{} ", request.method.as_str()),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(request.url.clone(), Style::default().fg(Color::White)),
+            ])];
+            if dialog.target_environment.is_some() {
+                lines.push(Line::from(Span::styled(
+                    format!("{} value(s) rewritten", rewritten),
+                    Style::default().fg(if rewritten > 0 {
+                        Color::Green
+                    } else {
+                        Color::Gray
+                    }),
+                )));
+            }
+            lines
+        }
+        None => vec![Line::from(Span::styled(
+            "The request could not be read",
+            Style::default().fg(Color::Red),
+        ))],
+    };
+    let preview = Paragraph::new(preview_lines)
+        .wrap(Wrap { trim: false })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Preview")
+                .style(Style::default().bg(Color::DarkGray)),
+        );
+    f.render_widget(preview, chunks[2]);
+
+    let instructions =
+        Paragraph::new("j/k: Folder  |  e: Target environment  |  Enter: Clone  |  Esc: Cancel")
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Controls")
+                    .style(Style::default().bg(Color::DarkGray)),
+            )
+            .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[3]);
+}
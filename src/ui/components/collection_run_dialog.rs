@@ -0,0 +1,191 @@
+use crate::app::App;
+use crate::services::collection_runner::RunResult;
+use crate::ui::components::http_response_viewer::format_bytes;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Gauge, List, ListItem, ListState, Paragraph},
+};
+
+/// Render the collection run results: progress, pass/fail summary and one row per request
+pub fn render_collection_run_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let Some(run) = &app.collection_run else {
+        return;
+    };
+
+    let popup_area = centered_rect(80, 75, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Progress
+            Constraint::Min(5),    // Results
+            Constraint::Length(4), // Selected result
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let done = run.results.len();
+    let ratio = if run.total == 0 {
+        1.0
+    } else {
+        done as f64 / run.total as f64
+    };
+    let state = if run.finished { "Finished" } else { "Running" };
+    let gauge = Gauge::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("▶ Collection Run: {} ({})", run.folder, state))
+                .title_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .gauge_style(Style::default().fg(if run.failed_count() == 0 {
+            Color::Green
+        } else {
+            Color::Yellow
+        }))
+        .ratio(ratio.min(1.0))
+        .label(format!(
+            "{}/{}  ✅ {} passed  ❌ {} failed  ⏱ {} ms",
+            done,
+            run.total,
+            run.passed_count(),
+            run.failed_count(),
+            run.total_duration_ms()
+        ));
+    f.render_widget(gauge, chunks[0]);
+
+    let items: Vec<ListItem> = run
+        .results
+        .iter()
+        .map(|result| {
+            let (icon, status) = match result.status_code {
+                Some(code) if result.passed() => ("✅", format!("{:>3}", code)),
+                Some(code) => ("❌", format!("{:>3}", code)),
+                None => ("❌", "ERR".to_string()),
+            };
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{} ", icon)),
+                Span::styled(
+                    format!("{} ", status),
+                    Style::default()
+                        .fg(status_color(result))
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{:<7}", result.method.as_str()),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(result.name.clone(), Style::default().fg(Color::White)),
+                Span::styled(
+                    format!(
+                        "  {} ms, {}",
+                        result.duration_ms,
+                        format_bytes(result.size_bytes)
+                    ),
+                    Style::default().fg(Color::Gray),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Results")
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .highlight_style(Style::default().bg(Color::Blue));
+    let mut list_state = ListState::default();
+    if !run.results.is_empty() {
+        list_state.select(Some(app.collection_run_selected));
+    }
+    f.render_stateful_widget(list, chunks[1], &mut list_state);
+
+    let details = match run.results.get(app.collection_run_selected) {
+        Some(result) => {
+            let outcome = match &result.error {
+                Some(error) => {
+                    Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red)))
+                }
+                None => {
+                    let mut spans = vec![Span::styled(
+                        format!(
+                            "{} {}",
+                            result.status_code.unwrap_or_default(),
+                            result.status_text
+                        ),
+                        Style::default().fg(status_color(result)),
+                    )];
+                    let failed: Vec<_> = result.failed_assertions().collect();
+                    if let Some(first) = failed.first() {
+                        spans.push(Span::styled(
+                            format!(
+                                "  ✗ {} failed: {} ({})",
+                                failed.len(),
+                                first.assertion,
+                                first.message
+                            ),
+                            Style::default().fg(Color::Red),
+                        ));
+                    } else if !result.assertions.is_empty() {
+                        spans.push(Span::styled(
+                            format!("  ✓ {} assertion(s) passed", result.assertions.len()),
+                            Style::default().fg(Color::Green),
+                        ));
+                    }
+                    Line::from(spans)
+                }
+            };
+            vec![
+                Line::from(vec![
+                    Span::styled(
+                        format!("{} ", result.method.as_str()),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::raw(result.url.clone()),
+                ]),
+                outcome,
+            ]
+        }
+        None => vec![Line::from(Span::styled(
+            "Waiting for the first response...",
+            Style::default().fg(Color::Gray),
+        ))],
+    };
+    let details = Paragraph::new(details).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Details")
+            .style(Style::default().bg(Color::DarkGray)),
+    );
+    f.render_widget(details, chunks[2]);
+
+    let instructions =
+        Paragraph::new("↑/↓ j/k: Select  |  Enter: Open request  |  Esc: Close (run continues)")
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Controls")
+                    .style(Style::default().bg(Color::DarkGray)),
+            )
+            .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[3]);
+}
+
+fn status_color(result: &RunResult) -> Color {
+    match result.status_code {
+        Some(200..=299) => Color::Green,
+        Some(300..=399) => Color::Yellow,
+        Some(400..=499) => Color::Red,
+        Some(500..=599) => Color::Magenta,
+        Some(_) => Color::Gray,
+        None => Color::Red,
+    }
+}
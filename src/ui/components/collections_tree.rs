@@ -1,7 +1,7 @@
 use crate::app::{App, FocusedPane};
-use crate::models::{NodeType, TreeNode};
+use crate::models::{FolderColor, NodeType, TreeNode};
 use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::widgets::{BorderType, Paragraph};
+use ratatui::widgets::Paragraph;
 use ratatui::{
     Frame,
     layout::Rect,
@@ -39,12 +39,20 @@ pub fn render_collections_tree(f: &mut Frame, area: Rect, app: &App) {
     let visible_nodes = app.tree_state.get_visible_nodes();
 
     // Create list items for each visible node
-    let items: Vec<ListItem> = visible_nodes
+    let mut items: Vec<ListItem> = visible_nodes
         .iter()
         .enumerate()
-        .map(|(index, node)| render_tree_node(node, index, &app))
+        .map(|(index, node)| render_tree_node(node, index, app))
         .collect();
 
+    // Placeholder node while the background scan is still streaming folders in
+    if app.tree_state.is_loading {
+        items.push(ListItem::new(Line::from(Span::styled(
+            "⏳ loading…",
+            Style::default().fg(Color::DarkGray),
+        ))));
+    }
+
     // Show instructions if tree is empty
     let list = if items.is_empty() {
         let empty_items = vec![
@@ -142,10 +150,10 @@ fn render_tree_node<'a>(node: &'a TreeNode, index: usize, app: &App) -> ListItem
             Style::default().fg(Color::White).bg(Color::DarkGray),
             Style::default().fg(Color::Blue),
         ),
-        (NodeType::Folder, false, false) => (
-            Style::default().fg(Color::Yellow),
-            Style::default().fg(Color::Yellow),
-        ),
+        (NodeType::Folder, false, false) => {
+            let color = node.color.map_or(Color::Yellow, label_color);
+            (Style::default().fg(color), Style::default().fg(color))
+        }
         (NodeType::Template, false, false) => (
             Style::default().fg(Color::White),
             Style::default().fg(Color::White),
@@ -164,17 +172,46 @@ fn render_tree_node<'a>(node: &'a TreeNode, index: usize, app: &App) -> ListItem
         _ => "",
     };
 
+    // Annotated items carry a marker, turning into a reminder once their review date is due
+    let note_marker = match app.note_for(&node.path) {
+        Some(note) if note.is_due(chrono::Local::now().date_naive()) => {
+            Span::styled(" ⏰", Style::default().fg(Color::Red))
+        }
+        Some(_) => Span::styled(" 📝", Style::default().fg(Color::Gray)),
+        None => Span::raw(""),
+    };
+
+    // Labelled folders keep a dot in their color while focused or selected too
+    let color_marker = match node.color {
+        Some(color) => Span::styled(" ●", Style::default().fg(label_color(color))),
+        None => Span::raw(""),
+    };
+
     let line = Line::from(vec![
         Span::raw(indent),
         Span::styled(icon, icon_style),
         Span::raw(" "),
         Span::styled(&node.name, name_style),
+        color_marker,
         Span::styled(expand_indicator, Style::default().fg(Color::Gray)),
+        note_marker,
     ]);
 
     ListItem::new(line)
 }
 
+/// Terminal color of a folder's label
+fn label_color(color: FolderColor) -> Color {
+    match color {
+        FolderColor::Red => Color::Red,
+        FolderColor::Yellow => Color::LightYellow,
+        FolderColor::Green => Color::Green,
+        FolderColor::Cyan => Color::Cyan,
+        FolderColor::Blue => Color::LightBlue,
+        FolderColor::Magenta => Color::Magenta,
+    }
+}
+
 ///// Render a single tree node as a list item
 //fn render_tree_node<'a>(node: &'a TreeNode, index: usize, app: &App) -> ListItem<'a> {
 //    let is_focused =
@@ -238,18 +275,6 @@ fn render_tree_node<'a>(node: &'a TreeNode, index: usize, app: &App) -> ListItem
 //    ListItem::new(line)
 //}
 
-/// Render focus indicator and help text when tree is focused
-fn render_tree_focus_indicator(f: &mut Frame, area: Rect, app: &App) {
-    // We could add a small indicator or help text here
-    // For now, the border color change is sufficient
-
-    // Get current focused node for context
-    if let Some(focused_node) = app.tree_state.get_focused_node() {
-        // Could show additional info about the focused node
-        // But for now, the visual styling is enough
-    }
-}
-
 /// Get help text for tree navigation
 pub fn get_tree_help_text() -> Vec<String> {
     vec![
@@ -0,0 +1,188 @@
+use crate::models::confirmation::{Confirmation, DangerLevel};
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+};
+
+/// Render the shared confirmation dialog, colored by its danger level
+pub fn render_confirmation_dialog(f: &mut Frame, area: Rect, confirmation: &Confirmation) {
+    let popup_area = centered_rect(70, 60, area);
+
+    f.render_widget(Clear, popup_area);
+
+    let color = confirmation.danger.color();
+    let mut constraints = vec![
+        Constraint::Length(3),                                     // Title
+        Constraint::Length(3),                                     // Subject
+        Constraint::Length(confirmation.details.len() as u16 + 2), // Details
+    ];
+    if !confirmation.items.is_empty() {
+        constraints.push(Constraint::Min(5)); // Affected items
+    } else {
+        constraints.push(Constraint::Min(0));
+    }
+    if confirmation.typed_confirmation.is_some() {
+        constraints.push(Constraint::Length(3)); // Typed confirmation
+    }
+    constraints.push(Constraint::Length(3)); // Buttons
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(popup_area);
+
+    // Title
+    let title = Paragraph::new(format!(
+        "{} {}",
+        confirmation.danger.icon(),
+        confirmation.title
+    ))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Confirm")
+            .title_style(Style::default().fg(color))
+            .style(Style::default().bg(Color::DarkGray))
+            .border_style(Style::default().fg(color)),
+    )
+    .style(Style::default().fg(Color::White));
+    f.render_widget(title, chunks[0]);
+
+    // Subject
+    let subject = Paragraph::new(confirmation.subject.clone())
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Item")
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .style(Style::default().fg(Color::Yellow));
+    f.render_widget(subject, chunks[1]);
+
+    // Details: the first line carries the danger color
+    let detail_lines: Vec<Line> = confirmation
+        .details
+        .iter()
+        .enumerate()
+        .map(|(index, detail)| {
+            let style = if index == 0 || confirmation.danger == DangerLevel::Destructive {
+                Style::default().fg(color)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            Line::from(Span::styled(detail.clone(), style))
+        })
+        .collect();
+    let details = Paragraph::new(detail_lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(match confirmation.danger {
+                DangerLevel::Info => "Details",
+                DangerLevel::Warn | DangerLevel::Destructive => "Warning",
+            })
+            .style(Style::default().bg(Color::DarkGray)),
+    );
+    f.render_widget(details, chunks[2]);
+
+    // Affected items, e.g. a folder's contents
+    if !confirmation.items.is_empty() {
+        // As many as fit, keeping a row for the "... more" line when some don't
+        let rows = (chunks[3].height as usize).saturating_sub(2).max(1);
+        let shown = if confirmation.items.len() > rows {
+            rows.saturating_sub(1).max(1)
+        } else {
+            rows
+        };
+        let mut all_items: Vec<ListItem> = confirmation
+            .items
+            .iter()
+            .take(shown)
+            .map(|item| {
+                let (icon, name) = if let Some(pos) = item.find(' ') {
+                    (&item[..pos], &item[pos + 1..])
+                } else {
+                    ("", item.as_str())
+                };
+
+                let icon_color = match icon {
+                    "+" => Color::Green,
+                    "-" => Color::Red,
+                    "~" => Color::Yellow,
+                    _ => Color::Blue,
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled(icon, Style::default().fg(icon_color)),
+                    Span::raw(" "),
+                    Span::styled(name, Style::default().fg(Color::White)),
+                ]))
+            })
+            .collect();
+
+        if confirmation.items.len() > shown {
+            all_items.push(ListItem::new(Line::from(Span::styled(
+                format!("... and {} more items", confirmation.items.len() - shown),
+                Style::default().fg(Color::Gray),
+            ))));
+        }
+
+        let contents_list = List::new(all_items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(confirmation.items_title.clone())
+                .style(Style::default().bg(Color::DarkGray)),
+        );
+        f.render_widget(contents_list, chunks[3]);
+    }
+
+    // Typed confirmation input
+    if let Some(expected) = &confirmation.typed_confirmation {
+        let input_color = if confirmation.can_confirm() {
+            Color::Green
+        } else {
+            Color::White
+        };
+        let input = Paragraph::new(format!("{}█", confirmation.typed))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("Type '{}' to confirm", expected))
+                    .style(Style::default().bg(Color::DarkGray))
+                    .border_style(Style::default().fg(color)),
+            )
+            .style(Style::default().fg(input_color));
+        f.render_widget(input, chunks[4]);
+    }
+
+    // Buttons; the confirm button is dimmed until typed input matches
+    let confirm_style = if confirmation.can_confirm() {
+        Style::default().fg(color).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Gray)
+    };
+    let buttons = vec![Line::from(vec![
+        Span::styled("Enter", confirm_style),
+        Span::raw(": "),
+        Span::styled(confirmation.confirm_label.clone(), confirm_style),
+        Span::raw("    "),
+        Span::styled("Esc", Style::default().fg(Color::Green)),
+        Span::raw(": "),
+        Span::styled(
+            confirmation.cancel_label.clone(),
+            Style::default().fg(Color::Green),
+        ),
+    ])];
+
+    let button_widget = Paragraph::new(buttons)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Controls")
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .style(Style::default().fg(Color::White));
+    f.render_widget(button_widget, chunks[chunks.len() - 1]);
+}
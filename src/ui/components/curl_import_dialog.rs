@@ -0,0 +1,119 @@
+use crate::app::App;
+use crate::models::http_client::HttpRequestBody;
+use crate::services::curl_import::parse_curl_command;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+/// Render the "paste a cURL command" import dialog
+pub fn render_curl_import_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(70, 60, area);
+
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),    // Command
+            Constraint::Length(4), // Preview / error
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    // Command input
+    let (command_text, command_style) = if app.curl_import_text.is_empty() {
+        (
+            "Paste a curl command, e.g. curl -X POST https://api.example.com/items -d '{...}'"
+                .to_string(),
+            Style::default().fg(Color::DarkGray),
+        )
+    } else {
+        (
+            format!("{}▏", app.curl_import_text),
+            Style::default().fg(Color::White),
+        )
+    };
+    let command = Paragraph::new(command_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("📋 Import from cURL")
+                .title_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::DarkGray))
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .style(command_style)
+        .wrap(Wrap { trim: false });
+    f.render_widget(command, chunks[0]);
+
+    // Live preview of what will be imported
+    let preview_lines = if let Some(error) = &app.curl_import_error {
+        vec![Line::from(Span::styled(
+            format!("❌ {}", error),
+            Style::default().fg(Color::Red),
+        ))]
+    } else if app.curl_import_text.trim().is_empty() {
+        vec![Line::from(Span::styled(
+            "Method, -H headers, -d/--data-urlencode/-F/-T body and -u auth are imported",
+            Style::default().fg(Color::Gray),
+        ))]
+    } else {
+        match parse_curl_command(&app.curl_import_text) {
+            Ok(request) => {
+                let body = match &request.body {
+                    HttpRequestBody::None => "no body",
+                    HttpRequestBody::Json { .. } => "JSON body",
+                    HttpRequestBody::Form { .. } => "form body",
+                    HttpRequestBody::Multipart { .. } => "multipart body",
+                    HttpRequestBody::Text { .. } => "text body",
+                    HttpRequestBody::Raw { .. } => "raw body",
+                    HttpRequestBody::File { .. } => "file body",
+                };
+                vec![
+                    Line::from(Span::styled(
+                        format!("✅ {} {}", request.method.as_str(), request.url),
+                        Style::default().fg(Color::Green),
+                    )),
+                    Line::from(Span::styled(
+                        format!(
+                            "{} header(s), {} query param(s), {}, auth: {}",
+                            request.headers.len(),
+                            request.query_params.len(),
+                            body,
+                            request.auth.title()
+                        ),
+                        Style::default().fg(Color::Gray),
+                    )),
+                ]
+            }
+            Err(e) => vec![Line::from(Span::styled(
+                format!("⚠️  {}", e),
+                Style::default().fg(Color::Yellow),
+            ))],
+        }
+    };
+    let preview = Paragraph::new(preview_lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Preview")
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(preview, chunks[1]);
+
+    let instructions = Paragraph::new("Enter: Import  |  Del: Clear  |  Esc: Cancel")
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Controls")
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[2]);
+}
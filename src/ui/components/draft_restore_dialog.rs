@@ -0,0 +1,80 @@
+use crate::app::App;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+/// Render the "restore unsaved work" prompt shown after a crash or quit
+pub fn render_draft_restore_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let Some(draft) = &app.pending_draft else {
+        return;
+    };
+
+    let popup_area = centered_rect(60, 40, area);
+    f.render_widget(Clear, popup_area);
+
+    let request = &draft.content.http_request;
+    let url = if request.url.trim().is_empty() {
+        "(no URL)".to_string()
+    } else {
+        request.url.clone()
+    };
+    let filled_fields = draft
+        .content
+        .automation_fields
+        .iter()
+        .filter(|field| !field.value.is_empty())
+        .count();
+
+    let lines = vec![
+        Line::from(vec![
+            Span::raw("Unsaved work from "),
+            Span::styled(
+                app.format_timestamp_with_date(&draft.saved_at),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" was found."),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("HTTP request: ", Style::default().fg(Color::Cyan)),
+            Span::raw(format!(
+                "{} — {} {}",
+                request.name,
+                request.method.as_str(),
+                url
+            )),
+        ]),
+        Line::from(vec![
+            Span::styled("Automation form: ", Style::default().fg(Color::Cyan)),
+            Span::raw(format!("{} field(s) filled in", filled_fields)),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Enter/y", Style::default().fg(Color::Yellow)),
+            Span::raw(": Restore  |  "),
+            Span::styled("Esc/n", Style::default().fg(Color::Yellow)),
+            Span::raw(": Discard"),
+        ]),
+    ];
+
+    let dialog = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("💾 Restore Draft")
+                .title_style(Style::default().fg(Color::Yellow))
+                .style(Style::default().bg(Color::DarkGray))
+                .border_style(Style::default().fg(Color::Yellow)),
+        )
+        .style(Style::default().fg(Color::White))
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(dialog, popup_area);
+}
@@ -0,0 +1,140 @@
+use crate::app::App;
+use crate::ui::components::key_value_editor::render_key_value_editor;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+/// Render the environment variables editor
+pub fn render_environment_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(60, 60, area);
+
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Environments
+            Constraint::Length(3), // Proxy
+            Constraint::Min(5),    // Variables
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    // Every environment, the active one highlighted (or being renamed)
+    let active = app.config.active_environment_name();
+    let mut names = Vec::new();
+    for name in app.config.environment_names() {
+        if !names.is_empty() {
+            names.push(Span::styled("  ", Style::default()));
+        }
+        if name == active {
+            let label = match &app.environment_name_input {
+                Some(input) => format!(" {}▏", input),
+                None => format!(" {} ", name),
+            };
+            names.push(Span::styled(
+                label,
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        } else {
+            names.push(Span::styled(name, Style::default().fg(Color::Gray)));
+        }
+    }
+    let environments = Paragraph::new(Line::from(names)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("🌍 Environment")
+            .title_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::DarkGray)),
+    );
+    f.render_widget(environments, chunks[0]);
+
+    // The environment's own proxy, or the global one it inherits
+    let hint = Style::default().fg(Color::Gray);
+    let proxy_line = if let Some(input) = &app.environment_proxy_input {
+        Line::from(Span::styled(
+            format!("{}▏", input),
+            Style::default().fg(Color::Yellow).bg(Color::Black),
+        ))
+    } else {
+        let proxy = app.config.active_proxy();
+        let inherited = app.config.active_environment.is_some()
+            && app
+                .config
+                .environments
+                .iter()
+                .any(|env| env.name == active && env.proxy.is_empty());
+        if proxy.is_empty() {
+            Line::from(Span::styled(
+                "(none; host:port, socks5://host:port or \"direct\")",
+                hint,
+            ))
+        } else if inherited {
+            Line::from(vec![
+                Span::styled(proxy.display_url(), Style::default().fg(Color::White)),
+                Span::styled("  (global)", hint),
+            ])
+        } else {
+            Line::from(Span::styled(
+                proxy.display_url(),
+                Style::default().fg(Color::White),
+            ))
+        }
+    };
+    let proxy = Paragraph::new(proxy_line).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Proxy")
+            .title_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::DarkGray)),
+    );
+    f.render_widget(proxy, chunks[1]);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("Variables of {}", active))
+        .title_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::DarkGray));
+    f.render_widget(block, chunks[2]);
+
+    render_key_value_editor(
+        f,
+        chunks[2].inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        }),
+        &app.http_state.environment_vars,
+        &app.environment_editor,
+        " = ",
+        "No environment variables",
+        true,
+        app.reveal_secrets,
+    );
+
+    let instructions = if app.environment_name_input.is_some() {
+        "Type: Environment name  |  Enter: Save  |  Esc: Cancel"
+    } else if app.environment_proxy_input.is_some() {
+        "Type: Proxy URL (empty inherits, \"direct\" bypasses)  |  Enter: Save  |  Esc: Cancel"
+    } else if app.environment_editor.is_editing() {
+        "Type: Edit  |  Tab: Name/Value  |  Enter: Save  |  Esc: Cancel"
+    } else {
+        "a/e/t/d j/k: Variables  |  s: Secret  |  [/]: Switch  |  n: New env  |  r: Rename  |  p: Proxy  |  D: Delete env  |  Esc: Close"
+    };
+    let instructions = Paragraph::new(instructions)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Controls")
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[3]);
+}
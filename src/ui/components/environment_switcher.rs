@@ -0,0 +1,102 @@
+use crate::app::App;
+use crate::services::audit_log::is_production_environment;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// Render the environment switcher: every environment with its base URL, active one marked
+pub fn render_environment_switcher(f: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(60, 50, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),    // Environments
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let active = app.config.active_environment_name();
+    let items: Vec<ListItem> = app
+        .config
+        .environment_names()
+        .into_iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let variables = app.environment_variables_for(&name);
+            // The variable most likely to say where requests go
+            let base_url = variables.iter().find(|variable| {
+                let lower = variable.name.to_ascii_lowercase();
+                variable.enabled && (lower.contains("url") || lower.contains("host"))
+            });
+            let (marker, name_style) = if name == active {
+                (
+                    "● ",
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                ("  ", Style::default().fg(Color::White))
+            };
+
+            let mut spans = vec![
+                Span::styled(
+                    format!("{} ", index + 1),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(marker, Style::default().fg(Color::Green)),
+                Span::styled(name.clone(), name_style),
+            ];
+            if is_production_environment(&name) {
+                spans.push(Span::styled(
+                    " PROD",
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                ));
+            }
+            spans.push(Span::styled(
+                format!("  {} variable(s)", variables.len()),
+                Style::default().fg(Color::Gray),
+            ));
+            if let Some(variable) = base_url {
+                spans.push(Span::styled(
+                    format!("  {} = {}", variable.name, variable.value),
+                    Style::default().fg(Color::Cyan),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("🌍 Switch Environment")
+                .title_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::DarkGray))
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .highlight_style(Style::default().bg(Color::Blue));
+    let mut list_state =
+        ListState::default().with_selected(Some(app.environment_switcher_selected));
+    f.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    let instructions = Paragraph::new(
+        "j/k: Move  |  Enter: Switch  |  1-9: Switch directly  |  e: Edit variables  |  Esc: Close",
+    )
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Controls")
+            .style(Style::default().bg(Color::DarkGray)),
+    )
+    .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[1]);
+}
@@ -0,0 +1,35 @@
+use crate::models::stepper::NumberRange;
+use ratatui::{
+    style::{Color, Style},
+    text::Span,
+};
+
+/// A `[x]` / `[ ]` checkbox
+pub fn checkbox_span(on: bool) -> Span<'static> {
+    Span::styled(
+        if on { "[x]" } else { "[ ]" },
+        Style::default().fg(Color::Green),
+    )
+}
+
+/// A `◀ value ▶` stepper; an arrow is dimmed when the value sits at that bound
+pub fn stepper_spans(value: &str, range: NumberRange, value_style: Style) -> Vec<Span<'static>> {
+    let parsed = value.trim().parse::<i64>().ok();
+    let arrow_style = |enabled: bool| {
+        if enabled {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        }
+    };
+
+    vec![
+        Span::styled("◀ ", arrow_style(parsed.is_none_or(|v| v > range.min))),
+        Span::styled(value.to_string(), value_style),
+        Span::styled(" ▶", arrow_style(parsed.is_none_or(|v| v < range.max))),
+        Span::styled(
+            format!("  ({}-{})", range.min, range.max),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]
+}
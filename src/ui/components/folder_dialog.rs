@@ -1,10 +1,10 @@
 // Folder creation dialog component (add to ui/components/folder_dialog.rs)
 use crate::app::App;
+use crate::ui::layout::centered_rect;
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
-    text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
 };
 
@@ -91,24 +91,3 @@ pub fn render_folder_creation_dialog(f: &mut Frame, area: Rect, app: &App) {
         .style(Style::default().fg(Color::Gray));
     f.render_widget(instructions, chunks[4]);
 }
-
-/// Helper function to create a centered rectangle
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
-}
@@ -0,0 +1,31 @@
+use crate::app::App;
+use crate::ui::components::markdown::markdown_lines;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+/// Render the README.md of the folder focused in the tree
+pub fn render_folder_readme(f: &mut Frame, area: Rect, app: &App) {
+    let Some(readme) = app.folder_readme.as_ref() else {
+        return;
+    };
+    let text = readme.text.as_deref().unwrap_or_default();
+
+    let panel = Paragraph::new(markdown_lines(text))
+        .wrap(Wrap { trim: false })
+        .scroll((app.folder_readme_scroll, 0))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    "📖 {}/README.md (PgUp/PgDn: scroll)",
+                    readme.folder
+                ))
+                .title_style(Style::default().fg(Color::Cyan))
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+    f.render_widget(panel, area);
+}
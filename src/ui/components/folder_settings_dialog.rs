@@ -0,0 +1,81 @@
+use crate::app::App;
+use crate::ui::components::key_value_editor::render_key_value_editor;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+/// Render the variables (and auth summary) a collection folder passes to its requests
+pub fn render_folder_settings_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let Some(dialog) = app.folder_settings_dialog.as_ref() else {
+        return;
+    };
+    let popup_area = centered_rect(60, 60, area);
+
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Auth
+            Constraint::Min(5),    // Variables
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let auth = Paragraph::new(Line::from(vec![
+        Span::styled(dialog.auth.title(), Style::default().fg(Color::White)),
+        Span::styled(
+            "  (used by requests without auth of their own)",
+            Style::default().fg(Color::Gray),
+        ),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("📁 Auth of {}", dialog.folder))
+            .title_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::DarkGray)),
+    );
+    f.render_widget(auth, chunks[0]);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Variables (the environment's values win)")
+        .title_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::DarkGray));
+    f.render_widget(block, chunks[1]);
+
+    render_key_value_editor(
+        f,
+        chunks[1].inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        }),
+        &dialog.variables,
+        &dialog.editor,
+        " = ",
+        "No folder variables",
+        true,
+        app.reveal_secrets,
+    );
+
+    let instructions = if dialog.editor.is_editing() {
+        "Type: Edit  |  Tab: Name/Value  |  Enter: Save  |  Esc: Cancel"
+    } else {
+        "a/e/v/t/d j/k: Variables  |  s: Secret  |  u: Auth in $EDITOR  |  Esc: Save and close"
+    };
+    let instructions = Paragraph::new(instructions)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Controls")
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[2]);
+}
@@ -0,0 +1,134 @@
+use crate::app::App;
+use crate::models::SearchCategory;
+use crate::models::search::SendCommand;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// Render the global search overlay: query input plus ranked, badged results
+pub fn render_global_search(f: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(70, 70, area);
+    f.render_widget(Clear, popup_area);
+    let send_command = SendCommand::parse(&app.global_search_query);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Query
+            Constraint::Min(3),    // Results
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let query = Paragraph::new(Line::from(vec![
+        Span::styled("🔎 ", Style::default().fg(Color::Cyan)),
+        Span::styled(
+            format!("{}█", app.global_search_query),
+            Style::default().fg(Color::White),
+        ),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(match &send_command {
+                Some(_) => "Send Request".to_string(),
+                None => format!(
+                    "Search Everything ({} indexed)",
+                    app.global_search_entries.len()
+                ),
+            })
+            .title_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::DarkGray))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(query, chunks[0]);
+
+    let detail_width = chunks[1].width.saturating_sub(4) as usize;
+    let items: Vec<ListItem> = app
+        .global_search_results
+        .iter()
+        .filter_map(|&index| app.global_search_entries.get(index))
+        .map(|entry| {
+            let color = badge_color(entry.category);
+            let mut detail: String = entry
+                .detail
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ");
+            if detail.chars().count() > detail_width {
+                detail = detail
+                    .chars()
+                    .take(detail_width.saturating_sub(1))
+                    .collect();
+                detail.push('…');
+            }
+            ListItem::new(vec![
+                Line::from(vec![
+                    Span::styled(
+                        format!("[{}] ", entry.category.badge()),
+                        Style::default().fg(color).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(entry.title.clone(), Style::default().fg(Color::White)),
+                ]),
+                Line::from(Span::styled(
+                    format!("  {}", detail),
+                    Style::default().fg(Color::Gray),
+                )),
+            ])
+        })
+        .collect();
+
+    let results_title = match &send_command {
+        Some(command) if !command.summary().is_empty() => format!(
+            "Requests ({}) - send with {}",
+            app.global_search_results.len(),
+            command.summary()
+        ),
+        Some(_) => format!("Requests ({})", app.global_search_results.len()),
+        None if app.global_search_query.trim().is_empty() => "Results".to_string(),
+        None => format!("Results ({})", app.global_search_results.len()),
+    };
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(results_title)
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .highlight_style(Style::default().bg(Color::Blue));
+    let mut list_state = ListState::default();
+    if !app.global_search_results.is_empty() {
+        list_state.select(Some(app.global_search_selected));
+    }
+    f.render_stateful_widget(list, chunks[1], &mut list_state);
+
+    let instructions = if send_command.is_some() {
+        "send <request> name=value env=<environment>  |  ↑/↓: Select  |  Enter: Send  |  Esc: Close"
+    } else {
+        "Type to search (or send <request> …)  |  ↑/↓: Select  |  Enter: Jump  |  Esc: Close"
+    };
+    let instructions = Paragraph::new(instructions)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Controls")
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[2]);
+}
+
+fn badge_color(category: SearchCategory) -> Color {
+    match category {
+        SearchCategory::Template => Color::Green,
+        SearchCategory::Request => Color::Cyan,
+        SearchCategory::Folder => Color::Yellow,
+        SearchCategory::Log => Color::Magenta,
+        SearchCategory::Help => Color::LightBlue,
+    }
+}
@@ -0,0 +1,118 @@
+use crate::app::App;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+/// Render the health panel: what was loaded on launch and anything that looks broken
+pub fn render_health_panel(f: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(70, 60, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),    // Report
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let heading = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+    let ok = Style::default().fg(Color::Green);
+    let warn = Style::default().fg(Color::Yellow);
+
+    let mut lines = Vec::new();
+    match &app.health_report {
+        None => lines.push(Line::from(Span::styled(
+            "Checking...",
+            Style::default().fg(Color::Gray),
+        ))),
+        Some(report) => {
+            lines.push(Line::from(vec![
+                Span::raw("Templates: "),
+                Span::styled(report.templates.to_string(), ok),
+                Span::raw("    Requests: "),
+                Span::styled(report.requests.to_string(), ok),
+            ]));
+            lines.push(Line::from(vec![
+                Span::raw("Environment: "),
+                Span::styled(report.environment.clone(), ok),
+                Span::raw(format!(" ({} variables)", report.environment_variables)),
+            ]));
+            lines.push(match &report.python {
+                Ok(version) => Line::from(vec![
+                    Span::raw("Python: "),
+                    Span::styled(version.clone(), ok),
+                ]),
+                Err(error) => Line::from(vec![
+                    Span::raw("Python: "),
+                    Span::styled(
+                        format!("✗ {} (automation won't run)", error),
+                        Style::default().fg(Color::Red),
+                    ),
+                ]),
+            });
+
+            let sections = [
+                ("Config warnings", &report.config_warnings),
+                ("Broken files", &report.broken_files),
+            ];
+            for (title, items) in sections {
+                if items.is_empty() {
+                    continue;
+                }
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(
+                    format!("▶ {} ({})", title, items.len()),
+                    heading,
+                )));
+                for item in items {
+                    lines.push(Line::from(vec![
+                        Span::styled("  • ", warn),
+                        Span::raw(item.clone()),
+                    ]));
+                }
+            }
+            if report.problem_count() == 0 {
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled("✓ No problems found", ok)));
+            }
+        }
+    }
+
+    let report = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("🩺 Startup Health")
+                .title_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .style(Style::default().fg(Color::White))
+        .wrap(Wrap { trim: false })
+        .scroll((app.health_panel_scroll, 0));
+    f.render_widget(report, chunks[0]);
+
+    let instructions = Paragraph::new(Line::from(vec![
+        Span::styled("j/k", Style::default().fg(Color::Yellow)),
+        Span::raw(": Scroll  "),
+        Span::styled("r", Style::default().fg(Color::Yellow)),
+        Span::raw(": Check again  "),
+        Span::styled("Esc/Enter", Style::default().fg(Color::Yellow)),
+        Span::raw(": Close"),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Controls")
+            .style(Style::default().bg(Color::DarkGray)),
+    )
+    .style(Style::default().fg(Color::White));
+    f.render_widget(instructions, chunks[1]);
+}
@@ -1,4 +1,5 @@
-use crate::app::{App, FocusedPane, InputMode};
+use crate::app::App;
+use crate::ui::layout::centered_rect;
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
@@ -138,7 +139,7 @@ fn render_help_content(f: &mut Frame, area: Rect, app: &App) {
 
     let items: Vec<ListItem> = filtered_sections
         .iter()
-        .flat_map(|section| create_section_items(section))
+        .flat_map(create_section_items)
         .collect();
 
     let help_list = List::new(items).block(
@@ -157,6 +158,8 @@ fn render_help_instructions(f: &mut Frame, area: Rect) {
         Span::raw(": Switch sections  "),
         Span::styled("Type", Style::default().fg(Color::Yellow)),
         Span::raw(": Search  "),
+        Span::styled("Ctrl+T", Style::default().fg(Color::Yellow)),
+        Span::raw(": Tutorial  "),
         Span::styled("Esc/?", Style::default().fg(Color::Yellow)),
         Span::raw(": Close help"),
     ])];
@@ -174,7 +177,7 @@ fn render_help_instructions(f: &mut Frame, area: Rect) {
 }
 
 /// Get all help sections with comprehensive keybindings including HTTP client
-fn get_help_sections() -> Vec<HelpSection> {
+pub fn get_help_sections() -> Vec<HelpSection> {
     vec![
         HelpSection {
             title: "Global Shortcuts".to_string(),
@@ -184,8 +187,44 @@ fn get_help_sections() -> Vec<HelpSection> {
                 HelpItem::new("J/K", "Focus next/previous pane (circular)"),
                 HelpItem::new("F1", "Switch to Automation mode"),
                 HelpItem::new("F4", "Switch to HTTP Client mode"),
-                HelpItem::new("F5/F6/F7", "Focus Collections/Form/Logs directly"),
+                HelpItem::new("F5/F6/F7", "Focus Collections/Form/Logs directly")
+                    .with_example("Below 80x20 only the focused pane is shown; these keys switch between panes"),
                 HelpItem::new("?", "Show this help dialog"),
+                HelpItem::new("? → Ctrl+T", "Start (or stop) the guided tutorial")
+                    .with_example("Walks through creating a folder, saving a template, importing a collection and sending a request"),
+                HelpItem::new("F9", "Open settings (templates/collections directories)")
+                    .with_example("Change storage locations, move existing data, or save new items as YAML"),
+                HelpItem::new("F10", "Show What's New (embedded changelog)"),
+                HelpItem::new("Ctrl+G", "Toggle the performance overlay")
+                    .with_example("Render time, event-loop latency, message queue depth and buffer sizes"),
+                HelpItem::new("F11", "Startup health: items loaded, python, config warnings, broken files")
+                    .with_example("Opens by itself on launch when something needs attention; r checks again"),
+                HelpItem::new("config.json", "startup_actions: open straight into your daily workflow")
+                    .with_example(r#"[{"switch_mode":"http"},{"activate_environment":"staging"},{"open":"users/List users"},{"focus":"form"}]"#),
+                HelpItem::new("F9 → Backups", "Snapshot templates and collections on a schedule; r restores one")
+                    .with_example("Daily, keep last 7 by default; restoring backs up the current state first"),
+                HelpItem::new("--safe-mode", "Launch with the default config, no scripts and read-only storage")
+                    .with_example("Recover when a bad config or corrupted state file stops the app from starting"),
+                HelpItem::new(
+                    "Ctrl+L",
+                    "Automation: toggle timeline/output layout during runs",
+                ),
+                HelpItem::new(
+                    "Ctrl+K",
+                    "Search everything: items, URLs, field values, logs, help",
+                )
+                .with_example("Type to rank results, ↑/↓ to select, Enter to jump"),
+                HelpItem::new("Ctrl+K send …", "Send a saved request with one-off variables")
+                    .with_example("send users/get-user id=42 env=prod - overrides and environment apply to this send only"),
+                HelpItem::new("Ctrl+A", "Audit log: deletes, renames, overwrites, credential changes, prod sends")
+                    .with_example("Append-only audit.log in the data directory; sends count as prod when the environment name has a 'prod' or 'production' word"),
+                HelpItem::new("Ctrl+O", "Quick switcher: jump to a recently opened item")
+                    .with_example("Ctrl+Tab also works in terminals that report it"),
+                HelpItem::new(
+                    "Alt+← / Alt+→",
+                    "Go back/forward through visited panes and items",
+                )
+                .with_example("Returns to the exact tree position you jumped from (Alt+b/f too)"),
             ],
         },
         HelpSection {
@@ -201,10 +240,18 @@ fn get_help_sections() -> Vec<HelpSection> {
                 HelpItem::new("Ctrl+F", "Create new folder")
                     .with_example("Creates subfolder in currently selected location"),
                 HelpItem::new("F2 or R", "Rename selected item"),
-                HelpItem::new("Delete", "Delete selected item (with confirmation)"),
+                HelpItem::new("d", "Duplicate the selected template as \"<name> copy\" and rename it"),
+                HelpItem::new("n", "Add or edit a note on the selected item")
+                    .with_example("Tab switches to the review date; Ctrl+S saves"),
+                HelpItem::new("Delete", "Delete selected item (with confirmation)")
+                    .with_example("Non-empty folders ask you to type the folder name first"),
                 HelpItem::new("Ctrl+X", "Cut item to clipboard"),
                 HelpItem::new("Ctrl+C", "Copy item to clipboard"),
                 HelpItem::new("Ctrl+V", "Paste item from clipboard"),
+                HelpItem::new("x", "Export the focused template as a bundle (Automation)")
+                    .with_example("Template, website config and script name in one checksummed .tuibundle under exports/"),
+                HelpItem::new("Ctrl+I or F8", "Import a template bundle (Automation)")
+                    .with_example("Edited or corrupted bundles are rejected; existing templates are never overwritten"),
                 HelpItem::new("F12", "Refresh tree from storage"),
             ],
         },
@@ -215,8 +262,19 @@ fn get_help_sections() -> Vec<HelpSection> {
                 HelpItem::new("j/k", "Navigate to next/previous field (Vim style)"),
                 HelpItem::new("Tab/Shift+Tab", "Navigate to next/previous field"),
                 HelpItem::new("Delete", "Clear current field completely"),
+                HelpItem::new("+/- or ←/→", "Step a number field within its range"),
+                HelpItem::new("Space or Enter", "Toggle a checkbox field"),
+                HelpItem::new("E", "Edit the focused text field in $EDITOR"),
+                HelpItem::new("I", "Show the loaded template's field docs")
+                    .with_example("Templates can describe fields under field_docs; the focused field's help shows below it"),
                 HelpItem::new("Ctrl+N", "Save current form as new template"),
+                HelpItem::new("p", "Input presets: apply, save (s) or delete (D) field value sets")
+                    .with_example("A preset keeps only what differs from the loaded template and layers on top of any template"),
                 HelpItem::new("F3", "Start automation with current form data"),
+                HelpItem::new("Ctrl+R", "Run queue: runs started while another is running")
+                    .with_example("j/k select, Shift+J/K reorder, d cancels; queued runs start one after another"),
+                HelpItem::new("Auto-lock", "Credentials are cleared after the idle time set in Settings")
+                    .with_example("Off by default; a locked session asks for a new login before the next run"),
                 HelpItem::new("Ctrl+C", "Set demo credentials (temporary)"),
                 HelpItem::new("Ctrl+X", "Clear credentials"),
             ],
@@ -228,6 +286,7 @@ fn get_help_sections() -> Vec<HelpSection> {
                 HelpItem::new("←/→", "Move cursor left/right within field"),
                 HelpItem::new("Home/End", "Jump to start/end of field"),
                 HelpItem::new("Backspace", "Delete character before cursor"),
+                HelpItem::new("↑/↓", "Step a number field while editing it"),
                 HelpItem::new(
                     "Tab/Shift+Tab",
                     "Move to next/previous field (stay in edit mode)",
@@ -241,6 +300,8 @@ fn get_help_sections() -> Vec<HelpSection> {
             title: "HTTP Request Editor - Normal Mode".to_string(),
             items: vec![
                 HelpItem::new("i or Enter", "Enter edit mode for URL input"),
+                HelpItem::new("U", "Edit a long URL in an expanded popup")
+                    .with_example("Wraps the whole URL and lists its decoded query parameters"),
                 HelpItem::new(
                     "Tab/Shift+Tab",
                     "Navigate request tabs (Headers/Body/Query/Auth/Settings)",
@@ -252,19 +313,119 @@ fn get_help_sections() -> Vec<HelpSection> {
                     .with_example("1=GET, 2=POST, 3=PUT, 4=DELETE"),
                 HelpItem::new("Space or F3", "Send HTTP request")
                     .with_example("Execute the current request and show response"),
+                HelpItem::new("P", "Send once bypassing the configured proxy")
+                    .with_example("The response Info tab shows the proxy, protocol and certificate fingerprint"),
                 HelpItem::new("Ctrl+N", "Create new HTTP request"),
                 HelpItem::new("Delete", "Clear current tab content")
                     .with_example("Clear headers, body, or query params depending on active tab"),
+                HelpItem::new(
+                    "b",
+                    "Cycle body type on the Body tab (None/JSON/Text/Form/Multipart/Raw/File)",
+                )
+                .with_example("File streams a file as the raw body; type its path in the editor"),
+                HelpItem::new("R (Headers tab)", "Edit all headers as raw 'Name: Value' lines")
+                    .with_example("Paste a block copied from browser devtools; # disables a line; Ctrl+S applies"),
+                HelpItem::new("G (Headers tab)", "Edit default headers sent with every request")
+                    .with_example("e.g. User-Agent or a trace header; a request's own header of the same name wins, even disabled"),
+                HelpItem::new("E", "Open the body or Script/Tests/Extract text in $EDITOR")
+                    .with_example("Uses $VISUAL, then $EDITOR (e.g. EDITOR=\"code --wait\"); the text reloads when the editor exits"),
+                HelpItem::new("B (JSON body)", "Switch between the raw JSON editor and builder rows")
+                    .with_example("user.name = Ada / tags[0] = admin / age = 36 / id = \"42\" (quoted stays a string)"),
+                HelpItem::new("f (Multipart body)", "Switch the selected part between text and file upload")
+                    .with_example("A file part's value is its path, e.g. ~/reports/q3.pdf"),
+                HelpItem::new("c (Auth tab)", "Cycle auth type, including Custom (script)")
+                    .with_example("j/k selects a field, i edits it, l toggles API key location"),
+                HelpItem::new("g / f (Auth tab)", "OAuth 2.0: get a new access token / forget the cached one")
+                    .with_example("Tokens are cached for the session and refreshed before they expire"),
+                HelpItem::new("Cookies tab", "Cookie jar shared by all requests (Delete clears it)")
+                    .with_example("Set-Cookie responses are sent back automatically; F9 keeps them between sessions"),
+                HelpItem::new("Script tab", "Pre-request script run before every send")
+                    .with_example("set ts = now() / header X-Signature = hmac_sha256(env(\"KEY\"), url() + ts)"),
+                HelpItem::new("Tests tab", "Assertions checked after the response, one per line")
+                    .with_example("status == 200 / header Content-Type contains json / json $.data.id == 42"),
+                HelpItem::new("Tests tab (schema)", "Validate the JSON body against a JSON Schema, one result per violation")
+                    .with_example("schema ~/schemas/user.json / schema {\"type\": \"object\", \"required\": [\"id\"]}"),
+                HelpItem::new("Extract tab", "Save response values as environment variables")
+                    .with_example("token = $.data.access_token / request_id = header X-Request-Id"),
+                HelpItem::new("Docs tab", "Notes about the endpoint, shown as formatted markdown")
+                    .with_example("i edits the raw text, E opens it in $EDITOR; saved as the request's description"),
+                HelpItem::new("{{name}}", "Filled in from script and environment variables (Ctrl+E)"),
+                HelpItem::new("Settings tab", "Timeout, redirects, SSL verification and user agent")
+                    .with_example("j/k selects a row, t toggles, +/- steps numbers, i edits the user agent"),
+                HelpItem::new("Settings tab (retries)", "Resend on 5xx or connection errors with exponential backoff")
+                    .with_example("0 attempts uses the global policy (F9); each retry is logged and waits twice as long"),
+                HelpItem::new("Settings tab (conditional GET)", "Revalidate cached GET/HEAD responses with their ETag/Last-Modified")
+                    .with_example("A 304 Not Modified shows the cached body marked \"served from cache\"; t turns it off per request"),
+                HelpItem::new("Settings tab (certificates)", "CA bundle and client certificate (mTLS) for this request")
+                    .with_example("PEM cert + key or a .p12/.pfx bundle; blank rows use \"tls\" from config.json"),
+                HelpItem::new("Settings tab (proxy)", "HTTP or SOCKS proxy and its login for this request")
+                    .with_example("proxy.corp:3128 or socks5h://jump:1080; \"direct\" bypasses the environment's proxy"),
+                HelpItem::new("p (Settings tab)", "Choose a pagination rule").with_example(
+                    "Link header, JSON next URL (next_page_url), cursor field or page parameter; +/- sets max pages",
+                ),
+                HelpItem::new("a (Settings tab)", "Auto-follow: every send fetches all pages")
+                    .with_example("The response title counts pages as they arrive; Ctrl+B cancels"),
+                HelpItem::new("Ctrl+P", "Fetch all pages and aggregate the items"),
+                HelpItem::new("Ctrl+E", "Edit environment variables").with_example(
+                    "[/] switches environment, n adds one, r renames it, p sets its proxy, D deletes it",
+                ),
+                HelpItem::new("s (Environment)", "Flag the selected variable as secret (🔒)")
+                    .with_example("Secret values, auth tokens and credential headers show as ••• in the editor and response, and are scrubbed from logs"),
+                HelpItem::new("Alt+s", "Reveal or mask secret values"),
+                HelpItem::new("Alt+e", "Switch environment from a list, 1-9 picks one directly")
+                    .with_example("Shows each environment's base URL variable; the status line always names the active one"),
+                HelpItem::new("Ctrl+U", "Import a request from a pasted cURL command"),
+                HelpItem::new("y", "Copy the request as cURL, Python, JavaScript or Rust")
+                    .with_example("Pick a format in the popup; Enter copies it to the clipboard"),
+                HelpItem::new("Ctrl+R", "Browse the history of sent requests").with_example(
+                    "Enter re-opens, s re-sends, c diffs, d deletes; retention is set in Settings (F9)",
+                ),
+                HelpItem::new("Ctrl+Y", "Recent sends palette: fuzzy-find a sent request by method, URL or status")
+                    .with_example("Enter or Alt+1-9 re-sends it, Tab loads it into the editor; each method + URL is listed once"),
+                HelpItem::new("Alt+.", "Re-send the last sent request from anywhere")
+                    .with_example("Switches back to its tab if still open, otherwise re-opens it from history"),
+                HelpItem::new("Alt+t", "Load test: send the current request N times, C at a time")
+                    .with_example("Shows p50/p95/p99 latency, error rate and req/s; c cancels, Esc leaves it running"),
+                HelpItem::new("Request log", "Every send is appended to requests.log as a curl command")
+                    .with_example("Credentials are redacted unless enabled under Request Log in Settings (F9)"),
+                HelpItem::new("Ctrl+T", "Show bytes sent and received this session")
+                    .with_example("Per-response sizes are on the response Info tab"),
+                HelpItem::new("Info tab", "Timing breakdown: prepare, DNS, connect + wait, download")
+                    .with_example("Connect and TLS time are included in 'connect + wait' with the server's own time"),
+                HelpItem::new("Ctrl+L", "Toggle side-by-side request/response layout"),
+                HelpItem::new("Ctrl+W", "Toggle focus between request editor and response"),
+                HelpItem::new("Alt+h / Alt+l", "Switch between open request tabs")
+                    .with_example("Loading a request opens it in a tab; Ctrl+N opens a blank one; tabs come back on restart"),
+                HelpItem::new("Alt+Shift+H / Alt+Shift+L", "Move the current tab left / right"),
+                HelpItem::new("Alt+r / Alt+w", "Rename the current tab (the stored name stays) / close it"),
+            ],
+        },
+        HelpSection {
+            title: "Key/Value Lists (Headers, Query, Form, Variables)".to_string(),
+            items: vec![
+                HelpItem::new("j/k or ↑/↓", "Select row"),
+                HelpItem::new("a", "Add a row and start typing its name"),
+                HelpItem::new("e / v", "Edit name / value of the selected row"),
+                HelpItem::new("Tab", "While editing: switch between name and value"),
+                HelpItem::new("Tab (header name)", "Complete a common header name first")
+                    .with_example("auth⇥ → Authorization, cont⇥ → Content- (matches listed below the rows)"),
+                HelpItem::new("Enter / Esc", "While editing: save / cancel"),
+                HelpItem::new("t", "Enable or disable the selected row"),
+                HelpItem::new("d", "Delete the selected row"),
             ],
         },
         HelpSection {
             title: "HTTP Request Editor - Edit Mode".to_string(),
             items: vec![
-                HelpItem::new("Esc", "Exit edit mode, return to normal mode"),
+                HelpItem::new("Esc", "Exit edit mode, return to normal mode")
+                    .with_example("JSON bodies are validated here; the cursor jumps to any error"),
                 HelpItem::new("Type", "Edit URL or current field content"),
+                HelpItem::new("Enter / ←/→", "New line / move cursor in the Body tab"),
                 HelpItem::new("Backspace", "Delete characters"),
                 HelpItem::new("Tab/Shift+Tab", "Switch tabs while staying in edit mode"),
                 HelpItem::new("F3", "Send request from edit mode"),
+                HelpItem::new("Ctrl+B", "Cancel the request being sent (or its page fetch)")
+                    .with_example("Stops waiting on a hung server; a response already on its way is dropped"),
                 HelpItem::new("Ctrl+N", "Save request as new item"),
             ],
         },
@@ -280,9 +441,35 @@ fn get_help_sections() -> Vec<HelpSection> {
                     .with_example("Copy JSON or text response for use elsewhere"),
                 HelpItem::new("Delete", "Clear current response")
                     .with_example("Remove response to prepare for new request"),
-                HelpItem::new("j/k or ↑/↓", "Scroll through response content")
-                    .with_example("Navigate long responses or header lists"),
+                HelpItem::new("j/k or ↑/↓", "Move the cursor through a formatted JSON body")
+                    .with_example("The JSONPath of the line under the cursor is shown above the body"),
                 HelpItem::new("g/G", "Jump to top/bottom of response"),
+                HelpItem::new("y", "Copy the JSONPath under the cursor")
+                    .with_example("Paste it into an extraction rule or a test assertion"),
+                HelpItem::new("r", "Toggle raw / formatted body")
+                    .with_example("JSON is detected, pretty-printed and syntax highlighted"),
+                HelpItem::new("Binary bodies", "Shown as a hex + ASCII dump; j/k scroll, r shows the decoded text")
+                    .with_example("Images, octet-stream, protobuf and anything that isn't UTF-8; s saves the exact bytes"),
+                HelpItem::new("Image bodies", "Shown inline in iTerm2-compatible terminals, and in kitty for PNG only; otherwise as dimensions and size")
+                    .with_example("Sixel terminals aren't supported and nothing is converted, so a JPEG in kitty gets the metadata view; r switches to the hex dump"),
+                HelpItem::new("t", "Toggle the collapsible JSON tree view")
+                    .with_example("Enter/Space folds a node, h/l collapse/expand, h on a leaf goes to its parent"),
+                HelpItem::new("Y", "Copy the value under the tree cursor")
+                    .with_example("Strings are copied unquoted, objects and arrays as pretty JSON"),
+                HelpItem::new(
+                    "/",
+                    "Filter the body with JSONPath or a jq-style expression",
+                )
+                .with_example("$.items[0].name, $..id, .items[] | .name, .items | length"),
+                HelpItem::new("f then n/N", "Find text in the body, then jump to the next/previous match")
+                    .with_example("Case-insensitive and searches the view as shown - filtered, raw or formatted"),
+                HelpItem::new("d", "Diff against the previous response of this request")
+                    .with_example("Or press c on an entry in History (Ctrl+R) to diff against it"),
+                HelpItem::new("s", "Save response body to the exports directory")
+                    .with_example("Bodies over the display limit (F9) show truncated; s still saves the full body"),
+                HelpItem::new("b / B", "Export a bug report (Markdown / JSON)").with_example(
+                    "Request, the full response body, timing and environment with secrets redacted",
+                ),
             ],
         },
         HelpSection {
@@ -301,6 +488,9 @@ fn get_help_sections() -> Vec<HelpSection> {
                 HelpItem::new("/", "Enter search mode")
                     .with_example("Type to filter logs, Esc to exit"),
                 HelpItem::new("Ctrl+C", "Clear current search filter"),
+                HelpItem::new("m", "Insert a marker line")
+                    .with_example("Mark the start of a run attempt"),
+                HelpItem::new("t", "Cycle time filter: all → 5m → 15m → 1h → since marker"),
             ],
         },
         HelpSection {
@@ -345,17 +535,39 @@ fn get_help_sections() -> Vec<HelpSection> {
                 HelpItem::new("Ctrl+N", "Save current request to collection")
                     .with_example("Fill request editor, then Ctrl+N to save"),
                 HelpItem::new("Ctrl+F", "Create new folder"),
-                HelpItem::new("Ctrl+I or F8", "Import Postman collection") // NEW
-                    .with_example("Import .json collection files from Postman"),
+                HelpItem::new("Ctrl+I or F8", "Import a Postman, OpenAPI, Insomnia or Bruno collection") // NEW
+                    .with_example(
+                        "Postman .json, OpenAPI 3.x / Swagger .json or .yaml, Insomnia v4 exports, Bruno folders",
+                    ),
                 HelpItem::new("F2 or R", "Rename collection item"),
+                HelpItem::new("d", "Duplicate the selected request as \"<name> copy\" and rename it"),
+                HelpItem::new("n", "Add or edit a note on the focused item, optionally with a review date")
+                    .with_example("Review by 2025-03-01, +3d or +2w; when due, the status line shows a reminder and the tree a ⏰"),
                 HelpItem::new("Delete", "Delete collection item"),
                 HelpItem::new("Ctrl+X/C/V", "Cut/copy/paste collection items"),
+                HelpItem::new("x", "Run every request in the focused folder in order")
+                    .with_example("Shows pass/fail and timings per request; X re-opens the results"),
+                HelpItem::new("E", "Bind the focused folder to an environment (cycles, then none)")
+                    .with_example("Loading a request below it switches to that environment; the status line flags overrides"),
+                HelpItem::new("A", "Edit the auth and variables every request in the focused folder inherits")
+                    .with_example("Variables are edited in place (u opens the auth as JSON in $EDITOR); a request's own auth and the environment's values win"),
+                HelpItem::new("l", "Cycle the focused folder's color label (none, then each color)"),
+                HelpItem::new("+ / -", "Move the focused folder above or below its alphabetical place")
+                    .with_example("Raises or lowers its sort weight; heavier folders sort first, stored in the folder's .folder file"),
+                HelpItem::new("c", "Clone the focused request to a folder, optionally rewritten for another environment")
+                    .with_example("e picks the environment; literal values of the source environment are swapped for the target's"),
+                HelpItem::new("PgUp/PgDn", "Scroll the focused folder's README.md")
+                    .with_example("A folder with a README.md shows it in place of the form while focused in the tree"),
                 HelpItem::new("F12", "Refresh collections from storage"),
             ],
         },
         HelpSection {
-            title: "Import Postman Collections".to_string(), // NEW section
+            title: "Import Collections (Postman / OpenAPI / Insomnia / Bruno)".to_string(), // NEW section
             items: vec![
+                HelpItem::new("Ctrl+F", "Cycle Postman, OpenAPI, Insomnia and Bruno formats")
+                    .with_example("OpenAPI imports one request per operation, grouped by tag"),
+                HelpItem::new("Type", "Enter an Insomnia export or a Bruno collection folder")
+                    .with_example("Detected automatically; request groups and subfolders become folders, {{ _.var }} becomes {{var}}"),
                 HelpItem::new("Type", "Enter file path to .json collection")
                     .with_example("/Users/username/Downloads/my-api.postman_collection.json"),
                 HelpItem::new("Tab", "Auto-suggest common file locations")
@@ -365,7 +577,8 @@ fn get_help_sections() -> Vec<HelpSection> {
                 HelpItem::new("Ctrl+.", "Quick path to current directory"),
                 HelpItem::new("Enter", "Import the collection (if valid)")
                     .with_example("Creates folders and requests in HTTP collections tree"),
-                HelpItem::new("Esc", "Cancel import dialog"),
+                HelpItem::new("Esc", "Cancel import dialog, or stop a running import")
+                    .with_example("Postman files are streamed with progress; requests already written are kept"),
                 HelpItem::new("Delete", "Clear current file path"),
             ],
         },
@@ -404,7 +617,7 @@ fn filter_help_sections(sections: &[HelpSection], app: &App) -> Vec<HelpSection>
 }
 
 /// Create list items for a help section
-fn create_section_items(section: &HelpSection) -> Vec<ListItem> {
+fn create_section_items(section: &HelpSection) -> Vec<ListItem<'_>> {
     let mut items = Vec::new();
 
     // Section header
@@ -440,24 +653,3 @@ fn create_section_items(section: &HelpSection) -> Vec<ListItem> {
 
     items
 }
-
-/// Helper function to create a centered rectangle
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
-}
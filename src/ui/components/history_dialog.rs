@@ -0,0 +1,156 @@
+use crate::app::App;
+use crate::services::history_storage::HistoryEntry;
+use crate::ui::components::http_response_viewer::format_bytes;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// Render the request history browser, newest first, with details of the selection
+pub fn render_history_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(80, 75, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),    // Entries
+            Constraint::Length(5), // Selected entry
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let items: Vec<ListItem> = app
+        .history_entries
+        .iter()
+        .rev()
+        .map(|entry| {
+            let status = match entry.status_code {
+                Some(code) => format!("{:>3}", code),
+                None => "ERR".to_string(),
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{} ", app.format_timestamp_with_date(&entry.sent_at)),
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::styled(
+                    format!("{} ", status),
+                    Style::default()
+                        .fg(status_color(entry))
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{:<7}", entry.request.method.as_str()),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(
+                    entry.request.name.clone(),
+                    Style::default().fg(Color::White),
+                ),
+                Span::styled(
+                    format!(
+                        "  {} ms, {}",
+                        entry.duration_ms,
+                        format_bytes(entry.size_bytes)
+                    ),
+                    Style::default().fg(Color::Gray),
+                ),
+            ]))
+        })
+        .collect();
+
+    let title = format!(
+        "🕘 Request History ({} of {})",
+        app.history_entries.len(),
+        app.config.history_retention.label()
+    );
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .title_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .highlight_style(Style::default().bg(Color::Blue));
+    let mut list_state = ListState::default();
+    if !app.history_entries.is_empty() {
+        list_state.select(Some(app.history_selected));
+    }
+    f.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    let details = match app.selected_history_entry() {
+        Some(entry) => {
+            let outcome = match &entry.error {
+                Some(error) => {
+                    Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red)))
+                }
+                None => Line::from(Span::styled(
+                    format!(
+                        "{} {}",
+                        entry.status_code.unwrap_or_default(),
+                        entry.status_text
+                    ),
+                    Style::default().fg(status_color(entry)),
+                )),
+            };
+            vec![
+                Line::from(vec![
+                    Span::styled(
+                        format!("{} ", entry.request.method.as_str()),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::raw(entry.request.url.clone()),
+                ]),
+                Line::from(Span::styled(
+                    format!(
+                        "{} header(s), {} query param(s)",
+                        entry.request.headers.len(),
+                        entry.request.query_params.len()
+                    ),
+                    Style::default().fg(Color::Gray),
+                )),
+                outcome,
+            ]
+        }
+        None => vec![Line::from(Span::styled(
+            "No requests sent yet",
+            Style::default().fg(Color::Gray),
+        ))],
+    };
+    let details = Paragraph::new(details).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Details")
+            .style(Style::default().bg(Color::DarkGray)),
+    );
+    f.render_widget(details, chunks[1]);
+
+    let instructions = Paragraph::new(
+        "↑/↓ j/k: Select  |  Enter: Re-open  |  s: Re-send  |  c: Diff with current  |  d: Delete  |  Esc: Close",
+    )
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Controls")
+            .style(Style::default().bg(Color::DarkGray)),
+    )
+    .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[2]);
+}
+
+pub(crate) fn status_color(entry: &HistoryEntry) -> Color {
+    match entry.status_code {
+        Some(200..=299) => Color::Green,
+        Some(300..=399) => Color::Yellow,
+        Some(400..=499) => Color::Red,
+        Some(500..=599) => Color::Magenta,
+        Some(_) => Color::Gray,
+        None => Color::Red,
+    }
+}
@@ -0,0 +1,115 @@
+use crate::app::App;
+use crate::ui::components::history_dialog::status_color;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// Render the recent-sends palette: fuzzy query plus matching sends, newest or best first
+pub fn render_history_palette(f: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(70, 60, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Query
+            Constraint::Min(3),    // Sends
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let query = Paragraph::new(Line::from(vec![
+        Span::styled("🕘 ", Style::default().fg(Color::Cyan)),
+        Span::styled(
+            format!("{}█", app.history_palette_query),
+            Style::default().fg(Color::White),
+        ),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Recent Sends")
+            .title_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::DarkGray))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(query, chunks[0]);
+
+    let items: Vec<ListItem> = app
+        .history_palette_results
+        .iter()
+        .filter_map(|&index| app.history_entries.get(index))
+        .enumerate()
+        .map(|(position, entry)| {
+            let status = match entry.status_code {
+                Some(code) => format!("{:>3}", code),
+                None => "ERR".to_string(),
+            };
+            let shortcut = if position < 9 {
+                format!("{} ", position + 1)
+            } else {
+                "  ".to_string()
+            };
+            ListItem::new(vec![
+                Line::from(vec![
+                    Span::styled(shortcut, Style::default().fg(Color::Yellow)),
+                    Span::styled(
+                        format!("{} ", status),
+                        Style::default()
+                            .fg(status_color(entry))
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!("{:<7}", entry.request.method.as_str()),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::styled(entry.request.url.clone(), Style::default().fg(Color::White)),
+                ]),
+                Line::from(Span::styled(
+                    format!(
+                        "      {}  {}",
+                        entry.request.name,
+                        app.format_timestamp_with_date(&entry.sent_at)
+                    ),
+                    Style::default().fg(Color::Gray),
+                )),
+            ])
+        })
+        .collect();
+
+    let results_title = if app.history_entries.is_empty() {
+        "No requests sent yet".to_string()
+    } else {
+        format!("Sends ({})", app.history_palette_results.len())
+    };
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(results_title)
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .highlight_style(Style::default().bg(Color::Blue));
+    let mut list_state = ListState::default();
+    if !app.history_palette_results.is_empty() {
+        list_state.select(Some(app.history_palette_selected));
+    }
+    f.render_stateful_widget(list, chunks[1], &mut list_state);
+
+    let instructions = Paragraph::new(
+        "Type to filter  |  ↑/↓: Select  |  Enter or Alt+1-9: Re-send  |  Tab: Load into editor  |  Esc: Close",
+    )
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Controls")
+            .style(Style::default().bg(Color::DarkGray)),
+    )
+    .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[2]);
+}
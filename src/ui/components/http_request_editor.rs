@@ -1,16 +1,37 @@
+use crate::models::KeyValueColumn;
+use crate::models::header_names::header_name_suggestions;
+use crate::models::http::{
+    REQUEST_SETTINGS_ROWS, SETTINGS_ROW_CA_BUNDLE, SETTINGS_ROW_CLIENT_CERT,
+    SETTINGS_ROW_CLIENT_CERT_PASSWORD, SETTINGS_ROW_CLIENT_KEY, SETTINGS_ROW_FOLLOW_REDIRECTS,
+    SETTINGS_ROW_MAX_REDIRECTS, SETTINGS_ROW_PROXY_PASSWORD, SETTINGS_ROW_PROXY_URL,
+    SETTINGS_ROW_PROXY_USERNAME, SETTINGS_ROW_RETRY_ATTEMPTS, SETTINGS_ROW_RETRY_BACKOFF,
+    SETTINGS_ROW_RETRY_ON, SETTINGS_ROW_TIMEOUT, SETTINGS_ROW_USE_CACHE, SETTINGS_ROW_USER_AGENT,
+    SETTINGS_ROW_VERIFY_SSL,
+};
+use crate::models::http_client::{DEFAULT_USER_AGENT, HttpHeader, overrides_header};
+use crate::models::stepper::{
+    MAX_PAGES_RANGE, MAX_REDIRECTS_RANGE, RETRY_ATTEMPTS_RANGE, RETRY_BACKOFF_MS_RANGE,
+    TIMEOUT_SECS_RANGE,
+};
+use crate::services::multipart::file_content_type;
+use crate::services::storage_migration::expand_user_path;
+use crate::ui::components::field_widgets::{checkbox_span, stepper_spans};
+use crate::ui::components::http_response_viewer::format_bytes;
+use crate::ui::components::key_value_editor::render_key_value_editor;
+use crate::ui::components::markdown::markdown_lines;
 use crate::{
     app::{App, FocusedPane, InputMode},
     models::{
         http::{BodyContentType, HttpRequestTab, HttpState},
-        http_client::{HttpAuth, HttpRequestBody},
+        http_client::{HttpAuth, HttpRequestBody, PaginationRule},
     },
 };
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout, Margin, Rect},
+    layout::{Constraint, Direction, Layout, Margin, Position, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Tabs},
+    widgets::{Block, Borders, Paragraph, Tabs, Wrap},
 };
 
 /// Render the HTTP request editor with method, URL, and tabbed content
@@ -20,16 +41,64 @@ pub fn render_http_request_editor(f: &mut Frame, area: Rect, state: &HttpState,
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
+            Constraint::Length(1), // Open request tabs
             Constraint::Length(3), // Method and URL bar
             Constraint::Min(5),    // Tabbed content
         ])
         .split(area);
 
+    // Render the strip of open requests
+    render_open_request_tabs(f, chunks[0], state, app);
+
     // Render method and URL bar
-    render_method_url_bar(f, chunks[0], state, app, is_focused);
+    render_method_url_bar(f, chunks[1], state, app, is_focused);
 
     // Render tabbed content
-    render_request_tabs(f, chunks[1], state, app, is_focused);
+    render_request_tabs(f, chunks[2], state, app, is_focused);
+}
+
+/// Render one label per open request, the active one highlighted (or being renamed)
+fn render_open_request_tabs(f: &mut Frame, area: Rect, state: &HttpState, app: &App) {
+    let mut spans = Vec::new();
+    for (index, tab) in state.open_tabs.iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::styled("│", Style::default().fg(Color::DarkGray)));
+        }
+        let active = index == state.active_tab;
+        if active && let Some(input) = &app.tab_rename_input {
+            spans.push(Span::styled(
+                format!(" ✎ {}▏ ", input),
+                Style::default().fg(Color::Yellow).bg(Color::Black),
+            ));
+            continue;
+        }
+
+        let mut label: String = state.tab_label(index).chars().take(24).collect();
+        if state.in_flight.contains_key(&tab.key) {
+            label.push_str(" ⟳");
+        }
+        let style = if active {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+        spans.push(Span::styled(format!(" {} ", label), style));
+    }
+    if app.tab_rename_input.is_some() {
+        spans.push(Span::styled(
+            "  Enter: rename (empty resets)  Esc: cancel",
+            Style::default().fg(Color::DarkGray),
+        ));
+    } else if state.open_tabs.len() > 1 {
+        spans.push(Span::styled(
+            "  Alt+h/l switch · Alt+H/L move",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
 }
 
 /// Render the method selector and URL input bar
@@ -87,13 +156,31 @@ fn render_method_selector(f: &mut Frame, area: Rect, state: &HttpState, is_focus
     f.render_widget(method_widget, area);
 }
 
-/// Render URL input field
+/// Render URL input field, scrolled horizontally to keep the cursor in view
 fn render_url_input(f: &mut Frame, area: Rect, state: &HttpState, app: &App, is_focused: bool) {
+    let editing_url = is_focused && app.input_mode == InputMode::Edit && state.is_url_editable();
+    let width = area.width.saturating_sub(2) as usize;
+    let chars: Vec<char> = state.current_request.url.chars().collect();
+    let overflows = chars.len() >= width;
+
     let url_value = if state.current_request.url.is_empty() {
-        "https://api.example.com/endpoint"
+        "https://api.example.com/endpoint".to_string()
+    } else if editing_url {
+        let offset = state.url_scroll_offset(width);
+        let mut visible: Vec<char> = chars.iter().skip(offset).take(width).copied().collect();
+        if offset > 0 && !visible.is_empty() {
+            visible[0] = '…';
+        }
+        visible.into_iter().collect()
+    } else if overflows && width > 0 {
+        // Not editing: show the start, marking that there is more
+        let mut visible: String = chars.iter().take(width - 1).collect();
+        visible.push('…');
+        visible
     } else {
-        &state.current_request.url
+        state.current_request.url.clone()
     };
+    let title = if overflows { "URL (U: expand)" } else { "URL" };
 
     let url_style = if is_focused && app.input_mode == InputMode::Edit {
         Style::default().fg(Color::White).bg(Color::DarkGray)
@@ -107,7 +194,7 @@ fn render_url_input(f: &mut Frame, area: Rect, state: &HttpState, app: &App, is_
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("URL")
+                .title(title)
                 .title_style(Style::default().fg(Color::Green))
                 .border_style(if is_focused {
                     Style::default().fg(Color::Blue)
@@ -118,12 +205,17 @@ fn render_url_input(f: &mut Frame, area: Rect, state: &HttpState, app: &App, is_
         .style(url_style);
 
     f.render_widget(url_widget, area);
+
+    if editing_url {
+        let column = state.url_cursor.min(chars.len()) - state.url_scroll_offset(width);
+        f.set_cursor_position(Position::new(area.x + 1 + column as u16, area.y + 1));
+    }
 }
 
 /// Render send button
 fn render_send_button(f: &mut Frame, area: Rect, state: &HttpState, is_focused: bool) {
-    let (button_text, button_style) = if state.is_sending {
-        ("⏳ SENDING...", Style::default().fg(Color::Yellow))
+    let (button_text, button_style) = if state.is_sending() {
+        ("⏳ ^B stop", Style::default().fg(Color::Yellow))
     } else if !state.is_valid() {
         ("❌ INVALID", Style::default().fg(Color::Red))
     } else {
@@ -158,8 +250,7 @@ fn render_send_button(f: &mut Frame, area: Rect, state: &HttpState, is_focused:
 fn render_request_tabs(f: &mut Frame, area: Rect, state: &HttpState, app: &App, is_focused: bool) {
     let tab_titles: Vec<String> = HttpRequestTab::all()
         .iter()
-        .enumerate()
-        .map(|(i, tab)| {
+        .map(|tab| {
             let title = tab.title();
             // Add indicators for tabs with content
             let indicator = match tab {
@@ -169,6 +260,13 @@ fn render_request_tabs(f: &mut Frame, area: Rect, state: &HttpState, app: &App,
                     " •"
                 }
                 HttpRequestTab::Auth if state.current_request.auth != HttpAuth::None => " •",
+                HttpRequestTab::Script if !state.current_request.pre_request_script.is_empty() => {
+                    " •"
+                }
+                HttpRequestTab::Tests if !state.current_request.tests.is_empty() => " •",
+                HttpRequestTab::Extract if !state.current_request.extract.is_empty() => " •",
+                HttpRequestTab::Docs if !state.current_request.description.is_empty() => " •",
+                HttpRequestTab::Settings if !state.current_request.settings.is_default() => " •",
                 _ => "",
             };
             format!("{}{}", title, indicator)
@@ -219,56 +317,160 @@ fn render_request_tabs(f: &mut Frame, area: Rect, state: &HttpState, app: &App,
             render_query_params_tab(f, content_area, state, app, is_focused)
         }
         HttpRequestTab::Auth => render_auth_tab(f, content_area, state, app, is_focused),
+        HttpRequestTab::Cookies => render_cookies_tab(f, content_area, state, app, is_focused),
+        HttpRequestTab::Script => render_script_tab(f, content_area, state, app, is_focused),
+        HttpRequestTab::Tests => render_tests_tab(f, content_area, state, app, is_focused),
+        HttpRequestTab::Extract => render_extract_tab(f, content_area, state, app, is_focused),
+        HttpRequestTab::Docs => render_docs_tab(f, content_area, state, app, is_focused),
         HttpRequestTab::Settings => render_settings_tab(f, content_area, state, app, is_focused),
     }
 }
 
 /// Render headers tab content
-fn render_headers_tab(f: &mut Frame, area: Rect, state: &HttpState, _app: &App, _is_focused: bool) {
-    if state.current_request.headers.is_empty() {
-        let empty_text = vec![
-            Line::from(""),
-            Line::from(Span::styled(
-                "No headers added",
+fn render_headers_tab(f: &mut Frame, area: Rect, state: &HttpState, app: &App, is_focused: bool) {
+    let area = render_default_headers_line(f, area, state, app);
+    let editor = &state.key_value_editor;
+    let suggestions = if editor.editing == Some(KeyValueColumn::Name) {
+        header_name_suggestions(&editor.buffer)
+    } else {
+        Vec::new()
+    };
+    let area = if suggestions.is_empty() {
+        area
+    } else {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(area);
+        let mut spans = vec![Span::styled("Tab ⇥ ", Style::default().fg(Color::Yellow))];
+        spans.extend(suggestions.iter().enumerate().map(|(index, name)| {
+            let separator = if index == 0 { "" } else { "  " };
+            Span::styled(
+                format!("{}{}", separator, name),
                 Style::default().fg(Color::DarkGray),
-            )),
-            Line::from(""),
-            Line::from(Span::styled(
-                "Press 'a' to add a header",
-                Style::default().fg(Color::Gray),
-            )),
-        ];
+            )
+        }));
+        f.render_widget(Paragraph::new(Line::from(spans)), chunks[1]);
+        chunks[0]
+    };
 
-        let empty_widget = Paragraph::new(empty_text);
-        f.render_widget(empty_widget, area);
-    } else {
-        let header_items: Vec<ListItem> = state
-            .current_request
-            .headers
-            .iter()
-            .enumerate()
-            .map(|(i, header)| {
-                let enabled_indicator = if header.enabled { "✓" } else { "✗" };
-                let style = if header.enabled {
-                    Style::default().fg(Color::White)
-                } else {
-                    Style::default().fg(Color::DarkGray)
-                };
+    render_key_value_editor(
+        f,
+        area,
+        &state.current_request.headers,
+        &state.key_value_editor,
+        ": ",
+        "No headers added - a adds one, R pastes a raw block",
+        is_focused,
+        app.reveal_secrets,
+    );
+}
 
-                ListItem::new(Line::from(vec![
-                    Span::styled(format!("{} ", enabled_indicator), style),
-                    Span::styled(
-                        format!("{}: ", header.name),
-                        Style::default().fg(Color::Cyan),
-                    ),
-                    Span::styled(&header.value, style),
-                ]))
-            })
-            .collect();
+/// List the default headers this request gets below the header rows, striking
+/// out the ones it overrides; returns the area left for the rows
+fn render_default_headers_line(f: &mut Frame, area: Rect, state: &HttpState, app: &App) -> Rect {
+    let defaults: Vec<&HttpHeader> = app
+        .config
+        .default_headers
+        .iter()
+        .filter(|header| header.enabled)
+        .collect();
+    if defaults.is_empty() {
+        return area;
+    }
 
-        let headers_list = List::new(header_items);
-        f.render_widget(headers_list, area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(area);
+    let mut spans = vec![Span::styled(
+        "+ defaults: ",
+        Style::default().fg(Color::Cyan),
+    )];
+    for (index, header) in defaults.iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::raw("  "));
+        }
+        let style = if overrides_header(&state.current_request.headers, &header.name) {
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::CROSSED_OUT)
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+        spans.push(Span::styled(header.name.clone(), style));
     }
+    spans.push(Span::styled(
+        "  (G: edit)",
+        Style::default().fg(Color::DarkGray),
+    ));
+    f.render_widget(Paragraph::new(Line::from(spans)), chunks[1]);
+    chunks[0]
+}
+
+/// Render the cookie jar shared by every request, with details of the selected cookie
+fn render_cookies_tab(f: &mut Frame, area: Rect, state: &HttpState, app: &App, is_focused: bool) {
+    let jar = app.cookie_jar.lock().unwrap();
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Selected cookie details
+            Constraint::Min(3),    // Cookies
+        ])
+        .split(area);
+
+    let sent = reqwest::Url::parse(&state.current_request.url)
+        .map(|url| jar.cookies.iter().filter(|c| c.matches(&url)).count())
+        .unwrap_or(0);
+    let summary = Line::from(vec![
+        Span::styled(
+            format!("{} cookie(s), {} sent to this URL", jar.cookies.len(), sent),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::styled(
+            "  Set-Cookie responses fill the jar; Delete clears it",
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]);
+    let details = match jar.cookies.get(state.key_value_editor.selected) {
+        Some(cookie) => {
+            let domain = if cookie.domain.is_empty() {
+                "any host".to_string()
+            } else if cookie.host_only {
+                cookie.domain.clone()
+            } else {
+                format!(".{}", cookie.domain)
+            };
+            let expires = cookie.expires.map_or("session".to_string(), |expires| {
+                app.config
+                    .timestamp_format
+                    .format(&expires.with_timezone(&chrono::Local))
+            });
+            let secure = if cookie.secure { "  secure" } else { "" };
+            format!(
+                "{}  path {}  expires {}{}",
+                domain, cookie.path, expires, secure
+            )
+        }
+        None => String::new(),
+    };
+    let header = Paragraph::new(vec![
+        summary,
+        Line::from(Span::styled(details, Style::default().fg(Color::DarkGray))),
+    ]);
+    f.render_widget(header, chunks[0]);
+
+    render_key_value_editor(
+        f,
+        chunks[1],
+        &jar.cookies,
+        &state.key_value_editor,
+        "=",
+        "No cookies yet - responses with Set-Cookie add them here",
+        is_focused,
+        app.reveal_secrets,
+    );
 }
 
 /// Render body tab content  
@@ -297,19 +499,86 @@ fn render_body_tab(f: &mut Frame, area: Rect, state: &HttpState, app: &App, is_f
             let no_body_widget = Paragraph::new(no_body_text);
             f.render_widget(no_body_widget, body_chunks[1]);
         }
+        BodyContentType::Json if state.json_builder.is_some() => {
+            render_json_builder(f, body_chunks[1], state, app, is_focused);
+        }
         BodyContentType::Json | BodyContentType::Text | BodyContentType::Raw => {
             render_body_text_editor(f, body_chunks[1], state, app, is_focused);
         }
-        BodyContentType::Form => {
+        BodyContentType::Form | BodyContentType::Multipart => {
             render_form_fields_editor(f, body_chunks[1], state, app, is_focused);
         }
+        BodyContentType::File => {
+            render_file_body_editor(f, body_chunks[1], state, app, is_focused);
+        }
+    }
+}
+
+/// Render the path of a file body with what will be sent from it
+fn render_file_body_editor(
+    f: &mut Frame,
+    area: Rect,
+    state: &HttpState,
+    app: &App,
+    is_focused: bool,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(2)])
+        .split(area);
+    render_body_text_editor(f, chunks[0], state, app, is_focused);
+
+    let HttpRequestBody::File { path, content_type } = &state.current_request.body else {
+        return;
+    };
+    if path.trim().is_empty() {
+        return;
     }
+    let file = match std::fs::metadata(expand_user_path(path)) {
+        Ok(metadata) if metadata.is_file() => Span::styled(
+            format!(
+                "📎 {} streamed at send time",
+                format_bytes(metadata.len() as usize)
+            ),
+            Style::default().fg(Color::Green),
+        ),
+        _ => Span::styled(
+            "⚠ File not found (variables are resolved at send time)",
+            Style::default().fg(Color::Yellow),
+        ),
+    };
+    let info = vec![
+        Line::from(file),
+        Line::from(Span::styled(
+            format!(
+                "Content-Type: {} (a Content-Type header overrides it)",
+                file_content_type(path, content_type)
+            ),
+            Style::default().fg(Color::Gray),
+        )),
+    ];
+    f.render_widget(Paragraph::new(info), chunks[1]);
 }
 
 /// Render body type selector
 fn render_body_type_selector(f: &mut Frame, area: Rect, state: &HttpState, _is_focused: bool) {
-    let type_text = format!("Type: {}", state.current_body_type.title());
-    let type_widget = Paragraph::new(type_text).style(Style::default().fg(Color::Yellow));
+    let mut spans = vec![
+        Span::styled(
+            format!("Type: {}", state.current_body_type.title()),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::styled("  (b: change)", Style::default().fg(Color::DarkGray)),
+    ];
+    if state.current_body_type == BodyContentType::Json {
+        let toggle = if state.json_builder.is_some() {
+            "  B: raw JSON"
+        } else {
+            "  B: builder"
+        };
+        spans.push(Span::styled(toggle, Style::default().fg(Color::DarkGray)));
+    }
+    let type_line = Line::from(spans);
+    let type_widget = Paragraph::new(type_line);
 
     f.render_widget(type_widget, area);
 }
@@ -322,14 +591,27 @@ fn render_body_text_editor(
     app: &App,
     is_focused: bool,
 ) {
+    // Reserve a line for the JSON parse error, if any
+    let (text_area, error_area) = if state.body_error.is_some() && area.height > 2 {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(area);
+        (chunks[0], Some(chunks[1]))
+    } else {
+        (area, None)
+    };
+
     let content = state.get_body_content();
     let placeholder = match state.current_body_type {
         BodyContentType::Json => "{\n  \"key\": \"value\"\n}",
         BodyContentType::Text => "Enter text content here...",
         BodyContentType::Raw => "Raw content...",
+        BodyContentType::File => "Path of the file to send, e.g. ~/uploads/photo.png",
         _ => "",
     };
 
+    let is_editing = is_focused && app.input_mode == InputMode::Edit;
     let display_content = if content.is_empty() {
         placeholder
     } else {
@@ -337,158 +619,375 @@ fn render_body_text_editor(
     };
     let style = if content.is_empty() {
         Style::default().fg(Color::DarkGray)
-    } else if is_focused && app.input_mode == InputMode::Edit {
+    } else if is_editing {
         Style::default().fg(Color::White).bg(Color::DarkGray)
     } else {
         Style::default().fg(Color::White)
     };
 
+    // Keep the cursor line in view (no wrapping, so line/column map straight to the screen)
+    let (cursor_line, cursor_column) = state.body_cursor_line_col();
+    let scroll = (cursor_line as u16).saturating_sub(text_area.height.saturating_sub(1));
+
     let body_widget = Paragraph::new(display_content)
         .style(style)
-        .wrap(ratatui::widgets::Wrap { trim: true });
+        .scroll((scroll, 0));
 
-    f.render_widget(body_widget, area);
+    f.render_widget(body_widget, text_area);
+
+    if is_editing {
+        let cursor_x = (text_area.x + cursor_column as u16)
+            .min(text_area.x + text_area.width.saturating_sub(1));
+        let cursor_y = text_area.y + cursor_line as u16 - scroll;
+        f.set_cursor_position(Position::new(cursor_x, cursor_y));
+    }
+
+    if let (Some(error), Some(error_area)) = (&state.body_error, error_area) {
+        let error_widget = Paragraph::new(format!("✗ Invalid JSON at {}", error))
+            .style(Style::default().fg(Color::Red));
+        f.render_widget(error_widget, error_area);
+    }
 }
 
-/// Render form fields editor (for form-data)
-fn render_form_fields_editor(
-    f: &mut Frame,
-    area: Rect,
-    _state: &HttpState,
-    _app: &App,
-    _is_focused: bool,
-) {
-    let form_text = vec![
-        Line::from(""),
+/// Render the pre-request script editor
+fn render_script_tab(f: &mut Frame, area: Rect, state: &HttpState, app: &App, is_focused: bool) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Syntax reminder
+            Constraint::Min(3),    // Script
+        ])
+        .split(area);
+
+    let reminder = Paragraph::new(vec![
+        Line::from(vec![
+            Span::styled("Runs before sending", Style::default().fg(Color::Yellow)),
+            Span::styled(
+                "  set/header/query NAME = expr, remove header|query NAME",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]),
         Line::from(Span::styled(
-            "Form data editor",
-            Style::default().fg(Color::Yellow),
+            "now() iso_now() uuid() env(\"X\") sha256(s) hmac_sha256(key, s) base64(s) + joins; {{name}} fills in values",
+            Style::default().fg(Color::DarkGray),
         )),
-        Line::from(""),
+    ]);
+    f.render_widget(reminder, chunks[0]);
+
+    render_script_text(
+        f,
+        chunks[1],
+        state,
+        app,
+        is_focused,
+        "set ts = now()\nheader X-Timestamp = ts",
+    );
+}
+
+/// Render the post-response assertions editor
+fn render_tests_tab(f: &mut Frame, area: Rect, state: &HttpState, app: &App, is_focused: bool) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Syntax reminder
+            Constraint::Min(3),    // Assertions
+        ])
+        .split(area);
+
+    let reminder = Paragraph::new(vec![
+        Line::from(vec![
+            Span::styled(
+                "Checked after the response",
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::styled(
+                "  status, header NAME, json PATH, body, time (ms), size (bytes), schema FILE|{...}",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]),
         Line::from(Span::styled(
-            "Key-value pairs for form submission",
-            Style::default().fg(Color::Gray),
+            "== != < <= > >= contains exists missing; status == 2xx; {{name}} fills in values",
+            Style::default().fg(Color::DarkGray),
         )),
-    ];
+    ]);
+    f.render_widget(reminder, chunks[0]);
 
-    let form_widget = Paragraph::new(form_text);
-    f.render_widget(form_widget, area);
+    render_script_text(
+        f,
+        chunks[1],
+        state,
+        app,
+        is_focused,
+        "status == 200\nheader Content-Type contains json\njson $.data.id exists",
+    );
 }
 
-/// Render query parameters tab
-fn render_query_params_tab(
+/// Render the extract tab: rules copying response values into environment variables
+fn render_extract_tab(f: &mut Frame, area: Rect, state: &HttpState, app: &App, is_focused: bool) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Syntax reminder
+            Constraint::Min(3),    // Rules
+        ])
+        .split(area);
+
+    let reminder = Paragraph::new(vec![
+        Line::from(vec![
+            Span::styled(
+                "Saved to the environment after the response",
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::styled(
+                "  name = $.json.path, header NAME, status, body",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]),
+        Line::from(Span::styled(
+            "Later requests use them as {{name}}, including the rest of a collection run",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ]);
+    f.render_widget(reminder, chunks[0]);
+
+    render_script_text(
+        f,
+        chunks[1],
+        state,
+        app,
+        is_focused,
+        "token = $.data.access_token\nrequest_id = header X-Request-Id",
+    );
+}
+
+/// Render the request's notes: formatted markdown to read, raw text while editing
+fn render_docs_tab(f: &mut Frame, area: Rect, state: &HttpState, app: &App, is_focused: bool) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Hint
+            Constraint::Min(3),    // Notes
+        ])
+        .split(area);
+
+    let hint = Paragraph::new(Line::from(vec![
+        Span::styled(
+            "Notes about this endpoint",
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::styled(
+            "  markdown: # headings, - lists, `code`, **bold**; i edits, E opens $EDITOR",
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]));
+    f.render_widget(hint, chunks[0]);
+
+    let is_editing = is_focused && app.input_mode == InputMode::Edit;
+    if is_editing || state.current_request.description.trim().is_empty() {
+        render_script_text(
+            f,
+            chunks[1],
+            state,
+            app,
+            is_focused,
+            "## List users\nReturns a page of users. Needs the `admin` scope.",
+        );
+        return;
+    }
+    f.render_widget(
+        Paragraph::new(markdown_lines(&state.current_request.description))
+            .wrap(Wrap { trim: false }),
+        chunks[1],
+    );
+}
+
+/// Render the text of the Script, Tests, Extract or Docs tab with its edit cursor
+fn render_script_text(
     f: &mut Frame,
-    area: Rect,
+    text_area: Rect,
     state: &HttpState,
-    _app: &App,
-    _is_focused: bool,
+    app: &App,
+    is_focused: bool,
+    placeholder: &str,
 ) {
-    if state.current_request.query_params.is_empty() {
-        let empty_text = vec![
-            Line::from(""),
-            Line::from(Span::styled(
-                "No query parameters",
-                Style::default().fg(Color::DarkGray),
-            )),
-            Line::from(""),
-            Line::from(Span::styled(
-                "Press 'a' to add a parameter",
-                Style::default().fg(Color::Gray),
-            )),
-        ];
-
-        let empty_widget = Paragraph::new(empty_text);
-        f.render_widget(empty_widget, area);
+    let script = state.script_text();
+    let is_editing = is_focused && app.input_mode == InputMode::Edit;
+    let (display, style) = if script.is_empty() {
+        (placeholder, Style::default().fg(Color::DarkGray))
+    } else if is_editing {
+        (
+            script,
+            Style::default().fg(Color::White).bg(Color::DarkGray),
+        )
     } else {
-        let param_items: Vec<ListItem> = state
-            .current_request
-            .query_params
-            .iter()
-            .map(|param| {
-                let enabled_indicator = if param.enabled { "✓" } else { "✗" };
-                let style = if param.enabled {
-                    Style::default().fg(Color::White)
-                } else {
-                    Style::default().fg(Color::DarkGray)
-                };
+        (script, Style::default().fg(Color::White))
+    };
 
-                ListItem::new(Line::from(vec![
-                    Span::styled(format!("{} ", enabled_indicator), style),
-                    Span::styled(format!("{}=", param.name), Style::default().fg(Color::Cyan)),
-                    Span::styled(&param.value, style),
-                ]))
-            })
-            .collect();
+    let (cursor_line, cursor_column) = state.script_cursor_line_col();
+    let scroll = (cursor_line as u16).saturating_sub(text_area.height.saturating_sub(1));
+    f.render_widget(
+        Paragraph::new(display).style(style).scroll((scroll, 0)),
+        text_area,
+    );
 
-        let params_list = List::new(param_items);
-        f.render_widget(params_list, area);
+    if is_editing {
+        let cursor_x = (text_area.x + cursor_column as u16)
+            .min(text_area.x + text_area.width.saturating_sub(1));
+        let cursor_y = text_area.y + cursor_line as u16 - scroll;
+        f.set_cursor_position(Position::new(cursor_x, cursor_y));
+    }
+}
+
+/// Render form fields or multipart parts (file parts are marked with 📎)
+fn render_form_fields_editor(
+    f: &mut Frame,
+    area: Rect,
+    state: &HttpState,
+    app: &App,
+    is_focused: bool,
+) {
+    match &state.current_request.body {
+        HttpRequestBody::Multipart { parts } => render_key_value_editor(
+            f,
+            area,
+            parts,
+            &state.key_value_editor,
+            "=",
+            "No parts; 'f' marks a part as a file upload (value = path)",
+            is_focused,
+            app.reveal_secrets,
+        ),
+        HttpRequestBody::Form { fields } => render_key_value_editor(
+            f,
+            area,
+            fields,
+            &state.key_value_editor,
+            "=",
+            "No form fields",
+            is_focused,
+            app.reveal_secrets,
+        ),
+        _ => {}
     }
 }
 
+/// Render the JSON body builder rows, with the reason they don't build if they don't
+fn render_json_builder(f: &mut Frame, area: Rect, state: &HttpState, app: &App, is_focused: bool) {
+    let Some(rows) = &state.json_builder else {
+        return;
+    };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(1)])
+        .split(area);
+
+    render_key_value_editor(
+        f,
+        chunks[0],
+        rows,
+        &state.key_value_editor,
+        " = ",
+        "No rows yet - a adds one, e.g. user.name = Ada or tags[0] = admin",
+        is_focused,
+        app.reveal_secrets,
+    );
+
+    let footer = match &state.json_builder_error {
+        Some(error) => Span::styled(format!("⚠ {}", error), Style::default().fg(Color::Red)),
+        None => Span::styled(
+            "Values are JSON (42, true, null, \"42\", []); anything else is a string",
+            Style::default().fg(Color::DarkGray),
+        ),
+    };
+    f.render_widget(Paragraph::new(Line::from(footer)), chunks[1]);
+}
+
+/// Render query parameters tab
+fn render_query_params_tab(
+    f: &mut Frame,
+    area: Rect,
+    state: &HttpState,
+    app: &App,
+    is_focused: bool,
+) {
+    render_key_value_editor(
+        f,
+        area,
+        &state.current_request.query_params,
+        &state.key_value_editor,
+        "=",
+        "No query parameters",
+        is_focused,
+        app.reveal_secrets,
+    );
+}
+
 /// Render authentication tab
-fn render_auth_tab(f: &mut Frame, area: Rect, state: &HttpState, _app: &App, _is_focused: bool) {
-    let auth_text = match &state.current_request.auth {
-        HttpAuth::None => vec![
-            Line::from(""),
-            Line::from(Span::styled(
-                "No authentication",
-                Style::default().fg(Color::DarkGray),
-            )),
-            Line::from(""),
-            Line::from(Span::styled(
-                "Available types: Bearer Token, Basic Auth, API Key",
-                Style::default().fg(Color::Gray),
-            )),
-        ],
-        HttpAuth::Bearer { token } => vec![
-            Line::from(Span::styled(
-                "Bearer Token Authentication",
-                Style::default().fg(Color::Yellow),
-            )),
-            Line::from(""),
-            Line::from(vec![
-                Span::raw("Token: "),
-                Span::styled(
-                    if token.len() > 20 {
-                        format!("{}...", &token[..20])
-                    } else {
-                        token.clone()
-                    },
-                    Style::default().fg(Color::Cyan),
-                ),
-            ]),
-        ],
-        HttpAuth::Basic { username, .. } => vec![
-            Line::from(Span::styled(
-                "Basic Authentication",
+fn render_auth_tab(f: &mut Frame, area: Rect, state: &HttpState, app: &App, is_focused: bool) {
+    let auth = &state.current_request.auth;
+    let is_editing = is_focused && app.input_mode == InputMode::Edit;
+
+    let mut auth_text = vec![
+        Line::from(vec![
+            Span::styled(
+                format!("{} Authentication", auth.title()),
                 Style::default().fg(Color::Yellow),
-            )),
-            Line::from(""),
-            Line::from(vec![
-                Span::raw("Username: "),
-                Span::styled(username, Style::default().fg(Color::Cyan)),
-            ]),
-            Line::from(vec![
-                Span::raw("Password: "),
-                Span::styled("••••••••", Style::default().fg(Color::DarkGray)),
-            ]),
-        ],
-        HttpAuth::ApiKey {
-            key,
-            value,
-            location,
-        } => vec![
-            Line::from(Span::styled(
-                "API Key Authentication",
+            ),
+            Span::styled(
+                if app.reveal_secrets {
+                    "  (c: change type, Alt+s: mask secrets)"
+                } else {
+                    "  (c: change type, Alt+s: reveal secrets)"
+                },
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]),
+        Line::from(""),
+    ];
+
+    if *auth == HttpAuth::None {
+        auth_text.push(Line::from(Span::styled(
+            "No authentication",
+            Style::default().fg(Color::DarkGray),
+        )));
+        auth_text.push(Line::from(""));
+        auth_text.push(Line::from(Span::styled(
+            "Available types: Bearer Token, Basic Auth, API Key, Custom (script), OAuth 2.0",
+            Style::default().fg(Color::Gray),
+        )));
+    }
+
+    for (index, (label, value)) in auth.fields().into_iter().enumerate() {
+        let focused = is_focused && index == state.auth_focused_field;
+        let secret = HttpAuth::is_secret_field(label) && !app.reveal_secrets;
+        let shown = if secret && !(focused && is_editing) {
+            "•".repeat(value.chars().count())
+        } else if focused && is_editing {
+            format!("{}▏", value)
+        } else {
+            value.to_string()
+        };
+
+        let value_style = if focused && is_editing {
+            Style::default().fg(Color::Yellow).bg(Color::Black)
+        } else {
+            Style::default().fg(Color::Cyan)
+        };
+
+        auth_text.push(Line::from(vec![
+            Span::styled(
+                if focused { "▶ " } else { "  " },
                 Style::default().fg(Color::Yellow),
-            )),
-            Line::from(""),
-            Line::from(vec![
-                Span::raw("Key: "),
-                Span::styled(key, Style::default().fg(Color::Cyan)),
-            ]),
-            Line::from(vec![
-                Span::raw("Location: "),
+            ),
+            Span::raw(format!("{}: ", label)),
+            Span::styled(shown, value_style),
+        ]));
+    }
+
+    match auth {
+        HttpAuth::ApiKey { location, .. } => {
+            auth_text.push(Line::from(vec![
+                Span::raw("  Location: "),
                 Span::styled(
                     match location {
                         crate::models::ApiKeyLocation::Header => "Header",
@@ -496,34 +995,395 @@ fn render_auth_tab(f: &mut Frame, area: Rect, state: &HttpState, _app: &App, _is
                     },
                     Style::default().fg(Color::Green),
                 ),
-            ]),
-        ],
-    };
+                Span::styled("  (l: toggle)", Style::default().fg(Color::DarkGray)),
+            ]));
+        }
+        HttpAuth::Script { .. } => {
+            auth_text.push(Line::from(""));
+            for hint in [
+                "The script gets the request as JSON on stdin (method, url, headers,",
+                "query_params, body) and prints a JSON object of headers to add.",
+                ".py files run with python3; other files are executed directly.",
+            ] {
+                auth_text.push(Line::from(Span::styled(
+                    hint,
+                    Style::default().fg(Color::Gray),
+                )));
+            }
+        }
+        HttpAuth::OAuth2ClientCredentials { .. } | HttpAuth::OAuth2AuthorizationCode { .. } => {
+            auth_text.push(Line::from(""));
+            let (status, color) = match app.current_oauth2_token() {
+                None => (
+                    "No token yet, one is fetched on send".to_string(),
+                    Color::Gray,
+                ),
+                Some(token) if token.needs_refresh() => (
+                    "Token expired, refreshed on send".to_string(),
+                    Color::Yellow,
+                ),
+                Some(token) => match token.expires_at {
+                    Some(expires_at) => (
+                        format!(
+                            "Token cached, expires in {} min",
+                            (expires_at - chrono::Utc::now()).num_minutes()
+                        ),
+                        Color::Green,
+                    ),
+                    None => ("Token cached".to_string(), Color::Green),
+                },
+            };
+            auth_text.push(Line::from(vec![
+                Span::raw("  Token: "),
+                Span::styled(status, Style::default().fg(color)),
+                Span::styled(
+                    "  (g: get new token, f: forget)",
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]));
+            if matches!(auth, HttpAuth::OAuth2AuthorizationCode { .. }) {
+                auth_text.push(Line::from(""));
+                for hint in [
+                    "Sign-in opens in your browser and redirects to",
+                    "http://127.0.0.1:<port>/callback; register that redirect URI",
+                    "with the provider. PKCE is always used.",
+                ] {
+                    auth_text.push(Line::from(Span::styled(
+                        hint,
+                        Style::default().fg(Color::Gray),
+                    )));
+                }
+            }
+        }
+        _ => {}
+    }
 
     let auth_widget = Paragraph::new(auth_text);
     f.render_widget(auth_widget, area);
 }
 
 /// Render settings tab
-fn render_settings_tab(
-    f: &mut Frame,
-    area: Rect,
-    _state: &HttpState,
-    _app: &App,
-    _is_focused: bool,
-) {
-    let settings_text = vec![
-        Line::from(Span::styled(
-            "Request Settings",
-            Style::default().fg(Color::Yellow),
-        )),
+fn render_settings_tab(f: &mut Frame, area: Rect, state: &HttpState, app: &App, is_focused: bool) {
+    let pagination = &state.current_request.pagination;
+    let settings = &state.current_request.settings;
+    let is_editing = is_focused && app.input_mode == InputMode::Edit;
+    let value_style = Style::default().fg(Color::Cyan);
+    let hint_style = Style::default().fg(Color::DarkGray);
+    let row = |index: usize, label: &str, mut spans: Vec<Span<'static>>| {
+        let mut line = vec![
+            Span::styled(
+                if is_focused && state.settings_focused_field == index {
+                    "▶ "
+                } else {
+                    "  "
+                },
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::raw(format!("{}: ", label)),
+        ];
+        line.append(&mut spans);
+        Line::from(line)
+    };
+
+    let mut timeout = stepper_spans(
+        &settings.timeout_secs.to_string(),
+        TIMEOUT_SECS_RANGE,
+        value_style,
+    );
+    timeout.push(Span::styled(
+        if settings.timeout_secs == 0 {
+            "  seconds, no limit"
+        } else {
+            "  seconds"
+        },
+        hint_style,
+    ));
+    let mut max_redirects = stepper_spans(
+        &settings.max_redirects.to_string(),
+        MAX_REDIRECTS_RANGE,
+        if settings.follow_redirects {
+            value_style
+        } else {
+            hint_style
+        },
+    );
+    max_redirects.push(Span::styled("  +/- to step", hint_style));
+
+    // Zero attempts defers to the global policy, which the hint spells out
+    let retry = &settings.retry;
+    let own_retry = retry.max_attempts > 0;
+    let mut retry_attempts = stepper_spans(
+        &retry.max_attempts.to_string(),
+        RETRY_ATTEMPTS_RANGE,
+        value_style,
+    );
+    retry_attempts.push(Span::styled(
+        match retry.max_attempts {
+            0 => format!("  global: {}", app.config.retry.label()),
+            1 => "  no retries".to_string(),
+            _ => "  attempts".to_string(),
+        },
+        hint_style,
+    ));
+    let mut retry_backoff = stepper_spans(
+        &retry.backoff_ms.to_string(),
+        RETRY_BACKOFF_MS_RANGE,
+        if own_retry { value_style } else { hint_style },
+    );
+    retry_backoff.push(Span::styled("  ms, doubling each retry", hint_style));
+    let retry_on = Span::styled(
+        retry.retry_on.label(),
+        if own_retry { value_style } else { hint_style },
+    );
+
+    let user_agent_focused = is_focused && state.settings_focused_field == SETTINGS_ROW_USER_AGENT;
+    let user_agent = if user_agent_focused && is_editing {
+        Span::styled(
+            format!("{}▏", settings.user_agent),
+            Style::default().fg(Color::Yellow).bg(Color::Black),
+        )
+    } else if settings.user_agent.is_empty() {
+        Span::styled(format!("(default: {})", DEFAULT_USER_AGENT), hint_style)
+    } else {
+        Span::styled(settings.user_agent.clone(), value_style)
+    };
+
+    // Certificate paths; blank rows show what the global config supplies instead
+    let global_tls = &app.config.tls;
+    let uses_global_identity = settings.tls.client_cert.trim().is_empty();
+    let tls_value = |index: usize, value: &str, global: &str, fallback: &str, secret: bool| {
+        if is_editing && state.settings_focused_field == index {
+            Span::styled(
+                format!("{}▏", value),
+                Style::default().fg(Color::Yellow).bg(Color::Black),
+            )
+        } else if !value.is_empty() {
+            let shown = if secret {
+                "•".repeat(value.chars().count())
+            } else {
+                value.to_string()
+            };
+            Span::styled(shown, value_style)
+        } else if !global.is_empty() {
+            let shown = if secret { "••••" } else { global };
+            Span::styled(format!("(global: {})", shown), hint_style)
+        } else {
+            Span::styled(fallback.to_string(), hint_style)
+        }
+    };
+    let (global_cert, global_key, global_password) = if uses_global_identity {
+        (
+            global_tls.client_cert.as_str(),
+            global_tls.client_key.as_str(),
+            global_tls.client_cert_password.as_str(),
+        )
+    } else {
+        ("", "", "")
+    };
+    let ca_bundle = tls_value(
+        SETTINGS_ROW_CA_BUNDLE,
+        &settings.tls.ca_bundle,
+        &global_tls.ca_bundle,
+        "(system roots only)",
+        false,
+    );
+    let client_cert = tls_value(
+        SETTINGS_ROW_CLIENT_CERT,
+        &settings.tls.client_cert,
+        global_cert,
+        "(none; .pem or .p12/.pfx)",
+        false,
+    );
+    let client_key = tls_value(
+        SETTINGS_ROW_CLIENT_KEY,
+        &settings.tls.client_key,
+        global_key,
+        "(in the certificate file)",
+        false,
+    );
+    let client_cert_password = tls_value(
+        SETTINGS_ROW_CLIENT_CERT_PASSWORD,
+        &settings.tls.client_cert_password,
+        global_password,
+        "(none)",
+        true,
+    );
+
+    // Proxy rows; blank ones show the environment's or global proxy they fall back to
+    let environment_proxy = app.config.active_proxy();
+    let own_proxy = !settings.proxy.is_empty();
+    let (fallback_url, fallback_username, fallback_password) = if own_proxy {
+        (String::new(), "", "")
+    } else {
+        (
+            environment_proxy.display_url(),
+            environment_proxy.username.as_str(),
+            environment_proxy.password.as_str(),
+        )
+    };
+    let proxy_url = tls_value(
+        SETTINGS_ROW_PROXY_URL,
+        &settings.proxy.url,
+        &fallback_url,
+        "(none; host:port, socks5://host:port or \"direct\")",
+        false,
+    );
+    let proxy_username = tls_value(
+        SETTINGS_ROW_PROXY_USERNAME,
+        &settings.proxy.username,
+        fallback_username,
+        "(none)",
+        false,
+    );
+    let proxy_password = tls_value(
+        SETTINGS_ROW_PROXY_PASSWORD,
+        &settings.proxy.password,
+        fallback_password,
+        "(none)",
+        true,
+    );
+
+    let mut settings_text = vec![
+        Line::from(vec![
+            Span::styled("Request Settings", Style::default().fg(Color::Yellow)),
+            Span::styled(
+                "  (j/k: select, t: toggle, +/-: step, i: edit text)",
+                hint_style,
+            ),
+        ]),
+        Line::from(""),
+        row(SETTINGS_ROW_TIMEOUT, "Timeout", timeout),
+        row(
+            SETTINGS_ROW_FOLLOW_REDIRECTS,
+            "Follow redirects",
+            vec![checkbox_span(settings.follow_redirects)],
+        ),
+        row(SETTINGS_ROW_MAX_REDIRECTS, "Max redirects", max_redirects),
+        row(
+            SETTINGS_ROW_VERIFY_SSL,
+            "Verify SSL",
+            vec![
+                checkbox_span(settings.verify_ssl),
+                Span::styled(
+                    if settings.verify_ssl {
+                        ""
+                    } else {
+                        "  invalid certificates are accepted"
+                    },
+                    Style::default().fg(Color::Red),
+                ),
+            ],
+        ),
+        row(
+            SETTINGS_ROW_RETRY_ATTEMPTS,
+            "Retry attempts",
+            retry_attempts,
+        ),
+        row(SETTINGS_ROW_RETRY_BACKOFF, "Retry backoff", retry_backoff),
+        row(SETTINGS_ROW_RETRY_ON, "Retry on", vec![retry_on]),
+        row(
+            SETTINGS_ROW_USE_CACHE,
+            "Conditional GET",
+            vec![
+                checkbox_span(settings.use_cache),
+                Span::styled(
+                    if settings.use_cache {
+                        "  cached body is shown on 304 Not Modified"
+                    } else {
+                        "  always downloads the full response"
+                    },
+                    hint_style,
+                ),
+            ],
+        ),
+        row(SETTINGS_ROW_USER_AGENT, "User agent", vec![user_agent]),
+        row(SETTINGS_ROW_CA_BUNDLE, "CA bundle", vec![ca_bundle]),
+        row(SETTINGS_ROW_CLIENT_CERT, "Client cert", vec![client_cert]),
+        row(SETTINGS_ROW_CLIENT_KEY, "Client key", vec![client_key]),
+        row(
+            SETTINGS_ROW_CLIENT_CERT_PASSWORD,
+            "Cert password",
+            vec![client_cert_password],
+        ),
+        row(SETTINGS_ROW_PROXY_URL, "Proxy", vec![proxy_url]),
+        row(
+            SETTINGS_ROW_PROXY_USERNAME,
+            "Proxy user",
+            vec![proxy_username],
+        ),
+        row(
+            SETTINGS_ROW_PROXY_PASSWORD,
+            "Proxy password",
+            vec![proxy_password],
+        ),
         Line::from(""),
-        Line::from("• Follow redirects: Yes"),
-        Line::from("• Verify SSL: Yes"),
-        Line::from("• Timeout: 30 seconds"),
-        Line::from("• User Agent: Custom HTTP Client"),
+        Line::from(vec![
+            Span::styled("Pagination: ", Style::default().fg(Color::Yellow)),
+            Span::styled(pagination.rule.title(), Style::default().fg(Color::Green)),
+            Span::styled("  (p: change rule)", Style::default().fg(Color::DarkGray)),
+            Span::raw("   Auto-follow on send: "),
+            Span::styled(
+                if pagination.auto_follow { "on" } else { "off" },
+                Style::default().fg(if pagination.auto_follow {
+                    Color::Green
+                } else {
+                    Color::DarkGray
+                }),
+            ),
+            Span::styled("  (a)", Style::default().fg(Color::DarkGray)),
+        ]),
     ];
 
+    if pagination.rule != PaginationRule::None {
+        for (index, (label, value)) in pagination.fields().into_iter().enumerate() {
+            let focused =
+                is_focused && REQUEST_SETTINGS_ROWS + index == state.settings_focused_field;
+            let (shown, value_style) = if focused && is_editing {
+                (
+                    format!("{}▏", value),
+                    Style::default().fg(Color::Yellow).bg(Color::Black),
+                )
+            } else if value.is_empty() {
+                let placeholder = if label == "Items path" {
+                    "(whole body)"
+                } else {
+                    "(empty)"
+                };
+                (
+                    placeholder.to_string(),
+                    Style::default().fg(Color::DarkGray),
+                )
+            } else {
+                (value.to_string(), Style::default().fg(Color::Cyan))
+            };
+
+            settings_text.push(Line::from(vec![
+                Span::styled(
+                    if focused { "▶ " } else { "  " },
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::raw(format!("{}: ", label)),
+                Span::styled(shown, value_style),
+            ]));
+        }
+
+        let mut max_pages = vec![Span::raw("  Max pages: ")];
+        max_pages.extend(stepper_spans(
+            &pagination.max_pages.to_string(),
+            MAX_PAGES_RANGE,
+            Style::default().fg(Color::Cyan),
+        ));
+        max_pages.push(Span::styled(
+            "  +/- to step",
+            Style::default().fg(Color::DarkGray),
+        ));
+        settings_text.push(Line::from(max_pages));
+        settings_text.push(Line::from(Span::styled(
+            "  Ctrl+P: fetch all pages into one document",
+            Style::default().fg(Color::Gray),
+        )));
+    }
+
     let settings_widget = Paragraph::new(settings_text);
     f.render_widget(settings_widget, area);
 }
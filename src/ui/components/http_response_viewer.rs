@@ -1,15 +1,23 @@
+use crate::models::hex_dump::{HEX_ROW_BYTES, hex_row, hex_row_count};
+use crate::models::image_info::{ImageInfo, image_info};
+use crate::services::image_preview::ImagePreview;
+use crate::services::json_filter::{apply_json_filter, filter_json_value, json_path_lines};
+use crate::services::json_tree::{JsonTreeRow, json_tree_rows};
+use crate::ui::components::json_highlight::{
+    highlight_json, highlight_range, is_json_body, pretty_print_json,
+};
 use crate::{
     app::{App, FocusedPane},
     models::{
         http::{HttpResponseTab, HttpState},
-        http_client::HttpResponse,
+        http_client::{HttpResponse, TimingBreakdown},
     },
 };
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
-    text::{Line, Span},
+    text::{Line, Span, Text},
     widgets::{Block, Borders, List, ListItem, Paragraph, Tabs, Wrap},
 };
 
@@ -18,7 +26,27 @@ pub fn render_http_response_viewer(f: &mut Frame, area: Rect, state: &HttpState,
     let is_focused = app.focused_pane == FocusedPane::Logs; // Reuse Logs focus for response viewer
 
     if let Some(response) = &state.last_response {
-        render_response_with_tabs(f, area, response, state, is_focused);
+        // Servers that echo the request back would otherwise show its credentials.
+        // The main loop keeps the masked copy and the view up to date.
+        let response = app.masked_last_response.as_ref().unwrap_or(response);
+        // Build a view only if that never ran
+        let built;
+        let view = match &app.response_view {
+            Some(view) => view,
+            None => {
+                built = ResponseBodyView::new(response, state);
+                &built
+            }
+        };
+        render_response_with_tabs(
+            f,
+            area,
+            response,
+            view,
+            state,
+            is_focused,
+            &app.image_preview,
+        );
     } else {
         render_empty_response(f, area, state, is_focused);
     }
@@ -26,7 +54,20 @@ pub fn render_http_response_viewer(f: &mut Frame, area: Rect, state: &HttpState,
 
 /// Render response viewer when no response is available
 fn render_empty_response(f: &mut Frame, area: Rect, state: &HttpState, is_focused: bool) {
-    let empty_text = if state.is_sending {
+    let empty_text = if let Some((pages, items)) = state.current_page_progress() {
+        vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                format!("📚 Fetched {} page(s), {} item(s)...", pages, items),
+                Style::default().fg(Color::Yellow),
+            )),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Ctrl+B cancels",
+                Style::default().fg(Color::Gray),
+            )),
+        ]
+    } else if state.is_sending() {
         vec![
             Line::from(""),
             Line::from(Span::styled(
@@ -81,8 +122,10 @@ fn render_response_with_tabs(
     f: &mut Frame,
     area: Rect,
     response: &HttpResponse,
+    view: &ResponseBodyView,
     state: &HttpState,
     is_focused: bool,
+    preview: &ImagePreview,
 ) {
     let tab_titles: Vec<String> = HttpResponseTab::all()
         .iter()
@@ -90,9 +133,18 @@ fn render_response_with_tabs(
             let title = tab.title();
             // Add indicators for tabs with content
             let indicator = match tab {
-                HttpResponseTab::Headers if !response.headers.is_empty() => " •",
-                HttpResponseTab::Body if !response.body.is_empty() => " •",
-                _ => "",
+                HttpResponseTab::Headers if !response.headers.is_empty() => " •".to_string(),
+                HttpResponseTab::Body if !response.body.is_empty() => " •".to_string(),
+                HttpResponseTab::Tests if !response.assertions.is_empty() => {
+                    let passed = response.assertions.iter().filter(|a| a.passed).count();
+                    let icon = if passed == response.assertions.len() {
+                        "✓"
+                    } else {
+                        "✗"
+                    };
+                    format!(" {} {}/{}", icon, passed, response.assertions.len())
+                }
+                _ => String::new(),
             };
             format!("{}{}", title, indicator)
         })
@@ -114,12 +166,39 @@ fn render_response_with_tabs(
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(Span::styled(
-                    title,
-                    Style::default()
-                        .fg(status_color)
-                        .add_modifier(Modifier::BOLD),
-                ))
+                .title(Line::from(vec![
+                    Span::styled(
+                        title,
+                        Style::default()
+                            .fg(status_color)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        if response.served_from_cache {
+                            " ⟲ served from cache "
+                        } else {
+                            ""
+                        },
+                        Style::default().fg(Color::Black).bg(Color::Cyan),
+                    ),
+                    Span::styled(
+                        state
+                            .current_page_progress()
+                            .map(|(pages, items)| {
+                                format!(" 📚 page {}, {} item(s)... ", pages, items)
+                            })
+                            .unwrap_or_default(),
+                        Style::default().fg(Color::Black).bg(Color::Yellow),
+                    ),
+                    Span::styled(
+                        if state.is_response_stale() {
+                            " ⚠ stale (request modified) "
+                        } else {
+                            ""
+                        },
+                        Style::default().fg(Color::Black).bg(Color::Yellow),
+                    ),
+                ]))
                 .border_style(if is_focused {
                     Style::default().fg(Color::Blue)
                 } else {
@@ -147,14 +226,24 @@ fn render_response_with_tabs(
         vertical: 1,
     });
     match state.current_response_tab {
-        HttpResponseTab::Body => render_response_body_tab(f, content_area, response),
+        HttpResponseTab::Body => {
+            render_response_body_tab(f, content_area, response, view, state, preview)
+        }
         HttpResponseTab::Headers => render_response_headers_tab(f, content_area, response),
+        HttpResponseTab::Tests => render_response_tests_tab(f, content_area, response),
         HttpResponseTab::Info => render_response_info_tab(f, content_area, response),
     }
 }
 
-/// Render response body tab
-fn render_response_body_tab(f: &mut Frame, area: Rect, response: &HttpResponse) {
+/// Render response body tab (JSON is pretty-printed and highlighted unless the raw view is on)
+fn render_response_body_tab(
+    f: &mut Frame,
+    area: Rect,
+    response: &HttpResponse,
+    view: &ResponseBodyView,
+    state: &HttpState,
+    preview: &ImagePreview,
+) {
     if response.body.is_empty() {
         let empty_text = vec![
             Line::from(""),
@@ -166,19 +255,551 @@ fn render_response_body_tab(f: &mut Frame, area: Rect, response: &HttpResponse)
 
         let empty_widget = Paragraph::new(empty_text);
         f.render_widget(empty_widget, area);
+        return;
+    }
+
+    let show_filter = state.response_filter_mode || !state.response_filter.is_empty();
+    let show_search = state.response_search_mode || !state.response_search.is_empty();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(if show_filter { 3 } else { 0 }), // Filter bar
+            Constraint::Length(if show_search { 3 } else { 0 }), // Find bar
+            Constraint::Length(1),                               // View indicator
+            Constraint::Min(1),                                  // Body
+        ])
+        .split(area);
+
+    let image = shows_image(response, state);
+    if image.is_some() || shows_hex_dump(response, state) {
+        // Filters and find don't apply to bytes; the bars still show what was typed
+        if show_filter {
+            render_filter_bar(f, chunks[0], state, false, None);
+        }
+        if show_search {
+            render_search_bar(f, chunks[1], state, 0, 0);
+        }
+        match image {
+            Some(info) => render_image_view(f, chunks[2], chunks[3], response, &info, preview),
+            None => render_hex_dump(f, chunks[2], chunks[3], response, state),
+        }
+        return;
+    }
+
+    let is_json = view.is_json;
+    let raw = state.response_raw_view;
+    let tree = &view.tree;
+
+    let matches = &state.response_search_matches;
+    let current_match = state
+        .response_search_current
+        .min(matches.len().saturating_sub(1));
+
+    // The line to keep on screen: the JSON cursor, else the current match
+    let mut focus_line = matches.get(current_match).map(|found| found.line);
+    // JSONPath under the cursor, for the tree and formatted JSON only
+    let mut cursor_path = None;
+    let mut cursor_line = None;
+    if let Some(paths) = &view.json_paths {
+        let cursor = state
+            .response_cursor
+            .min(view.lines.len().saturating_sub(1));
+        if let Some(path) = paths.get(cursor) {
+            cursor_path = Some(path.clone());
+            cursor_line = Some(cursor);
+            focus_line = Some(cursor);
+        }
+    }
+
+    // Keep the focus line visible, counting the rows wrapped lines take up. Only the
+    // lines from the top of the screen down are copied out of the view.
+    let lines = &view.lines;
+    let width = chunks[3].width.max(1) as usize;
+    let height = chunks[3].height as usize;
+    let rows = |line: &Line| line.width().max(1).div_ceil(width);
+    let mut scroll = 0;
+    if let Some(focus) = focus_line.filter(|focus| *focus < lines.len()) {
+        let focus_bottom: usize = lines[..=focus].iter().map(rows).sum();
+        scroll = focus_bottom.saturating_sub(height);
+    }
+    let mut first = 0;
+    while first < lines.len() && rows(&lines[first]) <= scroll {
+        scroll -= rows(&lines[first]);
+        first += 1;
+    }
+    let mut last = first;
+    let mut filled = 0;
+    while last < lines.len() && filled < scroll + height {
+        filled += rows(&lines[last]);
+        last += 1;
+    }
+    let mut shown: Vec<Line> = lines[first..last].to_vec();
+
+    for (index, found) in matches.iter().enumerate() {
+        let Some(line) = found
+            .line
+            .checked_sub(first)
+            .and_then(|line| shown.get_mut(line))
+        else {
+            continue;
+        };
+        let style = if index == current_match {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Black).bg(Color::Cyan)
+        };
+        *line = highlight_range(line, found.start, found.end, style);
+    }
+    if let Some(line) = cursor_line
+        .and_then(|cursor| cursor.checked_sub(first))
+        .and_then(|cursor| shown.get_mut(cursor))
+    {
+        *line = line.clone().style(Style::default().bg(Color::DarkGray));
+    }
+    let body_text = Text::from(shown);
+    let filter_error = view.text.as_ref().err().cloned();
+
+    if show_filter {
+        render_filter_bar(f, chunks[0], state, is_json, filter_error);
+    }
+    if show_search {
+        render_search_bar(f, chunks[1], state, matches.len(), current_match);
+    }
+
+    let view_label = match (is_json, raw) {
+        _ if tree.is_some() => "JSON Tree",
+        (false, _) => "Raw",
+        (true, true) => "Raw JSON",
+        (true, false) => "Formatted JSON",
+    };
+    let mut view_spans = vec![
+        Span::styled(view_label, Style::default().fg(Color::Magenta)),
+        Span::styled(
+            "  (r: toggle raw, t: tree, /: filter, f: find)",
+            Style::default().fg(Color::DarkGray),
+        ),
+    ];
+    if let Some(truncation) = &response.truncation {
+        view_spans.push(Span::styled(
+            format!(
+                "  ⚠ Display {} - s: save full body (exports always use it)",
+                truncation.label()
+            ),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    if let Some(path) = cursor_path {
+        view_spans.extend([
+            Span::raw("  "),
+            Span::styled(path, Style::default().fg(Color::Cyan)),
+            Span::styled(
+                if tree.is_some() {
+                    " (j/k: move, Enter/h/l: fold, y: copy path, Y: copy value)"
+                } else {
+                    " (j/k: move, y: copy)"
+                },
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]);
+    }
+    let view_line = Line::from(view_spans);
+    f.render_widget(Paragraph::new(view_line), chunks[2]);
+
+    let body_widget = Paragraph::new(body_text)
+        .style(Style::default().fg(Color::White))
+        .wrap(Wrap { trim: false })
+        .scroll((scroll as u16, 0));
+
+    f.render_widget(body_widget, chunks[3]);
+}
+
+/// What the body tab shows for a response and the view settings, worked out once when
+/// either changes instead of on every frame: bodies can be megabytes of JSON.
+#[derive(Debug, Clone)]
+pub struct ResponseBodyView {
+    pub is_json: bool,
+    /// The body parsed as JSON, when it is JSON
+    pub document: Option<serde_json::Value>,
+    /// Rows of the collapsible tree, when the tree view is on for an unfiltered JSON body
+    pub tree: Option<Vec<JsonTreeRow>>,
+    /// The text shown - filtered, raw or pretty-printed - or why the filter failed.
+    /// Search matches are found in this text so their line numbers line up with `lines`.
+    pub text: Result<String, String>,
+    /// `text` as drawn, highlighted when it's JSON
+    pub lines: Vec<Line<'static>>,
+    /// JSONPath of each line, for the tree and formatted JSON without a filter
+    pub json_paths: Option<Vec<String>>,
+}
+
+impl ResponseBodyView {
+    pub fn new(response: &HttpResponse, state: &HttpState) -> Self {
+        if shows_image(response, state).is_some() || shows_hex_dump(response, state) {
+            return Self {
+                is_json: false,
+                document: None,
+                tree: None,
+                text: Ok(String::new()),
+                lines: Vec::new(),
+                json_paths: None,
+            };
+        }
+
+        // The same test as `is_json_body`, keeping the parsed document
+        let body = &response.body;
+        let trimmed = body.trim_start();
+        let json_type = response.content_type.contains("json");
+        let document = (json_type || trimmed.starts_with('{') || trimmed.starts_with('['))
+            .then(|| serde_json::from_str::<serde_json::Value>(body).ok())
+            .flatten();
+        let is_json = json_type || document.is_some();
+        let raw = state.response_raw_view;
+        let filter = state.response_filter.trim();
+
+        let tree = document
+            .as_ref()
+            .filter(|_| state.response_tree_view && filter.is_empty())
+            .map(|document| json_tree_rows(document, &state.response_collapsed));
+        let text = if let Some(rows) = &tree {
+            Ok(rows
+                .iter()
+                .map(JsonTreeRow::text)
+                .collect::<Vec<_>>()
+                .join("\n"))
+        } else if is_json && !filter.is_empty() {
+            let filtered = match &document {
+                Some(document) => filter_json_value(document, filter),
+                None => apply_json_filter(body, filter),
+            };
+            filtered.map_err(|e| e.to_string()).map(|filtered| {
+                if raw {
+                    filtered.to_string()
+                } else {
+                    serde_json::to_string_pretty(&filtered).unwrap_or_default()
+                }
+            })
+        } else if raw || !is_json {
+            Ok(body.clone())
+        } else {
+            Ok(document
+                .as_ref()
+                .and_then(|document| serde_json::to_string_pretty(document).ok())
+                .unwrap_or_else(|| body.clone()))
+        };
+
+        let lines = match (&tree, &text) {
+            (Some(rows), _) => rows.iter().map(tree_row_line).collect(),
+            (None, Ok(text)) if is_json && !raw => highlight_json(text),
+            (None, Ok(text)) => text
+                .lines()
+                .map(|line| Line::from(line.to_string()))
+                .collect(),
+            (None, Err(_)) => vec![Line::from(Span::styled(
+                "No result - fix the filter expression",
+                Style::default().fg(Color::DarkGray),
+            ))],
+        };
+
+        let json_paths = match (&tree, &document) {
+            (Some(rows), _) => Some(rows.iter().map(|row| row.path.clone()).collect()),
+            (None, Some(document)) if !raw && filter.is_empty() => Some(json_path_lines(document)),
+            (None, None) if is_json && !raw && filter.is_empty() => Some(Vec::new()),
+            _ => None,
+        };
+
+        Self {
+            is_json,
+            document,
+            tree,
+            text,
+            lines,
+            json_paths,
+        }
+    }
+}
+
+/// Binary bodies show as a hex dump unless the raw view asks for the decoded text.
+/// Images are the other way round: the hex dump is their raw view.
+pub fn shows_hex_dump(response: &HttpResponse, state: &HttpState) -> bool {
+    if image_info(&response.body_bytes).is_some() {
+        state.response_raw_view
     } else {
-        // Try to format the body based on content type
-        let formatted_body = format_response_body(&response.body, &response.content_type);
+        !response.body_bytes.is_empty() && !state.response_raw_view
+    }
+}
+
+/// Format and size of an image body, unless the raw view shows its hex dump
+pub fn shows_image(response: &HttpResponse, state: &HttpState) -> Option<ImageInfo> {
+    if state.response_raw_view {
+        return None;
+    }
+    image_info(&response.body_bytes)
+}
+
+/// Render an image body: inline when the terminal has a graphics protocol for it,
+/// otherwise its dimensions and size
+fn render_image_view(
+    f: &mut Frame,
+    view_area: Rect,
+    body_area: Rect,
+    response: &HttpResponse,
+    info: &ImageInfo,
+    preview: &ImagePreview,
+) {
+    let summary = format!(
+        "{} image, {} × {} px, {}",
+        info.format.label(),
+        info.width,
+        info.height,
+        format_bytes(response.full_body_len())
+    );
+    let unavailable = match preview.protocol {
+        _ if response.truncation.is_some() => Some(
+            "Only the start of the image was kept (display limit) - s saves it in full".to_string(),
+        ),
+        None => Some(
+            "Inline previews need the kitty or iTerm2 graphics protocol (not sixel), outside tmux/screen"
+                .to_string(),
+        ),
+        Some(protocol) if !protocol.supports(info.format) => Some(format!(
+            "{} shows PNG images inline; this one is {}",
+            protocol.label(),
+            info.format.label()
+        )),
+        Some(_) => None,
+    };
 
-        let body_widget = Paragraph::new(formatted_body)
-            .style(Style::default().fg(Color::White))
-            .wrap(Wrap { trim: false })
-            .scroll((0, 0)); // TODO: Add scrolling support
+    let mut view_spans = vec![
+        Span::styled("Image", Style::default().fg(Color::Magenta)),
+        Span::styled(
+            "  (r: hex dump, s: save)",
+            Style::default().fg(Color::DarkGray),
+        ),
+    ];
+    if let (None, Some(protocol)) = (&unavailable, preview.protocol) {
+        view_spans.push(Span::styled(
+            format!("  {} via {}", summary, protocol.label()),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+    f.render_widget(Paragraph::new(Line::from(view_spans)), view_area);
 
-        f.render_widget(body_widget, area);
+    match unavailable {
+        // The body area is left blank for the image drawn over it after the frame
+        None => preview.place(Some(body_area)),
+        Some(reason) => {
+            let lines = vec![
+                Line::from(""),
+                Line::from(Span::styled(
+                    format!("🖼  {}", summary),
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                )),
+                Line::from(Span::styled(
+                    format!("Content-Type: {}", response.content_type),
+                    Style::default().fg(Color::Gray),
+                )),
+                Line::from(""),
+                Line::from(Span::styled(reason, Style::default().fg(Color::DarkGray))),
+            ];
+            f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), body_area);
+        }
     }
 }
 
+/// Render a `hexdump -C` style view of a binary body, only the rows that fit
+fn render_hex_dump(
+    f: &mut Frame,
+    view_area: Rect,
+    body_area: Rect,
+    response: &HttpResponse,
+    state: &HttpState,
+) {
+    let bytes = &response.body_bytes;
+    let rows = hex_row_count(bytes.len());
+    let cursor = state.response_cursor.min(rows.saturating_sub(1));
+    let height = (body_area.height as usize).max(1);
+    let top = (cursor + 1).saturating_sub(height);
+
+    let lines: Vec<Line> = (top..rows.min(top + height))
+        .filter_map(|row| hex_row(bytes, row).map(|hex| (row, hex)))
+        .map(|(row, hex)| {
+            let line = Line::from(vec![
+                Span::styled(
+                    format!("{:08x}  ", hex.offset),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(hex.hex, Style::default().fg(Color::White)),
+                Span::styled("  |", Style::default().fg(Color::DarkGray)),
+                Span::styled(hex.ascii, Style::default().fg(Color::Green)),
+                Span::styled("|", Style::default().fg(Color::DarkGray)),
+            ]);
+            if row == cursor {
+                line.style(Style::default().bg(Color::DarkGray))
+            } else {
+                line
+            }
+        })
+        .collect();
+
+    let mut view_spans = vec![
+        Span::styled("Hex", Style::default().fg(Color::Magenta)),
+        Span::styled(
+            if image_info(bytes).is_some() {
+                "  (r: show image, j/k: scroll, g/G: top/bottom)"
+            } else {
+                "  (r: show as text, j/k: scroll, g/G: top/bottom)"
+            },
+            Style::default().fg(Color::DarkGray),
+        ),
+        Span::styled(
+            format!(
+                "  offset 0x{:08x} of {}",
+                cursor * HEX_ROW_BYTES,
+                format_bytes(bytes.len())
+            ),
+            Style::default().fg(Color::Cyan),
+        ),
+    ];
+    if let Some(truncation) = &response.truncation {
+        view_spans.push(Span::styled(
+            format!(
+                "  ⚠ Display {} - s: save full body (exports always use it)",
+                truncation.label()
+            ),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    f.render_widget(Paragraph::new(Line::from(view_spans)), view_area);
+    f.render_widget(Paragraph::new(lines), body_area);
+}
+
+/// One tree row with its key and value colored like the formatted JSON view.
+/// The text matches `JsonTreeRow::text` so search matches line up.
+fn tree_row_line(row: &JsonTreeRow) -> Line<'static> {
+    let mut spans = vec![Span::styled(
+        format!("{}{} ", "  ".repeat(row.depth), row.marker()),
+        Style::default().fg(Color::Yellow),
+    )];
+    if let Some(label) = &row.label {
+        spans.push(Span::styled(
+            label.clone(),
+            Style::default().fg(Color::Cyan),
+        ));
+        spans.push(Span::styled(": ", Style::default().fg(Color::White)));
+    }
+    if row.summary.starts_with(['{', '[']) {
+        spans.push(Span::styled(
+            row.summary.clone(),
+            Style::default().fg(Color::DarkGray),
+        ));
+    } else {
+        spans.extend(
+            highlight_json(&row.summary)
+                .into_iter()
+                .flat_map(|line| line.spans),
+        );
+    }
+    Line::from(spans)
+}
+
+/// Render the find-in-body input with the match count
+fn render_search_bar(f: &mut Frame, area: Rect, state: &HttpState, total: usize, current: usize) {
+    let (text, style) = if state.response_search.is_empty() {
+        (
+            "Type to find in the body (Enter: keep, Esc: clear, n/N: next/previous)".to_string(),
+            Style::default().fg(Color::DarkGray),
+        )
+    } else {
+        let cursor = if state.response_search_mode {
+            "█"
+        } else {
+            ""
+        };
+        (
+            format!("{}{}", state.response_search, cursor),
+            Style::default().fg(Color::White),
+        )
+    };
+
+    let (title, title_color) = if state.response_search.is_empty() {
+        ("🔎 Find".to_string(), Color::Cyan)
+    } else if total == 0 {
+        ("🔎 Find - no matches".to_string(), Color::Red)
+    } else {
+        (format!("🔎 Find - {}/{}", current + 1, total), Color::Cyan)
+    };
+    let search_bar = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .title_style(Style::default().fg(title_color))
+                .border_style(if state.response_search_mode {
+                    Style::default().fg(Color::Blue)
+                } else {
+                    Style::default().fg(Color::White)
+                }),
+        )
+        .style(style);
+
+    f.render_widget(search_bar, area);
+}
+
+/// Render the JSONPath / jq filter input above the body
+fn render_filter_bar(
+    f: &mut Frame,
+    area: Rect,
+    state: &HttpState,
+    is_json: bool,
+    error: Option<String>,
+) {
+    let (text, style) = if !is_json {
+        (
+            "Filters only apply to JSON responses".to_string(),
+            Style::default().fg(Color::DarkGray),
+        )
+    } else if state.response_filter.is_empty() {
+        (
+            "$.items[0].name, $..id or .items[] | .name (Enter: apply, Esc: clear)".to_string(),
+            Style::default().fg(Color::DarkGray),
+        )
+    } else {
+        let cursor = if state.response_filter_mode {
+            "█"
+        } else {
+            ""
+        };
+        (
+            format!("{}{}", state.response_filter, cursor),
+            Style::default().fg(Color::White),
+        )
+    };
+
+    let (title, border_color) = match &error {
+        Some(error) => (format!("🔍 Filter - {}", error), Color::Red),
+        None => ("🔍 Filter".to_string(), Color::Cyan),
+    };
+    let filter_bar = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .title_style(Style::default().fg(border_color))
+                .border_style(if state.response_filter_mode {
+                    Style::default().fg(Color::Blue)
+                } else {
+                    Style::default().fg(Color::White)
+                }),
+        )
+        .style(style);
+
+    f.render_widget(filter_bar, area);
+}
+
 /// Render response headers tab
 fn render_response_headers_tab(f: &mut Frame, area: Rect, response: &HttpResponse) {
     if response.headers.is_empty() {
@@ -212,11 +833,59 @@ fn render_response_headers_tab(f: &mut Frame, area: Rect, response: &HttpRespons
     }
 }
 
+/// Render the pass/fail result of each assertion
+fn render_response_tests_tab(f: &mut Frame, area: Rect, response: &HttpResponse) {
+    if response.assertions.is_empty() && response.extracted.is_empty() {
+        let empty_text = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                "No assertions - add them on the request's Tests tab",
+                Style::default().fg(Color::DarkGray),
+            )),
+        ];
+        f.render_widget(Paragraph::new(empty_text), area);
+        return;
+    }
+
+    let items: Vec<ListItem> = response
+        .assertions
+        .iter()
+        .map(|result| {
+            let (icon, color) = if result.passed {
+                ("✅", Color::Green)
+            } else {
+                ("❌", Color::Red)
+            };
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{} ", icon)),
+                Span::styled(result.assertion.clone(), Style::default().fg(color)),
+                Span::styled(
+                    format!("  {}", result.message),
+                    Style::default().fg(Color::Gray),
+                ),
+            ]))
+        })
+        .chain(response.extracted.iter().map(|variable| {
+            ListItem::new(Line::from(vec![
+                Span::raw("🔗 "),
+                Span::styled(variable.name.clone(), Style::default().fg(Color::Cyan)),
+                Span::styled(
+                    format!(" = {}", variable.value),
+                    Style::default().fg(Color::Gray),
+                ),
+            ]))
+        }))
+        .collect();
+
+    f.render_widget(List::new(items), area);
+}
+
 /// Render response info tab (status, timing, etc.)
 fn render_response_info_tab(f: &mut Frame, area: Rect, response: &HttpResponse) {
     let status_color = response.status_color();
+    let transfer = &response.transfer;
 
-    let info_lines = vec![
+    let mut info_lines = vec![
         Line::from(vec![
             Span::raw("Status: "),
             Span::styled(
@@ -246,6 +915,15 @@ fn render_response_info_tab(f: &mut Frame, area: Rect, response: &HttpResponse)
                 format_bytes(response.body.len()),
                 Style::default().fg(Color::Yellow),
             ),
+            Span::styled(wire_size_note(response), Style::default().fg(Color::Gray)),
+            Span::styled(
+                response
+                    .truncation
+                    .as_ref()
+                    .map(|truncation| format!(" {}", truncation.label()))
+                    .unwrap_or_default(),
+                Style::default().fg(Color::Yellow),
+            ),
         ]),
         Line::from(""),
         Line::from(vec![
@@ -254,22 +932,139 @@ fn render_response_info_tab(f: &mut Frame, area: Rect, response: &HttpResponse)
                 response.headers.len().to_string(),
                 Style::default().fg(Color::Magenta),
             ),
+            Span::styled(
+                format!(" ({})", format_bytes(transfer.response_header_bytes)),
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("Request: "),
+            Span::styled(
+                format!("{} body", format_bytes(transfer.request_body_bytes)),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::raw(", "),
+            Span::styled(
+                format!(
+                    "{} header(s) ({})",
+                    transfer.request_header_count,
+                    format_bytes(transfer.request_header_bytes)
+                ),
+                Style::default().fg(Color::Magenta),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("Transferred: "),
+            Span::styled(
+                format!(
+                    "↑ {}  ↓ {}",
+                    format_bytes(transfer.sent_bytes()),
+                    format_bytes(transfer.received_bytes(response.body.len()))
+                ),
+                Style::default().fg(Color::Cyan),
+            ),
         ]),
     ];
 
+    // Responses from older history entries have no breakdown
+    if !response.timing.is_empty() {
+        info_lines.push(Line::from(""));
+        info_lines.push(Line::from("Timing:"));
+        info_lines.extend(timing_bars(&response.timing, area.width));
+    }
+
+    // Older history entries and cached pages have no connection details
+    let connection = &response.connection;
+    if !connection.http_version.is_empty() {
+        info_lines.push(Line::from(""));
+        info_lines.push(Line::from(vec![
+            Span::raw("Protocol: "),
+            Span::styled(
+                connection.http_version.clone(),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]));
+        info_lines.push(Line::from(vec![
+            Span::raw("Proxy: "),
+            Span::styled(
+                connection.proxy_label(),
+                Style::default().fg(if connection.proxy_bypassed {
+                    Color::Yellow
+                } else {
+                    Color::Cyan
+                }),
+            ),
+            Span::styled(
+                "  (P: send once without proxy)",
+                Style::default().fg(Color::Gray),
+            ),
+        ]));
+        if let Some(fingerprint) = &connection.certificate_sha256 {
+            info_lines.push(Line::from(vec![
+                Span::raw("Certificate SHA-256: "),
+                Span::styled(fingerprint.clone(), Style::default().fg(Color::Magenta)),
+            ]));
+        }
+    }
+
     let info_widget = Paragraph::new(info_lines);
     f.render_widget(info_widget, area);
 }
 
-/// Format response body based on content type
-fn format_response_body(body: &str, content_type: &str) -> String {
-    if content_type.contains("application/json") {
-        // Try to pretty-print JSON
-        if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(body) {
-            if let Ok(pretty) = serde_json::to_string_pretty(&parsed) {
-                return pretty;
-            }
+/// One bar per phase, scaled to the slowest so small phases stay visible
+fn timing_bars(timing: &TimingBreakdown, width: u16) -> Vec<Line<'static>> {
+    const LABEL_WIDTH: usize = 16;
+    let bar_width = (width as usize)
+        .saturating_sub(LABEL_WIDTH + 14)
+        .clamp(10, 40);
+    let phases = timing.phases();
+    let slowest = phases.iter().map(|(_, ms)| *ms).max().unwrap_or(0).max(1);
+
+    phases
+        .into_iter()
+        .enumerate()
+        .map(|(index, (label, ms))| {
+            let color = [Color::Gray, Color::Cyan, Color::Yellow, Color::Green][index % 4];
+            let filled = (ms as usize * bar_width).div_ceil(slowest as usize);
+            let bar = if filled == 0 {
+                "▏".to_string()
+            } else {
+                "█".repeat(filled)
+            };
+            Line::from(vec![
+                Span::raw(format!("  {:<width$}", label, width = LABEL_WIDTH)),
+                Span::styled(bar, Style::default().fg(color)),
+                Span::styled(format!(" {} ms", ms), Style::default().fg(Color::Gray)),
+            ])
+        })
+        .collect()
+}
+
+/// Size on the wire next to the decoded body size, when the server reported one that differs
+fn wire_size_note(response: &HttpResponse) -> String {
+    let Some(wire_bytes) = response.transfer.response_wire_bytes else {
+        return String::new();
+    };
+    if response.served_from_cache {
+        return " (from cache, nothing downloaded)".to_string();
+    }
+    match response.header("content-encoding") {
+        Some(encoding) => format!(" ({} on the wire, {})", format_bytes(wire_bytes), encoding),
+        None if wire_bytes != response.body.len() => {
+            format!(" ({} on the wire)", format_bytes(wire_bytes))
         }
+        None => String::new(),
+    }
+}
+
+/// Format response body based on content type (JSON is detected even without a JSON content type)
+pub fn format_response_body(body: &str, content_type: &str) -> String {
+    if is_json_body(body, content_type)
+        && let Some(pretty) = pretty_print_json(body)
+    {
+        return pretty;
     }
 
     // Return body as-is if we can't format it
@@ -277,7 +1072,7 @@ fn format_response_body(body: &str, content_type: &str) -> String {
 }
 
 /// Format byte count in human-readable format
-fn format_bytes(bytes: usize) -> String {
+pub fn format_bytes(bytes: usize) -> String {
     const UNITS: &[&str] = &["B", "KB", "MB", "GB"];
     let mut size = bytes as f64;
     let mut unit_index = 0;
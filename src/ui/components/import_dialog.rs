@@ -1,15 +1,20 @@
 // Create new file: src/ui/components/import_dialog.rs
 
-use crate::app::App;
+use crate::app::{App, ImportFormat};
+use crate::services::bruno_import::is_bruno_collection;
+use crate::services::template_bundle::BUNDLE_EXTENSION;
+use crate::ui::components::http_response_viewer::format_bytes;
+use crate::ui::layout::centered_rect;
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph},
+    widgets::{Block, Borders, Clear, Gauge, Paragraph},
 };
+use std::sync::atomic::Ordering;
 
-/// Render Postman collection import dialog
+/// Render the collection import dialog (Postman, OpenAPI, Insomnia, Bruno or a template bundle)
 pub fn render_import_dialog(f: &mut Frame, area: Rect, app: &App) {
     let popup_area = centered_rect(70, 60, area);
 
@@ -28,21 +33,35 @@ pub fn render_import_dialog(f: &mut Frame, area: Rect, app: &App) {
         .split(popup_area);
 
     // Title
-    let title = Paragraph::new("📥 Import Postman Collection")
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("Import Collection")
-                .title_style(Style::default().fg(Color::Cyan))
-                .style(Style::default().bg(Color::DarkGray))
-                .border_style(Style::default().fg(Color::Cyan)),
-        )
-        .style(Style::default().fg(Color::White));
+    let format_hint = if app.import_dialog_format == ImportFormat::TemplateBundle {
+        "(checksum verified before import)"
+    } else {
+        "(Ctrl+F: switch format)"
+    };
+    let title = Paragraph::new(Line::from(vec![
+        Span::raw(format!("📥 Import {}  ", app.import_dialog_format.title())),
+        Span::styled(format_hint, Style::default().fg(Color::Gray)),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Import Collection")
+            .title_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::DarkGray))
+            .border_style(Style::default().fg(Color::Cyan)),
+    )
+    .style(Style::default().fg(Color::White));
     f.render_widget(title, chunks[0]);
 
     // File path field
     let file_path_text = if app.import_dialog_file_path.is_empty() {
-        "Enter path to .json collection file..."
+        match app.import_dialog_format {
+            ImportFormat::Postman => "Enter path to .json collection file...",
+            ImportFormat::OpenApi => "Enter path to .json/.yaml OpenAPI spec...",
+            ImportFormat::Insomnia => "Enter path to a .json/.yaml Insomnia export...",
+            ImportFormat::Bruno => "Enter path to a Bruno collection folder...",
+            ImportFormat::TemplateBundle => "Enter path to a .tuibundle file...",
+        }
     } else {
         &app.import_dialog_file_path
     };
@@ -73,10 +92,10 @@ pub fn render_import_dialog(f: &mut Frame, area: Rect, app: &App) {
         )),
         Line::from("  ~/Downloads/my-collection.json"),
         Line::from("  /Users/username/Desktop/api-tests.postman_collection.json"),
-        Line::from("  ./collections/sample.json"),
+        Line::from("  ~/Downloads/Insomnia_2024-01-01.json  •  ~/bruno/my-api/"),
         Line::from(""),
         Line::from(Span::styled(
-            "💡 Tip: Drag & drop files to terminal (some terminals)",
+            "💡 Insomnia exports and Bruno folders are detected automatically",
             Style::default().fg(Color::Gray),
         )),
     ];
@@ -91,8 +110,31 @@ pub fn render_import_dialog(f: &mut Frame, area: Rect, app: &App) {
         .style(Style::default().fg(Color::White));
     f.render_widget(instructions_widget, chunks[2]);
 
-    // Error message or validation
-    if let Some(error) = &app.import_dialog_error {
+    // Import progress, error message or validation
+    if let Some(progress) = &app.import_progress {
+        let label = if app.import_cancel.load(Ordering::Relaxed) {
+            "Cancelling...".to_string()
+        } else {
+            format!(
+                "{} / {}  •  {} requests, {} folders",
+                format_bytes(progress.bytes_read as usize),
+                format_bytes(progress.total_bytes as usize),
+                progress.requests,
+                progress.folders
+            )
+        };
+        let gauge = Gauge::default()
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Importing")
+                    .style(Style::default().bg(Color::DarkGray)),
+            )
+            .gauge_style(Style::default().fg(Color::Green))
+            .ratio(progress.ratio())
+            .label(label);
+        f.render_widget(gauge, chunks[3]);
+    } else if let Some(error) = &app.import_dialog_error {
         let error_msg = Paragraph::new(error.as_str())
             .block(
                 Block::default()
@@ -104,7 +146,8 @@ pub fn render_import_dialog(f: &mut Frame, area: Rect, app: &App) {
         f.render_widget(error_msg, chunks[3]);
     } else if !app.import_dialog_file_path.is_empty() {
         // Show file validation status
-        let (status_text, status_color) = validate_file_path_display(&app.import_dialog_file_path);
+        let (status_text, status_color) =
+            validate_file_path_display(&app.import_dialog_file_path, app.import_dialog_format);
         let status_msg = Paragraph::new(status_text)
             .block(
                 Block::default()
@@ -118,26 +161,51 @@ pub fn render_import_dialog(f: &mut Frame, area: Rect, app: &App) {
 
     // Preview info (if file is valid)
     if let Some(preview) = &app.import_dialog_preview {
-        let preview_lines = vec![
-            Line::from(vec![
-                Span::raw("Collection: "),
-                Span::styled(&preview.name, Style::default().fg(Color::Green)),
-            ]),
-            Line::from(vec![
+        let is_bundle = app.import_dialog_format == ImportFormat::TemplateBundle;
+        let mut preview_lines = vec![Line::from(vec![
+            Span::raw(if is_bundle {
+                "Template: "
+            } else {
+                "Collection: "
+            }),
+            Span::styled(&preview.name, Style::default().fg(Color::Green)),
+        ])];
+        if is_bundle {
+            preview_lines.push(Line::from(vec![
+                Span::raw("Fields: "),
+                Span::styled(
+                    preview.request_count.to_string(),
+                    Style::default().fg(Color::Yellow),
+                ),
+            ]));
+            preview_lines.push(Line::from(vec![
+                Span::raw("Website: "),
+                Span::styled(
+                    preview.description.clone().unwrap_or_default(),
+                    Style::default().fg(Color::Cyan),
+                ),
+            ]));
+        } else if preview.counts_known {
+            preview_lines.push(Line::from(vec![
                 Span::raw("Requests: "),
                 Span::styled(
                     preview.request_count.to_string(),
                     Style::default().fg(Color::Yellow),
                 ),
-            ]),
-            Line::from(vec![
+            ]));
+            preview_lines.push(Line::from(vec![
                 Span::raw("Folders: "),
                 Span::styled(
                     preview.folder_count.to_string(),
                     Style::default().fg(Color::Cyan),
                 ),
-            ]),
-        ];
+            ]));
+        } else {
+            preview_lines.push(Line::from(Span::styled(
+                "Large file: requests are counted while importing",
+                Style::default().fg(Color::Gray),
+            )));
+        }
 
         let preview_widget = Paragraph::new(preview_lines)
             .block(
@@ -163,7 +231,13 @@ pub fn render_import_dialog(f: &mut Frame, area: Rect, app: &App) {
     // Buttons
     let can_import = app.import_dialog_preview.is_some() && app.import_dialog_error.is_none();
 
-    let buttons = if can_import {
+    let buttons = if app.import_progress.is_some() {
+        vec![Line::from(vec![
+            Span::styled("Esc", Style::default().fg(Color::Red)),
+            Span::raw(": "),
+            Span::styled("Cancel import", Style::default().fg(Color::Red)),
+        ])]
+    } else if can_import {
         vec![Line::from(vec![
             Span::styled("Enter", Style::default().fg(Color::Green)),
             Span::raw(": "),
@@ -195,39 +269,46 @@ pub fn render_import_dialog(f: &mut Frame, area: Rect, app: &App) {
 }
 
 /// Validate file path and return display text and color
-fn validate_file_path_display(file_path: &str) -> (String, Color) {
+fn validate_file_path_display(file_path: &str, format: ImportFormat) -> (String, Color) {
     use std::path::Path;
 
     let path = Path::new(file_path);
+    let extension = path.extension().and_then(|s| s.to_str()).unwrap_or("");
 
     if !path.exists() {
         ("❌ File does not exist".to_string(), Color::Red)
+    } else if format == ImportFormat::Bruno {
+        if is_bruno_collection(path) {
+            ("✅ Bruno collection found".to_string(), Color::Green)
+        } else {
+            (
+                "⚠️  Folder should contain bruno.json or .bru files".to_string(),
+                Color::Yellow,
+            )
+        }
     } else if !path.is_file() {
         ("❌ Path is not a file".to_string(), Color::Red)
-    } else if path.extension().and_then(|s| s.to_str()) != Some("json") {
+    } else if matches!(format, ImportFormat::OpenApi | ImportFormat::Insomnia) {
+        if matches!(extension, "json" | "yaml" | "yml") {
+            ("✅ File found".to_string(), Color::Green)
+        } else {
+            (
+                "⚠️  File should be .json, .yaml or .yml".to_string(),
+                Color::Yellow,
+            )
+        }
+    } else if format == ImportFormat::TemplateBundle {
+        if extension == BUNDLE_EXTENSION {
+            ("✅ Bundle file found".to_string(), Color::Green)
+        } else {
+            (
+                format!("⚠️  File should be .{}", BUNDLE_EXTENSION),
+                Color::Yellow,
+            )
+        }
+    } else if extension != "json" {
         ("⚠️  File should be .json format".to_string(), Color::Yellow)
     } else {
         ("✅ Valid JSON file found".to_string(), Color::Green)
     }
 }
-
-/// Helper function to create a centered rectangle
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
-}
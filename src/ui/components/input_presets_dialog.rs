@@ -0,0 +1,121 @@
+use crate::app::App;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// Render the automation input presets picker with the selected preset's values
+pub fn render_input_presets_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(70, 60, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),    // Presets
+            Constraint::Min(5),    // Values of the selected preset
+            Constraint::Length(3), // Name input or instructions
+        ])
+        .split(popup_area);
+
+    let presets = &app.config.input_presets;
+    let items: Vec<ListItem> = if presets.is_empty() {
+        vec![ListItem::new(Line::from(Span::styled(
+            "No presets yet - fill in the form and press s to save one",
+            Style::default().fg(Color::Gray),
+        )))]
+    } else {
+        presets
+            .iter()
+            .map(|preset| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(preset.name.clone(), Style::default().fg(Color::Cyan)),
+                    Span::styled(
+                        format!("  {} value(s)", preset.field_values.len()),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ]))
+            })
+            .collect()
+    };
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("🎛️ Input Presets")
+                .title_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+        );
+    let selected = (!presets.is_empty()).then_some(app.input_presets_selected);
+    let mut list_state = ListState::default().with_selected(selected);
+    f.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    // Values the selected preset would set; fields it doesn't name keep their values
+    let value_lines: Vec<Line> = presets
+        .get(app.input_presets_selected)
+        .map(|preset| {
+            preset
+                .field_values
+                .iter()
+                .map(|(field, value)| {
+                    let known = app
+                        .automation_state
+                        .fields
+                        .iter()
+                        .any(|form_field| &form_field.name == field);
+                    Line::from(vec![
+                        Span::styled(
+                            format!("{}: ", field),
+                            Style::default().fg(if known {
+                                Color::Yellow
+                            } else {
+                                Color::DarkGray
+                            }),
+                        ),
+                        Span::styled(value.clone(), Style::default().fg(Color::White)),
+                    ])
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let values = Paragraph::new(value_lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Values (applied on top of the current form)")
+            .style(Style::default().bg(Color::DarkGray)),
+    );
+    f.render_widget(values, chunks[1]);
+
+    let footer = match &app.input_preset_name_input {
+        Some(name) => Paragraph::new(format!("{}█", name))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Preset name (Enter: save, Esc: cancel)")
+                    .border_style(Style::default().fg(Color::Blue))
+                    .style(Style::default().bg(Color::DarkGray)),
+            )
+            .style(Style::default().fg(Color::White)),
+        None => Paragraph::new(
+            "j/k: Move  |  Enter: Apply  |  s: Save form as preset  |  D: Delete  |  Esc: Close",
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Controls")
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .style(Style::default().fg(Color::Gray)),
+    };
+    f.render_widget(footer, chunks[2]);
+}
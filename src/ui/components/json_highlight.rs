@@ -0,0 +1,123 @@
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+
+/// Whether a response body should be treated as JSON (by content type, or by parsing it)
+pub fn is_json_body(body: &str, content_type: &str) -> bool {
+    if content_type.contains("json") {
+        return true;
+    }
+    let trimmed = body.trim_start();
+    (trimmed.starts_with('{') || trimmed.starts_with('['))
+        && serde_json::from_str::<serde_json::Value>(body).is_ok()
+}
+
+/// Pretty-print a JSON body; `None` if it doesn't parse
+pub fn pretty_print_json(body: &str) -> Option<String> {
+    let parsed = serde_json::from_str::<serde_json::Value>(body).ok()?;
+    serde_json::to_string_pretty(&parsed).ok()
+}
+
+/// Color JSON text line by line: keys, strings, numbers, booleans/null and punctuation
+pub fn highlight_json(text: &str) -> Vec<Line<'static>> {
+    text.lines().map(highlight_json_line).collect()
+}
+
+fn highlight_json_line(line: &str) -> Line<'static> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut spans = Vec::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let c = chars[index];
+        let start = index;
+
+        if c == '"' {
+            // String: runs to the next unescaped quote (or the end of the line)
+            index += 1;
+            while index < chars.len() && chars[index] != '"' {
+                if chars[index] == '\\' {
+                    index += 1;
+                }
+                index += 1;
+            }
+            index = (index + 1).min(chars.len());
+
+            let is_key = chars[index..]
+                .iter()
+                .find(|c| !c.is_whitespace())
+                .is_some_and(|&c| c == ':');
+            let color = if is_key { Color::Cyan } else { Color::Green };
+            spans.push(token(&chars[start..index], color));
+        } else if c == '-' || c.is_ascii_digit() {
+            while index < chars.len()
+                && (chars[index].is_ascii_digit() || "+-.eE".contains(chars[index]))
+            {
+                index += 1;
+            }
+            spans.push(token(&chars[start..index], Color::Yellow));
+        } else if c.is_ascii_alphabetic() {
+            while index < chars.len() && chars[index].is_ascii_alphabetic() {
+                index += 1;
+            }
+            spans.push(token(&chars[start..index], Color::Magenta));
+        } else {
+            while index < chars.len()
+                && chars[index] != '"'
+                && chars[index] != '-'
+                && !chars[index].is_ascii_alphanumeric()
+            {
+                index += 1;
+            }
+            spans.push(token(&chars[start..index], Color::White));
+        }
+    }
+
+    Line::from(spans)
+}
+
+fn token(chars: &[char], color: Color) -> Span<'static> {
+    Span::styled(chars.iter().collect::<String>(), Style::default().fg(color))
+}
+
+/// Restyle characters `start..end` of a line on top of its existing colors,
+/// splitting spans where the range begins and ends
+pub fn highlight_range(
+    line: &Line<'static>,
+    start: usize,
+    end: usize,
+    style: Style,
+) -> Line<'static> {
+    let mut spans = Vec::new();
+    let mut offset = 0;
+    for span in &line.spans {
+        let chars: Vec<char> = span.content.chars().collect();
+        let (span_start, span_end) = (offset, offset + chars.len());
+        offset = span_end;
+        if span_end <= start || span_start >= end {
+            spans.push(span.clone());
+            continue;
+        }
+
+        let from = start.saturating_sub(span_start);
+        let to = (end - span_start).min(chars.len());
+        if from > 0 {
+            spans.push(Span::styled(
+                chars[..from].iter().collect::<String>(),
+                span.style,
+            ));
+        }
+        spans.push(Span::styled(
+            chars[from..to].iter().collect::<String>(),
+            span.style.patch(style),
+        ));
+        if to < chars.len() {
+            spans.push(Span::styled(
+                chars[to..].iter().collect::<String>(),
+                span.style,
+            ));
+        }
+    }
+    Line::from(spans).style(line.style)
+}
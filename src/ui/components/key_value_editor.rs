@@ -0,0 +1,117 @@
+use crate::models::secrets::MASK;
+use crate::models::{KeyValueColumn, KeyValueEditor, KeyValueEntry};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{List, ListItem, ListState, Paragraph},
+};
+
+/// Render a list of name/value rows with selection and inline editing.
+///
+/// Shared by headers, query params, form fields and variables so they all look
+/// and behave the same. `separator` is drawn between name and value (": ", "=").
+/// Secret values are masked unless `reveal_secrets` is set or the value is being edited.
+#[allow(clippy::too_many_arguments)]
+pub fn render_key_value_editor<T: KeyValueEntry>(
+    f: &mut Frame,
+    area: Rect,
+    rows: &[T],
+    editor: &KeyValueEditor,
+    separator: &str,
+    empty_label: &str,
+    is_focused: bool,
+    reveal_secrets: bool,
+) {
+    if rows.is_empty() {
+        let empty_text = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                empty_label.to_string(),
+                Style::default().fg(Color::DarkGray),
+            )),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Press 'a' to add a row",
+                Style::default().fg(Color::Gray),
+            )),
+        ];
+
+        f.render_widget(Paragraph::new(empty_text), area);
+        return;
+    }
+
+    let items: Vec<ListItem> = rows
+        .iter()
+        .enumerate()
+        .map(|(index, row)| {
+            let (name, value, enabled) = row.parts();
+            let editing = if index == editor.selected {
+                editor.editing
+            } else {
+                None
+            };
+
+            let enabled_indicator = if enabled { "✓" } else { "✗" };
+            let style = if enabled {
+                Style::default().fg(Color::White)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+
+            let mut spans = vec![
+                Span::styled(format!("{} ", enabled_indicator), style),
+                cell(
+                    name,
+                    editor,
+                    editing == Some(KeyValueColumn::Name),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(separator.to_string(), Style::default().fg(Color::Cyan)),
+            ];
+            if let Some(marker) = row.value_marker() {
+                spans.push(Span::styled(marker, Style::default().fg(Color::Magenta)));
+            }
+            if row.is_secret() {
+                spans.push(Span::styled("🔒 ", Style::default().fg(Color::Magenta)));
+            }
+            let value = if row.is_secret() && !reveal_secrets && !value.is_empty() {
+                MASK
+            } else {
+                value
+            };
+            spans.push(cell(
+                value,
+                editor,
+                editing == Some(KeyValueColumn::Value),
+                style,
+            ));
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let highlight_style = if is_focused {
+        Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
+
+    let list = List::new(items).highlight_style(highlight_style);
+    let mut list_state = ListState::default().with_selected(Some(editor.selected));
+    f.render_stateful_widget(list, area, &mut list_state);
+}
+
+/// A single name or value cell, showing the edit buffer and cursor while editing
+fn cell(text: &str, editor: &KeyValueEditor, editing: bool, style: Style) -> Span<'static> {
+    if editing {
+        Span::styled(
+            format!("{}▏", editor.buffer),
+            Style::default().fg(Color::Yellow).bg(Color::Black),
+        )
+    } else {
+        Span::styled(text.to_string(), style)
+    }
+}
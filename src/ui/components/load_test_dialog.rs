@@ -0,0 +1,212 @@
+use crate::app::App;
+use crate::services::load_test::LoadTest;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Gauge, Paragraph},
+};
+
+/// Render the load test popup: iteration/concurrency settings, progress and latency numbers
+pub fn render_load_test_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(70, 70, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4), // Request and settings
+            Constraint::Length(3), // Progress
+            Constraint::Min(8),    // Results
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let request = &app.http_state.current_request;
+    let running = app.load_test_running();
+    let field_style = |index: usize| {
+        if !running && app.load_test_field == index {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        }
+    };
+    let settings = &app.load_test_settings;
+    let setup = Paragraph::new(vec![
+        Line::from(vec![
+            Span::styled(
+                format!("{} ", request.method.as_str()),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::raw(request.url.clone()),
+        ]),
+        Line::from(vec![
+            Span::raw("Requests: "),
+            Span::styled(format!(" {} ", settings.iterations), field_style(0)),
+            Span::raw("   Concurrency: "),
+            Span::styled(format!(" {} ", settings.concurrency), field_style(1)),
+        ]),
+    ])
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("🔥 Load Test")
+            .title_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::DarkGray)),
+    );
+    f.render_widget(setup, chunks[0]);
+
+    let Some(test) = &app.load_test else {
+        let empty =
+            Paragraph::new("Set the number of requests and how many run at once, then press Enter")
+                .style(Style::default().fg(Color::Gray))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .style(Style::default().bg(Color::DarkGray)),
+                );
+        f.render_widget(empty, chunks[1].union(chunks[2]));
+        render_instructions(f, chunks[3], running);
+        return;
+    };
+
+    let done = test.samples.len();
+    let total = test.settings.iterations.max(1);
+    let state = if test.cancelled {
+        "Cancelled"
+    } else if test.finished() {
+        "Finished"
+    } else {
+        "Running"
+    };
+    let gauge = Gauge::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("{} ({})", test.request_name, state))
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .gauge_style(Style::default().fg(if test.error_count() == 0 {
+            Color::Green
+        } else {
+            Color::Yellow
+        }))
+        .ratio((done as f64 / total as f64).min(1.0))
+        .label(format!(
+            "{}/{}  ⏱ {:.1} s",
+            done,
+            test.settings.iterations,
+            test.wall_time_ms() as f64 / 1000.0
+        ));
+    f.render_widget(gauge, chunks[1]);
+
+    let results = Paragraph::new(result_lines(test)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Results")
+            .style(Style::default().bg(Color::DarkGray)),
+    );
+    f.render_widget(results, chunks[2]);
+
+    render_instructions(f, chunks[3], running);
+}
+
+/// Latency percentiles, error rate, throughput and status breakdown
+fn result_lines(test: &LoadTest) -> Vec<Line<'static>> {
+    let label =
+        |text: &str| Span::styled(format!("{:<12}", text), Style::default().fg(Color::Gray));
+    let millis =
+        |value: Option<u64>| value.map_or_else(|| "-".to_string(), |ms| format!("{} ms", ms));
+
+    let error_color = if test.error_count() == 0 {
+        Color::Green
+    } else {
+        Color::Red
+    };
+    let statuses: Vec<String> = test
+        .status_counts()
+        .into_iter()
+        .map(|(status, count)| format!("{} ×{}", status, count))
+        .collect();
+
+    let mut lines = vec![
+        Line::from(vec![
+            label("Latency"),
+            Span::styled(
+                format!(
+                    "p50 {}   p95 {}   p99 {}",
+                    millis(test.percentile(50.0)),
+                    millis(test.percentile(95.0)),
+                    millis(test.percentile(99.0))
+                ),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            label(""),
+            Span::styled(
+                format!(
+                    "min {}   max {}",
+                    millis(test.percentile(0.0)),
+                    millis(test.percentile(100.0))
+                ),
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+        Line::from(vec![
+            label("Errors"),
+            Span::styled(
+                format!(
+                    "{:.1}% ({} of {})",
+                    test.error_rate() * 100.0,
+                    test.error_count(),
+                    test.samples.len()
+                ),
+                Style::default().fg(error_color),
+            ),
+        ]),
+        Line::from(vec![
+            label("Throughput"),
+            Span::styled(
+                format!("{:.1} req/s", test.throughput()),
+                Style::default().fg(Color::White),
+            ),
+        ]),
+        Line::from(vec![
+            label("Statuses"),
+            Span::styled(statuses.join("   "), Style::default().fg(Color::White)),
+        ]),
+    ];
+    if let Some(error) = test.last_error() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("Last error: {}", error),
+            Style::default().fg(Color::Red),
+        )));
+    }
+    lines
+}
+
+fn render_instructions(f: &mut Frame, area: Rect, running: bool) {
+    let text = if running {
+        "c: Cancel  |  Esc: Close (test continues)"
+    } else {
+        "Tab: Switch field  |  0-9/Backspace or +/-: Edit  |  Enter: Start  |  Esc: Close"
+    };
+    let instructions = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Controls")
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, area);
+}
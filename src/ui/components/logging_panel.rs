@@ -1,5 +1,5 @@
 use crate::app::{App, FocusedPane};
-use crate::models::LogEntry;
+use crate::models::{LogEntry, LogTimeFilter};
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
@@ -83,7 +83,7 @@ fn render_log_entries(f: &mut Frame, area: Rect, app: &App, is_focused: bool) {
 
     let items: Vec<ListItem> = visible_logs
         .iter()
-        .map(|log_entry| create_log_list_item(log_entry))
+        .map(|log_entry| create_log_list_item(log_entry, app))
         .collect();
 
     // Build title with scroll indicators and focus state
@@ -96,7 +96,13 @@ fn render_log_entries(f: &mut Frame, area: Rect, app: &App, is_focused: bool) {
 
     let focus_indicator = if is_focused { " [FOCUSED]" } else { "" };
 
-    let log_count_info = if app.log_search_query.is_empty() {
+    let time_filter = if app.log_time_filter == LogTimeFilter::All {
+        String::new()
+    } else {
+        format!(", {}", app.log_time_filter.label())
+    };
+
+    let log_count_info = if !app.is_log_filter_active() {
         format!(
             "Logs ({} total){}{}",
             app.log_entries.len(),
@@ -105,9 +111,10 @@ fn render_log_entries(f: &mut Frame, area: Rect, app: &App, is_focused: bool) {
         )
     } else {
         format!(
-            "Logs ({} of {} shown){}{}",
+            "Logs ({} of {} shown{}){}{}",
             app.get_filtered_logs().len(),
             app.log_entries.len(),
+            time_filter,
             scroll_indicator,
             focus_indicator
         )
@@ -160,13 +167,23 @@ fn render_log_entries(f: &mut Frame, area: Rect, app: &App, is_focused: bool) {
 }
 
 /// Create a styled list item for a log entry
-fn create_log_list_item(log_entry: &LogEntry) -> ListItem {
+fn create_log_list_item<'a>(log_entry: &'a LogEntry, app: &App) -> ListItem<'a> {
+    if log_entry.is_marker {
+        return ListItem::new(Line::from(vec![
+            Span::styled(
+                format!("[{}] ", app.format_timestamp(&log_entry.timestamp)),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled(&log_entry.message, Style::default().fg(Color::Magenta)),
+        ]));
+    }
+
     let level_style = log_entry.level.style();
 
     let line = Line::from(vec![
         // Timestamp
         Span::styled(
-            format!("[{}]", log_entry.timestamp.format("%H:%M:%S")),
+            format!("[{}]", app.format_timestamp(&log_entry.timestamp)),
             Style::default().fg(Color::DarkGray),
         ),
         Span::raw(" "),
@@ -205,7 +222,7 @@ pub fn render_log_summary(f: &mut Frame, area: Rect, app: &App) {
 
         lines.push(Line::from(vec![
             Span::styled(
-                format!("[{}]", log_entry.timestamp.format("%H:%M:%S")),
+                format!("[{}]", app.format_timestamp(&log_entry.timestamp)),
                 Style::default().fg(Color::DarkGray),
             ),
             Span::raw(" "),
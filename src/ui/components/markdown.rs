@@ -0,0 +1,194 @@
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+/// Render basic markdown for display: headings, lists, quotes, rules, fenced code,
+/// and inline code, bold, italic and links
+pub fn markdown_lines(text: &str) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut in_code_block = false;
+
+    for raw in text.lines() {
+        let trimmed = raw.trim_start();
+        if trimmed.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", raw),
+                Style::default().fg(Color::Yellow),
+            )));
+            continue;
+        }
+
+        let heading_level = trimmed.chars().take_while(|c| *c == '#').count();
+        if (1..=6).contains(&heading_level) && trimmed[heading_level..].starts_with(' ') {
+            let style = match heading_level {
+                1 => Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                2 => Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+                _ => Style::default()
+                    .fg(Color::LightBlue)
+                    .add_modifier(Modifier::BOLD),
+            };
+            lines.push(Line::from(Span::styled(
+                trimmed[heading_level..].trim().to_string(),
+                style,
+            )));
+            continue;
+        }
+
+        if is_rule(trimmed) {
+            lines.push(Line::from(Span::styled(
+                "─".repeat(40),
+                Style::default().fg(Color::DarkGray),
+            )));
+            continue;
+        }
+
+        if let Some(quote) = trimmed.strip_prefix('>') {
+            let mut spans = vec![Span::styled("│ ", Style::default().fg(Color::DarkGray))];
+            spans.extend(inline_spans(
+                quote.trim_start(),
+                Style::default()
+                    .fg(Color::Gray)
+                    .add_modifier(Modifier::ITALIC),
+            ));
+            lines.push(Line::from(spans));
+            continue;
+        }
+
+        let indent = " ".repeat(raw.len() - trimmed.len());
+        let base = Style::default().fg(Color::White);
+        if let Some(item) = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|marker| trimmed.strip_prefix(marker))
+        {
+            let mut spans = vec![Span::styled(
+                format!("{}  • ", indent),
+                Style::default().fg(Color::Green),
+            )];
+            spans.extend(inline_spans(item, base));
+            lines.push(Line::from(spans));
+            continue;
+        }
+        if let Some((number, item)) = trimmed.split_once(". ")
+            && !number.is_empty()
+            && number.chars().all(|c| c.is_ascii_digit())
+        {
+            let mut spans = vec![Span::styled(
+                format!("{}  {}. ", indent, number),
+                Style::default().fg(Color::Green),
+            )];
+            spans.extend(inline_spans(item, base));
+            lines.push(Line::from(spans));
+            continue;
+        }
+
+        lines.push(Line::from(inline_spans(raw, base)));
+    }
+
+    lines
+}
+
+fn is_rule(line: &str) -> bool {
+    let line = line.trim_end();
+    line.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|marker| line.chars().all(|c| c == *marker))
+}
+
+/// Inline `code`, **bold**, *italic* / _italic_ and [text](url) within one line
+fn inline_spans(text: &str, base: Style) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+
+    let flush = |plain: &mut String, spans: &mut Vec<Span<'static>>| {
+        if !plain.is_empty() {
+            spans.push(Span::styled(std::mem::take(plain), base));
+        }
+    };
+
+    while let Some(c) = rest.chars().next() {
+        // snake_case words aren't emphasis
+        let inside_word = plain.chars().last().is_some_and(char::is_alphanumeric);
+        let styled = match c {
+            '`' => delimited(rest, "`").map(|(inner, after)| {
+                (
+                    vec![Span::styled(
+                        inner.to_string(),
+                        Style::default().fg(Color::Yellow),
+                    )],
+                    after,
+                )
+            }),
+            '_' if inside_word => None,
+            '*' | '_' if rest.starts_with("**") || rest.starts_with("__") => {
+                delimited(rest, &rest[..2]).map(|(inner, after)| {
+                    (
+                        inline_spans(inner, base.add_modifier(Modifier::BOLD)),
+                        after,
+                    )
+                })
+            }
+            '*' | '_' => delimited(rest, &rest[..1]).map(|(inner, after)| {
+                (
+                    inline_spans(inner, base.add_modifier(Modifier::ITALIC)),
+                    after,
+                )
+            }),
+            '[' => link(rest).map(|(label, url, after)| {
+                (
+                    vec![
+                        Span::styled(
+                            label.to_string(),
+                            base.fg(Color::LightBlue).add_modifier(Modifier::UNDERLINED),
+                        ),
+                        Span::styled(format!(" ({})", url), Style::default().fg(Color::DarkGray)),
+                    ],
+                    after,
+                )
+            }),
+            _ => None,
+        };
+
+        match styled {
+            Some((mut inner, after)) => {
+                flush(&mut plain, &mut spans);
+                spans.append(&mut inner);
+                rest = after;
+            }
+            None => {
+                plain.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    flush(&mut plain, &mut spans);
+    spans
+}
+
+/// Text between an opening `marker` at the start of `text` and the next one
+fn delimited<'a>(text: &'a str, marker: &str) -> Option<(&'a str, &'a str)> {
+    let after_open = &text[marker.len()..];
+    let end = after_open.find(marker)?;
+    (end > 0).then(|| (&after_open[..end], &after_open[end + marker.len()..]))
+}
+
+/// `[label](url)` at the start of `text`
+fn link(text: &str) -> Option<(&str, &str, &str)> {
+    let label_end = text.find("](")?;
+    let url_end = text[label_end + 2..].find(')')? + label_end + 2;
+    Some((
+        &text[1..label_end],
+        &text[label_end + 2..url_end],
+        &text[url_end + 1..],
+    ))
+}
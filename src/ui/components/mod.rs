@@ -1,24 +1,91 @@
+pub mod audit_log_dialog;
 pub mod automation_form;
+pub mod backup_dialog;
+pub mod clone_request_dialog;
+pub mod collection_run_dialog;
 pub mod collections_tree;
-pub mod delete_confirmation_dialog;
+pub mod confirmation_dialog;
+pub mod curl_import_dialog;
+pub mod draft_restore_dialog;
+pub mod environment_dialog;
+pub mod environment_switcher;
+pub mod field_widgets;
 pub mod folder_dialog;
+pub mod folder_readme;
+pub mod folder_settings_dialog;
+pub mod global_search;
+pub mod health_panel;
 pub mod help_dialog;
+pub mod history_dialog;
+pub mod history_palette;
 pub mod http_request_editor;
 pub mod http_response_viewer;
 pub mod import_dialog;
+pub mod input_presets_dialog;
+pub mod json_highlight;
+pub mod key_value_editor;
+pub mod load_test_dialog;
 pub mod logging_panel;
+pub mod markdown;
+pub mod note_dialog;
+pub mod perf_overlay;
+pub mod quick_switcher;
+pub mod raw_headers_dialog;
 pub mod rename_dialog;
+pub mod response_diff_dialog;
+pub mod run_queue_dialog;
+pub mod run_timeline;
+pub mod settings_dialog;
+pub mod snippet_dialog;
 pub mod status_line;
 pub mod template_dialog;
+pub mod template_info_dialog;
+pub mod transfer_stats_dialog;
+pub mod tutorial_panel;
+pub mod update_notice;
+pub mod url_editor_dialog;
+pub mod whats_new;
 
 // Re-export components for easier imports
+pub use audit_log_dialog::render_audit_log_dialog;
 pub use automation_form::{render_automation_form, render_login_popup};
+pub use backup_dialog::render_backup_dialog;
+pub use clone_request_dialog::render_clone_request_dialog;
+pub use collection_run_dialog::render_collection_run_dialog;
 pub use collections_tree::{get_tree_help_text, render_collections_tree};
-pub use delete_confirmation_dialog::render_delete_confirmation_dialog;
+pub use confirmation_dialog::render_confirmation_dialog;
+pub use curl_import_dialog::render_curl_import_dialog;
+pub use draft_restore_dialog::render_draft_restore_dialog;
+pub use environment_dialog::render_environment_dialog;
+pub use environment_switcher::render_environment_switcher;
 pub use folder_dialog::render_folder_creation_dialog;
+pub use folder_readme::render_folder_readme;
+pub use folder_settings_dialog::render_folder_settings_dialog;
+pub use global_search::render_global_search;
+pub use health_panel::render_health_panel;
 pub use help_dialog::render_help_dialog;
+pub use history_dialog::render_history_dialog;
+pub use history_palette::render_history_palette;
 pub use import_dialog::render_import_dialog;
+pub use input_presets_dialog::render_input_presets_dialog;
+pub use key_value_editor::render_key_value_editor;
+pub use load_test_dialog::render_load_test_dialog;
 pub use logging_panel::{render_log_stats, render_log_summary, render_logging_panel};
+pub use note_dialog::render_note_dialog;
+pub use perf_overlay::render_perf_overlay;
+pub use quick_switcher::render_quick_switcher;
+pub use raw_headers_dialog::render_raw_headers_dialog;
 pub use rename_dialog::render_rename_dialog;
+pub use response_diff_dialog::render_response_diff_dialog;
+pub use run_queue_dialog::render_run_queue_dialog;
+pub use run_timeline::render_run_timeline;
+pub use settings_dialog::render_settings_dialog;
+pub use snippet_dialog::render_snippet_dialog;
 pub use status_line::{get_mode_indicator, render_status_line};
 pub use template_dialog::render_template_creation_dialog;
+pub use template_info_dialog::render_template_info_dialog;
+pub use transfer_stats_dialog::render_transfer_stats_dialog;
+pub use tutorial_panel::render_tutorial_panel;
+pub use update_notice::render_update_notice;
+pub use url_editor_dialog::render_url_editor_dialog;
+pub use whats_new::render_whats_new;
@@ -0,0 +1,97 @@
+use crate::app::App;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+/// Render the notes popup: freeform text and an optional "review by" date
+pub fn render_note_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let Some(dialog) = app.note_dialog.as_ref() else {
+        return;
+    };
+    let popup_area = centered_rect(60, 60, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),    // Note text
+            Constraint::Length(3), // Review by
+            Constraint::Length(3), // Error or hint
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let active = Style::default().fg(Color::Yellow);
+    let inactive = Style::default().fg(Color::Gray);
+
+    let mut text = dialog.text.clone();
+    if !dialog.editing_date {
+        text.push('▏');
+    }
+    let note = Paragraph::new(text)
+        .wrap(Wrap { trim: false })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("📝 Note on '{}'", dialog.path))
+                .title_style(Style::default().fg(Color::Cyan))
+                .border_style(if dialog.editing_date {
+                    inactive
+                } else {
+                    active
+                })
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .style(Style::default().fg(Color::White));
+    f.render_widget(note, chunks[0]);
+
+    let mut review_by = dialog.review_by.clone();
+    if dialog.editing_date {
+        review_by.push('▏');
+    }
+    let date = Paragraph::new(review_by)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Review by (YYYY-MM-DD, +3d, +2w; empty for none)")
+                .border_style(if dialog.editing_date {
+                    active
+                } else {
+                    inactive
+                })
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .style(Style::default().fg(Color::White));
+    f.render_widget(date, chunks[1]);
+
+    let status = match &dialog.error {
+        Some(error) => Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red))),
+        None => Line::from(Span::styled(
+            "A due date shows a reminder in the status line; clear it once reviewed",
+            Style::default().fg(Color::Gray),
+        )),
+    };
+    let status = Paragraph::new(status).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .style(Style::default().bg(Color::DarkGray)),
+    );
+    f.render_widget(status, chunks[2]);
+
+    let instructions = Paragraph::new(
+        "Tab: Text/date  |  Enter: New line (saves in date)  |  Ctrl+S: Save  |  Esc: Cancel",
+    )
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Controls")
+            .style(Style::default().bg(Color::DarkGray)),
+    )
+    .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[3]);
+}
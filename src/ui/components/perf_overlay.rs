@@ -0,0 +1,99 @@
+use crate::app::App;
+use crate::ui::components::http_response_viewer::format_bytes;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use std::time::Duration;
+
+/// Frames slower than this miss the 20 FPS tick
+const SLOW_FRAME: Duration = Duration::from_millis(50);
+
+/// Render the performance overlay in the top-right corner
+pub fn render_perf_overlay(f: &mut Frame, area: Rect, app: &App) {
+    let stats = &app.perf_stats;
+    let buffers = app.buffer_usage();
+
+    let timing = |label: &str, average: Duration, max: Duration| {
+        Line::from(vec![
+            Span::styled(format!("{:<8}", label), Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!(
+                    "{:>6.1}ms avg {:>6.1}ms max",
+                    average.as_secs_f64() * 1000.0,
+                    max.as_secs_f64() * 1000.0
+                ),
+                Style::default().fg(if max > SLOW_FRAME {
+                    Color::Yellow
+                } else {
+                    Color::White
+                }),
+            ),
+        ])
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(format!("{:<8}", "FPS"), Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("{:>6.1}", stats.fps()),
+                Style::default().fg(Color::White),
+            ),
+        ]),
+        timing(
+            "Render",
+            stats.render_times.average(),
+            stats.render_times.max(),
+        ),
+        timing(
+            "Loop",
+            stats.loop_latencies.average(),
+            stats.loop_latencies.max(),
+        ),
+        Line::from(vec![
+            Span::styled(format!("{:<8}", "Queue"), Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!(
+                    "{:>6} now {:>6} max",
+                    stats.queue_depths.last(),
+                    stats.queue_depths.max()
+                ),
+                Style::default().fg(Color::White),
+            ),
+        ]),
+        Line::from(""),
+    ];
+    for buffer in &buffers {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:<10}", buffer.label),
+                Style::default().fg(Color::Gray),
+            ),
+            Span::styled(
+                format!(
+                    "{:>7} items {:>9}",
+                    buffer.items,
+                    format_bytes(buffer.bytes)
+                ),
+                Style::default().fg(Color::White),
+            ),
+        ]));
+    }
+
+    let width = 42.min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let overlay_area = Rect::new(area.right().saturating_sub(width), area.y, width, height);
+    f.render_widget(Clear, overlay_area);
+
+    let overlay = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("📈 Performance (Ctrl+G)")
+            .title_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black)),
+    );
+    f.render_widget(overlay, overlay_area);
+}
@@ -0,0 +1,79 @@
+use crate::app::App;
+use crate::models::RecentItemKind;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// Render the recent items quick switcher
+pub fn render_quick_switcher(f: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(60, 50, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),    // Recent items
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let items: Vec<ListItem> = app
+        .config
+        .recent_items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let (icon, color) = match item.kind {
+                RecentItemKind::Template => ("📋", Color::Green),
+                RecentItemKind::Request => ("🌐", Color::Cyan),
+            };
+            let folder = if item.folder().is_empty() {
+                "Root"
+            } else {
+                item.folder()
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{} ", index + 1),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::raw(format!("{} ", icon)),
+                Span::styled(item.name().to_string(), Style::default().fg(color)),
+                Span::styled(format!("  {}", folder), Style::default().fg(Color::Gray)),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("🔀 Recent Items")
+                .title_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+        );
+    let mut list_state = ListState::default().with_selected(Some(app.quick_switcher_selected));
+    f.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    let instructions =
+        Paragraph::new("Tab/j/k: Move  |  Enter: Open  |  1-9: Open directly  |  Esc: Close")
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Controls")
+                    .style(Style::default().bg(Color::DarkGray)),
+            )
+            .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[1]);
+}
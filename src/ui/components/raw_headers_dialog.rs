@@ -0,0 +1,103 @@
+use crate::app::App;
+use crate::services::raw_headers::parse_raw_headers;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+/// Render the raw `Name: Value` editor for the request headers
+pub fn render_raw_headers_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let Some(raw) = &app.raw_headers_text else {
+        return;
+    };
+    let popup_area = centered_rect(70, 60, area);
+
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),    // Header lines
+            Constraint::Length(3), // Preview / error
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let (text, style) = if raw.is_empty() {
+        (
+            "Paste headers copied from devtools, one per line, e.g.\nAccept: application/json"
+                .to_string(),
+            Style::default().fg(Color::DarkGray),
+        )
+    } else {
+        (format!("{}▏", raw), Style::default().fg(Color::White))
+    };
+    let title = if app.raw_headers_defaults {
+        "📝 Default Headers (sent with every request that doesn't set its own)"
+    } else {
+        "📝 Raw Headers"
+    };
+    let editor = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .title_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::DarkGray))
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .style(style)
+        .wrap(Wrap { trim: false });
+    f.render_widget(editor, chunks[0]);
+
+    // Live preview of what applying would do
+    let preview = if let Some(error) = &app.raw_headers_error {
+        Span::styled(format!("❌ {}", error), Style::default().fg(Color::Red))
+    } else {
+        match parse_raw_headers(raw) {
+            Ok(parsed) => {
+                let disabled = parsed
+                    .headers
+                    .iter()
+                    .filter(|header| !header.enabled)
+                    .count();
+                let mut summary = format!("✅ {} header(s)", parsed.headers.len());
+                if disabled > 0 {
+                    summary.push_str(&format!(", {} disabled (#)", disabled));
+                }
+                if !parsed.skipped.is_empty() {
+                    summary.push_str(&format!(", leaving out {}", parsed.skipped.join(", ")));
+                }
+                Span::styled(summary, Style::default().fg(Color::Green))
+            }
+            Err(e) => Span::styled(format!("⚠️  {}", e), Style::default().fg(Color::Yellow)),
+        }
+    };
+    let preview = Paragraph::new(Line::from(preview))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Preview")
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(preview, chunks[1]);
+
+    let instructions = Paragraph::new(if app.raw_headers_defaults {
+        "Ctrl+S: Save defaults  |  Enter: New line  |  Del: Clear  |  Esc: Cancel"
+    } else {
+        "Ctrl+S: Replace headers  |  Enter: New line  |  Del: Clear  |  Esc: Cancel"
+    })
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Controls")
+            .style(Style::default().bg(Color::DarkGray)),
+    )
+    .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[2]);
+}
@@ -0,0 +1,120 @@
+use crate::app::App;
+use crate::services::response_diff::{DiffKind, DiffLine};
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+/// Render the response diff overlay: what was compared, a summary and the line diff
+pub fn render_response_diff_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let Some(diff) = &app.response_diff else {
+        return;
+    };
+
+    let popup_area = centered_rect(90, 85, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4), // Compared responses + summary
+            Constraint::Min(3),    // Diff lines
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let (added, removed, changed) = diff.summary();
+    let summary = if diff.is_identical() {
+        Span::styled("No differences", Style::default().fg(Color::Green))
+    } else {
+        Span::styled(
+            format!(
+                "+{} added  -{} removed  ~{} changed",
+                added, removed, changed
+            ),
+            Style::default().fg(Color::Yellow),
+        )
+    };
+    let header = Paragraph::new(vec![
+        Line::from(vec![
+            Span::styled("- ", Style::default().fg(Color::Red)),
+            Span::raw(diff.old_label.clone()),
+        ]),
+        Line::from(vec![
+            Span::styled("+ ", Style::default().fg(Color::Green)),
+            Span::raw(diff.new_label.clone()),
+            Span::raw("   "),
+            summary,
+        ]),
+    ])
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("🔀 Response Diff")
+            .title_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::DarkGray)),
+    );
+    f.render_widget(header, chunks[0]);
+
+    let visible_height = chunks[1].height.saturating_sub(2) as usize;
+    let lines: Vec<Line> = diff
+        .lines
+        .iter()
+        .skip(app.response_diff_scroll)
+        .take(visible_height)
+        .flat_map(render_diff_line)
+        .take(visible_height)
+        .collect();
+
+    let body = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "Lines {}-{} of {}",
+                (app.response_diff_scroll + 1).min(diff.lines.len()),
+                (app.response_diff_scroll + visible_height).min(diff.lines.len()),
+                diff.lines.len()
+            ))
+            .style(Style::default().bg(Color::Black)),
+    );
+    f.render_widget(body, chunks[1]);
+
+    let instructions = Paragraph::new(
+        "j/k: Scroll  |  PgUp/PgDn: Page  |  n/N: Next/previous change  |  g/G: Top/bottom  |  Esc: Close",
+    )
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Controls")
+            .style(Style::default().bg(Color::DarkGray)),
+    )
+    .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[2]);
+}
+
+/// A diff line with its gutter marker; changed lines show the old line above the new one
+fn render_diff_line(line: &DiffLine) -> Vec<Line<'static>> {
+    let styled = |marker: &str, text: &str, color: Color| {
+        Line::from(vec![
+            Span::styled(
+                format!("{} ", marker),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(text.to_string(), Style::default().fg(color)),
+        ])
+    };
+
+    match &line.kind {
+        DiffKind::Unchanged => vec![styled(" ", &line.text, Color::Gray)],
+        DiffKind::Added => vec![styled("+", &line.text, Color::Green)],
+        DiffKind::Removed => vec![styled("-", &line.text, Color::Red)],
+        DiffKind::Changed(old) => vec![
+            styled("~", old, Color::Red),
+            styled("~", &line.text, Color::Green),
+        ],
+    }
+}
@@ -0,0 +1,107 @@
+use crate::app::App;
+use crate::ui::layout::centered_rect;
+use chrono::Local;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// Render the automation run queue: what's running now and what starts next
+pub fn render_run_queue_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let state = &app.automation_state;
+    let popup_area = centered_rect(60, 60, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Current run
+            Constraint::Min(5),    // Queue
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let current = if state.is_running {
+        let elapsed = state
+            .run_started_at
+            .map(|started| (Local::now() - started).num_seconds())
+            .unwrap_or(0);
+        Span::styled(
+            format!("⏳ Running for {}s", elapsed),
+            Style::default().fg(Color::Yellow),
+        )
+    } else {
+        Span::styled("Idle", Style::default().fg(Color::Gray))
+    };
+    let current = Paragraph::new(Line::from(current)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Now")
+            .style(Style::default().bg(Color::DarkGray)),
+    );
+    f.render_widget(current, chunks[0]);
+
+    let items: Vec<ListItem> = if state.run_queue.is_empty() {
+        vec![ListItem::new(Span::styled(
+            "No runs waiting - starting a run while one is in progress offers to queue it",
+            Style::default().fg(Color::Gray),
+        ))]
+    } else {
+        state
+            .run_queue
+            .iter()
+            .enumerate()
+            .map(|(index, run)| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{}. ", index + 1),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(run.label.clone(), Style::default().fg(Color::White)),
+                    Span::styled(
+                        format!(
+                            "  queued {}",
+                            app.config.timestamp_format.format(&run.queued_at)
+                        ),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ]))
+            })
+            .collect()
+    };
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("⏸ Run Queue ({})", state.run_queue.len()))
+                .title_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::Blue)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("▶ ");
+    let mut list_state = ListState::default();
+    if !state.run_queue.is_empty() {
+        list_state.select(Some(app.run_queue_selected));
+    }
+    f.render_stateful_widget(list, chunks[1], &mut list_state);
+
+    let instructions = Paragraph::new(
+        "j/k: Select  |  Shift+J/K: Move  |  d: Cancel run  |  Enter: Start next if idle  |  Esc: Close",
+    )
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Controls")
+            .style(Style::default().bg(Color::DarkGray)),
+    )
+    .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[2]);
+}
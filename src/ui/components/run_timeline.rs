@@ -0,0 +1,65 @@
+use crate::models::TimestampFormat;
+use crate::modes::automation::AutomationState;
+use chrono::Local;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem},
+};
+
+/// Render the step timeline of the current automation run
+pub fn render_run_timeline(
+    f: &mut Frame,
+    area: Rect,
+    state: &AutomationState,
+    timestamp_format: TimestampFormat,
+) {
+    let mut items: Vec<ListItem> = state
+        .run_steps
+        .iter()
+        .map(|step| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{} ", timestamp_format.format(&step.at)),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(step.message.clone(), Style::default().fg(Color::White)),
+            ]))
+        })
+        .collect();
+
+    if state.is_running {
+        let elapsed = state
+            .run_started_at
+            .map(|started| (Local::now() - started).num_seconds())
+            .unwrap_or(0);
+        items.push(ListItem::new(Line::from(Span::styled(
+            format!("⏳ Running… {}s", elapsed),
+            Style::default().fg(Color::Yellow),
+        ))));
+    }
+
+    // Keep the newest steps in view
+    let visible = area.height.saturating_sub(2) as usize;
+    let skip = items.len().saturating_sub(visible);
+    let items: Vec<ListItem> = items.into_iter().skip(skip).collect();
+
+    let queued = match state.run_queue.len() {
+        0 => String::new(),
+        count => format!(" - {} queued, Ctrl+R", count),
+    };
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "🧭 Run Timeline ({} steps{})",
+                state.run_steps.len(),
+                queued
+            ))
+            .title_style(Style::default().fg(Color::Cyan)),
+    );
+
+    f.render_widget(list, area);
+}
@@ -0,0 +1,318 @@
+use crate::app::App;
+use crate::services::request_log::RequestLog;
+use crate::ui::components::field_widgets::checkbox_span;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+/// Render the settings dialog (storage directories, migration and update checks)
+pub fn render_settings_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(70, 90, area);
+
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Length(3), // Templates directory
+            Constraint::Length(3), // Collections directory
+            Constraint::Length(3), // Move data toggle
+            Constraint::Length(3), // Update check toggle
+            Constraint::Length(3), // JSON auto-format toggle
+            Constraint::Length(3), // Block invalid JSON toggle
+            Constraint::Length(3), // Timestamp format
+            Constraint::Length(3), // History retention
+            Constraint::Length(3), // Storage format
+            Constraint::Length(3), // Cookie persistence toggle
+            Constraint::Length(3), // Request log secrets toggle
+            Constraint::Length(3), // Idle auto-lock
+            Constraint::Length(3), // Response display limit
+            Constraint::Length(3), // Retry policy
+            Constraint::Length(3), // Backup schedule
+            Constraint::Length(3), // Error message (if any)
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    // Title
+    let title = Paragraph::new("⚙ Settings")
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Preferences")
+                .title_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .style(Style::default().fg(Color::White));
+    f.render_widget(title, chunks[0]);
+
+    render_text_field(
+        f,
+        chunks[1],
+        "Templates Directory",
+        &app.settings_dialog_templates_dir,
+        app.settings_dialog_focused_field == 0,
+    );
+    render_text_field(
+        f,
+        chunks[2],
+        "HTTP Collections Directory",
+        &app.settings_dialog_collections_dir,
+        app.settings_dialog_focused_field == 1,
+    );
+
+    render_toggle(
+        f,
+        chunks[3],
+        "Migration",
+        "Move existing templates and collections to the new directories",
+        app.settings_dialog_move_data,
+        app.settings_dialog_focused_field == 2,
+    );
+    render_toggle(
+        f,
+        chunks[4],
+        "Updates",
+        "Check for new versions at startup",
+        app.settings_dialog_check_updates,
+        app.settings_dialog_focused_field == 3,
+    );
+    render_toggle(
+        f,
+        chunks[5],
+        "JSON Bodies",
+        "Pretty-print JSON bodies when leaving edit mode",
+        app.settings_dialog_auto_format_json,
+        app.settings_dialog_focused_field == 4,
+    );
+    render_toggle(
+        f,
+        chunks[6],
+        "Send Guard",
+        "Block sending requests with invalid JSON bodies",
+        app.settings_dialog_block_invalid_json,
+        app.settings_dialog_focused_field == 5,
+    );
+
+    // Timestamp format (Space cycles through the four styles)
+    let timestamps = Paragraph::new(Line::from(vec![
+        Span::styled(
+            app.settings_dialog_timestamp_format.label(),
+            Style::default().fg(Color::Green),
+        ),
+        Span::raw(format!(
+            "  e.g. {}",
+            app.settings_dialog_timestamp_format
+                .format(&chrono::Local::now())
+        )),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Timestamps (Space: cycle)")
+            .style(Style::default().bg(Color::DarkGray))
+            .border_style(focus_style(app.settings_dialog_focused_field == 6)),
+    )
+    .style(Style::default().fg(Color::White));
+    f.render_widget(timestamps, chunks[7]);
+
+    // History retention (Space cycles through the presets)
+    let history = Paragraph::new(Span::styled(
+        app.settings_dialog_history_retention.label(),
+        Style::default().fg(Color::Green),
+    ))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("History (Space: cycle)")
+            .style(Style::default().bg(Color::DarkGray))
+            .border_style(focus_style(app.settings_dialog_focused_field == 7)),
+    )
+    .style(Style::default().fg(Color::White));
+    f.render_widget(history, chunks[8]);
+
+    // File format for new templates and requests (Space toggles JSON/YAML)
+    let storage = Paragraph::new(Line::from(vec![
+        Span::styled(
+            app.settings_dialog_storage_format.label(),
+            Style::default().fg(Color::Green),
+        ),
+        Span::raw("  for new files; existing files keep their format"),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Storage Format (Space: cycle)")
+            .style(Style::default().bg(Color::DarkGray))
+            .border_style(focus_style(app.settings_dialog_focused_field == 8)),
+    )
+    .style(Style::default().fg(Color::White));
+    f.render_widget(storage, chunks[9]);
+
+    render_toggle(
+        f,
+        chunks[10],
+        "Cookies",
+        "Keep the cookie jar between sessions",
+        app.settings_dialog_persist_cookies,
+        app.settings_dialog_focused_field == 9,
+    );
+
+    render_toggle(
+        f,
+        chunks[11],
+        "Request Log",
+        &format!(
+            "Include credentials in logged curl commands ({})",
+            RequestLog::default_path().display()
+        ),
+        app.settings_dialog_request_log_secrets,
+        app.settings_dialog_focused_field == 10,
+    );
+
+    // Idle auto-lock (Space cycles through the presets)
+    let auto_lock = Paragraph::new(Line::from(vec![
+        Span::styled(
+            app.settings_dialog_auto_lock.label(),
+            Style::default().fg(Color::Green),
+        ),
+        Span::raw("  clears login credentials and OAuth tokens"),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Auto-Lock (Space: cycle)")
+            .style(Style::default().bg(Color::DarkGray))
+            .border_style(focus_style(app.settings_dialog_focused_field == 11)),
+    )
+    .style(Style::default().fg(Color::White));
+    f.render_widget(auto_lock, chunks[12]);
+
+    // Response display limit (Space cycles through the presets)
+    let response_limit = Paragraph::new(Line::from(vec![
+        Span::styled(
+            app.settings_dialog_response_limit.label(),
+            Style::default().fg(Color::Green),
+        ),
+        Span::raw("  of each body kept in memory; larger ones are truncated and spooled to disk"),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Response Display Limit (Space: cycle)")
+            .style(Style::default().bg(Color::DarkGray))
+            .border_style(focus_style(app.settings_dialog_focused_field == 12)),
+    )
+    .style(Style::default().fg(Color::White));
+    f.render_widget(response_limit, chunks[13]);
+
+    // Global retry policy (Space cycles through the presets)
+    let retry = Paragraph::new(Line::from(vec![
+        Span::styled(
+            app.settings_dialog_retry.label(),
+            Style::default().fg(Color::Green),
+        ),
+        Span::raw("  for requests without their own"),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Retry Policy (Space: cycle)")
+            .style(Style::default().bg(Color::DarkGray))
+            .border_style(focus_style(app.settings_dialog_focused_field == 13)),
+    )
+    .style(Style::default().fg(Color::White));
+    f.render_widget(retry, chunks[14]);
+
+    // Automatic backups (Space cycles through the presets, R opens the restore picker)
+    let backups = Paragraph::new(Line::from(vec![
+        Span::styled(
+            app.settings_dialog_backup_schedule.label(),
+            Style::default().fg(Color::Green),
+        ),
+        Span::raw(match app.last_backup_at {
+            Some(at) => format!("  last {}", app.format_timestamp(&at)),
+            None => "  no backup yet".to_string(),
+        }),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Backups (Space: cycle, r: restore…)")
+            .style(Style::default().bg(Color::DarkGray))
+            .border_style(focus_style(app.settings_dialog_focused_field == 14)),
+    )
+    .style(Style::default().fg(Color::White));
+    f.render_widget(backups, chunks[15]);
+
+    // Error message
+    if let Some(error) = &app.settings_dialog_error {
+        let error_msg = Paragraph::new(error.as_str())
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Error")
+                    .style(Style::default().bg(Color::DarkGray)),
+            )
+            .style(Style::default().fg(Color::Red));
+        f.render_widget(error_msg, chunks[16]);
+    }
+
+    // Instructions
+    let instructions =
+        Paragraph::new("Tab: Next field  |  Space: Toggle option  |  Enter: Apply  |  Esc: Cancel")
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Controls")
+                    .style(Style::default().bg(Color::DarkGray)),
+            )
+            .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[17]);
+}
+
+/// Render a checkbox option with focus highlighting
+fn render_toggle(f: &mut Frame, area: Rect, title: &str, label: &str, on: bool, focused: bool) {
+    let toggle = Paragraph::new(Line::from(vec![
+        checkbox_span(on),
+        Span::raw(format!(" {}", label)),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title.to_string())
+            .style(Style::default().bg(Color::DarkGray))
+            .border_style(focus_style(focused)),
+    )
+    .style(Style::default().fg(Color::White));
+    f.render_widget(toggle, area);
+}
+
+/// Render an editable text field with focus highlighting
+fn render_text_field(f: &mut Frame, area: Rect, title: &str, value: &str, focused: bool) {
+    let field = Paragraph::new(value)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title.to_string())
+                .title_style(focus_style(focused))
+                .style(Style::default().bg(Color::DarkGray))
+                .border_style(focus_style(focused)),
+        )
+        .style(Style::default().fg(Color::White));
+    f.render_widget(field, area);
+}
+
+fn focus_style(focused: bool) -> Style {
+    if focused {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::White)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::app::App;
+use crate::services::snippet_generator::{SnippetFormat, generate_snippet};
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// Render the copy-as-snippet format picker with a preview of the selected format
+pub fn render_snippet_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(80, 70, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(SnippetFormat::all().len() as u16 + 2), // Formats
+            Constraint::Min(5),                                        // Preview
+            Constraint::Length(3),                                     // Instructions
+        ])
+        .split(popup_area);
+
+    let items: Vec<ListItem> = SnippetFormat::all()
+        .iter()
+        .enumerate()
+        .map(|(index, format)| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{} ", index + 1),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(format.title(), Style::default().fg(Color::Cyan)),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("📋 Copy Request As")
+                .title_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+        );
+    let mut list_state = ListState::default().with_selected(Some(app.snippet_dialog_selected));
+    f.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    let format = app.selected_snippet_format();
+    let snippet = generate_snippet(&app.http_state.current_request, format);
+    let preview = Paragraph::new(snippet)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Preview — {}", format.title()))
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .style(Style::default().fg(Color::White));
+    f.render_widget(preview, chunks[1]);
+
+    let instructions =
+        Paragraph::new("Tab/j/k: Move  |  Enter: Copy  |  1-4: Copy directly  |  Esc: Close")
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Controls")
+                    .style(Style::default().bg(Color::DarkGray)),
+            )
+            .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[2]);
+}
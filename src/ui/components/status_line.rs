@@ -1,4 +1,6 @@
 use crate::app::{App, AppMode, FocusedPane, InputMode};
+use crate::models::http::{BodyContentType, HttpRequestTab};
+use crate::services::audit_log::is_production_environment;
 use ratatui::{
     Frame,
     layout::Rect,
@@ -11,18 +13,147 @@ use ratatui::{
 pub fn render_status_line(f: &mut Frame, area: Rect, app: &App) {
     let status_text = get_contextual_status_text(app);
 
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .title("Keybindings")
+        .title_style(Style::default().fg(Color::Cyan));
+
+    if let Some(indicator) = get_background_task_indicator(app) {
+        block = block.title(indicator.right_aligned());
+    }
+    if let Some(indicator) = get_environment_indicator(app) {
+        block = block.title(indicator.right_aligned());
+    }
+    if let Some(indicator) = get_note_reminder_indicator(app) {
+        block = block.title(indicator.right_aligned());
+    }
+    if app.safe_mode {
+        block = block.title(
+            Line::from(Span::styled(
+                " SAFE MODE: read-only, scripts off ",
+                Style::default().fg(Color::Black).bg(Color::Magenta),
+            ))
+            .right_aligned(),
+        );
+    }
+    if app.session_locked {
+        block = block.title(
+            Line::from(Span::styled(
+                " 🔒 Locked: log in to run automations ",
+                Style::default().fg(Color::Red),
+            ))
+            .right_aligned(),
+        );
+    }
+
     let status_paragraph = Paragraph::new(status_text)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("Keybindings")
-                .title_style(Style::default().fg(Color::Cyan)),
-        )
+        .block(block)
         .style(Style::default().fg(Color::White));
 
     f.render_widget(status_paragraph, area);
 }
 
+/// Summarize tasks from the inactive mode: finished ones first, then ones still running
+fn get_background_task_indicator(app: &App) -> Option<Line<'static>> {
+    let other_mode = match app.current_mode {
+        AppMode::Automation => AppMode::Http,
+        AppMode::Http => AppMode::Automation,
+    };
+    let switch_key = match other_mode {
+        AppMode::Automation => "F1",
+        AppMode::Http => "F4",
+    };
+
+    if let Some((mode, summary)) = app.background_notices.last() {
+        let extra = app.background_notices.len() - 1;
+        let mut text = format!(" ● {:?}: {}", mode, summary);
+        if extra > 0 {
+            text.push_str(&format!(" (+{})", extra));
+        }
+        return Some(Line::from(vec![
+            Span::styled(text, Style::default().fg(Color::Green)),
+            Span::styled(
+                format!(" [{}] ", switch_key),
+                Style::default().fg(Color::Yellow),
+            ),
+        ]));
+    }
+
+    let running = app.running_task_count(&other_mode);
+    if running > 0 {
+        return Some(Line::from(Span::styled(
+            format!(" ⏳ {} {:?} task(s) running ", running, other_mode),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+
+    None
+}
+
+/// Active environment, with the folder binding of the loaded request when it has one
+fn get_environment_indicator(app: &App) -> Option<Line<'static>> {
+    if app.current_mode != AppMode::Http {
+        return None;
+    }
+    let active = app.config.active_environment_name();
+    let Some(binding) = app.current_folder_environment() else {
+        let style = if is_production_environment(active) {
+            Style::default().fg(Color::White).bg(Color::Red)
+        } else {
+            Style::default().fg(Color::Green)
+        };
+        return Some(Line::from(Span::styled(format!(" 🌍 {} ", active), style)));
+    };
+
+    if binding.environment == active {
+        Some(Line::from(Span::styled(
+            format!(" 🌍 {} (folder {}) ", active, binding.folder),
+            Style::default().fg(Color::Green),
+        )))
+    } else {
+        Some(Line::from(Span::styled(
+            format!(
+                " ⚠ 🌍 {} overrides {} of folder {} ",
+                active, binding.environment, binding.folder
+            ),
+            Style::default().fg(Color::Black).bg(Color::Yellow),
+        )))
+    }
+}
+
+/// Oldest note whose "review by" date has arrived, with a count of the others
+fn get_note_reminder_indicator(app: &App) -> Option<Line<'static>> {
+    let due = app.due_notes();
+    let note = due.first()?;
+    let mut text = format!(" ⏰ Review {}", note.path);
+    if !note.headline().is_empty() {
+        let headline: String = note.headline().chars().take(40).collect();
+        text.push_str(&format!(": {}", headline));
+    }
+    if due.len() > 1 {
+        text.push_str(&format!(" (+{})", due.len() - 1));
+    }
+    text.push(' ');
+    Some(Line::from(Span::styled(
+        text,
+        Style::default().fg(Color::Black).bg(Color::Yellow),
+    )))
+}
+
+/// Whether the current request tab shows a key/value list
+fn has_key_value_list(app: &App) -> bool {
+    match app.http_state.current_request_tab {
+        HttpRequestTab::Headers | HttpRequestTab::QueryParams | HttpRequestTab::Cookies => true,
+        HttpRequestTab::Body => {
+            matches!(
+                app.http_state.current_body_type,
+                BodyContentType::Form | BodyContentType::Multipart
+            ) || app.http_state.is_json_builder_active()
+        }
+        _ => false,
+    }
+}
+
 /// Get context-aware status text based on focused pane, mode, and current app state
 fn get_contextual_status_text(app: &App) -> Line<'static> {
     // Helper function to create colored spans
@@ -51,6 +182,9 @@ fn get_contextual_status_text(app: &App) -> Line<'static> {
             key("F2/R"),
             desc(":rename"),
             separator(),
+            key("n"),
+            desc(":note"),
+            separator(),
             key("Del"),
             desc(":delete"),
             separator(),
@@ -71,6 +205,9 @@ fn get_contextual_status_text(app: &App) -> Line<'static> {
             key("Ctrl+N"),
             desc(":save template"),
             separator(),
+            key("p"),
+            desc(":presets"),
+            separator(),
             key("H/L"),
             desc(":switch pane"),
             separator(),
@@ -136,6 +273,12 @@ fn get_contextual_status_text(app: &App) -> Line<'static> {
                     key("g/G"),
                     desc(":top/bottom"),
                     separator(),
+                    key("m"),
+                    desc(":marker"),
+                    separator(),
+                    key("t"),
+                    desc(&format!(":time ({})", app.log_time_filter.label())),
+                    separator(),
                     key("H/L"),
                     desc(":switch pane"),
                     separator(),
@@ -172,13 +315,78 @@ fn get_contextual_status_text(app: &App) -> Line<'static> {
             key("F2/R"),
             desc(":rename"),
             separator(),
+            key("n"),
+            desc(":note"),
+            separator(),
             key("Del"),
             desc(":delete"),
             separator(),
+            key("x"),
+            desc(":run folder"),
+            separator(),
             key("F1"),
             desc(":automation mode"),
         ]),
 
+        (AppMode::Http, FocusedPane::Form, _) if app.http_state.key_value_editor.is_editing() => {
+            let tab = if app.http_state.current_request_tab == HttpRequestTab::Headers {
+                ":complete/name/value"
+            } else {
+                ":name/value"
+            };
+            Line::from(vec![
+                key("Type"),
+                desc(":edit cell"),
+                separator(),
+                key("Tab"),
+                desc(tab),
+                separator(),
+                key("Enter"),
+                desc(":save"),
+                separator(),
+                key("Esc"),
+                desc(":cancel"),
+            ])
+        }
+
+        (AppMode::Http, FocusedPane::Form, InputMode::Normal) if has_key_value_list(app) => {
+            let mut spans = vec![
+                key("a"),
+                desc(":add"),
+                separator(),
+                key("e/v"),
+                desc(":edit name/value"),
+                separator(),
+                key("t"),
+                desc(":toggle"),
+                separator(),
+                key("d"),
+                desc(":delete"),
+                separator(),
+            ];
+            if app.http_state.current_request_tab == HttpRequestTab::Body
+                && app.http_state.current_body_type == BodyContentType::Multipart
+            {
+                spans.extend([key("f"), desc(":text/file"), separator()]);
+            }
+            if app.http_state.current_request_tab == HttpRequestTab::Body
+                && app.http_state.is_json_builder_active()
+            {
+                spans.extend([key("B"), desc(":raw JSON"), separator()]);
+            }
+            spans.extend([
+                key("j/k"),
+                desc(":select"),
+                separator(),
+                key("Tab"),
+                desc(":next tab"),
+                separator(),
+                key("Space/F3"),
+                desc(":send request"),
+            ]);
+            Line::from(spans)
+        }
+
         (AppMode::Http, FocusedPane::Form, InputMode::Normal) => {
             let method = &app.http_state.current_request.method.as_str();
             Line::from(vec![
@@ -197,9 +405,18 @@ fn get_contextual_status_text(app: &App) -> Line<'static> {
                 key("Space/F3"),
                 desc(":send request"),
                 separator(),
+                key("y"),
+                desc(":copy as"),
+                separator(),
+                key("Ctrl+R"),
+                desc(":history"),
+                separator(),
                 key("H/L"),
                 desc(":switch pane"),
                 separator(),
+                key("Ctrl+W/L"),
+                desc(":response focus/split"),
+                separator(),
                 key("F1"),
                 desc(":automation"),
             ])
@@ -248,7 +465,7 @@ fn get_contextual_status_text(app: &App) -> Line<'static> {
                     key("H/L"),
                     desc(":switch pane"),
                 ])
-            } else if app.http_state.is_sending {
+            } else if app.http_state.is_sending() {
                 Line::from(vec![
                     key("⏳"),
                     desc("Sending request..."),
@@ -286,7 +503,7 @@ pub fn get_compact_status_text(app: &App) -> String {
             "↑/↓:nav Enter:select Ctrl+N:new F4:HTTP ?:help".to_string()
         }
         (AppMode::Automation, FocusedPane::Form, InputMode::Normal) => {
-            "i:edit j/k:nav Tab:next Ctrl+N:save F3:run F4:HTTP".to_string()
+            "i:edit j/k:nav Tab:next Ctrl+N:save p:presets F3:run F4:HTTP".to_string()
         }
         (AppMode::Automation, FocusedPane::Form, InputMode::Edit) => {
             "Esc:normal ←/→:cursor Tab:next F3:run".to_string()
@@ -295,7 +512,7 @@ pub fn get_compact_status_text(app: &App) -> String {
             if app.log_search_mode {
                 "Type:search Esc:exit j/k:scroll F4:HTTP".to_string()
             } else {
-                "/:search j/k:scroll g/G:top/bottom F4:HTTP".to_string()
+                "/:search j/k:scroll m:marker t:time F4:HTTP".to_string()
             }
         }
 
@@ -313,7 +530,7 @@ pub fn get_compact_status_text(app: &App) -> String {
         (AppMode::Http, FocusedPane::Logs, _) => {
             if app.http_state.last_response.is_some() {
                 "Tab:tabs Ctrl+C:copy j/k:scroll Del:clear".to_string()
-            } else if app.http_state.is_sending {
+            } else if app.http_state.is_sending() {
                 "⏳ Sending request...".to_string()
             } else {
                 "F3:send H:request F1:auto ?:help".to_string()
@@ -343,10 +560,10 @@ pub fn get_mode_indicator(app: &App) -> String {
 fn get_http_mode_indicator(app: &App) -> String {
     let method = &app.http_state.current_request.method;
     let is_valid = app.http_state.is_valid();
-    let is_sending = app.http_state.is_sending;
+    let is_sending = app.http_state.is_sending();
 
-    if is_sending {
-        " [SENDING]".to_string()
+    let indicator = if is_sending {
+        " [SENDING - Ctrl+B cancels]".to_string()
     } else if app.focused_pane == FocusedPane::Form {
         match app.input_mode {
             InputMode::Normal => format!(" [{}]", method.as_str()),
@@ -356,5 +573,13 @@ fn get_http_mode_indicator(app: &App) -> String {
         " [INVALID]".to_string()
     } else {
         format!(" [{}]", method.as_str())
+    };
+
+    // Requests sent from other entries keep running in the background
+    let in_flight = app.http_state.in_flight_count();
+    if in_flight > usize::from(is_sending) {
+        format!("{} [{} in flight]", indicator, in_flight)
+    } else {
+        indicator
     }
 }
@@ -0,0 +1,80 @@
+use crate::app::App;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+/// Render the loaded template's description and what each of its fields expects
+pub fn render_template_info_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let Some(template) = &app.loaded_template else {
+        return;
+    };
+    let popup_area = centered_rect(60, 60, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),    // Description and field docs
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let mut lines = Vec::new();
+    if !template.description.is_empty() {
+        lines.push(Line::from(Span::styled(
+            template.description.clone(),
+            Style::default().fg(Color::White),
+        )));
+        lines.push(Line::from(""));
+    }
+    if template.field_docs.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "This template doesn't document its fields",
+            Style::default().fg(Color::Gray),
+        )));
+    }
+    for (name, doc) in &template.field_docs {
+        lines.push(Line::from(Span::styled(
+            name.clone(),
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        )));
+        if !doc.help.is_empty() {
+            lines.push(Line::from(format!("  {}", doc.help)));
+        }
+        if !doc.example.is_empty() {
+            lines.push(Line::from(Span::styled(
+                format!("  e.g. {}", doc.example),
+                Style::default().fg(Color::Cyan),
+            )));
+        }
+    }
+
+    let info = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("ℹ {}", template.name))
+                .title_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::DarkGray))
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(info, chunks[0]);
+
+    let instructions = Paragraph::new("Esc/Enter: Close  |  I: Show again from the form")
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Controls")
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[1]);
+}
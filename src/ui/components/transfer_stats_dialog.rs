@@ -0,0 +1,75 @@
+use crate::app::App;
+use crate::ui::components::http_response_viewer::format_bytes;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+/// Render the cumulative transfer stats for this session
+pub fn render_transfer_stats_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let stats = &app.session_transfer;
+
+    let popup_area = centered_rect(50, 45, area);
+    f.render_widget(Clear, popup_area);
+
+    let row = |label: &str, value: String, color: Color| {
+        Line::from(vec![
+            Span::raw(format!("{:<20}", label)),
+            Span::styled(value, Style::default().fg(color)),
+        ])
+    };
+
+    let lines = vec![
+        row(
+            "Requests:",
+            format!("{} ({} failed)", stats.requests, stats.failures),
+            Color::White,
+        ),
+        row(
+            "Sent:",
+            format_bytes(stats.sent_bytes as usize),
+            Color::Yellow,
+        ),
+        row(
+            "Received:",
+            format_bytes(stats.received_bytes as usize),
+            Color::Green,
+        ),
+        row(
+            "Response bodies:",
+            format_bytes(stats.response_body_bytes as usize),
+            Color::Cyan,
+        ),
+        row(
+            "Largest response:",
+            format_bytes(stats.largest_response_bytes),
+            Color::Cyan,
+        ),
+        row(
+            "Average time:",
+            format!("{} ms", stats.average_duration_ms()),
+            Color::Magenta,
+        ),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Esc", Style::default().fg(Color::Yellow)),
+            Span::raw(": Close"),
+        ]),
+    ];
+
+    let dialog = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("📶 Session Transfer Stats")
+                .title_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .style(Style::default().fg(Color::White));
+
+    f.render_widget(dialog, popup_area);
+}
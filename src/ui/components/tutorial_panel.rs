@@ -0,0 +1,93 @@
+use crate::app::App;
+use crate::models::tutorial::TutorialStep;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+/// Render the tutorial panel in the bottom-right corner. It never takes keys,
+/// so the steps are done with the app's real shortcuts underneath it.
+pub fn render_tutorial_panel(f: &mut Frame, area: Rect, app: &App) {
+    let Some(tutorial) = &app.tutorial else {
+        return;
+    };
+    let Some(current) = tutorial.current() else {
+        return;
+    };
+
+    let mut lines: Vec<Line> = TutorialStep::ALL
+        .iter()
+        .enumerate()
+        .map(|(index, step)| {
+            let (marker, style) = if index + 1 < tutorial.position() {
+                ("✓", Style::default().fg(Color::Green))
+            } else if *step == current {
+                (
+                    "▶",
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                ("·", Style::default().fg(Color::DarkGray))
+            };
+            Line::from(Span::styled(
+                format!("{} {}. {}", marker, index + 1, step.title()),
+                style,
+            ))
+        })
+        .collect();
+
+    lines.push(Line::from(""));
+    for text in current.instructions() {
+        lines.push(Line::from(Span::styled(
+            *text,
+            Style::default().fg(Color::White),
+        )));
+    }
+    lines.push(Line::from(""));
+
+    let mut keys = vec![Span::styled("Keys: ", Style::default().fg(Color::Gray))];
+    for key in current.keys() {
+        keys.push(Span::styled(
+            format!(" {} ", key),
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+        keys.push(Span::raw(" "));
+    }
+    lines.push(Line::from(keys));
+    lines.push(Line::from(Span::styled(
+        "? then Ctrl+T stops the tutorial",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let width = 60.min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let panel_area = Rect::new(
+        area.right().saturating_sub(width),
+        area.bottom().saturating_sub(height),
+        width,
+        height,
+    );
+    f.render_widget(Clear, panel_area);
+
+    let panel = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "🎓 Tutorial - step {}/{}",
+                tutorial.position(),
+                TutorialStep::ALL.len()
+            ))
+            .title_style(Style::default().fg(Color::Cyan))
+            .border_style(Style::default().fg(Color::Yellow))
+            .style(Style::default().bg(Color::Black)),
+    );
+    f.render_widget(panel, panel_area);
+}
@@ -0,0 +1,76 @@
+use crate::app::App;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+/// Render the "new version available" notice
+pub fn render_update_notice(f: &mut Frame, area: Rect, app: &App) {
+    let Some(info) = &app.update_notice else {
+        return;
+    };
+
+    let popup_area = centered_rect(60, 40, area);
+    f.render_widget(Clear, popup_area);
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::raw("Version "),
+            Span::styled(
+                info.version.clone(),
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(
+                " is available (you have {})",
+                env!("CARGO_PKG_VERSION")
+            )),
+        ]),
+        Line::from(""),
+    ];
+
+    if info.highlights.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No changelog highlights provided",
+            Style::default().fg(Color::Gray),
+        )));
+    } else {
+        lines.push(Line::from(Span::styled(
+            "Highlights:",
+            Style::default().fg(Color::Cyan),
+        )));
+        for highlight in &info.highlights {
+            lines.push(Line::from(format!("  • {}", highlight)));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        info.url.clone(),
+        Style::default().fg(Color::Blue),
+    )));
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("Esc/Enter", Style::default().fg(Color::Yellow)),
+        Span::raw(": Dismiss"),
+    ]));
+
+    let notice = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("🎉 Update Available")
+                .title_style(Style::default().fg(Color::Green))
+                .style(Style::default().bg(Color::DarkGray))
+                .border_style(Style::default().fg(Color::Green)),
+        )
+        .style(Style::default().fg(Color::White))
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(notice, popup_area);
+}
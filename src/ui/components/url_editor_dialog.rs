@@ -0,0 +1,98 @@
+use crate::app::App;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Position, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+/// Render the expanded URL editor: the whole URL wrapped over several lines,
+/// with its query string broken out underneath
+pub fn render_url_editor_dialog(f: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(80, 60, area);
+
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),    // URL
+            Constraint::Min(5),    // Query parameters
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let state = &app.http_state;
+    let url = &state.current_request.url;
+
+    // Wrap at exact character widths so the cursor lands where the text is drawn
+    let url_block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("🔗 URL ({} chars)", url.chars().count()))
+        .title_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::DarkGray));
+    let inner = url_block.inner(chunks[0]);
+    let width = inner.width.max(1) as usize;
+    let chars: Vec<char> = url.chars().collect();
+    let lines: Vec<Line> = chars
+        .chunks(width)
+        .map(|chunk| Line::from(chunk.iter().collect::<String>()))
+        .collect();
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(url_block)
+            .style(Style::default().fg(Color::White)),
+        chunks[0],
+    );
+
+    let cursor = state.url_cursor.min(chars.len());
+    let cursor_row = (cursor / width) as u16;
+    if cursor_row < inner.height {
+        f.set_cursor_position(Position::new(
+            inner.x + (cursor % width) as u16,
+            inner.y + cursor_row,
+        ));
+    }
+
+    // Decoded query parameters, as the server will see them
+    let params: Vec<Line> = match reqwest::Url::parse(url) {
+        Ok(parsed) if parsed.query().is_some_and(|query| !query.is_empty()) => parsed
+            .query_pairs()
+            .map(|(name, value)| {
+                Line::from(vec![
+                    Span::styled(name.into_owned(), Style::default().fg(Color::Cyan)),
+                    Span::raw(" = "),
+                    Span::styled(value.into_owned(), Style::default().fg(Color::White)),
+                ])
+            })
+            .collect(),
+        Ok(_) => vec![Line::from(Span::styled(
+            "No query string",
+            Style::default().fg(Color::Gray),
+        ))],
+        Err(e) => vec![Line::from(Span::styled(
+            format!("Not a valid URL yet: {}", e),
+            Style::default().fg(Color::Yellow),
+        ))],
+    };
+    let params_widget = Paragraph::new(params).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Query Parameters (decoded)")
+            .style(Style::default().bg(Color::DarkGray)),
+    );
+    f.render_widget(params_widget, chunks[1]);
+
+    let instructions =
+        Paragraph::new("Type/paste: Edit  |  ←/→ Home/End: Move  |  Enter: Apply  |  Esc: Cancel")
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Controls")
+                    .style(Style::default().bg(Color::DarkGray)),
+            )
+            .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[2]);
+}
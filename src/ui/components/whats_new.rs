@@ -0,0 +1,68 @@
+use crate::app::App;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+/// Render the What's New overlay from the embedded changelog
+pub fn render_whats_new(f: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(70, 70, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),    // Changelog
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let mut lines = Vec::new();
+    for release in &app.whats_new_releases {
+        lines.push(Line::from(Span::styled(
+            format!("▶ Version {}", release.version),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )));
+        for entry in &release.entries {
+            lines.push(Line::from(vec![
+                Span::styled("  • ", Style::default().fg(Color::Green)),
+                Span::raw(entry.clone()),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+
+    let changelog = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("✨ What's New")
+                .title_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .style(Style::default().fg(Color::White))
+        .wrap(Wrap { trim: false })
+        .scroll((app.whats_new_scroll, 0));
+    f.render_widget(changelog, chunks[0]);
+
+    let instructions = Paragraph::new(Line::from(vec![
+        Span::styled("j/k", Style::default().fg(Color::Yellow)),
+        Span::raw(": Scroll  "),
+        Span::styled("Esc/Enter", Style::default().fg(Color::Yellow)),
+        Span::raw(": Close"),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Controls")
+            .style(Style::default().bg(Color::DarkGray)),
+    )
+    .style(Style::default().fg(Color::White));
+    f.render_widget(instructions, chunks[1]);
+}
@@ -3,49 +3,103 @@ use crate::ui::components::http_request_editor::render_http_request_editor;
 use crate::ui::components::http_response_viewer::render_http_response_viewer;
 use crate::ui::components::rename_dialog::render_rename_dialog;
 use crate::ui::components::{
-    get_mode_indicator, render_automation_form, render_collections_tree,
-    render_delete_confirmation_dialog, render_folder_creation_dialog, render_help_dialog,
-    render_import_dialog, render_logging_panel, render_login_popup, render_status_line,
-    render_template_creation_dialog,
+    get_mode_indicator, render_audit_log_dialog, render_automation_form, render_backup_dialog,
+    render_clone_request_dialog, render_collection_run_dialog, render_collections_tree,
+    render_confirmation_dialog, render_curl_import_dialog, render_draft_restore_dialog,
+    render_environment_dialog, render_environment_switcher, render_folder_creation_dialog,
+    render_folder_readme, render_folder_settings_dialog, render_global_search, render_health_panel,
+    render_help_dialog, render_history_dialog, render_history_palette, render_import_dialog,
+    render_input_presets_dialog, render_load_test_dialog, render_logging_panel, render_login_popup,
+    render_note_dialog, render_perf_overlay, render_quick_switcher, render_raw_headers_dialog,
+    render_response_diff_dialog, render_run_queue_dialog, render_run_timeline,
+    render_settings_dialog, render_snippet_dialog, render_status_line,
+    render_template_creation_dialog, render_template_info_dialog, render_transfer_stats_dialog,
+    render_tutorial_panel, render_update_notice, render_url_editor_dialog, render_whats_new,
 };
 use ratatui::{
     Frame,
+    layout::Alignment,
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Tabs},
+    widgets::{Block, Borders, Paragraph, Tabs, Wrap},
 };
 
-/// Render the main application layout
-pub fn render_app(f: &mut Frame, app: &App) {
-    let size = f.area();
+/// Below this size the three panes no longer fit side by side
+pub const MIN_FULL_LAYOUT_WIDTH: u16 = 80;
+pub const MIN_FULL_LAYOUT_HEIGHT: u16 = 20;
+
+/// Below this size even a single pane is unusable
+const MIN_TINY_LAYOUT_WIDTH: u16 = 30;
+const MIN_TINY_LAYOUT_HEIGHT: u16 = 8;
+
+/// Whether the terminal is too small for the full three-pane layout
+pub fn is_tiny_screen(area: Rect) -> bool {
+    area.width < MIN_FULL_LAYOUT_WIDTH || area.height < MIN_FULL_LAYOUT_HEIGHT
+}
 
-    // Main layout: Header → Content
-    let main_chunks = Layout::default()
+/// A rectangle of the given percentages of `r`, centered in it (for popups and dialogs)
+pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // Header
-            Constraint::Min(10),   // Main content
-            Constraint::Length(3), // Status line
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
         ])
-        .split(size);
+        .split(popup_layout[1])[1]
+}
 
-    // Render header
-    render_header(f, main_chunks[0], app);
+/// Render the main application layout
+pub fn render_app(f: &mut Frame, app: &App) {
+    let size = f.area();
+    // The response viewer asks for the inline image again if it's still on screen
+    app.image_preview.place(None);
 
-    // Render main content area
-    render_main_content(f, main_chunks[1], app);
+    if size.width < MIN_TINY_LAYOUT_WIDTH || size.height < MIN_TINY_LAYOUT_HEIGHT {
+        render_too_small(f, size);
+        return;
+    }
 
-    // Render status line
-    render_status_line(f, main_chunks[2], app);
+    if is_tiny_screen(size) {
+        render_tiny_layout(f, size, app);
+    } else {
+        // Main layout: Header → Content
+        let main_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Header
+                Constraint::Min(10),   // Main content
+                Constraint::Length(3), // Status line
+            ])
+            .split(size);
 
-    // Render modal dialogs (in order of priority - delete confirmation has the highest priority)
+        // Render header
+        render_header(f, main_chunks[0], app);
+
+        // Render main content area
+        render_main_content(f, main_chunks[1], app);
+
+        // Render status line
+        render_status_line(f, main_chunks[2], app);
+    }
+
+    // Render modal dialogs (in order of priority - confirmations sit just below help and import)
     if app.show_help_dialog {
         render_help_dialog(f, size, app);
     } else if app.show_import_dialog {
         render_import_dialog(f, size, app);
-    } else if app.show_delete_confirmation_dialog {
-        render_delete_confirmation_dialog(f, size, app);
+    } else if let Some(confirmation) = &app.confirmation {
+        render_confirmation_dialog(f, size, confirmation);
     } else if app.show_login_popup {
         render_login_popup(f, size, app);
     } else if app.show_template_dialog {
@@ -54,10 +108,184 @@ pub fn render_app(f: &mut Frame, app: &App) {
         render_folder_creation_dialog(f, size, app);
     } else if app.show_rename_dialog {
         render_rename_dialog(f, size, app);
+    } else if app.backup_dialog.is_some() {
+        render_backup_dialog(f, size, app);
+    } else if app.show_settings_dialog {
+        render_settings_dialog(f, size, app);
+    } else if app.show_whats_new {
+        render_whats_new(f, size, app);
+    } else if app.show_health_panel {
+        render_health_panel(f, size, app);
+    } else if app.update_notice.is_some() {
+        render_update_notice(f, size, app);
+    } else if app.pending_draft.is_some() {
+        render_draft_restore_dialog(f, size, app);
+    } else if app.show_environment_dialog {
+        render_environment_dialog(f, size, app);
+    } else if app.show_environment_switcher {
+        render_environment_switcher(f, size, app);
+    } else if app.show_quick_switcher {
+        render_quick_switcher(f, size, app);
+    } else if app.show_curl_import_dialog {
+        render_curl_import_dialog(f, size, app);
+    } else if app.raw_headers_text.is_some() {
+        render_raw_headers_dialog(f, size, app);
+    } else if app.show_url_editor {
+        render_url_editor_dialog(f, size, app);
+    } else if app.show_snippet_dialog {
+        render_snippet_dialog(f, size, app);
+    } else if app.show_input_presets_dialog {
+        render_input_presets_dialog(f, size, app);
+    } else if app.clone_request_dialog.is_some() {
+        render_clone_request_dialog(f, size, app);
+    } else if app.note_dialog.is_some() {
+        render_note_dialog(f, size, app);
+    } else if app.folder_settings_dialog.is_some() {
+        render_folder_settings_dialog(f, size, app);
+    } else if app.show_global_search {
+        render_global_search(f, size, app);
+    } else if app.show_history_dialog {
+        render_history_dialog(f, size, app);
+    } else if app.show_history_palette {
+        render_history_palette(f, size, app);
+    } else if app.response_diff.is_some() {
+        render_response_diff_dialog(f, size, app);
+    } else if app.show_collection_run {
+        render_collection_run_dialog(f, size, app);
+    } else if app.show_load_test {
+        render_load_test_dialog(f, size, app);
+    } else if app.show_transfer_stats {
+        render_transfer_stats_dialog(f, size, app);
+    } else if app.audit_log_entries.is_some() {
+        render_audit_log_dialog(f, size, app);
+    } else if app.show_template_info {
+        render_template_info_dialog(f, size, app);
+    } else if app.show_run_queue {
+        render_run_queue_dialog(f, size, app);
+    }
+
+    // The tutorial points at keys in the dialogs it opens, so it stays visible on top
+    render_tutorial_panel(f, size, app);
+
+    // Diagnostics float above everything, including dialogs, without taking keys
+    if app.show_perf_overlay {
+        render_perf_overlay(f, size, app);
+    }
+
+    // The inline image would cover anything drawn over its area, so hide it then
+    if let Some(area) = app.image_preview.area() {
+        let buffer = f.buffer_mut();
+        let covered = area.positions().any(|position| {
+            buffer
+                .cell(position)
+                .is_some_and(|cell| cell.symbol() != " ")
+        });
+        if covered {
+            app.image_preview.place(None);
+        }
     }
 }
 
 /// Render the header with mode tabs and indicators
+/// Tiny-screen mode: a pane switcher bar and only the focused pane, full size
+fn render_tiny_layout(f: &mut Frame, area: Rect, app: &App) {
+    // The status line only fits once there's room left for the pane itself
+    let status_height = if area.height >= 14 { 3 } else { 0 };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),             // Pane switcher
+            Constraint::Min(4),                // Focused pane
+            Constraint::Length(status_height), // Status line
+        ])
+        .split(area);
+
+    let mode = match app.current_mode {
+        AppMode::Automation => "Auto",
+        AppMode::Http => "HTTP",
+    };
+    let panes = [
+        (FocusedPane::Collections, "F5 Tree"),
+        (
+            FocusedPane::Form,
+            match app.current_mode {
+                AppMode::Automation if app.is_run_layout_active() => "F6 Steps",
+                AppMode::Automation => "F6 Form",
+                AppMode::Http => "F6 Request",
+            },
+        ),
+        (
+            FocusedPane::Logs,
+            match app.current_mode {
+                AppMode::Automation => "F7 Logs",
+                AppMode::Http => "F7 Response",
+            },
+        ),
+    ];
+    let mut spans = vec![Span::styled(
+        format!(" {} ", mode),
+        Style::default().fg(Color::Black).bg(Color::Cyan),
+    )];
+    for (pane, label) in panes {
+        let style = if app.focused_pane == pane {
+            Style::default()
+                .fg(Color::Yellow)
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(format!(" {} ", label), style));
+    }
+    f.render_widget(Paragraph::new(Line::from(spans)), chunks[0]);
+
+    let pane_area = chunks[1];
+    match (&app.focused_pane, &app.current_mode) {
+        (FocusedPane::Collections, _) => render_collections_tree(f, pane_area, app),
+        (FocusedPane::Form, AppMode::Automation) if app.is_run_layout_active() => {
+            render_run_timeline(
+                f,
+                pane_area,
+                &app.automation_state,
+                app.config.timestamp_format,
+            );
+        }
+        (FocusedPane::Form, AppMode::Automation) => {
+            render_automation_form(f, pane_area, &app.automation_state, &app.auth_service, app)
+        }
+        (FocusedPane::Form, AppMode::Http) => {
+            render_http_request_editor(f, pane_area, &app.http_state, app)
+        }
+        (FocusedPane::Logs, AppMode::Automation) => render_logging_panel(f, pane_area, app),
+        (FocusedPane::Logs, AppMode::Http) => {
+            render_http_response_viewer(f, pane_area, &app.http_state, app)
+        }
+    }
+
+    if status_height > 0 {
+        render_status_line(f, chunks[2], app);
+    }
+}
+
+/// Shown instead of any pane when the terminal can't fit even one
+fn render_too_small(f: &mut Frame, area: Rect) {
+    let message = Paragraph::new(vec![
+        Line::from(Span::styled(
+            "Terminal too small",
+            Style::default().fg(Color::Yellow),
+        )),
+        Line::from(format!("{}x{}", area.width, area.height)),
+        Line::from(format!(
+            "needs {}x{}",
+            MIN_TINY_LAYOUT_WIDTH, MIN_TINY_LAYOUT_HEIGHT
+        )),
+    ])
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true });
+    f.render_widget(message, area);
+}
+
 fn render_header(f: &mut Frame, area: Rect, app: &App) {
     let mode_titles = vec![
         format!(
@@ -89,26 +317,6 @@ fn render_header(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(tabs, area);
 }
 
-/// Get mode indicator for HTTP mode
-fn get_http_mode_indicator(app: &App) -> String {
-    let method = &app.http_state.current_request.method;
-    let is_valid = app.http_state.is_valid();
-    let is_sending = app.http_state.is_sending;
-
-    if is_sending {
-        " [SENDING]".to_string()
-    } else if app.focused_pane == FocusedPane::Form {
-        match app.input_mode {
-            crate::app::InputMode::Normal => format!(" [{}]", method.as_str()),
-            crate::app::InputMode::Edit => format!(" [EDIT {}]", method.as_str()),
-        }
-    } else if !is_valid {
-        " [INVALID]".to_string()
-    } else {
-        format!(" [{}]", method.as_str())
-    }
-}
-
 /// Main content layout controller - always show 3-pane layout with mode-specific content
 fn render_main_content(f: &mut Frame, area: Rect, app: &App) {
     // Always use 3-pane layout: Collections (left) | Content (top-right) | Logs/Response (bottom-right)
@@ -125,6 +333,24 @@ fn render_main_content(f: &mut Frame, area: Rect, app: &App) {
 
     // Split right side based on current mode
     match app.current_mode {
+        AppMode::Automation if app.is_run_layout_active() => {
+            // Live run: Step timeline (left) | Run output (right)
+            let run_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(40), // Step timeline
+                    Constraint::Percentage(60), // Live output
+                ])
+                .split(horizontal_chunks[1]);
+
+            render_run_timeline(
+                f,
+                run_chunks[0],
+                &app.automation_state,
+                app.config.timestamp_format,
+            );
+            render_logging_panel(f, run_chunks[1], app);
+        }
         AppMode::Automation => {
             // Automation: Form (top) | Logs (bottom)
             let vertical_chunks = Layout::default()
@@ -135,33 +361,51 @@ fn render_main_content(f: &mut Frame, area: Rect, app: &App) {
                 ])
                 .split(horizontal_chunks[1]);
 
-            render_automation_form(
-                f,
-                vertical_chunks[0],
-                &app.automation_state,
-                &app.auth_service,
-                app,
-            );
+            if app.is_folder_readme_shown() {
+                render_folder_readme(f, vertical_chunks[0], app);
+            } else {
+                render_automation_form(
+                    f,
+                    vertical_chunks[0],
+                    &app.automation_state,
+                    &app.auth_service,
+                    app,
+                );
+            }
 
             render_logging_panel(f, vertical_chunks[1], app);
         }
         AppMode::Http => {
-            // HTTP: Request Editor (top) | Response Viewer (bottom)
-            let vertical_chunks = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([
-                    Constraint::Percentage(60), // Request Editor (top)
-                    Constraint::Percentage(40), // Response Viewer (bottom)
-                ])
-                .split(horizontal_chunks[1]);
+            // HTTP: Request Editor (top) | Response Viewer (bottom), or side by side
+            let http_chunks = if app.config.http_split_layout {
+                Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([
+                        Constraint::Percentage(50), // Request Editor (left)
+                        Constraint::Percentage(50), // Response Viewer (right)
+                    ])
+                    .split(horizontal_chunks[1])
+            } else {
+                Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Percentage(60), // Request Editor (top)
+                        Constraint::Percentage(40), // Response Viewer (bottom)
+                    ])
+                    .split(horizontal_chunks[1])
+            };
 
-            render_http_request_editor(f, vertical_chunks[0], &app.http_state, app);
-            render_http_response_viewer(f, vertical_chunks[1], &app.http_state, app);
+            if app.is_folder_readme_shown() {
+                render_folder_readme(f, http_chunks[0], app);
+            } else {
+                render_http_request_editor(f, http_chunks[0], &app.http_state, app);
+            }
+            render_http_response_viewer(f, http_chunks[1], &app.http_state, app);
         }
     }
 }
 // TODO: old version
-/// Main content layout controller - always show 3-pane layout
+// Main content layout controller - always show 3-pane layout
 // fn render_main_content(f: &mut Frame, area: Rect, app: &App) {
 //     // Always use 3-pane layout: Collections (left) | Form (top-right) | Logs (bottom-right)
 //     let horizontal_chunks = Layout::default()
@@ -203,30 +447,6 @@ fn render_main_content(f: &mut Frame, area: Rect, app: &App) {
 //     // Logs in bottom-right (always visible now)
 //     render_logging_panel(f, vertical_chunks[1], app);
 // }
-/// Placeholder for HTTP mode
-fn render_http_placeholder(f: &mut Frame, area: Rect) {
-    let placeholder_text = vec![
-        Line::from("🚧 HTTP Client Mode"),
-        Line::from(""),
-        Line::from("This mode will include:"),
-        Line::from("• HTTP request builder"),
-        Line::from("• Request/Response viewer"),
-        Line::from("• Collection management"),
-        Line::from(""),
-        Line::from("Press F1 to switch back to Automation mode."),
-    ];
-
-    let paragraph = Paragraph::new(placeholder_text)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("HTTP Client Mode (Coming Soon)")
-                .title_style(Style::default().fg(Color::Yellow)),
-        )
-        .style(Style::default().fg(Color::DarkGray));
-
-    f.render_widget(paragraph, area);
-}
 
 /// Helper function to get theme colors
 pub fn get_theme_colors() -> ThemeColors {
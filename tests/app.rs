@@ -0,0 +1,439 @@
+//! Behaviour of the app as a whole, driven through `App` the way the event loop does.
+//!
+//! Like `safe_mode.rs` this is its own test binary: the XDG directories point at one temp
+//! dir shared by every test here, so nothing touches the real config or data.
+
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use std::sync::OnceLock;
+use tempfile::TempDir;
+use tui_one::app::{App, AppMessage, AppMode};
+use tui_one::models::KeyValuePair;
+use tui_one::models::confirmation::{ConfirmAction, Confirmation};
+use tui_one::models::http_client::{
+    HttpMethod, HttpRequest, HttpRequestBody, HttpResponse, PaginationRule,
+};
+use tui_one::services::audit_log::AuditAction;
+use tui_one::services::bug_report::BugReportFormat;
+
+static STORAGE_ROOT: OnceLock<TempDir> = OnceLock::new();
+
+/// A freshly started app whose config, data and cache live in a temp dir
+fn test_app() -> App {
+    STORAGE_ROOT.get_or_init(|| {
+        let temp = tempfile::tempdir().unwrap();
+        // SAFETY: every test calls this before anything reads the environment
+        unsafe {
+            std::env::set_var("HOME", temp.path());
+            std::env::set_var("XDG_CONFIG_HOME", temp.path().join("config"));
+            std::env::set_var("XDG_DATA_HOME", temp.path().join("data"));
+            std::env::set_var("XDG_CACHE_HOME", temp.path().join("cache"));
+        }
+        temp
+    });
+    App::new()
+}
+
+fn json_response(body: String) -> HttpResponse {
+    HttpResponse {
+        status_code: 200,
+        status_text: "OK".to_string(),
+        headers: Vec::new(),
+        body,
+        body_bytes: Vec::new(),
+        content_type: "application/json".to_string(),
+        duration_ms: 5,
+        served_from_cache: false,
+        connection: Default::default(),
+        transfer: Default::default(),
+        assertions: Vec::new(),
+        extracted: Vec::new(),
+        truncation: None,
+        timing: Default::default(),
+    }
+}
+
+fn logged(app: &App, text: &str) -> bool {
+    app.log_entries
+        .iter()
+        .any(|entry| entry.message.contains(text))
+}
+
+#[tokio::test]
+async fn test_invalid_json_body_blocks_an_auto_follow_send() {
+    let mut app = test_app();
+    app.config.block_invalid_json = true;
+
+    let mut request = HttpRequest::new("Users")
+        .with_method(HttpMethod::POST)
+        .with_url("https://api.example.com/users");
+    request.body = HttpRequestBody::Json {
+        content: "{\"name\": ".to_string(),
+    };
+    request.pagination.rule = PaginationRule::LinkHeader;
+    request.pagination.auto_follow = true;
+    app.http_state.load_request("Users", request);
+
+    app.send_http_request().await.unwrap();
+    assert!(!app.http_state.is_sending());
+    assert!(logged(&app, "Request not sent: fix the JSON body"));
+    assert!(!logged(&app, "Fetching up to"));
+}
+
+#[tokio::test]
+async fn test_fetching_all_pages_from_production_is_audited() {
+    let mut app = test_app();
+    app.config.active_environment = Some("Production".to_string());
+
+    let mut request = HttpRequest::new("Items").with_url("http://127.0.0.1:9/audited-items");
+    request.pagination.rule = PaginationRule::LinkHeader;
+    app.http_state.load_request("Items", request);
+
+    app.fetch_all_pages();
+    let entries = app.audit_log.read_recent(100).unwrap();
+    assert!(entries.iter().any(|entry| {
+        entry.action == AuditAction::ProductionSend
+            && entry.detail == "GET http://127.0.0.1:9/audited-items (Production)"
+    }));
+}
+
+#[tokio::test]
+async fn test_queued_run_starts_once_nothing_is_running() {
+    let mut app = test_app();
+    let queue_confirmation =
+        || Confirmation::new("Automation Running", "form", ConfirmAction::QueueAutomation);
+
+    // Still running: the run waits its turn
+    app.automation_state.set_running(true);
+    app.confirmation = Some(queue_confirmation());
+    app.confirm_pending().await.unwrap();
+    assert_eq!(app.automation_state.run_queue.len(), 1);
+    assert!(!logged(&app, "Queued runs are waiting for credentials"));
+
+    // The run finished while the confirmation was open: the queue starts right away
+    // (and, without credentials, asks for them instead of sitting there)
+    app.automation_state.run_queue.clear();
+    app.automation_state.set_running(false);
+    app.confirmation = Some(queue_confirmation());
+    app.confirm_pending().await.unwrap();
+    assert!(logged(&app, "Queued runs are waiting for credentials"));
+}
+
+#[tokio::test]
+async fn test_resend_sends_the_request_as_it_was_sent() {
+    let mut app = test_app();
+    let original = "http://127.0.0.1:9/resend-original";
+    app.http_state
+        .load_request("Resend", HttpRequest::new("Resend").with_url(original));
+    let key = app.http_state.begin_send();
+    app.http_state
+        .finish_send(&key, Some(json_response("{}".to_string())));
+
+    app.http_state.current_request.url = "http://127.0.0.1:9/resend-edited".to_string();
+    app.resend_last_request().await.unwrap();
+
+    let sending = &app.http_state.in_flight[&app.http_state.current_request_key];
+    assert_eq!(sending.url, original);
+    // The edited tab keeps its edits
+    let edited = app.http_state.tab_index("Resend").unwrap();
+    assert_ne!(edited, app.http_state.active_tab);
+    assert_eq!(
+        app.http_state.open_tabs[edited].request.url,
+        "http://127.0.0.1:9/resend-edited"
+    );
+}
+
+#[tokio::test]
+async fn test_response_view_is_built_once_per_change() {
+    let mut app = test_app();
+    let items: Vec<String> = (0..200)
+        .map(|index| format!("{{\"id\": {}, \"name\": \"item-{}\"}}", index, index))
+        .collect();
+    app.http_state
+        .load_request("Items", HttpRequest::new("Items"));
+    let key = app.http_state.begin_send();
+    app.http_state
+        .finish_send(&key, Some(json_response(format!("[{}]", items.join(",")))));
+
+    app.refresh_response_view();
+    let view = app.response_view.as_ref().unwrap();
+    assert!(view.is_json);
+    assert_eq!(view.json_paths.as_ref().unwrap().len(), view.lines.len());
+
+    // Nothing changed: the next frames reuse the view as it is
+    app.response_view.as_mut().unwrap().lines.clear();
+    app.refresh_response_view();
+    assert!(app.response_view.as_ref().unwrap().lines.is_empty());
+
+    // A new filter lays the body out again
+    app.http_state.response_filter = "$[150].name".to_string();
+    app.refresh_response_view();
+    let view = app.response_view.as_ref().unwrap();
+    assert_eq!(view.text.as_deref(), Ok("\"item-150\""));
+    assert_eq!(view.json_paths, None);
+
+    // The line under the cursor is scrolled into view far down the body
+    app.http_state.response_filter.clear();
+    app.http_state.response_cursor = 150 * 4 + 3;
+    app.refresh_response_view();
+    app.switch_mode(AppMode::Http);
+    app.show_whats_new = false;
+    let mut terminal = Terminal::new(TestBackend::new(160, 50)).unwrap();
+    terminal.draw(|f| tui_one::ui::render_app(f, &app)).unwrap();
+    let screen: String = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect();
+    assert!(screen.contains("\"item-150\""));
+    assert!(screen.contains("$[150].name"));
+}
+
+#[tokio::test]
+async fn test_search_matches_are_found_once_per_query() {
+    let mut app = test_app();
+    app.http_state
+        .load_request("Search", HttpRequest::new("Search"));
+    let key = app.http_state.begin_send();
+    app.http_state.finish_send(
+        &key,
+        Some(json_response(
+            r#"{"a": "needle", "b": "needle"}"#.to_string(),
+        )),
+    );
+
+    app.http_state.response_search = "needle".to_string();
+    app.response_search_changed();
+    assert_eq!(app.http_state.response_search_matches.len(), 2);
+    assert_eq!(app.http_state.response_cursor, 1);
+
+    // n/N step through the stored matches without searching again
+    app.http_state.response_search_matches.truncate(1);
+    app.step_response_search(true);
+    assert_eq!(app.http_state.response_search_current, 0);
+
+    // A new response is searched again
+    let key = app.http_state.begin_send();
+    app.http_state
+        .finish_send(&key, Some(json_response(r#"["hay"]"#.to_string())));
+    app.refresh_response_view();
+    assert!(app.http_state.response_search_matches.is_empty());
+}
+
+#[tokio::test]
+async fn test_response_secrets_are_masked_once_per_response() {
+    let mut app = test_app();
+    let request = HttpRequest::new("Echo").with_header("X-Api-Key", "echoed-secret-value");
+    app.http_state.load_request("Echo", request);
+    let key = app.http_state.begin_send();
+    app.http_state.finish_send(
+        &key,
+        Some(json_response(
+            r#"{"key": "echoed-secret-value"}"#.to_string(),
+        )),
+    );
+
+    app.refresh_response_view();
+    let masked = app.masked_last_response.as_ref().unwrap();
+    assert!(!masked.body.contains("echoed-secret-value"));
+
+    // A new filter reuses the masked copy
+    app.masked_last_response.as_mut().unwrap().body = "kept".to_string();
+    app.http_state.response_filter = "$.key".to_string();
+    app.refresh_response_view();
+    assert_eq!(app.masked_last_response.as_ref().unwrap().body, "kept");
+
+    // Revealing secrets shows the response as it came
+    app.reveal_secrets = true;
+    app.refresh_response_view();
+    assert!(app.masked_last_response.is_none());
+    let view = app.response_view.as_ref().unwrap();
+    assert_eq!(view.text.as_deref(), Ok("\"echoed-secret-value\""));
+}
+
+#[tokio::test]
+async fn test_bug_report_shows_the_request_as_it_was_sent() {
+    let mut app = test_app();
+    app.config.active_environment = Some("Staging".to_string());
+    app.http_state.environment_vars = vec![KeyValuePair::new("base", "http://127.0.0.1:9")];
+    let request = HttpRequest::new("Report me").with_url("{{base}}/reported");
+    app.http_state.load_request("Report me", request);
+    let key = app.http_state.begin_send();
+    app.http_state
+        .finish_send(&key, Some(json_response("{}".to_string())));
+    app.http_state.current_request.url = "{{base}}/edited-since".to_string();
+
+    app.export_bug_report(BugReportFormat::Json);
+
+    let exports = tui_one::models::config::data_directory().join("exports");
+    let report = std::fs::read_dir(exports)
+        .unwrap()
+        .flatten()
+        .find(|entry| entry.file_name().to_string_lossy().starts_with("Report_me"))
+        .unwrap();
+    let report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(report.path()).unwrap()).unwrap();
+    assert_eq!(report["environment"], "Staging");
+    assert_eq!(report["url"], "http://127.0.0.1:9/reported");
+}
+
+#[tokio::test]
+async fn test_result_of_a_cancelled_send_is_not_taken_for_the_next_one() {
+    let mut app = test_app();
+    // Accepts the connection but never answers, so each send stays in flight
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/slow", listener.local_addr().unwrap());
+    app.http_state
+        .load_request("Slow", HttpRequest::new("Slow").with_url(url));
+    let key = app.http_state.current_request_key.clone();
+
+    app.send_http_request().await.unwrap();
+    app.cancel_http_request();
+    app.send_http_request().await.unwrap();
+
+    // The first send's response was already queued when it was cancelled
+    app.message_sender
+        .send(AppMessage::HttpResponseReceived {
+            request_key: key.clone(),
+            generation: 1,
+            sent: None,
+            response: Box::new(json_response("{\"stale\": true}".to_string())),
+        })
+        .unwrap();
+    app.process_messages().await.unwrap();
+
+    assert!(app.http_state.last_response.is_none());
+    assert!(app.http_state.is_sending());
+    assert!(app.http_send_tasks.contains_key(&key));
+    app.cancel_http_request();
+    assert!(!app.http_state.is_sending());
+}
+
+#[tokio::test]
+async fn test_duration_covers_the_body_download() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let mut app = test_app();
+    // Sends the headers at once and the body a while later
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/slow-body", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let _ = socket.read(&mut [0; 4096]).await;
+        socket
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n")
+            .await
+            .unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+        socket.write_all(b"ok").await.unwrap();
+    });
+    app.http_state
+        .load_request("Slow body", HttpRequest::new("Slow body").with_url(url));
+
+    app.send_http_request().await.unwrap();
+    for _ in 0..100 {
+        app.process_messages().await.unwrap();
+        if app.http_state.last_response.is_some() {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+
+    let response = app.http_state.last_response.as_ref().unwrap();
+    assert_eq!(response.body, "ok");
+    assert!(response.timing.connect_ms.is_some());
+    assert!(!response.timing.tls_handshake);
+    assert!(response.timing.download_ms >= 250);
+    assert!(response.duration_ms >= response.timing.download_ms);
+    let phases: Vec<&str> = response
+        .timing
+        .phases()
+        .iter()
+        .map(|(label, _)| *label)
+        .collect();
+    assert_eq!(phases, vec!["Prepare", "Connect", "Waiting", "Download"]);
+}
+
+#[tokio::test]
+async fn test_folder_variables_are_edited_in_place_and_auth_in_the_editor() {
+    let mut app = test_app();
+    app.http_collection_storage
+        .save_request("Folder vars", "List", HttpRequest::new("List"))
+        .unwrap();
+    app.current_mode = AppMode::Http;
+    app.refresh_tree_from_storage().await.unwrap();
+    assert!(app.tree_state.reveal("Folder vars"));
+
+    app.show_folder_settings_dialog();
+    let dialog = app.folder_settings_dialog.as_mut().unwrap();
+    dialog.variables.push(KeyValuePair::new("tenant", "acme"));
+    app.toggle_folder_variable_secret();
+    app.hide_folder_settings_dialog();
+
+    let settings = app
+        .http_collection_storage
+        .read_folder_settings("Folder vars");
+    assert_eq!(settings.variables.len(), 1);
+    assert!(settings.variables[0].secret);
+
+    // Only the auth goes through $EDITOR; the variables stay as they were
+    app.show_folder_settings_dialog();
+    app.edit_folder_auth();
+    let target = app.pending_external_edit.take().unwrap();
+    app.finish_external_edit(
+        target,
+        Ok(serde_json::json!({"Bearer": {"token": "{{token}}"}}).to_string()),
+    );
+    let dialog = app.folder_settings_dialog.as_ref().unwrap();
+    assert_eq!(dialog.auth.title(), "Bearer Token");
+    let settings = app
+        .http_collection_storage
+        .read_folder_settings("Folder vars");
+    assert_eq!(settings.auth.unwrap().title(), "Bearer Token");
+    assert_eq!(settings.variables[0].value, "acme");
+}
+
+#[tokio::test]
+async fn test_collection_run_logs_mask_the_credentials_of_each_request() {
+    use tui_one::models::http_client::HttpHeader;
+
+    let mut app = test_app();
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let _ = socket.read(&mut [0; 4096]).await;
+        let _ = socket
+            .write_all(b"HTTP/1.1 204 No Content\r\nContent-Length: 0\r\n\r\n")
+            .await;
+    });
+    // The key comes from a variable nobody flagged secret, and the script logs a copy
+    app.http_state
+        .environment_vars
+        .push(KeyValuePair::new("api_token", "run-secret-value"));
+    let mut request = HttpRequest::new("Keyed").with_url(url);
+    request.headers = vec![HttpHeader::new("X-Api-Key", "{{api_token}}")];
+    request.pre_request_script = "set copied = header(\"X-Api-Key\")".to_string();
+    app.http_collection_storage
+        .save_request("Scrubbed run", "Keyed", request)
+        .unwrap();
+    app.current_mode = AppMode::Http;
+    app.refresh_tree_from_storage().await.unwrap();
+    assert!(app.tree_state.reveal("Scrubbed run"));
+
+    app.run_collection_folder();
+    for _ in 0..100 {
+        app.process_messages().await.unwrap();
+        if app.collection_run.as_ref().is_some_and(|run| run.finished) {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+
+    assert!(app.collection_run.as_ref().unwrap().finished);
+    assert!(logged(&app, "Pre-request script of 'Keyed' set copied="));
+    assert!(!logged(&app, "run-secret-value"));
+}
@@ -0,0 +1,111 @@
+//! `--safe-mode` must not write anything under the config, data or cache directories.
+//!
+//! This lives in its own test binary because it points the XDG directories at a temp dir
+//! and turns on the process-wide read-only flag, which would leak into the unit tests.
+
+use std::path::Path;
+use std::time::Duration;
+use tui_one::app::App;
+use tui_one::models::LogLevel;
+use tui_one::models::config::Environment;
+use tui_one::models::http_client::{HttpHeader, HttpRequest, HttpResponse};
+use tui_one::services::audit_log::{AuditAction, AuditEntry, AuditLog};
+use tui_one::services::drafts::{DraftContent, DraftStore};
+use tui_one::services::process_registry::ProcessRegistry;
+use tui_one::services::request_log::RequestLog;
+use tui_one::services::response_cache::ResponseCache;
+
+/// Every file under `root`, relative to it
+fn files_under(root: &Path) -> Vec<String> {
+    let mut files = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in std::fs::read_dir(&dir).into_iter().flatten().flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                pending.push(path);
+            } else {
+                files.push(path.strip_prefix(root).unwrap().display().to_string());
+            }
+        }
+    }
+    files
+}
+
+#[tokio::test]
+async fn test_safe_mode_leaves_data_directories_untouched() {
+    let temp = tempfile::tempdir().unwrap();
+    // SAFETY: this binary has a single test and nothing else reads the environment yet
+    unsafe {
+        std::env::set_var("HOME", temp.path());
+        std::env::set_var("XDG_CONFIG_HOME", temp.path().join("config"));
+        std::env::set_var("XDG_DATA_HOME", temp.path().join("data"));
+        std::env::set_var("XDG_CACHE_HOME", temp.path().join("cache"));
+    }
+
+    let mut app = App::with_safe_mode(true);
+
+    // What the main loop does every tick, with everything that could write made due
+    app.config.persist_cookies = true;
+    app.cookie_jar.lock().unwrap().changed = true;
+    app.save_cookie_jar();
+    app.http_state.rename_active_tab("Renamed");
+    app.persist_request_tabs();
+    app.config.backup_schedule.interval_hours = 1;
+    app.last_backup_at = None;
+    app.run_scheduled_backup();
+    // Switching environments (by hand or by loading a folder-bound request) stays in memory
+    app.config.environments.push(Environment {
+        name: "Staging".to_string(),
+        variables: Vec::new(),
+        proxy: Default::default(),
+    });
+    assert!(app.switch_environment("Staging"));
+    assert_eq!(app.config.active_environment_name(), "Staging");
+    assert!(
+        !app.log_entries
+            .iter()
+            .any(|entry| entry.level == LogLevel::Error)
+    );
+
+    // Writers that run in the background of a send or an automation run
+    let request = HttpRequest::new("Users").with_url("https://api.example.com/users");
+    let draft = DraftContent::capture("key", &request, &[]);
+    DraftStore::default().save(&draft).unwrap();
+    RequestLog::new(RequestLog::default_path(), false)
+        .append(chrono::Local::now(), &request, "200 OK")
+        .unwrap();
+    AuditLog::new(AuditLog::default_path())
+        .append(&AuditEntry::new(
+            AuditAction::ProductionSend,
+            Vec::new(),
+            "GET https://example.com (Production)",
+        ))
+        .unwrap();
+    let response = HttpResponse {
+        status_code: 200,
+        status_text: "OK".to_string(),
+        headers: vec![HttpHeader::new("ETag", "\"v1\"")],
+        body: "[]".to_string(),
+        body_bytes: Vec::new(),
+        content_type: "application/json".to_string(),
+        duration_ms: 5,
+        served_from_cache: false,
+        connection: Default::default(),
+        transfer: Default::default(),
+        assertions: Vec::new(),
+        extracted: Vec::new(),
+        truncation: None,
+        timing: Default::default(),
+    };
+    assert!(!ResponseCache::default().store("key", &response).unwrap());
+    ProcessRegistry::default()
+        .register(std::process::id(), "safe-mode-test", None)
+        .unwrap();
+
+    // Let the startup tree scan and storage initialization finish
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    app.process_messages().await.unwrap();
+
+    assert_eq!(files_under(temp.path()), Vec::<String>::new());
+}
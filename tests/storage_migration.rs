@@ -0,0 +1,146 @@
+//! The one-time move of templates and collections out of the config directory.
+//!
+//! Its own test binary: the XDG directories point at a temp dir, and the tests take
+//! turns since they share it.
+
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+use tempfile::TempDir;
+use tui_one::models::AppConfig;
+use tui_one::models::config::{
+    CURRENT_LAYOUT_VERSION, config_directory, data_directory, legacy_data_directory,
+};
+use tui_one::services::storage_migration::migrate_legacy_layout;
+
+static STORAGE_ROOT: OnceLock<TempDir> = OnceLock::new();
+static TURN: Mutex<()> = Mutex::new(());
+
+/// Point the XDG directories at an empty temp dir; hold the guard for the whole test
+fn empty_storage() -> std::sync::MutexGuard<'static, ()> {
+    let turn = TURN.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let root = STORAGE_ROOT.get_or_init(|| {
+        let temp = tempfile::tempdir().unwrap();
+        // SAFETY: set once, before anything in this binary reads the environment
+        unsafe {
+            std::env::set_var("HOME", temp.path());
+            std::env::set_var("XDG_CONFIG_HOME", temp.path().join("config"));
+            std::env::set_var("XDG_DATA_HOME", temp.path().join("data"));
+        }
+        temp
+    });
+    for dir in ["config", "data"] {
+        let _ = std::fs::remove_dir_all(root.path().join(dir));
+    }
+    turn
+}
+
+/// A config saved before the split, with templates next to the config file
+fn legacy_config() -> AppConfig {
+    AppConfig {
+        templates_directory: legacy_data_directory().join("templates"),
+        layout_version: 0,
+        ..AppConfig::default()
+    }
+}
+
+fn write(path: &Path, content: &str) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+fn read(path: &Path) -> String {
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn test_fresh_install_moves_nothing() {
+    let _turn = empty_storage();
+    let mut config = AppConfig::default();
+
+    assert_eq!(migrate_legacy_layout(&mut config).unwrap(), None);
+    assert_eq!(
+        config.templates_directory,
+        data_directory().join("templates")
+    );
+    assert!(!data_directory().exists());
+    assert!(!config_directory().exists());
+}
+
+#[test]
+fn test_legacy_layout_is_moved_to_the_data_directory() {
+    let _turn = empty_storage();
+    let legacy = legacy_data_directory();
+    write(&legacy.join("templates/Customer/Add.json"), "template");
+    write(&legacy.join("http-collections/Users/List.json"), "request");
+    let mut config = legacy_config();
+
+    let summary = migrate_legacy_layout(&mut config).unwrap().unwrap();
+
+    assert!(summary.starts_with("Migrated 2 item(s)"));
+    let data = data_directory();
+    assert_eq!(config.templates_directory, data.join("templates"));
+    assert_eq!(read(&data.join("templates/Customer/Add.json")), "template");
+    assert_eq!(
+        read(&data.join("http-collections/Users/List.json")),
+        "request"
+    );
+    assert!(!legacy.join("templates").exists());
+    assert!(!legacy.join("http-collections").exists());
+    // Saved, so it only runs once
+    let saved = AppConfig::load().unwrap();
+    assert_eq!(saved.layout_version, CURRENT_LAYOUT_VERSION);
+    assert_eq!(saved.templates_directory, data.join("templates"));
+}
+
+#[test]
+fn test_existing_target_is_not_overwritten() {
+    let _turn = empty_storage();
+    let legacy = legacy_data_directory();
+    let data = data_directory();
+    write(&legacy.join("templates/Customer/Add.json"), "legacy");
+    write(&legacy.join("templates/Orders/Create.json"), "orders");
+    write(&data.join("templates/Customer/Add.json"), "current");
+    let mut config = legacy_config();
+
+    migrate_legacy_layout(&mut config).unwrap();
+
+    assert_eq!(read(&data.join("templates/Customer/Add.json")), "current");
+    assert_eq!(read(&data.join("templates/Orders/Create.json")), "orders");
+    // What couldn't be moved stays where it was
+    assert_eq!(read(&legacy.join("templates/Customer/Add.json")), "legacy");
+}
+
+#[test]
+fn test_failed_migration_loses_nothing_and_runs_again() {
+    let _turn = empty_storage();
+    let legacy = legacy_data_directory();
+    let data = data_directory();
+    write(&legacy.join("templates/Customer/Add.json"), "template");
+    write(&legacy.join("http-collections/Users/List.json"), "request");
+    // A file where the collections directory should go stops the second half
+    write(&data.join("http-collections"), "in the way");
+    legacy_config().save().unwrap();
+    let mut config = legacy_config();
+
+    assert!(migrate_legacy_layout(&mut config).is_err());
+    assert_eq!(read(&data.join("templates/Customer/Add.json")), "template");
+    assert_eq!(
+        read(&legacy.join("http-collections/Users/List.json")),
+        "request"
+    );
+    assert_eq!(AppConfig::load().unwrap().layout_version, 0);
+
+    // The next launch finishes the move
+    std::fs::remove_file(data.join("http-collections")).unwrap();
+    let mut config = AppConfig::load().unwrap();
+    migrate_legacy_layout(&mut config).unwrap();
+    assert_eq!(read(&data.join("templates/Customer/Add.json")), "template");
+    assert_eq!(
+        read(&data.join("http-collections/Users/List.json")),
+        "request"
+    );
+    assert_eq!(
+        AppConfig::load().unwrap().layout_version,
+        CURRENT_LAYOUT_VERSION
+    );
+}